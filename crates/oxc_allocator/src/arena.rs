@@ -189,6 +189,14 @@ mod test {
         assert_eq!(*b, "v");
     }
 
+    #[test]
+    fn reset() {
+        let mut allocator = Allocator::default();
+        let ptr = allocator.alloc_str(&"x".repeat(1024)).as_ptr();
+        allocator.reset();
+        assert_eq!(allocator.alloc_str(&"y".repeat(1024)).as_ptr(), ptr);
+    }
+
     #[test]
     fn box_debug() {
         let allocator = Allocator::default();
@@ -17,3 +17,11 @@ impl Deref for Allocator {
         &self.bump
     }
 }
+
+impl Allocator {
+    /// Deallocate everything allocated in the arena while keeping its largest chunk of memory,
+    /// so the arena can be reused for another source without allocating again.
+    pub fn reset(&mut self) {
+        self.bump.reset();
+    }
+}
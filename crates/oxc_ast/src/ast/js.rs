@@ -701,6 +701,9 @@ pub struct BinaryExpression<'a> {
     pub span: Span,
     pub left: Expression<'a>,
     pub operator: BinaryOperator,
+    /// Span of the operator token
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub operator_span: Span,
     pub right: Expression<'a>,
 }
 
@@ -723,6 +726,9 @@ pub struct LogicalExpression<'a> {
     pub span: Span,
     pub left: Expression<'a>,
     pub operator: LogicalOperator,
+    /// Span of the operator token
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub operator_span: Span,
     pub right: Expression<'a>,
 }
 
@@ -744,6 +750,9 @@ pub struct AssignmentExpression<'a> {
     #[cfg_attr(feature = "serde", serde(flatten))]
     pub span: Span,
     pub operator: AssignmentOperator,
+    /// Span of the operator token
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub operator_span: Span,
     pub left: AssignmentTarget<'a>,
     pub right: Expression<'a>,
 }
@@ -1024,6 +1033,10 @@ pub enum VariableDeclarationKind {
     Var,
     Const,
     Let,
+    /// <https://github.com/tc39/proposal-explicit-resource-management>
+    Using,
+    #[cfg_attr(feature = "serde", serde(rename = "await using"))]
+    AwaitUsing,
 }
 
 impl VariableDeclarationKind {
@@ -1036,7 +1049,11 @@ impl VariableDeclarationKind {
     }
 
     pub fn is_lexical(&self) -> bool {
-        matches!(self, Self::Const | Self::Let)
+        matches!(self, Self::Const | Self::Let | Self::Using | Self::AwaitUsing)
+    }
+
+    pub fn is_using(&self) -> bool {
+        matches!(self, Self::Using | Self::AwaitUsing)
     }
 }
 
@@ -1046,6 +1063,8 @@ impl fmt::Display for VariableDeclarationKind {
             Self::Var => "var",
             Self::Const => "const",
             Self::Let => "let",
+            Self::Using => "using",
+            Self::AwaitUsing => "await using",
         };
         write!(f, "{s}")
     }
@@ -1753,6 +1772,7 @@ pub struct AccessorProperty<'a> {
     pub value: Option<Expression<'a>>,
     pub computed: bool,
     pub r#static: bool,
+    pub decorators: Vec<'a, Decorator<'a>>,
 }
 
 #[derive(Debug, Hash)]
@@ -157,6 +157,12 @@ pub struct JSXAttribute<'a> {
     pub value: Option<JSXAttributeValue<'a>>,
 }
 
+impl<'a> JSXAttribute<'a> {
+    pub fn is_identifier(&self, name: &str) -> bool {
+        matches!(&self.name, JSXAttributeName::Identifier(ident) if ident.name == name)
+    }
+}
+
 /// JSX Spread Attribute
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type"))]
@@ -174,6 +180,18 @@ pub enum JSXAttributeName<'a> {
     NamespacedName(Box<'a, JSXNamespacedName>),
 }
 
+impl<'a> JSXAttributeName<'a> {
+    /// The attribute name as written, e.g. `onClick` or `xlink:href`.
+    pub fn get_name(&self) -> String {
+        match self {
+            Self::Identifier(ident) => ident.name.to_string(),
+            Self::NamespacedName(name) => {
+                format!("{}:{}", name.namespace.name, name.property.name)
+            }
+        }
+    }
+}
+
 /// JSX Attribute Value
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
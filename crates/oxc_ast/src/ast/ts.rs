@@ -55,6 +55,26 @@ pub enum TSEnumMemberName<'a> {
     NumberLiteral(NumberLiteral<'a>),
 }
 
+impl<'a> TSEnumMemberName<'a> {
+    /// The name of the member, computed names are folded when they are string,
+    /// number or no-substitution template literals, e.g. `['a']` and `` [`a`] ``
+    pub fn static_name(&self) -> Option<Atom> {
+        match self {
+            Self::Identifier(ident) => Some(ident.name.clone()),
+            Self::StringLiteral(lit) => Some(lit.value.clone()),
+            Self::NumberLiteral(lit) => Some(Atom::from(lit.value.to_string())),
+            Self::ComputedPropertyName(expr) => match expr {
+                Expression::StringLiteral(lit) => Some(lit.value.clone()),
+                Expression::NumberLiteral(lit) => Some(Atom::from(lit.value.to_string())),
+                Expression::TemplateLiteral(lit) => {
+                    lit.expressions.is_empty().then(|| lit.quasi()).flatten().cloned()
+                }
+                _ => None,
+            },
+        }
+    }
+}
+
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(tag = "type", rename_all = "camelCase"))]
 pub struct TSTypeAnnotation<'a> {
@@ -634,7 +654,7 @@ pub struct TSModuleDeclaration<'a> {
 #[derive(Debug, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize), serde(untagged))]
 pub enum TSModuleDeclarationName {
-    Identifier(IdentifierName),
+    Identifier(BindingIdentifier),
     StringLiteral(StringLiteral),
 }
 
@@ -321,12 +321,14 @@ impl<'a> AstBuilder<'a> {
         &self,
         span: Span,
         operator: AssignmentOperator,
+        operator_span: Span,
         left: AssignmentTarget<'a>,
         right: Expression<'a>,
     ) -> Expression<'a> {
         Expression::AssignmentExpression(self.alloc(AssignmentExpression {
             span,
             operator,
+            operator_span,
             left,
             right,
         }))
@@ -341,9 +343,16 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         left: Expression<'a>,
         operator: BinaryOperator,
+        operator_span: Span,
         right: Expression<'a>,
     ) -> Expression<'a> {
-        Expression::BinaryExpression(self.alloc(BinaryExpression { span, left, operator, right }))
+        Expression::BinaryExpression(self.alloc(BinaryExpression {
+            span,
+            left,
+            operator,
+            operator_span,
+            right,
+        }))
     }
 
     pub fn call_expression(
@@ -404,9 +413,16 @@ impl<'a> AstBuilder<'a> {
         span: Span,
         left: Expression<'a>,
         operator: LogicalOperator,
+        operator_span: Span,
         right: Expression<'a>,
     ) -> Expression<'a> {
-        Expression::LogicalExpression(self.alloc(LogicalExpression { span, left, operator, right }))
+        Expression::LogicalExpression(self.alloc(LogicalExpression {
+            span,
+            left,
+            operator,
+            operator_span,
+            right,
+        }))
     }
 
     pub fn computed_member_expression(
@@ -695,6 +711,7 @@ impl<'a> AstBuilder<'a> {
         value: Option<Expression<'a>>,
         computed: bool,
         r#static: bool,
+        decorators: Vec<'a, Decorator<'a>>,
     ) -> ClassElement<'a> {
         ClassElement::AccessorProperty(self.alloc(AccessorProperty {
             span,
@@ -702,6 +719,7 @@ impl<'a> AstBuilder<'a> {
             value,
             computed,
             r#static,
+            decorators,
         }))
     }
 
@@ -376,6 +376,15 @@ impl<'a> GetSpan for JSXAttributeItem<'a> {
     }
 }
 
+impl<'a> GetSpan for JSXAttributeName<'a> {
+    fn span(&self) -> Span {
+        match &self {
+            JSXAttributeName::Identifier(ident) => ident.span,
+            JSXAttributeName::NamespacedName(name) => name.span,
+        }
+    }
+}
+
 impl<'a> GetSpan for JSXExpression<'a> {
     fn span(&self) -> Span {
         match &self {
@@ -12,6 +12,11 @@ use oxc_span::Span;
 pub struct Trivias {
     /// Keyed by span.start
     comments: BTreeMap<u32, Comment>,
+
+    /// Parentheses around assignment targets, e.g. `(a) = b`, from `(` to `)` keyed by span.start.
+    /// Parenthesized expressions are preserved as `ParenthesizedExpression` in the AST,
+    /// but assignment targets have no parenthesized variant.
+    parenthesized_targets: BTreeMap<u32, u32>,
 }
 
 /// Single or multiline comment
@@ -51,6 +56,26 @@ impl Trivias {
         &self.comments
     }
 
+    /// Remove the comments and parentheses starting at or after `offset`
+    pub fn truncate(&mut self, offset: u32) {
+        self.comments.retain(|start, _| *start < offset);
+        self.parenthesized_targets.retain(|start, _| *start < offset);
+    }
+
+    /// Spans of the parentheses around assignment targets, including `(` and `)`
+    pub fn parenthesized_targets(&self) -> impl Iterator<Item = Span> + '_ {
+        self.parenthesized_targets.iter().map(|(start, end)| Span::new(*start, *end))
+    }
+
+    /// Whether the assignment target at `span` is wrapped in parentheses
+    pub fn is_parenthesized_target(&self, span: Span) -> bool {
+        self.parenthesized_targets.range(..span.start).any(|(_, end)| span.end < *end)
+    }
+
+    pub fn add_parenthesized_target(&mut self, span: Span) {
+        self.parenthesized_targets.insert(span.start, span.end);
+    }
+
     pub fn has_comments_between(&self, span: Span) -> bool {
         self.comments.range(span.start..span.end).count() > 0
     }
@@ -18,6 +18,9 @@ pub trait Visit<'a>: Sized {
     fn visit_program(&mut self, program: &'a Program<'a>) {
         let kind = AstKind::Program(program);
         self.enter_node(kind);
+        if let Some(hashbang) = &program.hashbang {
+            self.visit_hashbang(hashbang);
+        }
         for directive in &program.directives {
             self.visit_directive(directive);
         }
@@ -271,6 +274,12 @@ pub trait Visit<'a>: Sized {
         self.leave_node(kind);
     }
 
+    fn visit_hashbang(&mut self, hashbang: &'a Hashbang) {
+        let kind = AstKind::Hashbang(hashbang);
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
     fn visit_directive(&mut self, directive: &'a Directive) {
         let kind = AstKind::Directive(directive);
         self.enter_node(kind);
@@ -405,7 +414,7 @@ pub trait Visit<'a>: Sized {
             ClassElement::StaticBlock(block) => self.visit_static_block(block),
             ClassElement::MethodDefinition(def) => self.visit_method_definition(def),
             ClassElement::PropertyDefinition(def) => self.visit_property_definition(def),
-            ClassElement::AccessorProperty(_def) => { /* TODO */ }
+            ClassElement::AccessorProperty(def) => self.visit_accessor_property(def),
             ClassElement::TSAbstractMethodDefinition(def) => {
                 self.visit_method_definition(&def.method_definition);
             }
@@ -450,6 +459,16 @@ pub trait Visit<'a>: Sized {
         self.leave_node(kind);
     }
 
+    fn visit_accessor_property(&mut self, def: &'a AccessorProperty<'a>) {
+        for decorator in &def.decorators {
+            self.visit_decorator(decorator);
+        }
+        self.visit_property_key(&def.key);
+        if let Some(value) = &def.value {
+            self.visit_expression(value);
+        }
+    }
+
     /* ----------  Expression ---------- */
 
     fn visit_expression(&mut self, expr: &'a Expression<'a>) {
@@ -947,12 +966,15 @@ pub trait Visit<'a>: Sized {
     }
 
     fn visit_jsx_attribute_item(&mut self, item: &'a JSXAttributeItem<'a>) {
+        let kind = AstKind::JSXAttributeItem(item);
+        self.enter_node(kind);
         match &item {
             JSXAttributeItem::Attribute(attribute) => self.visit_jsx_attribute(attribute),
             JSXAttributeItem::SpreadAttribute(attribute) => {
                 self.visit_jsx_spread_attribute(attribute);
             }
         }
+        self.leave_node(kind);
     }
 
     fn visit_jsx_attribute(&mut self, attribute: &'a JSXAttribute<'a>) {
@@ -988,9 +1010,12 @@ pub trait Visit<'a>: Sized {
     }
 
     fn visit_jsx_fragment(&mut self, elem: &'a JSXFragment<'a>) {
+        let kind = AstKind::JSXFragment(elem);
+        self.enter_node(kind);
         for child in &elem.children {
             self.visit_jsx_child(child);
         }
+        self.leave_node(kind);
     }
 
     fn visit_jsx_child(&mut self, child: &'a JSXChild<'a>) {
@@ -1235,6 +1260,9 @@ pub trait Visit<'a>: Sized {
         let kind = AstKind::TSEnumMember(member);
         self.enter_node(kind);
 
+        if let TSEnumMemberName::ComputedPropertyName(expr) = &member.id {
+            self.visit_expression(expr);
+        }
         if let Some(initializer) = &member.initializer {
             self.visit_expression(initializer);
         }
@@ -1291,7 +1319,7 @@ pub trait Visit<'a>: Sized {
         let kind = AstKind::TSModuleDeclaration(decl);
         self.enter_node(kind);
         match &decl.id {
-            TSModuleDeclarationName::Identifier(ident) => self.visit_identifier_name(ident),
+            TSModuleDeclarationName::Identifier(ident) => self.visit_binding_identifier(ident),
             TSModuleDeclarationName::StringLiteral(lit) => self.visit_string_literal(lit),
         }
         match &decl.body {
@@ -1396,6 +1424,7 @@ pub trait Visit<'a>: Sized {
             TSType::TSTypePredicate(ty) => self.visit_ts_type_predicate(ty),
             TSType::TSTypeLiteral(ty) => self.visit_ts_type_literal(ty),
             TSType::TSIndexedAccessType(ty) => self.visit_ts_indexed_access_type(ty),
+            TSType::TSTypeQuery(ty) => self.visit_ts_type_query(ty),
             _ => {}
         }
     }
@@ -1417,6 +1446,16 @@ pub trait Visit<'a>: Sized {
         self.leave_node(kind);
     }
 
+    fn visit_ts_type_query(&mut self, ty: &'a TSTypeQuery<'a>) {
+        let kind = AstKind::TSTypeQuery(ty);
+        self.enter_node(kind);
+        self.visit_ts_type_name(&ty.expr_name);
+        if let Some(parameters) = &ty.type_parameters {
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+        self.leave_node(kind);
+    }
+
     fn visit_ts_type_predicate(&mut self, ty: &'a TSTypePredicate<'a>) {
         if let Some(annotation) = &ty.type_annotation {
             self.visit_ts_type_annotation(annotation);
@@ -1511,7 +1550,7 @@ pub trait Visit<'a>: Sized {
     fn visit_ts_type_name(&mut self, name: &'a TSTypeName<'a>) {
         match &name {
             TSTypeName::IdentifierReference(ident) => self.visit_identifier_reference(ident),
-            TSTypeName::QualifiedName(_) => {}
+            TSTypeName::QualifiedName(name) => self.visit_ts_type_name(&name.left),
         }
     }
 
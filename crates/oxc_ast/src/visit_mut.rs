@@ -294,7 +294,7 @@ pub trait VisitMut<'a, 'b>: Sized {
             ClassElement::StaticBlock(block) => self.visit_static_block(block),
             ClassElement::MethodDefinition(def) => self.visit_method_definition(def),
             ClassElement::PropertyDefinition(def) => self.visit_property_definition(def),
-            ClassElement::AccessorProperty(_def) => { /* TODO */ }
+            ClassElement::AccessorProperty(def) => self.visit_accessor_property(def),
             ClassElement::TSAbstractMethodDefinition(def) => {
                 self.visit_method_definition(&mut def.method_definition);
             }
@@ -330,6 +330,16 @@ pub trait VisitMut<'a, 'b>: Sized {
         }
     }
 
+    fn visit_accessor_property(&mut self, def: &'b mut AccessorProperty<'a>) {
+        for decorator in def.decorators.iter_mut() {
+            self.visit_decorator(decorator);
+        }
+        self.visit_property_key(&mut def.key);
+        if let Some(value) = &mut def.value {
+            self.visit_expression(value);
+        }
+    }
+
     /* ----------  Expression ---------- */
 
     fn visit_expression(&mut self, expr: &'b mut Expression<'a>) {
@@ -943,6 +953,9 @@ pub trait VisitMut<'a, 'b>: Sized {
     }
 
     fn visit_enum_member(&mut self, member: &'b mut TSEnumMember<'a>) {
+        if let TSEnumMemberName::ComputedPropertyName(expr) = &mut member.id {
+            self.visit_expression(expr);
+        }
         if let Some(initializer) = &mut member.initializer {
             self.visit_expression(initializer);
         }
@@ -982,7 +995,7 @@ pub trait VisitMut<'a, 'b>: Sized {
 
     fn visit_ts_module_declaration(&mut self, decl: &'b mut TSModuleDeclaration<'a>) {
         match &mut decl.id {
-            TSModuleDeclarationName::Identifier(ident) => self.visit_identifier_name(ident),
+            TSModuleDeclarationName::Identifier(ident) => self.visit_binding_identifier(ident),
             TSModuleDeclarationName::StringLiteral(lit) => self.visit_string_literal(lit),
         }
         match &mut decl.body {
@@ -1403,6 +1403,8 @@ impl<'a> AstLower<'a> {
             ast::VariableDeclarationKind::Var => hir::VariableDeclarationKind::Var,
             ast::VariableDeclarationKind::Const => hir::VariableDeclarationKind::Const,
             ast::VariableDeclarationKind::Let => hir::VariableDeclarationKind::Let,
+            ast::VariableDeclarationKind::Using => hir::VariableDeclarationKind::Using,
+            ast::VariableDeclarationKind::AwaitUsing => hir::VariableDeclarationKind::AwaitUsing,
         };
         let declarations = self.lower_vec(&decl.declarations, Self::lower_variable_declarator);
         self.hir.variable_declaration(decl.span, kind, declarations)
@@ -1416,6 +1418,8 @@ impl<'a> AstLower<'a> {
             ast::VariableDeclarationKind::Var => hir::VariableDeclarationKind::Var,
             ast::VariableDeclarationKind::Const => hir::VariableDeclarationKind::Const,
             ast::VariableDeclarationKind::Let => hir::VariableDeclarationKind::Let,
+            ast::VariableDeclarationKind::Using => hir::VariableDeclarationKind::Using,
+            ast::VariableDeclarationKind::AwaitUsing => hir::VariableDeclarationKind::AwaitUsing,
         };
 
         let (includes, excludes) = if decl.kind.is_lexical() {
@@ -1624,7 +1628,8 @@ impl<'a> AstLower<'a> {
     ) -> Box<'a, hir::AccessorProperty<'a>> {
         let key = self.lower_property_key(&def.key);
         let value = def.value.as_ref().map(|expr| self.lower_expression(expr));
-        self.hir.accessor_property(def.span, key, value, def.computed, def.r#static)
+        let decorators = self.lower_vec(&def.decorators, Self::lower_decorator);
+        self.hir.accessor_property(def.span, key, value, def.computed, def.r#static, decorators)
     }
 
     fn lower_ts_enum_declaration(
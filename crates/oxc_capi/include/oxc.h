@@ -0,0 +1,51 @@
+#ifndef OXC_H
+#define OXC_H
+
+/*
+ * A C ABI for the oxc parser, linter and resolver.
+ *
+ * Arguments and return values are NUL terminated UTF-8 strings, structured return values are JSON.
+ * On failure a function returns NULL and stores the error message in `error` if it is not NULL.
+ * Strings returned by oxc, including errors, must be released with `oxc_free`.
+ */
+
+#include <stdbool.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/*
+ * Parse `source_text`, the file name decides the source type, e.g. `.tsx`.
+ * `source_filename` may be NULL for `index.js`.
+ *
+ * Returns `{ "program": <ESTree>, "errors": [{ "message", "start", "end" }] }`.
+ */
+char *oxc_parse(const char *source_text, const char *source_filename, char **error);
+
+/*
+ * Lint `source_text`, `source_filename` may be NULL for `index.js`.
+ * `config` is NULL for the `correctness` category, or JSON in the format of the `--config` file of the CLI.
+ * `fix` computes fixes, including suggestions and dangerous fixes.
+ *
+ * Returns `[{ "rule", "severity", "message", "help", "start", "end", "fix": { "kind", "start", "end", "content" } }]`.
+ */
+char *oxc_lint(const char *source_text, const char *source_filename, const char *config, bool fix, char **error);
+
+/*
+ * Resolve `request` from `directory` like Node.js `require.resolve`.
+ *
+ * Returns the resolved path.
+ */
+char *oxc_resolve(const char *directory, const char *request, char **error);
+
+/*
+ * Release a string returned by oxc, NULL is ignored.
+ */
+void oxc_free(char *string);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* OXC_H */
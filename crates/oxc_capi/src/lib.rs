@@ -0,0 +1,306 @@
+//! A C ABI for the parser, linter and resolver, declared in `include/oxc.h`.
+//!
+//! Arguments and return values are NUL terminated UTF-8 strings, structured return values are JSON.
+//! On failure a function returns `NULL` and stores the error message in `error` if it is not `NULL`.
+//! Strings returned by this crate must be released with [oxc_free].
+
+use std::{
+    ffi::{c_char, CStr, CString},
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::Path,
+    ptr,
+};
+
+use oxc_allocator::Allocator;
+use oxc_linter::{LintConfig, LintOptions, Linter};
+use oxc_parser::Parser;
+use oxc_resolver::{ResolveOptions, Resolver};
+use oxc_span::{SourceType, VALID_EXTENSIONS};
+use serde_json::{json, Value};
+
+/// Parse `source_text`, the file name decides the source type, e.g. `.tsx`, and defaults to `index.js`.
+///
+/// Returns `{ "program": <ESTree>, "errors": [{ "message", "start", "end" }] }`.
+///
+/// # Safety
+///
+/// * `source_text` and `source_filename` must be `NULL` or NUL terminated strings
+/// * `error` must be `NULL` or valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn oxc_parse(
+    source_text: *const c_char,
+    source_filename: *const c_char,
+    error: *mut *mut c_char,
+) -> *mut c_char {
+    call(error, || {
+        let source_text = to_str(source_text, "source_text")?;
+        let source_filename = to_str_or(source_filename, "source_filename", "index.js")?;
+        let source_type = source_type(source_filename)?;
+
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let errors = ret
+            .errors
+            .iter()
+            .map(|error| {
+                let (start, end) = error
+                    .labels()
+                    .and_then(|mut labels| labels.next())
+                    .map_or((0, 0), |label| (label.offset(), label.offset() + label.len()));
+                json!({ "message": error.to_string(), "start": start, "end": end })
+            })
+            .collect::<Vec<_>>();
+        Ok(json!({ "program": ret.program, "errors": errors }).to_string())
+    })
+}
+
+/// Lint `source_text`, the file name decides the source type and defaults to `index.js`.
+/// `config` is `NULL` for the `correctness` category, or JSON in the format of the `--config` file of the CLI.
+/// `fix` computes fixes, including suggestions and dangerous fixes.
+///
+/// Returns `[{ "rule", "severity", "message", "help", "start", "end", "fix": { "kind", "start", "end", "content" } }]`,
+/// with UTF-8 byte offsets, and `null` for the missing `rule` of syntax errors, `help` and `fix`.
+///
+/// # Safety
+///
+/// * `source_text`, `source_filename` and `config` must be `NULL` or NUL terminated strings
+/// * `error` must be `NULL` or valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn oxc_lint(
+    source_text: *const c_char,
+    source_filename: *const c_char,
+    config: *const c_char,
+    fix: bool,
+    error: *mut *mut c_char,
+) -> *mut c_char {
+    call(error, || {
+        let source_text = to_str(source_text, "source_text")?;
+        let source_filename = to_str_or(source_filename, "source_filename", "index.js")?;
+        source_type(source_filename)?;
+        let config = if config.is_null() {
+            None
+        } else {
+            let value = serde_json::from_str::<Value>(to_str(config, "config")?)
+                .map_err(|error| format!("Invalid config: {error}"))?;
+            Some(
+                LintConfig::from_value(&value)
+                    .map_err(|error| format!("Invalid config: {error}"))?,
+            )
+        };
+        let options = LintOptions::default()
+            .with_config(config)
+            .with_fix(fix)
+            .with_fix_suggestions(fix)
+            .with_fix_dangerously(fix);
+
+        let diagnostics = Linter::lint_source(Path::new(source_filename), source_text, options)
+            .into_iter()
+            .map(|diagnostic| {
+                json!({
+                    "rule": diagnostic.rule.map(|rule| rule.to_string()),
+                    "severity": format!("{:?}", diagnostic.severity).to_lowercase(),
+                    "message": diagnostic.message,
+                    "help": diagnostic.help,
+                    "start": diagnostic.span.start,
+                    "end": diagnostic.span.end,
+                    "fix": diagnostic.fix.map(|fix| json!({
+                        "kind": fix.kind.as_str(),
+                        "start": fix.span.start,
+                        "end": fix.span.end,
+                        "content": fix.content,
+                    })),
+                })
+            })
+            .collect::<Vec<_>>();
+        Ok(Value::from(diagnostics).to_string())
+    })
+}
+
+/// Resolve `request` from `directory` like Node.js `require.resolve`,
+/// with the `node` and `import` conditions and the extensions of JavaScript and TypeScript.
+///
+/// Returns the resolved path.
+///
+/// # Safety
+///
+/// * `directory` and `request` must be `NULL` or NUL terminated strings
+/// * `error` must be `NULL` or valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn oxc_resolve(
+    directory: *const c_char,
+    request: *const c_char,
+    error: *mut *mut c_char,
+) -> *mut c_char {
+    call(error, || {
+        let directory = to_str(directory, "directory")?;
+        let request = to_str(request, "request")?;
+        let resolver = Resolver::new(ResolveOptions {
+            condition_names: vec!["node".into(), "import".into()],
+            extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
+            ..ResolveOptions::default()
+        });
+        resolver
+            .resolve(directory, request)
+            .map(|resolution| resolution.full_path().to_string_lossy().into_owned())
+            .map_err(|error| error.to_string())
+    })
+}
+
+/// Release a string returned by oxc, `NULL` is ignored.
+///
+/// # Safety
+///
+/// * `string` must be `NULL` or returned by oxc, and not released before
+#[no_mangle]
+pub unsafe extern "C" fn oxc_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Run `f` without unwinding into C, and return its value or store its error in `error`.
+unsafe fn call(error: *mut *mut c_char, f: impl FnOnce() -> Result<String, String>) -> *mut c_char {
+    let result = catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(ToString::to_string)
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(format!("oxc panicked: {message}"))
+    });
+    match result {
+        Ok(value) => into_raw(&value),
+        Err(message) => {
+            if !error.is_null() {
+                *error = into_raw(&message);
+            }
+            ptr::null_mut()
+        }
+    }
+}
+
+unsafe fn to_str<'a>(string: *const c_char, name: &str) -> Result<&'a str, String> {
+    if string.is_null() {
+        return Err(format!("`{name}` is NULL"));
+    }
+    CStr::from_ptr(string).to_str().map_err(|_| format!("`{name}` is not UTF-8"))
+}
+
+unsafe fn to_str_or<'a>(
+    string: *const c_char,
+    name: &str,
+    default: &'a str,
+) -> Result<&'a str, String> {
+    if string.is_null() {
+        Ok(default)
+    } else {
+        to_str(string, name)
+    }
+}
+
+fn source_type(source_filename: &str) -> Result<SourceType, String> {
+    SourceType::from_path(source_filename)
+        .map_err(|_| format!("Invalid file extension: {source_filename}"))
+}
+
+fn into_raw(string: &str) -> *mut c_char {
+    // JSON escapes NUL, so it can only be in error messages
+    CString::new(string.replace('\0', "")).unwrap_or_default().into_raw()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        ffi::{c_char, CStr, CString},
+        ptr,
+    };
+
+    use serde_json::{json, Value};
+
+    use super::{oxc_free, oxc_lint, oxc_parse, oxc_resolve};
+
+    fn c(string: &str) -> CString {
+        CString::new(string).unwrap()
+    }
+
+    /// Take the return value or the error of a call
+    unsafe fn take(f: impl FnOnce(*mut *mut c_char) -> *mut c_char) -> Result<String, String> {
+        let mut error = ptr::null_mut();
+        let value = f(&mut error);
+        let take = |string: *mut c_char| {
+            let owned = CStr::from_ptr(string).to_str().unwrap().to_string();
+            oxc_free(string);
+            owned
+        };
+        if value.is_null() {
+            Err(take(error))
+        } else {
+            assert!(error.is_null());
+            Ok(take(value))
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let source_text = c("let");
+        let ret = unsafe { take(|error| oxc_parse(source_text.as_ptr(), ptr::null(), error)) };
+        let ret = serde_json::from_str::<Value>(&ret.unwrap()).unwrap();
+        assert_eq!(ret["program"]["type"], "Program");
+        assert_eq!(ret["errors"].as_array().unwrap().len(), 1);
+
+        let filename = c("foo.txt");
+        let ret =
+            unsafe { take(|error| oxc_parse(source_text.as_ptr(), filename.as_ptr(), error)) };
+        assert_eq!(ret, Err("Invalid file extension: foo.txt".into()));
+
+        let ret = unsafe { take(|error| oxc_parse(ptr::null(), ptr::null(), error)) };
+        assert_eq!(ret, Err("`source_text` is NULL".into()));
+
+        // The error is optional
+        assert!(unsafe { oxc_parse(ptr::null(), ptr::null(), ptr::null_mut()) }.is_null());
+    }
+
+    #[test]
+    fn lint() {
+        let source_text = c("debugger;");
+        let ret = unsafe {
+            take(|error| oxc_lint(source_text.as_ptr(), ptr::null(), ptr::null(), true, error))
+        };
+        assert_eq!(
+            serde_json::from_str::<Value>(&ret.unwrap()).unwrap(),
+            json!([{
+                "rule": "eslint/no-debugger",
+                "severity": "warning",
+                "message": "`debugger` statement is not allowed",
+                "help": null,
+                "start": 0,
+                "end": 9,
+                "fix": { "kind": "safe", "start": 0, "end": 9, "content": "" },
+            }])
+        );
+
+        let config = c(r#"{ "rules": { "no-debugger": "off" } }"#);
+        let ret = unsafe {
+            take(|error| oxc_lint(source_text.as_ptr(), ptr::null(), config.as_ptr(), false, error))
+        };
+        assert_eq!(ret, Ok("[]".into()));
+
+        let config = c(r#"{ "rules": { "foo": "off" } }"#);
+        let ret = unsafe {
+            take(|error| oxc_lint(source_text.as_ptr(), ptr::null(), config.as_ptr(), false, error))
+        };
+        assert_eq!(ret, Err("Invalid config: unknown rule `foo`".into()));
+    }
+
+    #[test]
+    fn resolve() {
+        let directory = c(env!("CARGO_MANIFEST_DIR"));
+        let request = c("./src/lib.rs");
+        let ret = unsafe { take(|error| oxc_resolve(directory.as_ptr(), request.as_ptr(), error)) };
+        assert!(ret.unwrap().ends_with("lib.rs"));
+
+        let request = c("./foo");
+        let ret = unsafe { take(|error| oxc_resolve(directory.as_ptr(), request.as_ptr(), error)) };
+        assert!(ret.is_err());
+    }
+}
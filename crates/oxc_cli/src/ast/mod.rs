@@ -0,0 +1,235 @@
+use std::{fmt::Write, fs};
+
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::{Semantic, SemanticBuilder};
+use oxc_span::{SourceType, Span};
+use serde_json::{json, Value};
+
+use crate::{command::AstOptions, runner::Runner, CliRunResult};
+
+/// Print the AST, the symbol table and the scope tree of a file, for debugging lint rules.
+pub struct AstRunner {
+    options: AstOptions,
+}
+
+impl Runner for AstRunner {
+    type Options = AstOptions;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    fn run(self) -> CliRunResult {
+        let AstOptions { json, symbols, scopes, path } = self.options;
+        let Ok(source_text) = fs::read_to_string(&path) else {
+            return CliRunResult::PathNotFound { paths: vec![path] };
+        };
+        let source_type = SourceType::from_path(&path).unwrap_or_default();
+
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic_ret = SemanticBuilder::new(&source_text, source_type)
+            .with_trivias(ret.trivias)
+            .with_check_syntax_error(true)
+            .build(program);
+        let semantic = semantic_ret.semantic;
+
+        let output = if json {
+            let mut output = json!({ "program": program });
+            if symbols {
+                output["symbols"] = symbols_json(&semantic);
+            }
+            if scopes {
+                output["scopes"] = scopes_json(&semantic);
+            }
+            serde_json::to_string_pretty(&output).unwrap()
+        } else {
+            let mut output = format!("{program:#?}\n");
+            if symbols {
+                output.push_str(&symbols_text(&semantic));
+            }
+            if scopes {
+                output.push_str(&scopes_text(&semantic));
+            }
+            output
+        };
+        println!("{output}");
+
+        // Diagnostics are printed to stderr so that the JSON output stays valid
+        let mut number_of_errors = 0;
+        for error in ret.errors.into_iter().chain(semantic_ret.errors) {
+            eprintln!("{:?}", error.with_source_code(source_text.clone()));
+            number_of_errors += 1;
+        }
+        CliRunResult::AstResult { number_of_errors }
+    }
+}
+
+fn span_json(span: Span) -> Value {
+    json!({ "start": span.start, "end": span.end })
+}
+
+fn reference_access(is_read: bool, is_write: bool) -> &'static str {
+    match (is_read, is_write) {
+        (true, true) => "read write",
+        (false, true) => "write",
+        _ => "read",
+    }
+}
+
+/// `id: name flags span, scope id, references [span access]`, e.g.
+/// `0: a SymbolFlags(BlockScopedVariable) 4..5, scope 0, references [12..13 read]`
+fn symbols_text(semantic: &Semantic) -> String {
+    let symbols = semantic.symbols();
+    let mut output = String::from("\nSymbols:\n");
+    for symbol_id in symbols.iter() {
+        let span = symbols.get_span(symbol_id);
+        let references = symbols.resolved_references[symbol_id]
+            .iter()
+            .map(|reference_id| {
+                let reference = &symbols.references[*reference_id];
+                let span = reference.span();
+                let access = reference_access(reference.is_read(), reference.is_write());
+                format!("{}..{} {access}", span.start, span.end)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            output,
+            "  {}: {} {:?} {}..{}, scope {}, references [{references}]",
+            symbol_id.index(),
+            symbols.get_name(symbol_id),
+            symbols.get_flag(symbol_id),
+            span.start,
+            span.end,
+            symbols.get_scope_id(symbol_id).index(),
+        )
+        .unwrap();
+    }
+    output
+}
+
+fn symbols_json(semantic: &Semantic) -> Value {
+    let symbols = semantic.symbols();
+    symbols
+        .iter()
+        .map(|symbol_id| {
+            let references = symbols.resolved_references[symbol_id]
+                .iter()
+                .map(|reference_id| {
+                    let reference = &symbols.references[*reference_id];
+                    json!({
+                        "span": span_json(reference.span()),
+                        "access": reference_access(reference.is_read(), reference.is_write()),
+                    })
+                })
+                .collect::<Vec<_>>();
+            json!({
+                "id": symbol_id.index(),
+                "name": symbols.get_name(symbol_id).as_str(),
+                "flags": format!("{:?}", symbols.get_flag(symbol_id)),
+                "span": span_json(symbols.get_span(symbol_id)),
+                "scope": symbols.get_scope_id(symbol_id).index(),
+                "references": references,
+            })
+        })
+        .collect()
+}
+
+/// Scopes indented by depth, `id: flags, bindings [name]`, e.g.
+/// `0: ScopeFlags(StrictMode | Top), bindings [a]`
+fn scopes_text(semantic: &Semantic) -> String {
+    let scopes = semantic.scopes();
+    let mut output = String::from("\nScopes:\n");
+    for scope_id in scopes.descendants() {
+        let depth = scopes.ancestors(scope_id).count();
+        let bindings =
+            scopes.get_bindings(scope_id).keys().map(oxc_span::Atom::as_str).collect::<Vec<_>>();
+        writeln!(
+            output,
+            "{}{}: {:?}, bindings [{}]",
+            "  ".repeat(depth),
+            scope_id.index(),
+            scopes.get_flags(scope_id),
+            bindings.join(", ")
+        )
+        .unwrap();
+    }
+    output
+}
+
+fn scopes_json(semantic: &Semantic) -> Value {
+    let scopes = semantic.scopes();
+    scopes
+        .descendants()
+        .map(|scope_id| {
+            let bindings = scopes
+                .get_bindings(scope_id)
+                .iter()
+                .map(|(name, symbol_id)| (name.to_string(), json!(symbol_id.index())))
+                .collect::<serde_json::Map<_, _>>();
+            json!({
+                "id": scope_id.index(),
+                "parent": scopes.get_parent_id(scope_id).map(oxc_semantic::ScopeId::index),
+                "flags": format!("{:?}", scopes.get_flags(scope_id)),
+                "bindings": bindings,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+    use serde_json::json;
+
+    use super::{scopes_json, scopes_text, symbols_json, symbols_text};
+    use crate::{cli_command, AstRunner, CliCommand, CliRunResult, Runner};
+
+    #[test]
+    fn symbols_and_scopes() {
+        let source_text = "let a = 1;\nfunction f(b) { a = b; }\n";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type).build(program).semantic;
+
+        let symbols = symbols_text(&semantic);
+        assert!(symbols.contains("  0: a "), "{symbols}");
+        assert!(symbols.contains("4..5, scope 0, references [27..28 write]"), "{symbols}");
+        assert!(symbols.contains("  2: b "), "{symbols}");
+        assert!(symbols.contains("scope 1, references [31..32 read]"), "{symbols}");
+
+        let scopes = scopes_text(&semantic);
+        assert!(scopes.contains("\n  0: "), "{scopes}");
+        assert!(scopes.contains("bindings [a, f]\n    1: "), "{scopes}");
+        assert!(scopes.ends_with("bindings [b]\n"), "{scopes}");
+
+        assert_eq!(
+            symbols_json(&semantic)[0]["references"],
+            json!([{ "span": { "start": 27, "end": 28 }, "access": "write" }])
+        );
+        assert_eq!(scopes_json(&semantic)[1]["parent"], json!(0));
+        assert_eq!(scopes_json(&semantic)[1]["bindings"], json!({ "b": 2 }));
+    }
+
+    #[test]
+    fn run() {
+        let test = |args: &[&str]| {
+            let CliCommand::Ast(options) = cli_command().run_inner(args).unwrap() else {
+                unreachable!()
+            };
+            AstRunner::new(options).run()
+        };
+        let result = test(&["ast", "--json", "--symbols", "--scopes", "fixtures/debugger.js"]);
+        assert!(matches!(result, CliRunResult::AstResult { number_of_errors: 0 }));
+        let result = test(&["ast", "fixtures/missing.js"]);
+        assert!(matches!(result, CliRunResult::PathNotFound { .. }));
+    }
+}
@@ -1,7 +1,10 @@
 use bpaf::{doc::Style, Bpaf};
-use oxc_linter::AllowWarnDeny;
-use std::{ffi::OsString, path::PathBuf};
+use oxc_diagnostics::ColumnEncoding;
+use oxc_linter::{AllowWarnDeny, LINT_PARTIAL_LOADER_EXT};
+use oxc_span::VALID_EXTENSIONS;
+use std::{ffi::OsString, path::PathBuf, str::FromStr};
 
+#[allow(clippy::large_enum_variant)] // parsed once at startup
 #[derive(Debug, Clone, Bpaf)]
 #[bpaf(options)]
 pub enum CliCommand {
@@ -12,23 +15,10 @@ pub enum CliCommand {
     /// Use Ezno to type check source code (experimental and work in progress)
     #[bpaf(command)]
     Check(#[bpaf(external(check_options))] CheckOptions),
-}
-
-impl CliCommand {
-    pub fn handle_threads(&self) {
-        match self {
-            Self::Lint(options) => {
-                Self::set_rayon_threads(options.misc_options.threads);
-            }
-            Self::Check(_) => {}
-        }
-    }
 
-    fn set_rayon_threads(threads: Option<usize>) {
-        if let Some(threads) = threads {
-            rayon::ThreadPoolBuilder::new().num_threads(threads).build_global().unwrap();
-        }
-    }
+    /// Print the AST, symbols and scopes of a file, for debugging lint rules
+    #[bpaf(command)]
+    Ast(#[bpaf(external(ast_options))] AstOptions),
 }
 
 // To add a header or footer, see
@@ -41,12 +31,6 @@ pub struct LintCommand {
     pub lint_options: LintOptions,
 }
 
-impl LintCommand {
-    pub fn handle_threads(&self) {
-        CliCommand::set_rayon_threads(self.lint_options.misc_options.threads);
-    }
-}
-
 /// Miscellaneous
 #[derive(Debug, Clone, Bpaf)]
 pub struct MiscOptions {
@@ -58,29 +42,122 @@ pub struct MiscOptions {
     #[bpaf(switch, hide_usage)]
     pub rules: bool,
 
-    /// Number of threads to use. Set to 1 for using only 1 CPU core
+    /// Output format of `--rules`: `default`, `md` or `json`,
+    /// or the reporter of diagnostics: `default`, `json`, `github`, `compact` or `stylish`
+    #[bpaf(
+        short('f'),
+        long("format"),
+        argument("FORMAT"),
+        fallback(OutputFormat::Default),
+        hide_usage
+    )]
+    pub format: OutputFormat,
+
+    /// Print the configuration that would be used to lint the file and exit
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub print_config: Option<PathBuf>,
+
+    /// Number of threads to use. Set to 1 for using only 1 CPU core,
+    /// which lints the files one at a time in the same order on every run
     #[bpaf(argument("INT"), hide_usage)]
     pub threads: Option<usize>,
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum OutputFormat {
+    Default,
+    /// Markdown, only for `--rules`
+    Md,
+    Json,
+    /// The reporters of the same name, only for linting
+    Github,
+    Compact,
+    Stylish,
+}
+
+impl OutputFormat {
+    /// The reporter used with `--format` when linting, `None` for the default reporter and `md`
+    pub fn reporter(self) -> Option<ReporterFormat> {
+        match self {
+            Self::Default | Self::Md => None,
+            Self::Json => Some(ReporterFormat::Json),
+            Self::Github => Some(ReporterFormat::Github),
+            Self::Compact => Some(ReporterFormat::Compact),
+            Self::Stylish => Some(ReporterFormat::Stylish),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(Self::Default),
+            "md" => Ok(Self::Md),
+            "json" => Ok(Self::Json),
+            "github" => Ok(Self::Github),
+            "compact" => Ok(Self::Compact),
+            "stylish" => Ok(Self::Stylish),
+            _ => Err(format!(
+                "'{s}' is not a known format, expected `default`, `md`, `json`, `github`, `compact` or `stylish`"
+            )),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Bpaf)]
 pub struct LintOptions {
     #[bpaf(external(lint_filter), map(LintFilter::into_tuple), many)]
     pub filter: Vec<(AllowWarnDeny, String)>,
 
+    /// JSON config file with the levels of categories and rules, applied before `-A`, `-W` and `-D`,
+    /// e.g. `{ "categories": { "pedantic": "warn" }, "rules": { "eq-eq-eq": ["deny", "smart"] } }`.
+    /// The `.oxlintrc.json` of a workspace package replaces it for the files of the package.
+    #[bpaf(short('c'), long("config"), argument("PATH"), hide_usage)]
+    pub config: Option<PathBuf>,
+
     /// Use the experimental import plugin and detect ESM problems
     #[bpaf(switch, hide_usage)]
     pub import_plugin: bool,
 
+    /// Write the resolved module graph to PATH as JSON, or as DOT when PATH ends with `.dot`.
+    /// Implies `--import-plugin`
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub module_graph: Option<PathBuf>,
+
+    /// Use the TypeScript compiler for type-aware rules, requires `node` and `typescript`
+    #[bpaf(switch, hide_usage)]
+    pub type_aware: bool,
+
+    /// Only check syntax and semantic errors without running any rule, e.g. as a fast CI gate
+    #[bpaf(switch, hide_usage)]
+    pub syntax_only: bool,
+
+    /// Also lint files with this extension, e.g. `--extensions html` for inline scripts in HTML files
+    /// or `--extensions md` for code blocks in Markdown files.
+    /// Use `EXT=AS` to lint files as another extension, e.g. `--extensions es6=js`
+    #[bpaf(argument("EXT"), many, hide_usage)]
+    pub extensions: Vec<Extension>,
+
     #[bpaf(external)]
     pub fix_options: FixOptions,
 
     #[bpaf(external)]
     pub ignore_options: IgnoreOptions,
 
+    #[bpaf(external)]
+    pub git_options: GitOptions,
+
+    #[bpaf(external)]
+    pub module_options: ModuleOptions,
+
     #[bpaf(external)]
     pub warning_options: WarningOptions,
 
+    #[bpaf(external)]
+    pub output_options: OutputOptions,
+
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
@@ -89,10 +166,43 @@ pub struct LintOptions {
     pub paths: Vec<PathBuf>,
 }
 
+/// A file extension to lint, optionally linted as another supported extension, e.g. `es6=js`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extension {
+    pub name: String,
+    pub lint_as: Option<String>,
+}
+
+impl FromStr for Extension {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, lint_as) = match s.split_once('=') {
+            Some((name, lint_as)) => (name, Some(lint_as)),
+            None => (s, None),
+        };
+        let ext = lint_as.unwrap_or(name);
+        if name.is_empty()
+            || !(VALID_EXTENSIONS.contains(&ext) || LINT_PARTIAL_LOADER_EXT.contains(&ext))
+        {
+            return Err(format!(
+                "unsupported extension '{ext}', expected one of {}",
+                VALID_EXTENSIONS
+                    .iter()
+                    .chain(LINT_PARTIAL_LOADER_EXT)
+                    .copied()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        Ok(Self { name: name.to_string(), lint_as: lint_as.map(ToString::to_string) })
+    }
+}
+
 // This is formatted according to
 // <https://docs.rs/bpaf/latest/bpaf/params/struct.NamedArg.html#method.help>
-/// Allowing / Denying Multiple Lints
-/// For example `-D correctness -A no-debugger` or `-A all -D no-debugger`.
+/// Allowing / Warning / Denying Multiple Lints
+/// For example `-D correctness -W pedantic -A no-debugger` or `-A all -D no-debugger`.
 /// ㅤ
 ///  The default category is "-D correctness".
 ///  Use "--rules" for rule names.
@@ -102,6 +212,7 @@ pub struct LintOptions {
 ///  * correctness - code that is outright wrong or useless
 ///  * suspicious  - code that is most likely wrong or useless
 ///  * pedantic    - lints which are rather strict or have occasional false positives
+///  * perf        - code that can be written to run faster
 ///  * style       - code that should be written in a more idiomatic way
 ///  * nursery     - new lints that are still under development
 ///  * restriction - lints which prevent the use of language and library features
@@ -113,6 +224,11 @@ pub enum LintFilter {
         #[bpaf(short('A'), long("allow"), argument("NAME"))]
         String,
     ),
+    Warn(
+        /// Warn about the rule or category (emit a warning)
+        #[bpaf(short('W'), long("warn"), argument("NAME"))]
+        String,
+    ),
     Deny(
         /// Deny the rule or category (emit an error)
         #[bpaf(short('D'), long("deny"), argument("NAME"))]
@@ -124,6 +240,7 @@ impl LintFilter {
     fn into_tuple(self) -> (AllowWarnDeny, String) {
         match self {
             Self::Allow(s) => (AllowWarnDeny::Allow, s),
+            Self::Warn(s) => (AllowWarnDeny::Warn, s),
             Self::Deny(s) => (AllowWarnDeny::Deny, s),
         }
     }
@@ -131,10 +248,23 @@ impl LintFilter {
 
 /// Fix Problems
 #[derive(Debug, Clone, Bpaf)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct FixOptions {
     /// Fix as many issues as possible. Only unfixed issues are reported in the output
     #[bpaf(switch)]
     pub fix: bool,
+
+    /// Compute fixes without writing them to disk, print a unified diff of the changes instead
+    #[bpaf(switch)]
+    pub fix_dry_run: bool,
+
+    /// Also apply suggested fixes, which may change the behavior of the code. Implies `--fix`
+    #[bpaf(switch, hide_usage)]
+    pub fix_suggestions: bool,
+
+    /// Also apply unsafe fixes, which may break the code. Implies `--fix`
+    #[bpaf(switch, hide_usage)]
+    pub fix_dangerously: bool,
 }
 
 const NO_IGNORE_HELP: &[(&str, Style)] = &[
@@ -162,6 +292,43 @@ pub struct IgnoreOptions {
     ///
     #[bpaf(switch, hide_usage, help(NO_IGNORE_HELP))]
     pub no_ignore: bool,
+
+    /// Skip files larger than BYTES, e.g. bundles and generated code
+    #[bpaf(argument("BYTES"), hide_usage)]
+    pub max_file_size: Option<u64>,
+}
+
+/// Git
+#[derive(Debug, Clone, Bpaf)]
+pub struct GitOptions {
+    /// Only lint the files changed since REF, e.g. `origin/main`,
+    /// including uncommitted and untracked files
+    #[bpaf(argument("REF"), hide_usage)]
+    pub since: Option<String>,
+
+    /// Only lint the files staged for commit, e.g. in a pre-commit hook
+    #[bpaf(switch, hide_usage)]
+    pub staged: bool,
+}
+
+/// Modules loaded by the import plugin
+#[derive(Debug, Clone, Bpaf)]
+pub struct ModuleOptions {
+    /// How many levels of dependencies of the linted files the import plugin loads, default 1
+    #[bpaf(argument("N"), hide_usage)]
+    pub module_depth: Option<usize>,
+
+    /// Do not load the dependencies of the linted files, same as `--module-depth 0`
+    #[bpaf(switch, hide_usage)]
+    pub no_cross_module: bool,
+
+    /// Only load dependencies inside the current working directory and outside of `node_modules`
+    #[bpaf(switch, hide_usage)]
+    pub workspace_modules_only: bool,
+
+    /// Cache the exports of dependencies in PATH, so unchanged dependencies are not parsed again
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub module_cache: Option<PathBuf>,
 }
 
 /// Handle Warnings
@@ -175,6 +342,89 @@ pub struct WarningOptions {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     #[bpaf(argument("INT"), hide_usage)]
     pub max_warnings: Option<usize>,
+
+    /// Do not report the existing diagnostics recorded in this baseline file,
+    /// only new diagnostics fail the run
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub baseline: Option<PathBuf>,
+
+    /// Record all diagnostics in this baseline file instead of reporting them,
+    /// takes precedence over --baseline
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub generate_baseline: Option<PathBuf>,
+}
+
+/// Output
+#[derive(Debug, Clone, Bpaf)]
+pub struct OutputOptions {
+    /// Report diagnostics with REPORTER: `default`, `json`, `github`, `compact` or `stylish`.
+    /// Use `REPORTER=PATH` to write the report to a file, and repeat to use several reporters,
+    /// e.g. `--reporter default --reporter json=report.json`
+    #[bpaf(argument("REPORTER"), many, hide_usage)]
+    pub reporter: Vec<ReporterOption>,
+
+    /// Write the reports without a PATH to this file instead of stdout
+    #[bpaf(short('o'), long("output-file"), argument("PATH"), hide_usage)]
+    pub output_file: Option<PathBuf>,
+
+    /// Print the number of diagnostics per rule, the files with the most diagnostics
+    /// and the number of fixable diagnostics
+    #[bpaf(switch, hide_usage)]
+    pub statistics: bool,
+
+    /// Count the columns of reporters in `utf8` bytes, `utf16` code units like ESLint
+    /// and editors with the Language Server Protocol, or `grapheme` clusters.
+    /// Defaults to `utf16` for `compact` and `stylish`, and to `utf8` for the other reporters
+    #[bpaf(argument("ENCODING"), optional, hide_usage)]
+    pub column_encoding: Option<ColumnEncoding>,
+}
+
+/// A reporter of diagnostics, optionally writing to a file, e.g. `json=report.json`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReporterOption {
+    pub format: ReporterFormat,
+    pub path: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReporterFormat {
+    /// Human-readable output with the source code of each diagnostic
+    Default,
+    /// ESLint compatible JSON
+    Json,
+    /// GitHub Actions workflow commands, shown as annotations on pull requests
+    Github,
+    /// ESLint's `compact` formatter, one line per diagnostic
+    Compact,
+    /// ESLint's `stylish` formatter, a table of diagnostics per file
+    Stylish,
+}
+
+impl FromStr for ReporterOption {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (format, path) = match s.split_once('=') {
+            Some((format, path)) => (format, Some(path)),
+            None => (s, None),
+        };
+        let format = match format {
+            "default" => ReporterFormat::Default,
+            "json" => ReporterFormat::Json,
+            "github" => ReporterFormat::Github,
+            "compact" => ReporterFormat::Compact,
+            "stylish" => ReporterFormat::Stylish,
+            _ => {
+                return Err(format!(
+                    "'{format}' is not a known reporter, expected `default`, `json`, `github`, `compact` or `stylish`"
+                ))
+            }
+        };
+        if path.is_some_and(str::is_empty) {
+            return Err(format!("missing path of the `{s}` reporter"));
+        }
+        Ok(Self { format, path: path.map(PathBuf::from) })
+    }
 }
 
 #[derive(Debug, Clone, Bpaf)]
@@ -192,6 +442,25 @@ pub struct CheckOptions {
     pub path: PathBuf,
 }
 
+#[derive(Debug, Clone, Bpaf)]
+pub struct AstOptions {
+    /// Print JSON instead of a pretty printed tree
+    #[bpaf(switch)]
+    pub json: bool,
+
+    /// Also print the symbol table, with the resolved references of each symbol
+    #[bpaf(switch)]
+    pub symbols: bool,
+
+    /// Also print the scope tree, with the bindings of each scope
+    #[bpaf(switch)]
+    pub scopes: bool,
+
+    /// File to inspect
+    #[bpaf(positional("PATH"))]
+    pub path: PathBuf,
+}
+
 // windows binary has an`.exe` extension, which invalidates the snapshots
 #[cfg(all(test, not(target_os = "windows")))]
 mod snapshot {
@@ -218,7 +487,8 @@ mod snapshot {
 
 #[cfg(test)]
 mod misc_options {
-    use super::{lint_command, MiscOptions};
+    use super::{lint_command, MiscOptions, OutputFormat};
+    use std::path::PathBuf;
 
     fn get_misc_options(arg: &str) -> MiscOptions {
         let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -230,6 +500,8 @@ mod misc_options {
         let options = get_misc_options(".");
         assert!(!options.timing);
         assert!(!options.rules);
+        assert_eq!(options.format, OutputFormat::Default);
+        assert!(options.print_config.is_none());
         assert!(options.threads.is_none());
     }
 
@@ -250,10 +522,138 @@ mod misc_options {
         let options = get_misc_options("--rules");
         assert!(options.rules);
     }
+
+    #[test]
+    fn print_config() {
+        let options = get_misc_options("--print-config foo.js");
+        assert_eq!(options.print_config, Some(PathBuf::from("foo.js")));
+    }
+
+    #[test]
+    fn list_rules_format() {
+        let options = get_misc_options("--rules --format md");
+        assert!(options.rules);
+        assert_eq!(options.format, OutputFormat::Md);
+
+        let options = get_misc_options("--rules --format json");
+        assert_eq!(options.format, OutputFormat::Json);
+
+        let options = get_misc_options("-f github .");
+        assert_eq!(options.format, OutputFormat::Github);
+
+        let args = ["--rules", "--format", "yaml"].map(String::from);
+        assert!(lint_command().run_inner(args.as_slice()).is_err());
+    }
+}
+
+#[cfg(test)]
+mod output_options {
+    use super::{lint_command, ColumnEncoding, OutputOptions, ReporterFormat, ReporterOption};
+    use std::path::PathBuf;
+
+    fn get_output_options(arg: &str) -> OutputOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap().lint_options.output_options
+    }
+
+    #[test]
+    fn default() {
+        let options = get_output_options(".");
+        assert!(options.reporter.is_empty());
+        assert!(options.output_file.is_none());
+        assert!(!options.statistics);
+    }
+
+    #[test]
+    fn statistics() {
+        let options = get_output_options("--statistics .");
+        assert!(options.statistics);
+    }
+
+    #[test]
+    fn reporters() {
+        let options = get_output_options("--reporter default --reporter json=report.json .");
+        assert_eq!(
+            options.reporter,
+            vec![
+                ReporterOption { format: ReporterFormat::Default, path: None },
+                ReporterOption {
+                    format: ReporterFormat::Json,
+                    path: Some(PathBuf::from("report.json"))
+                },
+            ]
+        );
+
+        let options =
+            get_output_options("--reporter github --reporter compact --reporter stylish .");
+        assert_eq!(
+            options.reporter,
+            vec![
+                ReporterOption { format: ReporterFormat::Github, path: None },
+                ReporterOption { format: ReporterFormat::Compact, path: None },
+                ReporterOption { format: ReporterFormat::Stylish, path: None },
+            ]
+        );
+
+        for reporter in ["yaml", "json=", "=report.json"] {
+            let args = ["--reporter", reporter, "."].map(String::from);
+            assert!(lint_command().run_inner(args.as_slice()).is_err());
+        }
+    }
+
+    #[test]
+    fn output_file() {
+        let options = get_output_options("--reporter json --output-file report.json .");
+        assert_eq!(options.output_file, Some(PathBuf::from("report.json")));
+
+        let options = get_output_options("-o report.txt .");
+        assert_eq!(options.output_file, Some(PathBuf::from("report.txt")));
+    }
+
+    #[test]
+    fn column_encoding() {
+        let options = get_output_options(".");
+        assert_eq!(options.column_encoding, None);
+        let options = get_output_options("--reporter json --column-encoding utf16 .");
+        assert_eq!(options.column_encoding, Some(ColumnEncoding::Utf16));
+        let args = ["--column-encoding", "utf32", "."].map(String::from);
+        assert!(lint_command().run_inner(args.as_slice()).is_err());
+    }
+}
+
+#[cfg(test)]
+mod git_options {
+    use super::{lint_command, GitOptions};
+
+    fn get_git_options(arg: &str) -> GitOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap().lint_options.git_options
+    }
+
+    #[test]
+    fn default() {
+        let options = get_git_options(".");
+        assert_eq!(options.since, None);
+        assert!(!options.staged);
+    }
+
+    #[test]
+    fn since() {
+        let options = get_git_options("--since origin/main .");
+        assert_eq!(options.since, Some("origin/main".to_string()));
+    }
+
+    #[test]
+    fn staged() {
+        let options = get_git_options("--staged");
+        assert!(options.staged);
+    }
 }
 
 #[cfg(test)]
 mod warning_options {
+    use std::path::PathBuf;
+
     use super::{lint_command, WarningOptions};
 
     fn get_warning_options(arg: &str) -> WarningOptions {
@@ -266,6 +666,8 @@ mod warning_options {
         let options = get_warning_options(".");
         assert!(!options.quiet);
         assert_eq!(options.max_warnings, None);
+        assert_eq!(options.baseline, None);
+        assert_eq!(options.generate_baseline, None);
     }
 
     #[test]
@@ -279,11 +681,19 @@ mod warning_options {
         let options = get_warning_options("--max-warnings 10 .");
         assert_eq!(options.max_warnings, Some(10));
     }
+
+    #[test]
+    fn baseline() {
+        let options = get_warning_options("--baseline baseline.json .");
+        assert_eq!(options.baseline, Some(PathBuf::from("baseline.json")));
+        let options = get_warning_options("--generate-baseline baseline.json .");
+        assert_eq!(options.generate_baseline, Some(PathBuf::from("baseline.json")));
+    }
 }
 
 #[cfg(test)]
 mod lint_options {
-    use super::{lint_command, LintOptions};
+    use super::{lint_command, Extension, LintOptions};
     use oxc_linter::AllowWarnDeny;
     use std::path::PathBuf;
 
@@ -297,6 +707,7 @@ mod lint_options {
         let options = get_lint_options(".");
         assert_eq!(options.paths, vec![PathBuf::from(".")]);
         assert!(!options.fix_options.fix);
+        assert!(!options.fix_options.fix_dry_run);
     }
 
     #[test]
@@ -315,14 +726,79 @@ mod lint_options {
     }
 
     #[test]
-    fn filter() {
+    fn fix_dry_run() {
+        let options = get_lint_options("--fix-dry-run test.js");
+        assert!(!options.fix_options.fix);
+        assert!(options.fix_options.fix_dry_run);
+    }
+
+    #[test]
+    fn extensions() {
+        let options = get_lint_options("--extensions html --extensions es6=js .");
+        assert_eq!(
+            options.extensions,
+            vec![
+                Extension { name: "html".into(), lint_as: None },
+                Extension { name: "es6".into(), lint_as: Some("js".into()) }
+            ]
+        );
+
+        let options = get_lint_options(".");
+        assert!(options.extensions.is_empty());
+
+        for ext in ["rs", "es6=rs", "=js"] {
+            let args = ["--extensions", ext, "."].map(String::from);
+            assert!(lint_command().run_inner(args.as_slice()).is_err());
+        }
+    }
+
+    #[test]
+    fn module_graph() {
+        let options = get_lint_options("--module-graph graph.json .");
+        assert_eq!(options.module_graph, Some(PathBuf::from("graph.json")));
+        assert!(!options.import_plugin);
+    }
+
+    #[test]
+    fn module_depth() {
         let options =
-            get_lint_options("-D suspicious --deny pedantic -A no-debugger --allow no-var src");
+            get_lint_options("--module-depth 3 --workspace-modules-only .").module_options;
+        assert_eq!(options.module_depth, Some(3));
+        assert!(options.workspace_modules_only);
+        assert!(!options.no_cross_module);
+        let options = get_lint_options("--no-cross-module .").module_options;
+        assert_eq!(options.module_depth, None);
+        assert!(options.no_cross_module);
+        assert_eq!(options.module_cache, None);
+        let options = get_lint_options("--module-cache .oxc/modules.json .").module_options;
+        assert_eq!(options.module_cache, Some(PathBuf::from(".oxc/modules.json")));
+    }
+
+    #[test]
+    fn fix_kinds() {
+        let options = get_lint_options("--fix-suggestions --fix-dangerously test.js");
+        assert!(options.fix_options.fix_suggestions);
+        assert!(options.fix_options.fix_dangerously);
+    }
+
+    #[test]
+    fn config() {
+        let options = get_lint_options("-c .oxlintrc.json src");
+        assert_eq!(options.config, Some(PathBuf::from(".oxlintrc.json")));
+        assert_eq!(get_lint_options("src").config, None);
+    }
+
+    #[test]
+    fn filter() {
+        let options = get_lint_options(
+            "-D suspicious --deny pedantic -W perf -A no-debugger --allow no-var src",
+        );
         assert_eq!(
             options.filter,
             [
                 (AllowWarnDeny::Deny, "suspicious".into()),
                 (AllowWarnDeny::Deny, "pedantic".into()),
+                (AllowWarnDeny::Warn, "perf".into()),
                 (AllowWarnDeny::Allow, "no-debugger".into()),
                 (AllowWarnDeny::Allow, "no-var".into())
             ]
@@ -371,4 +847,10 @@ mod ignore_options {
         let options = get_ignore_options("--ignore-pattern ./test --ignore-pattern bar.js foo.js");
         assert_eq!(options.ignore_pattern, vec![String::from("./test"), String::from("bar.js")]);
     }
+
+    #[test]
+    fn max_file_size() {
+        let options = get_ignore_options("--max-file-size 1048576 foo.js");
+        assert_eq!(options.max_file_size, Some(1_048_576));
+    }
 }
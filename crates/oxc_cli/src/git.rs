@@ -1,66 +1,145 @@
-use std::path::Path;
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+    process::Command,
+};
 
-use git2::Repository;
 use miette::Diagnostic;
-use oxc_diagnostics::{thiserror::Error, Error};
+use oxc_diagnostics::{
+    thiserror::{self, Error},
+    Error,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("No repository found")]
-#[diagnostic(severity(warning), help("Ensure target path(s) belong to a Git repository"))]
+#[diagnostic(help("Run oxlint inside a Git repository to use `--since` or `--staged`"))]
 struct NoRepositoryFound;
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("Multiple repositories found")]
-#[diagnostic(severity(warning), help("Ensure all paths belong to a single repository"))]
-struct MultipleRepositoriesFound;
+#[error("`git {0}` failed: {1}")]
+struct GitCommandFailed(String, String);
 
-#[derive(Debug, Error, Diagnostic)]
-#[error("Uncommitted changes")]
-#[diagnostic(severity(warning), help("Commit any changes before linting"))]
-struct UncommittedChanges;
-
-pub struct Git<'a> {
-    // paths: &'a Vec<Box<Path>>,
-    repos: Vec<Repository>,
+/// The Git repository containing the current directory,
+/// used to lint only the files changed relative to a ref or staged for commit.
+///
+/// Runs the `git` executable instead of reading the repository,
+/// so the result is the same as `git diff` in hooks and CI.
+pub struct Git {
+    /// Canonical path of the working tree
+    root: PathBuf,
 }
 
-impl<'a> Git<'a> {
-    pub fn new() -> Self {
-        // let repos: Vec<Repository> =
-        // paths.iter().filter_map(|path| Repository::discover(path).ok()).collect();
-        Self { repos }
+impl Git {
+    /// # Errors
+    ///
+    /// * When `cwd` is not inside a Git repository, or `git` is not installed
+    pub fn discover(cwd: &Path) -> Result<Self, Error> {
+        let root = Self::git(cwd, &["rev-parse", "--show-toplevel"])
+            .map_err(|_| Error::from(NoRepositoryFound))?;
+        let root = PathBuf::from(root.trim_end());
+        Ok(Self { root: root.canonicalize().unwrap_or(root) })
     }
 
-    pub fn verify(&'a self) -> Result<&'a Repository, Error> {
-        if self.repos.is_empty() {
-            return Err(NoRepositoryFound.into());
-        }
-        match self.is_same_repo() {
-            Ok(repo) => {
-                for path in self.paths {
-                    if Self::is_uncommitted(repo, path) {
-                        return Err(UncommittedChanges.into());
-                    }
-                }
-                Ok(repo)
-            }
-            err => err,
-        }
+    /// Existing files changed since the merge base of `since` and `HEAD`,
+    /// including uncommitted and untracked files, e.g. `origin/main` for the changes of a branch.
+    ///
+    /// # Errors
+    ///
+    /// * When `since` is not a valid ref
+    pub fn changed_since(&self, since: &str) -> Result<HashSet<PathBuf>, Error> {
+        let merge_base = Self::git(&self.root, &["merge-base", since, "HEAD"])?;
+        let mut files =
+            self.files(&["diff", "--name-only", "-z", "--diff-filter=d", merge_base.trim_end()])?;
+        files.extend(self.files(&["ls-files", "-z", "--others", "--exclude-standard"])?);
+        Ok(files)
+    }
+
+    /// Existing files staged for commit, e.g. in a pre-commit hook.
+    ///
+    /// # Errors
+    ///
+    /// * When `git` fails
+    pub fn staged(&self) -> Result<HashSet<PathBuf>, Error> {
+        self.files(&["diff", "--cached", "--name-only", "-z", "--diff-filter=d"])
+    }
+
+    /// Absolute paths of the NUL separated paths printed by `git args`,
+    /// which are relative to the root of the working tree.
+    fn files(&self, args: &[&str]) -> Result<HashSet<PathBuf>, Error> {
+        let output = Self::git(&self.root, args)?;
+        Ok(output
+            .split('\0')
+            .filter(|path| !path.is_empty())
+            .map(|path| self.root.join(path))
+            .collect())
     }
 
-    /// Given a list of repositories, verify they're all the same repository.
-    fn is_same_repo(&self) -> Result<&Repository, Error> {
-        assert!(!self.repos.is_empty());
-        let first_repo = self.repos.first().unwrap();
-        for repo in &self.repos[1..] {
-            if repo.path() != first_repo.path() {
-                return Err(MultipleRepositoriesFound.into());
-            }
+    fn git(dir: &Path, args: &[&str]) -> Result<String, Error> {
+        let command = args.join(" ");
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .output()
+            .map_err(|error| GitCommandFailed(command.clone(), error.to_string()))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            return Err(GitCommandFailed(command, stderr).into());
         }
-        Ok(first_repo)
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path, process::Command};
+
+    use super::Git;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", "user.name=oxc", "-c", "user.email=oxc@example.com"])
+            .args(args)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {args:?}");
     }
 
-    fn is_uncommitted(repo: &Repository, path: &Path) -> bool {
-        repo.status_file(path).map_or(true, |status| !matches!(status, git2::Status::CURRENT))
+    #[test]
+    fn changed_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs::create_dir(temp_dir.path().join("src")).unwrap();
+        let dir = temp_dir.path().canonicalize().unwrap();
+
+        git(&dir, &["init", "-q"]);
+        for file in ["committed.js", "modified.js", "deleted.js"] {
+            fs::write(dir.join("src").join(file), "debugger;\n").unwrap();
+        }
+        git(&dir, &["add", "."]);
+        git(&dir, &["commit", "-q", "-m", "initial"]);
+        git(&dir, &["branch", "base"]);
+
+        fs::write(dir.join("src/modified.js"), "debugger;\ndebugger;\n").unwrap();
+        fs::write(dir.join("src/staged.js"), "debugger;\n").unwrap();
+        fs::write(dir.join("src/untracked.js"), "debugger;\n").unwrap();
+        fs::write(dir.join(".gitignore"), "ignored.js\n").unwrap();
+        fs::write(dir.join("src/ignored.js"), "debugger;\n").unwrap();
+        fs::remove_file(dir.join("src/deleted.js")).unwrap();
+        git(&dir, &["add", "src/staged.js", "src/deleted.js"]);
+
+        let repo = Git::discover(&dir.join("src")).unwrap();
+        assert_eq!(repo.root, dir);
+
+        let staged = repo.staged().unwrap();
+        assert_eq!(staged, [dir.join("src/staged.js")].into());
+
+        let changed = repo.changed_since("base").unwrap();
+        let expected = ["src/modified.js", "src/staged.js", "src/untracked.js", ".gitignore"];
+        assert_eq!(changed, expected.iter().map(|file| dir.join(file)).collect());
+
+        assert!(repo.changed_since("missing").is_err());
     }
 }
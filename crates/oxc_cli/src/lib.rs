@@ -1,14 +1,33 @@
+mod ast;
 mod command;
+mod git;
 mod lint;
 mod result;
 mod runner;
 mod type_check;
 mod walk;
+mod workspace;
 
 pub use crate::{
+    ast::AstRunner,
     command::*,
     lint::LintRunner,
     result::{CliRunResult, LintResult},
     runner::Runner,
     type_check::TypeCheckRunner,
 };
+
+/// Print `tracing` events and the duration of spans to stderr, filtered by the `OXC_LOG` environment variable,
+/// e.g. `OXC_LOG=debug` or `OXC_LOG=oxc_linter=trace,oxc_resolver=debug`.
+pub fn init_tracing_subscriber() {
+    use tracing_subscriber::{filter::Targets, fmt, fmt::format::FmtSpan, prelude::*, registry};
+
+    let Ok(env_var) = std::env::var("OXC_LOG") else { return };
+    match env_var.parse::<Targets>() {
+        Ok(targets) => registry()
+            .with(targets)
+            .with(fmt::layer().with_writer(std::io::stderr).with_span_events(FmtSpan::CLOSE))
+            .init(),
+        Err(error) => eprintln!("Invalid OXC_LOG {env_var:?}: {error}"),
+    }
+}
@@ -11,7 +11,7 @@ static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 use oxc_cli::{CliRunResult, LintRunner, Runner};
 
 fn main() -> CliRunResult {
+    oxc_cli::init_tracing_subscriber();
     let command = oxc_cli::lint_command().fallback_to_usage().run();
-    command.handle_threads();
     LintRunner::new(command.lint_options).run()
 }
@@ -1,9 +1,30 @@
-use std::io::BufWriter;
+use std::{
+    collections::HashSet,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{Mutex, Once},
+};
 
-use oxc_diagnostics::DiagnosticService;
-use oxc_linter::{LintOptions, LintService, Linter};
+use oxc_diagnostics::{
+    Baseline, ColumnEncoding, CompactReporter, DiagnosticReporter, DiagnosticService,
+    GithubReporter, GraphicalReporter, JsonReporter, Statistics, StylishReporter,
+};
+use oxc_linter::{CancellationToken, LintConfig, LintOptions, LintService, Linter};
 
-use crate::{command::LintOptions as CliLintOptions, walk::Walk, CliRunResult, LintResult, Runner};
+use crate::{
+    command::{
+        GitOptions, LintOptions as CliLintOptions, OutputFormat, OutputOptions, ReporterFormat,
+        ReporterOption, WarningOptions,
+    },
+    git::Git,
+    walk::Walk,
+    workspace::find_workspaces,
+    CliRunResult, LintResult, Runner,
+};
+
+/// Config file in a workspace package, for the files of the package
+const WORKSPACE_CONFIG_FILE: &str = ".oxlintrc.json";
 
 pub struct LintRunner {
     options: CliLintOptions,
@@ -16,67 +37,417 @@ impl Runner for LintRunner {
         Self { options }
     }
 
+    #[allow(clippy::too_many_lines)]
     fn run(self) -> CliRunResult {
         if self.options.misc_options.rules {
-            let mut stdout = BufWriter::new(std::io::stdout());
-            Linter::print_rules(&mut stdout);
-            return CliRunResult::None;
+            return Self::print_rules(self.options.misc_options.format);
         }
 
         let CliLintOptions {
             paths,
             filter,
+            config,
             import_plugin,
+            module_graph,
+            type_aware,
+            syntax_only,
+            extensions,
             warning_options,
             ignore_options,
+            git_options,
+            module_options,
             fix_options,
+            mut output_options,
             misc_options,
         } = self.options;
 
+        // `--format` adds a reporter, as in ESLint
+        if misc_options.format == OutputFormat::Md {
+            return CliRunResult::InvalidOptions {
+                message: "`--format md` is only supported with `--rules`".to_string(),
+            };
+        }
+        output_options.reporter.extend(
+            misc_options.format.reporter().map(|format| ReporterOption { format, path: None }),
+        );
+
         let now = std::time::Instant::now();
+        let cwd = std::env::current_dir().unwrap().into_boxed_path();
+        let import_plugin = import_plugin || module_graph.is_some();
 
-        let paths = Walk::new(&paths, &ignore_options).paths();
-        let number_of_files = paths.len();
+        let config = match config.map(|path| LintConfig::from_file(&path)).transpose() {
+            Ok(config) => config,
+            Err(error) => return CliRunResult::ConfigError { error },
+        };
+        let workspaces = find_workspaces(&cwd);
+        let workspace_configs = match Self::workspace_configs(&workspaces) {
+            Ok(workspace_configs) => workspace_configs,
+            Err(error) => return CliRunResult::ConfigError { error },
+        };
 
-        let cwd = std::env::current_dir().unwrap().into_boxed_path();
+        let module_cache = module_options.module_cache.clone();
         let lint_options = LintOptions::default()
             .with_filter(filter)
-            .with_fix(fix_options.fix)
+            .with_config(config)
+            .with_fix(fix_options.fix || fix_options.fix_suggestions || fix_options.fix_dangerously)
+            .with_fix_dry_run(fix_options.fix_dry_run)
+            .with_fix_suggestions(fix_options.fix_suggestions)
+            .with_fix_dangerously(fix_options.fix_dangerously)
             .with_timing(misc_options.timing)
-            .with_import_plugin(import_plugin);
-        let lint_service = LintService::new(cwd, &paths, lint_options);
+            .with_import_plugin(import_plugin)
+            .with_max_module_depth(if module_options.no_cross_module {
+                Some(0)
+            } else {
+                module_options.module_depth
+            })
+            .with_workspace_modules_only(module_options.workspace_modules_only)
+            .with_module_graph(module_graph.is_some())
+            .with_module_cache(module_options.module_cache)
+            .with_workspaces(workspaces)
+            .with_workspace_configs(workspace_configs)
+            .with_type_aware(type_aware)
+            .with_syntax_only(syntax_only)
+            .with_max_file_size(ignore_options.max_file_size)
+            .with_extension_aliases(
+                extensions
+                    .iter()
+                    .filter_map(|ext| Some((ext.name.clone(), ext.lint_as.clone()?)))
+                    .collect(),
+            );
+
+        if let Some(path) = misc_options.print_config {
+            if !path.exists() {
+                return CliRunResult::PathNotFound { paths: vec![path] };
+            }
+            // The config file of the workspace package containing `path` replaces `--config`
+            let absolute_path = cwd.join(&path);
+            let workspace_config = lint_options
+                .workspace_configs
+                .iter()
+                .filter(|(workspace, _)| absolute_path.starts_with(workspace))
+                .max_by_key(|(workspace, _)| workspace.components().count())
+                .map(|(_, config)| config.clone());
+            let lint_options = match workspace_config {
+                Some(config) => lint_options.with_config(Some(config)),
+                None => lint_options,
+            };
+            let mut stdout = BufWriter::new(std::io::stdout());
+            Linter::from_options(lint_options).print_config(&mut stdout, &path);
+            return CliRunResult::None;
+        }
+
+        let statistics = output_options.statistics;
+        let fix_diffs_to_stderr = Self::has_stdout_report(&output_options);
+        let reporters = match Self::reporters(output_options) {
+            Ok(reporters) => reporters,
+            Err((path, error)) => return CliRunResult::OutputFileError { path, error },
+        };
+
+        let baseline = match Self::baseline(&warning_options) {
+            Ok(baseline) => baseline,
+            Err((path, error)) => return CliRunResult::BaselineError { path, error },
+        };
+
+        let changed_files = match Self::changed_files(&cwd, &git_options) {
+            Ok(changed_files) => changed_files,
+            Err(error) => return CliRunResult::GitError { error },
+        };
+
+        let lint_service = match LintService::new(cwd, lint_options) {
+            Ok(lint_service) => lint_service,
+            Err(error) => return CliRunResult::TypeInfoError { error },
+        };
+        Self::cancel_on_ctrl_c(lint_service.cancellation_token().clone());
+
+        let mut diagnostic_service = Self::diagnostic_service(
+            lint_service.linter(),
+            &warning_options,
+            reporters,
+            statistics,
+            baseline,
+        );
 
-        let diagnostic_service = DiagnosticService::default()
-            .with_quiet(warning_options.quiet)
-            .with_max_warnings(warning_options.max_warnings);
+        // A dedicated pool with `--threads`, the global pool otherwise.
+        let thread_pool = misc_options
+            .threads
+            .map(|threads| rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap());
+        let number_of_threads = thread_pool
+            .as_ref()
+            .map_or_else(rayon::current_num_threads, rayon::ThreadPool::current_num_threads);
+
+        // Files are linted while they are being walked
+        let extension_names = extensions.iter().map(|ext| ext.name.clone()).collect();
+        let paths = Walk::new(&paths, &ignore_options)
+            .with_extensions(extension_names)
+            .with_only(changed_files)
+            .with_threads(misc_options.threads)
+            .paths();
 
         // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
-        rayon::spawn({
+        let lint = {
             let tx_error = diagnostic_service.sender().clone();
             let lint_service = lint_service.clone();
             move || {
-                lint_service.run(&tx_error);
+                lint_service.run(paths, &tx_error);
             }
-        });
+        };
+        match &thread_pool {
+            Some(thread_pool) => thread_pool.spawn(lint),
+            None => rayon::spawn(lint),
+        }
         diagnostic_service.run();
+        let number_of_files = lint_service.number_of_files();
+        tracing::debug!(number_of_files, elapsed = ?now.elapsed(), "linted files");
+
+        let fix_diffs = lint_service.take_fix_diffs();
+        let printed = if fix_diffs_to_stderr {
+            Self::print_fix_diffs(io::stderr().lock(), &fix_diffs)
+        } else {
+            Self::print_fix_diffs(BufWriter::new(io::stdout().lock()), &fix_diffs)
+        };
+        if let Err(error) = printed {
+            eprintln!("Failed to print the fixes: {error}");
+        }
+
+        if let Err(error) = lint_service.persist_module_cache() {
+            let path = module_cache.unwrap_or_default();
+            return CliRunResult::ModuleCacheError { path, error };
+        }
+
+        if lint_service.cancellation_token().is_cancelled() {
+            return CliRunResult::Cancelled;
+        }
 
         lint_service.linter().print_execution_times_if_enable();
 
+        if let Some(statistics) = diagnostic_service.statistics() {
+            print!("{statistics}");
+        }
+
+        if let Some(path) = module_graph {
+            if let Err(error) = Self::write_module_graph(&lint_service, &path) {
+                return CliRunResult::ModuleGraphError { path, error };
+            }
+        }
+
+        let baseline = diagnostic_service.baseline();
+        if let (Some(path), Some(baseline)) = (warning_options.generate_baseline, baseline) {
+            if let Err(error) = baseline.write(&path) {
+                return CliRunResult::BaselineError { path, error };
+            }
+        }
+
         CliRunResult::LintResult(LintResult {
             duration: now.elapsed(),
             number_of_rules: lint_service.linter().number_of_rules(),
             number_of_files,
+            number_of_threads,
             number_of_warnings: diagnostic_service.warnings_count(),
             number_of_errors: diagnostic_service.errors_count(),
+            number_of_suppressed: baseline.map_or(0, Baseline::suppressed),
             max_warnings_exceeded: diagnostic_service.max_warnings_exceeded(),
         })
     }
 }
 
+impl LintRunner {
+    /// Cancel `token` on Ctrl-C, a second Ctrl-C exits immediately.
+    ///
+    /// The handler is installed once per process and cancels the token of the latest run.
+    fn cancel_on_ctrl_c(token: CancellationToken) {
+        static TOKEN: Mutex<Option<CancellationToken>> = Mutex::new(None);
+        static HANDLER: Once = Once::new();
+        *TOKEN.lock().unwrap() = Some(token);
+        HANDLER.call_once(|| {
+            let handler = ctrlc::set_handler(|| {
+                let Some(token) = TOKEN.lock().unwrap().clone() else { return };
+                if token.is_cancelled() {
+                    std::process::exit(130);
+                }
+                token.cancel();
+            });
+            // Ctrl-C terminates the process as usual when the handler cannot be installed.
+            if let Err(error) = handler {
+                eprintln!("Failed to handle Ctrl-C: {error}");
+            }
+        });
+    }
+
+    /// The config files of the workspace packages, which configure the files in the package
+    /// instead of `--config`.
+    fn workspace_configs(
+        workspaces: &[PathBuf],
+    ) -> Result<Vec<(PathBuf, LintConfig)>, oxc_diagnostics::Error> {
+        workspaces
+            .iter()
+            .filter_map(|workspace| {
+                let path = workspace.join(WORKSPACE_CONFIG_FILE);
+                path.is_file()
+                    .then(|| LintConfig::from_file(&path).map(|config| (workspace.clone(), config)))
+            })
+            .collect()
+    }
+
+    /// Files changed since `--since` and staged with `--staged`, `None` to lint all files.
+    fn changed_files(
+        cwd: &Path,
+        options: &GitOptions,
+    ) -> Result<Option<HashSet<PathBuf>>, oxc_diagnostics::Error> {
+        if options.since.is_none() && !options.staged {
+            return Ok(None);
+        }
+        let git = Git::discover(cwd)?;
+        let mut files = HashSet::new();
+        if let Some(since) = &options.since {
+            files.extend(git.changed_since(since)?);
+        }
+        if options.staged {
+            files.extend(git.staged()?);
+        }
+        Ok(Some(files))
+    }
+
+    fn diagnostic_service(
+        linter: &Linter,
+        warning_options: &WarningOptions,
+        reporters: Vec<Box<dyn DiagnosticReporter>>,
+        statistics: bool,
+        baseline: Option<Baseline>,
+    ) -> DiagnosticService {
+        let statistics = statistics.then(|| {
+            Statistics::new(linter.fixable_rules().map(|rule| rule.to_string()).collect())
+        });
+        reporters.into_iter().fold(
+            DiagnosticService::default()
+                .with_quiet(warning_options.quiet)
+                .with_max_warnings(warning_options.max_warnings)
+                .with_statistics(statistics)
+                .with_baseline(baseline),
+            DiagnosticService::with_reporter,
+        )
+    }
+
+    fn print_rules(format: OutputFormat) -> CliRunResult {
+        let mut stdout = BufWriter::new(std::io::stdout());
+        match format {
+            OutputFormat::Default => Linter::print_rules(&mut stdout),
+            OutputFormat::Md => Linter::print_rules_markdown(&mut stdout),
+            OutputFormat::Json => Linter::print_rules_json(&mut stdout),
+            OutputFormat::Github | OutputFormat::Compact | OutputFormat::Stylish => {
+                return CliRunResult::InvalidOptions {
+                    message: "Reporters are not supported with `--rules`, expected `--format default`, `md` or `json`".to_string(),
+                };
+            }
+        }
+        CliRunResult::None
+    }
+
+    /// An empty baseline to generate, the baseline to read, or `None` without a baseline.
+    fn baseline(options: &WarningOptions) -> Result<Option<Baseline>, (PathBuf, io::Error)> {
+        match (&options.generate_baseline, &options.baseline) {
+            (Some(_), _) => Ok(Some(Baseline::generate())),
+            (None, Some(path)) => {
+                Baseline::read(path).map(Some).map_err(|error| (path.clone(), error))
+            }
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// Write the module graph as DOT when `path` ends with `.dot`, as JSON otherwise.
+    fn write_module_graph(lint_service: &LintService, path: &Path) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        if path.extension().is_some_and(|ext| ext == "dot") {
+            lint_service.print_module_graph_dot(&mut writer)?;
+        } else {
+            lint_service.print_module_graph_json(&mut writer)?;
+        }
+        writer.flush()
+    }
+
+    /// Whether a reporter other than the default one writes to stdout, e.g. `--reporter json`,
+    /// so the diffs of `--fix-dry-run` are printed to stderr instead of corrupting the report.
+    fn has_stdout_report(options: &OutputOptions) -> bool {
+        options.output_file.is_none()
+            && options.reporter.iter().any(|reporter| {
+                reporter.path.is_none() && reporter.format != ReporterFormat::Default
+            })
+    }
+
+    /// Print the diffs of `--fix-dry-run`, stopping quietly when the reader has gone away,
+    /// e.g. `oxlint --fix-dry-run . | head`.
+    fn print_fix_diffs<W: Write>(mut writer: W, fix_diffs: &[(PathBuf, String)]) -> io::Result<()> {
+        let printed = fix_diffs
+            .iter()
+            .try_for_each(|(_, diff)| writer.write_all(diff.as_bytes()))
+            .and_then(|()| writer.flush());
+        match printed {
+            Err(error) if error.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+            printed => printed,
+        }
+    }
+
+    /// The reporters of `--reporter`, writing to `--output-file` or stdout when they have no path.
+    fn reporters(
+        options: OutputOptions,
+    ) -> Result<Vec<Box<dyn DiagnosticReporter>>, (PathBuf, io::Error)> {
+        let OutputOptions { mut reporter, output_file, column_encoding, .. } = options;
+        if reporter.is_empty() {
+            reporter.push(ReporterOption { format: ReporterFormat::Default, path: None });
+        }
+        reporter
+            .into_iter()
+            .map(|ReporterOption { format, path }| {
+                match path.or_else(|| output_file.clone()) {
+                    Some(path) => match File::create(&path) {
+                        Ok(file) => Ok(Self::reporter(format, Box::new(file), column_encoding)),
+                        Err(err) => Err((path, err)),
+                    },
+                    // Colors are only used on stdout
+                    None if format == ReporterFormat::Default => {
+                        Ok(Box::new(GraphicalReporter::stdout()) as Box<dyn DiagnosticReporter>)
+                    }
+                    None => Ok(Self::reporter(format, Box::new(io::stdout()), column_encoding)),
+                }
+            })
+            .collect()
+    }
+
+    /// The reporter of `format`, counting columns in UTF-16 code units like ESLint for its
+    /// formats when `column_encoding` is `None`.
+    fn reporter(
+        format: ReporterFormat,
+        writer: Box<dyn Write>,
+        column_encoding: Option<ColumnEncoding>,
+    ) -> Box<dyn DiagnosticReporter> {
+        let eslint_column_encoding = column_encoding.unwrap_or(ColumnEncoding::Utf16);
+        let column_encoding = column_encoding.unwrap_or_default();
+        match format {
+            ReporterFormat::Default => Box::new(GraphicalReporter::new_nocolor(writer)),
+            ReporterFormat::Json => {
+                Box::new(JsonReporter::new(writer).with_column_encoding(column_encoding))
+            }
+            ReporterFormat::Github => {
+                Box::new(GithubReporter::new(writer).with_column_encoding(column_encoding))
+            }
+            ReporterFormat::Compact => {
+                Box::new(CompactReporter::new(writer).with_column_encoding(eslint_column_encoding))
+            }
+            ReporterFormat::Stylish => {
+                Box::new(StylishReporter::new(writer).with_column_encoding(eslint_column_encoding))
+            }
+        }
+    }
+}
+
 #[cfg(all(test, not(target_os = "windows")))]
 mod test {
+    use std::{
+        io::{self, Write},
+        path::{Path, PathBuf},
+    };
+
     use super::LintRunner;
-    use crate::{lint_command, CliRunResult, LintResult, Runner};
+    use crate::{lint_command, workspace::find_workspaces, CliRunResult, LintResult, Runner};
 
     fn test(args: &[&str]) -> LintResult {
         let mut new_args = vec!["--quiet"];
@@ -95,13 +466,35 @@ mod test {
         test(args);
     }
 
+    #[test]
+    fn statistics() {
+        let args = &["--statistics", "fixtures"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 4);
+    }
+
+    #[test]
+    fn threads() {
+        let dir = tempfile::tempdir().unwrap();
+        let reports = ["first.json", "second.json"].map(|file| {
+            let report = dir.path().join(file);
+            let reporter = format!("json={}", report.to_string_lossy());
+            let result = test(&["--threads", "1", "--reporter", &reporter, "fixtures"]);
+            assert_eq!(result.number_of_threads, 1);
+            assert_eq!(result.number_of_warnings, 4);
+            std::fs::read_to_string(report).unwrap()
+        });
+        // Files are linted one at a time in the same order
+        assert_eq!(reports[0], reports[1]);
+    }
+
     #[test]
     fn dir() {
         let args = &["fixtures"];
         let result = test(args);
         assert!(result.number_of_rules > 0);
-        assert_eq!(result.number_of_files, 2);
-        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_files, 3);
+        assert_eq!(result.number_of_warnings, 4);
         assert_eq!(result.number_of_errors, 0);
     }
 
@@ -123,6 +516,75 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn vue() {
+        let args = &["fixtures/vue/debugger.vue"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn markdown() {
+        let args = &["fixtures/markdown/debugger.md"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 0);
+
+        let args = &["--extensions", "md", "fixtures/markdown/debugger.md"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn html() {
+        let args = &["fixtures/html"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 0);
+
+        let args = &["--extensions", "html", "fixtures/html"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn extension_alias() {
+        let args = &["--extensions", "es6=js", "fixtures/extensions"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+
+        // `.es6` files are not linted by default
+        let args = &["fixtures/extensions"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 0);
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        // "café" encoded as Latin-1, reported as a warning instead of aborting
+        let args = &["tests/fixtures/encodings/latin1.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn utf16() {
+        // UTF-16 LE with a byte order mark
+        let args = &["tests/fixtures/encodings/utf16.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
     #[test]
     fn wrong_extension() {
         let args = &["foo.asdf"];
@@ -134,7 +596,7 @@ mod test {
 
     #[test]
     fn ignore_pattern() {
-        let args = &["--ignore-pattern", "**/*.js", "fixtures"];
+        let args = &["--ignore-pattern", "**/*.{js,vue,md}", "fixtures"];
         let result = test(args);
         assert_eq!(result.number_of_files, 0);
         assert_eq!(result.number_of_warnings, 0);
@@ -158,4 +620,331 @@ mod test {
         assert_eq!(result.number_of_warnings, 0);
         assert_eq!(result.number_of_errors, 0);
     }
+
+    #[test]
+    fn config() {
+        // `no-setter-return` reports errors, `no-debugger` reports warnings
+        let file = "tests/fixtures/config/a.js";
+        let config = "tests/fixtures/config/oxlintrc.json";
+
+        let result = test(&[file]);
+        assert_eq!((result.number_of_warnings, result.number_of_errors), (1, 1));
+
+        let result = test(&["-W", "correctness", file]);
+        assert_eq!((result.number_of_warnings, result.number_of_errors), (2, 0));
+
+        let result = test(&["-c", config, file]);
+        assert_eq!((result.number_of_warnings, result.number_of_errors), (1, 0));
+
+        // The command line is applied after the config file
+        let result = test(&["-c", config, "-D", "no-debugger", file]);
+        assert_eq!((result.number_of_warnings, result.number_of_errors), (2, 0));
+    }
+
+    #[test]
+    fn config_not_found() {
+        let args = &["-c", "fixtures/config/missing.json", "fixtures"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        assert!(matches!(LintRunner::new(options).run(), CliRunResult::ConfigError { .. }));
+    }
+
+    #[test]
+    fn workspace_configs() {
+        // `b` is not a workspace package and has no config file
+        let workspaces = find_workspaces(Path::new("tests/fixtures/workspaces/pnpm"));
+        let workspace_configs = LintRunner::workspace_configs(&workspaces).unwrap();
+        let workspaces = workspace_configs.iter().map(|(workspace, _)| workspace.as_path());
+        assert_eq!(
+            workspaces.collect::<Vec<_>>(),
+            [Path::new("tests/fixtures/workspaces/pnpm/packages/a")]
+        );
+    }
+
+    #[test]
+    fn syntax_only() {
+        let result = test(&["--syntax-only", "fixtures"]);
+        assert_eq!(result.number_of_rules, 0);
+        assert_eq!(result.number_of_files, 3);
+        assert_eq!((result.number_of_warnings, result.number_of_errors), (0, 0));
+
+        // Only the redeclaration is reported, not `debugger`
+        let file = "tests/fixtures/syntax_only/a.js";
+        let result = test(&["--syntax-only", "-D", "all", file]);
+        assert_eq!((result.number_of_warnings, result.number_of_errors), (0, 1));
+    }
+
+    #[test]
+    fn module_graph() {
+        let dir = tempfile::tempdir().unwrap();
+        let json = dir.path().join("graph.json");
+        let dot = dir.path().join("graph.dot");
+
+        for output in [&json, &dot] {
+            let args =
+                ["--module-graph", output.to_str().unwrap(), "tests/fixtures/module_graph/a.js"];
+            let result = test(&args);
+            assert_eq!(result.number_of_files, 1);
+        }
+
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(json).unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {
+                    "path": "tests/fixtures/module_graph/a.js",
+                    "requests": [
+                        {
+                            "specifier": "./b",
+                            "resolved": "tests/fixtures/module_graph/b.js",
+                            "dynamic": false
+                        },
+                        { "specifier": "missing", "resolved": null, "dynamic": false },
+                        {
+                            "specifier": "./d",
+                            "resolved": "tests/fixtures/module_graph/d.js",
+                            "dynamic": true
+                        }
+                    ]
+                },
+                { "path": "tests/fixtures/module_graph/b.js", "requests": [] },
+                { "path": "tests/fixtures/module_graph/d.js", "requests": [] }
+            ])
+        );
+        let dot = std::fs::read_to_string(dot).unwrap();
+        let (a, b, d) = (
+            "tests/fixtures/module_graph/a.js",
+            "tests/fixtures/module_graph/b.js",
+            "tests/fixtures/module_graph/d.js",
+        );
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.contains(&format!(r#"  "{a}" -> "{b}" [label="./b"];"#)));
+        assert!(dot.contains(&format!(r#"  "{a}" -> "missing" [style=dashed];"#)));
+        assert!(dot.contains(&format!(r#"  "{a}" -> "{d}" [label="./d", style=dotted];"#)));
+
+        // The graph cannot be written below a file
+        let output = dir.path().join("graph.json").join("graph.json");
+        let args = ["--module-graph", output.to_str().unwrap(), "tests/fixtures/module_graph/a.js"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        let result = LintRunner::new(options).run();
+        assert!(matches!(result, CliRunResult::ModuleGraphError { .. }), "{result:?}");
+    }
+
+    #[test]
+    fn module_depth() {
+        let dir = tempfile::tempdir().unwrap();
+        let graph = dir.path().join("graph.json");
+        let (a, b, c) = (
+            "tests/fixtures/module_depth/a.js",
+            "tests/fixtures/module_depth/b.js",
+            "tests/fixtures/module_depth/c.js",
+        );
+        let dep = "tests/fixtures/module_depth/node_modules/dep/index.js";
+
+        let module_graph = |args: &[&str]| {
+            let args = [&["--module-graph", graph.to_str().unwrap()], args, &[a]].concat();
+            test(&args);
+            std::fs::read_to_string(&graph).unwrap()
+        };
+
+        // The direct dependencies by default
+        let json = module_graph(&[]);
+        assert!(json.contains(&format!(r#""resolved": "{b}""#)));
+        assert!(!json.contains(&format!(r#""resolved": "{c}""#)));
+
+        let json = module_graph(&["--module-depth", "2"]);
+        assert!(json.contains(&format!(r#""resolved": "{c}""#)));
+
+        let json = module_graph(&["--no-cross-module"]);
+        assert!(!json.contains(&format!(r#""path": "{b}""#)));
+
+        // Dependencies in `node_modules` are resolved but not loaded
+        let json = module_graph(&["--workspace-modules-only"]);
+        assert!(json.contains(&format!(r#""path": "{b}""#)));
+        assert!(json.contains(&format!(r#""resolved": "{dep}""#)));
+        assert!(!json.contains(&format!(r#""path": "{dep}""#)));
+    }
+
+    #[test]
+    fn module_cache() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cache = temp_dir.path().join("modules.json");
+        let dir = Path::new("tests/fixtures/module_cache").canonicalize().unwrap();
+        let a = dir.join("a.js").to_string_lossy().to_string();
+        let b = dir.join("b.js").to_string_lossy().to_string();
+        let args = ["--import-plugin", "--module-cache", cache.to_str().unwrap(), a.as_str()];
+
+        // Dependencies are cached, the linted files are not
+        let result = test(&args);
+        assert_eq!(result.number_of_errors + result.number_of_warnings, 0);
+        let modules = std::fs::read_to_string(&cache).unwrap();
+        assert!(modules.contains(&format!("{b:?}")));
+        assert!(!modules.contains(&format!("{a:?}")));
+
+        // The exports of `b.js` are read from the cache
+        let result = test(&args);
+        assert_eq!(result.number_of_errors + result.number_of_warnings, 0);
+
+        // The cache cannot be written below a file
+        let cache = dir.join("a.js").join("modules.json");
+        let args = ["--import-plugin", "--module-cache", cache.to_str().unwrap(), a.as_str()];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        let result = LintRunner::new(options).run();
+        assert!(matches!(result, CliRunResult::ModuleCacheError { .. }), "{result:?}");
+    }
+
+    #[test]
+    fn source_type_directive() {
+        // `with` statements are not allowed in modules
+        let result = test(&["-A", "all", "tests/fixtures/source_type/module.mjs"]);
+        assert_eq!(result.number_of_errors, 1);
+
+        let result = test(&["-A", "all", "tests/fixtures/source_type/script.mjs"]);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn format() {
+        let result = test(&["-f", "github", "fixtures/debugger.js"]);
+        assert_eq!(result.number_of_files, 1);
+
+        for args in [["--rules", "-f", "github"], ["-f", "md", "fixtures/debugger.js"]] {
+            let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+            let result = LintRunner::new(options).run();
+            assert!(matches!(result, CliRunResult::InvalidOptions { .. }), "{result:?}");
+        }
+    }
+
+    #[test]
+    fn type_aware_without_typescript() {
+        // `typescript` is not installed in the fixtures
+        let args = ["--type-aware", "fixtures/debugger.js"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        let result = LintRunner::new(options).run();
+        assert!(matches!(result, CliRunResult::TypeInfoError { .. }), "{result:?}");
+    }
+
+    #[test]
+    fn fix_dry_run() {
+        let args = &["--fix-dry-run", "fixtures/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        // The fixed `no-debugger` diagnostic is printed as a diff instead
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 0);
+        let source_text = std::fs::read_to_string("fixtures/debugger.js").unwrap();
+        assert_eq!(source_text, "debugger;\n");
+    }
+
+    #[test]
+    fn fix_skips_suggestions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("fix.js");
+        let source_text = "debugger;\nif (!a in b) {}\ntypeof a === undefined;\n";
+        std::fs::write(&path, source_text).unwrap();
+
+        let result = test(&["--fix", path.to_str().unwrap()]);
+        assert_eq!(result.number_of_files, 1);
+        // Safe fixes are still applied by `--fix`, suggestions are only reported
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "\nif (!a in b) {}\ntypeof a === undefined;\n"
+        );
+
+        let result = test(&["--fix-suggestions", path.to_str().unwrap()]);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "\nif (!(a in b)) {}\ntypeof a === \"undefined\";\n"
+        );
+    }
+
+    #[test]
+    fn print_fix_diffs() {
+        struct BrokenPipe;
+        impl Write for BrokenPipe {
+            fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+                Err(io::ErrorKind::BrokenPipe.into())
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let fix_diffs = vec![
+            (PathBuf::from("a.js"), "--- a/a.js\n+++ b/a.js\n".to_string()),
+            (PathBuf::from("b.js"), "--- a/b.js\n+++ b/b.js\n".to_string()),
+        ];
+        let mut output = vec![];
+        LintRunner::print_fix_diffs(&mut output, &fix_diffs).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "--- a/a.js\n+++ b/a.js\n--- a/b.js\n+++ b/b.js\n"
+        );
+        assert!(LintRunner::print_fix_diffs(BrokenPipe, &fix_diffs).is_ok());
+
+        let options = lint_command().run_inner(["--reporter", "json", "."].as_slice()).unwrap();
+        assert!(LintRunner::has_stdout_report(&options.lint_options.output_options));
+        let args = ["--reporter", "json", "--output-file", "report.json", "."];
+        let options = lint_command().run_inner(args.as_slice()).unwrap();
+        assert!(!LintRunner::has_stdout_report(&options.lint_options.output_options));
+        let options = lint_command().run_inner(["."].as_slice()).unwrap();
+        assert!(!LintRunner::has_stdout_report(&options.lint_options.output_options));
+    }
+
+    #[test]
+    fn reporters() {
+        let dir = tempfile::tempdir().unwrap();
+        let json = dir.path().join("report.json");
+        let text = dir.path().join("report.txt");
+        let json_reporter = format!("json={}", json.to_string_lossy());
+        let args = [
+            "--reporter",
+            "default",
+            "--reporter",
+            &json_reporter,
+            "--output-file",
+            text.to_str().unwrap(),
+            "fixtures/debugger.js",
+        ];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        let CliRunResult::LintResult(result) = LintRunner::new(options).run() else {
+            unreachable!()
+        };
+        assert_eq!(result.number_of_warnings, 1);
+
+        let report: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&json).unwrap()).unwrap();
+        assert_eq!(report[0]["filePath"], "fixtures/debugger.js");
+        assert_eq!(report[0]["messages"][0]["ruleId"], "eslint/no-debugger");
+        assert_eq!(report[0]["messages"][0]["line"], 1);
+        let text = std::fs::read_to_string(&text).unwrap();
+        assert!(text.contains("rule: eslint/no-debugger"));
+
+        let args = ["--output-file", "missing/report.txt", "fixtures/debugger.js"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        assert!(matches!(LintRunner::new(options).run(), CliRunResult::OutputFileError { .. }));
+    }
+
+    #[test]
+    fn baseline() {
+        let dir = tempfile::tempdir().unwrap();
+        let baseline = dir.path().join("baseline.json");
+        let baseline = baseline.to_str().unwrap();
+
+        let result = test(&["--generate-baseline", baseline, "fixtures"]);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_suppressed, 4);
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(baseline).unwrap()).unwrap();
+        assert_eq!(json["files"]["fixtures/debugger.js"]["eslint/no-debugger"], 1);
+
+        let result = test(&["--baseline", baseline, "fixtures"]);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_suppressed, 4);
+
+        let args = ["--baseline", "missing/baseline.json", "fixtures"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap().lint_options;
+        assert!(matches!(LintRunner::new(options).run(), CliRunResult::BaselineError { .. }));
+    }
 }
@@ -8,13 +8,14 @@ static GLOBAL: jemallocator::Jemalloc = jemallocator::Jemalloc;
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
-use oxc_cli::{CliCommand, CliRunResult, LintRunner, Runner, TypeCheckRunner};
+use oxc_cli::{AstRunner, CliCommand, CliRunResult, LintRunner, Runner, TypeCheckRunner};
 
 fn main() -> CliRunResult {
+    oxc_cli::init_tracing_subscriber();
     let options = oxc_cli::cli_command().fallback_to_usage().run();
-    options.handle_threads();
     match options {
         CliCommand::Lint(options) => LintRunner::new(options).run(),
         CliCommand::Check(options) => TypeCheckRunner::new(options).run(),
+        CliCommand::Ast(options) => AstRunner::new(options).run(),
     }
 }
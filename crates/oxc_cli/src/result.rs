@@ -7,9 +7,50 @@ use std::{
 #[derive(Debug)]
 pub enum CliRunResult {
     None,
-    PathNotFound { paths: Vec<PathBuf> },
+    PathNotFound {
+        paths: Vec<PathBuf>,
+    },
+    OutputFileError {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    BaselineError {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    ModuleCacheError {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    ModuleGraphError {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    GitError {
+        error: oxc_diagnostics::Error,
+    },
+    ConfigError {
+        error: oxc_diagnostics::Error,
+    },
+    /// Options that cannot be used together, e.g. `--rules --format github`
+    InvalidOptions {
+        message: String,
+    },
+    /// The TypeScript compiler could not be started for `--type-aware`
+    TypeInfoError {
+        error: std::io::Error,
+    },
+    /// Linting was stopped with Ctrl-C, the diagnostics of the linted files were reported
+    Cancelled,
     LintResult(LintResult),
-    TypeCheckResult { duration: Duration, number_of_diagnostics: usize },
+    TypeCheckResult {
+        duration: Duration,
+        number_of_diagnostics: usize,
+    },
+    /// The AST was printed, the syntax and semantic errors were printed to stderr
+    AstResult {
+        number_of_errors: usize,
+    },
 }
 
 #[derive(Debug)]
@@ -17,8 +58,11 @@ pub struct LintResult {
     pub duration: Duration,
     pub number_of_rules: usize,
     pub number_of_files: usize,
+    pub number_of_threads: usize,
     pub number_of_warnings: usize,
     pub number_of_errors: usize,
+    /// Number of diagnostics suppressed or recorded by a baseline
+    pub number_of_suppressed: usize,
     pub max_warnings_exceeded: bool,
 }
 
@@ -30,16 +74,51 @@ impl Termination for CliRunResult {
                 println!("Path {paths:?} does not exist.");
                 ExitCode::from(1)
             }
+            Self::OutputFileError { path, error } => {
+                println!("Failed to create the output file {path:?}: {error}");
+                ExitCode::from(1)
+            }
+            Self::BaselineError { path, error } => {
+                println!("Failed to read or write the baseline {path:?}: {error}");
+                ExitCode::from(1)
+            }
+            Self::ModuleCacheError { path, error } => {
+                println!("Failed to write the module cache {path:?}: {error}");
+                ExitCode::from(1)
+            }
+            Self::ModuleGraphError { path, error } => {
+                println!("Failed to write the module graph {path:?}: {error}");
+                ExitCode::from(1)
+            }
+            Self::GitError { error } | Self::ConfigError { error } => {
+                println!("{error:?}");
+                ExitCode::from(1)
+            }
+            Self::InvalidOptions { message } => {
+                println!("{message}");
+                ExitCode::from(1)
+            }
+            Self::TypeInfoError { error } => {
+                println!("Failed to start the TypeScript compiler for --type-aware: {error}");
+                ExitCode::from(1)
+            }
+            Self::AstResult { number_of_errors } => ExitCode::from(u8::from(number_of_errors > 0)),
+            Self::Cancelled => {
+                println!("Linting was cancelled.");
+                // 128 + SIGINT, as for processes terminated by Ctrl-C
+                ExitCode::from(130)
+            }
             Self::LintResult(LintResult {
                 duration,
                 number_of_rules,
                 number_of_files,
+                number_of_threads,
                 number_of_warnings,
                 number_of_errors,
+                number_of_suppressed,
                 max_warnings_exceeded,
             }) => {
                 let ms = duration.as_millis();
-                let threads = rayon::current_num_threads();
                 let number_of_diagnostics = number_of_warnings + number_of_errors;
 
                 if number_of_diagnostics > 0 {
@@ -48,7 +127,7 @@ impl Termination for CliRunResult {
 
                 let s = if number_of_files == 1 { "" } else { "s" };
                 println!(
-                    "Finished in {ms}ms on {number_of_files} file{s} with {number_of_rules} rules using {threads} threads."
+                    "Finished in {ms}ms on {number_of_files} file{s} with {number_of_rules} rules using {number_of_threads} threads."
                 );
 
                 if max_warnings_exceeded {
@@ -62,6 +141,13 @@ impl Termination for CliRunResult {
                     if number_of_errors == 1 { "" } else { "s" }
                 );
 
+                if number_of_suppressed > 0 {
+                    println!(
+                        "{number_of_suppressed} existing diagnostic{} in the baseline not reported.",
+                        if number_of_suppressed == 1 { " is" } else { "s are" }
+                    );
+                }
+
                 let exit_code = u8::from(number_of_diagnostics > 0);
                 ExitCode::from(exit_code)
             }
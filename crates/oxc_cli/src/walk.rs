@@ -1,4 +1,5 @@
 use std::{
+    collections::HashSet,
     path::{Path, PathBuf},
     sync::mpsc,
 };
@@ -8,39 +9,72 @@ use oxc_span::VALID_EXTENSIONS;
 
 use crate::IgnoreOptions;
 
+/// Extensions of files with embedded JavaScript that are linted by default,
+/// other extensions supported by the partial loaders need `--extensions`, e.g. `md` and `html`
+/// whose code blocks and inline scripts are often incomplete snippets
+const DEFAULT_PARTIAL_LOADER_EXT: &[&str] = &["vue"];
+
+/// Number of paths each walker thread collects before sending them
+const BATCH_SIZE: usize = 256;
+
+/// Number of batches in flight, so walker threads wait instead of buffering without bound
+const CHANNEL_BOUND: usize = 64;
+
 pub struct Walk {
-    inner: ignore::WalkParallel,
+    inner: ignore::WalkBuilder,
+    /// Extensions to lint in addition to the default ones
+    extensions: Vec<String>,
+    /// Only lint these canonical paths when set, e.g. the files changed in Git
+    only: Option<HashSet<PathBuf>>,
 }
 
-struct WalkBuilder {
-    sender: mpsc::Sender<Vec<Box<Path>>>,
+struct WalkBuilder<'s> {
+    sender: mpsc::SyncSender<Vec<Box<Path>>>,
+    extensions: &'s [String],
+    only: Option<&'s HashSet<PathBuf>>,
 }
 
-impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
+impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder<'s> {
     fn build(&mut self) -> Box<dyn ignore::ParallelVisitor + 's> {
-        Box::new(WalkCollector { paths: vec![], sender: self.sender.clone() })
+        Box::new(WalkCollector {
+            paths: vec![],
+            sender: self.sender.clone(),
+            extensions: self.extensions,
+            only: self.only,
+        })
     }
 }
 
-struct WalkCollector {
+struct WalkCollector<'s> {
     paths: Vec<Box<Path>>,
-    sender: mpsc::Sender<Vec<Box<Path>>>,
+    sender: mpsc::SyncSender<Vec<Box<Path>>>,
+    extensions: &'s [String],
+    only: Option<&'s HashSet<PathBuf>>,
 }
 
-impl Drop for WalkCollector {
+impl<'s> Drop for WalkCollector<'s> {
     fn drop(&mut self) {
         let paths = std::mem::take(&mut self.paths);
-        self.sender.send(paths).unwrap();
+        // The receiver is gone when linting stopped early
+        _ = self.sender.send(paths);
     }
 }
 
-impl ignore::ParallelVisitor for WalkCollector {
+impl<'s> ignore::ParallelVisitor for WalkCollector<'s> {
     fn visit(&mut self, entry: Result<ignore::DirEntry, ignore::Error>) -> ignore::WalkState {
         match entry {
             Ok(entry) => {
-                if entry.file_type().is_some_and(|ft| !ft.is_dir()) && Walk::is_wanted_entry(&entry)
+                if entry.file_type().is_some_and(|ft| !ft.is_dir())
+                    && Walk::is_wanted_entry(&entry, self.extensions)
+                    && self.only.map_or(true, |only| only.contains(entry.path()))
                 {
                     self.paths.push(entry.path().to_path_buf().into_boxed_path());
+                    if self.paths.len() >= BATCH_SIZE {
+                        let paths = std::mem::take(&mut self.paths);
+                        if self.sender.send(paths).is_err() {
+                            return ignore::WalkState::Quit;
+                        }
+                    }
                 }
                 ignore::WalkState::Continue
             }
@@ -82,19 +116,46 @@ impl Walk {
         // Turning off `follow_links` because:
         // * following symlinks is a really slow syscall
         // * it is super rare to have symlinked source code
-        let inner = inner.ignore(false).git_global(false).follow_links(false).build_parallel();
-        Self { inner }
+        inner.ignore(false).git_global(false).follow_links(false);
+        Self { inner, extensions: vec![], only: None }
+    }
+
+    /// Also lint files with these extensions, e.g. `html`
+    #[must_use]
+    pub fn with_extensions(mut self, extensions: Vec<String>) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
+    /// Number of walker threads, the number of CPUs when `None`
+    #[must_use]
+    pub fn with_threads(mut self, threads: Option<usize>) -> Self {
+        if let Some(threads) = threads {
+            self.inner.threads(threads);
+        }
+        self
     }
 
-    pub fn paths(self) -> Vec<Box<Path>> {
-        let (sender, receiver) = mpsc::channel::<Vec<Box<Path>>>();
-        let mut builder = WalkBuilder { sender };
-        self.inner.visit(&mut builder);
-        drop(builder);
-        receiver.into_iter().flatten().collect()
+    /// Only lint these paths, which are absolute and canonical like the walked paths
+    #[must_use]
+    pub fn with_only(mut self, only: Option<HashSet<PathBuf>>) -> Self {
+        self.only = only;
+        self
     }
 
-    fn is_wanted_entry(dir_entry: &DirEntry) -> bool {
+    /// Walk in parallel on other threads, the paths are received in batches while the walker
+    /// threads are running, so they can be linted before the walk finishes.
+    pub fn paths(self) -> impl Iterator<Item = Box<Path>> {
+        let (sender, receiver) = mpsc::sync_channel::<Vec<Box<Path>>>(CHANNEL_BOUND);
+        let Self { inner, extensions, only } = self;
+        std::thread::spawn(move || {
+            let mut builder = WalkBuilder { sender, extensions: &extensions, only: only.as_ref() };
+            inner.build_parallel().visit(&mut builder);
+        });
+        receiver.into_iter().flatten()
+    }
+
+    fn is_wanted_entry(dir_entry: &DirEntry, extensions: &[String]) -> bool {
         let Some(file_type) = dir_entry.file_type() else { return false };
         if file_type.is_dir() {
             return false;
@@ -104,6 +165,34 @@ impl Walk {
             return false;
         }
         let Some(extension) = dir_entry.path().extension() else { return false };
-        VALID_EXTENSIONS.contains(&extension.to_string_lossy().as_ref())
+        let extension = extension.to_string_lossy();
+        VALID_EXTENSIONS.contains(&extension.as_ref())
+            || DEFAULT_PARTIAL_LOADER_EXT.contains(&extension.as_ref())
+            || extensions.iter().any(|ext| ext == extension.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::Walk;
+    use crate::IgnoreOptions;
+
+    #[test]
+    fn markdown_is_opt_in() {
+        let options = IgnoreOptions {
+            ignore_path: ".eslintignore".into(),
+            ignore_pattern: vec![],
+            no_ignore: false,
+            max_file_size: None,
+        };
+        let paths = [PathBuf::from("fixtures/markdown")];
+        assert!(Walk::new(&paths, &options).paths().next().is_none());
+
+        let walk = Walk::new(&paths, &options).with_extensions(vec!["md".into()]);
+        let paths = walk.paths().collect::<Vec<_>>();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("fixtures/markdown/debugger.md"));
     }
 }
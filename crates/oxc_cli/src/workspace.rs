@@ -0,0 +1,116 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+
+/// Package directories of the pnpm, yarn or npm workspace containing `cwd`.
+///
+/// The workspace root is the closest directory with a `pnpm-workspace.yaml`,
+/// or a `package.json` with a `workspaces` field, and its patterns are matched against the
+/// directories below the root that contain a `package.json`. Empty when there is no workspace.
+pub fn find_workspaces(cwd: &Path) -> Vec<PathBuf> {
+    let Some((root, patterns)) = cwd.ancestors().find_map(|dir| {
+        let patterns = workspace_patterns(dir)?;
+        Some((dir, patterns))
+    }) else {
+        return vec![];
+    };
+
+    let (excludes, includes): (Vec<_>, Vec<_>) =
+        patterns.iter().partition(|pattern| pattern.starts_with('!'));
+    let (Some(includes), Some(excludes)) = (
+        glob_set(includes.iter().map(|pattern| pattern.as_str())),
+        glob_set(excludes.iter().map(|pattern| &pattern[1..])),
+    ) else {
+        return vec![];
+    };
+
+    let mut workspaces = ignore::WalkBuilder::new(root)
+        .filter_entry(|entry| entry.file_name() != "node_modules")
+        .build()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_some_and(|ft| ft.is_dir()))
+        .filter_map(|entry| {
+            let relative_path = entry.path().strip_prefix(root).ok()?;
+            (includes.is_match(relative_path)
+                && !excludes.is_match(relative_path)
+                && entry.path().join("package.json").is_file())
+            .then(|| entry.path().to_path_buf())
+        })
+        .collect::<Vec<_>>();
+    workspaces.sort_unstable();
+    workspaces
+}
+
+/// Workspace patterns declared in `dir`, `None` when `dir` is not a workspace root.
+fn workspace_patterns(dir: &Path) -> Option<Vec<String>> {
+    if let Ok(pnpm_workspace) = fs::read_to_string(dir.join("pnpm-workspace.yaml")) {
+        return Some(pnpm_workspace_patterns(&pnpm_workspace));
+    }
+    let package_json = fs::read_to_string(dir.join("package.json")).ok()?;
+    let package_json = serde_json::from_str::<serde_json::Value>(&package_json).ok()?;
+    // `"workspaces": [...]` for npm and yarn, `"workspaces": { "packages": [...] }` for yarn
+    let workspaces = package_json.get("workspaces")?;
+    let patterns = workspaces.get("packages").unwrap_or(workspaces).as_array()?;
+    Some(patterns.iter().filter_map(serde_json::Value::as_str).map(ToString::to_string).collect())
+}
+
+/// The `packages` list of a `pnpm-workspace.yaml`, which is a list of quoted or unquoted globs.
+fn pnpm_workspace_patterns(source_text: &str) -> Vec<String> {
+    source_text
+        .lines()
+        .skip_while(|line| line.trim_end() != "packages:")
+        .skip(1)
+        .map(|line| line.split(" #").next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+        .map_while(|line| line.strip_prefix('-'))
+        .map(|pattern| pattern.trim().trim_matches(|c| c == '\'' || c == '"').to_string())
+        .collect()
+}
+
+fn glob_set<'a>(patterns: impl Iterator<Item = &'a str>) -> Option<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(Glob::new(pattern.trim_end_matches('/')).ok()?);
+    }
+    builder.build().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::{find_workspaces, pnpm_workspace_patterns};
+
+    #[test]
+    fn pnpm_workspace() {
+        let source_text = r#"
+packages:
+  # all packages in direct subdirs of packages/
+  - 'packages/*'
+  - "apps/**" # comment
+  - components/*
+  - '!**/test/**'
+catalog:
+  react: ^18.0.0
+"#;
+        assert_eq!(
+            pnpm_workspace_patterns(source_text),
+            vec!["packages/*", "apps/**", "components/*", "!**/test/**"]
+        );
+    }
+
+    #[test]
+    fn workspaces() {
+        let root = Path::new("tests/fixtures/workspaces/npm");
+        let expected = vec![root.join("packages/a"), root.join("packages/b")];
+        assert_eq!(find_workspaces(root), expected);
+        assert_eq!(find_workspaces(&root.join("packages/a")), expected);
+
+        // pnpm-workspace.yaml takes precedence over package.json
+        let root = Path::new("tests/fixtures/workspaces/pnpm");
+        assert_eq!(find_workspaces(root), vec![root.join("packages/a")]);
+    }
+}
@@ -0,0 +1,2 @@
+({ set a(v) { return v; } });
+debugger;
@@ -0,0 +1,2 @@
+import b from './b';
+import dep from 'dep';
@@ -0,0 +1,2 @@
+// @sourceType script
+with (a) {}
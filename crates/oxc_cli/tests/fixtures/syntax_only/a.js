@@ -0,0 +1,2 @@
+debugger;
+let a; let a;
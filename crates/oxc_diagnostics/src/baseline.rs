@@ -0,0 +1,142 @@
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use crate::Error;
+
+/// Number of diagnostics per file and rule that already exist in a code base,
+/// so a linter can be adopted incrementally and only new diagnostics are reported.
+///
+/// Diagnostics are matched by file and rule instead of by position,
+/// so editing a file does not make its existing diagnostics new.
+/// Diagnostics without a rule, e.g. parse errors, are never suppressed.
+///
+/// A baseline is a JSON file:
+///
+/// ```json
+/// { "files": { "src/a.js": { "eslint/no-debugger": 2 } } }
+/// ```
+#[derive(Debug, Default)]
+pub struct Baseline {
+    files: BTreeMap<String, BTreeMap<String, usize>>,
+    /// Record all diagnostics instead of suppressing the recorded ones
+    generate: bool,
+    suppressed: usize,
+}
+
+impl Baseline {
+    /// An empty baseline that records all diagnostics, see [Baseline::write].
+    pub fn generate() -> Self {
+        Self { generate: true, ..Self::default() }
+    }
+
+    /// Read the baseline at `path` to suppress the diagnostics it records.
+    ///
+    /// # Errors
+    ///
+    /// * When the file cannot be read or is not a valid baseline
+    pub fn read(path: &Path) -> io::Result<Self> {
+        let json = fs::read_to_string(path)?;
+        let mut value: serde_json::Value = serde_json::from_str(&json)?;
+        let files = serde_json::from_value(value["files"].take())?;
+        Ok(Self { files, ..Self::default() })
+    }
+
+    /// Write the recorded diagnostics to `path`.
+    ///
+    /// # Errors
+    ///
+    /// * When the file cannot be written
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(&serde_json::json!({ "files": self.files }))?;
+        fs::write(path, json + "\n")
+    }
+
+    /// Number of diagnostics suppressed or recorded
+    pub fn suppressed(&self) -> usize {
+        self.suppressed
+    }
+
+    /// The diagnostics of the file at `path` that are not in the baseline.
+    /// When generating, all diagnostics with a rule are recorded and suppressed.
+    pub(crate) fn filter(&mut self, path: &Path, diagnostics: Vec<Error>) -> Vec<Error> {
+        let path = path.to_string_lossy();
+        diagnostics
+            .into_iter()
+            .filter(|diagnostic| {
+                let Some(rule) = diagnostic.code().map(|code| code.to_string()) else {
+                    return true;
+                };
+                if self.generate {
+                    let rules = self.files.entry(path.to_string()).or_default();
+                    *rules.entry(rule).or_default() += 1;
+                } else {
+                    // Each recorded diagnostic suppresses one diagnostic of the same rule.
+                    let Some(count) =
+                        self.files.get_mut(path.as_ref()).and_then(|rules| rules.get_mut(&rule))
+                    else {
+                        return true;
+                    };
+                    if *count == 0 {
+                        return true;
+                    }
+                    *count -= 1;
+                }
+                self.suppressed += 1;
+                false
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path};
+
+    use miette::miette;
+
+    use super::Baseline;
+
+    #[test]
+    fn baseline() {
+        let a = Path::new("src/a.js");
+        let b = Path::new("src/b.js");
+        let diagnostics = || {
+            vec![
+                miette!(code = "eslint/no-debugger", "debugger"),
+                miette!(code = "eslint/no-debugger", "debugger"),
+                miette!(code = "eslint/use-isnan", "NaN"),
+                miette!("Unexpected token"),
+            ]
+        };
+
+        let mut baseline = Baseline::generate();
+        let remaining = baseline.filter(a, diagnostics());
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(baseline.suppressed(), 3);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.json");
+        baseline.write(&path).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "files": { "src/a.js": { "eslint/no-debugger": 2, "eslint/use-isnan": 1 } }
+            })
+        );
+
+        let mut baseline = Baseline::read(&path).unwrap();
+        let mut diagnostics_a = diagnostics();
+        diagnostics_a.push(miette!(code = "eslint/no-debugger", "new debugger"));
+        let remaining = baseline.filter(a, diagnostics_a);
+        let messages = remaining.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(messages, ["Unexpected token", "new debugger"]);
+        assert_eq!(baseline.filter(b, diagnostics()).len(), 4);
+        assert_eq!(baseline.suppressed(), 3);
+
+        fs::write(&path, "{}").unwrap();
+        assert!(Baseline::read(&path).is_err());
+        fs::remove_file(&path).unwrap();
+        assert!(Baseline::read(&path).is_err());
+    }
+}
@@ -139,12 +139,14 @@ impl GraphicalReportHandler {
         f: &mut impl fmt::Write,
         diagnostic: &(dyn Diagnostic),
     ) -> fmt::Result {
-        self.render_header(f, diagnostic)?;
+        // CHANGED: the code and url are rendered by `render_code` after the help
+        // self.render_header(f, diagnostic)?;
         writeln!(f)?;
         self.render_causes(f, diagnostic)?;
         let src = diagnostic.source_code();
         self.render_snippets(f, diagnostic, src)?;
         self.render_footer(f, diagnostic)?;
+        self.render_code(f, diagnostic)?;
         self.render_related(f, diagnostic, src)?;
         if let Some(footer) = &self.footer {
             writeln!(f)?;
@@ -256,6 +258,22 @@ impl GraphicalReportHandler {
         Ok(())
     }
 
+    // CHANGED: ADDED
+    /// Render the code and url of the diagnostic below the help, e.g. the rule of a lint diagnostic
+    /// and the link to its documentation.
+    fn render_code(&self, f: &mut impl fmt::Write, diagnostic: &(dyn Diagnostic)) -> fmt::Result {
+        let Some(code) = diagnostic.code() else {
+            return Ok(());
+        };
+        let mut line = format!("  {} {}", "rule:".style(self.theme.styles.help), code);
+        if self.links != LinkStyle::None {
+            if let Some(url) = diagnostic.url() {
+                write!(line, " ({})", url.style(self.theme.styles.link))?;
+            }
+        }
+        writeln!(f, "{}", line)
+    }
+
     fn render_related(
         &self,
         f: &mut impl fmt::Write,
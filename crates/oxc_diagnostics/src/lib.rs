@@ -1,13 +1,24 @@
 //! Diagnostics Wrapper
 //! Exports `thiserror` and `miette`
 
+mod baseline;
 mod graphic_reporter;
 mod graphical_theme;
+mod reporter;
 mod service;
+mod statistics;
 
 use std::path::PathBuf;
 
-pub use crate::service::{DiagnosticSender, DiagnosticService, DiagnosticTuple};
+pub use crate::{
+    baseline::Baseline,
+    reporter::{
+        ColumnEncoding, CompactReporter, DiagnosticReporter, GithubReporter, GraphicalReporter,
+        JsonReporter, StylishReporter,
+    },
+    service::{DiagnosticSender, DiagnosticService, DiagnosticTuple},
+    statistics::Statistics,
+};
 pub use graphic_reporter::{GraphicalReportHandler, GraphicalTheme};
 pub use miette;
 pub use thiserror;
@@ -0,0 +1,647 @@
+//! Reporters render the diagnostics of [DiagnosticService](crate::DiagnosticService)
+
+use std::{
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use miette::SourceSpan;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{Error, GraphicalReportHandler, GraphicalTheme, MinifiedFileError, Severity};
+
+/// Renders diagnostics to a writer, e.g. stdout or a file.
+pub trait DiagnosticReporter {
+    /// Render the diagnostics of the file at `path`, sorted by position.
+    ///
+    /// # Errors
+    ///
+    /// * When writing fails
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: &[Error]) -> io::Result<()>;
+
+    /// Called once after the diagnostics of all files are rendered.
+    ///
+    /// # Errors
+    ///
+    /// * When writing fails
+    fn finish(&mut self) -> io::Result<()>;
+}
+
+/// How the columns of the positions of diagnostics are counted
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnEncoding {
+    /// Bytes of the UTF-8 source text
+    #[default]
+    Utf8,
+    /// UTF-16 code units, like ESLint and the Language Server Protocol
+    Utf16,
+    /// Extended grapheme clusters, the characters displayed by editors, e.g. `👍🏽` counts once
+    Grapheme,
+}
+
+impl ColumnEncoding {
+    /// The width of `text` in this encoding
+    pub fn width(self, text: &str) -> usize {
+        match self {
+            Self::Utf8 => text.len(),
+            Self::Utf16 => text.encode_utf16().count(),
+            Self::Grapheme => text.graphemes(true).count(),
+        }
+    }
+}
+
+impl FromStr for ColumnEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "utf8" => Ok(Self::Utf8),
+            "utf16" => Ok(Self::Utf16),
+            "grapheme" => Ok(Self::Grapheme),
+            _ => Err(format!(
+                "'{s}' is not a known column encoding, expected `utf8`, `utf16` or `grapheme`"
+            )),
+        }
+    }
+}
+
+/// Renders diagnostics for humans, with the source code and the help of each diagnostic.
+pub struct GraphicalReporter {
+    writer: BufWriter<Box<dyn Write>>,
+    handler: GraphicalReportHandler,
+}
+
+impl GraphicalReporter {
+    /// Colors are used when stdout and stderr are terminals, see [GraphicalTheme::default].
+    pub fn stdout() -> Self {
+        Self::new(Box::new(io::stdout()), GraphicalReportHandler::new())
+    }
+
+    /// Renders without colors, e.g. for writing to a file.
+    pub fn new_nocolor(writer: Box<dyn Write>) -> Self {
+        let handler = GraphicalReportHandler::new().with_theme(GraphicalTheme::unicode_nocolor());
+        Self::new(writer, handler)
+    }
+
+    fn new(writer: Box<dyn Write>, handler: GraphicalReportHandler) -> Self {
+        Self { writer: BufWriter::new(writer), handler }
+    }
+}
+
+impl DiagnosticReporter for GraphicalReporter {
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: &[Error]) -> io::Result<()> {
+        let mut output = String::new();
+        for diagnostic in diagnostics {
+            let mut err = String::new();
+            self.handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
+            // Skip large output and print only once
+            if err.lines().any(|line| line.len() >= 400) {
+                let minified_diagnostic = Error::new(MinifiedFileError(path.to_path_buf()));
+                output = format!("{minified_diagnostic:?}");
+                break;
+            }
+            output.push_str(&err);
+        }
+        self.writer.write_all(output.as_bytes())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Renders diagnostics as a JSON array of files in the format of ESLint's `json` formatter,
+/// with the rule of each diagnostic as `ruleId`.
+///
+/// Lines and columns start at 1, columns count bytes unless another [ColumnEncoding] is set.
+pub struct JsonReporter {
+    writer: BufWriter<Box<dyn Write>>,
+    column_encoding: ColumnEncoding,
+    files: Vec<serde_json::Value>,
+}
+
+impl JsonReporter {
+    pub fn new(writer: Box<dyn Write>) -> Self {
+        Self {
+            writer: BufWriter::new(writer),
+            column_encoding: ColumnEncoding::default(),
+            files: vec![],
+        }
+    }
+
+    #[must_use]
+    pub fn with_column_encoding(mut self, column_encoding: ColumnEncoding) -> Self {
+        self.column_encoding = column_encoding;
+        self
+    }
+}
+
+impl DiagnosticReporter for JsonReporter {
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: &[Error]) -> io::Result<()> {
+        let messages = diagnostics
+            .iter()
+            .map(|diagnostic| json_message(diagnostic, self.column_encoding))
+            .collect::<Vec<_>>();
+        let count = |severity: u8| messages.iter().filter(|m| m["severity"] == severity).count();
+        self.files.push(serde_json::json!({
+            "filePath": path.to_string_lossy(),
+            "errorCount": count(2),
+            "warningCount": count(1),
+            "messages": messages,
+        }));
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        serde_json::to_writer_pretty(&mut self.writer, &self.files)?;
+        writeln!(self.writer)?;
+        self.writer.flush()
+    }
+}
+
+fn json_message(diagnostic: &Error, column_encoding: ColumnEncoding) -> serde_json::Value {
+    let severity = if is_error(diagnostic) { 2 } else { 1 };
+    let mut message = serde_json::json!({
+        "ruleId": diagnostic.code().map(|code| code.to_string()),
+        "severity": severity,
+        "message": message(diagnostic),
+        "help": diagnostic.help().map(|help| help.to_string()),
+        "url": diagnostic.url().map(|url| url.to_string()),
+    });
+    if let Some((start, end)) = position(diagnostic, column_encoding) {
+        message["line"] = start.line.into();
+        message["column"] = start.column.into();
+        message["endLine"] = end.line.into();
+        message["endColumn"] = end.column.into();
+    }
+    message
+}
+
+/// Renders diagnostics as GitHub Actions workflow commands,
+/// which are shown as annotations on the changed lines of pull requests.
+///
+/// See <https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions>
+pub struct GithubReporter {
+    writer: BufWriter<Box<dyn Write>>,
+    column_encoding: ColumnEncoding,
+}
+
+impl GithubReporter {
+    pub fn new(writer: Box<dyn Write>) -> Self {
+        Self { writer: BufWriter::new(writer), column_encoding: ColumnEncoding::default() }
+    }
+
+    #[must_use]
+    pub fn with_column_encoding(mut self, column_encoding: ColumnEncoding) -> Self {
+        self.column_encoding = column_encoding;
+        self
+    }
+}
+
+impl DiagnosticReporter for GithubReporter {
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: &[Error]) -> io::Result<()> {
+        let file = escape_property(&path.to_string_lossy());
+        for diagnostic in diagnostics {
+            let command = match diagnostic.severity() {
+                Some(Severity::Error) | None => "error",
+                Some(Severity::Warning) => "warning",
+                Some(Severity::Advice) => "notice",
+            };
+            let mut properties = format!("file={file}");
+            if let Some((start, end)) = position(diagnostic, self.column_encoding) {
+                properties.push_str(&format!(
+                    ",line={},endLine={},col={},endColumn={}",
+                    start.line, end.line, start.column, end.column
+                ));
+            }
+            if let Some(code) = diagnostic.code() {
+                properties.push_str(&format!(",title={}", escape_property(&code.to_string())));
+            }
+            let message = escape_data(&message(diagnostic));
+            writeln!(self.writer, "::{command} {properties}::{message}")?;
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Renders one line per diagnostic, byte-compatible with ESLint's `compact` formatter
+/// printed to stdout.
+///
+/// As in ESLint, paths are absolute and columns count UTF-16 code units unless another
+/// [ColumnEncoding] is set.
+pub struct CompactReporter {
+    writer: BufWriter<Box<dyn Write>>,
+    column_encoding: ColumnEncoding,
+    /// Relative paths are displayed joined to it
+    cwd: PathBuf,
+    total: usize,
+}
+
+impl CompactReporter {
+    pub fn new(writer: Box<dyn Write>) -> Self {
+        Self {
+            writer: BufWriter::new(writer),
+            column_encoding: ColumnEncoding::Utf16,
+            cwd: std::env::current_dir().unwrap_or_default(),
+            total: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn with_column_encoding(mut self, column_encoding: ColumnEncoding) -> Self {
+        self.column_encoding = column_encoding;
+        self
+    }
+}
+
+impl DiagnosticReporter for CompactReporter {
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: &[Error]) -> io::Result<()> {
+        let path = self.cwd.join(path);
+        let path = path.to_string_lossy();
+        for diagnostic in diagnostics {
+            let (start, _) = position(diagnostic, self.column_encoding).unwrap_or_default();
+            let severity = if is_error(diagnostic) { "Error" } else { "Warning" };
+            write!(
+                self.writer,
+                "{path}: line {}, col {}, {severity} - {}",
+                start.line,
+                start.column,
+                message(diagnostic)
+            )?;
+            if let Some(code) = diagnostic.code() {
+                write!(self.writer, " ({code})")?;
+            }
+            writeln!(self.writer)?;
+        }
+        self.total += diagnostics.len();
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        if self.total > 0 {
+            writeln!(self.writer, "\n{} {}", self.total, plural(self.total, "problem"))?;
+        }
+        self.writer.flush()
+    }
+}
+
+/// Renders a table of diagnostics per file, byte-compatible with ESLint's `stylish` formatter
+/// printed to stdout without colors, except for the summary of fixable problems.
+///
+/// As in ESLint, paths are absolute and columns count UTF-16 code units unless another
+/// [ColumnEncoding] is set.
+pub struct StylishReporter {
+    writer: BufWriter<Box<dyn Write>>,
+    column_encoding: ColumnEncoding,
+    /// Relative paths are displayed joined to it
+    cwd: PathBuf,
+    errors: usize,
+    warnings: usize,
+}
+
+impl StylishReporter {
+    pub fn new(writer: Box<dyn Write>) -> Self {
+        Self {
+            writer: BufWriter::new(writer),
+            column_encoding: ColumnEncoding::Utf16,
+            cwd: std::env::current_dir().unwrap_or_default(),
+            errors: 0,
+            warnings: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn with_column_encoding(mut self, column_encoding: ColumnEncoding) -> Self {
+        self.column_encoding = column_encoding;
+        self
+    }
+}
+
+impl DiagnosticReporter for StylishReporter {
+    fn render_diagnostics(&mut self, path: &Path, diagnostics: &[Error]) -> io::Result<()> {
+        if diagnostics.is_empty() {
+            return Ok(());
+        }
+        let is_first_file = self.errors + self.warnings == 0;
+        let rows = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let (start, _) = position(diagnostic, self.column_encoding).unwrap_or_default();
+                let severity = if is_error(diagnostic) {
+                    self.errors += 1;
+                    "error"
+                } else {
+                    self.warnings += 1;
+                    "warning"
+                };
+                // ESLint removes the period at the end of messages
+                let mut message = message(diagnostic);
+                if message.ends_with('.') && !message.ends_with(" .") {
+                    message.pop();
+                }
+                let code = diagnostic.code().map(|code| code.to_string()).unwrap_or_default();
+                [start.line.to_string(), start.column.to_string(), severity.into(), message, code]
+            })
+            .collect::<Vec<_>>();
+
+        // The layout of the `text-table` package with the line number aligned to the right,
+        // `line:column` joined by a colon, and widths in UTF-16 code units as in JavaScript.
+        let mut widths = [0; 5];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(ColumnEncoding::Utf16.width(cell));
+            }
+        }
+        // ESLint starts the output with an empty line
+        if is_first_file {
+            writeln!(self.writer)?;
+        }
+        writeln!(self.writer, "{}", self.cwd.join(path).to_string_lossy())?;
+        let padding =
+            |cell: &String, width: usize| " ".repeat(width - ColumnEncoding::Utf16.width(cell));
+        for [line, column, severity, message, code] in &rows {
+            let row = format!(
+                "  {}{line}:{column}{}  {severity}{}  {message}{}  {code}",
+                padding(line, widths[0]),
+                padding(column, widths[1]),
+                padding(severity, widths[2]),
+                padding(message, widths[3]),
+            );
+            writeln!(self.writer, "{}", row.trim_end())?;
+        }
+        writeln!(self.writer)?;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        let total = self.errors + self.warnings;
+        if total > 0 {
+            // Followed by the empty line of `console.log`
+            writeln!(
+                self.writer,
+                "\u{2716} {total} {} ({} {}, {} {})",
+                plural(total, "problem"),
+                self.errors,
+                plural(self.errors, "error"),
+                self.warnings,
+                plural(self.warnings, "warning"),
+            )?;
+            writeln!(self.writer)?;
+        }
+        self.writer.flush()
+    }
+}
+
+/// The message of `diagnostic` without the rule prefix, e.g. `eslint(no-debugger): `,
+/// when the rule is reported separately as the code `eslint/no-debugger`.
+fn message(diagnostic: &Error) -> String {
+    let message = diagnostic.to_string();
+    let prefix = diagnostic.code().and_then(|code| {
+        let code = code.to_string();
+        let (plugin, rule) = code.split_once('/')?;
+        Some(format!("{plugin}({rule}): "))
+    });
+    match prefix {
+        Some(prefix) if message.starts_with(&prefix) => message[prefix.len()..].to_string(),
+        _ => message,
+    }
+}
+
+/// Errors and diagnostics without a severity are errors, everything else is a warning
+fn is_error(diagnostic: &Error) -> bool {
+    matches!(diagnostic.severity(), Some(Severity::Error) | None)
+}
+
+fn plural(count: usize, word: &str) -> String {
+    if count == 1 {
+        word.to_string()
+    } else {
+        format!("{word}s")
+    }
+}
+
+fn escape_data(value: &str) -> String {
+    value.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// One-based line and column, `0:0` when unknown as in ESLint
+#[derive(Debug, Default, Clone, Copy)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+/// Start and end position of the first label of `diagnostic`, with columns in `column_encoding`
+fn position(diagnostic: &Error, column_encoding: ColumnEncoding) -> Option<(Position, Position)> {
+    let label = diagnostic.labels().and_then(|mut labels| labels.next())?;
+    let source = diagnostic.source_code()?;
+    let position = |offset: usize| {
+        let contents = source.read_span(&SourceSpan::from((offset, 0)), 0, 0).ok()?;
+        // Columns of miette count bytes, the text of the line before `offset` is measured
+        // again for the other encodings.
+        let mut column = contents.column();
+        if column > 0 && column_encoding != ColumnEncoding::Utf8 {
+            let line = source.read_span(&SourceSpan::from((offset - column, column)), 0, 0).ok()?;
+            // Offsets inside of a character are counted in bytes
+            if let Ok(line) = std::str::from_utf8(line.data()) {
+                column = column_encoding.width(line);
+            }
+        }
+        Some(Position { line: contents.line() + 1, column: column + 1 })
+    };
+    Some((position(label.offset())?, position(label.offset() + label.len())?))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        cell::RefCell,
+        io::{self, Write},
+        path::Path,
+        rc::Rc,
+    };
+
+    use miette::{miette, LabeledSpan, NamedSource, Severity};
+
+    use super::{
+        ColumnEncoding, CompactReporter, DiagnosticReporter, GithubReporter, GraphicalReporter,
+        JsonReporter, StylishReporter,
+    };
+
+    /// A writer whose output can be read after it is moved into a reporter
+    #[derive(Clone, Default)]
+    struct SharedWriter(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedWriter {
+        fn output(&self) -> String {
+            String::from_utf8(self.0.borrow().clone()).unwrap()
+        }
+    }
+
+    fn diagnostics() -> Vec<miette::Error> {
+        let diagnostic = miette!(
+            severity = Severity::Warning,
+            code = "eslint/no-debugger",
+            labels = vec![LabeledSpan::at(7..16, "here")],
+            "eslint(no-debugger): `debugger` statement is not allowed"
+        );
+        vec![diagnostic.with_source_code(NamedSource::new("a.js", "a();\n  debugger;\n"))]
+    }
+
+    #[test]
+    fn graphical() {
+        let writer = SharedWriter::default();
+        let mut reporter = GraphicalReporter::new_nocolor(Box::new(writer.clone()));
+        reporter.render_diagnostics(Path::new("a.js"), &diagnostics()).unwrap();
+        reporter.finish().unwrap();
+        let output = writer.output();
+        assert!(output.contains("eslint(no-debugger): `debugger` statement is not allowed"));
+        assert!(output.contains("rule: eslint/no-debugger"));
+    }
+
+    #[test]
+    fn json() {
+        let writer = SharedWriter::default();
+        let mut reporter = JsonReporter::new(Box::new(writer.clone()));
+        reporter.render_diagnostics(Path::new("a.js"), &diagnostics()).unwrap();
+        reporter.render_diagnostics(Path::new("b.js"), &[]).unwrap();
+        reporter.finish().unwrap();
+        let output: serde_json::Value = serde_json::from_str(&writer.output()).unwrap();
+        assert_eq!(
+            output,
+            serde_json::json!([
+                {
+                    "filePath": "a.js",
+                    "errorCount": 0,
+                    "warningCount": 1,
+                    "messages": [{
+                        "ruleId": "eslint/no-debugger",
+                        "severity": 1,
+                        "message": "`debugger` statement is not allowed",
+                        "help": null,
+                        "url": null,
+                        "line": 2,
+                        "column": 3,
+                        "endLine": 2,
+                        "endColumn": 12,
+                    }],
+                },
+                { "filePath": "b.js", "errorCount": 0, "warningCount": 0, "messages": [] },
+            ])
+        );
+    }
+
+    #[test]
+    fn column_encoding() {
+        // 23 bytes, 18 UTF-16 code units and 15 graphemes before `debugger`
+        let source_text = "const é = '👍🏽'; debugger;\n";
+        let columns = |column_encoding: ColumnEncoding| {
+            let diagnostic = miette!(labels = vec![LabeledSpan::at(23..31, "here")], "debugger");
+            let diagnostic = diagnostic.with_source_code(NamedSource::new("a.js", source_text));
+            let writer = SharedWriter::default();
+            let mut reporter =
+                JsonReporter::new(Box::new(writer.clone())).with_column_encoding(column_encoding);
+            reporter.render_diagnostics(Path::new("a.js"), &[diagnostic]).unwrap();
+            reporter.finish().unwrap();
+            let output: serde_json::Value = serde_json::from_str(&writer.output()).unwrap();
+            let message = &output[0]["messages"][0];
+            (message["column"].as_u64().unwrap(), message["endColumn"].as_u64().unwrap())
+        };
+        assert_eq!(columns(ColumnEncoding::Utf8), (24, 32));
+        assert_eq!(columns(ColumnEncoding::Utf16), (19, 27));
+        assert_eq!(columns(ColumnEncoding::Grapheme), (16, 24));
+
+        assert_eq!("utf16".parse(), Ok(ColumnEncoding::Utf16));
+        assert!("utf-16".parse::<ColumnEncoding>().is_err());
+    }
+
+    #[test]
+    fn github() {
+        let writer = SharedWriter::default();
+        let mut reporter = GithubReporter::new(Box::new(writer.clone()));
+        let mut diagnostics = diagnostics();
+        diagnostics.push(miette!("100% broken,\nsee: a.js"));
+        reporter.render_diagnostics(Path::new("src/a,b.js"), &diagnostics).unwrap();
+        reporter.finish().unwrap();
+        assert_eq!(
+            writer.output(),
+            "::warning file=src/a%2Cb.js,line=2,endLine=2,col=3,endColumn=12,title=eslint/no-debugger::`debugger` statement is not allowed\n\
+             ::error file=src/a%2Cb.js::100%25 broken,%0Asee: a.js\n"
+        );
+    }
+
+    /// The diagnostics of `fixtures/eslint`, whose output is ESLint's for the same messages
+    fn render(mut reporter: impl DiagnosticReporter, writer: &SharedWriter) -> String {
+        let mut diagnostics = diagnostics();
+        diagnostics.push(miette!("Unexpected token."));
+        reporter.render_diagnostics(Path::new("/project/src/a.js"), &diagnostics).unwrap();
+        reporter.render_diagnostics(Path::new("/project/src/empty.js"), &[]).unwrap();
+        // `debugger` starts at byte 23 and UTF-16 code unit 18
+        let source_text = "const é = '👍🏽'; debugger\n";
+        let diagnostics = [
+            miette!(
+                severity = Severity::Warning,
+                code = "eslint/no-debugger",
+                labels = vec![LabeledSpan::at(23..31, "here")],
+                "eslint(no-debugger): `debugger` after '👍🏽' is not allowed."
+            ),
+            miette!(
+                code = "eslint/semi",
+                labels = vec![LabeledSpan::at(31..31, "here")],
+                "eslint(semi): Missing semicolon."
+            ),
+        ]
+        .map(|diagnostic| diagnostic.with_source_code(NamedSource::new("b.js", source_text)));
+        reporter.render_diagnostics(Path::new("/project/src/b.js"), &diagnostics).unwrap();
+        reporter.finish().unwrap();
+        writer.output()
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn compact() {
+        let writer = SharedWriter::default();
+        let output = render(CompactReporter::new(Box::new(writer.clone())), &writer);
+        assert_eq!(output, include_str!("../fixtures/eslint/compact.txt"));
+
+        let writer = SharedWriter::default();
+        let mut reporter = CompactReporter::new(Box::new(writer.clone()));
+        reporter.finish().unwrap();
+        assert_eq!(writer.output(), "");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn stylish() {
+        let writer = SharedWriter::default();
+        let output = render(StylishReporter::new(Box::new(writer.clone())), &writer);
+        assert_eq!(output, include_str!("../fixtures/eslint/stylish.txt"));
+
+        // Relative paths are displayed as absolute paths
+        let writer = SharedWriter::default();
+        let mut reporter = StylishReporter::new(Box::new(writer.clone()));
+        reporter.render_diagnostics(Path::new("a.js"), &diagnostics()).unwrap();
+        reporter.finish().unwrap();
+        let cwd = std::env::current_dir().unwrap();
+        assert!(writer.output().starts_with(&format!("\n{}\n", cwd.join("a.js").display())));
+    }
+}
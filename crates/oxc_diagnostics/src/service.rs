@@ -1,12 +1,17 @@
 use std::{
     cell::Cell,
-    io::{BufWriter, Write},
     path::{Path, PathBuf},
     sync::mpsc,
     sync::Arc,
 };
 
-use crate::{miette::NamedSource, Error, GraphicalReportHandler, MinifiedFileError, Severity};
+use crate::{
+    baseline::Baseline,
+    miette::NamedSource,
+    reporter::{DiagnosticReporter, GraphicalReporter},
+    statistics::Statistics,
+    Error, Severity,
+};
 
 pub type DiagnosticTuple = (PathBuf, Vec<Error>);
 pub type DiagnosticSender = mpsc::Sender<Option<DiagnosticTuple>>;
@@ -26,6 +31,15 @@ pub struct DiagnosticService {
     /// Total number of errors received
     errors_count: Cell<usize>,
 
+    /// Reporters that render the diagnostics, a [GraphicalReporter] to stdout when empty
+    reporters: Vec<Box<dyn DiagnosticReporter>>,
+
+    /// Number of warnings and errors per rule and per file, collected when enabled
+    statistics: Option<Statistics>,
+
+    /// Existing diagnostics that are not reported and do not count as warnings or errors
+    baseline: Option<Baseline>,
+
     sender: DiagnosticSender,
     receiver: DiagnosticReceiver,
 }
@@ -38,6 +52,9 @@ impl Default for DiagnosticService {
             max_warnings: None,
             warnings_count: Cell::new(0),
             errors_count: Cell::new(0),
+            reporters: vec![],
+            statistics: None,
+            baseline: None,
             sender,
             receiver,
         }
@@ -57,6 +74,35 @@ impl DiagnosticService {
         self
     }
 
+    /// Render the diagnostics with `reporter`, in addition to the other reporters.
+    #[must_use]
+    pub fn with_reporter(mut self, reporter: Box<dyn DiagnosticReporter>) -> Self {
+        self.reporters.push(reporter);
+        self
+    }
+
+    /// Collect the number of warnings and errors per rule and per file.
+    #[must_use]
+    pub fn with_statistics(mut self, statistics: Option<Statistics>) -> Self {
+        self.statistics = statistics;
+        self
+    }
+
+    pub fn statistics(&self) -> Option<&Statistics> {
+        self.statistics.as_ref()
+    }
+
+    /// Suppress the diagnostics recorded in `baseline`, or record all diagnostics when generating one.
+    #[must_use]
+    pub fn with_baseline(mut self, baseline: Option<Baseline>) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    pub fn baseline(&self) -> Option<&Baseline> {
+        self.baseline.as_ref()
+    }
+
     pub fn sender(&self) -> &DiagnosticSender {
         &self.sender
     }
@@ -86,15 +132,40 @@ impl DiagnosticService {
         (path.to_path_buf(), diagnostics)
     }
 
+    /// Sort the diagnostics of a file by span and remove identical diagnostics,
+    /// so the output does not depend on the order in which rules ran in parallel.
+    fn sort_and_dedup(diagnostics: Vec<Error>) -> Vec<Error> {
+        let mut diagnostics = diagnostics
+            .into_iter()
+            .map(|diagnostic| {
+                let span = diagnostic
+                    .labels()
+                    .and_then(|mut labels| labels.next())
+                    .map(|label| (label.offset(), label.len()));
+                // The message includes the rule name, e.g. `eslint(no-debugger): ...`
+                let message = diagnostic.to_string();
+                (span, message, diagnostic)
+            })
+            .collect::<Vec<_>>();
+        diagnostics.sort_by(|a, b| (a.0, &a.1).cmp(&(b.0, &b.1)));
+        diagnostics.dedup_by(|a, b| a.0 == b.0 && a.1 == b.1);
+        diagnostics.into_iter().map(|(_, _, diagnostic)| diagnostic).collect()
+    }
+
     /// # Panics
     ///
-    /// * When the writer fails to write
-    pub fn run(&self) {
-        let mut buf_writer = BufWriter::new(std::io::stdout());
-        let handler = GraphicalReportHandler::new();
+    /// * When a reporter fails to write
+    pub fn run(&mut self) {
+        if self.reporters.is_empty() {
+            self.reporters.push(Box::new(GraphicalReporter::stdout()));
+        }
 
         while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
-            let mut output = String::new();
+            let mut diagnostics = Self::sort_and_dedup(diagnostics);
+            if let Some(baseline) = &mut self.baseline {
+                diagnostics = baseline.filter(&path, diagnostics);
+            }
+            let mut reported = vec![];
             for diagnostic in diagnostics {
                 let severity = diagnostic.severity();
                 let is_warning = severity == Some(Severity::Warning);
@@ -108,6 +179,9 @@ impl DiagnosticService {
                         let errors_count = self.errors_count() + 1;
                         self.errors_count.set(errors_count);
                     }
+                    if let Some(statistics) = &mut self.statistics {
+                        statistics.record(&path, &diagnostic);
+                    }
                     // The --quiet flag follows ESLint's --quiet behavior as documented here: https://eslint.org/docs/latest/use/command-line-interface#--quiet
                     // Note that it does not disable ALL diagnostics, only Warning diagnostics
                     if self.quiet {
@@ -120,21 +194,66 @@ impl DiagnosticService {
                         }
                     }
                 }
-
-                let mut err = String::new();
-                handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
-                // Skip large output and print only once
-                if err.lines().any(|line| line.len() >= 400) {
-                    let minified_diagnostic = Error::new(MinifiedFileError(path.clone()));
-                    err = format!("{minified_diagnostic:?}");
-                    output = err;
-                    break;
-                }
-                output.push_str(&err);
+                reported.push(diagnostic);
+            }
+            if reported.is_empty() {
+                continue;
+            }
+            for reporter in &mut self.reporters {
+                reporter.render_diagnostics(&path, &reported).unwrap();
             }
-            buf_writer.write_all(output.as_bytes()).unwrap();
         }
 
-        buf_writer.flush().unwrap();
+        for reporter in &mut self.reporters {
+            reporter.finish().unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use miette::{miette, LabeledSpan};
+
+    use super::DiagnosticService;
+    use crate::{GraphicalReportHandler, GraphicalTheme};
+
+    #[test]
+    fn sort_and_dedup() {
+        let diagnostic = |message: &str, offset: usize| {
+            miette!(labels = vec![LabeledSpan::at_offset(offset, "here")], "{message}")
+        };
+        let diagnostics = vec![
+            diagnostic("b", 5),
+            diagnostic("a", 5),
+            diagnostic("c", 1),
+            diagnostic("b", 5),
+            miette!("no label"),
+        ];
+        let messages = DiagnosticService::sort_and_dedup(diagnostics)
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(messages, vec!["no label", "c", "a", "b"]);
+    }
+
+    #[test]
+    fn render_rule() {
+        let handler = GraphicalReportHandler::new().with_theme(GraphicalTheme::unicode_nocolor());
+        let diagnostic = miette!(
+            code = "eslint/no-debugger",
+            url = "https://example.com/no-debugger",
+            help = "Delete this code.",
+            "`debugger` statement is not allowed"
+        );
+        let mut output = String::new();
+        handler.render_report(&mut output, diagnostic.as_ref()).unwrap();
+        assert_eq!(output.matches("eslint/no-debugger").count(), 1);
+        assert!(output.ends_with(
+            "  help: Delete this code.\n  rule: eslint/no-debugger (https://example.com/no-debugger)\n"
+        ));
+
+        let mut output = String::new();
+        handler.with_urls(false).render_report(&mut output, diagnostic.as_ref()).unwrap();
+        assert!(output.ends_with("  rule: eslint/no-debugger\n"));
     }
 }
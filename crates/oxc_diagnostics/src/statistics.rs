@@ -0,0 +1,114 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use crate::Error;
+
+/// Number of files listed in the summary, the files with the most diagnostics come first
+const TOP_FILES: usize = 10;
+
+/// Number of diagnostics per rule and per file, printed as a summary after a run.
+#[derive(Debug, Default)]
+pub struct Statistics {
+    /// Diagnostics per rule, diagnostics without a rule such as parse errors have an empty rule
+    rules: HashMap<String, usize>,
+    files: HashMap<PathBuf, usize>,
+    /// Rules that provide fixes, e.g. `eslint/no-debugger`
+    fixable_rules: HashSet<String>,
+    fixable: usize,
+    total: usize,
+}
+
+impl Statistics {
+    /// Diagnostics of `fixable_rules` are counted as fixable.
+    pub fn new(fixable_rules: HashSet<String>) -> Self {
+        Self { fixable_rules, ..Self::default() }
+    }
+
+    pub(crate) fn record(&mut self, path: &Path, diagnostic: &Error) {
+        let rule = diagnostic.code().map(|code| code.to_string()).unwrap_or_default();
+        if self.fixable_rules.contains(&rule) {
+            self.fixable += 1;
+        }
+        *self.rules.entry(rule).or_default() += 1;
+        *self.files.entry(path.to_path_buf()).or_default() += 1;
+        self.total += 1;
+    }
+
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    pub fn fixable(&self) -> usize {
+        self.fixable
+    }
+
+    /// Counts in descending order, ties in ascending order of the key
+    fn sorted<K: Ord>(counts: &HashMap<K, usize>) -> Vec<(&K, usize)> {
+        let mut counts = counts.iter().map(|(key, count)| (key, *count)).collect::<Vec<_>>();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        counts
+    }
+}
+
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.total == 0 {
+            return Ok(());
+        }
+        let width = self.total.to_string().len();
+        writeln!(f, "Diagnostics per rule:")?;
+        for (rule, count) in Self::sorted(&self.rules) {
+            let rule = if rule.is_empty() { "(no rule)" } else { rule };
+            writeln!(f, "  {count:>width$}  {rule}")?;
+        }
+        writeln!(f, "Files with the most diagnostics:")?;
+        for (path, count) in Self::sorted(&self.files).into_iter().take(TOP_FILES) {
+            writeln!(f, "  {count:>width$}  {}", path.to_string_lossy())?;
+        }
+        writeln!(
+            f,
+            "{} of {} diagnostics are potentially fixable with `--fix`.",
+            self.fixable, self.total
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use miette::miette;
+
+    use super::Statistics;
+
+    #[test]
+    fn statistics() {
+        let mut statistics = Statistics::new(["eslint/no-debugger".to_string()].into());
+        assert_eq!(statistics.to_string(), "");
+
+        let a = Path::new("a.js");
+        let b = Path::new("b.js");
+        for _ in 0..10 {
+            statistics.record(a, &miette!(code = "eslint/no-debugger", "debugger"));
+        }
+        statistics.record(b, &miette!(code = "eslint/no-debugger", "debugger"));
+        statistics.record(b, &miette!(code = "eslint/use-isnan", "NaN"));
+        statistics.record(b, &miette!("Unexpected token"));
+        assert_eq!(statistics.total(), 13);
+        assert_eq!(statistics.fixable(), 11);
+        assert_eq!(
+            statistics.to_string(),
+            "Diagnostics per rule:\n\
+             \x20 11  eslint/no-debugger\n\
+             \x20  1  (no rule)\n\
+             \x20  1  eslint/use-isnan\n\
+             Files with the most diagnostics:\n\
+             \x20 10  a.js\n\
+             \x20  3  b.js\n\
+             11 of 13 diagnostics are potentially fixable with `--fix`.\n"
+        );
+    }
+}
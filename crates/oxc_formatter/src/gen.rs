@@ -462,6 +462,8 @@ impl<'a> Gen for VariableDeclaration<'a> {
             VariableDeclarationKind::Const => b"const",
             VariableDeclarationKind::Let => b"let",
             VariableDeclarationKind::Var => b"var",
+            VariableDeclarationKind::Using => b"using",
+            VariableDeclarationKind::AwaitUsing => b"await using",
         });
         p.print_space();
         p.print_list(&self.declarations);
@@ -529,6 +531,7 @@ impl<'a> Gen for FunctionBody<'a> {
 
 impl<'a> Gen for FormalParameter<'a> {
     fn gen(&self, p: &mut Formatter) {
+        self.decorators.gen(p);
         self.pattern.gen(p);
     }
 }
@@ -1403,6 +1406,7 @@ impl Gen for MetaProperty {
 
 impl<'a> Gen for Class<'a> {
     fn gen(&self, p: &mut Formatter) {
+        self.decorators.gen(p);
         p.print_str(b"class");
         if let Some(id) = &self.id {
             p.print_space();
@@ -1660,6 +1664,7 @@ impl<'a> Gen for StaticBlock<'a> {
 
 impl<'a> Gen for MethodDefinition<'a> {
     fn gen(&self, p: &mut Formatter) {
+        self.decorators.gen(p);
         if self.r#static {
             p.print_str(b"static ");
         }
@@ -1697,6 +1702,7 @@ impl<'a> Gen for MethodDefinition<'a> {
 
 impl<'a> Gen for PropertyDefinition<'a> {
     fn gen(&self, p: &mut Formatter) {
+        self.decorators.gen(p);
         if self.r#static {
             p.print_str(b"static ");
         }
@@ -1718,6 +1724,7 @@ impl<'a> Gen for PropertyDefinition<'a> {
 
 impl<'a> Gen for AccessorProperty<'a> {
     fn gen(&self, p: &mut Formatter) {
+        self.decorators.gen(p);
         if self.r#static {
             p.print_str(b"static ");
         }
@@ -1824,3 +1831,19 @@ impl<'a> Gen for AssignmentPattern<'a> {
         self.right.gen(p);
     }
 }
+
+impl<'a> Gen for Vec<'a, Decorator<'a>> {
+    fn gen(&self, p: &mut Formatter) {
+        for decorator in self {
+            decorator.gen(p);
+            p.print_space();
+        }
+    }
+}
+
+impl<'a> Gen for Decorator<'a> {
+    fn gen(&self, p: &mut Formatter) {
+        p.print(b'@');
+        self.expression.gen(p);
+    }
+}
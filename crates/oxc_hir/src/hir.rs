@@ -1096,6 +1096,10 @@ pub enum VariableDeclarationKind {
     Var,
     Const,
     Let,
+    /// <https://github.com/tc39/proposal-explicit-resource-management>
+    Using,
+    #[cfg_attr(feature = "serde", serde(rename = "await using"))]
+    AwaitUsing,
 }
 
 impl VariableDeclarationKind {
@@ -1104,7 +1108,11 @@ impl VariableDeclarationKind {
     }
 
     pub fn is_lexical(&self) -> bool {
-        matches!(self, Self::Const | Self::Let)
+        matches!(self, Self::Const | Self::Let | Self::Using | Self::AwaitUsing)
+    }
+
+    pub fn is_using(&self) -> bool {
+        matches!(self, Self::Using | Self::AwaitUsing)
     }
 }
 
@@ -1114,6 +1122,8 @@ impl fmt::Display for VariableDeclarationKind {
             Self::Var => "var",
             Self::Const => "const",
             Self::Let => "let",
+            Self::Using => "using",
+            Self::AwaitUsing => "await using",
         };
         write!(f, "{s}")
     }
@@ -1722,6 +1732,7 @@ pub struct AccessorProperty<'a> {
     pub value: Option<Expression<'a>>,
     pub computed: bool,
     pub r#static: bool,
+    pub decorators: Vec<'a, Decorator<'a>>,
 }
 
 #[derive(Debug, Hash)]
@@ -866,8 +866,9 @@ impl<'a> HirBuilder<'a> {
         value: Option<Expression<'a>>,
         computed: bool,
         r#static: bool,
+        decorators: Vec<'a, Decorator<'a>>,
     ) -> Box<'a, AccessorProperty<'a>> {
-        self.alloc(AccessorProperty { span, key, value, computed, r#static })
+        self.alloc(AccessorProperty { span, key, value, computed, r#static, decorators })
     }
 
     /* ---------- Declarations ---------- */
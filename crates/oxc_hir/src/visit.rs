@@ -460,7 +460,7 @@ pub trait Visit<'a>: Sized {
             ClassElement::StaticBlock(block) => self.visit_static_block(block),
             ClassElement::MethodDefinition(def) => self.visit_method_definition(def),
             ClassElement::PropertyDefinition(def) => self.visit_property_definition(def),
-            ClassElement::AccessorProperty(_def) => { /* TODO */ }
+            ClassElement::AccessorProperty(def) => self.visit_accessor_property(def),
         }
     }
 
@@ -497,6 +497,16 @@ pub trait Visit<'a>: Sized {
         self.leave_node(kind);
     }
 
+    fn visit_accessor_property(&mut self, def: &'a AccessorProperty<'a>) {
+        for decorator in &def.decorators {
+            self.visit_decorator(decorator);
+        }
+        self.visit_property_key(&def.key);
+        if let Some(value) = &def.value {
+            self.visit_expression(value);
+        }
+    }
+
     /* ----------  Expression ---------- */
 
     fn visit_expression(&mut self, expr: &'a Expression<'a>) {
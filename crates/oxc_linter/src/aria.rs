@@ -0,0 +1,74 @@
+//! [WAI-ARIA 1.2 states and properties](https://www.w3.org/TR/wai-aria-1.2/#state_prop_def)
+//! Each property is mapped to the type of value it accepts, as defined by
+//! [aria-query](https://github.com/A11yance/aria-query/blob/main/src/ariaPropsMap.js).
+
+use phf::{phf_map, Map};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AriaPropertyType {
+    Boolean,
+    Id,
+    IdList,
+    Integer,
+    Number,
+    String,
+    Token,
+    TokenList,
+    Tristate,
+}
+
+pub const ARIA_PROPERTIES: Map<&'static str, AriaPropertyType> = phf_map! {
+    "aria-activedescendant" => AriaPropertyType::Id,
+    "aria-atomic" => AriaPropertyType::Boolean,
+    "aria-autocomplete" => AriaPropertyType::Token,
+    "aria-braillelabel" => AriaPropertyType::String,
+    "aria-brailleroledescription" => AriaPropertyType::String,
+    "aria-busy" => AriaPropertyType::Boolean,
+    "aria-checked" => AriaPropertyType::Tristate,
+    "aria-colcount" => AriaPropertyType::Integer,
+    "aria-colindex" => AriaPropertyType::Integer,
+    "aria-colindextext" => AriaPropertyType::String,
+    "aria-colspan" => AriaPropertyType::Integer,
+    "aria-controls" => AriaPropertyType::IdList,
+    "aria-current" => AriaPropertyType::Token,
+    "aria-describedby" => AriaPropertyType::IdList,
+    "aria-description" => AriaPropertyType::String,
+    "aria-details" => AriaPropertyType::Id,
+    "aria-disabled" => AriaPropertyType::Boolean,
+    "aria-dropeffect" => AriaPropertyType::TokenList,
+    "aria-errormessage" => AriaPropertyType::Id,
+    "aria-expanded" => AriaPropertyType::Boolean,
+    "aria-flowto" => AriaPropertyType::IdList,
+    "aria-grabbed" => AriaPropertyType::Boolean,
+    "aria-haspopup" => AriaPropertyType::Token,
+    "aria-hidden" => AriaPropertyType::Boolean,
+    "aria-invalid" => AriaPropertyType::Token,
+    "aria-keyshortcuts" => AriaPropertyType::String,
+    "aria-label" => AriaPropertyType::String,
+    "aria-labelledby" => AriaPropertyType::IdList,
+    "aria-level" => AriaPropertyType::Integer,
+    "aria-live" => AriaPropertyType::Token,
+    "aria-modal" => AriaPropertyType::Boolean,
+    "aria-multiline" => AriaPropertyType::Boolean,
+    "aria-multiselectable" => AriaPropertyType::Boolean,
+    "aria-orientation" => AriaPropertyType::Token,
+    "aria-owns" => AriaPropertyType::IdList,
+    "aria-placeholder" => AriaPropertyType::String,
+    "aria-posinset" => AriaPropertyType::Integer,
+    "aria-pressed" => AriaPropertyType::Tristate,
+    "aria-readonly" => AriaPropertyType::Boolean,
+    "aria-relevant" => AriaPropertyType::TokenList,
+    "aria-required" => AriaPropertyType::Boolean,
+    "aria-roledescription" => AriaPropertyType::String,
+    "aria-rowcount" => AriaPropertyType::Integer,
+    "aria-rowindex" => AriaPropertyType::Integer,
+    "aria-rowindextext" => AriaPropertyType::String,
+    "aria-rowspan" => AriaPropertyType::Integer,
+    "aria-selected" => AriaPropertyType::Boolean,
+    "aria-setsize" => AriaPropertyType::Integer,
+    "aria-sort" => AriaPropertyType::Token,
+    "aria-valuemax" => AriaPropertyType::Number,
+    "aria-valuemin" => AriaPropertyType::Number,
+    "aria-valuenow" => AriaPropertyType::Number,
+    "aria-valuetext" => AriaPropertyType::String,
+};
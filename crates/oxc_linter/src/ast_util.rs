@@ -1,4 +1,7 @@
-use std::hash::{Hash, Hasher};
+use std::{
+    borrow::Cow,
+    hash::{Hash, Hasher},
+};
 
 use oxc_ast::AstKind;
 use oxc_semantic::AstNode;
@@ -14,40 +17,48 @@ pub fn calculate_hash<T: Hash>(t: &T) -> u64 {
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
 
-use crate::context::LintContext;
+use crate::{
+    constant_evaluation::{ConstantEvaluation, ConstantValue},
+    context::LintContext,
+};
 
 /// Test if an AST node is a boolean value that never changes. Specifically we
 /// test for:
 /// 1. Literal booleans (`true` or `false`)
 /// 2. Unary `!` expressions with a constant value
 /// 3. Constant booleans created via the `Boolean` global function
+/// 4. Other expressions that evaluate to a constant boolean, e.g. `1 < 2`
 pub fn is_static_boolean<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
     match expr {
         Expression::BooleanLiteral(_) => true,
         Expression::CallExpression(call_expr) => call_expr.is_constant(true, ctx),
-        Expression::UnaryExpression(unary_expr) => {
-            unary_expr.operator == UnaryOperator::LogicalNot
-                && unary_expr.argument.is_constant(true, ctx)
+        Expression::UnaryExpression(unary_expr)
+            if unary_expr.operator == UnaryOperator::LogicalNot =>
+        {
+            unary_expr.argument.is_constant(true, ctx)
         }
-        _ => false,
+        _ => matches!(expr.evaluate(ctx), Some(ConstantValue::Boolean(_))),
     }
 }
 
 /// Checks if a branch node of `LogicalExpression` short circuits the whole condition
-fn is_logical_identity(op: LogicalOperator, expr: &Expression) -> bool {
+fn is_logical_identity<'a>(
+    op: LogicalOperator,
+    expr: &Expression<'a>,
+    ctx: &LintContext<'a>,
+) -> bool {
     match expr {
-        expr if expr.is_literal_expression() => {
-            let boolean_value = expr.get_boolean_value();
-            (op == LogicalOperator::Or && boolean_value == Some(true))
-                || (op == LogicalOperator::And && boolean_value == Some(false))
-        }
-        Expression::UnaryExpression(unary_expr) => {
-            op == LogicalOperator::And && unary_expr.operator == UnaryOperator::Void
+        Expression::UnaryExpression(unary_expr)
+            if op == LogicalOperator::And && unary_expr.operator == UnaryOperator::Void =>
+        {
+            true
         }
-        Expression::LogicalExpression(logical_expr) => {
-            op == logical_expr.operator
-                && (is_logical_identity(logical_expr.operator, &logical_expr.left)
-                    || is_logical_identity(logical_expr.operator, &logical_expr.right))
+        Expression::LogicalExpression(logical_expr)
+            if op == logical_expr.operator
+                && (is_logical_identity(logical_expr.operator, &logical_expr.left, ctx)
+                    || is_logical_identity(logical_expr.operator, &logical_expr.right, ctx)) =>
+        {
+            true
         }
         Expression::AssignmentExpression(assign_expr) => {
             matches!(
@@ -57,10 +68,14 @@ fn is_logical_identity(op: LogicalOperator, expr: &Expression) -> bool {
                 && assign_expr.operator == AssignmentOperator::LogicalAnd)
                 || (op == LogicalOperator::Or
                     && assign_expr.operator == AssignmentOperator::LogicalOr))
-                && is_logical_identity(op, &assign_expr.right)
+                && is_logical_identity(op, &assign_expr.right, ctx)
         }
-        Expression::ParenthesizedExpression(expr) => is_logical_identity(op, &expr.expression),
-        _ => false,
+        Expression::ParenthesizedExpression(expr) => is_logical_identity(op, &expr.expression, ctx),
+        _ => match expr.evaluate_to_boolean(ctx) {
+            Some(true) => op == LogicalOperator::Or,
+            Some(false) => op == LogicalOperator::And,
+            None => false,
+        },
     }
 }
 
@@ -111,10 +126,10 @@ impl<'a, 'b> IsConstant<'a, 'b> for Expression<'a> {
                 let is_left_constant = expr.left.is_constant(in_boolean_position, ctx);
                 let is_right_constant = expr.right.is_constant(in_boolean_position, ctx);
                 let is_left_short_circuit =
-                    is_left_constant && is_logical_identity(expr.operator, &expr.left);
+                    is_left_constant && is_logical_identity(expr.operator, &expr.left, ctx);
                 let is_right_short_circuit = in_boolean_position
                     && is_right_constant
-                    && is_logical_identity(expr.operator, &expr.right);
+                    && is_logical_identity(expr.operator, &expr.right, ctx);
                 (is_left_constant && is_right_constant)
                     || is_left_short_circuit
                     || is_right_short_circuit
@@ -123,10 +138,10 @@ impl<'a, 'b> IsConstant<'a, 'b> for Expression<'a> {
             Self::AssignmentExpression(expr) => match expr.operator {
                 AssignmentOperator::Assign => expr.right.is_constant(in_boolean_position, ctx),
                 AssignmentOperator::LogicalAnd if in_boolean_position => {
-                    is_logical_identity(LogicalOperator::And, &expr.right)
+                    is_logical_identity(LogicalOperator::And, &expr.right, ctx)
                 }
                 AssignmentOperator::LogicalOr if in_boolean_position => {
-                    is_logical_identity(LogicalOperator::Or, &expr.right)
+                    is_logical_identity(LogicalOperator::Or, &expr.right, ctx)
                 }
                 _ => false,
             },
@@ -276,3 +291,227 @@ pub fn get_declaration_of_variable<'a, 'b>(
     let symbol_id = reference.symbol_id()?;
     Some(ctx.nodes().get_node(symbol_table.get_declaration(symbol_id)))
 }
+
+/// Return the JSX attribute with the given name on an opening element.
+/// Spread attributes are not inspected.
+pub fn has_jsx_prop<'a, 'b>(
+    node: &'b JSXOpeningElement<'a>,
+    target_prop: &str,
+) -> Option<&'b JSXAttribute<'a>> {
+    node.attributes.iter().find_map(|attr| match attr {
+        JSXAttributeItem::Attribute(attr) if attr.is_identifier(target_prop) => Some(&**attr),
+        _ => None,
+    })
+}
+
+/// Same as [`has_jsx_prop`], but compares attribute names case-insensitively.
+pub fn has_jsx_prop_lowercase<'a, 'b>(
+    node: &'b JSXOpeningElement<'a>,
+    target_prop: &str,
+) -> Option<&'b JSXAttribute<'a>> {
+    node.attributes.iter().find_map(|attr| match attr {
+        JSXAttributeItem::Attribute(attr)
+            if attr.name.get_name().eq_ignore_ascii_case(target_prop) =>
+        {
+            Some(&**attr)
+        }
+        _ => None,
+    })
+}
+
+/// Whether the opening element has a spread attribute, e.g. `<a {...props} />`
+pub fn has_jsx_spread_attribute(node: &JSXOpeningElement) -> bool {
+    node.attributes.iter().any(|attr| matches!(attr, JSXAttributeItem::SpreadAttribute(_)))
+}
+
+/// Return the element type for plain identifiers and namespaced names, e.g. `img` for `<img />`.
+/// Member expressions such as `<Foo.Bar />` are never DOM elements and return `None`.
+pub fn get_element_type(element: &JSXOpeningElement) -> Option<String> {
+    match &element.name {
+        JSXElementName::Identifier(ident) => Some(ident.name.to_string()),
+        JSXElementName::NamespacedName(name) => {
+            Some(format!("{}:{}", name.namespace.name, name.property.name))
+        }
+        JSXElementName::MemberExpression(_) => None,
+    }
+}
+
+/// Return the static string value of a JSX attribute, e.g. `"foo"` for `alt="foo"` or `alt={"foo"}`.
+/// A valueless attribute (`<input disabled />`) returns `None`.
+pub fn get_string_prop_value<'b>(attr: &'b JSXAttribute<'_>) -> Option<&'b str> {
+    match attr.value.as_ref()? {
+        JSXAttributeValue::StringLiteral(lit) => Some(lit.value.as_str()),
+        JSXAttributeValue::ExpressionContainer(container) => match &container.expression {
+            JSXExpression::Expression(Expression::StringLiteral(lit)) => Some(lit.value.as_str()),
+            JSXExpression::Expression(Expression::TemplateLiteral(lit)) => {
+                lit.is_no_substitution_template().then(|| lit.quasi()).flatten().map(Atom::as_str)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether the attribute value is statically known to be `undefined` or `null`,
+/// e.g. `alt={undefined}`, or an empty expression container such as `alt={}`.
+pub fn is_jsx_prop_value_nullish(attr: &JSXAttribute) -> bool {
+    let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value else {
+        return false;
+    };
+    match &container.expression {
+        JSXExpression::EmptyExpression(_) => true,
+        JSXExpression::Expression(expr) => {
+            let expr = expr.without_parenthesized();
+            expr.is_null() || expr.evaluate_to_undefined()
+        }
+    }
+}
+
+/// Whether the expression is a literal, or a template literal without substitutions,
+/// ignoring any parentheses.
+pub fn is_literal_value(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::TemplateLiteral(lit) => lit.expressions.is_empty(),
+        expr => expr.is_literal_expression(),
+    }
+}
+
+/// Get the string value of a literal, as produced by `String(value)` in JavaScript.
+/// Returns `None` for expressions that are not static, see [`is_literal_value`].
+pub fn get_static_string_value<'a>(expr: &'a Expression<'a>) -> Option<Cow<'a, str>> {
+    match expr.without_parenthesized() {
+        Expression::StringLiteral(lit) => Some(Cow::Borrowed(lit.value.as_str())),
+        Expression::NumberLiteral(lit) => number_to_property_name(lit.value).map(Cow::Owned),
+        Expression::BigintLiteral(lit) => Some(Cow::Owned(lit.value.to_string())),
+        Expression::BooleanLiteral(lit) => Some(Cow::Borrowed(lit.as_str())),
+        Expression::NullLiteral(_) => Some(Cow::Borrowed("null")),
+        Expression::RegExpLiteral(lit) => Some(Cow::Owned(lit.regex.to_string())),
+        Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+            lit.quasi().map(|quasi| Cow::Borrowed(quasi.as_str()))
+        }
+        _ => None,
+    }
+}
+
+/// Get the name of a property key as it is stored on the object at runtime,
+/// e.g. `a`, `'a'`, `['a']` and `` [`a`] `` all return `"a"` and `0x1` returns `"1"`.
+/// Returns `None` for private names and computed keys that are not static.
+pub fn get_static_property_name<'a>(key: &'a PropertyKey<'a>) -> Option<Cow<'a, str>> {
+    match key {
+        PropertyKey::Identifier(ident) => Some(Cow::Borrowed(ident.name.as_str())),
+        PropertyKey::PrivateIdentifier(_) => None,
+        PropertyKey::Expression(expr) => get_static_string_value(expr),
+    }
+}
+
+/// `Number.prototype.toString` for the numbers that are formatted the same way in Rust,
+/// i.e. without an exponent.
+fn number_to_property_name(value: f64) -> Option<String> {
+    if value == 0.0 {
+        return Some("0".to_string());
+    }
+    (value.is_finite() && (1e-6..1e21).contains(&value.abs())).then(|| value.to_string())
+}
+
+/// Check whether two expressions are structurally the same, ignoring parentheses and
+/// whitespace, e.g. `foo.bar()` and `(foo . bar ())`.
+/// Only side-effect free shapes commonly compared by rules are supported, `false` is returned for
+/// anything else.
+#[allow(clippy::float_cmp)]
+pub fn is_same_expression(left: &Expression, right: &Expression) -> bool {
+    match (left.without_parenthesized(), right.without_parenthesized()) {
+        (Expression::Identifier(left), Expression::Identifier(right)) => left.name == right.name,
+        (Expression::ThisExpression(_), Expression::ThisExpression(_))
+        | (Expression::Super(_), Expression::Super(_))
+        | (Expression::NullLiteral(_), Expression::NullLiteral(_)) => true,
+        (Expression::StringLiteral(left), Expression::StringLiteral(right)) => {
+            left.value == right.value
+        }
+        (Expression::NumberLiteral(left), Expression::NumberLiteral(right)) => {
+            left.value == right.value
+        }
+        (Expression::BigintLiteral(left), Expression::BigintLiteral(right)) => {
+            left.value == right.value
+        }
+        (Expression::BooleanLiteral(left), Expression::BooleanLiteral(right)) => {
+            left.value == right.value
+        }
+        (Expression::RegExpLiteral(left), Expression::RegExpLiteral(right)) => {
+            left.regex.pattern == right.regex.pattern && left.regex.flags == right.regex.flags
+        }
+        (Expression::TemplateLiteral(left), Expression::TemplateLiteral(right)) => {
+            left.quasis.len() == right.quasis.len()
+                && left.quasis.iter().zip(&right.quasis).all(|(l, r)| l.value.raw == r.value.raw)
+                && is_same_expressions(&left.expressions, &right.expressions)
+        }
+        (Expression::MemberExpression(left), Expression::MemberExpression(right)) => {
+            is_same_member_expression(left, right)
+        }
+        (Expression::CallExpression(left), Expression::CallExpression(right)) => {
+            is_same_call_expression(left, right)
+        }
+        (Expression::ChainExpression(left), Expression::ChainExpression(right)) => {
+            match (&left.expression, &right.expression) {
+                (ChainElement::CallExpression(left), ChainElement::CallExpression(right)) => {
+                    is_same_call_expression(left, right)
+                }
+                (ChainElement::MemberExpression(left), ChainElement::MemberExpression(right)) => {
+                    is_same_member_expression(left, right)
+                }
+                _ => false,
+            }
+        }
+        (Expression::UnaryExpression(left), Expression::UnaryExpression(right)) => {
+            left.operator == right.operator && is_same_expression(&left.argument, &right.argument)
+        }
+        (Expression::BinaryExpression(left), Expression::BinaryExpression(right)) => {
+            left.operator == right.operator
+                && is_same_expression(&left.left, &right.left)
+                && is_same_expression(&left.right, &right.right)
+        }
+        (Expression::LogicalExpression(left), Expression::LogicalExpression(right)) => {
+            left.operator == right.operator
+                && is_same_expression(&left.left, &right.left)
+                && is_same_expression(&left.right, &right.right)
+        }
+        _ => false,
+    }
+}
+
+fn is_same_expressions(left: &[Expression], right: &[Expression]) -> bool {
+    left.len() == right.len() && left.iter().zip(right).all(|(l, r)| is_same_expression(l, r))
+}
+
+fn is_same_member_expression(left: &MemberExpression, right: &MemberExpression) -> bool {
+    if left.optional() != right.optional() || !is_same_expression(left.object(), right.object()) {
+        return false;
+    }
+    match (left, right) {
+        (
+            MemberExpression::ComputedMemberExpression(left),
+            MemberExpression::ComputedMemberExpression(right),
+        ) => is_same_expression(&left.expression, &right.expression),
+        (
+            MemberExpression::StaticMemberExpression(left),
+            MemberExpression::StaticMemberExpression(right),
+        ) => left.property.name == right.property.name,
+        (
+            MemberExpression::PrivateFieldExpression(left),
+            MemberExpression::PrivateFieldExpression(right),
+        ) => left.field.name == right.field.name,
+        _ => false,
+    }
+}
+
+fn is_same_call_expression(left: &CallExpression, right: &CallExpression) -> bool {
+    left.optional == right.optional
+        && is_same_expression(&left.callee, &right.callee)
+        && left.arguments.len() == right.arguments.len()
+        && left.arguments.iter().zip(&right.arguments).all(|(l, r)| match (l, r) {
+            (Argument::Expression(l), Argument::Expression(r)) => is_same_expression(l, r),
+            (Argument::SpreadElement(l), Argument::SpreadElement(r)) => {
+                is_same_expression(&l.argument, &r.argument)
+            }
+            _ => false,
+        })
+}
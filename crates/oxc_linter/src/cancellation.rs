@@ -0,0 +1,39 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// Shared flag to stop linting, e.g. on Ctrl-C or when the language server shuts down.
+///
+/// Files are not linted once the token is cancelled, files that are being linted are finished,
+/// so the diagnostics of the linted files are still reported.
+#[derive(Debug, Default, Clone)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CancellationToken;
+
+    #[test]
+    fn cancel() {
+        let token = CancellationToken::new();
+        assert!(!token.is_cancelled());
+        let clone = token.clone();
+        std::thread::spawn(move || clone.cancel()).join().unwrap();
+        assert!(token.is_cancelled());
+    }
+}
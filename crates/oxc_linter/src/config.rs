@@ -0,0 +1,208 @@
+use std::{fs, path::Path};
+
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+    Error as Report,
+};
+use rustc_hash::FxHashMap;
+use serde_json::Value;
+
+use crate::{AllowWarnDeny, RuleCategory, RULES};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to read config file {0}: {1}")]
+struct FailedToReadConfig(String, String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid config file {0}: {1}")]
+#[diagnostic(help(
+    "Expected `{{ \"categories\": {{ \"pedantic\": \"warn\" }}, \"rules\": {{ \"eq-eq-eq\": [\"deny\", \"smart\"] }} }}`"
+))]
+struct InvalidConfig(String, String);
+
+/// Rule configuration read from a JSON config file, e.g.
+///
+/// ```json
+/// {
+///   "categories": { "pedantic": "warn", "nursery": "off" },
+///   "rules": { "no-debugger": "off", "eq-eq-eq": ["deny", "smart"] },
+///   "globals": { "jQuery": "readonly", "Promise": "off" }
+/// }
+/// ```
+///
+/// Levels are `"allow"`, `"warn"` and `"deny"`, or ESLint's `"off"`, `"error"`, `0`, `1` and `2`.
+/// Categories are applied before rules, and the command line filter after the config file.
+/// Within each object the keys are applied in order, e.g. `"all": "off"` before a category.
+#[derive(Debug, Default, Clone)]
+pub struct LintConfig {
+    /// Categories and then rules, in the format of [crate::LintOptions::filter]
+    pub(crate) filter: Vec<(AllowWarnDeny, String)>,
+    /// Options following the level of a rule, e.g. `["smart"]` for `"eq-eq-eq": ["deny", "smart"]`
+    pub(crate) rule_options: FxHashMap<String, Value>,
+    /// Global variables in addition to the built-in globals, or built-in globals turned off
+    pub(crate) globals: FxHashMap<String, GlobalValue>,
+}
+
+/// How a global variable of the config file may be used, as in ESLint's `globals`
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GlobalValue {
+    Readonly,
+    Writable,
+    /// Not defined, e.g. to disallow a built-in global
+    Off,
+}
+
+impl GlobalValue {
+    /// `"readonly"`, `"writable"` and `"off"`, or ESLint's `"readable"`, `"writeable"`,
+    /// `false` and `true`
+    fn from_json(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(s) => match s.as_str() {
+                "readonly" | "readable" => Some(Self::Readonly),
+                "writable" | "writeable" => Some(Self::Writable),
+                "off" => Some(Self::Off),
+                _ => None,
+            },
+            Value::Bool(writable) => Some(if *writable { Self::Writable } else { Self::Readonly }),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Readonly => "readonly",
+            Self::Writable => "writable",
+            Self::Off => "off",
+        }
+    }
+}
+
+impl LintConfig {
+    /// # Errors
+    ///
+    /// * When the file cannot be read, is not JSON, or configures unknown categories and rules
+    pub fn from_file(path: &Path) -> Result<Self, Report> {
+        let display = path.to_string_lossy().to_string();
+        let source = fs::read_to_string(path)
+            .map_err(|error| FailedToReadConfig(display.clone(), error.to_string()))?;
+        let value = serde_json::from_str::<Value>(&source)
+            .map_err(|error| InvalidConfig(display.clone(), error.to_string()))?;
+        Self::from_value(&value).map_err(|error| InvalidConfig(display, error).into())
+    }
+
+    /// # Errors
+    ///
+    /// * When `value` configures unknown categories and rules, or invalid levels
+    pub fn from_value(value: &Value) -> Result<Self, String> {
+        let mut config = Self::default();
+
+        if let Some(categories) = value.get("categories") {
+            let categories = categories.as_object().ok_or("`categories` must be an object")?;
+            for (name, level) in categories {
+                if name != "all" && RuleCategory::from(name).is_none() {
+                    return Err(format!("unknown category `{name}`"));
+                }
+                let level = AllowWarnDeny::from_json(level)
+                    .ok_or_else(|| format!("invalid level for category `{name}`"))?;
+                config.filter.push((level, name.clone()));
+            }
+        }
+
+        if let Some(rules) = value.get("rules") {
+            let rules = rules.as_object().ok_or("`rules` must be an object")?;
+            for (name, value) in rules {
+                // Both `no-debugger` and `eslint/no-debugger`
+                let rule = RULES
+                    .iter()
+                    .find(|rule| {
+                        rule.name() == name
+                            || name
+                                .strip_prefix(rule.plugin_name())
+                                .and_then(|n| n.strip_prefix('/'))
+                                == Some(rule.name())
+                    })
+                    .ok_or_else(|| format!("unknown rule `{name}`"))?;
+                let (level, options) = match value {
+                    Value::Array(array) => (array.first(), Some(&array[1.min(array.len())..])),
+                    value => (Some(value), None),
+                };
+                let level = level
+                    .and_then(AllowWarnDeny::from_json)
+                    .ok_or_else(|| format!("invalid level for rule `{name}`"))?;
+                config.filter.push((level, rule.name().to_string()));
+                if let Some(options) = options.filter(|options| !options.is_empty()) {
+                    config.rule_options.insert(rule.name().to_string(), options.to_vec().into());
+                }
+            }
+        }
+
+        if let Some(globals) = value.get("globals") {
+            let globals = globals.as_object().ok_or("`globals` must be an object")?;
+            for (name, value) in globals {
+                let value = GlobalValue::from_json(value)
+                    .ok_or_else(|| format!("invalid value for global `{name}`"))?;
+                config.globals.insert(name.clone(), value);
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{GlobalValue, LintConfig};
+    use crate::AllowWarnDeny;
+
+    #[test]
+    fn from_value() {
+        let config = LintConfig::from_value(&json!({
+            "categories": { "pedantic": "warn", "nursery": 0 },
+            "rules": { "eslint/no-debugger": "off", "eq-eq-eq": ["error", "smart"], "no-empty": [2] }
+        }))
+        .unwrap();
+        assert_eq!(
+            config.filter,
+            vec![
+                (AllowWarnDeny::Warn, "pedantic".into()),
+                (AllowWarnDeny::Allow, "nursery".into()),
+                (AllowWarnDeny::Allow, "no-debugger".into()),
+                (AllowWarnDeny::Deny, "eq-eq-eq".into()),
+                (AllowWarnDeny::Deny, "no-empty".into()),
+            ]
+        );
+        assert_eq!(config.rule_options.get("eq-eq-eq"), Some(&json!(["smart"])));
+        assert_eq!(config.rule_options.get("no-empty"), None);
+    }
+
+    #[test]
+    fn globals() {
+        let config = LintConfig::from_value(&json!({
+            "globals": { "jQuery": "readonly", "app": true, "Promise": "off" }
+        }))
+        .unwrap();
+        assert_eq!(config.globals.get("jQuery"), Some(&GlobalValue::Readonly));
+        assert_eq!(config.globals.get("app"), Some(&GlobalValue::Writable));
+        assert_eq!(config.globals.get("Promise"), Some(&GlobalValue::Off));
+    }
+
+    #[test]
+    fn invalid() {
+        let error = |value| LintConfig::from_value(&value).unwrap_err();
+        assert_eq!(error(json!({ "categories": [] })), "`categories` must be an object");
+        assert_eq!(error(json!({ "categories": { "foo": "warn" } })), "unknown category `foo`");
+        assert_eq!(error(json!({ "rules": { "foo": "warn" } })), "unknown rule `foo`");
+        assert_eq!(
+            error(json!({ "rules": { "no-debugger": "on" } })),
+            "invalid level for rule `no-debugger`"
+        );
+        assert_eq!(error(json!({ "globals": [] })), "`globals` must be an object");
+        assert_eq!(
+            error(json!({ "globals": { "jQuery": "on" } })),
+            "invalid value for global `jQuery`"
+        );
+    }
+}
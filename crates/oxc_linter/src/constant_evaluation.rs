@@ -0,0 +1,306 @@
+//! A small constant evaluator over literals, template literals and operators with known semantics.
+//!
+//! The evaluator is conservative: it returns `None` whenever the value cannot be determined
+//! without running the code, e.g. for references to local variables, calls or BigInts.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+
+use crate::context::LintContext;
+
+/// A statically known primitive value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstantValue {
+    Number(f64),
+    String(String),
+    Boolean(bool),
+    Null,
+    Undefined,
+}
+
+impl ConstantValue {
+    /// <https://tc39.es/ecma262/#sec-toboolean>
+    pub fn to_boolean(&self) -> bool {
+        match self {
+            Self::Number(n) => *n != 0.0 && !n.is_nan(),
+            Self::String(s) => !s.is_empty(),
+            Self::Boolean(b) => *b,
+            Self::Null | Self::Undefined => false,
+        }
+    }
+
+    /// <https://tc39.es/ecma262/#sec-tonumber>
+    pub fn to_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(n) => Some(*n),
+            Self::Boolean(b) => Some(f64::from(u8::from(*b))),
+            Self::Null => Some(0.0),
+            Self::Undefined => Some(f64::NAN),
+            Self::String(s) => {
+                let s = s.trim();
+                if s.is_empty() {
+                    return Some(0.0);
+                }
+                // Only plain decimal numbers, other formats such as `0x10` are left unknown.
+                s.parse::<f64>().ok().filter(|n| n.is_finite())
+            }
+        }
+    }
+
+    /// <https://tc39.es/ecma262/#sec-tostring>
+    ///
+    /// Numbers are only converted when the result is unambiguous, i.e. for integers.
+    pub fn to_js_string(&self) -> Option<String> {
+        match self {
+            Self::String(s) => Some(s.clone()),
+            Self::Boolean(b) => Some(b.to_string()),
+            Self::Null => Some("null".to_string()),
+            Self::Undefined => Some("undefined".to_string()),
+            Self::Number(n) => number_to_string(*n),
+        }
+    }
+
+    /// The result of the `typeof` operator.
+    pub fn type_of(&self) -> &'static str {
+        match self {
+            Self::Number(_) => "number",
+            Self::String(_) => "string",
+            Self::Boolean(_) => "boolean",
+            Self::Null => "object",
+            Self::Undefined => "undefined",
+        }
+    }
+
+    /// <https://tc39.es/ecma262/#sec-isstrictlyequal>
+    #[allow(clippy::float_cmp)]
+    pub fn strict_equals(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a == b,
+            (Self::String(a), Self::String(b)) => a == b,
+            (Self::Boolean(a), Self::Boolean(b)) => a == b,
+            (Self::Null, Self::Null) | (Self::Undefined, Self::Undefined) => true,
+            _ => false,
+        }
+    }
+
+    /// <https://tc39.es/ecma262/#sec-islooselyequal>
+    #[allow(clippy::float_cmp)]
+    pub fn loose_equals(&self, other: &Self) -> Option<bool> {
+        match (self, other) {
+            (Self::Null | Self::Undefined, Self::Null | Self::Undefined) => Some(true),
+            (Self::Null | Self::Undefined, _) | (_, Self::Null | Self::Undefined) => Some(false),
+            (Self::String(a), Self::String(b)) => Some(a == b),
+            _ => Some(self.to_number()? == other.to_number()?),
+        }
+    }
+}
+
+fn number_to_string(n: f64) -> Option<String> {
+    if n.is_nan() {
+        return Some("NaN".to_string());
+    }
+    if n.is_infinite() {
+        return Some(if n > 0.0 { "Infinity" } else { "-Infinity" }.to_string());
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    if n.fract() == 0.0 && n.abs() < 1e15 {
+        return Some((n as i64).to_string());
+    }
+    None
+}
+
+pub trait ConstantEvaluation<'a> {
+    /// Evaluate the expression to a primitive value, if it is statically known.
+    fn evaluate(&self, ctx: &LintContext<'a>) -> Option<ConstantValue>;
+
+    /// Evaluate the truthiness of the expression, if it is statically known.
+    /// Unlike [`ConstantEvaluation::evaluate`], this also knows that objects are always truthy.
+    fn evaluate_to_boolean(&self, ctx: &LintContext<'a>) -> Option<bool>;
+}
+
+impl<'a> ConstantEvaluation<'a> for Expression<'a> {
+    fn evaluate(&self, ctx: &LintContext<'a>) -> Option<ConstantValue> {
+        match self {
+            Self::NumberLiteral(lit) => Some(ConstantValue::Number(lit.value)),
+            Self::StringLiteral(lit) => Some(ConstantValue::String(lit.value.to_string())),
+            Self::BooleanLiteral(lit) => Some(ConstantValue::Boolean(lit.value)),
+            Self::NullLiteral(_) => Some(ConstantValue::Null),
+            Self::Identifier(ident) if ctx.semantic().is_reference_to_global_variable(ident) => {
+                match ident.name.as_str() {
+                    "undefined" => Some(ConstantValue::Undefined),
+                    "NaN" => Some(ConstantValue::Number(f64::NAN)),
+                    "Infinity" => Some(ConstantValue::Number(f64::INFINITY)),
+                    _ => None,
+                }
+            }
+            Self::TemplateLiteral(template) => evaluate_template_literal(template, ctx),
+            Self::ParenthesizedExpression(expr) => expr.expression.evaluate(ctx),
+            Self::SequenceExpression(expr) => expr.expressions.last()?.evaluate(ctx),
+            Self::ConditionalExpression(expr) => {
+                if expr.test.evaluate_to_boolean(ctx)? {
+                    expr.consequent.evaluate(ctx)
+                } else {
+                    expr.alternate.evaluate(ctx)
+                }
+            }
+            Self::UnaryExpression(expr) => evaluate_unary_expression(expr, ctx),
+            Self::LogicalExpression(expr) => evaluate_logical_expression(expr, ctx),
+            Self::BinaryExpression(expr) => evaluate_binary_expression(expr, ctx),
+            _ => None,
+        }
+    }
+
+    fn evaluate_to_boolean(&self, ctx: &LintContext<'a>) -> Option<bool> {
+        match self {
+            Self::ObjectExpression(_)
+            | Self::ArrayExpression(_)
+            | Self::ArrowExpression(_)
+            | Self::FunctionExpression(_)
+            | Self::ClassExpression(_)
+            | Self::RegExpLiteral(_)
+            | Self::NewExpression(_) => Some(true),
+            Self::BigintLiteral(_) => self.get_boolean_value(),
+            Self::ParenthesizedExpression(expr) => expr.expression.evaluate_to_boolean(ctx),
+            Self::SequenceExpression(expr) => expr.expressions.last()?.evaluate_to_boolean(ctx),
+            Self::UnaryExpression(expr) if expr.operator == UnaryOperator::LogicalNot => {
+                expr.argument.evaluate_to_boolean(ctx).map(|b| !b)
+            }
+            Self::LogicalExpression(expr) => {
+                let left = expr.left.evaluate_to_boolean(ctx);
+                match expr.operator {
+                    LogicalOperator::Or if left == Some(true) => Some(true),
+                    LogicalOperator::And if left == Some(false) => Some(false),
+                    LogicalOperator::Or | LogicalOperator::And => {
+                        left?;
+                        expr.right.evaluate_to_boolean(ctx)
+                    }
+                    LogicalOperator::Coalesce => self.evaluate(ctx).map(|v| v.to_boolean()),
+                }
+            }
+            _ => self.evaluate(ctx).map(|value| value.to_boolean()),
+        }
+    }
+}
+
+fn evaluate_template_literal<'a>(
+    template: &TemplateLiteral<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<ConstantValue> {
+    let mut result = String::new();
+    for (i, quasi) in template.quasis.iter().enumerate() {
+        result.push_str(quasi.value.cooked.as_ref()?.as_str());
+        if let Some(expr) = template.expressions.get(i) {
+            result.push_str(&expr.evaluate(ctx)?.to_js_string()?);
+        }
+    }
+    Some(ConstantValue::String(result))
+}
+
+fn evaluate_unary_expression<'a>(
+    expr: &UnaryExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<ConstantValue> {
+    match expr.operator {
+        UnaryOperator::LogicalNot => {
+            expr.argument.evaluate_to_boolean(ctx).map(|b| ConstantValue::Boolean(!b))
+        }
+        UnaryOperator::Void => Some(ConstantValue::Undefined),
+        UnaryOperator::Typeof => {
+            let value = match &expr.argument {
+                Expression::ArrowExpression(_)
+                | Expression::FunctionExpression(_)
+                | Expression::ClassExpression(_) => "function",
+                Expression::ObjectExpression(_)
+                | Expression::ArrayExpression(_)
+                | Expression::RegExpLiteral(_) => "object",
+                Expression::BigintLiteral(_) => "bigint",
+                argument => argument.evaluate(ctx)?.type_of(),
+            };
+            Some(ConstantValue::String(value.to_string()))
+        }
+        UnaryOperator::UnaryNegation => {
+            Some(ConstantValue::Number(-expr.argument.evaluate(ctx)?.to_number()?))
+        }
+        UnaryOperator::UnaryPlus => {
+            Some(ConstantValue::Number(expr.argument.evaluate(ctx)?.to_number()?))
+        }
+        UnaryOperator::BitwiseNot | UnaryOperator::Delete => None,
+    }
+}
+
+fn evaluate_logical_expression<'a>(
+    expr: &LogicalExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<ConstantValue> {
+    let left = expr.left.evaluate(ctx)?;
+    let use_left = match expr.operator {
+        LogicalOperator::Or => left.to_boolean(),
+        LogicalOperator::And => !left.to_boolean(),
+        LogicalOperator::Coalesce => {
+            !matches!(left, ConstantValue::Null | ConstantValue::Undefined)
+        }
+    };
+    if use_left {
+        Some(left)
+    } else {
+        expr.right.evaluate(ctx)
+    }
+}
+
+fn evaluate_binary_expression<'a>(
+    expr: &BinaryExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<ConstantValue> {
+    let left = expr.left.evaluate(ctx)?;
+    let right = expr.right.evaluate(ctx)?;
+    let value = match expr.operator {
+        BinaryOperator::Addition => {
+            if matches!(left, ConstantValue::String(_)) || matches!(right, ConstantValue::String(_))
+            {
+                let mut s = left.to_js_string()?;
+                s.push_str(&right.to_js_string()?);
+                ConstantValue::String(s)
+            } else {
+                ConstantValue::Number(left.to_number()? + right.to_number()?)
+            }
+        }
+        BinaryOperator::Subtraction => {
+            ConstantValue::Number(left.to_number()? - right.to_number()?)
+        }
+        BinaryOperator::Multiplication => {
+            ConstantValue::Number(left.to_number()? * right.to_number()?)
+        }
+        BinaryOperator::Division => ConstantValue::Number(left.to_number()? / right.to_number()?),
+        BinaryOperator::Remainder => ConstantValue::Number(left.to_number()? % right.to_number()?),
+        BinaryOperator::Exponential => {
+            ConstantValue::Number(left.to_number()?.powf(right.to_number()?))
+        }
+        BinaryOperator::StrictEquality => ConstantValue::Boolean(left.strict_equals(&right)),
+        BinaryOperator::StrictInequality => ConstantValue::Boolean(!left.strict_equals(&right)),
+        BinaryOperator::Equality => ConstantValue::Boolean(left.loose_equals(&right)?),
+        BinaryOperator::Inequality => ConstantValue::Boolean(!left.loose_equals(&right)?),
+        BinaryOperator::LessThan
+        | BinaryOperator::LessEqualThan
+        | BinaryOperator::GreaterThan
+        | BinaryOperator::GreaterEqualThan => {
+            let ordering = match (&left, &right) {
+                (ConstantValue::String(a), ConstantValue::String(b)) => {
+                    // Strings are compared by UTF-16 code units
+                    Some(a.encode_utf16().cmp(b.encode_utf16()))
+                }
+                _ => left.to_number()?.partial_cmp(&right.to_number()?),
+            };
+            // Comparisons involving `NaN` are always false
+            let Some(ordering) = ordering else { return Some(ConstantValue::Boolean(false)) };
+            ConstantValue::Boolean(match expr.operator {
+                BinaryOperator::LessThan => ordering.is_lt(),
+                BinaryOperator::LessEqualThan => ordering.is_le(),
+                BinaryOperator::GreaterThan => ordering.is_gt(),
+                _ => ordering.is_ge(),
+            })
+        }
+        _ => return None,
+    };
+    Some(value)
+}
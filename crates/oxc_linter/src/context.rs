@@ -1,13 +1,25 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::RefCell,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Arc,
+};
 
-use oxc_diagnostics::Error;
+use oxc_diagnostics::{Error, Severity};
 use oxc_formatter::{Formatter, FormatterOptions};
+use oxc_resolver::PackageJson;
 use oxc_semantic::{AstNodes, JSDocComment, ScopeTree, Semantic, SymbolTable};
-use oxc_span::SourceType;
+use oxc_span::{SourceType, Span};
+use rustc_hash::FxHashMap;
 
 use crate::{
+    config::GlobalValue,
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
+    file_directives::FileDirectives,
     fixer::{Fix, Message},
+    globals::BUILTINS,
+    rule::RuleId,
+    type_info::TypeInfoProvider,
     AstNode,
 };
 
@@ -18,10 +30,28 @@ pub struct LintContext<'a> {
 
     disable_directives: DisableDirectives<'a>,
 
+    /// Directives in the leading comments, e.g. `@ts-nocheck`
+    file_directives: FileDirectives,
+
     /// Whether or not to apply code fixes during linting.
     fix: bool,
 
-    current_rule_name: &'static str,
+    /// The rule that is running, attached to the diagnostics it reports.
+    current_rule: RuleId,
+
+    /// The severity declared by the running rule, of the diagnostics it reports.
+    current_severity: Option<Severity>,
+
+    /// Path of the file being linted, used for querying type information.
+    file_path: PathBuf,
+
+    type_info: Option<Arc<dyn TypeInfoProvider>>,
+
+    /// The closest package.json of the file being linted, found by the import plugin.
+    package_json: Option<Arc<PackageJson>>,
+
+    /// The `globals` of the config file
+    globals: Arc<FxHashMap<String, GlobalValue>>,
 }
 
 impl<'a> LintContext<'a> {
@@ -32,8 +62,14 @@ impl<'a> LintContext<'a> {
             semantic: Rc::clone(semantic),
             diagnostics: RefCell::new(vec![]),
             disable_directives,
+            file_directives: FileDirectives::default(),
             fix: false,
-            current_rule_name: "",
+            current_rule: RuleId::new("", ""),
+            current_severity: None,
+            file_path: PathBuf::new(),
+            type_info: None,
+            package_json: None,
+            globals: Arc::default(),
         }
     }
 
@@ -43,6 +79,36 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    #[must_use]
+    pub fn with_file_path<P: Into<PathBuf>>(mut self, file_path: P) -> Self {
+        self.file_path = file_path.into();
+        self
+    }
+
+    #[must_use]
+    pub fn with_type_info(mut self, type_info: Option<Arc<dyn TypeInfoProvider>>) -> Self {
+        self.type_info = type_info;
+        self
+    }
+
+    #[must_use]
+    pub fn with_file_directives(mut self, file_directives: FileDirectives) -> Self {
+        self.file_directives = file_directives;
+        self
+    }
+
+    #[must_use]
+    pub fn with_package_json(mut self, package_json: Option<Arc<PackageJson>>) -> Self {
+        self.package_json = package_json;
+        self
+    }
+
+    #[must_use]
+    pub fn with_globals(mut self, globals: Arc<FxHashMap<String, GlobalValue>>) -> Self {
+        self.globals = globals;
+        self
+    }
+
     pub fn semantic(&self) -> &Rc<Semantic<'a>> {
         &self.semantic
     }
@@ -55,8 +121,39 @@ impl<'a> LintContext<'a> {
         self.semantic().source_type()
     }
 
-    pub fn with_rule_name(&mut self, name: &'static str) {
-        self.current_rule_name = name;
+    pub fn file_path(&self) -> &Path {
+        &self.file_path
+    }
+
+    pub fn file_directives(&self) -> FileDirectives {
+        self.file_directives
+    }
+
+    /// How the global variable `name` may be used, from the `globals` of the config file
+    /// or the built-in globals, `None` when it is not defined.
+    pub fn global(&self, name: &str) -> Option<GlobalValue> {
+        match self.globals.get(name) {
+            Some(GlobalValue::Off) => None,
+            Some(value) => Some(*value),
+            None => BUILTINS.get(name).map(|&writable| {
+                if writable {
+                    GlobalValue::Writable
+                } else {
+                    GlobalValue::Readonly
+                }
+            }),
+        }
+    }
+
+    /// The closest package.json of the file being linted,
+    /// only available when the import plugin is enabled.
+    pub fn package_json(&self) -> Option<&PackageJson> {
+        self.package_json.as_deref()
+    }
+
+    pub fn with_rule(&mut self, rule: RuleId, severity: Option<Severity>) {
+        self.current_rule = rule;
+        self.current_severity = severity;
     }
 
     /* Diagnostics */
@@ -65,8 +162,12 @@ impl<'a> LintContext<'a> {
         self.diagnostics.into_inner()
     }
 
-    fn add_diagnostic(&self, message: Message<'a>) {
-        if !self.disable_directives.contains(self.current_rule_name, message.start()) {
+    fn add_diagnostic(&self, mut message: Message<'a>) {
+        if !self.disable_directives.contains(self.current_rule.name, message.start()) {
+            if !self.current_rule.name.is_empty() {
+                message.rule = Some(self.current_rule);
+            }
+            message.severity = self.current_severity.or(message.severity);
             self.diagnostics.borrow_mut().push(message);
         }
     }
@@ -108,4 +209,22 @@ impl<'a> LintContext<'a> {
     pub fn jsdoc(&self, node: &AstNode<'a>) -> Option<JSDocComment<'a>> {
         self.semantic().jsdoc().get_by_node(node)
     }
+
+    /* Type Information */
+
+    /// Whether type information is available for type-aware rules,
+    /// it is not for files with `@ts-nocheck`.
+    pub fn has_type_info(&self) -> bool {
+        self.type_info().is_some()
+    }
+
+    /// Whether the expression at `span` is a `Promise` or another thenable.
+    /// Returns `None` when type information is not available.
+    pub fn is_promise_like(&self, span: Span) -> Option<bool> {
+        self.type_info()?.is_promise_like(&self.file_path, span)
+    }
+
+    fn type_info(&self) -> Option<&Arc<dyn TypeInfoProvider>> {
+        self.type_info.as_ref().filter(|_| !self.file_directives.ts_nocheck)
+    }
 }
@@ -0,0 +1,109 @@
+//! Directives in the leading comments of a file that change how the file is processed.
+//!
+//! * `@sourceType script` and `@sourceType module` parse the file as a script or as a module,
+//!   whatever its extension.
+//! * `@ts-nocheck` disables type checking, type-aware rules skip the file like `tsc` does.
+//!
+//! Only the comments before the first token are read, after an optional hashbang, because the
+//! source type must be known before the file is parsed.
+
+use oxc_span::{ModuleKind, SourceType};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FileDirectives {
+    /// `@sourceType module` or `@sourceType script`
+    pub module_kind: Option<ModuleKind>,
+    /// `@ts-nocheck`
+    pub ts_nocheck: bool,
+}
+
+impl FileDirectives {
+    pub fn parse(source_text: &str) -> Self {
+        let mut directives = Self::default();
+        for comment in leading_comments(source_text) {
+            for pragma in comment.split('@').skip(1) {
+                let mut words = pragma.split(|c: char| c.is_whitespace() || c == '*');
+                match words.next() {
+                    Some("ts-nocheck") => directives.ts_nocheck = true,
+                    Some("sourceType") if directives.module_kind.is_none() => {
+                        directives.module_kind = match words.find(|word| !word.is_empty()) {
+                            Some("module") => Some(ModuleKind::Module),
+                            Some("script") => Some(ModuleKind::Script),
+                            _ => None,
+                        };
+                    }
+                    _ => {}
+                }
+            }
+        }
+        directives
+    }
+
+    /// `source_type` overridden by `@sourceType`
+    pub fn source_type(self, source_type: SourceType) -> SourceType {
+        match self.module_kind {
+            Some(ModuleKind::Module) => source_type.with_module(true),
+            Some(ModuleKind::Script) => source_type.with_script(true),
+            None => source_type,
+        }
+    }
+}
+
+/// The text of the comments before the first token
+fn leading_comments(source_text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = source_text.strip_prefix('\u{feff}').unwrap_or(source_text);
+    if rest.starts_with("#!") {
+        rest = rest.find('\n').map_or("", |end| &rest[end..]);
+    }
+    std::iter::from_fn(move || {
+        rest = rest.trim_start();
+        let (comment, end) = if let Some(comment) = rest.strip_prefix("//") {
+            let end = comment.find(['\n', '\r']).unwrap_or(comment.len());
+            (&comment[..end], end + 2)
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            let end = comment.find("*/")?;
+            (&comment[..end], end + 4)
+        } else {
+            return None;
+        };
+        rest = &rest[end..];
+        Some(comment)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::{ModuleKind, SourceType};
+
+    use super::FileDirectives;
+
+    #[test]
+    fn parse() {
+        let parse = FileDirectives::parse;
+        assert_eq!(parse("foo();"), FileDirectives::default());
+        assert!(parse("// @ts-nocheck\nfoo();").ts_nocheck);
+        assert!(parse("#!/usr/bin/env node\n/** @ts-nocheck */\nfoo();").ts_nocheck);
+        assert!(parse("// license\n\n/*\n * @ts-nocheck\n */").ts_nocheck);
+        // Comments after the first token are not directives
+        assert!(!parse("foo(); // @ts-nocheck").ts_nocheck);
+        assert!(!parse("'use strict';\n// @ts-nocheck").ts_nocheck);
+
+        assert_eq!(parse("/* @sourceType script */").module_kind, Some(ModuleKind::Script));
+        assert_eq!(
+            parse("// @sourceType module\n// @sourceType script").module_kind,
+            Some(ModuleKind::Module)
+        );
+        assert_eq!(parse("// @sourceType unambiguous").module_kind, None);
+    }
+
+    #[test]
+    fn source_type() {
+        let source_type = SourceType::default().with_module(true);
+        let script = FileDirectives::parse("// @sourceType script").source_type(source_type);
+        assert!(script.is_script());
+        let module =
+            FileDirectives::parse("// @sourceType module").source_type(SourceType::default());
+        assert!(module.is_module());
+        assert_eq!(FileDirectives::default().source_type(source_type), source_type);
+    }
+}
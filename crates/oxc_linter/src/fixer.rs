@@ -1,21 +1,57 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt, path::Path};
 
-use oxc_diagnostics::Error;
+use oxc_diagnostics::{
+    miette::{Diagnostic, LabeledSpan, SourceCode},
+    Error, Severity,
+};
 use oxc_span::Span;
+use similar::TextDiff;
+
+use crate::rule::RuleId;
+
+/// How safe a fix is to apply automatically.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum FixKind {
+    /// Does not change the behavior of the code, applied with `--fix`
+    #[default]
+    Safe,
+    /// Likely what the user wants but may change the behavior of the code,
+    /// applied with `--fix-suggestions`
+    Suggestion,
+    /// Aggressive rewrites that may break the code, applied with `--fix-dangerously`
+    Unsafe,
+}
+
+impl FixKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Safe => "safe",
+            Self::Suggestion => "suggestion",
+            Self::Unsafe => "unsafe",
+        }
+    }
+}
 
 #[derive(Debug, Default)]
 pub struct Fix<'a> {
     pub content: Cow<'a, str>,
     pub span: Span,
+    pub kind: FixKind,
 }
 
 impl<'a> Fix<'a> {
     pub const fn delete(span: Span) -> Self {
-        Self { content: Cow::Borrowed(""), span }
+        Self { content: Cow::Borrowed(""), span, kind: FixKind::Safe }
     }
 
     pub fn new<T: Into<Cow<'a, str>>>(content: T, span: Span) -> Self {
-        Self { content: content.into(), span }
+        Self { content: content.into(), span, kind: FixKind::Safe }
+    }
+
+    #[must_use]
+    pub fn with_kind(mut self, kind: FixKind) -> Self {
+        self.kind = kind;
+        self
     }
 }
 
@@ -26,12 +62,40 @@ pub struct FixResult<'a> {
     pub messages: Vec<Message<'a>>,
 }
 
+impl<'a> FixResult<'a> {
+    /// Unified diff from `source_text` to the fixed code, `None` when nothing was fixed.
+    pub fn unified_diff(&self, source_text: &str, path: &Path) -> Option<String> {
+        if !self.fixed {
+            return None;
+        }
+        unified_diff(source_text, &self.fixed_code, path)
+    }
+}
+
+/// Unified diff from `source_text` to `fixed_code`, `None` when they are the same.
+pub fn unified_diff(source_text: &str, fixed_code: &str, path: &Path) -> Option<String> {
+    if source_text == fixed_code {
+        return None;
+    }
+    let path = path.to_string_lossy();
+    let diff = TextDiff::from_lines(source_text, fixed_code)
+        .unified_diff()
+        .header(&format!("a/{path}"), &format!("b/{path}"))
+        .to_string();
+    Some(diff)
+}
+
 #[derive(Debug)]
 pub struct Message<'a> {
     pub error: Error,
     start: u32,
     end: u32,
     pub fix: Option<Fix<'a>>,
+    /// The rule that reported the diagnostic, `None` for parser and semantic errors
+    pub rule: Option<RuleId>,
+    /// The severity declared by the rule, or warning for rules enabled as warnings.
+    /// `None` for parser and semantic errors, which report their own severity.
+    pub severity: Option<Severity>,
     fixed: bool,
 }
 
@@ -45,7 +109,15 @@ impl<'a> Message<'a> {
             .iter()
             .max_by_key(|span| span.offset() + span.len())
             .map_or(0, |span| (span.offset() + span.len()) as u32);
-        Self { error, start, end, fix, fixed: false }
+        Self { error, start, end, fix, rule: None, severity: None, fixed: false }
+    }
+
+    /// The diagnostic for reporting, with the rule as its code and the severity of the message.
+    pub fn into_error(self) -> Error {
+        match (self.rule, self.severity) {
+            (None, None) => self.error,
+            (rule, severity) => Error::new(RuleDiagnostic { rule, severity, error: self.error }),
+        }
     }
 
     pub fn start(&self) -> u32 {
@@ -57,6 +129,48 @@ impl<'a> Message<'a> {
     }
 }
 
+/// A diagnostic reported by a rule, see [Message::into_error]
+#[derive(Debug)]
+struct RuleDiagnostic {
+    rule: Option<RuleId>,
+    severity: Option<Severity>,
+    error: Error,
+}
+
+impl fmt::Display for RuleDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl std::error::Error for RuleDiagnostic {}
+
+impl Diagnostic for RuleDiagnostic {
+    fn code<'b>(&'b self) -> Option<Box<dyn fmt::Display + 'b>> {
+        self.rule.map(|rule| Box::new(rule) as Box<dyn fmt::Display>)
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.severity.or_else(|| self.error.severity())
+    }
+
+    fn help<'b>(&'b self) -> Option<Box<dyn fmt::Display + 'b>> {
+        self.error.help()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.error.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.error.labels()
+    }
+
+    fn related<'b>(&'b self) -> Option<Box<dyn Iterator<Item = &'b dyn Diagnostic> + 'b>> {
+        self.error.related()
+    }
+}
+
 /// The fixer of the code.
 /// Note that our parser has handled the BOM, so we don't need to port the BOM test cases from `ESLint`.
 pub struct Fixer<'a> {
@@ -81,16 +195,30 @@ impl<'a> Fixer<'a> {
         }
 
         self.messages.sort_by_key(|m| m.fix.as_ref().unwrap_or(&Fix::default()).span);
+        let hashbang_end = hashbang_end(source_text);
         let mut fixed = false;
         let mut output = String::with_capacity(source_text.len());
         let mut last_pos: i64 = -1;
         self.messages.iter_mut().filter(|m| m.fix.is_some()).for_each(|m| {
-            let Fix { content, span } = m.fix.as_ref().unwrap();
-            let start = span.start;
-            let end = span.end;
-            if start > end {
+            let Fix { content, span, .. } = m.fix.as_ref().unwrap();
+            let mut start = span.start;
+            let mut end = span.end;
+            // A reversed span, or one splitting a multi-byte character, would corrupt the code.
+            if start > end
+                || !source_text.is_char_boundary(start as usize)
+                || !source_text.is_char_boundary(end as usize)
+            {
                 return;
             }
+            // Code inserted at the start of the file goes after the hashbang,
+            // and the hashbang itself is never changed.
+            if start < hashbang_end {
+                if start != end {
+                    return;
+                }
+                start = hashbang_end;
+                end = hashbang_end;
+            }
             if i64::from(start) <= last_pos {
                 return;
             }
@@ -112,46 +240,68 @@ impl<'a> Fixer<'a> {
     }
 }
 
+/// End of the hashbang line including its line terminator, `0` when there is no hashbang.
+#[allow(clippy::cast_possible_truncation)] // for `as u32`
+fn hashbang_end(source_text: &str) -> u32 {
+    let start = if source_text.starts_with('\u{feff}') { '\u{feff}'.len_utf8() } else { 0 };
+    let text = &source_text[start..];
+    if !text.starts_with("#!") {
+        return 0;
+    }
+    let line_end = text.find(['\n', '\r', '\u{2028}', '\u{2029}']).unwrap_or(text.len());
+    let rest = &text[line_end..];
+    let terminator =
+        if rest.starts_with("\r\n") { 2 } else { rest.chars().next().map_or(0, char::len_utf8) };
+    (start + line_end + terminator) as u32
+}
+
 #[cfg(test)]
 mod test {
-    use std::borrow::Cow;
+    use std::{borrow::Cow, path::Path};
 
     use miette::{self, Diagnostic};
-    use oxc_diagnostics::{thiserror::Error, Error};
+    use oxc_diagnostics::{thiserror::Error, Error, Severity};
     use oxc_span::Span;
 
-    use super::{Fix, FixResult, Fixer, Message};
+    use super::{Fix, FixKind, FixResult, Fixer, Message};
+    use crate::rule::RuleId;
 
     const TEST_CODE: &str = "var answer = 6 * 7;";
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("End")]
     struct InsertAtEnd;
-    const INSERT_AT_END: Fix = Fix { span: Span::new(19, 19), content: Cow::Borrowed("// end") };
+    const INSERT_AT_END: Fix =
+        Fix { span: Span::new(19, 19), content: Cow::Borrowed("// end"), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("Start")]
     struct InsertAtStart;
-    const INSERT_AT_START: Fix = Fix { span: Span::new(0, 0), content: Cow::Borrowed("// start") };
+    const INSERT_AT_START: Fix =
+        Fix { span: Span::new(0, 0), content: Cow::Borrowed("// start"), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("Multiply")]
     struct InsertAtMiddle;
-    const INSERT_AT_MIDDLE: Fix = Fix { span: Span::new(13, 13), content: Cow::Borrowed("5 *") };
+    const INSERT_AT_MIDDLE: Fix =
+        Fix { span: Span::new(13, 13), content: Cow::Borrowed("5 *"), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("foo")]
     struct ReplaceId;
-    const REPLACE_ID: Fix = Fix { span: Span::new(4, 10), content: Cow::Borrowed("foo") };
+    const REPLACE_ID: Fix =
+        Fix { span: Span::new(4, 10), content: Cow::Borrowed("foo"), kind: FixKind::Safe };
     #[derive(Debug, Error, Diagnostic)]
     #[error("let")]
     struct ReplaceVar;
-    const REPLACE_VAR: Fix = Fix { span: Span::new(0, 3), content: Cow::Borrowed("let") };
+    const REPLACE_VAR: Fix =
+        Fix { span: Span::new(0, 3), content: Cow::Borrowed("let"), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("5")]
     struct ReplaceNum;
-    const REPLACE_NUM: Fix = Fix { span: Span::new(13, 14), content: Cow::Borrowed("5") };
+    const REPLACE_NUM: Fix =
+        Fix { span: Span::new(13, 14), content: Cow::Borrowed("5"), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("removestart")]
@@ -171,7 +321,8 @@ mod test {
     #[derive(Debug, Error, Diagnostic)]
     #[error("reversed range")]
     struct ReverseRange;
-    const REVERSE_RANGE: Fix = Fix { span: Span::new(3, 0), content: Cow::Borrowed(" ") };
+    const REVERSE_RANGE: Fix =
+        Fix { span: Span::new(3, 0), content: Cow::Borrowed(" "), kind: FixKind::Safe };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("nofix")]
@@ -237,12 +388,54 @@ mod test {
         assert_eq!(result.messages.len(), 0);
     }
 
+    #[test]
+    fn insert_at_the_start_after_hashbang() {
+        for (source_text, expected) in [
+            ("#!/usr/bin/env node\nfoo", "#!/usr/bin/env node\n// startfoo"),
+            ("#!/usr/bin/env node\r\nfoo", "#!/usr/bin/env node\r\n// startfoo"),
+            ("\u{feff}#!/usr/bin/env node\nfoo", "\u{feff}#!/usr/bin/env node\n// startfoo"),
+        ] {
+            let messages = vec![create_message(InsertAtStart, Some(INSERT_AT_START))];
+            let result = Fixer::new(source_text, messages).fix();
+            assert_eq!(result.fixed_code, expected);
+            assert_eq!(result.messages.len(), 0);
+        }
+    }
+
+    #[test]
+    fn ignore_fix_in_hashbang() {
+        let source_text = "#!/usr/bin/env node\nvar answer = 6 * 7;";
+        let messages = vec![create_message(RemoveStart, Some(REMOVE_START))];
+        let result = Fixer::new(source_text, messages).fix();
+        assert_eq!(result.fixed_code, source_text);
+        assert_eq!(result.messages.len(), 1);
+        assert!(!result.fixed);
+    }
+
     #[test]
     fn ignore_reverse_range() {
         let result = get_fix_result(vec![create_message(ReverseRange, Some(REVERSE_RANGE))]);
         assert_eq!(result.fixed_code, TEST_CODE);
     }
 
+    #[test]
+    fn ignore_fix_inside_of_a_character() {
+        // `é` and `👍` are 2 and 4 bytes long
+        let source_text = "var é = '👍';";
+        let fix = Fix { span: Span::new(5, 7), content: Cow::Borrowed("e"), kind: FixKind::Safe };
+        let result = Fixer::new(source_text, vec![create_message(ReplaceId, Some(fix))]).fix();
+        assert_eq!(result.fixed_code, source_text);
+        assert!(!result.fixed);
+
+        let fix = Fix { span: Span::new(4, 6), content: Cow::Borrowed("e"), kind: FixKind::Safe };
+        let messages = vec![
+            create_message(ReplaceId, Some(fix)),
+            create_message(InsertAtEnd, Some(Fix::delete(Span::new(10, 14)))),
+        ];
+        let result = Fixer::new(source_text, messages).fix();
+        assert_eq!(result.fixed_code, "var e = '';");
+    }
+
     #[test]
     fn replace_at_the_start() {
         let result = get_fix_result(vec![create_message(ReplaceVar, Some(REPLACE_VAR))]);
@@ -392,4 +585,41 @@ mod test {
         assert_eq!(result.messages[1].error.to_string(), "nofix2");
         assert!(result.fixed);
     }
+
+    #[test]
+    fn unified_diff() {
+        let result = get_fix_result(vec![create_message(ReplaceId, Some(REPLACE_ID))]);
+        let diff = result.unified_diff(TEST_CODE, Path::new("test.js")).unwrap();
+        assert_eq!(
+            diff,
+            "--- a/test.js\n+++ b/test.js\n@@ -1 +1 @@\n-var answer = 6 * 7;\n\\ No newline at end of file\n+var foo = 6 * 7;\n\\ No newline at end of file\n"
+        );
+    }
+
+    #[test]
+    fn unified_diff_without_fixes() {
+        let result = get_fix_result(vec![create_message(NoFix(Span::default()), None)]);
+        assert!(result.unified_diff(TEST_CODE, Path::new("test.js")).is_none());
+    }
+
+    #[test]
+    fn into_error() {
+        let message = create_message(NoFix1(Span::new(1, 3)), None);
+        let error = message.into_error();
+        assert!(error.code().is_none());
+
+        let mut message = create_message(NoFix1(Span::new(1, 3)), None);
+        message.rule = Some(RuleId::new("eslint", "no-debugger"));
+        let error = message.into_error();
+        assert_eq!(error.to_string(), "nofix1");
+        assert_eq!(error.code().unwrap().to_string(), "eslint/no-debugger");
+        assert!(error.url().is_none());
+        assert_eq!(error.labels().unwrap().next().unwrap().offset(), 1);
+
+        let mut message = create_message(NoFix1(Span::new(1, 3)), None);
+        message.severity = Some(Severity::Warning);
+        let error = message.into_error();
+        assert!(error.code().is_none());
+        assert_eq!(error.severity(), Some(Severity::Warning));
+    }
 }
@@ -3,36 +3,63 @@
 #[cfg(test)]
 mod tester;
 
+mod aria;
 mod ast_util;
+mod cancellation;
+mod config;
+mod constant_evaluation;
 mod context;
 mod disable_directives;
+mod file_directives;
 mod fixer;
 mod globals;
 mod jest_ast_util;
+mod lint_diagnostic;
+mod module_cache;
 mod options;
+mod partial_loader;
 pub mod rule;
 mod rule_timer;
 mod rules;
 mod service;
+mod source_file;
+mod type_info;
 
-use std::{self, fs, io::Write, rc::Rc, time::Duration};
+use std::{self, ffi::OsStr, fs, io::Write, path::Path, rc::Rc, sync::Arc, time::Duration};
 
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+use oxc_diagnostics::Severity;
 pub(crate) use oxc_semantic::AstNode;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 pub use crate::{
+    cancellation::CancellationToken,
+    config::{GlobalValue, LintConfig},
     context::LintContext,
-    fixer::Fix,
+    fixer::{Fix, FixKind},
     fixer::{FixResult, Fixer, Message},
+    lint_diagnostic::{LintDiagnostic, LintFix},
     options::{AllowWarnDeny, LintOptions},
-    rule::RuleCategory,
+    partial_loader::LINT_PARTIAL_LOADER_EXT,
+    rule::{RuleCapabilities, RuleCategory, RuleId},
     service::LintService,
+    type_info::{TscTypeInfoProvider, TypeInfoProvider},
 };
+use file_directives::FileDirectives;
+use partial_loader::PartialLoader;
 pub(crate) use rules::{RuleEnum, RULES};
 
 #[derive(Debug)]
 pub struct Linter {
     rules: Vec<RuleEnum>,
+    /// Rules enabled with [`AllowWarnDeny::Warn`], whose diagnostics are reported as warnings
+    warnings: FxHashSet<&'static str>,
+    /// The `globals` of the config file, shared by the contexts of all files
+    globals: Arc<FxHashMap<String, GlobalValue>>,
     options: LintOptions,
 }
 
@@ -41,14 +68,32 @@ impl Linter {
         let rules = RULES
             .iter()
             .cloned()
-            .filter(|rule| rule.category() == RuleCategory::Correctness)
+            .filter(|rule| RuleCategory::DEFAULT.contains(&rule.category()))
             .collect::<Vec<_>>();
-        Self { rules, options: LintOptions::default() }
+        Self {
+            rules,
+            warnings: FxHashSet::default(),
+            globals: Arc::default(),
+            options: LintOptions::default(),
+        }
     }
 
     pub fn from_options(options: LintOptions) -> Self {
-        let rules = options.derive_rules();
-        Self { rules, options }
+        let (rules, warnings) = options.derive_rules().into_iter().fold(
+            (vec![], FxHashSet::default()),
+            |(mut rules, mut warnings), (rule, allow_warn_deny)| {
+                if allow_warn_deny == AllowWarnDeny::Warn {
+                    warnings.insert(rule.name());
+                }
+                rules.push(rule);
+                (rules, warnings)
+            },
+        );
+        tracing::debug!(rules = rules.len(), warnings = warnings.len(), "enabled rules");
+        let globals = Arc::new(
+            options.config.as_ref().map(|config| config.globals.clone()).unwrap_or_default(),
+        );
+        Self { rules, warnings, globals, options }
     }
 
     #[must_use]
@@ -61,6 +106,13 @@ impl Linter {
         &self.rules
     }
 
+    /// What the enabled rules need from the dependencies of the linted files
+    pub fn capabilities(&self) -> RuleCapabilities {
+        self.rules.iter().fold(RuleCapabilities::empty(), |capabilities, rule| {
+            capabilities | rule.capabilities()
+        })
+    }
+
     pub fn options(&self) -> &LintOptions {
         &self.options
     }
@@ -69,6 +121,14 @@ impl Linter {
         self.rules.len()
     }
 
+    /// The enabled rules that provide fixes
+    pub fn fixable_rules(&self) -> impl Iterator<Item = RuleId> + '_ {
+        self.rules
+            .iter()
+            .filter(|rule| rule.fix().is_some())
+            .map(|rule| RuleId::new(rule.plugin_name(), rule.name()))
+    }
+
     #[must_use]
     pub fn with_fix(mut self, yes: bool) -> Self {
         self.options.fix = yes;
@@ -82,30 +142,114 @@ impl Linter {
     }
 
     pub fn run<'a>(&self, ctx: LintContext<'a>) -> Vec<Message<'a>> {
+        if self.rules.is_empty() {
+            return vec![];
+        }
+
         let timing = self.options.timing;
         let semantic = Rc::clone(ctx.semantic());
-        let mut ctx = ctx.with_fix(self.options.fix);
+        let mut ctx = ctx
+            .with_fix(self.options.fix || self.options.fix_dry_run)
+            .with_globals(Arc::clone(&self.globals));
 
         for rule in &self.rules {
-            ctx.with_rule_name(rule.name());
+            ctx.with_rule(RuleId::new(rule.plugin_name(), rule.name()), Some(rule.severity()));
             rule.run_once(&ctx, timing);
         }
 
         for node in semantic.nodes().iter() {
             for rule in &self.rules {
-                ctx.with_rule_name(rule.name());
+                ctx.with_rule(RuleId::new(rule.plugin_name(), rule.name()), Some(rule.severity()));
                 rule.run(node, &ctx, timing);
             }
         }
 
         for symbol in semantic.symbols().iter() {
             for rule in &self.rules {
-                ctx.with_rule_name(rule.name());
+                ctx.with_rule(RuleId::new(rule.plugin_name(), rule.name()), Some(rule.severity()));
                 rule.run_on_symbol(symbol, &ctx, timing);
             }
         }
 
-        ctx.into_message()
+        let mut messages = ctx.into_message();
+        for message in &mut messages {
+            // Fixes of disallowed kinds are reported as unfixed diagnostics.
+            if message.fix.as_ref().is_some_and(|fix| !self.options.allows_fix_kind(fix.kind)) {
+                message.fix = None;
+            }
+            if message.rule.is_some_and(|rule| self.warnings.contains(rule.name)) {
+                message.severity = Some(Severity::Warning);
+            }
+        }
+        messages
+    }
+
+    /// Lint `source_text` as the file at `path`, e.g. the unsaved content of an editor,
+    /// without reading files or setting up a [LintService] and its diagnostic channel.
+    ///
+    /// The import plugin does not resolve the dependencies of the file,
+    /// and fixes are only computed with [`LintOptions::with_fix`].
+    pub fn lint_source(
+        path: &Path,
+        source_text: &str,
+        options: LintOptions,
+    ) -> Vec<LintDiagnostic> {
+        let linter = Self::from_options(options);
+        let ext = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+        let lint_as = linter.options.resolve_extension(ext);
+        let source_type = if lint_as == ext {
+            SourceType::from_path(path)
+        } else {
+            SourceType::from_path(path.with_extension(lint_as))
+        };
+        source_type.map_or_else(
+            // Sources embedded in the file are padded, so spans point into the original file.
+            |_| {
+                PartialLoader::parse(lint_as, source_text)
+                    .unwrap_or_default()
+                    .iter()
+                    .flat_map(|source| {
+                        let padded_source_text = source.padded_source_text(source_text);
+                        linter.lint_source_text(path, &padded_source_text, source.source_type)
+                    })
+                    .collect()
+            },
+            |source_type| linter.lint_source_text(path, source_text, source_type),
+        )
+    }
+
+    fn lint_source_text(
+        &self,
+        path: &Path,
+        source_text: &str,
+        source_type: SourceType,
+    ) -> Vec<LintDiagnostic> {
+        let file_directives = FileDirectives::parse(source_text);
+        let source_type = file_directives.source_type(source_type);
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type)
+            .allow_return_outside_function(true)
+            .allow_legacy_decorators(true)
+            .parse();
+        let messages = if ret.errors.is_empty() {
+            let program = allocator.alloc(ret.program);
+            let semantic_ret = SemanticBuilder::new(source_text, source_type)
+                .with_trivias(ret.trivias)
+                .with_check_syntax_error(true)
+                .build_module_record(path.to_path_buf(), program)
+                .build(program);
+            if semantic_ret.errors.is_empty() {
+                let ctx = LintContext::new(&Rc::new(semantic_ret.semantic))
+                    .with_file_path(path)
+                    .with_file_directives(file_directives);
+                self.run(ctx)
+            } else {
+                semantic_ret.errors.into_iter().map(|err| Message::new(err, None)).collect()
+            }
+        } else {
+            ret.errors.into_iter().map(|err| Message::new(err, None)).collect()
+        };
+        messages.into_iter().map(LintDiagnostic::new).collect()
     }
 
     #[allow(unused)]
@@ -126,12 +270,116 @@ impl Linter {
         for (category, rules) in rules_by_category {
             writeln!(writer, "{} ({}):", category, rules.len()).unwrap();
             for rule in rules {
-                writeln!(writer, "• {}/{}", rule.plugin_name(), rule.name()).unwrap();
+                let fix = rule.fix().map(|fix| format!(" ({})", fix.as_str())).unwrap_or_default();
+                writeln!(writer, "• {}/{}{fix}", rule.plugin_name(), rule.name()).unwrap();
             }
         }
         writeln!(writer, "Total: {}", RULES.len()).unwrap();
     }
 
+    /// Print the documentation of all rules as Markdown, used for generating the website.
+    ///
+    /// # Panics
+    /// When writing to `writer` fails.
+    pub fn print_rules_markdown<W: Write>(writer: &mut W) {
+        writeln!(writer, "# Rules").unwrap();
+        for rule in Self::sorted_rules() {
+            writeln!(writer, "\n## {}/{}\n", rule.plugin_name(), rule.name()).unwrap();
+            let category = rule.category().as_str();
+            let fix = rule.fix().map_or("none", FixKind::as_str);
+            let severity = severity_name(rule.severity());
+            writeln!(writer, "Category: `{category}`, Fix: `{fix}`, Severity: `{severity}`")
+                .unwrap();
+            if let Some(documentation) = rule.documentation() {
+                write!(writer, "\n{}", documentation.trim_end()).unwrap();
+                writeln!(writer).unwrap();
+            }
+            if let Some(schema) = schema(rule) {
+                let schema = serde_json::to_string_pretty(&schema).unwrap();
+                writeln!(writer, "\n### Options\n\n```json\n{schema}\n```").unwrap();
+            }
+        }
+    }
+
+    /// Print the metadata and documentation of all rules as a JSON array, used by editors.
+    ///
+    /// # Panics
+    /// When writing to `writer` fails.
+    pub fn print_rules_json<W: Write>(writer: &mut W) {
+        let rules = Self::sorted_rules()
+            .into_iter()
+            .map(|rule| {
+                serde_json::json!({
+                    "name": rule.name(),
+                    "plugin": rule.plugin_name(),
+                    "category": rule.category().as_str(),
+                    "fix": rule.fix().map(FixKind::as_str),
+                    "severity": severity_name(rule.severity()),
+                    "schema": schema(rule),
+                    "documentation": rule.documentation(),
+                })
+            })
+            .collect::<Vec<_>>();
+        serde_json::to_writer_pretty(&mut *writer, &rules).unwrap();
+        writeln!(writer).unwrap();
+    }
+
+    /// Print the configuration that applies to `path` as JSON, for debugging configurations.
+    ///
+    /// The rules, their options and the globals are those of this linter, e.g. one built with
+    /// the config file of the workspace package containing `path`; only `lintAs`, the extension
+    /// `path` is linted as, depends on `path` itself.
+    ///
+    /// # Panics
+    /// When writing to `writer` fails.
+    pub fn print_config<W: Write>(&self, writer: &mut W, path: &Path) {
+        let config = self.options.config.as_ref();
+        let rules = self
+            .rules
+            .iter()
+            .map(|rule| {
+                let allow_warn_deny = if self.warnings.contains(rule.name()) {
+                    AllowWarnDeny::Warn
+                } else {
+                    AllowWarnDeny::Deny
+                };
+                let level = serde_json::Value::from(allow_warn_deny.as_str());
+                // `[level, ...options]` as in the config file
+                let value = match config.and_then(|config| config.rule_options.get(rule.name())) {
+                    Some(serde_json::Value::Array(options)) => {
+                        std::iter::once(level).chain(options.iter().cloned()).collect()
+                    }
+                    _ => level,
+                };
+                (format!("{}/{}", rule.plugin_name(), rule.name()), value)
+            })
+            .collect::<serde_json::Map<_, _>>();
+        let globals = self
+            .globals
+            .iter()
+            .map(|(name, value)| (name.clone(), value.as_str().into()))
+            .collect::<serde_json::Map<_, _>>();
+        let lint_as =
+            path.extension().and_then(OsStr::to_str).map(|ext| self.options.resolve_extension(ext));
+        let config = serde_json::json!({
+            "path": path.to_string_lossy(),
+            "lintAs": lint_as,
+            "rules": rules,
+            "globals": globals,
+            "fix": self.options.fix,
+            "importPlugin": self.options.import_plugin,
+            "typeAware": self.options.type_aware,
+        });
+        serde_json::to_writer_pretty(&mut *writer, &config).unwrap();
+        writeln!(writer).unwrap();
+    }
+
+    fn sorted_rules() -> Vec<&'static RuleEnum> {
+        let mut rules = RULES.iter().collect::<Vec<_>>();
+        rules.sort_by_key(|rule| (rule.plugin_name(), rule.name()));
+        rules
+    }
+
     pub fn print_execution_times_if_enable(&self) {
         if !self.options.timing {
             return;
@@ -153,9 +401,27 @@ impl Linter {
     }
 }
 
+/// The options schema of `rule`, validated by `rule::test::ensure_schema`
+fn schema(rule: &RuleEnum) -> Option<serde_json::Value> {
+    rule.schema().map(|schema| serde_json::from_str(schema).unwrap())
+}
+
+fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Advice => "advice",
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::Linter;
+    use std::path::Path;
+
+    use rustc_hash::FxHashMap;
+    use serde_json::json;
+
+    use super::{AllowWarnDeny, FixKind, LintConfig, LintOptions, Linter, RuleCapabilities, RULES};
 
     #[test]
     fn print_rules() {
@@ -163,4 +429,175 @@ mod test {
         Linter::print_rules(&mut writer);
         assert!(!writer.is_empty());
     }
+
+    #[test]
+    fn print_config() {
+        let options = LintOptions::default()
+            .with_filter(vec![
+                (AllowWarnDeny::Deny, "no-debugger".into()),
+                (AllowWarnDeny::Warn, "no-empty".into()),
+            ])
+            .with_import_plugin(true);
+        let mut writer = Vec::new();
+        Linter::from_options(options).print_config(&mut writer, Path::new("foo.ts"));
+        let config: serde_json::Value = serde_json::from_slice(&writer).unwrap();
+        assert_eq!(
+            config,
+            serde_json::json!({
+                "path": "foo.ts",
+                "lintAs": "ts",
+                "rules": { "eslint/no-debugger": "deny", "eslint/no-empty": "warn" },
+                "globals": {},
+                "fix": false,
+                "importPlugin": true,
+                "typeAware": false,
+            })
+        );
+    }
+
+    #[test]
+    fn print_config_with_config_file() {
+        let config = LintConfig::from_file(Path::new("fixtures/config/oxlintrc.json")).unwrap();
+
+        let options = LintOptions::default()
+            .with_config(Some(config))
+            .with_extension_aliases(vec![("mts".into(), "ts".into())]);
+        let mut writer = Vec::new();
+        Linter::from_options(options).print_config(&mut writer, Path::new("src/foo.mts"));
+        let config: serde_json::Value = serde_json::from_slice(&writer).unwrap();
+        assert_eq!(
+            config,
+            serde_json::json!({
+                "path": "src/foo.mts",
+                "lintAs": "ts",
+                "rules": { "eslint/eq-eq-eq": ["deny", "smart"], "eslint/no-empty": "warn" },
+                "globals": { "jQuery": "readonly", "Promise": "off" },
+                "fix": false,
+                "importPlugin": false,
+                "typeAware": false,
+            })
+        );
+    }
+
+    #[test]
+    fn globals() {
+        let config = LintConfig::from_value(&json!({
+            "categories": { "all": "off" },
+            "rules": { "no-undef": "deny", "no-global-assign": "deny" },
+            "globals": { "jQuery": "readonly", "app": "writable", "Promise": "off" }
+        }))
+        .unwrap();
+        let lint = |source_text: &str| {
+            let options = LintOptions::default().with_config(Some(config.clone()));
+            Linter::lint_source(Path::new("foo.js"), source_text, options)
+                .into_iter()
+                .map(|diagnostic| diagnostic.rule.unwrap().name)
+                .collect::<Vec<_>>()
+        };
+        assert!(lint("jQuery; app = 1;").is_empty());
+        assert_eq!(lint("jQuery = 1;"), vec!["no-global-assign"]);
+        // Built-in globals can be turned off
+        assert_eq!(lint("Promise;"), vec!["no-undef"]);
+        assert!(lint("Array;").is_empty());
+    }
+
+    #[test]
+    fn capabilities() {
+        let capabilities = |filter: &[&str]| {
+            let filter =
+                filter.iter().map(|rule| (AllowWarnDeny::Deny, (*rule).to_string())).collect();
+            Linter::from_options(LintOptions::default().with_filter(filter)).capabilities()
+        };
+        assert_eq!(capabilities(&["no-debugger"]), RuleCapabilities::empty());
+        assert_eq!(capabilities(&["no-self-import"]), RuleCapabilities::MODULE_GRAPH);
+        assert_eq!(
+            capabilities(&["no-self-import", "named"]),
+            RuleCapabilities::DEPENDENCY_EXPORTS
+        );
+        assert!(capabilities(&["named"]).contains(RuleCapabilities::MODULE_GRAPH));
+    }
+
+    #[test]
+    fn derive_rules() {
+        let levels = |options: LintOptions| {
+            options
+                .derive_rules()
+                .into_iter()
+                .map(|(rule, allow_warn_deny)| (rule.name(), allow_warn_deny))
+                .collect::<FxHashMap<_, _>>()
+        };
+
+        // The default categories
+        let rules = levels(LintOptions::default());
+        assert_eq!(rules.get("no-debugger"), Some(&AllowWarnDeny::Deny));
+        assert_eq!(rules.get("no-return-await"), None);
+
+        // The command line replaces the default categories
+        let rules =
+            levels(LintOptions::default().with_filter(vec![(AllowWarnDeny::Warn, "perf".into())]));
+        assert_eq!(rules.get("no-debugger"), None);
+        assert_eq!(rules.get("no-return-await"), Some(&AllowWarnDeny::Warn));
+
+        // The config file is applied on top of the default categories, the command line after it
+        let config = LintConfig::from_value(&json!({
+            "categories": { "correctness": "warn" },
+            "rules": { "no-debugger": "off", "eq-eq-eq": ["deny", "smart"] }
+        }))
+        .unwrap();
+        let rules = levels(LintOptions::default().with_config(Some(config.clone())));
+        assert_eq!(rules.get("use-isnan"), Some(&AllowWarnDeny::Warn));
+        assert_eq!(rules.get("no-debugger"), None);
+        assert_eq!(rules.get("eq-eq-eq"), Some(&AllowWarnDeny::Deny));
+
+        let rules = levels(
+            LintOptions::default()
+                .with_config(Some(config))
+                .with_filter(vec![(AllowWarnDeny::Deny, "no-debugger".into())]),
+        );
+        assert_eq!(rules.get("use-isnan"), Some(&AllowWarnDeny::Warn));
+        assert_eq!(rules.get("no-debugger"), Some(&AllowWarnDeny::Deny));
+    }
+
+    #[test]
+    fn print_rules_markdown() {
+        let mut writer = Vec::new();
+        Linter::print_rules_markdown(&mut writer);
+        let markdown = String::from_utf8(writer).unwrap();
+        assert!(markdown.contains(
+            "## eslint/no-debugger\n\nCategory: `correctness`, Fix: `safe`, Severity: `warning`\n"
+        ));
+        assert!(markdown.contains("### Options\n\n```json\n[\n  {\n    \"type\": \"object\""));
+    }
+
+    #[test]
+    fn print_rules_json() {
+        let mut writer = Vec::new();
+        Linter::print_rules_json(&mut writer);
+        let rules: Vec<serde_json::Value> = serde_json::from_slice(&writer).unwrap();
+        assert_eq!(rules.len(), RULES.len());
+        let no_debugger = rules.iter().find(|rule| rule["name"] == "no-debugger").unwrap();
+        assert_eq!(no_debugger["plugin"], "eslint");
+        assert_eq!(no_debugger["category"], "correctness");
+        assert_eq!(no_debugger["fix"], "safe");
+        assert_eq!(no_debugger["severity"], "warning");
+        assert!(no_debugger["schema"].is_null());
+        assert!(no_debugger["documentation"].is_string());
+        let no_empty = rules.iter().find(|rule| rule["name"] == "no-empty").unwrap();
+        assert_eq!(no_empty["schema"][0]["properties"]["allowEmptyCatch"]["type"], "boolean");
+    }
+
+    #[test]
+    fn allows_fix_kind() {
+        let options = LintOptions::default().with_fix(true);
+        assert!(options.allows_fix_kind(FixKind::Safe));
+        assert!(!options.allows_fix_kind(FixKind::Suggestion));
+        assert!(!options.allows_fix_kind(FixKind::Unsafe));
+
+        let options = options.with_fix_suggestions(true);
+        assert!(options.allows_fix_kind(FixKind::Suggestion));
+        assert!(!options.allows_fix_kind(FixKind::Unsafe));
+
+        let options = options.with_fix_dangerously(true);
+        assert!(options.allows_fix_kind(FixKind::Unsafe));
+    }
 }
@@ -0,0 +1,136 @@
+use oxc_diagnostics::Severity;
+use oxc_span::Span;
+
+use crate::{FixKind, Message, RuleId};
+
+/// A diagnostic of [crate::Linter::lint_source], owning its message and fix,
+/// for library consumers which do not render diagnostics with `DiagnosticService`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintDiagnostic {
+    /// The rule that reported the diagnostic, `None` for syntax errors
+    pub rule: Option<RuleId>,
+    pub severity: Severity,
+    /// The message without the `plugin(rule): ` prefix, e.g. "`debugger` statement is not allowed"
+    pub message: String,
+    pub help: Option<String>,
+    /// From the start of the first label to the end of the last label
+    pub span: Span,
+    pub fix: Option<LintFix>,
+}
+
+/// Replace `span` with `content` to fix a [LintDiagnostic]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintFix {
+    pub kind: FixKind,
+    pub span: Span,
+    pub content: String,
+}
+
+impl LintDiagnostic {
+    pub(crate) fn new(message: Message) -> Self {
+        let error = &message.error;
+        let severity = message.severity.or_else(|| error.severity()).unwrap_or(Severity::Error);
+        let text = error.to_string();
+        let text = message
+            .rule
+            .and_then(|rule| {
+                let (prefix, text) = text.split_once(&format!("({}): ", rule.name))?;
+                (!prefix.contains(' ')).then(|| text.to_string())
+            })
+            .unwrap_or(text);
+        Self {
+            rule: message.rule,
+            severity,
+            message: text,
+            help: error.help().map(|help| help.to_string()),
+            span: Span::new(message.start(), message.end()),
+            fix: message.fix.map(|fix| LintFix {
+                kind: fix.kind,
+                span: fix.span,
+                content: fix.content.into_owned(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_diagnostics::Severity;
+    use oxc_span::Span;
+
+    use super::{LintDiagnostic, LintFix};
+    use crate::{FixKind, LintOptions, Linter, RuleId};
+
+    #[test]
+    fn lint_source() {
+        let path = Path::new("foo.js");
+        let diagnostics = Linter::lint_source(path, "debugger;\n", LintOptions::default());
+        assert_eq!(
+            diagnostics,
+            vec![LintDiagnostic {
+                rule: Some(RuleId::new("eslint", "no-debugger")),
+                severity: Severity::Warning,
+                message: "`debugger` statement is not allowed".into(),
+                help: None,
+                span: Span::new(0, 9),
+                fix: None,
+            }]
+        );
+
+        // Fixes are computed with `with_fix`
+        let options = LintOptions::default().with_fix(true);
+        let diagnostics = Linter::lint_source(path, "debugger;\n", options);
+        assert_eq!(
+            diagnostics[0].fix,
+            Some(LintFix { kind: FixKind::Safe, span: Span::new(0, 9), content: String::new() })
+        );
+    }
+
+    #[test]
+    fn lint_source_syntax_error() {
+        let diagnostics = Linter::lint_source(Path::new("foo.js"), "let", LintOptions::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, None);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn lint_source_declared_severity() {
+        let source_text = "({ set a(value) { return value; } });";
+        let diagnostics =
+            Linter::lint_source(Path::new("foo.js"), source_text, LintOptions::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, Some(RuleId::new("eslint", "no-setter-return")));
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn lint_source_file_directives() {
+        // `with` statements are not allowed in modules
+        let source_text = "with (a) {}";
+        let diagnostics =
+            Linter::lint_source(Path::new("foo.mjs"), source_text, LintOptions::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, None);
+
+        let source_text = "// @sourceType script\nwith (a) {}";
+        let diagnostics =
+            Linter::lint_source(Path::new("foo.mjs"), source_text, LintOptions::default());
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn lint_source_partial() {
+        let source_text = "<template></template>\n<script>\ndebugger;\n</script>\n";
+        let diagnostics =
+            Linter::lint_source(Path::new("foo.vue"), source_text, LintOptions::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].span, Span::new(31, 40));
+
+        let diagnostics =
+            Linter::lint_source(Path::new("foo.txt"), "debugger;", LintOptions::default());
+        assert!(diagnostics.is_empty());
+    }
+}
@@ -0,0 +1,326 @@
+//! A persistent cache of the module records of dependencies, so that repeated runs do not
+//! parse unchanged dependencies, e.g. `node_modules` packages, again.
+//!
+//! Entries are keyed by path and invalidated when the modification time or the size of the file
+//! changes. A cached record has the module requests, exports and CommonJS exports of the module,
+//! which is what rules use of a dependency, but not its import entries.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
+    time::UNIX_EPOCH,
+};
+
+use dashmap::DashMap;
+use oxc_semantic::ModuleRecord;
+use oxc_span::{Atom, Span};
+use oxc_syntax::module_record::{
+    ExportEntry, ExportExportName, ExportImportName, ExportLocalName, NameSpan,
+};
+use serde_json::{json, Value};
+
+/// Bumped when the format of the cached records changes, older caches are discarded
+const VERSION: u64 = 1;
+
+pub struct ModuleCache {
+    path: PathBuf,
+    modules: DashMap<Box<Path>, CachedModule>,
+    /// Whether a module was added since the cache was read
+    changed: AtomicBool,
+}
+
+struct CachedModule {
+    /// Modification time in seconds and nanoseconds since the Unix epoch
+    modified: (u64, u32),
+    len: u64,
+    record: Value,
+}
+
+impl ModuleCache {
+    /// Read the cache at `path`, starting empty when it does not exist or is invalid.
+    pub fn new(path: PathBuf) -> Self {
+        let modules = fs::read(&path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<Value>(&bytes).ok())
+            .filter(|cache| cache["version"].as_u64() == Some(VERSION))
+            .and_then(|cache| {
+                let modules = cache["modules"].as_object()?.iter().filter_map(|(path, module)| {
+                    let modified = module["modified"].as_array()?;
+                    let module = CachedModule {
+                        modified: (
+                            modified.first()?.as_u64()?,
+                            u32::try_from(modified.get(1)?.as_u64()?).ok()?,
+                        ),
+                        len: module["len"].as_u64()?,
+                        record: module["record"].clone(),
+                    };
+                    Some((PathBuf::from(path).into_boxed_path(), module))
+                });
+                Some(modules.collect::<DashMap<_, _>>())
+            })
+            .unwrap_or_default();
+        tracing::debug!(path = %path.display(), modules = modules.len(), "read module cache");
+        Self { path, modules, changed: AtomicBool::new(false) }
+    }
+
+    /// The cached module record of `path` if the file is unchanged.
+    pub fn get(&self, path: &Path, metadata: &fs::Metadata) -> Option<ModuleRecord> {
+        let module = self.modules.get(path)?;
+        if Some(module.modified) != modified(metadata) || module.len != metadata.len() {
+            return None;
+        }
+        decode_module_record(path, &module.record)
+    }
+
+    pub fn insert(&self, path: &Path, metadata: &fs::Metadata, module_record: &ModuleRecord) {
+        let Some(modified) = modified(metadata) else { return };
+        let module = CachedModule {
+            modified,
+            len: metadata.len(),
+            record: encode_module_record(module_record),
+        };
+        self.modules.insert(path.to_path_buf().into_boxed_path(), module);
+        self.changed.store(true, Ordering::Relaxed);
+    }
+
+    /// Write the cache if a module was added.
+    ///
+    /// # Errors
+    /// When the cache file cannot be written.
+    pub fn persist(&self) -> std::io::Result<()> {
+        if !self.changed.swap(false, Ordering::Relaxed) {
+            return Ok(());
+        }
+        let modules = self
+            .modules
+            .iter()
+            .map(|entry| {
+                let module = entry.value();
+                let value = json!({
+                    "modified": [module.modified.0, module.modified.1],
+                    "len": module.len,
+                    "record": module.record,
+                });
+                (entry.key().to_string_lossy().to_string(), value)
+            })
+            .collect::<serde_json::Map<_, _>>();
+        let cache = json!({ "version": VERSION, "modules": modules });
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_vec(&cache)?)
+    }
+}
+
+fn modified(metadata: &fs::Metadata) -> Option<(u64, u32)> {
+    let modified = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+    Some((modified.as_secs(), modified.subsec_nanos()))
+}
+
+fn encode_module_record(module_record: &ModuleRecord) -> Value {
+    fn requests<'a>(requests: impl Iterator<Item = (&'a Atom, &'a Vec<Span>)>) -> Vec<Value> {
+        requests
+            .map(|(specifier, spans)| {
+                json!([
+                    specifier.as_str(),
+                    spans.iter().map(|span| encode_span(*span)).collect::<Vec<_>>()
+                ])
+            })
+            .collect()
+    }
+    let bindings = |bindings: &rustc_hash::FxHashMap<Atom, Span>| {
+        bindings
+            .iter()
+            .map(|(name, span)| encode_name_span(&NameSpan::new(name.clone(), *span)))
+            .collect::<Vec<_>>()
+    };
+    let entries =
+        |entries: &[ExportEntry]| entries.iter().map(encode_export_entry).collect::<Vec<_>>();
+    json!({
+        "requestedModules": requests(module_record.requested_modules.iter()),
+        "dynamicImports": requests(module_record.dynamic_imports.iter()),
+        "localExportEntries": entries(&module_record.local_export_entries),
+        "indirectExportEntries": entries(&module_record.indirect_export_entries),
+        "starExportEntries": entries(&module_record.star_export_entries),
+        "exportedBindings": bindings(&module_record.exported_bindings),
+        "exportDefault": module_record.export_default.map(encode_span),
+        "hasModuleSyntax": module_record.has_module_syntax,
+        "commonjsExports": bindings(&module_record.commonjs_exports),
+        "commonjsExportAssignment": module_record.commonjs_export_assignment.map(encode_span),
+    })
+}
+
+fn decode_module_record(path: &Path, value: &Value) -> Option<ModuleRecord> {
+    fn requests<T: FromIterator<(Atom, Vec<Span>)>>(value: &Value) -> Option<T> {
+        value
+            .as_array()?
+            .iter()
+            .map(|request| {
+                let specifier = Atom::from(request.get(0)?.as_str()?);
+                let spans =
+                    request.get(1)?.as_array()?.iter().map(decode_span).collect::<Option<_>>();
+                Some((specifier, spans?))
+            })
+            .collect()
+    }
+    fn bindings<T: FromIterator<(Atom, Span)>>(value: &Value) -> Option<T> {
+        value
+            .as_array()?
+            .iter()
+            .map(|binding| decode_name_span(binding).map(|name| (name.name().clone(), name.span())))
+            .collect()
+    }
+    fn entries(value: &Value) -> Option<Vec<ExportEntry>> {
+        value.as_array()?.iter().map(decode_export_entry).collect()
+    }
+    let mut module_record = ModuleRecord::new(path.to_path_buf());
+    module_record.requested_modules = requests(&value["requestedModules"])?;
+    module_record.dynamic_imports = requests(&value["dynamicImports"])?;
+    module_record.local_export_entries = entries(&value["localExportEntries"])?;
+    module_record.indirect_export_entries = entries(&value["indirectExportEntries"])?;
+    module_record.star_export_entries = entries(&value["starExportEntries"])?;
+    module_record.exported_bindings = bindings(&value["exportedBindings"])?;
+    module_record.export_default = decode_span(&value["exportDefault"]);
+    module_record.has_module_syntax = value["hasModuleSyntax"].as_bool()?;
+    module_record.commonjs_exports = bindings(&value["commonjsExports"])?;
+    module_record.commonjs_export_assignment = decode_span(&value["commonjsExportAssignment"]);
+    Some(module_record)
+}
+
+fn encode_span(span: Span) -> Value {
+    json!([span.start, span.end])
+}
+
+fn decode_span(value: &Value) -> Option<Span> {
+    let start = u32::try_from(value.get(0)?.as_u64()?).ok()?;
+    let end = u32::try_from(value.get(1)?.as_u64()?).ok()?;
+    Some(Span::new(start, end))
+}
+
+fn encode_name_span(name: &NameSpan) -> Value {
+    json!([name.name().as_str(), name.span().start, name.span().end])
+}
+
+fn decode_name_span(value: &Value) -> Option<NameSpan> {
+    let name = Atom::from(value.get(0)?.as_str()?);
+    let start = u32::try_from(value.get(1)?.as_u64()?).ok()?;
+    let end = u32::try_from(value.get(2)?.as_u64()?).ok()?;
+    Some(NameSpan::new(name, Span::new(start, end)))
+}
+
+fn encode_export_entry(export_entry: &ExportEntry) -> Value {
+    let import_name = match &export_entry.import_name {
+        ExportImportName::Name(name) => json!({ "name": encode_name_span(name) }),
+        ExportImportName::All => json!("all"),
+        ExportImportName::AllButDefault => json!("allButDefault"),
+        ExportImportName::Null => Value::Null,
+    };
+    let export_name = match &export_entry.export_name {
+        ExportExportName::Name(name) => json!({ "name": encode_name_span(name) }),
+        ExportExportName::Default(span) => json!({ "default": encode_span(*span) }),
+        ExportExportName::Null => Value::Null,
+    };
+    let local_name = match &export_entry.local_name {
+        ExportLocalName::Name(name) => json!({ "name": encode_name_span(name) }),
+        ExportLocalName::Default(span) => json!({ "default": encode_span(*span) }),
+        ExportLocalName::Null => Value::Null,
+    };
+    json!({
+        "span": encode_span(export_entry.span),
+        "moduleRequest": export_entry.module_request.as_ref().map(encode_name_span),
+        "importName": import_name,
+        "exportName": export_name,
+        "localName": local_name,
+    })
+}
+
+fn decode_export_entry(value: &Value) -> Option<ExportEntry> {
+    let module_request = &value["moduleRequest"];
+    let import_name = match &value["importName"] {
+        Value::Null => ExportImportName::Null,
+        Value::String(name) if name == "all" => ExportImportName::All,
+        Value::String(name) if name == "allButDefault" => ExportImportName::AllButDefault,
+        name => ExportImportName::Name(decode_name_span(&name["name"])?),
+    };
+    let export_name = match &value["exportName"] {
+        Value::Null => ExportExportName::Null,
+        name if name.get("default").is_some() => {
+            ExportExportName::Default(decode_span(&name["default"])?)
+        }
+        name => ExportExportName::Name(decode_name_span(&name["name"])?),
+    };
+    let local_name = match &value["localName"] {
+        Value::Null => ExportLocalName::Null,
+        name if name.get("default").is_some() => {
+            ExportLocalName::Default(decode_span(&name["default"])?)
+        }
+        name => ExportLocalName::Name(decode_name_span(&name["name"])?),
+    };
+    Some(ExportEntry {
+        span: decode_span(&value["span"])?,
+        module_request: if module_request.is_null() {
+            None
+        } else {
+            Some(decode_name_span(module_request)?)
+        },
+        import_name,
+        export_name,
+        local_name,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use super::ModuleCache;
+
+    #[test]
+    fn module_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        let module = dir.join("module.js");
+        let source_text = "import a from './a';\nimport('./b');\nexport * from './c';\n\
+            export { d as e } from './d';\nexport const f = 1;\nexport default f;\n";
+        fs::write(&module, source_text).unwrap();
+        let cache_path = dir.join("cache").join("modules.json");
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let program =
+            allocator.alloc(Parser::new(&allocator, source_text, source_type).parse().program);
+        let module_record = SemanticBuilder::new(source_text, source_type)
+            .build_module_record(module.clone(), program)
+            .module_record();
+
+        let metadata = fs::metadata(&module).unwrap();
+        let cache = ModuleCache::new(cache_path.clone());
+        assert!(cache.get(&module, &metadata).is_none());
+        cache.insert(&module, &metadata, &module_record);
+        cache.persist().unwrap();
+
+        let cached = ModuleCache::new(cache_path).get(&module, &metadata).unwrap();
+        assert_eq!(cached.resolved_absolute_path, module);
+        assert_eq!(cached.requested_modules, module_record.requested_modules);
+        assert_eq!(cached.dynamic_imports, module_record.dynamic_imports);
+        assert_eq!(cached.local_export_entries, module_record.local_export_entries);
+        assert_eq!(cached.indirect_export_entries, module_record.indirect_export_entries);
+        assert_eq!(cached.star_export_entries, module_record.star_export_entries);
+        assert_eq!(cached.exported_bindings, module_record.exported_bindings);
+        assert_eq!(cached.export_default, module_record.export_default);
+        assert!(cached.has_module_syntax);
+        assert!(cached.import_entries.is_empty());
+
+        // Invalidated when the file changes
+        fs::write(&module, "module.exports = {};\n").unwrap();
+        let metadata = fs::metadata(&module).unwrap();
+        let cache = ModuleCache::new(dir.join("cache").join("modules.json"));
+        assert!(cache.get(Path::new(&module), &metadata).is_none());
+    }
+}
@@ -1,34 +1,58 @@
-use crate::{RuleCategory, RuleEnum, RULES};
-use rustc_hash::FxHashSet;
+use std::path::PathBuf;
 
-#[derive(Debug)]
+use crate::{FixKind, LintConfig, RuleCategory, RuleEnum, RULES};
+use rustc_hash::FxHashMap;
+
+#[derive(Debug, Default, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct LintOptions {
-    /// Allow / Deny rules in order. [("allow" / "deny", rule name)]
-    /// Defaults to [("deny", "correctness")]
+    /// Allow / Warn / Deny rules or categories in order. [("allow" / "warn" / "deny", rule name)]
+    /// Applied after the config file, [`RuleCategory::DEFAULT`] are denied before the config file when empty
     pub filter: Vec<(AllowWarnDeny, String)>,
+    /// The config file, which configures rules before `filter`
+    pub config: Option<LintConfig>,
     pub fix: bool,
+    /// Compute fixes without writing them, print a unified diff of the changes instead
+    pub fix_dry_run: bool,
+    /// Also apply fixes of kind [`FixKind::Suggestion`]
+    pub fix_suggestions: bool,
+    /// Also apply fixes of kind [`FixKind::Unsafe`]
+    pub fix_dangerously: bool,
     pub timing: bool,
     pub import_plugin: bool,
-}
-
-impl Default for LintOptions {
-    fn default() -> Self {
-        Self {
-            filter: vec![(AllowWarnDeny::Deny, String::from("correctness"))],
-            fix: false,
-            timing: false,
-            import_plugin: false,
-        }
-    }
+    /// How deep the import plugin loads the dependencies of the linted files, `Some(0)` disables
+    /// cross-module analysis, `None` loads the direct dependencies only
+    pub max_module_depth: Option<usize>,
+    /// Only load dependencies inside the current working directory and outside of `node_modules`
+    pub workspace_modules_only: bool,
+    /// Load the dependencies for printing the module graph, even when no enabled rule needs them
+    pub module_graph: bool,
+    /// File caching the module records of dependencies between runs
+    pub module_cache: Option<PathBuf>,
+    /// Query the TypeScript compiler for type information used by type-aware rules
+    pub type_aware: bool,
+    /// Lint files with the first extension as if they had the second, e.g. `("es6", "js")`
+    pub extension_aliases: Vec<(String, String)>,
+    /// Package directories of the monorepo workspace, each resolved with its own `tsconfig.json`
+    pub workspaces: Vec<PathBuf>,
+    /// Config files of workspace packages, used instead of `config` for the files in the package
+    pub workspace_configs: Vec<(PathBuf, LintConfig)>,
+    /// Skip files larger than this number of bytes, e.g. bundles and generated files
+    pub max_file_size: Option<u64>,
+    /// Only report syntax and semantic errors, no rule is enabled
+    pub syntax_only: bool,
 }
 
 impl LintOptions {
     #[must_use]
     pub fn with_filter(mut self, filter: Vec<(AllowWarnDeny, String)>) -> Self {
-        if !filter.is_empty() {
-            self.filter = filter;
-        }
+        self.filter = filter;
+        self
+    }
+
+    #[must_use]
+    pub fn with_config(mut self, config: Option<LintConfig>) -> Self {
+        self.config = config;
         self
     }
 
@@ -38,6 +62,33 @@ impl LintOptions {
         self
     }
 
+    #[must_use]
+    pub fn with_fix_dry_run(mut self, yes: bool) -> Self {
+        self.fix_dry_run = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_fix_suggestions(mut self, yes: bool) -> Self {
+        self.fix_suggestions = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_fix_dangerously(mut self, yes: bool) -> Self {
+        self.fix_dangerously = yes;
+        self
+    }
+
+    /// Whether fixes of `kind` should be applied.
+    pub fn allows_fix_kind(&self, kind: FixKind) -> bool {
+        match kind {
+            FixKind::Safe => true,
+            FixKind::Suggestion => self.fix_suggestions,
+            FixKind::Unsafe => self.fix_dangerously,
+        }
+    }
+
     #[must_use]
     pub fn with_timing(mut self, yes: bool) -> Self {
         self.timing = yes;
@@ -49,12 +100,88 @@ impl LintOptions {
         self.import_plugin = yes;
         self
     }
+
+    #[must_use]
+    pub fn with_max_module_depth(mut self, max_module_depth: Option<usize>) -> Self {
+        self.max_module_depth = max_module_depth;
+        self
+    }
+
+    #[must_use]
+    pub fn with_workspace_modules_only(mut self, yes: bool) -> Self {
+        self.workspace_modules_only = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_module_graph(mut self, yes: bool) -> Self {
+        self.module_graph = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_module_cache(mut self, module_cache: Option<PathBuf>) -> Self {
+        self.module_cache = module_cache;
+        self
+    }
+
+    #[must_use]
+    pub fn with_type_aware(mut self, yes: bool) -> Self {
+        self.type_aware = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_extension_aliases(mut self, extension_aliases: Vec<(String, String)>) -> Self {
+        self.extension_aliases = extension_aliases;
+        self
+    }
+
+    #[must_use]
+    pub fn with_workspaces(mut self, workspaces: Vec<PathBuf>) -> Self {
+        self.workspaces = workspaces;
+        self
+    }
+
+    #[must_use]
+    pub fn with_workspace_configs(mut self, workspace_configs: Vec<(PathBuf, LintConfig)>) -> Self {
+        self.workspace_configs = workspace_configs;
+        self
+    }
+
+    #[must_use]
+    pub fn with_max_file_size(mut self, max_file_size: Option<u64>) -> Self {
+        self.max_file_size = max_file_size;
+        self
+    }
+
+    #[must_use]
+    pub fn with_syntax_only(mut self, yes: bool) -> Self {
+        self.syntax_only = yes;
+        self
+    }
+
+    /// Whether the import plugin loads the dependencies of a module at `depth`,
+    /// the linted files are at depth 0.
+    pub fn loads_dependencies_at(&self, depth: usize) -> bool {
+        depth < self.max_module_depth.unwrap_or(1)
+    }
+
+    /// The extension that files with extension `ext` are linted as.
+    pub fn resolve_extension<'a>(&'a self, ext: &'a str) -> &'a str {
+        self.extension_aliases
+            .iter()
+            .find(|(name, _)| name == ext)
+            .map_or(ext, |(_, lint_as)| lint_as.as_str())
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum AllowWarnDeny {
     Allow,
-    // Warn,
+    /// Report the diagnostics of the rule as warnings
+    Warn,
+    /// Report the diagnostics of the rule with its own severity
     Deny,
 }
 
@@ -62,56 +189,101 @@ impl From<&'static str> for AllowWarnDeny {
     fn from(s: &'static str) -> Self {
         match s {
             "allow" => Self::Allow,
+            "warn" => Self::Warn,
             "deny" => Self::Deny,
             _ => unreachable!(),
         }
     }
 }
 
+impl AllowWarnDeny {
+    /// Also accepts the levels of ESLint, `"off"` and `"error"`
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "allow" | "off" => Some(Self::Allow),
+            "warn" => Some(Self::Warn),
+            "deny" | "error" => Some(Self::Deny),
+            _ => None,
+        }
+    }
+
+    /// A level in a config file, a string or ESLint's `0`, `1` and `2`
+    pub fn from_json(value: &serde_json::Value) -> Option<Self> {
+        match value {
+            serde_json::Value::String(s) => Self::parse(s),
+            serde_json::Value::Number(n) => match n.as_u64()? {
+                0 => Some(Self::Allow),
+                1 => Some(Self::Warn),
+                2 => Some(Self::Deny),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::Warn => "warn",
+            Self::Deny => "deny",
+        }
+    }
+}
+
 impl LintOptions {
-    pub fn derive_rules(&self) -> Vec<RuleEnum> {
-        let mut rules: FxHashSet<RuleEnum> = FxHashSet::default();
+    /// The enabled rules with their level, configured with the options of the config file
+    pub fn derive_rules(&self) -> Vec<(RuleEnum, AllowWarnDeny)> {
+        if self.syntax_only {
+            return vec![];
+        }
+
+        let mut rules: FxHashMap<RuleEnum, AllowWarnDeny> = FxHashMap::default();
 
-        for (allow_warn_deny, name_or_category) in &self.filter {
+        // The command line replaces the default categories, the config file is applied on top of them
+        let default_filter = if self.filter.is_empty() {
+            RuleCategory::DEFAULT
+                .iter()
+                .map(|category| (AllowWarnDeny::Deny, category.as_str().to_string()))
+                .collect::<Vec<_>>()
+        } else {
+            vec![]
+        };
+        let config_filter = self.config.as_ref().map_or(&[][..], |config| &config.filter);
+
+        for (allow_warn_deny, name_or_category) in
+            default_filter.iter().chain(config_filter).chain(&self.filter)
+        {
             let maybe_category = RuleCategory::from(name_or_category.as_str());
+            let matches = |rule: &RuleEnum| {
+                maybe_category.map_or_else(
+                    || name_or_category == "all" || rule.name() == name_or_category,
+                    |category| rule.category() == category,
+                )
+            };
             match allow_warn_deny {
-                AllowWarnDeny::Deny => {
-                    match maybe_category {
-                        Some(category) => rules.extend(
-                            RULES.iter().filter(|rule| rule.category() == category).cloned(),
-                        ),
-                        None => {
-                            if name_or_category == "all" {
-                                rules.extend(RULES.iter().cloned());
-                            } else {
-                                rules.extend(
-                                    RULES
-                                        .iter()
-                                        .filter(|rule| rule.name() == name_or_category)
-                                        .cloned(),
-                                );
-                            }
-                        }
-                    };
-                }
-                AllowWarnDeny::Allow => {
-                    match maybe_category {
-                        Some(category) => rules.retain(|rule| rule.category() != category),
-                        None => {
-                            if name_or_category == "all" {
-                                rules.clear();
-                            } else {
-                                rules.retain(|rule| rule.name() != name_or_category);
-                            }
-                        }
-                    };
+                AllowWarnDeny::Warn | AllowWarnDeny::Deny => {
+                    for rule in RULES.iter().filter(|rule| matches(rule)) {
+                        rules.insert(rule.clone(), *allow_warn_deny);
+                    }
                 }
+                AllowWarnDeny::Allow => rules.retain(|rule, _| !matches(rule)),
             }
         }
 
-        let mut rules = rules.into_iter().collect::<Vec<_>>();
+        let mut rules = rules
+            .into_iter()
+            .map(|(rule, allow_warn_deny)| {
+                let options = self
+                    .config
+                    .as_ref()
+                    .and_then(|config| config.rule_options.get(rule.name()))
+                    .cloned();
+                let rule = if options.is_some() { rule.read_json(options) } else { rule };
+                (rule, allow_warn_deny)
+            })
+            .collect::<Vec<_>>();
         // for stable diagnostics output ordering
-        rules.sort_unstable_by_key(|rule| rule.name());
+        rules.sort_unstable_by_key(|(rule, _)| rule.name());
         rules
     }
 }
@@ -0,0 +1,75 @@
+use oxc_span::SourceType;
+
+use super::{
+    script_tag::{ScriptTag, ScriptTags},
+    JavaScriptSource,
+};
+
+/// Extract inline `<script>` elements from HTML files.
+///
+/// Scripts with a non-JavaScript `type`, e.g. `importmap` or `text/template`, are skipped.
+/// See <https://html.spec.whatwg.org/multipage/scripting.html#attr-script-type>
+pub struct HtmlPartialLoader<'a> {
+    source_text: &'a str,
+}
+
+impl<'a> HtmlPartialLoader<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text }
+    }
+
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        ScriptTags::new(self.source_text)
+            .filter_map(|tag| {
+                let source_type = Self::source_type(&tag)?;
+                Some(JavaScriptSource::new(tag.source_text, source_type, tag.start))
+            })
+            .collect()
+    }
+
+    fn source_type(tag: &ScriptTag) -> Option<SourceType> {
+        match tag.attribute("type").map(str::to_ascii_lowercase).as_deref() {
+            None | Some("" | "text/javascript" | "application/javascript") => {
+                Some(SourceType::default().with_script(true))
+            }
+            Some("module") => Some(SourceType::default().with_module(true)),
+            Some(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{HtmlPartialLoader, JavaScriptSource};
+
+    fn parse_html(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        HtmlPartialLoader::new(source_text).parse()
+    }
+
+    #[test]
+    fn test_parse_html() {
+        let source_text = r#"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <script src="./main.js"></script>
+            <script>var a = 1</script>
+            <script type="module">import "./foo.js"</script>
+            <script type="text/JavaScript">var b = 2</script>
+            <script type="importmap">{ "imports": {} }</script>
+            <script type="text/template"><div></div></script>
+          </head>
+        </html>
+        "#;
+
+        let sources = parse_html(source_text);
+        assert_eq!(sources.len(), 4);
+        assert_eq!(sources[0].source_text, "");
+        assert_eq!(sources[1].source_text, "var a = 1");
+        assert!(sources[1].source_type.is_script());
+        assert_eq!(sources[2].source_text, r#"import "./foo.js""#);
+        assert!(sources[2].source_type.is_module());
+        assert_eq!(sources[3].source_text, "var b = 2");
+        assert!(sources[3].source_type.is_script());
+    }
+}
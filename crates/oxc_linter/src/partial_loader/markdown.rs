@@ -0,0 +1,142 @@
+use oxc_span::SourceType;
+
+use super::JavaScriptSource;
+
+/// Extract fenced JavaScript and TypeScript code blocks from Markdown and MDX files.
+///
+/// See <https://spec.commonmark.org/0.30/#fenced-code-blocks>
+pub struct MarkdownPartialLoader<'a> {
+    source_text: &'a str,
+}
+
+/// An opened code fence, e.g. "```ts"
+struct Fence {
+    marker: u8,
+    length: usize,
+    source_type: Option<SourceType>,
+}
+
+impl<'a> MarkdownPartialLoader<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text }
+    }
+
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        let mut results = vec![];
+        let mut fence: Option<(Fence, usize)> = None;
+        let mut offset = 0;
+        for line in self.source_text.split_inclusive('\n') {
+            let line_start = offset;
+            offset += line.len();
+            match &fence {
+                None => {
+                    if let Some(opened) = Self::opening_fence(line) {
+                        fence = Some((opened, offset));
+                    }
+                }
+                Some((opened, content_start)) => {
+                    if !Self::is_closing_fence(line, opened) {
+                        continue;
+                    }
+                    if let Some(source_type) = opened.source_type {
+                        let source_text = &self.source_text[*content_start..line_start];
+                        #[allow(clippy::cast_possible_truncation)]
+                        results.push(JavaScriptSource::new(
+                            source_text,
+                            source_type,
+                            *content_start as u32,
+                        ));
+                    }
+                    fence = None;
+                }
+            }
+        }
+        results
+    }
+
+    /// A fence is at least three backticks or tildes, indented by at most three spaces.
+    fn fence(line: &str) -> Option<(u8, usize, &str)> {
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        if indent > 3 {
+            return None;
+        }
+        let line = &line[indent..];
+        let marker = *line.as_bytes().first()?;
+        if !matches!(marker, b'`' | b'~') {
+            return None;
+        }
+        let length = line.bytes().take_while(|b| *b == marker).count();
+        (length >= 3).then(|| (marker, length, line[length..].trim()))
+    }
+
+    fn opening_fence(line: &str) -> Option<Fence> {
+        let (marker, length, info) = Self::fence(line)?;
+        // The info string of a backtick fence may not contain backticks
+        if marker == b'`' && info.contains('`') {
+            return None;
+        }
+        let lang = info.split(|c: char| c.is_whitespace() || c == '{').next().unwrap_or_default();
+        Some(Fence { marker, length, source_type: Self::source_type(lang) })
+    }
+
+    fn is_closing_fence(line: &str, opened: &Fence) -> bool {
+        Self::fence(line).is_some_and(|(marker, length, info)| {
+            marker == opened.marker && length >= opened.length && info.is_empty()
+        })
+    }
+
+    fn source_type(lang: &str) -> Option<SourceType> {
+        let source_type = SourceType::default().with_module(true);
+        let source_type = match lang.to_ascii_lowercase().as_str() {
+            "js" | "javascript" | "mjs" | "cjs" => source_type,
+            "jsx" => source_type.with_jsx(true),
+            "ts" | "typescript" | "mts" | "cts" => source_type.with_typescript(true),
+            "tsx" => source_type.with_typescript(true).with_jsx(true),
+            _ => return None,
+        };
+        Some(source_type)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{JavaScriptSource, MarkdownPartialLoader};
+
+    fn parse_markdown(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        MarkdownPartialLoader::new(source_text).parse()
+    }
+
+    #[test]
+    fn test_parse_markdown() {
+        let source_text = "# Title\n\n```js\ndebugger;\n```\n\nText\n\n~~~~tsx title=\"a.tsx\"\nconst a: number = <div />\n~~~\n~~~~\n";
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 2);
+
+        assert_eq!(sources[0].source_text, "debugger;\n");
+        assert_eq!(sources[0].start, 15);
+        assert_eq!(&source_text[15..25], sources[0].source_text);
+        assert!(sources[0].source_type.is_javascript());
+        assert!(!sources[0].source_type.is_jsx());
+
+        // A shorter fence does not close the block
+        assert_eq!(sources[1].source_text, "const a: number = <div />\n~~~\n");
+        assert!(sources[1].source_type.is_typescript());
+        assert!(sources[1].source_type.is_jsx());
+    }
+
+    #[test]
+    fn test_parse_markdown_ignores_other_languages() {
+        let source_text =
+            "```sh\nnpm install\n```\n```\nplain\n```\n   ```TypeScript\nlet a: number\n   ```\n";
+        let sources = parse_markdown(source_text);
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_text, "let a: number\n");
+        assert!(sources[0].source_type.is_typescript());
+    }
+
+    #[test]
+    fn test_parse_markdown_unclosed_fence() {
+        assert!(parse_markdown("```js\ndebugger;\n").is_empty());
+        assert!(parse_markdown("    ```js\ndebugger;\n    ```\n").is_empty());
+    }
+}
@@ -0,0 +1,84 @@
+//! Extract JavaScript and TypeScript sources embedded in other file types,
+//! e.g. `<script>` blocks in Vue components and HTML files and fenced code blocks in Markdown.
+
+mod html;
+mod markdown;
+mod script_tag;
+mod vue;
+
+use oxc_span::SourceType;
+
+pub use self::{html::HtmlPartialLoader, markdown::MarkdownPartialLoader, vue::VuePartialLoader};
+
+/// File extensions that are linted through a partial loader
+pub const LINT_PARTIAL_LOADER_EXT: &[&str] = &["vue", "md", "mdx", "html", "htm"];
+
+/// A JavaScript or TypeScript block embedded in another file, e.g. `<script>` in a `.vue` file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JavaScriptSource<'a> {
+    pub source_text: &'a str,
+    pub source_type: SourceType,
+    /// Byte offset of `source_text` in the original file
+    pub start: u32,
+}
+
+impl<'a> JavaScriptSource<'a> {
+    pub fn new(source_text: &'a str, source_type: SourceType, start: u32) -> Self {
+        Self { source_text, source_type, start }
+    }
+
+    /// The original file with everything outside this source replaced by whitespace.
+    ///
+    /// Line breaks and byte offsets are preserved, so spans and line numbers of the
+    /// parsed program point into the original file.
+    pub fn padded_source_text(&self, original_source_text: &str) -> String {
+        let start = self.start as usize;
+        let end = start + self.source_text.len();
+        let mut padded = String::with_capacity(original_source_text.len());
+        Self::pad(&mut padded, &original_source_text[..start]);
+        padded.push_str(self.source_text);
+        Self::pad(&mut padded, &original_source_text[end..]);
+        padded
+    }
+
+    fn pad(output: &mut String, text: &str) {
+        for c in text.chars() {
+            if matches!(c, '\n' | '\r') {
+                output.push(c);
+            } else {
+                output.extend(std::iter::repeat(' ').take(c.len_utf8()));
+            }
+        }
+    }
+}
+
+pub struct PartialLoader;
+
+impl PartialLoader {
+    /// Extract the JavaScript sources of a file with extension `ext`.
+    /// Returns `None` when the extension is not supported by a partial loader.
+    pub fn parse<'a>(ext: &str, source_text: &'a str) -> Option<Vec<JavaScriptSource<'a>>> {
+        match ext {
+            "vue" => Some(VuePartialLoader::new(source_text).parse()),
+            "md" | "mdx" => Some(MarkdownPartialLoader::new(source_text).parse()),
+            "html" | "htm" => Some(HtmlPartialLoader::new(source_text).parse()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+
+    use super::JavaScriptSource;
+
+    #[test]
+    fn padded_source_text() {
+        let original = "<a>é\r\n<script>x</script>\n</a>";
+        let source = JavaScriptSource::new("x", SourceType::default(), 15);
+        let padded = source.padded_source_text(original);
+        assert_eq!(padded, "     \r\n        x         \n    ");
+        assert_eq!(padded.len(), original.len());
+    }
+}
@@ -0,0 +1,159 @@
+const SCRIPT_START: &str = "<script";
+const SCRIPT_END: &str = "</script>";
+const COMMENT_START: &str = "<!--";
+const COMMENT_END: &str = "-->";
+
+/// A `<script>` element with content in an HTML-like file
+pub struct ScriptTag<'a> {
+    /// Everything between `<script` and `>`
+    attributes: &'a str,
+    pub source_text: &'a str,
+    /// Byte offset of `source_text` in the file
+    pub start: u32,
+}
+
+impl<'a> ScriptTag<'a> {
+    /// Value of the attribute `name`, e.g. `ts` for `lang` in `<script setup lang="ts">`.
+    /// Returns `Some("")` for attributes without a value.
+    pub fn attribute(&self, name: &str) -> Option<&'a str> {
+        let attributes = self.attributes;
+        attributes.match_indices(name).find_map(|(index, _)| {
+            let before = attributes[..index].chars().next_back();
+            if !before.map_or(true, char::is_whitespace) {
+                return None;
+            }
+            let rest = &attributes[index + name.len()..];
+            let Some(value) = rest.trim_start().strip_prefix('=') else {
+                return rest.chars().next().map_or(true, char::is_whitespace).then_some("");
+            };
+            let value = value.trim_start();
+            match value.chars().next()? {
+                quote @ ('"' | '\'') => value[1..].split(quote).next(),
+                _ => value.split(char::is_whitespace).next(),
+            }
+        })
+    }
+}
+
+/// Iterator over the `<script>` elements with content, skipping HTML comments.
+pub struct ScriptTags<'a> {
+    source_text: &'a str,
+    pointer: usize,
+}
+
+impl<'a> ScriptTags<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text, pointer: 0 }
+    }
+}
+
+impl<'a> Iterator for ScriptTags<'a> {
+    type Item = ScriptTag<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let rest = &self.source_text[self.pointer..];
+            let script_start = rest.find(SCRIPT_START)?;
+            if let Some(comment_start) = rest.find(COMMENT_START) {
+                if comment_start < script_start {
+                    let comment_end = rest[comment_start..].find(COMMENT_END)?;
+                    self.pointer += comment_start + comment_end + COMMENT_END.len();
+                    continue;
+                }
+            }
+            self.pointer += script_start + SCRIPT_START.len();
+
+            // Make sure this is `<script` and not e.g. `<scripts`
+            if !matches!(
+                self.source_text.as_bytes().get(self.pointer),
+                Some(b'>' | b'/' | b' ' | b'\t' | b'\n' | b'\r')
+            ) {
+                continue;
+            }
+
+            let tag_end = find_tag_end(&self.source_text[self.pointer..])?;
+            let attributes = &self.source_text[self.pointer..self.pointer + tag_end];
+            self.pointer += tag_end + 1;
+
+            // `<script src="..." />`
+            if attributes.ends_with('/') {
+                continue;
+            }
+
+            let start = self.pointer;
+            let end = start + self.source_text[start..].find(SCRIPT_END)?;
+            self.pointer = end + SCRIPT_END.len();
+
+            #[allow(clippy::cast_possible_truncation)]
+            return Some(ScriptTag {
+                attributes,
+                source_text: &self.source_text[start..end],
+                start: start as u32,
+            });
+        }
+    }
+}
+
+/// Offset of the `>` closing a start tag, skipping quoted attribute values which may contain `>`,
+/// e.g. `generic="T extends Record<string, unknown>"`.
+fn find_tag_end(attributes: &str) -> Option<usize> {
+    let mut quote = None;
+    attributes.bytes().position(|byte| match (quote, byte) {
+        (None, b'>') => true,
+        (None, b'"' | b'\'') => {
+            quote = Some(byte);
+            false
+        }
+        (Some(q), _) if q == byte => {
+            quote = None;
+            false
+        }
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::ScriptTags;
+
+    #[test]
+    fn test_script_tags() {
+        let source_text = r#"
+        <!-- <script>commented()</script> -->
+        <script src="./foo.js" />
+        <scripts>not a script</scripts>
+        <script>debugger</script>
+        <script type="module" async>foo()</script>
+        "#;
+
+        let tags = ScriptTags::new(source_text).collect::<Vec<_>>();
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[0].source_text, "debugger");
+        assert_eq!(&source_text[tags[0].start as usize..][..8], "debugger");
+        assert_eq!(tags[0].attribute("type"), None);
+        assert_eq!(tags[1].source_text, "foo()");
+        assert_eq!(tags[1].attribute("type"), Some("module"));
+        assert_eq!(tags[1].attribute("async"), Some(""));
+        assert_eq!(tags[1].attribute("sync"), None);
+    }
+
+    #[test]
+    fn test_quoted_attributes() {
+        let source_text = r#"<script setup lang="ts" generic="T extends Record<string, unknown>">
+        const props = defineProps<{ item: T }>();
+        </script>"#;
+
+        let tags = ScriptTags::new(source_text).collect::<Vec<_>>();
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].source_text.trim(), "const props = defineProps<{ item: T }>();");
+        assert_eq!(tags[0].attribute("lang"), Some("ts"));
+        assert_eq!(tags[0].attribute("generic"), Some("T extends Record<string, unknown>"));
+    }
+
+    #[test]
+    fn test_unclosed_script_tag() {
+        assert_eq!(ScriptTags::new("<script>unclosed").count(), 0);
+        assert_eq!(ScriptTags::new("<!-- <script>x</script>").count(), 0);
+        assert_eq!(ScriptTags::new("<script lang=\"ts>x</script>").count(), 0);
+    }
+}
@@ -0,0 +1,104 @@
+use oxc_span::SourceType;
+
+use super::{
+    script_tag::{ScriptTag, ScriptTags},
+    JavaScriptSource,
+};
+
+/// Extract `<script>` and `<script setup>` blocks from a Vue single-file component.
+///
+/// See <https://vuejs.org/api/sfc-spec.html>
+pub struct VuePartialLoader<'a> {
+    source_text: &'a str,
+}
+
+impl<'a> VuePartialLoader<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text }
+    }
+
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        ScriptTags::new(self.source_text)
+            .map(|tag| JavaScriptSource::new(tag.source_text, Self::source_type(&tag), tag.start))
+            .collect()
+    }
+
+    /// Source type from the `lang` attribute, e.g. `<script setup lang="ts">`
+    fn source_type(tag: &ScriptTag) -> SourceType {
+        let lang = tag.attribute("lang");
+        let is_typescript = matches!(lang, Some("ts" | "tsx"));
+        let is_jsx = matches!(lang, Some("jsx" | "tsx"));
+        SourceType::default().with_module(true).with_typescript(is_typescript).with_jsx(is_jsx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{JavaScriptSource, VuePartialLoader};
+
+    fn parse_vue(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        VuePartialLoader::new(source_text).parse()
+    }
+
+    #[test]
+    fn test_parse_vue_one_line() {
+        let source_text = r#"
+        <template>
+          <h1>hello world</h1>
+        </template>
+        <script> console.log("hi") </script>
+        "#;
+
+        let sources = parse_vue(source_text);
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_text, r#" console.log("hi") "#);
+        assert_eq!(
+            &source_text[sources[0].start as usize..][..sources[0].source_text.len()],
+            sources[0].source_text
+        );
+        assert!(sources[0].source_type.is_module());
+        assert!(sources[0].source_type.is_javascript());
+    }
+
+    #[test]
+    fn test_parse_vue_multi_line() {
+        let source_text = r#"
+        <template>
+          <h1>hello world</h1>
+        </template>
+        <script>
+          console.log("hi")
+        </script>
+        "#;
+
+        let sources = parse_vue(source_text);
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_text.trim(), r#"console.log("hi")"#);
+    }
+
+    #[test]
+    fn test_parse_vue_script_setup_with_lang() {
+        let source_text = r#"
+        <script lang="ts">
+          export default {}
+        </script>
+        <script setup lang='tsx'>
+          const a: number = <div />
+        </script>
+        "#;
+
+        let sources = parse_vue(source_text);
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text.trim(), "export default {}");
+        assert!(sources[0].source_type.is_typescript());
+        assert!(!sources[0].source_type.is_jsx());
+        assert_eq!(sources[1].source_text.trim(), "const a: number = <div />");
+        assert!(sources[1].source_type.is_typescript());
+        assert!(sources[1].source_type.is_jsx());
+    }
+
+    #[test]
+    fn test_parse_vue_without_script() {
+        assert!(parse_vue("<template><div /></template>").is_empty());
+    }
+}
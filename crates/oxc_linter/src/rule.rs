@@ -1,8 +1,10 @@
 use std::fmt;
 
+use bitflags::bitflags;
+use oxc_diagnostics::Severity;
 use oxc_semantic::SymbolId;
 
-use crate::{context::LintContext, AstNode};
+use crate::{context::LintContext, fixer::FixKind, AstNode};
 
 pub trait Rule: Sized + Default + fmt::Debug {
     /// Initialize from eslint json configuration
@@ -25,9 +27,36 @@ pub trait RuleMeta {
 
     const CATEGORY: RuleCategory;
 
+    /// The kind of fix the rule provides, if any
+    const FIX: Option<FixKind> = None;
+
+    /// The severity of the diagnostics reported by the rule, unless it is enabled as a warning.
+    /// This is the only place a rule declares its severity.
+    const SEVERITY: Severity = Severity::Warning;
+
+    /// What the rule needs from the dependencies of the linted file
+    const CAPABILITIES: RuleCapabilities = RuleCapabilities::empty();
+
     fn documentation() -> Option<&'static str> {
         None
     }
+
+    /// JSON schema of the configuration options, an array with a schema per option like ESLint's `meta.schema`
+    fn schema() -> Option<&'static str> {
+        None
+    }
+}
+
+bitflags! {
+    /// What a rule needs from the other modules of the project. The lint service only resolves
+    /// and parses the dependencies of a file when an enabled rule needs them.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct RuleCapabilities: u8 {
+        /// The paths the module requests resolve to, in `ModuleRecord::loaded_modules`
+        const MODULE_GRAPH = 1 << 0;
+        /// The module records of the dependencies with their exports, which are parsed for it
+        const DEPENDENCY_EXPORTS = 1 << 1 | Self::MODULE_GRAPH.bits();
+    }
 }
 
 /// Rule categories defined by rust-clippy
@@ -39,6 +68,8 @@ pub enum RuleCategory {
     Suspicious,
     /// Lints which are rather strict or have occasional false positives
     Pedantic,
+    /// Code that can be written to run faster
+    Perf,
     /// Code that should be written in a more idiomatic way
     Style,
     /// Lints which prevent the use of language and library features
@@ -57,6 +88,7 @@ impl RuleCategory {
             "correctness" => Some(Self::Correctness),
             "suspicious" => Some(Self::Suspicious),
             "pedantic" => Some(Self::Pedantic),
+            "perf" => Some(Self::Perf),
             "style" => Some(Self::Style),
             "restriction" => Some(Self::Restriction),
             "nursery" => Some(Self::Nursery),
@@ -65,12 +97,30 @@ impl RuleCategory {
     }
 }
 
+impl RuleCategory {
+    /// The categories enabled when neither the command line nor the config file configure any
+    pub const DEFAULT: [Self; 1] = [Self::Correctness];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Correctness => "correctness",
+            Self::Suspicious => "suspicious",
+            Self::Pedantic => "pedantic",
+            Self::Perf => "perf",
+            Self::Style => "style",
+            Self::Restriction => "restriction",
+            Self::Nursery => "nursery",
+        }
+    }
+}
+
 impl fmt::Display for RuleCategory {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Correctness => write!(f, "Correctness"),
             Self::Suspicious => write!(f, "Suspicious"),
             Self::Pedantic => write!(f, "Pedantic"),
+            Self::Perf => write!(f, "Perf"),
             Self::Style => write!(f, "Style"),
             Self::Restriction => write!(f, "Restriction"),
             Self::Nursery => write!(f, "Nursery"),
@@ -78,8 +128,28 @@ impl fmt::Display for RuleCategory {
     }
 }
 
+/// The rule that reported a diagnostic, displayed as `plugin/name`, e.g. `eslint/no-debugger`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuleId {
+    pub plugin_name: &'static str,
+    pub name: &'static str,
+}
+
+impl RuleId {
+    pub const fn new(plugin_name: &'static str, name: &'static str) -> Self {
+        Self { plugin_name, name }
+    }
+}
+
+impl fmt::Display for RuleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.plugin_name, self.name)
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use super::RuleId;
     use crate::RULES;
 
     #[test]
@@ -89,4 +159,21 @@ mod test {
             assert!(rule.documentation().is_some_and(|s| !s.is_empty()), "{}", rule.name());
         }
     }
+
+    #[test]
+    fn ensure_schema() {
+        for rule in RULES.iter() {
+            if let Some(schema) = rule.schema() {
+                let schema: serde_json::Value = serde_json::from_str(schema)
+                    .unwrap_or_else(|error| panic!("{}: {error}", rule.name()));
+                assert!(schema.is_array(), "{}", rule.name());
+            }
+        }
+    }
+
+    #[test]
+    fn rule_id() {
+        let rule_id = RuleId::new("eslint", "no-debugger");
+        assert_eq!(rule_id.to_string(), "eslint/no-debugger");
+    }
 }
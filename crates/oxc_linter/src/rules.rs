@@ -7,8 +7,12 @@
 
 /// <https://github.com/import-js/eslint-plugin-import>
 mod import {
+    pub mod default;
     pub mod named;
+    pub mod no_duplicates;
+    pub mod no_extraneous_dependencies;
     pub mod no_self_import;
+    pub mod order;
 }
 
 mod deepscan {
@@ -42,6 +46,7 @@ mod eslint {
     pub mod no_control_regex;
     pub mod no_debugger;
     pub mod no_delete_var;
+    pub mod no_dupe_args;
     pub mod no_dupe_class_members;
     pub mod no_dupe_else_if;
     pub mod no_dupe_keys;
@@ -52,14 +57,18 @@ mod eslint {
     pub mod no_eval;
     pub mod no_ex_assign;
     pub mod no_extra_boolean_cast;
+    pub mod no_fallthrough;
     pub mod no_func_assign;
     pub mod no_global_assign;
     pub mod no_import_assign;
     pub mod no_inner_declarations;
+    pub mod no_invalid_regexp;
     pub mod no_loss_of_precision;
     pub mod no_mixed_operators;
     pub mod no_new_symbol;
     pub mod no_obj_calls;
+    pub mod no_param_reassign;
+    pub mod no_promise_executor_return;
     pub mod no_prototype_builtins;
     pub mod no_return_await;
     pub mod no_self_assign;
@@ -72,8 +81,13 @@ mod eslint {
     pub mod no_unsafe_negation;
     pub mod no_unsafe_optional_chaining;
     pub mod no_unused_labels;
+    pub mod no_unused_vars;
     pub mod no_useless_catch;
     pub mod no_useless_escape;
+    pub mod prefer_const;
+    pub mod prefer_regex_literals;
+    pub mod require_await;
+    pub mod require_unicode_regexp;
     pub mod require_yield;
     pub mod use_isnan;
     pub mod valid_typeof;
@@ -87,9 +101,11 @@ mod typescript {
     pub mod no_empty_interface;
     pub mod no_explicit_any;
     pub mod no_extra_non_null_assertion;
+    pub mod no_floating_promises;
     pub mod no_misused_new;
     pub mod no_namespace;
     pub mod no_non_null_asserted_optional_chain;
+    pub mod no_non_null_assertion;
     pub mod no_this_alias;
     pub mod no_unnecessary_type_constraint;
     pub mod no_unsafe_declaration_merging;
@@ -115,6 +131,20 @@ mod unicorn {
     pub mod no_unnecessary_await;
 }
 
+/// <https://github.com/jsx-eslint/eslint-plugin-react>
+mod react {
+    pub mod jsx_key;
+    pub mod jsx_no_duplicate_props;
+    pub mod no_children_prop;
+}
+
+/// <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y>
+mod jsx_a11y {
+    pub mod alt_text;
+    pub mod anchor_is_valid;
+    pub mod aria_props;
+}
+
 oxc_macros::declare_all_lint_rules! {
     deepscan::bad_array_method_on_arguments,
     deepscan::bad_bitwise_operator,
@@ -143,6 +173,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_control_regex,
     eslint::no_debugger,
     eslint::no_delete_var,
+    eslint::no_dupe_args,
     eslint::no_dupe_class_members,
     eslint::no_dupe_else_if,
     eslint::no_dupe_keys,
@@ -153,14 +184,18 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_eval,
     eslint::no_ex_assign,
     eslint::no_extra_boolean_cast,
+    eslint::no_fallthrough,
     eslint::no_func_assign,
     eslint::no_global_assign,
     eslint::no_import_assign,
     eslint::no_inner_declarations,
+    eslint::no_invalid_regexp,
     eslint::no_loss_of_precision,
     eslint::no_mixed_operators,
     eslint::no_new_symbol,
     eslint::no_obj_calls,
+    eslint::no_param_reassign,
+    eslint::no_promise_executor_return,
     eslint::no_prototype_builtins,
     eslint::no_return_await,
     eslint::no_self_assign,
@@ -173,8 +208,13 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_unsafe_negation,
     eslint::no_unsafe_optional_chaining,
     eslint::no_unused_labels,
+    eslint::no_unused_vars,
     eslint::no_useless_catch,
     eslint::no_useless_escape,
+    eslint::prefer_const,
+    eslint::prefer_regex_literals,
+    eslint::require_await,
+    eslint::require_unicode_regexp,
     eslint::require_yield,
     eslint::use_isnan,
     eslint::valid_typeof,
@@ -185,7 +225,9 @@ oxc_macros::declare_all_lint_rules! {
     typescript::no_empty_interface,
     typescript::no_explicit_any,
     typescript::no_extra_non_null_assertion,
+    typescript::no_floating_promises,
     typescript::no_non_null_asserted_optional_chain,
+    typescript::no_non_null_assertion,
     typescript::no_unnecessary_type_constraint,
     typescript::no_unsafe_declaration_merging,
     typescript::no_misused_new,
@@ -205,6 +247,16 @@ oxc_macros::declare_all_lint_rules! {
     jest::no_interpolation_in_snapshots,
     unicorn::no_instanceof_array,
     unicorn::no_unnecessary_await,
+    react::jsx_key,
+    react::jsx_no_duplicate_props,
+    react::no_children_prop,
+    jsx_a11y::alt_text,
+    jsx_a11y::anchor_is_valid,
+    jsx_a11y::aria_props,
+    import::default,
     import::named,
-    import::no_self_import
+    import::no_duplicates,
+    import::no_extraneous_dependencies,
+    import::no_self_import,
+    import::order
 }
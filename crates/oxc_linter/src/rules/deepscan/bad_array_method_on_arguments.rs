@@ -14,7 +14,6 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 #[derive(Debug, Error, Diagnostic)]
 #[error("deepscan(bad-array-method-on-arguments): Bad array method on arguments")]
 #[diagnostic(
-    severity(warning),
     help(
         "The 'arguments' object does not have '{0}()' method. If an array method was intended, consider converting the 'arguments' object to an array or using ES6 rest parameter instead."
     )
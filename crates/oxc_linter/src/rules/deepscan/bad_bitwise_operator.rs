@@ -14,16 +14,14 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("deepscan(bad-bitwise-operator): Bad bitwise operator")]
-#[diagnostic(
-    severity(warning),
-    help("Bitwise operator '{0}' seems unintended. Did you mean logical operator '{1}'?")
-)]
+#[diagnostic(help(
+    "Bitwise operator '{0}' seems unintended. Did you mean logical operator '{1}'?"
+))]
 struct BadBitwiseOperatorDiagnostic(&'static str, &'static str, #[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("Bad bitwise operator")]
 #[diagnostic(
-    severity(warning),
     help(
         "Bitwise operator '|=' seems unintended. Consider using non-compound assignment and logical operator '||' instead."
     )
@@ -14,7 +14,6 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 #[derive(Debug, Error, Diagnostic)]
 #[error("deepscan(bad-comparison-sequence): Bad comparison sequence")]
 #[diagnostic(
-    severity(warning),
     help(
         "Comparison result should not be used directly as an operand of another comparison. If you need to compare three or more operands, you should connect each comparison operation with logical AND operator (`&&`)"
     )
@@ -13,10 +13,9 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("deepscan(bad-min-max-func): Math.min and Math.max combination leads to constant result")]
-#[diagnostic(
-    severity(warning),
-    help("This evaluates to {0:?} because of the incorrect `Math.min`/`Math.max` combination")
-)]
+#[diagnostic(help(
+    "This evaluates to {0:?} because of the incorrect `Math.min`/`Math.max` combination"
+))]
 struct BadMinMaxFuncDiagnostic(f64, #[label] pub Span);
 
 /// `https://deepscan.io/docs/rules/bad-min-max-func`
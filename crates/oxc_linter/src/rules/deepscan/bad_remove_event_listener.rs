@@ -11,7 +11,6 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 #[derive(Debug, Error, Diagnostic)]
 #[error("deepscan(bad-remove-event-listener): removeEventListener() should be called with a correct listener")]
 #[diagnostic(
-    severity(warning),
     help(
         "This 'removeEventListener()' call does nothing because a newly created function is passed. Consider using the exact function instance that was added at the 'addEventListener()' call"
     )
@@ -10,10 +10,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("deepscan(missing-throw): Missing throw")]
-#[diagnostic(
-    severity(warning),
-    help("The `throw` keyword seems to be missing in front of this 'new' expression")
-)]
+#[diagnostic(help("The `throw` keyword seems to be missing in front of this 'new' expression"))]
 struct MissingThrowDiagnostic(#[label] pub Span);
 
 /// `https://deepscan.io/docs/rules/missing-throw`
@@ -13,10 +13,9 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("deepscan(number-arg-out-of-range): Radix or precision arguments of number-related functions should not exceed the limit")]
-#[diagnostic(
-    severity(warning),
-    help("The first argument of 'Number.prototype.{0}' should be a number between {1} and {2}")
-)]
+#[diagnostic(help(
+    "The first argument of 'Number.prototype.{0}' should be a number between {1} and {2}"
+))]
 struct NumberArgOutOfRangeDiagnostic(Atom, usize, usize, #[label] pub Span);
 
 /// `https://deepscan.io/docs/rules/missing-throw`
@@ -13,10 +13,9 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("deepscan(uninvoked-array-callback): Uninvoked array callback")]
-#[diagnostic(
-    severity(warning),
-    help("consider filling the array with `undefined` values using `Array.prototype.fill()`")
-)]
+#[diagnostic(help(
+    "consider filling the array with `undefined` values using `Array.prototype.fill()`"
+))]
 struct UninvokedArrayCallbackDiagnostic(
     #[label("this callback will not be invoked")] Span,
     #[label("because this is an array with only empty slots")] Span,
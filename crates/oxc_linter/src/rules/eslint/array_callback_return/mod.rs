@@ -24,17 +24,11 @@ use crate::{
 #[derive(Debug, Error, Diagnostic)]
 enum ArrayCallbackReturnDiagnostic {
     #[error("eslint(array-callback-return): Missing return on some path for array method {0:?}")]
-    #[diagnostic(
-        severity(warning),
-        help("Array method {0:?} needs to have valid return on all code paths")
-    )]
+    #[diagnostic(help("Array method {0:?} needs to have valid return on all code paths"))]
     ExpectReturn(Atom, #[label] Span),
 
     #[error("eslint(array-callback-return): Unexpected return for array method {0}")]
-    #[diagnostic(
-        severity(warning),
-        help("Array method {0} expects no useless return from the function")
-    )]
+    #[diagnostic(help("Array method {0} expects no useless return from the function"))]
     ExpectNoReturn(Atom, #[label] Span),
 }
 
@@ -65,7 +59,14 @@ declare_oxc_lint!(
     /// });
     /// ```
     ArrayCallbackReturn,
-    pedantic
+    pedantic,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": { "allowImplicit": { "type": "boolean" }, "checkForEach": { "type": "boolean" } },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for ArrayCallbackReturn {
@@ -420,6 +421,11 @@ fn test() {
         ("var every = function() {}", None),
         ("foo[`${every}`](function() {})", None),
         ("foo.every(() => true)", None),
+        ("foo.map(function() { if (a) { return a; } throw new Error(); })", None),
+        (
+            "foo.forEach(function() { throw new Error(); })",
+            Some(serde_json::json!([{"checkForEach": true}])),
+        ),
     ];
 
     let fail = vec![
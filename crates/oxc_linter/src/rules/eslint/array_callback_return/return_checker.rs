@@ -2,7 +2,8 @@ use oxc_ast::ast::{BlockStatement, FunctionBody, Statement, SwitchCase};
 
 /// `StatementReturnStatus` describes whether the CFG corresponding to
 /// the statement is termitated by return statement in all/some/nome of
-/// its exit blocks.
+/// its exit blocks. Paths ending with a `throw` never reach an exit block,
+/// so they are ignored.
 ///
 /// For example, an "if" statement is terminated by explicit return if and only if either:
 /// 1. the test is always true and the consequent is terminated by explicit return
@@ -26,6 +27,9 @@ pub enum StatementReturnStatus {
 
     /// No return on all paths
     NotReturn,
+
+    /// Throw on all paths
+    AlwaysThrow,
 }
 
 impl StatementReturnStatus {
@@ -76,12 +80,15 @@ impl StatementReturnStatus {
             (false, true, false) => Self::SomeExplicit,
             (false, false, true) => Self::SomeImplicit,
             (false, false, false) => Self::NotReturn,
-            (true, false, false) => unreachable!(),
+            (true, false, false) => Self::AlwaysThrow,
         }
     }
 
     pub fn must_return(self) -> bool {
-        matches!(self, Self::AlwaysExplicit | Self::AlwaysImplicit | Self::AlwaysMixed)
+        matches!(
+            self,
+            Self::AlwaysExplicit | Self::AlwaysImplicit | Self::AlwaysMixed | Self::AlwaysThrow
+        )
     }
 
     pub fn may_return_explicit(self) -> bool {
@@ -127,6 +134,8 @@ pub fn check_statement(statement: &Statement) -> StatementReturnStatus {
             }
         }
 
+        Statement::ThrowStatement(_) => StatementReturnStatus::AlwaysThrow,
+
         Statement::IfStatement(stmt) => {
             let test = &stmt.test;
             let left = check_statement(&stmt.consequent);
@@ -441,4 +450,18 @@ mod tests {
       ";
         parse_statement_and_test(source, StatementReturnStatus::AlwaysImplicit);
     }
+
+    #[test]
+    fn test_if_throw() {
+        let source = "
+        function foo() {
+          if (a) {
+            return a;
+          } else {
+            throw new Error();
+          }
+        }
+      ";
+        parse_statement_and_test(source, StatementReturnStatus::AlwaysExplicit);
+    }
 }
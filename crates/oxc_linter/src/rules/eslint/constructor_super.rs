@@ -13,12 +13,12 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(constructor-super): Expected to call 'super()'.")]
-#[diagnostic(severity(warning), help("Ensure 'super()' is called from constructor"))]
+#[diagnostic(help("Ensure 'super()' is called from constructor"))]
 struct ConstructorSuperDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(constructor-super): Unexpected 'super()' because 'super' is not a constructor.")]
-#[diagnostic(severity(warning), help("Do not call 'super()' from constructor."))]
+#[diagnostic(help("Do not call 'super()' from constructor."))]
 struct SuperNotConstructorDiagnostic(
     #[label("unexpected 'super()'")] pub Span,
     #[label("because this is not a constructor")] pub Span,
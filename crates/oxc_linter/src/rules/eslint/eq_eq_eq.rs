@@ -14,7 +14,7 @@ use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(eqeqeq): Expected {1} and instead saw {0}")]
-#[diagnostic(severity(warning), help("Prefer {1} operator"))]
+#[diagnostic(help("Prefer {1} operator"))]
 struct EqEqEqDiagnostic(&'static str, &'static str, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -37,7 +37,16 @@ declare_oxc_lint!(
     /// a == b
     /// ```
     EqEqEq,
-    pedantic
+    pedantic,
+    fix,
+    schema = r#"[
+        { "enum": ["always", "smart"] },
+        {
+            "type": "object",
+            "properties": { "null": { "enum": ["always", "never", "ignore"] } },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for EqEqEq {
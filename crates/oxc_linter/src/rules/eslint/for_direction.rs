@@ -19,7 +19,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 #[error(
     "eslint(for-direction): The update clause in this loop moves the variable in the wrong direction"
 )]
-#[diagnostic(severity(warning), help("Use while loop for intended infinite loop"))]
+#[diagnostic(help("Use while loop for intended infinite loop"))]
 struct ForDirectionDiagnostic(
     #[label("This test moves in the wrong direction")] pub Span, /*test clause */
     #[label("with this update")] pub Span,                       /*update clause */
@@ -17,7 +17,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(getter-return): Expected to always return a value in getter.")]
-#[diagnostic(severity(warning), help("Return a value from all code paths in getter."))]
+#[diagnostic(help("Return a value from all code paths in getter."))]
 struct GetterReturnDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -41,9 +41,14 @@ declare_oxc_lint!(
     /// }
     /// ```
     GetterReturn,
-    nursery // This rule need a CFG to cover cases like
-            // `class Foo { get bar() { throw new Error() } }`
-            // which passes with ESLint
+    correctness,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": { "allowImplicit": { "type": "boolean" } },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl GetterReturn {
@@ -54,7 +59,7 @@ impl GetterReturn {
         if self.allow_implicit {
             return_status.must_return()
         } else {
-            return_status == return_checker::StatementReturnStatus::AlwaysExplicit
+            return_status.must_return() && !return_status.may_return_implicit()
         }
     }
 
@@ -213,6 +218,9 @@ fn test() {
         ("class foo { get bar(){return true;} }", None),
         ("class foo { get bar(){if(baz){return true;} else {return false;} } }", None),
         ("class foo { get(){return true;} }", None),
+        ("class foo { get bar(){ throw new Error('not implemented'); } }", None),
+        ("class foo { get bar(){ if (baz) { return true; } throw new Error(); } }", None),
+        ("var foo = { get bar(){ try { return true; } catch (e) { throw e; } } };", None),
         (
             "class foo { get bar(){return true;} }",
             Some(serde_json::json!([{ "allowImplicit": true }])),
@@ -285,6 +293,8 @@ fn test() {
         ("class foo { get bar(){} }", None),
         ("var foo = class {\n  static get\nbar(){} }", None),
         ("class foo { get bar(){ if (baz) { return true; }}}", None),
+        ("class foo { get bar(){ try { throw new Error(); } catch (e) {} } }", None),
+        ("class foo { get bar(){ if (baz) { return; } throw new Error(); } }", None),
         ("class foo { get bar(){ ~function () { return true; }()}}", None),
         ("class foo { get bar(){} }", Some(serde_json::json!([{ "allowImplicit": true }]))),
         (
@@ -10,7 +10,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-array-constructor): Disallow `Array` constructors")]
-#[diagnostic(severity(warning), help("Use array literal instead"))]
+#[diagnostic(help("Use array literal instead"))]
 struct NoArrayConstructorDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
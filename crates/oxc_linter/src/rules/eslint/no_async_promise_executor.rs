@@ -13,7 +13,6 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-async-promise-executor): Promise executor functions should not be `async`.")]
-#[diagnostic(severity(warning))]
 struct NoAsyncPromiseExecutorDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -11,10 +11,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-bitwise): Unexpected use of {0:?}")]
-#[diagnostic(
-    severity(warning),
-    help("bitwise operators are not allowed, maybe you mistyped `&&` or `||`")
-)]
+#[diagnostic(help("bitwise operators are not allowed, maybe you mistyped `&&` or `||`"))]
 struct NoBitwiseDiagnostic(&'static str, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -39,7 +36,17 @@ declare_oxc_lint!(
     /// var x = y | z;
     /// ```
     NoBitwise,
-    restriction
+    restriction,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": {
+                "allow": { "type": "array", "items": { "type": "string" }, "uniqueItems": true },
+                "int32Hint": { "type": "boolean" }
+            },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for NoBitwise {
@@ -11,7 +11,6 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-caller): Disallow the use of arguments.caller or arguments.callee")]
 #[diagnostic(
-    severity(warning),
     help(
         "'caller', 'callee', and 'arguments' properties may not be accessed on strict mode functions or the arguments objects for calls to them"
     )
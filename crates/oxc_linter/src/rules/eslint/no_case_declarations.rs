@@ -13,7 +13,6 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-case-declarations): Unexpected lexical declaration in case block.")]
-#[diagnostic(severity(warning))]
 struct NoCaseDeclarationsDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -70,8 +69,11 @@ impl Rule for NoCaseDeclarations {
                             let start = var.span.start;
                             let end = match var.kind {
                                 VariableDeclarationKind::Var => unreachable!(),
-                                VariableDeclarationKind::Const => 5,
+                                VariableDeclarationKind::Const | VariableDeclarationKind::Using => {
+                                    5
+                                }
                                 VariableDeclarationKind::Let => 3,
+                                VariableDeclarationKind::AwaitUsing => 11,
                             };
                             let end = start + end;
                             ctx.diagnostic(NoCaseDeclarationsDiagnostic(Span::new(start, end)));
@@ -107,6 +109,8 @@ fn test() {
         ("switch (a) { default: function f() {} break; }", None),
         ("switch (a) { case 1: class C {} break; }", None),
         ("switch (a) { default: class C {} break; }", None),
+        ("switch (a) { case 1: using x = foo(); break; }", None),
+        ("async function f() { switch (a) { case 1: await using x = foo(); } }", None),
     ];
 
     Tester::new(NoCaseDeclarations::NAME, pass, fail).test_and_snapshot();
@@ -10,7 +10,6 @@ use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-class-assign): Unexpected re-assignment of class {0}")]
-#[diagnostic(severity(warning))]
 struct NoClassAssignDiagnostic(
     Atom,
     #[label("{0} is declared as class here")] pub Span,
@@ -11,10 +11,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-compare-neg-zero): Do not use the {0} operator to compare against -0.")]
-#[diagnostic(
-    severity(warning),
-    help("Use Object.is(x, -0) to test equality with -0 and use 0 for other cases")
-)]
+#[diagnostic(help("Use Object.is(x, -0) to test equality with -0 and use 0 for other cases"))]
 struct NoCompareNegZeroDiagnostic(&'static str, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
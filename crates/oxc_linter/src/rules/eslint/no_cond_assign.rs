@@ -16,7 +16,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-cond-assign): Expected a conditional expression and instead saw an assignment")]
-#[diagnostic(severity(warning), help("Consider wrapping the assignment in additional parentheses"))]
+#[diagnostic(help("Consider wrapping the assignment in additional parentheses"))]
 struct NoCondAssignDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -42,7 +42,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoCondAssign,
-    correctness
+    correctness,
+    schema = r#"[{ "enum": ["except-parens", "always"] }]"#
 );
 
 impl Rule for NoCondAssign {
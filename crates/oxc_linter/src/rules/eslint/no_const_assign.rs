@@ -10,7 +10,6 @@ use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-const-assign): Unexpected re-assignment of const variable {0}")]
-#[diagnostic(severity(warning))]
 struct NoConstAssignDiagnostic(
     Atom,
     #[label("{0} is declared here as const")] pub Span,
@@ -93,6 +92,7 @@ fn test() {
         ("const [a, b, ...[c, ...d]] = [1, 2, 3, 4, 5]; d = 123", None),
         ("const d = 123; [a, b, ...[c, ...d]] = [1, 2, 3, 4, 5]", None),
         ("const b = 0; ({a, ...b} = {a: 1, c: 2, d: 3})", None),
+        ("{ using x = foo(); x = bar(); }", None),
     ];
 
     Tester::new(NoConstAssign::NAME, pass, fail).test_and_snapshot();
@@ -56,27 +56,24 @@ declare_oxc_lint!(
 #[error(
     "eslint(no-constant-binary-expression): Unexpected constant {0:?} on the left-hand side of a {1:?} expression"
 )]
-#[diagnostic(
-    severity(warning),
-    help("This expression always evaluates to the constant on the left-hand side")
-)]
+#[diagnostic(help("This expression always evaluates to the constant on the left-hand side"))]
 struct ConstantShortCircuit(&'static str, &'static str, #[label] Span);
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-constant-binary-expression): Unexpected constant binary expression")]
-#[diagnostic(severity(warning), help("This compares constantly with the {0}-hand side of the {1}"))]
+#[diagnostic(help("This compares constantly with the {0}-hand side of the {1}"))]
 struct ConstantBinaryOperand(&'static str, &'static str, #[label] Span);
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-constant-binary-expression): Unexpected comparison to newly constructed object")]
-#[diagnostic(severity(warning), help("These two values can never be equal"))]
+#[diagnostic(help("These two values can never be equal"))]
 struct ConstantAlwaysNew(#[label] Span);
 
 #[derive(Debug, Error, Diagnostic)]
 #[error(
     "eslint(no-constant-binary-expression): Unexpected comparison of two newly constructed objects"
 )]
-#[diagnostic(severity(warning), help("These two values can never be equal"))]
+#[diagnostic(help("These two values can never be equal"))]
 struct ConstantBothAlwaysNew(#[label] Span);
 
 impl Rule for NoConstantBinaryExpression {
@@ -254,7 +251,6 @@ impl NoConstantBinaryExpression {
                 _ => false,
             },
             Expression::CallExpression(call_expr) => call_expr.is_constant(true, ctx),
-            Expression::TemplateLiteral(lit) => lit.expressions.is_empty(),
             Expression::AssignmentExpression(assignment_expr) => {
                 assignment_expr.operator == AssignmentOperator::Assign
                     && Self::has_constant_loose_boolean_comparison(&assignment_expr.right, ctx)
@@ -267,7 +263,7 @@ impl NoConstantBinaryExpression {
             Expression::ParenthesizedExpression(paren_expr) => {
                 Self::has_constant_loose_boolean_comparison(&paren_expr.expression, ctx)
             }
-            expr if expr.is_literal_expression() => true,
+            expr if ast_util::is_literal_value(expr) => true,
             expr if expr.evaluate_to_undefined() => true,
             _ => false,
         }
@@ -662,6 +658,9 @@ fn test() {
         ("window.abc && false && anything", None),
         ("window.abc || true || anything", None),
         ("window.abc ?? 'non-nullish' ?? anything", None),
+        // Evaluated constant booleans
+        ("(1 < 2) === {}", None),
+        ("({}) == ('a' === 'a')", None),
     ];
 
     Tester::new(NoConstantBinaryExpression::NAME, pass, fail).test_and_snapshot();
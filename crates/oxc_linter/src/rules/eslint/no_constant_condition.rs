@@ -10,12 +10,18 @@ use crate::{ast_util::IsConstant, context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-constant-condition): Unexpected constant condition")]
-#[diagnostic(severity(warning), help("Constant expression as a test condition is not allowed"))]
+#[diagnostic(help("Constant expression as a test condition is not allowed"))]
 struct NoConstantConditionDiagnostic(#[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct NoConstantCondition {
-    _check_loops: bool,
+    check_loops: bool,
+}
+
+impl Default for NoConstantCondition {
+    fn default() -> Self {
+        Self { check_loops: true }
+    }
 }
 
 declare_oxc_lint!(
@@ -35,7 +41,14 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoConstantCondition,
-    correctness
+    correctness,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": { "checkLoops": { "type": "boolean" } },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for NoConstantCondition {
@@ -43,27 +56,61 @@ impl Rule for NoConstantCondition {
         let obj = value.get(0);
 
         Self {
-            _check_loops: obj
+            check_loops: obj
                 .and_then(|v| v.get("checkLoops"))
                 .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
+                .unwrap_or(true),
         }
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        match node.kind() {
-            AstKind::IfStatement(if_stmt) => {
-                if if_stmt.test.is_constant(true, ctx) {
-                    ctx.diagnostic(NoConstantConditionDiagnostic(if_stmt.test.span()));
-                }
+        let (test, is_loop) = match node.kind() {
+            AstKind::IfStatement(if_stmt) => (&if_stmt.test, false),
+            AstKind::ConditionalExpression(condition_expr) => (&condition_expr.test, false),
+            AstKind::WhileStatement(while_stmt) if self.check_loops => (&while_stmt.test, true),
+            AstKind::DoWhileStatement(do_while_stmt) if self.check_loops => {
+                (&do_while_stmt.test, true)
             }
-            AstKind::ConditionalExpression(condition_expr) => {
-                if condition_expr.test.is_constant(true, ctx) {
-                    ctx.diagnostic(NoConstantConditionDiagnostic(condition_expr.test.span()));
-                }
+            AstKind::ForStatement(for_stmt) if self.check_loops => {
+                let Some(test) = &for_stmt.test else { return };
+                (test, true)
             }
-            _ => {}
+            _ => return,
+        };
+        if !test.is_constant(true, ctx) {
+            return;
+        }
+        if is_loop && Self::yields_after_test(node, test.span(), ctx) {
+            return;
         }
+        ctx.diagnostic(NoConstantConditionDiagnostic(test.span()));
+    }
+}
+
+impl NoConstantCondition {
+    /// Constant loops in generators are allowed when they `yield` after the test is evaluated,
+    /// e.g. `function* foo() { while (true) { yield 'foo'; } }`
+    fn yields_after_test<'a>(node: &AstNode<'a>, test_span: Span, ctx: &LintContext<'a>) -> bool {
+        // The body of a `do while` loop is evaluated before its test
+        let start = if matches!(node.kind(), AstKind::DoWhileStatement(_)) {
+            node.kind().span().start
+        } else {
+            test_span.end
+        };
+        let end = node.kind().span().end;
+        ctx.nodes().iter().any(|yield_node| {
+            let AstKind::YieldExpression(yield_expr) = yield_node.kind() else { return false };
+            yield_expr.span.start >= start
+                && yield_expr.span.end <= end
+                && ctx.nodes().ancestors(yield_node.id()).take_while(|id| *id != node.id()).all(
+                    |id| {
+                        !matches!(
+                            ctx.nodes().kind(id),
+                            AstKind::Function(_) | AstKind::ArrowExpression(_)
+                        )
+                    },
+                )
+        })
     }
 }
 
@@ -71,6 +118,7 @@ impl Rule for NoConstantCondition {
 #[allow(clippy::too_many_lines)]
 fn test() {
     use crate::tester::Tester;
+    use serde_json::json;
 
     let pass = vec![
         ("if(a);", None),
@@ -199,30 +247,29 @@ fn test() {
         ("`foo${a}` === a ? 1 : 2", None),
         ("tag`a` === a ? 1 : 2", None),
         ("tag`${a}` === a ? 1 : 2", None),
-        //TODO
-        // ("while(~!a);", None),
-        // ("while(a = b);", None),
-        // ("while(`${a}`);", None),
-        // ("for(;x < 10;);", None),
-        // ("for(;;);", None),
-        // ("for(;`${a}`;);", None),
-        // ("do{ }while(x)", None),
-        // ("while(x += 3) {}", None),
-        // ("while(tag`a`) {}", None),
-        // ("while(tag`${a}`) {}", None),
-        // ("while(`\\\n${a}`) {}", None),
-        // ("while(true);", Some(json!([{"checkLoops":false}]))),
-        // ("for(;true;);", Some(json!([{"checkLoops":false}]))),
-        // ("do{}while(true)", Some(json!([{"checkLoops":false}]))),
-        // ("function* foo(){while(true){yield 'foo';}}", None),
-        // ("function* foo(){for(;true;){yield 'foo';}}", None),
-        // ("function* foo(){do{yield 'foo';}while(true)}", None),
-        // ("function* foo(){while (true) { while(true) {yield;}}}", None),
-        // ("function* foo() {for (; yield; ) {}}", None),
-        // ("function* foo() {for (; ; yield) {}}", None),
-        // ("function* foo() {while (true) {function* foo() {yield;}yield;}}", None),
-        // ("function* foo() { for (let x = yield; x < 10; x++) {yield;}yield;}", None),
-        // ("function* foo() { for (let x = yield; ; x++) { yield; }}", None),
+        ("while(~!a);", None),
+        ("while(a = b);", None),
+        ("while(`${a}`);", None),
+        ("for(;x < 10;);", None),
+        ("for(;;);", None),
+        ("for(;`${a}`;);", None),
+        ("do{ }while(x)", None),
+        ("while(x += 3) {}", None),
+        ("while(tag`a`) {}", None),
+        ("while(tag`${a}`) {}", None),
+        ("while(`\\\n${a}`) {}", None),
+        ("while(true);", Some(json!([{"checkLoops":false}]))),
+        ("for(;true;);", Some(json!([{"checkLoops":false}]))),
+        ("do{}while(true)", Some(json!([{"checkLoops":false}]))),
+        ("function* foo(){while(true){yield 'foo';}}", None),
+        ("function* foo(){for(;true;){yield 'foo';}}", None),
+        ("function* foo(){do{yield 'foo';}while(true)}", None),
+        ("function* foo(){while (true) { while(true) {yield;}}}", None),
+        ("function* foo() {for (; yield; ) {}}", None),
+        ("function* foo() {for (; ; yield) {}}", None),
+        ("function* foo() {while (true) {function* foo() {yield;}yield;}}", None),
+        ("function* foo() { for (let x = yield; x < 10; x++) {yield;}yield;}", None),
+        ("function* foo() { for (let x = yield; ; x++) { yield; }}", None),
     ];
 
     let fail = vec![
@@ -342,40 +389,44 @@ fn test() {
         ("`` ? 1 : 2;", None),
         ("`foo` ? 1 : 2;", None),
         ("`foo${bar}` ? 1 : 2;", None),
-        // TODO
-        // ("for(;true;);", None),
-        // ("for(;``;);", None),
-        // ("for(;`foo`;);", None),
-        // ("for(;`foo${bar}`;);", None),
-        // ("do{}while(true)", None),
-        // ("do{}while('1')", None),
-        // ("do{}while(0)", None),
-        // ("do{}while(t = -2)", None),
-        // ("do{}while(``)", None),
-        // ("do{}while(`foo`)", None),
-        // ("do{}while(`foo${bar}`)", None),
-        // ("while([]);", None),
-        // ("while(~!0);", None),
-        // ("while(x = 1);", None),
-        // ("while(function(){});", None),
-        // ("while(true);", None),
-        // ("while(1);", None),
-        // ("while(() => {});", None),
-        // ("while(`foo`);", None),
-        // ("while(``);", None),
-        // ("while(`${'foo'}`);", None),
-        // ("while(`${'foo' + 'bar'}`);", None),
-        // ("function* foo(){while(true){} yield 'foo';}", None),
-        // ("function* foo(){while(true){if (true) {yield 'foo';}}}", None),
-        // ("function* foo(){while(true){yield 'foo';} while(true) {}}", None),
-        // ("var a = function* foo(){while(true){} yield 'foo';}", None),
-        // ("while (true) { function* foo() {yield;}}", None),
-        // ("function* foo(){if (true) {yield 'foo';}}", None),
-        // ("function* foo() {for (let foo = yield; true;) {}}", None),
-        // ("function* foo() {for (foo = yield; true;) {}}", None),
-        // ("function foo() {while (true) {function* bar() {while (true) {yield;}}}}", None),
-        // ("function foo() {while (true) {const bar = function*() {while (true) {yield;}}}}", None),
-        // ("function* foo() { for (let foo = 1 + 2 + 3 + (yield); true; baz) {}}", None),
+        ("for(;true;);", None),
+        ("for(;``;);", None),
+        ("for(;`foo`;);", None),
+        ("for(;`foo${bar}`;);", None),
+        ("do{}while(true)", None),
+        ("do{}while('1')", None),
+        ("do{}while(0)", None),
+        ("do{}while(t = -2)", None),
+        ("do{}while(``)", None),
+        ("do{}while(`foo`)", None),
+        ("do{}while(`foo${bar}`)", None),
+        ("while([]);", None),
+        ("while(~!0);", None),
+        ("while(x = 1);", None),
+        ("while(function(){});", None),
+        ("while(true);", None),
+        ("while(1);", None),
+        ("while(() => {});", None),
+        ("while(`foo`);", None),
+        ("while(``);", None),
+        ("while(`${'foo'}`);", None),
+        ("while(`${'foo' + 'bar'}`);", None),
+        ("function* foo(){while(true){} yield 'foo';}", None),
+        ("function* foo(){while(true){if (true) {yield 'foo';}}}", None),
+        ("function* foo(){while(true){yield 'foo';} while(true) {}}", None),
+        ("var a = function* foo(){while(true){} yield 'foo';}", None),
+        ("while (true) { function* foo() {yield;}}", None),
+        ("function* foo(){if (true) {yield 'foo';}}", None),
+        ("function* foo() {for (let foo = yield; true;) {}}", None),
+        ("function* foo() {for (foo = yield; true;) {}}", None),
+        ("function foo() {while (true) {function* bar() {while (true) {yield;}}}}", None),
+        ("function foo() {while (true) {const bar = function*() {while (true) {yield;}}}}", None),
+        ("function* foo() { for (let foo = 1 + 2 + 3 + (yield); true; baz) {}}", None),
+        // Short circuits with evaluated constants
+        ("if (x || 1 + 1) {}", None),
+        ("if (x && 1 - 1) {}", None),
+        ("if (x || `${1}`) {}", None),
+        ("if (x && typeof 1 === 'string') {}", None),
     ];
 
     Tester::new(NoConstantCondition::NAME, pass, fail).test_and_snapshot();
@@ -1,4 +1,3 @@
-use lazy_static::lazy_static;
 use oxc_ast::{
     ast::{Argument, Expression, RegExpFlags},
     AstKind,
@@ -8,17 +7,17 @@ use oxc_diagnostics::{
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
+use oxc_regex_parser::{
+    ast::{Character, CharacterKind},
+    Parser, ParserOptions, Visit,
+};
 use oxc_span::{Atom, GetSpan, Span};
-use regex::{Matches, Regex};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-control-regex): Unexpected control character(s)")]
-#[diagnostic(
-    severity(warning),
-    help("Unexpected control character(s) in regular expression: \"{0}\"")
-)]
+#[diagnostic(help("Unexpected control character(s) in regular expression: \"{0}\""))]
 struct NoControlRegexDiagnostic(Atom, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -69,69 +68,44 @@ declare_oxc_lint!(
 
 impl Rule for NoControlRegex {
     fn run<'a>(&self, node: &AstNode<'a>, context: &LintContext<'a>) {
-        if let Some(RegexPatternData { pattern, flags, span }) = regex_pattern(node) {
-            let mut violations: Vec<&str> = Vec::new();
-            for matched_ctl_pattern in control_patterns(pattern) {
-                let ctl = matched_ctl_pattern.as_str();
-
-                // check for an even number of backslashes, since these will
-                // prevent the pattern from being a control sequence
-                if ctl.starts_with('\\') && matched_ctl_pattern.start() > 0 {
-                    let pattern_chars: Vec<char> = pattern.chars().collect(); // ew
-
-                    let mut first_backslash = matched_ctl_pattern.start();
-                    while first_backslash > 0 && pattern_chars[first_backslash] == '\\' {
-                        first_backslash -= 1;
-                    }
-
-                    let mut num_slashes = matched_ctl_pattern.start() - first_backslash;
-                    if first_backslash == 0 && pattern_chars[first_backslash] == '\\' {
-                        num_slashes += 1;
-                    }
-                    // even # of slashes
-                    if num_slashes % 2 == 0 {
-                        continue;
-                    }
-                }
-
-                if ctl.starts_with(r"\x") || ctl.starts_with(r"\u") {
-                    let mut numeric_part = &ctl[2..];
+        let Some(RegexPatternData { pattern, flags, span }) = regex_pattern(node) else {
+            return;
+        };
+        let options = ParserOptions::from_flags(flags.unwrap_or_else(RegExpFlags::empty));
+        // invalid patterns are reported by `no-invalid-regexp`
+        let Ok(parsed) = Parser::new(pattern, options).parse() else {
+            return;
+        };
 
-                    // extract numeric part from \u{00}
-                    if numeric_part.starts_with('{') {
-                        let has_unicode_flag = match flags {
-                            Some(flags) if flags.contains(RegExpFlags::U) => true,
-                            _ => {
-                                continue;
-                            }
-                        };
-
-                        // 1. Unicode control pattern is missing a curly brace
-                        //    and is therefore invalid. (note: we may want to
-                        //    report this?)
-                        // 2. Unicode flag is missing, which is needed for
-                        //    interpreting \u{`nn`} as a unicode character
-                        if !has_unicode_flag || !numeric_part.ends_with('}') {
-                            continue;
-                        }
-
-                        numeric_part = &numeric_part[1..numeric_part.len() - 1];
-                    }
-
-                    match u32::from_str_radix(numeric_part, 16) {
-                        Err(_) => continue,
-                        Ok(as_num) if as_num > 0x1f => continue,
-                        Ok(_) => { /* noop */ }
-                    }
-                }
+        let mut finder = ControlCharacterFinder { pattern, violations: vec![] };
+        finder.visit_pattern(&parsed);
+        if !finder.violations.is_empty() {
+            let violations = finder.violations.join(", ");
+            context.diagnostic(NoControlRegexDiagnostic(violations.into(), span));
+        }
+    }
+}
 
-                violations.push(ctl);
-            }
+/// Collects the control characters written as themselves or as `\x`, `\u` escapes, `\t`,
+/// `\n` and friends are fine.
+struct ControlCharacterFinder<'a> {
+    pattern: &'a str,
+    violations: Vec<&'a str>,
+}
 
-            if !violations.is_empty() {
-                let violations = violations.join(", ");
-                context.diagnostic(NoControlRegexDiagnostic(violations.into(), span));
-            }
+impl<'a> Visit for ControlCharacterFinder<'a> {
+    fn visit_character(&mut self, character: &Character) {
+        if character.value <= 0x1f
+            && matches!(
+                character.kind,
+                CharacterKind::Symbol
+                    | CharacterKind::HexEscape
+                    | CharacterKind::UnicodeEscape
+                    | CharacterKind::CodePointEscape
+            )
+        {
+            let Span { start, end } = character.span;
+            self.violations.push(&self.pattern[start as usize..end as usize]);
         }
     }
 }
@@ -246,16 +220,6 @@ fn regex_pattern<'a>(node: &AstNode<'a>) -> Option<RegexPatternData<'a>> {
     }
 }
 
-fn control_patterns(pattern: &Atom) -> Matches<'static, '_> {
-    lazy_static! {
-        static ref CTL_PAT: Regex = Regex::new(
-            r"([\x00-\x1f]|(?:\\x\w{2})|(?:\\u\w{4})|(?:\\u\{\w{1,4}\}))"
-            // r"((?:\\x\w{2}))"
-        ).unwrap();
-    }
-    CTL_PAT.find_iter(pattern.as_str())
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -273,7 +237,8 @@ mod tests {
             ],
             vec![r"new RegExp('\x00')", r"/\x00/", r"new RegExp('\x1f')", r"/\x1f/"],
         )
-        .test();
+        .with_snapshot_suffix("hex_literals")
+        .test_and_snapshot();
     }
 
     #[test]
@@ -302,7 +267,8 @@ mod tests {
                 r"let r = new RegExp('\\u001f');",
             ],
         )
-        .test();
+        .with_snapshot_suffix("unicode_literals")
+        .test_and_snapshot();
     }
 
     #[test]
@@ -322,7 +288,8 @@ mod tests {
                 r"let r = new RegExp('\\u{1F}', 'u');", // flags are known & contain u
             ],
         )
-        .test();
+        .with_snapshot_suffix("unicode_brackets")
+        .test_and_snapshot();
     }
 
     #[test]
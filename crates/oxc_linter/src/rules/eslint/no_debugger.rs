@@ -10,7 +10,6 @@ use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-debugger): `debugger` statement is not allowed")]
-#[diagnostic(severity(warning))]
 struct NoDebuggerDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -31,7 +30,8 @@ declare_oxc_lint!(
     /// debugger;
     /// ```
     NoDebugger,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoDebugger {
@@ -11,7 +11,6 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-delete-var): variables should not be deleted")]
-#[diagnostic(severity(warning))]
 struct NoDeleteVarDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -0,0 +1,81 @@
+use oxc_ast::{syntax_directed_operations::BoundNames, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-dupe-args): Duplicate param '{0}'")]
+#[diagnostic(help("Rename or remove the duplicated parameter"))]
+struct NoDupeArgsDiagnostic(Atom, #[label] pub Span, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDupeArgs;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow duplicate arguments in `function` definitions
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If more than one parameter has the same name in a function definition, the last
+    /// occurrence "shadows" the preceding occurrences. A duplicated name might be a typo.
+    ///
+    /// Duplicate parameters are only allowed outside of strict mode, in functions with simple
+    /// parameter lists, and are syntax errors everywhere else.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function foo(a, b, a) {
+    ///     console.log("value of the second a:", a);
+    /// }
+    /// ```
+    NoDupeArgs,
+    correctness
+);
+
+impl Rule for NoDupeArgs {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::FormalParameters(params) = node.kind() else { return };
+        let mut map = FxHashMap::default();
+        params.bound_names(&mut |ident| {
+            if let Some(prev_span) = map.insert(ident.name.clone(), ident.span) {
+                ctx.diagnostic(NoDupeArgsDiagnostic(ident.name.clone(), prev_span, ident.span));
+            }
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function a(a, b, c){}", None),
+        ("var a = function(a, b, c){}", None),
+        ("function a({a, b}, {c, d}){}", None),
+        ("function a([ , a]){}", None),
+        ("function foo([[a, b], [c, d]]){}", None),
+        ("function a(a, b){ var a; }", None),
+        ("function a(a){ function b(a){} }", None),
+    ];
+
+    let fail = vec![
+        ("function a(a, b, b) {}", None),
+        ("function a(a, a, a) {}", None),
+        ("function a(a, b, a) {}", None),
+        ("function a(a, b, a, b) {}", None),
+        ("var a = function(a, b, b) {}", None),
+        ("var a = function(a, a, a) {}", None),
+        ("var a = function(a, b, a) {}", None),
+        ("var a = function(a, b, a, b) {}", None),
+    ];
+
+    Tester::new(NoDupeArgs::NAME, pass, fail).test_and_snapshot();
+}
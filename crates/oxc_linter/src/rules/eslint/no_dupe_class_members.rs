@@ -17,7 +17,6 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-dupe-class-members): Duplicate class member: {0:?}")]
 #[diagnostic(
-    severity(warning),
     help(
         "The last declaration overwrites previous ones, remove one of them or rename if both should be retained"
     )
@@ -15,7 +15,6 @@ use crate::{ast_util::calculate_hash, context::LintContext, rule::Rule, AstNode}
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-dupe-else-if): duplicate conditions in if-else-if chains")]
 #[diagnostic(
-    severity(warning),
     help(
         "This branch can never execute. Its condition is a duplicate or covered by previous conditions in the if-else-if chain"
     )
@@ -1,6 +1,5 @@
-use lazy_static::lazy_static;
 use oxc_ast::{
-    ast::{Expression, ObjectPropertyKind, PropertyKey, PropertyKind},
+    ast::{ObjectPropertyKind, PropertyKind},
     AstKind,
 };
 use oxc_diagnostics::{
@@ -11,11 +10,11 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use rustc_hash::FxHashMap;
 
-use crate::{ast_util::calculate_hash, context::LintContext, rule::Rule, AstNode};
+use crate::{ast_util::get_static_property_name, context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-dupe-keys): Disallow duplicate keys in object literals")]
-#[diagnostic(severity(warning), help("Consider removing the duplicated key"))]
+#[diagnostic(help("Consider removing the duplicated key"))]
 struct NoDupeKeysDiagnostic(#[label] pub Span, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -47,8 +46,8 @@ impl Rule for NoDupeKeys {
         let mut map = FxHashMap::default();
         for prop in &obj_expr.properties {
             let ObjectPropertyKind::ObjectProperty(prop) = prop else { continue };
-            let Some(hash) = calculate_property_kind_hash(&prop.key) else { continue };
-            if let Some((prev_kind, prev_span)) = map.insert(hash, (prop.kind, prop.key.span())) {
+            let Some(name) = get_static_property_name(&prop.key) else { continue };
+            if let Some((prev_kind, prev_span)) = map.insert(name, (prop.kind, prop.key.span())) {
                 if prev_kind == PropertyKind::Init
                     || prop.kind == PropertyKind::Init
                     || prev_kind == prop.kind
@@ -60,33 +59,6 @@ impl Rule for NoDupeKeys {
     }
 }
 
-// todo: should this be located within oxc_ast?
-fn calculate_property_kind_hash(key: &PropertyKey) -> Option<u64> {
-    lazy_static! {
-        static ref NULL_HASH: u64 = calculate_hash(&"null");
-    }
-
-    match key {
-        PropertyKey::Identifier(ident) => Some(calculate_hash(&ident)),
-        PropertyKey::PrivateIdentifier(_) => None,
-        PropertyKey::Expression(expr) => match expr {
-            Expression::StringLiteral(lit) => Some(calculate_hash(&lit.value)),
-            // note: hashes won't work as expected if these aren't strings. Save
-            // NumberLiteral I don't think this should be too much of a problem
-            // b/c most people don't use `null`, regexes, etc. as object
-            // property keys when writing real code.
-            Expression::RegExpLiteral(lit) => Some(calculate_hash(&lit.regex.to_string())),
-            Expression::NumberLiteral(lit) => Some(calculate_hash(&lit.value.to_string())),
-            Expression::BigintLiteral(lit) => Some(calculate_hash(&lit.value.to_string())),
-            Expression::NullLiteral(_) => Some(*NULL_HASH),
-            Expression::TemplateLiteral(lit) => {
-                lit.expressions.is_empty().then(|| lit.quasi()).flatten().map(calculate_hash)
-            }
-            _ => None,
-        },
-    }
-}
-
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -128,6 +100,9 @@ fn test() {
         ("var x = { a: 1, set a(value) {} };", None),
         ("var x = { a: 1, b: { a: 2 }, get b() {} };", None),
         ("var x = ({ '/(?<zero>0)/': 1, [/(?<zero>0)/]: 2 })", None),
+        ("var x = { 1.5: 1, '1.5': 2 };", None),
+        ("var x = { true: 1, [true]: 2 };", None),
+        ("var x = { null: 1, [null]: 2 };", None),
     ];
 
     Tester::new(NoDupeKeys::NAME, pass, fail).test_and_snapshot();
@@ -11,7 +11,7 @@ use crate::{ast_util::calculate_hash, context::LintContext, rule::Rule, AstNode}
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-duplicate-case): Disallow duplicate case labels")]
-#[diagnostic(severity(warning), help("Remove the duplicated case"))]
+#[diagnostic(help("Remove the duplicated case"))]
 struct NoDuplicateCaseDiagnostic(#[label] pub Span, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
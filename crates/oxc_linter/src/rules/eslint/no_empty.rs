@@ -10,7 +10,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-empty): Disallow empty block statements")]
-#[diagnostic(severity(warning), help("Add comment inside empty {0} statement"))]
+#[diagnostic(help("Add comment inside empty {0} statement"))]
 struct NoEmptyDiagnostic(&'static str, #[label("Empty {0} statement")] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -34,6 +34,13 @@ declare_oxc_lint!(
     /// ```
     NoEmpty,
     restriction,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": { "allowEmptyCatch": { "type": "boolean" } },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for NoEmpty {
@@ -13,7 +13,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-empty-character-class): Empty character class")]
-#[diagnostic(severity(warning), help("Try to remove empty character class `[]` in regexp literal"))]
+#[diagnostic(help("Try to remove empty character class `[]` in regexp literal"))]
 struct NoEmptyCharacterClassDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -13,7 +13,6 @@ use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-eval): eval can be harmful.")]
-#[diagnostic(severity(warning))]
 struct NoEvalDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -49,7 +48,14 @@ declare_oxc_lint!(
     /// eval(someString);
     /// ```
     NoEval,
-    restriction
+    restriction,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": { "allowIndirect": { "type": "boolean" } },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for NoEval {
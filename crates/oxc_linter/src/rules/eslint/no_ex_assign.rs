@@ -10,7 +10,6 @@ use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-ex-assign): Do not assign to the exception parameter.")]
-#[diagnostic(severity(warning))]
 struct NoExAssignDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -12,18 +12,12 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-extra-boolean-cast): Redundant double negation")]
-#[diagnostic(
-    severity(warning),
-    help("Remove the double negation as it will already be coerced to a boolean")
-)]
+#[diagnostic(help("Remove the double negation as it will already be coerced to a boolean"))]
 struct NoExtraDoubleNegationCastDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-extra-boolean-cast): Redundant Boolean call")]
-#[diagnostic(
-    severity(warning),
-    help("Remove the Boolean call as it will already be coerced to a boolean")
-)]
+#[diagnostic(help("Remove the Boolean call as it will already be coerced to a boolean"))]
 struct NoExtraBooleanCastDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -51,7 +45,14 @@ declare_oxc_lint!(
     /// if (!!foo || bar) {}
     /// ```
     NoExtraBooleanCast,
-    correctness
+    correctness,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": { "enforceForLogicalOperands": { "type": "boolean" } },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for NoExtraBooleanCast {
@@ -0,0 +1,337 @@
+use lazy_static::lazy_static;
+use oxc_ast::{
+    ast::{Statement, SwitchCase},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use regex::Regex;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-fallthrough): Expected a 'break' statement before '{0}'")]
+#[diagnostic(help(
+    "Add a `break`, or a `// falls through` comment if the fallthrough is intended"
+))]
+struct NoFallthroughDiagnostic(&'static str, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoFallthrough {
+    /// Replaces the default `falls?\s?through` pattern of the comments which allow a fallthrough
+    comment_pattern: Option<Regex>,
+    /// Allow empty cases separated by blank lines
+    allow_empty_case: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow fallthrough of `case` statements
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A case without a `break`, `return` or `throw` continues into the next case, which is
+    /// often a mistake. An intended fallthrough can be marked with a comment matching
+    /// `falls?\s?through` right before the next case, such as `// falls through`.
+    ///
+    /// Cases without statements are allowed, unless they are separated from the next case by a
+    /// blank line and `allowEmptyCase` is not set.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// switch (foo) {
+    ///     case 1:
+    ///         doSomething();
+    ///     case 2:
+    ///         doSomethingElse();
+    /// }
+    /// ```
+    NoFallthrough,
+    correctness,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": {
+                "commentPattern": { "type": "string" },
+                "allowEmptyCase": { "type": "boolean" }
+            },
+            "additionalProperties": false
+        }
+    ]"#
+);
+
+impl Rule for NoFallthrough {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        Self {
+            comment_pattern: config
+                .and_then(|v| v.get("commentPattern"))
+                .and_then(serde_json::Value::as_str)
+                .and_then(|pattern| Regex::new(pattern).ok()),
+            allow_empty_case: config
+                .and_then(|v| v.get("allowEmptyCase"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::SwitchStatement(switch) = node.kind() else { return };
+        for (case, next_case) in switch.cases.iter().zip(switch.cases.iter().skip(1)) {
+            if !case_completes_normally(case) || self.has_fallthrough_comment(case, next_case, ctx)
+            {
+                continue;
+            }
+            if case.consequent.is_empty()
+                && (self.allow_empty_case || !has_blank_line_between(case, next_case, ctx))
+            {
+                continue;
+            }
+            let keyword = if next_case.is_default_case() { "default" } else { "case" };
+            ctx.diagnostic(NoFallthroughDiagnostic(keyword, next_case.span));
+        }
+    }
+}
+
+impl NoFallthrough {
+    /// Whether the last comment before `next_case` marks the fallthrough as intended
+    fn has_fallthrough_comment(
+        &self,
+        case: &SwitchCase,
+        next_case: &SwitchCase,
+        ctx: &LintContext,
+    ) -> bool {
+        lazy_static! {
+            static ref DEFAULT_PATTERN: Regex = Regex::new(r"(?i)falls?\s?through").unwrap();
+        }
+        // The comment may be the last thing in a block, e.g. `case 1: { foo(); // falls through }`
+        let start = match case.consequent.last() {
+            Some(Statement::BlockStatement(block)) => {
+                block.body.last().map_or(block.span.start, |stmt| stmt.span().end)
+            }
+            Some(stmt) => stmt.span().end,
+            None => case.span.start,
+        };
+        let comments = ctx.semantic().trivias().comments();
+        let Some((comment_start, comment)) = comments.range(start..next_case.span.start).last()
+        else {
+            return false;
+        };
+        let text = &ctx.source_text()[*comment_start as usize..comment.end() as usize];
+        self.comment_pattern.as_ref().unwrap_or(&DEFAULT_PATTERN).is_match(text.trim())
+    }
+}
+
+/// `case 1:`, a blank line, then `case 2:` looks like a mistake
+fn has_blank_line_between(case: &SwitchCase, next_case: &SwitchCase, ctx: &LintContext) -> bool {
+    let between = &ctx.source_text()[case.span.end as usize..next_case.span.start as usize];
+    between.matches('\n').count() > 1
+}
+
+fn case_completes_normally(case: &SwitchCase) -> bool {
+    case.consequent.iter().all(completes_normally)
+}
+
+/// Whether the statement can continue to the statement which follows it, e.g. a `return` cannot,
+/// and an `if` can when one of its branches can.
+fn completes_normally(stmt: &Statement) -> bool {
+    match stmt {
+        Statement::BreakStatement(_)
+        | Statement::ContinueStatement(_)
+        | Statement::ReturnStatement(_)
+        | Statement::ThrowStatement(_) => false,
+        Statement::BlockStatement(block) => block.body.iter().all(completes_normally),
+        Statement::IfStatement(stmt) => {
+            completes_normally(&stmt.consequent)
+                || stmt.alternate.as_ref().map_or(true, completes_normally)
+        }
+        Statement::TryStatement(stmt) => {
+            if stmt
+                .finalizer
+                .as_ref()
+                .is_some_and(|block| !block.body.iter().all(completes_normally))
+            {
+                return false;
+            }
+            stmt.block.body.iter().all(completes_normally)
+                || stmt
+                    .handler
+                    .as_ref()
+                    .is_some_and(|handler| handler.body.body.iter().all(completes_normally))
+        }
+        Statement::LabeledStatement(stmt) => {
+            completes_normally(&stmt.body) || has_break(&stmt.body, false, Some(&stmt.label.name))
+        }
+        // An infinite loop only completes with a `break`
+        Statement::WhileStatement(stmt) if stmt.test.get_boolean_value() == Some(true) => {
+            has_break(&stmt.body, true, None)
+        }
+        Statement::ForStatement(stmt)
+            if stmt.test.as_ref().map_or(true, |test| test.get_boolean_value() == Some(true)) =>
+        {
+            has_break(&stmt.body, true, None)
+        }
+        Statement::DoWhileStatement(stmt) => {
+            if stmt.test.get_boolean_value() == Some(true) {
+                has_break(&stmt.body, true, None)
+            } else {
+                completes_normally(&stmt.body) || has_break(&stmt.body, true, None)
+            }
+        }
+        // Without a `default`, no case may match
+        Statement::SwitchStatement(stmt) => {
+            !stmt.cases.iter().any(SwitchCase::is_default_case)
+                || stmt.cases.last().map_or(true, case_completes_normally)
+                || stmt
+                    .cases
+                    .iter()
+                    .flat_map(|case| &case.consequent)
+                    .any(|stmt| has_break(stmt, true, None))
+        }
+        _ => true,
+    }
+}
+
+/// Whether `stmt` contains a `break` out of it, either an unlabeled one when `unlabeled` is set,
+/// or one to `label`
+fn has_break(stmt: &Statement, unlabeled: bool, label: Option<&str>) -> bool {
+    let has_break_in = |stmts: &[Statement]| stmts.iter().any(|s| has_break(s, unlabeled, label));
+    match stmt {
+        Statement::BreakStatement(stmt) => {
+            stmt.label.as_ref().map_or(unlabeled, |target| Some(target.name.as_str()) == label)
+        }
+        Statement::BlockStatement(block) => has_break_in(&block.body),
+        Statement::IfStatement(stmt) => {
+            has_break(&stmt.consequent, unlabeled, label)
+                || stmt.alternate.as_ref().is_some_and(|alt| has_break(alt, unlabeled, label))
+        }
+        Statement::TryStatement(stmt) => {
+            has_break_in(&stmt.block.body)
+                || stmt.handler.as_ref().is_some_and(|handler| has_break_in(&handler.body.body))
+                || stmt.finalizer.as_ref().is_some_and(|block| has_break_in(&block.body))
+        }
+        Statement::LabeledStatement(stmt) => has_break(&stmt.body, unlabeled, label),
+        Statement::WithStatement(stmt) => has_break(&stmt.body, unlabeled, label),
+        // An unlabeled `break` in a nested loop or switch exits that one instead
+        Statement::WhileStatement(stmt) => has_break(&stmt.body, false, label),
+        Statement::DoWhileStatement(stmt) => has_break(&stmt.body, false, label),
+        Statement::ForStatement(stmt) => has_break(&stmt.body, false, label),
+        Statement::ForInStatement(stmt) => has_break(&stmt.body, false, label),
+        Statement::ForOfStatement(stmt) => has_break(&stmt.body, false, label),
+        Statement::SwitchStatement(stmt) => stmt
+            .cases
+            .iter()
+            .flat_map(|case| &case.consequent)
+            .any(|stmt| has_break(stmt, false, label)),
+        _ => false,
+    }
+}
+
+#[test]
+#[allow(clippy::too_many_lines)]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("switch(foo) { case 0: a(); /* falls through */ case 1: b(); }", None),
+        ("switch(foo) { case 0: a()\n /* falls through */ case 1: b(); }", None),
+        ("switch(foo) { case 0: a(); /* fall through */ case 1: b(); }", None),
+        ("switch(foo) { case 0: a(); /* fallthrough */ case 1: b(); }", None),
+        ("switch(foo) { case 0: a(); /* FALLS THROUGH */ case 1: b(); }", None),
+        ("switch(foo) { case 0: { a(); /* falls through */ } case 1: b(); }", None),
+        ("switch(foo) { case 0: { a()\n /* falls through */ } case 1: b(); }", None),
+        ("switch(foo) { case 0: a(); // falls through\n case 1: b(); }", None),
+        ("function foo() { switch(foo) { case 0: a(); return; case 1: b(); }; }", None),
+        ("switch(foo) { case 0: a(); throw 'foo'; case 1: b(); }", None),
+        ("while (a) { switch(foo) { case 0: a(); continue; case 1: b(); } }", None),
+        ("switch(foo) { case 0: case 1: a(); break; case 2: b(); }", None),
+        ("switch(foo) { case 0: case 1: break; case 2: b(); }", None),
+        ("switch(foo) { case 0: case 1: break; default: b(); }", None),
+        ("switch(foo) { case 0: case 1: a(); }", None),
+        ("switch(foo) { case 0: case 1: a(); break; }", None),
+        ("switch(foo) { case 0: case 1: break; }", None),
+        ("switch(foo) { case 0:\n case 1: break; }", None),
+        ("switch(foo) { case 0: // comment\n case 1: break; }", None),
+        ("function foo() { switch(foo) { case 0: case 1: return; } }", None),
+        ("function foo() { switch(foo) { case 0: {return;}\n case 1: {return;} } }", None),
+        ("switch(foo) { case 0: case 1: {break;} }", None),
+        ("switch(foo) { }", None),
+        ("switch(foo) { case 0: switch(bar) { case 2: break; } /* falls through */ case 1: break; }", None),
+        ("function foo() { switch(foo) { case 1: return a; a++; }}", None),
+        ("switch (foo) { case 0: a(); /* falls through */ default:  b(); /* comment */ }", None),
+        ("switch (foo) { case 0: a(); /* falls through */ default: /* comment */ b(); }", None),
+        ("switch (foo) { case 0: if (a) { break; } else { throw 0; } default: b(); }", None),
+        ("switch (foo) { case 0: try { break; } finally {} default: b(); }", None),
+        ("switch (foo) { case 0: try {} finally { break; } default: b(); }", None),
+        ("switch (foo) { case 0: try { throw 0; } catch (err) { break; } default: b(); }", None),
+        ("switch (foo) { case 0: do { throw 0; } while(a); default: b(); }", None),
+        ("switch (foo) { case 0: while (true) {} default: b(); }", None),
+        ("switch (foo) { case 0: for (;;) {} default: b(); }", None),
+        (
+            "function f() { switch (foo) { case 0: switch (bar) { case 1: return; default: throw 0; } default: b(); } }",
+            None,
+        ),
+        ("switch (foo) { case 0: a(); \n// eslint-disable-next-line no-fallthrough\n case 1: }", None),
+        (
+            "switch(foo) { case 0: a(); /* no break */ case 1: b(); }",
+            Some(json!([{ "commentPattern": "no break" }])),
+        ),
+        (
+            "switch(foo) { case 0: a(); /* no break: need to execute b() */ case 1: b(); }",
+            Some(json!([{ "commentPattern": "^no break" }])),
+        ),
+        (
+            "switch(foo) { case 0: a(); /* break omitted */ default:  b(); /* comment */ }",
+            Some(json!([{ "commentPattern": "break omitted" }])),
+        ),
+        ("switch(foo) { case 0: \n\n\n case 1: b(); }", Some(json!([{ "allowEmptyCase": true }]))),
+        (
+            "switch(foo) { case 0: \n /* with comments */  \n case 1: b(); }",
+            Some(json!([{ "allowEmptyCase": true }])),
+        ),
+        (
+            "switch (a) {\n case 1: ; break; \n case 3: }",
+            Some(json!([{ "allowEmptyCase": true }])),
+        ),
+        ("switch (a) {\n case 1: ; break; \n case 3: }", Some(json!([{ "allowEmptyCase": false }]))),
+    ];
+
+    let fail = vec![
+        ("switch(foo) { case 0: a();\ncase 1: b() }", None),
+        ("switch(foo) { case 0: a();\ndefault: b() }", None),
+        ("switch(foo) { case 0: a(); default: b() }", None),
+        ("switch(foo) { case 0: if (a) { break; } default: b() }", None),
+        ("switch(foo) { case 0: try { throw 0; } catch (err) {} default: b() }", None),
+        ("switch(foo) { case 0: while (a) { break; } default: b() }", None),
+        ("switch(foo) { case 0: do { break; } while (a); default: b() }", None),
+        ("switch(foo) { case 0: while (true) { break; } default: b() }", None),
+        ("switch(foo) { case 0: for (;;) { if (a) break; } default: b() }", None),
+        ("switch(foo) { case 0: label: { break label; } default: b() }", None),
+        ("switch(foo) { case 0: switch (bar) { case 1: break; default: throw 0; } default: b() }", None),
+        ("switch(foo) { case 0: switch (bar) { case 1: throw 0; } default: b() }", None),
+        ("switch(foo) {\n case 0:\n\n default: b() }", None),
+        ("switch(foo) { case 0: {} default: b() }", None),
+        ("switch(foo) { case 0: a(); /* falling through */ default: b() }", None),
+        ("switch(foo) { case 0: a(); /* falls through */ b(); default: c() }", None),
+        ("switch(foo) { case 0: a();\n/* falls through */ case 1: b();\ncase 2: c() }", None),
+        (
+            "switch(foo) { case 0: a();\n/* no break */\ncase 1: b(); }",
+            Some(json!([{ "commentPattern": "break omitted" }])),
+        ),
+        (
+            "switch(foo) { case 0: a();\n/* no break */\n/* todo: fix readability */\ndefault: b() }",
+            Some(json!([{ "commentPattern": "no break" }])),
+        ),
+        ("switch(foo) { case 0: \n\n\n case 1: b(); }", Some(json!([{ "allowEmptyCase": false }]))),
+        ("switch(foo) { case 0: \n\n\n case 1: b(); }", Some(json!([{}]))),
+    ];
+
+    Tester::new(NoFallthrough::NAME, pass, fail).test_and_snapshot();
+}
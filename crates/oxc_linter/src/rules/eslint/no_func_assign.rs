@@ -1,4 +1,3 @@
-use oxc_ast::AstKind;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::{self, Error},
@@ -11,7 +10,6 @@ use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-func-assign): '{0}' is a function.")]
-#[diagnostic(severity(warning))]
 struct NoFuncAssignDiagnostic(Atom, #[label("{0} is re-assigned here")] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -37,8 +35,7 @@ declare_oxc_lint!(
 impl Rule for NoFuncAssign {
     fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
         let symbol_table = ctx.semantic().symbols();
-        let decl = symbol_table.get_declaration(symbol_id);
-        if let AstKind::Function(_) = ctx.nodes().kind(decl) {
+        if symbol_table.get_flag(symbol_id).is_function() {
             for reference in symbol_table.get_resolved_references(symbol_id) {
                 if reference.is_write() {
                     ctx.diagnostic(NoFuncAssignDiagnostic(
@@ -5,11 +5,10 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, Span};
 
-use crate::{context::LintContext, globals::BUILTINS, rule::Rule};
+use crate::{config::GlobalValue, context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-global-assign): Read-only global '{0}' should not be modified.")]
-#[diagnostic(severity(warning))]
 struct NoGlobalAssignDiagnostic(
     Atom,
     #[label("Read-only global '{0}' should not be modified.")] pub Span,
@@ -32,7 +31,16 @@ declare_oxc_lint!(
     /// Object = null
     /// ```
     NoGlobalAssign,
-    correctness
+    correctness,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": {
+                "exceptions": { "type": "array", "items": { "type": "string" }, "uniqueItems": true }
+            },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for NoGlobalAssign {
@@ -60,7 +68,9 @@ impl Rule for NoGlobalAssign {
                 if reference.is_write() && symbol_table.is_global_reference(reference_id) {
                     let name = reference.name();
 
-                    if !self.excludes.contains(name) && BUILTINS.contains_key(name) {
+                    if !self.excludes.contains(name)
+                        && ctx.global(name) == Some(GlobalValue::Readonly)
+                    {
                         ctx.diagnostic(NoGlobalAssignDiagnostic(name.clone(), reference.span()));
                     }
                 }
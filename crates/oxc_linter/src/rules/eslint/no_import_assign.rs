@@ -10,7 +10,7 @@ use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-import-assign): do not assign to imported bindings")]
-#[diagnostic(severity(warning), help("imported bindings are readonly"))]
+#[diagnostic(help("imported bindings are readonly"))]
 struct NoImportAssignDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -12,7 +12,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 #[error(
     "eslint(no-inner-declarations): Variable or `function` declarations are not allowed in nested blocks"
 )]
-#[diagnostic(severity(warning), help("Move {0} declaration to {1} root"))]
+#[diagnostic(help("Move {0} declaration to {1} root"))]
 struct NoInnerDeclarationsDiagnostic(&'static str, &'static str, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -48,7 +48,10 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoInnerDeclarations,
-    correctness
+    correctness,
+    schema = r#"[
+        { "enum": ["functions", "both"] }
+    ]"#
 );
 
 impl Rule for NoInnerDeclarations {
@@ -0,0 +1,201 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_regex_parser::{parse_flags, Parser, ParserOptions};
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-invalid-regexp): Invalid regular expression")]
+#[diagnostic(help("{0}"))]
+struct NoInvalidRegexpDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoInvalidRegexp {
+    allow_constructor_flags: Vec<char>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow invalid regular expression strings in `RegExp` constructors
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An invalid pattern in a regular expression literal is a syntax error when the code is
+    /// parsed, but an invalid string in a `RegExp` constructor only throws an exception when the
+    /// code is executed.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// RegExp('[')
+    /// RegExp('.', 'z')
+    /// new RegExp('\\')
+    /// ```
+    NoInvalidRegexp,
+    correctness,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": {
+                "allowConstructorFlags": { "type": "array", "items": { "type": "string" } }
+            },
+            "additionalProperties": false
+        }
+    ]"#
+);
+
+impl Rule for NoInvalidRegexp {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_constructor_flags: value
+                .get(0)
+                .and_then(|v| v.get("allowConstructorFlags"))
+                .and_then(serde_json::Value::as_array)
+                .map(|v| {
+                    v.iter().filter_map(serde_json::Value::as_str).flat_map(str::chars).collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (callee, arguments, span) = match node.kind() {
+            AstKind::NewExpression(expr) => (&expr.callee, &expr.arguments, expr.span),
+            AstKind::CallExpression(expr) => (&expr.callee, &expr.arguments, expr.span),
+            _ => return,
+        };
+        if !callee.is_specific_id("RegExp") {
+            return;
+        }
+        let pattern = match arguments.first() {
+            Some(Argument::Expression(Expression::StringLiteral(pattern))) => {
+                Some(pattern.value.as_str())
+            }
+            _ => None,
+        };
+        // `None` when the flags are not known statically
+        let flags = match arguments.get(1) {
+            None => Some(""),
+            Some(Argument::Expression(Expression::StringLiteral(flags))) => {
+                Some(flags.value.as_str())
+            }
+            Some(_) => None,
+        };
+
+        let parsed_flags = match flags {
+            Some(flags) => {
+                let checked_flags = flags
+                    .chars()
+                    .filter(|c| !self.allow_constructor_flags.contains(c))
+                    .collect::<String>();
+                let Ok(parsed_flags) = parse_flags(&checked_flags) else {
+                    let message = format!("Invalid flags supplied to RegExp constructor '{flags}'");
+                    ctx.diagnostic(NoInvalidRegexpDiagnostic(message, span));
+                    return;
+                };
+                Some(parsed_flags)
+            }
+            None => None,
+        };
+
+        let Some(pattern) = pattern else {
+            return;
+        };
+        let error = parsed_flags.map_or_else(
+            // Unknown flags: only report a pattern which is invalid with and without the `u` and
+            // `v` flags, the error without them is reported
+            || {
+                [
+                    ParserOptions { unicode_mode: true, unicode_sets_mode: false },
+                    ParserOptions { unicode_mode: true, unicode_sets_mode: true },
+                    ParserOptions::default(),
+                ]
+                .into_iter()
+                .map(|options| Parser::new(pattern, options).parse().err())
+                .collect::<Option<Vec<_>>>()
+                .and_then(|errors| errors.into_iter().last())
+            },
+            |parsed_flags| {
+                Parser::new(pattern, ParserOptions::from_flags(parsed_flags)).parse().err()
+            },
+        );
+        if let Some(error) = error {
+            let flags = flags.unwrap_or_default();
+            let message = format!("Invalid regular expression: /{pattern}/{flags}: {error}");
+            ctx.diagnostic(NoInvalidRegexpDiagnostic(message, span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("RegExp('')", None),
+        ("RegExp()", None),
+        ("RegExp('.', 'g')", None),
+        ("new RegExp('.')", None),
+        ("new RegExp", None),
+        ("new RegExp('.', 'im')", None),
+        ("global.RegExp('\\\\')", None),
+        ("new RegExp('.', y)", None),
+        ("new RegExp('.', 'y')", None),
+        ("new RegExp('.', 'u')", None),
+        ("new RegExp('.', 'yu')", None),
+        ("new RegExp('/', 'yu')", None),
+        ("new RegExp('\\/', 'yu')", None),
+        ("new RegExp('\\\\u{65}', 'u')", None),
+        ("new RegExp('[\\\\u{0}-\\\\u{1F}]', 'u')", None),
+        ("new RegExp('.', 's')", None),
+        ("new RegExp('(?<=a)b')", None),
+        ("new RegExp('(?<!a)b')", None),
+        ("new RegExp('(?<a>b)\\\\k<a>')", None),
+        ("new RegExp('(?<a>b)\\\\k<a>', 'u')", None),
+        ("new RegExp('\\\\p{Letter}', 'u')", None),
+        ("new RegExp('.', 'd')", None),
+        ("new RegExp('[A--B]', 'v')", None),
+        ("new RegExp('[\\\\p{RGI_Emoji}--\\\\q{🇫🇷}]', 'v')", None),
+        // ES2018: `]` and `{` are valid without the `u` flag
+        ("RegExp(']')", None),
+        ("RegExp('{')", None),
+        // unknown flags
+        ("RegExp('{', flags)", None),
+        ("new RegExp('\\\\u{0}*', flags)", None),
+        ("new RegExp('[A--B]', flags)", None),
+        ("new RegExp(pattern, 'g')", None),
+        ("new RegExp('.', 'a')", Some(json!([{ "allowConstructorFlags": ["a"] }]))),
+        ("new RegExp('.', 'ag')", Some(json!([{ "allowConstructorFlags": ["a", "z"] }]))),
+        ("new RegExp('.', 'a')", Some(json!([{ "allowConstructorFlags": ["az"] }]))),
+    ];
+
+    let fail = vec![
+        ("RegExp('[');", None),
+        ("RegExp('.', 'z');", None),
+        ("RegExp('.', 'a');", Some(json!([{ "allowConstructorFlags": ["z"] }]))),
+        ("new RegExp(')');", None),
+        ("new RegExp('\\\\');", None),
+        ("new RegExp(pattern, 'gg');", None),
+        ("new RegExp('.', 'uv');", None),
+        ("RegExp('{', 'u');", None),
+        ("new RegExp('\\\\a', 'u');", None),
+        ("new RegExp('\\\\p{Foo}', 'u');", None),
+        ("new RegExp('(?<a>.)(?<a>.)');", None),
+        ("new RegExp('[[]', 'v');", None),
+        ("new RegExp('[^\\\\q{ab}]', 'v');", None),
+        ("new RegExp('a**', flags);", None),
+    ];
+
+    Tester::new(NoInvalidRegexp::NAME, pass, fail).test_and_snapshot();
+}
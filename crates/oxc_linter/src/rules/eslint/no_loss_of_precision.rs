@@ -12,7 +12,6 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-loss-of-precision): This number literal will lose precision at runtime.")]
-#[diagnostic(severity(warning))]
 struct NoLossOfPrecisionDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
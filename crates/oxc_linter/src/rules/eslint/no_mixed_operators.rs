@@ -11,10 +11,7 @@ use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-mixed-operators): Unexpected mix of {0} with {1}")]
-#[diagnostic(
-    severity(warning),
-    help("Use parentheses to clarify the intended order of operations.")
-)]
+#[diagnostic(help("Use parentheses to clarify the intended order of operations."))]
 struct NoMixedOperatorsDiagnostic(
     &'static str,      /*Node Operator */
     &'static str,      /*Parent Operator */
@@ -53,6 +50,31 @@ declare_oxc_lint! {
   /// ```
   NoMixedOperators,
   pedantic,
+  schema = r#"[
+      {
+          "type": "object",
+          "properties": {
+              "groups": {
+                  "type": "array",
+                  "items": {
+                      "type": "array",
+                      "items": {
+                          "enum": [
+                              "+", "-", "*", "/", "%", "**", "&", "|", "^", "~", "<<", ">>",
+                              ">>>", "==", "!=", "===", "!==", ">", ">=", "<", "<=", "&&", "||",
+                              "in", "instanceof", "?:", "??"
+                          ]
+                      },
+                      "minItems": 2,
+                      "uniqueItems": true
+                  },
+                  "uniqueItems": true
+              },
+              "allowSamePrecedence": { "type": "boolean" }
+          },
+          "additionalProperties": false
+      }
+  ]"#,
 }
 
 impl Rule for NoMixedOperators {
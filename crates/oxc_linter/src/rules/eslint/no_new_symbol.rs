@@ -11,7 +11,6 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-new-symbol): Disallow new operators with the Symbol object")]
 #[diagnostic(
-    severity(warning),
     help(
         "Symbol is not intended to be used with the new operator, but to be called as a function. Consider removing the new operator."
     )
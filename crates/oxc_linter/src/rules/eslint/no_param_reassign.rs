@@ -0,0 +1,293 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{AstNodeId, SymbolId};
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+use regex::Regex;
+use rustc_hash::FxHashSet;
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoParamReassignDiagnostic {
+    #[error("eslint(no-param-reassign): Assignment to function parameter '{0}'.")]
+    #[diagnostic(help("Assign to a new local variable instead"))]
+    Param(Atom, #[label] Span),
+    #[error("eslint(no-param-reassign): Assignment to property of function parameter '{0}'.")]
+    #[diagnostic(help("Modify a copy of the parameter instead"))]
+    Property(Atom, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoParamReassign {
+    /// Also report modifications of the properties of parameters
+    props: bool,
+    /// Parameters whose properties may be modified, e.g. `acc` of `reduce` callbacks
+    ignore_property_modifications_for: FxHashSet<String>,
+    ignore_property_modifications_for_regex: Vec<Regex>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow reassigning function parameters
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Assigning to a parameter makes the code harder to follow, and in sloppy mode it also
+    /// changes the `arguments` object. With `props`, modifying the properties of a parameter is
+    /// reported as well, since it mutates an object owned by the caller.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function foo(bar) {
+    ///     bar = 13;
+    /// }
+    ///
+    /// // with `{ "props": true }`
+    /// function foo(bar) {
+    ///     bar.prop = "value";
+    /// }
+    /// ```
+    NoParamReassign,
+    restriction,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": {
+                "props": { "type": "boolean" },
+                "ignorePropertyModificationsFor": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "uniqueItems": true
+                },
+                "ignorePropertyModificationsForRegex": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "uniqueItems": true
+                }
+            },
+            "additionalProperties": false
+        }
+    ]"#
+);
+
+impl Rule for NoParamReassign {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else { return Self::default() };
+        let strings = |key: &str| {
+            config
+                .get(key)
+                .and_then(serde_json::Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(serde_json::Value::as_str)
+        };
+        Self {
+            props: config.get("props").and_then(serde_json::Value::as_bool).unwrap_or_default(),
+            ignore_property_modifications_for: strings("ignorePropertyModificationsFor")
+                .map(String::from)
+                .collect(),
+            ignore_property_modifications_for_regex: strings("ignorePropertyModificationsForRegex")
+                .filter_map(|pattern| Regex::new(pattern).ok())
+                .collect(),
+        }
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let symbol_table = ctx.semantic().symbols();
+        let declaration = symbol_table.get_declaration(symbol_id);
+        if !matches!(ctx.nodes().kind(declaration), AstKind::FormalParameters(_)) {
+            return;
+        }
+        let name = symbol_table.get_name(symbol_id);
+        for reference in symbol_table.get_resolved_references(symbol_id) {
+            if reference.is_write() {
+                ctx.diagnostic(NoParamReassignDiagnostic::Param(name.clone(), reference.span()));
+            } else if self.props
+                && !self.is_ignored_property_assignment(name)
+                && is_modifying_property(reference.node_id(), ctx)
+            {
+                ctx.diagnostic(NoParamReassignDiagnostic::Property(name.clone(), reference.span()));
+            }
+        }
+    }
+}
+
+impl NoParamReassign {
+    fn is_ignored_property_assignment(&self, name: &str) -> bool {
+        self.ignore_property_modifications_for.contains(name)
+            || self.ignore_property_modifications_for_regex.iter().any(|regex| regex.is_match(name))
+    }
+}
+
+/// Whether the referenced identifier is the object of a member expression which is assigned,
+/// updated or deleted, e.g. `a.b = 1`, `a.b.c++`, `delete a[b]` or `[a.b] = []`
+fn is_modifying_property(node_id: AstNodeId, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    if !matches!(nodes.kind(node_id), AstKind::IdentifierReference(_)) {
+        return false;
+    }
+    let mut current = nodes.get_node(node_id);
+    while let Some(parent) = nodes.parent_node(current.id()) {
+        let current_span = current.kind().span();
+        match parent.kind() {
+            // The identifier is inside an assignment target, and only member expressions on the
+            // way up from the identifier, so it is the object being modified
+            AstKind::SimpleAssignmentTarget(_)
+            | AstKind::AssignmentTarget(_)
+            | AstKind::UpdateExpression(_) => return true,
+            AstKind::UnaryExpression(expr) if expr.operator == UnaryOperator::Delete => {
+                return true;
+            }
+            AstKind::MemberExpression(member) if member.object().span() != current_span => {
+                return false;
+            }
+            AstKind::CallExpression(call) if call.callee.span() != current_span => return false,
+            AstKind::ConditionalExpression(expr) if expr.test.span() == current_span => {
+                return false;
+            }
+            AstKind::MemberExpression(_)
+            | AstKind::CallExpression(_)
+            | AstKind::ConditionalExpression(_)
+            | AstKind::ParenthesizedExpression(_)
+            | AstKind::ChainExpression(_)
+            | AstKind::UnaryExpression(_)
+            | AstKind::TSAsExpression(_)
+            | AstKind::TSSatisfiesExpression(_)
+            | AstKind::TSNonNullExpression(_) => {}
+            _ => return false,
+        }
+        current = parent;
+    }
+    false
+}
+
+#[test]
+#[allow(clippy::too_many_lines)]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo(a) { var b = a; }", None),
+        ("function foo(a) { for (b in a); }", None),
+        ("function foo(a) { for (b of a); }", None),
+        ("function foo(a) { a.prop = 'value'; }", None),
+        ("function foo(a) { for (a.prop in obj); }", None),
+        ("function foo(a) { for (a.prop of arr); }", None),
+        ("function foo(a) { (function() { var a = 12; a++; })(); }", None),
+        ("function foo() { someGlobal = 13; }", None),
+        ("function foo(a) { a.b = 0; }", None),
+        ("function foo(a) { delete a.b; }", None),
+        ("function foo(a) { ++a.b; }", None),
+        ("function foo(a) { [a.b] = []; }", None),
+        ("function foo(a) { bar(a.b).c = 0; }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { data[a.b] = 0; }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { +a.b; }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { (a ? [] : [])[0] = 1; }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { (a.b ? [] : [])[0] = 1; }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { x = a.b; }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { ({ [a]: x } = {}); }", Some(json!([{ "props": true }]))),
+        (
+            "function foo(a) { a.b = 0; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a"] }])),
+        ),
+        (
+            "function foo(a) { ++a.b; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a"] }])),
+        ),
+        (
+            "function foo(a) { delete a.b; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a"] }])),
+        ),
+        (
+            "function foo(a, z) { a.b = 0; x.y = 0; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a", "x"] }])),
+        ),
+        (
+            "function foo(a) { a.b.c = 0;}",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a"] }])),
+        ),
+        (
+            "function foo(aFoo) { aFoo.b = 0; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsForRegex": ["^a.*$"] }])),
+        ),
+        (
+            "function foo(aFoo) { ++aFoo.b; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsForRegex": ["^a.*$"] }])),
+        ),
+        (
+            "function foo(aFoo, bBar) { aFoo.b = 0; bBar.c = 0; }",
+            Some(
+                json!([{ "props": true, "ignorePropertyModificationsForRegex": ["^a.*$", "^b.*$"] }]),
+            ),
+        ),
+        ("function foo(a) { ({ [a]: variable } = value) }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { ([...a.b] = obj); }", Some(json!([{ "props": false }]))),
+        ("function foo(a) { ({...a.b} = obj); }", Some(json!([{ "props": false }]))),
+        ("function foo(a) { for (obj[a.b] in obj); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { for (obj[a.b] of arr); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { a.b(); }", Some(json!([{ "props": true }]))),
+        ("function foo(a = 1) { return a; }", None),
+    ];
+
+    let fail = vec![
+        ("function foo(bar) { bar = 13; }", None),
+        ("function foo(bar) { bar += 13; }", None),
+        ("function foo(bar) { (function() { bar = 13; })(); }", None),
+        ("function foo(bar) { ++bar; }", None),
+        ("function foo(bar) { bar++; }", None),
+        ("function foo(bar) { --bar; }", None),
+        ("function foo(bar) { bar--; }", None),
+        ("function foo({bar}) { bar = 13; }", None),
+        ("function foo([, {bar}]) { bar = 13; }", None),
+        ("function foo(bar) { ({bar} = {}); }", None),
+        ("function foo(bar) { ({x: [, bar = 0]} = {}); }", None),
+        ("function foo(bar) { for (bar in baz); }", None),
+        ("function foo(bar) { for (bar of baz); }", None),
+        ("const foo = (bar) => { bar = 13; }", None),
+        ("class A { foo(bar) { bar = 13; } }", None),
+        ("function foo(bar) { bar.a = 0; }", Some(json!([{ "props": true }]))),
+        ("function foo(bar) { bar.get(0).a = 0; }", Some(json!([{ "props": true }]))),
+        ("function foo(bar) { delete bar.a; }", Some(json!([{ "props": true }]))),
+        ("function foo(bar) { ++bar.a; }", Some(json!([{ "props": true }]))),
+        ("function foo(bar) { for (bar.a in {}); }", Some(json!([{ "props": true }]))),
+        ("function foo(bar) { for (bar.a of []); }", Some(json!([{ "props": true }]))),
+        ("function foo(bar) { (bar ? bar : [])[0] = 1; }", Some(json!([{ "props": true }]))),
+        ("function foo(bar) { [bar.a] = []; }", Some(json!([{ "props": true }]))),
+        (
+            "function foo(bar) { [bar.a] = []; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a"] }])),
+        ),
+        (
+            "function foo(bar) { [bar.a] = []; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsForRegex": ["^a.*$"] }])),
+        ),
+        (
+            "function foo(bar) { [bar.a] = []; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsForRegex": ["^B.*$"] }])),
+        ),
+        ("function foo(bar) { ({foo: bar.a} = {}); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { ({a} = obj); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { ([...a] = obj); }", None),
+        ("function foo(a) { ({...a} = obj); }", None),
+        ("function foo(a) { ([...a.b] = obj); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { ({...a.b} = obj); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { for ({bar: a.b} in {}); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { for ([a.b] of []); }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { a &&= b; }", None),
+        ("function foo(a) { a ||= b; }", None),
+        ("function foo(a) { a ??= b; }", None),
+        ("function foo(a) { a.b &&= c; }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { a.b.c ||= d; }", Some(json!([{ "props": true }]))),
+        ("function foo(a) { a[b] ??= c; }", Some(json!([{ "props": true }]))),
+    ];
+
+    Tester::new(NoParamReassign::NAME, pass, fail).test_and_snapshot();
+}
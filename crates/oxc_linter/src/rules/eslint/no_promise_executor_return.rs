@@ -0,0 +1,189 @@
+use oxc_ast::{
+    ast::{Argument, Expression, Statement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-promise-executor-return): Return values from promise executor functions cannot be read.")]
+#[diagnostic(help("Call `resolve` with the value instead, or remove the return value"))]
+struct NoPromiseExecutorReturnDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoPromiseExecutorReturn {
+    /// Allow returning `void` expressions, e.g. `new Promise((resolve) => void setTimeout(resolve))`
+    allow_void: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow returning values from Promise executor functions
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The executor passed to `new Promise` settles the promise by calling `resolve` or
+    /// `reject`. The value returned from the executor is ignored, so returning a value is
+    /// either unnecessary or a mistake, such as returning the value instead of resolving with it.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// new Promise((resolve, reject) => {
+    ///     if (someCondition) {
+    ///         return defaultResult;
+    ///     }
+    ///     getSomething((err, result) => err ? reject(err) : resolve(result));
+    /// });
+    ///
+    /// new Promise((resolve) => setTimeout(resolve, 1000));
+    /// ```
+    NoPromiseExecutorReturn,
+    pedantic,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": { "allowVoid": { "type": "boolean" } },
+            "additionalProperties": false
+        }
+    ]"#
+);
+
+impl Rule for NoPromiseExecutorReturn {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_void: value
+                .get(0)
+                .and_then(|config| config.get("allowVoid"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (value, function_id) = match node.kind() {
+            AstKind::ReturnStatement(stmt) => {
+                let Some(argument) = &stmt.argument else { return };
+                let Some(function) = ctx.nodes().iter_parents(node.id()).skip(1).find(|parent| {
+                    matches!(parent.kind(), AstKind::Function(_) | AstKind::ArrowExpression(_))
+                }) else {
+                    return;
+                };
+                (argument, function.id())
+            }
+            AstKind::ArrowExpression(arrow) if arrow.expression => {
+                let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.first()
+                else {
+                    return;
+                };
+                (&stmt.expression, node.id())
+            }
+            _ => return,
+        };
+        if self.allow_void
+            && matches!(value.get_inner_expression(), Expression::UnaryExpression(expr) if expr.operator == UnaryOperator::Void)
+        {
+            return;
+        }
+        if is_promise_executor(function_id, ctx) {
+            ctx.diagnostic(NoPromiseExecutorReturnDiagnostic(value.span()));
+        }
+    }
+}
+
+/// Whether the function is the first argument of `new Promise(...)`
+fn is_promise_executor(function_id: AstNodeId, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let mut parents = nodes
+        .iter_parents(function_id)
+        .skip(1)
+        .map(AstNode::kind)
+        .skip_while(|kind| matches!(kind, AstKind::ParenthesizedExpression(_)));
+    let Some(AstKind::Argument(argument)) = parents.next() else { return false };
+    let Some(AstKind::NewExpression(new_expr)) = parents.next() else { return false };
+    let Expression::Identifier(callee) = new_expr.callee.get_inner_expression() else {
+        return false;
+    };
+    callee.name == "Promise"
+        && new_expr.arguments.first().is_some_and(|first| {
+            matches!(first, Argument::Expression(_)) && first.span() == argument.span()
+        })
+        && ctx.semantic().is_reference_to_global_variable(callee)
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo(resolve, reject) { return 1; }", None),
+        ("function Promise(resolve, reject) { return 1; }", None),
+        ("(function (resolve, reject) { return 1; })", None),
+        ("(function foo(resolve, reject) { return 1; })", None),
+        ("(resolve, reject) => 1", None),
+        ("(resolve, reject) => { return 1; }", None),
+        ("new Promise(function (resolve, reject) {})", None),
+        ("new Promise(function (resolve, reject) { return; })", None),
+        ("new Promise(function (resolve, reject) { if (foo) { return; } resolve(1); })", None),
+        ("new Promise(function (resolve, reject) { function foo() { return 1; } })", None),
+        ("new Promise((resolve, reject) => { return; })", None),
+        ("new Promise((resolve, reject) => { if (foo) { return; } })", None),
+        ("new Promise((resolve, reject) => { const foo = () => { return 1; }; })", None),
+        ("new Promise((resolve, reject) => { const foo = () => 1; })", None),
+        ("new Promise((resolve, reject) => { resolve(1); })", None),
+        ("new Promise((resolve, reject) => {})", None),
+        ("new Promise(foo)", None),
+        ("new Promise(...foo)", None),
+        (
+            "new Promise(function (resolve, reject) {}, function (resolve, reject) { return 1; })",
+            None,
+        ),
+        ("new Promise((resolve, reject) => {}, (resolve, reject) => 1)", None),
+        ("Promise(function (resolve, reject) { return 1; })", None),
+        ("Promise((resolve, reject) => 1)", None),
+        ("new Promise.foo(function (resolve, reject) { return 1; })", None),
+        ("new foo.Promise((resolve, reject) => 1)", None),
+        ("new Foo(function (resolve, reject) { return 1; })", None),
+        ("new Promise(function (resolve, reject) { return; }).then(() => 1)", None),
+        ("let Promise; new Promise(function (resolve, reject) { return 1; })", None),
+        ("function foo(Promise) { new Promise((resolve, reject) => 1); }", None),
+        (
+            "new Promise((resolve) => void setTimeout(resolve, 1))",
+            Some(json!([{ "allowVoid": true }])),
+        ),
+        (
+            "new Promise((resolve) => { return void setTimeout(resolve, 1); })",
+            Some(json!([{ "allowVoid": true }])),
+        ),
+        ("new Promise(r => void 0)", Some(json!([{ "allowVoid": true }]))),
+    ];
+
+    let fail = vec![
+        ("new Promise(function (resolve, reject) { return 1; })", None),
+        ("new Promise((resolve, reject) => { return 1; })", None),
+        ("new Promise((resolve, reject) => resolve(1))", None),
+        ("new Promise(resolve => setTimeout(resolve, 1000))", None),
+        ("new Promise(function foo(resolve, reject) { if (foo) { return 1; } })", None),
+        ("new Promise((resolve, reject) => { try { return 1; } catch {} })", None),
+        ("new Promise(function (resolve, reject) { function foo() {} return 1; })", None),
+        ("new Promise(async function (resolve, reject) { return 1; })", None),
+        ("new Promise(((resolve, reject) => resolve(1)))", None),
+        ("new (Promise)((resolve, reject) => resolve(1))", None),
+        ("new Promise(r => void 0)", None),
+        ("new Promise((resolve) => { return void setTimeout(resolve, 1); })", None),
+        ("new Promise((resolve) => { return void 0; })", Some(json!([{ "allowVoid": false }]))),
+        ("new Promise(r => void 0, foo)", Some(json!([{}]))),
+    ];
+
+    Tester::new(NoPromiseExecutorReturn::NAME, pass, fail).test_and_snapshot();
+}
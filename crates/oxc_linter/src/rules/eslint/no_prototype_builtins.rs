@@ -12,10 +12,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 #[error(
     "eslint(no-prototype-builtins): do not access Object.prototype method {0:?} from target object"
 )]
-#[diagnostic(
-    severity(warning),
-    help("to avoid prototype pollution, use `Object.prototype.{0}.call` instead")
-)]
+#[diagnostic(help("to avoid prototype pollution, use `Object.prototype.{0}.call` instead"))]
 struct NoPrototypeBuiltinsDiagnostic(String, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
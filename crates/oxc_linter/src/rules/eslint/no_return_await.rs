@@ -6,11 +6,16 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-return-await): Redundant use of `await` on a return value.")]
-#[diagnostic(severity(warning), help("Remove redundant `await`."))]
+#[diagnostic(help("Remove redundant `await`."))]
 struct NoReturnAwaitDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -31,7 +36,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoReturnAwait,
-    pedantic
+    perf,
+    suggestion
 );
 
 impl Rule for NoReturnAwait {
@@ -41,8 +47,21 @@ impl Rule for NoReturnAwait {
                 let start = await_expr.span.start;
                 let end = start + 5;
                 let await_keyword_span = Span::new(start, end);
+                // Without `await`, `return await\nfoo()` would return `undefined` because of
+                // automatic semicolon insertion
+                let between =
+                    &ctx.source_text()[end as usize..await_expr.argument.span().start as usize];
+                if between.contains(['\n', '\r', '\u{2028}', '\u{2029}']) {
+                    ctx.diagnostic(NoReturnAwaitDiagnostic(await_keyword_span));
+                    return;
+                }
+                let fix_span = if between.starts_with(' ') {
+                    Span::new(start, end + 1)
+                } else {
+                    await_keyword_span
+                };
                 ctx.diagnostic_with_fix(NoReturnAwaitDiagnostic(await_keyword_span), || {
-                    Fix::new("", await_keyword_span)
+                    Fix::delete(fix_span).with_kind(FixKind::Suggestion)
                 });
             }
         }
@@ -257,7 +276,28 @@ fn test() {
         ),
         ("\nasync () => {\nreturn await (\nfoo()\n)\n};\n", None),
         ("\nasync function foo() {\nreturn await // Test\n5;\n}\n", None),
-      ];
+    ];
+
+    let fix = vec![
+        (
+            "async function foo() { return await bar(); }",
+            "async function foo() { return bar(); }",
+            None,
+        ),
+        (
+            "async function foo() { return await(bar()); }",
+            "async function foo() { return (bar()); }",
+            None,
+        ),
+        ("async () => await bar()", "async () => bar()", None),
+        ("async () => (a, await bar())", "async () => (a, bar())", None),
+        ("async () => { return await (\nfoo()\n) }", "async () => { return (\nfoo()\n) }", None),
+        (
+            "async function foo() { return await // Test\n5; }",
+            "async function foo() { return await // Test\n5; }",
+            None,
+        ),
+    ];
 
-    Tester::new(NoReturnAwait::NAME, pass, fail).test_and_snapshot();
+    Tester::new(NoReturnAwait::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -19,7 +19,6 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-self-assign): this expression is assigned to itself")]
-#[diagnostic(severity(warning))]
 struct NoSelfAssignDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Clone)]
@@ -49,7 +48,14 @@ declare_oxc_lint!(
     /// [bar, baz] = [bar, qiz];
     /// ```
     NoSelfAssign,
-    correctness
+    correctness,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": { "props": { "type": "boolean" } },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for NoSelfAssign {
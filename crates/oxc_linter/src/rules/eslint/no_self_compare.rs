@@ -6,14 +6,11 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{ast_util::calculate_hash, context::LintContext, rule::Rule, AstNode};
+use crate::{ast_util::is_same_expression, context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-self-compare): Disallow comparisons where both sides are exactly the same")]
-#[diagnostic(
-    severity(warning),
-    help("If you are testing for NaN, you can use Number.isNaN function.")
-)]
+#[diagnostic(help("If you are testing for NaN, you can use Number.isNaN function."))]
 struct NoSelfCompareDiagnostic(#[label] pub Span, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -47,10 +44,7 @@ impl Rule for NoSelfCompare {
         if !binary_expr.operator.is_compare() && !binary_expr.operator.is_equality() {
             return;
         }
-        let left = calculate_hash(&binary_expr.left);
-        let right = calculate_hash(&binary_expr.right);
-
-        if left == right {
+        if is_same_expression(&binary_expr.left, &binary_expr.right) {
             ctx.diagnostic(NoSelfCompareDiagnostic(
                 binary_expr.left.span(),
                 binary_expr.right.span(),
@@ -70,6 +64,9 @@ fn test() {
         ("foo.bar.baz === foo.bar.qux", None),
         ("class C { #field; foo() { this.#field === this['#field']; } }", None),
         ("class C { #field; foo() { this['#field'] === this.#field; } }", None),
+        ("a.b === a['b']", None),
+        ("a?.b === a.b", None),
+        ("foo(a) === foo(b)", None),
     ];
 
     let fail = vec![
@@ -88,6 +85,10 @@ fn test() {
         ("x <= x", None),
         ("foo.bar().baz.qux >= foo.bar ().baz .qux", None),
         ("class C { #field; foo() { this.#field === this.#field; } }", None),
+        ("(x) === x", None),
+        ("a[0] === a[(0)]", None),
+        ("a?.b === a?.b", None),
+        ("`a${b}` === `a${b}`", None),
     ];
 
     Tester::new(NoSelfCompare::NAME, pass, fail).test_and_snapshot();
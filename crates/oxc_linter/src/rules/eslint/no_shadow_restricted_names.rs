@@ -13,7 +13,7 @@ use crate::{context::LintContext, globals::PRE_DEFINE_VAR, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-shadow-restricted-names): Shadowing of global properties such as 'undefined' is not allowed.")]
-#[diagnostic(severity(warning), help("Shadowing of global properties '{0}'."))]
+#[diagnostic(help("Shadowing of global properties '{0}'."))]
 struct NoShadowRestrictedNamesDiagnostic(Atom, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
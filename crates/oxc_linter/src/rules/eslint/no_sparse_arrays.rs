@@ -10,7 +10,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-sparse-arrays): Unexpected comma in middle of array")]
-#[diagnostic(severity(warning), help("remove the comma or insert `undefined`"))]
+#[diagnostic(help("remove the comma or insert `undefined`"))]
 struct NoSparseArraysDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
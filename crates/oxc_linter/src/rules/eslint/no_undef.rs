@@ -7,11 +7,11 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, Span};
 use oxc_syntax::operator::UnaryOperator;
 
-use crate::{context::LintContext, globals::BUILTINS, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-undef): Disallow the use of undeclared variables")]
-#[diagnostic(severity(warning), help("'{0}' is not defined."))]
+#[diagnostic(help("'{0}' is not defined."))]
 struct NoUndefDiagnostic(Atom, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -35,7 +35,14 @@ declare_oxc_lint!(
     /// var bar = a + 1;
     /// ```
     NoUndef,
-    nursery // https://github.com/web-infra-dev/oxc/issues/732
+    nursery, // https://github.com/web-infra-dev/oxc/issues/732
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": { "typeof": { "type": "boolean" } },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for NoUndef {
@@ -53,7 +60,7 @@ impl Rule for NoUndef {
         for reference_id_list in ctx.scopes().root_unresolved_references().values() {
             for &reference_id in reference_id_list {
                 let reference = symbol_table.get_reference(reference_id);
-                if BUILTINS.contains_key(reference.name().as_str()) {
+                if ctx.global(reference.name()).is_some() {
                     return;
                 }
 
@@ -61,6 +68,11 @@ impl Rule for NoUndef {
                 if !self.type_of && has_typeof_operator(node, ctx) {
                     return;
                 }
+                // The factory of the classic JSX runtime, e.g. `React` of `React.createElement`,
+                // is referenced by every element but is not written in the source
+                if matches!(node.kind(), AstKind::JSXOpeningElement(_) | AstKind::JSXFragment(_)) {
+                    continue;
+                }
 
                 ctx.diagnostic(NoUndefDiagnostic(reference.name().clone(), reference.span()));
             }
@@ -105,6 +117,8 @@ fn test() {
         ("var toString = 1;", None),
         ("function myFunc(...foo) {  return foo;}", None),
         ("var React, App, a=1; React.render(<App attr={a} />);", None),
+        ("/** @jsx h */ var a = <div><span /></div>;", None),
+        ("/** @jsxRuntime classic */ var a = <></>;", None),
         ("var console; [1,2,3].forEach(obj => {\n  console.log(obj);\n});", None),
         ("var Foo; class Bar extends Foo { constructor() { super();  }}", None),
         ("import Warning from '../lib/warning'; var warn = new Warning('text');", None),
@@ -13,10 +13,9 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-unsafe-finally): Unsafe finally block")]
-#[diagnostic(
-    severity(warning),
-    help("Control flow inside try or catch blocks will be overwritten by this statement")
-)]
+#[diagnostic(help(
+    "Control flow inside try or catch blocks will be overwritten by this statement"
+))]
 struct NoUnsafeFinallyDiagnostic(#[label] Span);
 
 #[derive(Debug, Default, Clone)]
@@ -11,12 +11,16 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("Unexpected logical not in the left hand side of '{0}' operator")]
 #[diagnostic(
-    severity(warning),
     help(
         "use parenthesis to express the negation of the whole boolean expression, as '!' binds more closely than '{0}'"
     )
@@ -47,7 +51,15 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoUnsafeNegation,
-    correctness
+    correctness,
+    suggestion,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": { "enforceForOrderingRelations": { "type": "boolean" } },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for NoUnsafeNegation {
@@ -99,7 +111,7 @@ impl NoUnsafeNegation {
                 formatter.print(b')');
                 formatter.into_code()
             };
-            Fix::new(modified_code, expr.span)
+            Fix::new(modified_code, expr.span).with_kind(FixKind::Suggestion)
         };
 
         ctx.diagnostic_with_fix(diagnostic, fix_producer);
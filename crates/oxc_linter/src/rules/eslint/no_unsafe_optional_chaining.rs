@@ -14,15 +14,12 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-unsafe-optional-chaining): Unsafe usage of optional chaining")]
-#[diagnostic(
-    severity(warning),
-    help("If this short-circuits with 'undefined' the evaluation will throw TypeError")
-)]
+#[diagnostic(help("If this short-circuits with 'undefined' the evaluation will throw TypeError"))]
 struct NoUnsafeOptionalChainingDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-unsafe-optional-chaining): Unsafe arithmetic operation on optional chaining")]
-#[diagnostic(severity(warning), help("This can result in NaN."))]
+#[diagnostic(help("This can result in NaN."))]
 struct NoUnsafeArithmeticDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -53,7 +50,14 @@ declare_oxc_lint!(
     /// const { bar } = obj?.foo;  // TypeError
     /// ```
     NoUnsafeOptionalChaining,
-    restriction // TypeScript checks optional chaining
+    correctness,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": { "disallowArithmeticOperators": { "type": "boolean" } },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for NoUnsafeOptionalChaining {
@@ -10,7 +10,7 @@ use crate::{context::LintContext, fixer::Fix, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-unused-labels): Disallow unused labels")]
-#[diagnostic(severity(warning), help("'{0}:' is defined but never used."))]
+#[diagnostic(help("'{0}:' is defined but never used."))]
 struct NoUnusedLabelsDiagnostic(Atom, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoUnusedLabels,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoUnusedLabels {
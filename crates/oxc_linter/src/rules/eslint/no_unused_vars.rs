@@ -0,0 +1,808 @@
+use lazy_static::lazy_static;
+use oxc_ast::{
+    ast::{
+        AssignmentTarget, BindingIdentifier, BindingPatternKind, Expression, FormalParameterKind,
+        MethodDefinitionKind, ModifierKind, PropertyKind, SimpleAssignmentTarget,
+    },
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{AstNodeId, Reference, ScopeFlags, ScopeId, SymbolFlags, SymbolId};
+use oxc_span::{Atom, GetSpan, Span};
+use regex::Regex;
+use rustc_hash::FxHashSet;
+
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum NoUnusedVarsDiagnostic {
+    #[error("eslint(no-unused-vars): '{0}' is defined but never used.")]
+    Defined(Atom, #[label] Span),
+    #[error("eslint(no-unused-vars): '{0}' is assigned a value but never used.")]
+    Assigned(Atom, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedVars {
+    vars: VarsOption,
+    vars_ignore_pattern: Option<Regex>,
+    args: ArgsOption,
+    args_ignore_pattern: Option<Regex>,
+    caught_errors: CaughtErrorsOption,
+    caught_errors_ignore_pattern: Option<Regex>,
+    destructured_array_ignore_pattern: Option<Regex>,
+    ignore_rest_siblings: bool,
+    ignore_class_with_static_init_block: bool,
+    jsdoc_references: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum VarsOption {
+    /// Check all variables, including the ones in the global scope
+    #[default]
+    All,
+    /// Only check variables which are not in the top level scope, of scripts and modules
+    Local,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum ArgsOption {
+    /// Only check the parameters after the last used parameter
+    #[default]
+    AfterUsed,
+    All,
+    None,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum CaughtErrorsOption {
+    /// The default since ESLint 9
+    #[default]
+    All,
+    None,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unused variables, functions, classes, imports, parameters and caught errors.
+    ///
+    /// A variable is used when it is read, a symbol that is exported is always used.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unused variables are most likely left over from refactoring,
+    /// they take up space and confuse readers.
+    ///
+    /// ### Options
+    ///
+    /// The options of ESLint 9 are supported:
+    /// `vars` (`"all"` or `"local"`), `varsIgnorePattern`,
+    /// `args` (`"after-used"`, `"all"` or `"none"`), `argsIgnorePattern`,
+    /// `caughtErrors` (`"all"` or `"none"`), `caughtErrorsIgnorePattern`,
+    /// `destructuredArrayIgnorePattern`, `ignoreRestSiblings` and `ignoreClassWithStaticInitBlock`.
+    ///
+    /// `typeof a` in types and decorators are usages. With `jsdocReferences`,
+    /// the names in `{@link a}` and `@see a` of JSDoc comments are usages too.
+    ///
+    /// A declaration of a single unused variable is suggested to be removed
+    /// when its initializer has no side effects, e.g. `let b = 1;`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import { a } from "a";
+    /// let b = 1;
+    /// function c(d) {}
+    /// try {} catch (e) {}
+    /// ```
+    NoUnusedVars,
+    nursery,
+    suggestion,
+    schema = r#"[
+        {
+            "oneOf": [
+                { "enum": ["all", "local"] },
+                {
+                    "type": "object",
+                    "properties": {
+                        "vars": { "enum": ["all", "local"] },
+                        "varsIgnorePattern": { "type": "string" },
+                        "args": { "enum": ["after-used", "all", "none"] },
+                        "argsIgnorePattern": { "type": "string" },
+                        "caughtErrors": { "enum": ["all", "none"] },
+                        "caughtErrorsIgnorePattern": { "type": "string" },
+                        "destructuredArrayIgnorePattern": { "type": "string" },
+                        "ignoreRestSiblings": { "type": "boolean" },
+                        "ignoreClassWithStaticInitBlock": { "type": "boolean" },
+                        "jsdocReferences": { "type": "boolean" }
+                    },
+                    "additionalProperties": false
+                }
+            ]
+        }
+    ]"#
+);
+
+impl Rule for NoUnusedVars {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else { return Self::default() };
+        if let Some(vars) = config.as_str() {
+            return Self { vars: VarsOption::from_str(vars), ..Self::default() };
+        }
+        let string = |key: &str| config.get(key).and_then(serde_json::Value::as_str);
+        let pattern = |key: &str| string(key).and_then(|pattern| Regex::new(pattern).ok());
+        let boolean =
+            |key: &str| config.get(key).and_then(serde_json::Value::as_bool).unwrap_or_default();
+        Self {
+            vars: string("vars").map(VarsOption::from_str).unwrap_or_default(),
+            vars_ignore_pattern: pattern("varsIgnorePattern"),
+            args: string("args").map(ArgsOption::from_str).unwrap_or_default(),
+            args_ignore_pattern: pattern("argsIgnorePattern"),
+            caught_errors: string("caughtErrors")
+                .map(CaughtErrorsOption::from_str)
+                .unwrap_or_default(),
+            caught_errors_ignore_pattern: pattern("caughtErrorsIgnorePattern"),
+            destructured_array_ignore_pattern: pattern("destructuredArrayIgnorePattern"),
+            ignore_rest_siblings: boolean("ignoreRestSiblings"),
+            ignore_class_with_static_init_block: boolean("ignoreClassWithStaticInitBlock"),
+            jsdoc_references: boolean("jsdocReferences"),
+        }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let jsdoc_references =
+            if self.jsdoc_references { jsdoc_references(ctx) } else { FxHashSet::default() };
+        for node in ctx.nodes().iter() {
+            let AstKind::BindingIdentifier(ident) = node.kind() else { continue };
+            let Some(symbol_id) = ident.symbol_id.get() else { continue };
+            // Redeclarations are reported once, at the first declaration
+            if ctx.symbols().get_span(symbol_id) != ident.span {
+                continue;
+            }
+            if self.is_ignored(node, ident, symbol_id, ctx)
+                || is_used(symbol_id, ctx)
+                || jsdoc_references.contains(ident.name.as_str())
+            {
+                continue;
+            }
+            let name = ident.name.clone();
+            let diagnostic = if let Some(span) = last_assignment(node, symbol_id, ctx) {
+                NoUnusedVarsDiagnostic::Assigned(name, span)
+            } else {
+                NoUnusedVarsDiagnostic::Defined(name, ident.span)
+            };
+            if let Some(span) = removable_declaration(node, symbol_id, ctx) {
+                ctx.diagnostic_with_fix(diagnostic, || {
+                    Fix::delete(span).with_kind(FixKind::Suggestion)
+                });
+            } else {
+                ctx.diagnostic(diagnostic);
+            }
+        }
+    }
+}
+
+impl VarsOption {
+    fn from_str(value: &str) -> Self {
+        match value {
+            "local" => Self::Local,
+            _ => Self::All,
+        }
+    }
+}
+
+impl ArgsOption {
+    fn from_str(value: &str) -> Self {
+        match value {
+            "all" => Self::All,
+            "none" => Self::None,
+            _ => Self::AfterUsed,
+        }
+    }
+}
+
+impl CaughtErrorsOption {
+    fn from_str(value: &str) -> Self {
+        match value {
+            "none" => Self::None,
+            _ => Self::All,
+        }
+    }
+}
+
+impl NoUnusedVars {
+    /// Whether the binding is not checked, regardless of its references.
+    fn is_ignored(
+        &self,
+        node: &AstNode,
+        ident: &BindingIdentifier,
+        symbol_id: SymbolId,
+        ctx: &LintContext,
+    ) -> bool {
+        let symbols = ctx.symbols();
+        let flags = symbols.get_flag(symbol_id);
+        if flags.intersects(SymbolFlags::EnumMember | SymbolFlags::TypeParameter) {
+            return true;
+        }
+        // `B` in `namespace A.B {}` is an exported member of `A`
+        if flags.contains(SymbolFlags::NameSpaceModule | SymbolFlags::Export) {
+            return true;
+        }
+
+        let parent = ctx.nodes().parent_node(node.id());
+        match parent.map(AstNode::kind) {
+            // The name of a class expression is only visible inside of the class
+            Some(AstKind::Class(class)) if class.is_expression() => return true,
+            Some(AstKind::Class(class))
+                if self.ignore_class_with_static_init_block && has_static_block(class) =>
+            {
+                return true
+            }
+            Some(AstKind::ObjectPattern(pattern))
+                if self.ignore_rest_siblings && pattern.rest.is_some() =>
+            {
+                return true
+            }
+            _ => {}
+        }
+        if let Some(pattern) = &self.destructured_array_ignore_pattern {
+            if is_in_array_pattern(node, ctx) && pattern.is_match(&ident.name) {
+                return true;
+            }
+        }
+
+        if ctx.semantic().exports().is_exported(symbol_id)
+            || symbols.is_merged_declaration(symbol_id)
+            || is_ambient(symbols.get_declaration(symbol_id), ctx)
+        {
+            return true;
+        }
+
+        let declaration = ctx.nodes().get_node(symbols.get_declaration(symbol_id));
+        match declaration.kind() {
+            AstKind::FormalParameters(params) => {
+                self.is_ignored_param(node, ident, params.kind, declaration, symbol_id, ctx)
+            }
+            AstKind::CatchClause(_) => {
+                self.caught_errors == CaughtErrorsOption::None
+                    || is_match(self.caught_errors_ignore_pattern.as_ref(), &ident.name)
+            }
+            _ => {
+                (self.vars == VarsOption::Local
+                    && symbols.get_scope_id(symbol_id) == ctx.scopes().root_scope_id())
+                    || is_match(self.vars_ignore_pattern.as_ref(), &ident.name)
+            }
+        }
+    }
+
+    fn is_ignored_param(
+        &self,
+        node: &AstNode,
+        ident: &BindingIdentifier,
+        kind: FormalParameterKind,
+        declaration: &AstNode,
+        symbol_id: SymbolId,
+        ctx: &LintContext,
+    ) -> bool {
+        if kind == FormalParameterKind::Signature
+            || self.args == ArgsOption::None
+            || is_match(self.args_ignore_pattern.as_ref(), &ident.name)
+        {
+            return true;
+        }
+
+        // Parameters of overloads, `declare function` and setters are required by the syntax
+        let Some(function) = ctx.nodes().parent_node(declaration.id()) else { return true };
+        match function.kind() {
+            AstKind::Function(func) => {
+                if func.body.is_none() {
+                    return true;
+                }
+                match ctx.nodes().parent_kind(function.id()) {
+                    Some(AstKind::MethodDefinition(method))
+                        if method.kind == MethodDefinitionKind::Set =>
+                    {
+                        return true
+                    }
+                    Some(AstKind::ObjectProperty(property))
+                        if property.kind == PropertyKind::Set =>
+                    {
+                        return true
+                    }
+                    _ => {}
+                }
+            }
+            AstKind::ArrowExpression(_) => {}
+            _ => return true,
+        }
+
+        let Some(AstKind::FormalParameter(param)) = ctx.nodes().parent_kind(node.id()) else {
+            // Destructured parameters are always checked, like in ESLint
+            return false;
+        };
+        // Parameter properties of constructors are class members
+        if param.accessibility.is_some() || param.readonly {
+            return true;
+        }
+
+        self.args == ArgsOption::AfterUsed
+            && is_before_used_param(ident.span, symbol_id, declaration.id(), ctx)
+    }
+}
+
+fn is_match(pattern: Option<&Regex>, name: &str) -> bool {
+    pattern.is_some_and(|pattern| pattern.is_match(name))
+}
+
+fn has_static_block(class: &oxc_ast::ast::Class) -> bool {
+    class
+        .body
+        .body
+        .iter()
+        .any(|element| matches!(element, oxc_ast::ast::ClassElement::StaticBlock(_)))
+}
+
+fn is_in_array_pattern(node: &AstNode, ctx: &LintContext) -> bool {
+    ctx.nodes()
+        .iter_parents(node.id())
+        .skip(1)
+        .find(|parent| !matches!(parent.kind(), AstKind::AssignmentPattern(_)))
+        .is_some_and(|parent| matches!(parent.kind(), AstKind::ArrayPattern(_)))
+}
+
+/// The names in `{@link a}`, `{@linkcode a.b}`, `{@linkplain a | text}` and `@see a` of JSDoc comments.
+fn jsdoc_references<'a>(ctx: &LintContext<'a>) -> FxHashSet<&'a str> {
+    lazy_static! {
+        static ref JSDOC_REFERENCE: Regex = Regex::new(
+            r"(?:\{@link(?:code|plain)?|@see)\s+([\p{ID_Start}$_][\p{ID_Continue}$\u200C\u200D]*)"
+        )
+        .unwrap();
+    }
+    let source_text = ctx.source_text();
+    ctx.semantic()
+        .trivias()
+        .comments()
+        .iter()
+        .filter(|(_, comment)| comment.is_multi_line())
+        .map(|(start, comment)| &source_text[*start as usize..comment.end() as usize])
+        .filter(|raw| raw.starts_with('*'))
+        .flat_map(|raw| JSDOC_REFERENCE.captures_iter(raw))
+        .filter_map(|captures| captures.get(1))
+        .map(|name| name.as_str())
+        .collect()
+}
+
+/// Declarations in `.d.ts` files, `declare` statements, and `declare module` or `declare global` blocks
+/// describe values which exist elsewhere.
+fn is_ambient(declaration: AstNodeId, ctx: &LintContext) -> bool {
+    ctx.source_type().is_typescript_definition()
+        || ctx.nodes().iter_parents(declaration).any(|node| {
+            let modifiers = match node.kind() {
+                AstKind::VariableDeclaration(decl) => &decl.modifiers,
+                AstKind::Function(func) => &func.modifiers,
+                AstKind::Class(class) => &class.modifiers,
+                AstKind::TSEnumDeclaration(decl) => &decl.modifiers,
+                AstKind::TSTypeAliasDeclaration(decl) => &decl.modifiers,
+                AstKind::TSInterfaceDeclaration(decl) => &decl.modifiers,
+                AstKind::TSModuleDeclaration(decl) => &decl.modifiers,
+                _ => return false,
+            };
+            modifiers.contains(ModifierKind::Declare)
+        })
+}
+
+/// A parameter followed by a used parameter is not reported with `args: "after-used"`,
+/// because it cannot be removed without changing the position of the used parameter.
+fn is_before_used_param(
+    span: Span,
+    symbol_id: SymbolId,
+    params: AstNodeId,
+    ctx: &LintContext,
+) -> bool {
+    let symbols = ctx.symbols();
+    ctx.scopes().get_bindings(symbols.get_scope_id(symbol_id)).values().any(|&other| {
+        symbols.get_declaration(other) == params
+            && symbols.get_span(other).start > span.start
+            && is_used(other, ctx)
+    })
+}
+
+fn is_used(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    let declaration = ctx.nodes().get_node(ctx.symbols().get_declaration(symbol_id));
+    ctx.symbols().get_resolved_references(symbol_id).any(|reference| {
+        reference.is_read()
+            && !is_self_call(reference, declaration)
+            && !is_read_for_itself(reference, symbol_id, ctx)
+    })
+}
+
+/// The span of the last write of an unused variable in its own function,
+/// which is the initializer of `let a = 1`, `for (const a of b)` and `const { a } = b`.
+fn last_assignment(node: &AstNode, symbol_id: SymbolId, ctx: &LintContext) -> Option<Span> {
+    let symbols = ctx.symbols();
+    let var_scope = var_scope_id(symbols.get_scope_id(symbol_id), ctx);
+    let last_write = ctx
+        .semantic()
+        .symbol_references_with_nodes(symbol_id)
+        .filter(|(reference, _, scope_id)| {
+            reference.is_write() && var_scope_id(*scope_id, ctx) == var_scope
+        })
+        .map(|(reference, _, _)| reference)
+        .last();
+    if let Some(reference) = last_write {
+        return Some(reference.span());
+    }
+
+    let declaration = symbols.get_declaration(symbol_id);
+    let AstKind::VariableDeclarator(declarator) = ctx.nodes().kind(declaration) else {
+        return None;
+    };
+    let is_for_in_of = ctx.nodes().iter_parents(declaration).nth(2).is_some_and(|parent| {
+        matches!(parent.kind(), AstKind::ForInStatement(_) | AstKind::ForOfStatement(_))
+    });
+    (declarator.init.is_some() || is_for_in_of).then_some(node.kind().span())
+}
+
+/// The span of the `let a = 1;` statement declaring only the unused variable, when it is never
+/// assigned again and the initializer has no side effects, so the statement can be removed.
+fn removable_declaration(node: &AstNode, symbol_id: SymbolId, ctx: &LintContext) -> Option<Span> {
+    if ctx.symbols().get_resolved_references(symbol_id).next().is_some() {
+        return None;
+    }
+    let mut parents = ctx.nodes().iter_parents(node.id()).skip(1);
+    let AstKind::VariableDeclarator(declarator) = parents.next()?.kind() else { return None };
+    let AstKind::VariableDeclaration(declaration) = parents.next()?.kind() else { return None };
+    // Not `for (let a = 1; ;)` or `export let a = 1`
+    let is_statement = matches!(
+        parents.next()?.kind(),
+        AstKind::Program(_) | AstKind::BlockStatement(_) | AstKind::FunctionBody(_)
+    );
+    // `using` declarations dispose of their value at the end of the block
+    (is_statement
+        && !declaration.kind.is_using()
+        && declaration.declarations.len() == 1
+        && matches!(declarator.id.kind, BindingPatternKind::BindingIdentifier(_))
+        && declarator
+            .init
+            .as_ref()
+            .map_or(true, |init| !ctx.semantic().may_have_side_effects(init)))
+    .then_some(declaration.span)
+}
+
+/// The scope of the function, class static block, namespace or program containing `scope_id`.
+fn var_scope_id(scope_id: ScopeId, ctx: &LintContext) -> ScopeId {
+    let scopes = ctx.scopes();
+    scopes
+        .ancestors(scope_id)
+        .find(|&scope_id| scopes.get_flags(scope_id).intersects(ScopeFlags::Var))
+        .unwrap_or_else(|| scopes.root_scope_id())
+}
+
+/// Whether the value of `a` is only read to assign it to `a` again,
+/// e.g. `a++`, `a += 1` and `a = a + 1` as statements.
+fn is_read_for_itself(reference: &Reference, symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    for parent in nodes.iter_parents(reference.node_id()).skip(1) {
+        match parent.kind() {
+            AstKind::SimpleAssignmentTarget(_)
+            | AstKind::AssignmentTarget(_)
+            | AstKind::ParenthesizedExpression(_)
+            | AstKind::BinaryExpression(_)
+            | AstKind::UnaryExpression(_) => {}
+            AstKind::UpdateExpression(_) => return is_unused_expression(parent, ctx),
+            AstKind::AssignmentExpression(assignment) => {
+                if assignment.operator.is_logical_operator() {
+                    return false;
+                }
+                let AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::AssignmentTargetIdentifier(ident),
+                ) = &assignment.left
+                else {
+                    return false;
+                };
+                let is_same_symbol = ident.reference_id.get().is_some_and(|reference_id| {
+                    ctx.symbols().get_reference(reference_id).symbol_id() == Some(symbol_id)
+                });
+                return is_same_symbol && is_unused_expression(parent, ctx);
+            }
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// `a++;` and `for (;; a++)`, but not `b = a++` or `if (a++)`.
+fn is_unused_expression(node: &AstNode, ctx: &LintContext) -> bool {
+    match ctx.nodes().parent_kind(node.id()) {
+        Some(AstKind::ExpressionStatement(_)) => true,
+        Some(AstKind::ForStatement(stmt)) => {
+            stmt.update.as_ref().is_some_and(|update| update.span() == node.kind().span())
+        }
+        _ => false,
+    }
+}
+
+/// A function or class which is only called or referenced by itself, e.g. a recursive function,
+/// is unused, including `const a = () => a()` and `class A { static create() { return new A() } }`.
+fn is_self_call(reference: &Reference, declaration: &AstNode) -> bool {
+    let span = match declaration.kind() {
+        AstKind::Function(func) if func.is_declaration() => func.span,
+        AstKind::Class(class) if class.is_declaration() => class.span,
+        AstKind::VariableDeclarator(declarator) => {
+            match declarator.init.as_ref().map(Expression::get_inner_expression) {
+                Some(Expression::FunctionExpression(func)) => func.span,
+                Some(Expression::ArrowExpression(arrow)) => arrow.span,
+                Some(Expression::ClassExpression(class)) => class.span,
+                _ => return false,
+            }
+        }
+        _ => return false,
+    };
+    span.start <= reference.span().start && reference.span().end <= span.end
+}
+
+#[test]
+#[allow(clippy::too_many_lines)]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var a = 1; console.log(a);", None),
+        ("function foo() {} foo();", None),
+        ("class A {} new A();", None),
+        ("import { a } from 'a'; a();", None),
+        ("import type { A } from 'a'; let b: A;  console.log(b);", None),
+        ("export const a = 1;", None),
+        ("export function foo() {}", None),
+        ("export default class A {}", None),
+        ("const a = 1; export { a };", None),
+        ("export namespace N { const a = 1; console.log(a); }", None),
+        ("namespace N { export const a = 1; }\nconsole.log(N.a);", None),
+        ("export namespace A.B { export const a = 1; }", None),
+        ("declare namespace N { const a: number; }", None),
+        ("let a = 1; a++; console.log(a);", None),
+        ("let a = 0; const b = a++; console.log(b);", None),
+        ("let a = 0; if (a++) {}", None),
+        ("let a = 0; a ||= 1;", None),
+        ("let a = 0; a = b(a) + 1;", None),
+        ("let a = { b: 1 }; a.b = 2;", None),
+        ("let a; function foo() { a = 1; } foo(); console.log(a);", None),
+        ("const a = function b() {}; a();", None),
+        ("const a = class B {}; a();", None),
+        ("enum E { A, B } console.log(E);", None),
+        ("type A<T> = T[]; let a: A<number> = []; console.log(a);", None),
+        ("function foo(a, b) { return b; } foo();", None),
+        ("(function(a, b) { return b; })();", None),
+        ("(function(a) {})();", Some(serde_json::json!([{ "args": "none" }]))),
+        ("(function(_a) {})();", Some(serde_json::json!([{ "argsIgnorePattern": "^_" }]))),
+        ("(function({ a }, b) { return b; })();", Some(serde_json::json!([{ "args": "none" }]))),
+        ("class A { set b(value) {} } new A();", None),
+        ("({ set b(value) {} });", None),
+        ("class A { constructor(private a: number) {} } new A(1);", None),
+        ("function foo(a: number): void; function foo(a) { return a; } foo(1);", None),
+        ("declare function foo(a: number): void; foo(1);", None),
+        ("let a: (b: number) => void; a();", None),
+        ("try {} catch (e) {}", Some(serde_json::json!([{ "caughtErrors": "none" }]))),
+        ("try {} catch (_e) {}", Some(serde_json::json!([{ "caughtErrorsIgnorePattern": "^_" }]))),
+        ("try {} catch (e) { console.log(e); }", None),
+        ("var _a = 1;", Some(serde_json::json!([{ "varsIgnorePattern": "^_" }]))),
+        ("var a = 1;", Some(serde_json::json!(["local"]))),
+        ("var a = 1;", Some(serde_json::json!([{ "vars": "local" }]))),
+        (
+            "const [_a, b] = c; console.log(b);",
+            Some(serde_json::json!([{ "destructuredArrayIgnorePattern": "^_" }])),
+        ),
+        (
+            "const { a, ...b } = c; console.log(b);",
+            Some(serde_json::json!([{ "ignoreRestSiblings": true }])),
+        ),
+        (
+            "class A { static {} }",
+            Some(serde_json::json!([{ "ignoreClassWithStaticInitBlock": true }])),
+        ),
+        ("/** @jsx h */ import { h } from 'preact'; export const a = <div />;", None),
+        ("function foo() {} function bar() {} module.exports = { foo }; exports.bar = bar;", None),
+    ];
+
+    let fail = vec![
+        ("var a = 1;", None),
+        ("let a; a = 1;", None),
+        ("let a; a = 1; a = 2;", None),
+        ("let a = 0; a++;", None),
+        ("let a = 0; a += 1;", None),
+        ("let a = 0; a = a + 1;", None),
+        ("for (let i = 0; ; i++) {}", None),
+        ("for (const a of b) {}", None),
+        ("for (const a in b) {}", None),
+        ("const { a } = b;", None),
+        ("let a; function foo() { a = 1; } foo();", None),
+        ("function foo() {}", None),
+        ("function foo() { foo(); }", None),
+        ("class A { static create() { return new A(); } }", None),
+        ("import { a } from 'a';", None),
+        ("import a, * as b from 'a';", None),
+        ("type A = number;", None),
+        ("interface A {}", None),
+        ("enum E { A }", None),
+        ("function foo(a, b) { return a; } foo();", None),
+        ("(function(a, b) {})();", Some(serde_json::json!([{ "args": "all" }]))),
+        ("(function(a, b) { return b; })();", Some(serde_json::json!([{ "args": "all" }]))),
+        ("(function({ a }, b) { return b; })();", None),
+        ("(function(a) {})();", Some(serde_json::json!([{ "argsIgnorePattern": "^_" }]))),
+        ("(a) => {};", None),
+        ("try {} catch (e) {}", None),
+        ("try {} catch (e) {}", Some(serde_json::json!([{ "caughtErrors": "all" }]))),
+        ("try {} catch (e) {}", Some(serde_json::json!([{ "caughtErrorsIgnorePattern": "^_" }]))),
+        ("var a = 1;", Some(serde_json::json!([{ "varsIgnorePattern": "^_" }]))),
+        ("function foo() { var a = 1; } foo();", Some(serde_json::json!(["local"]))),
+        (
+            "const [a, b] = c;",
+            Some(serde_json::json!([{ "destructuredArrayIgnorePattern": "^_" }])),
+        ),
+        ("const { a, ...b } = c; console.log(b);", None),
+        ("const { a, ...b } = c;", Some(serde_json::json!([{ "ignoreRestSiblings": true }]))),
+        ("class A { static {} }", None),
+        (
+            "class A { foo() {} }",
+            Some(serde_json::json!([{ "ignoreClassWithStaticInitBlock": true }])),
+        ),
+        ("export function foo() { const a = 1; }", None),
+        ("function foox() { return foox(); }", None),
+        ("(function() { function foox() { if (true) { return foox(); } } }())", None),
+        ("var a = function() { a(); };", None),
+        ("var a = function() { return function() { a(); }; };", None),
+        ("const a = () => { a(); };", None),
+        ("const a = (() => { a(); });", None),
+        ("let a = function b() { a(); };", None),
+        ("const a = async () => { await a(); };", None),
+        ("const A = class { static create() { return new A(); } };", None),
+        ("function foo() { setTimeout(() => foo(), 1); }", None),
+        ("class A {} /** See {@link A} */ export function b() {}", None),
+        (
+            "class A {} /* See {@link A} */ export function b() {}",
+            Some(serde_json::json!([{ "jsdocReferences": true }])),
+        ),
+        ("class A {} // See {@link A}", Some(serde_json::json!([{ "jsdocReferences": true }]))),
+        ("var a; var a;", None),
+        ("function foo(a: string): void; function foo(a) {}", None),
+        ("namespace N { const a = 1; }", None),
+        ("namespace N { export const a = 1; }", None),
+        ("namespace A.B { export const a = 1; }", None),
+    ];
+
+    let fix = vec![
+        ("let a = 1;", "", None),
+        ("function f() { const a = { b: [1, 'c'] }; }", "function f() {  }", None),
+        ("var a;", "", None),
+        ("let a = foo();", "let a = foo();", None),
+        ("let a = 1, b = 2;", "let a = 1, b = 2;", None),
+        ("let { a } = b;", "let { a } = b;", None),
+        ("let a = 1; a = 2;", "let a = 1; a = 2;", None),
+        ("for (let a = 0; ;) {}", "for (let a = 0; ;) {}", None),
+        ("using a = b;", "using a = b;", None),
+    ];
+
+    Tester::new(NoUnusedVars::NAME, pass, fail)
+        .expect_fix_of_kind(FixKind::Suggestion, fix)
+        .test_and_snapshot();
+}
+
+/// Test cases of <https://github.com/eslint/eslint/blob/main/tests/lib/rules/no-unused-vars.js>
+#[test]
+#[allow(clippy::too_many_lines)]
+fn test_eslint() {
+    use crate::tester::Tester;
+
+    let defined = |name: &str, column: usize| serde_json::json!({ "message": format!("'{name}' is defined but never used."), "line": 1, "column": column });
+    let assigned = |name: &str, column: usize| serde_json::json!({ "message": format!("'{name}' is assigned a value but never used."), "line": 1, "column": column });
+
+    let valid = serde_json::json!([
+        "var foo = 5;\n\nlabel: while (true) {\n  console.log(foo);\n  break label;\n}",
+        "var foo = 5;\n\nwhile (true) {\n  console.log(foo);\n  break;\n}",
+        "var box = {a: 2};\n    for (var prop in box) {\n        box[prop] = parseInt(box[prop]);\n}",
+        "f({ set foo(a) { return; } });",
+        { "code": "a; var a;", "options": ["all"] },
+        { "code": "var a=10; alert(a);", "options": ["all"] },
+        { "code": "var a=10; (function() { alert(a); })();", "options": ["all"] },
+        { "code": "var a=10; (function() { setTimeout(function() { alert(a); }, 0); })();", "options": ["all"] },
+        { "code": "var a=10; d[a] = 0;", "options": ["all"] },
+        { "code": "(function() { var a=10; return a; })();", "options": ["all"] },
+        { "code": "(function g() {})()", "options": ["all"] },
+        { "code": "function f(a) {alert(a);}; f();", "options": ["all"] },
+        { "code": "var c = 0; function f(a){ var b = a; return b; }; f(c);", "options": ["all"] },
+        { "code": "function a(x, y){ return y; }; a();", "options": ["all"] },
+        { "code": "var arr1 = [1, 2]; var arr2 = [3, 4]; for (var i in arr1) { arr1[i] = 5; } for (var i in arr2) { arr2[i] = 10; }", "options": ["all"] },
+        { "code": "var a=10;", "options": ["local"] },
+        { "code": "var min = \"min\"; Math[min];", "options": ["all"] },
+        { "code": "Foo.bar = function(baz) { return baz; };", "options": ["all"] },
+        "myFunc(function foo() {}.bind(this))",
+        "myFunc(function foo(){}.toString())",
+        "function foo(first, second) {\ndoStuff(function() {\nconsole.log(second);});}; foo()",
+        "(function() { var doSomething = function doSomething() {}; doSomething() }())",
+        { "code": "var a=10; (function() { alert(a); })();", "options": [{ "vars": "all" }] },
+        { "code": "function g(bar, baz) { return baz; }; g();", "options": [{ "vars": "all" }] },
+        { "code": "function g(bar, baz) { return baz; }; g();", "options": [{ "vars": "all", "args": "after-used" }] },
+        { "code": "function g(bar, baz) { return bar; }; g();", "options": [{ "vars": "all", "args": "none" }] },
+        { "code": "function g(bar, baz) { return 2; }; g();", "options": [{ "vars": "all", "args": "none" }] },
+        { "code": "function g(bar, baz) { return bar + baz; }; g();", "options": [{ "vars": "local", "args": "all" }] },
+        { "code": "var g = function(bar, baz) { return 2; }; g();", "options": [{ "vars": "all", "args": "none" }] },
+        "(function z() { z(); })();",
+        "var who = \"Paul\";\nmodule.exports = `Hello ${who}!`;",
+        "export var foo = 123;",
+        "export function foo () {}",
+        "let toUpper = (partial) => partial.toUpperCase; export {toUpper}",
+        "export class foo {}",
+        "class Foo{}; var x = new Foo(); x.foo()",
+        "const foo = \"hello!\";function bar(foobar = foo) {  foobar.replace(/!$/, \" world!\");}\nbar();",
+        "function Foo(){}; var x = new Foo(); x.foo()",
+        "function foo() {var foo = 1; return foo}; foo();",
+        "function foo(foo) {return foo}; foo(1);",
+        "function foo() {function foo() {return 1;}; return foo()}; foo();",
+        { "code": "var _a;", "options": [{ "vars": "all", "varsIgnorePattern": "^_" }] },
+        { "code": "var a; function foo() { var _b; } foo();", "options": [{ "vars": "local", "varsIgnorePattern": "^_" }] },
+        { "code": "function foo(_a) { } foo();", "options": [{ "args": "all", "argsIgnorePattern": "^_" }] },
+        { "code": "function foo(a, _b) { return a; } foo();", "options": [{ "args": "after-used", "argsIgnorePattern": "^_" }] },
+        { "code": "var [ firstItemIgnored, secondItem ] = items;\nconsole.log(secondItem);", "options": [{ "vars": "all", "varsIgnorePattern": "[iI]gnored" }] },
+        { "code": "const [ a, _b, c ] = items;\nconsole.log(a+c);", "options": [{ "destructuredArrayIgnorePattern": "^_" }] },
+        { "code": "let { foo, ...rest } = something;\nconsole.log(rest);", "options": [{ "ignoreRestSiblings": true }] },
+    ]);
+
+    let invalid = serde_json::json!([
+        { "code": "function foox() { return foox(); }", "errors": [defined("foox", 10)] },
+        { "code": "(function() { function foox() { if (true) { return foox(); } } }())", "errors": [defined("foox", 24)] },
+        { "code": "var a=10", "errors": [assigned("a", 5)] },
+        { "code": "function f() { var a = 1; return function(){ f(a *= 2); }; }", "errors": [defined("f", 10)] },
+        { "code": "function f() { var a = 1; return function(){ f(++a); }; }", "errors": [defined("f", 10)] },
+        { "code": "function foo(first, second) {\ndoStuff(function() {\nconsole.log(second);});};", "errors": [defined("foo", 10)] },
+        { "code": "var a=10;", "options": ["all"], "errors": [assigned("a", 5)] },
+        { "code": "var a=10; a=20;", "options": ["all"], "errors": [assigned("a", 11)] },
+        { "code": "var a=10; (function() { var a = 1; alert(a); })();", "options": ["all"], "errors": [assigned("a", 5)] },
+        { "code": "var a=10, b=0, c=null; alert(a+b)", "options": ["all"], "errors": [assigned("c", 16)] },
+        { "code": "var a=10, b=0, c=null; setTimeout(function() { var b=2; alert(a+b+c); }, 0);", "options": ["all"], "errors": [assigned("b", 11)] },
+        { "code": "function f(){var a=[];return a.map(function(){});}", "options": ["all"], "errors": [defined("f", 10)] },
+        { "code": "function f(){var a=[];return a.map(function g(){});}", "options": ["all"], "errors": [defined("f", 10)] },
+        {
+            "code": "function f(){var x;function a(){x=42;}function b(){alert(x);}}",
+            "options": ["all"],
+            "errors": [defined("f", 10), defined("a", 29), defined("b", 48)]
+        },
+        { "code": "function f(a) {}; f();", "options": ["all"], "errors": [defined("a", 12)] },
+        { "code": "function a(x, y, z){ return y; }; a();", "options": ["all"], "errors": [defined("z", 18)] },
+        { "code": "var min = Math.min", "options": ["all"], "errors": [assigned("min", 5)] },
+        { "code": "var min = {min: 1}", "options": ["all"], "errors": [assigned("min", 5)] },
+        { "code": "Foo.bar = function(baz) { return 1; };", "options": ["all"], "errors": [defined("baz", 20)] },
+        { "code": "var min = {min: 1}", "options": [{ "vars": "all" }], "errors": [assigned("min", 5)] },
+        { "code": "function gg(baz, bar) { return baz; }; gg();", "options": [{ "vars": "all" }], "errors": [defined("bar", 18)] },
+        { "code": "(function(foo, baz, bar) { return baz; })();", "options": [{ "vars": "all", "args": "after-used" }], "errors": [defined("bar", 21)] },
+        { "code": "(function(foo, baz, bar) { return baz; })();", "options": [{ "vars": "all", "args": "all" }], "errors": [defined("foo", 11), defined("bar", 21)] },
+        { "code": "(function z(foo) { var bar = 33; })();", "options": [{ "vars": "all", "args": "all" }], "errors": [defined("foo", 13), assigned("bar", 24)] },
+        { "code": "(function z(foo) { z(); })();", "options": [{}], "errors": [defined("foo", 13)] },
+        { "code": "import x from \"y\";", "errors": [defined("x", 8)] },
+        { "code": "export function fn2({ x, y }) {\n console.log(x); \n};", "errors": [defined("y", 26)] },
+        { "code": "export function fn2( x, y ) {\n console.log(x); \n};", "errors": [defined("y", 25)] },
+        { "code": "var _a; var b;", "options": [{ "vars": "all", "varsIgnorePattern": "^_" }], "errors": [{ "line": 1, "column": 13 }] },
+        {
+            "code": "const data = { type: 'coords', x: 1, y: 2 };\nconst { type, ...coords } = data;\n console.log(coords);",
+            "errors": [{ "message": "'type' is assigned a value but never used.", "line": 2, "column": 9 }]
+        },
+        { "code": "try{}catch(err){};", "errors": [defined("err", 12)] },
+        { "code": "(function ({ a }, b ) { return b; })();", "errors": [defined("a", 14)] },
+        { "code": "(function ({ a }, { b, c } ) { return b; })();", "errors": [defined("a", 14), defined("c", 24)] },
+        { "code": "var a = 1; var b = 2;", "errors": 2 },
+        // Columns count UTF-16 code units, escaped identifiers are reported by their name
+        { "code": "var 𠮷; var \\u{62};", "errors": [defined("𠮷", 5), defined("b", 13)] },
+    ]);
+
+    Tester::from_eslint(NoUnusedVars::NAME, &valid, &invalid)
+        .with_snapshot_suffix("eslint")
+        .test_and_snapshot();
+}
@@ -13,12 +13,10 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-useless-catch): Unnecessary try/catch wrapper")]
-#[diagnostic(severity(warning))]
 struct NoUselessCatchDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-useless-catch): Unnecessary catch clause")]
-#[diagnostic(severity(warning))]
 struct NoUselessCatchFinalizerDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -4,13 +4,22 @@ use oxc_diagnostics::{
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
+use oxc_regex_parser::{
+    ast::{Character, CharacterClass, CharacterKind, ClassElement},
+    Parser, ParserOptions, Visit,
+};
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-useless-escape): Unnecessary escape character {0:?}")]
-#[diagnostic(severity(warning))]
+#[diagnostic(help("Remove the `\\`"))]
 struct NoUselessEscapeDiagnostic(char, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -19,34 +28,53 @@ pub struct NoUselessEscape;
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Disallow unnecessary escape characters in strings, template literals and regular
+    /// expressions
     ///
     /// ### Why is this bad?
     ///
+    /// Escaping a character which has no special meaning does not change the value, and only
+    /// makes the code harder to read.
     ///
     /// ### Example
     /// ```javascript
+    /// "\'";
+    /// '\"';
+    /// "\#";
+    /// `\"${foo}\"`;
+    /// /!@#\%/;
+    /// /[a\-]/;
     /// ```
     NoUselessEscape,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoUselessEscape {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
-            AstKind::RegExpLiteral(literal)
-                if literal.regex.pattern.len() + literal.regex.flags.iter().count()
-                    != literal.span.size() as usize =>
-            {
-                check(
-                    ctx,
-                    literal.span.start,
-                    &check_regexp(literal.span.source_text(ctx.source_text())),
-                );
+            AstKind::RegExpLiteral(literal) => {
+                let pattern = literal.regex.pattern.as_str();
+                let options = ParserOptions::from_flags(literal.regex.flags);
+                // Invalid patterns are syntax errors
+                let Ok(parsed) = Parser::new(pattern, options).parse() else {
+                    return;
+                };
+                let mut finder = UselessEscapeFinder {
+                    pattern,
+                    unicode_sets_mode: options.unicode_sets_mode,
+                    offsets: vec![],
+                };
+                finder.visit_pattern(&parsed);
+                // Removing a `\` may join the character with its neighbours into new syntax,
+                // e.g. `/a{1\,2}/` becomes a quantifier
+                check(ctx, literal.span.start + 1, &finder.offsets, FixKind::Suggestion);
             }
             AstKind::StringLiteral(literal) => check(
                 ctx,
                 literal.span.start,
                 &check_string(literal.span.source_text(ctx.source_text())),
+                FixKind::Safe,
             ),
             AstKind::TemplateLiteral(literal) if !matches!(ctx.nodes().parent_kind(node.id()), Some(AstKind::TaggedTemplateExpression(expr)) if expr.quasi.span == literal.span) => {
                 for template_element in &literal.quasis {
@@ -54,6 +82,7 @@ impl Rule for NoUselessEscape {
                         ctx,
                         template_element.span.start - 1,
                         &check_template(template_element.span.source_text(ctx.source_text())),
+                        FixKind::Safe,
                     );
                 }
             }
@@ -62,66 +91,114 @@ impl Rule for NoUselessEscape {
     }
 }
 
+/// `offsets` are the offsets of the escaped characters from `start`
 #[allow(clippy::cast_possible_truncation)]
-fn check(ctx: &LintContext<'_>, start: u32, offsets: &[usize]) {
+fn check(ctx: &LintContext<'_>, start: u32, offsets: &[usize], kind: FixKind) {
     let source_text = ctx.source_text();
     for offset in offsets {
         let offset = start as usize + offset;
         let c = source_text[offset..].chars().next().unwrap();
         let offset = offset as u32;
         let len = c.len_utf8() as u32;
-        ctx.diagnostic(NoUselessEscapeDiagnostic(c, Span::new(offset - 1, offset + len)));
+        ctx.diagnostic_with_fix(
+            NoUselessEscapeDiagnostic(c, Span::new(offset - 1, offset + len)),
+            || Fix::delete(Span::new(offset - 1, offset)).with_kind(kind),
+        );
     }
 }
 
 const REGEX_GENERAL_ESCAPES: &str = "\\bcdDfnpPrsStvwWxu0123456789]";
 const REGEX_NON_CHARCLASS_ESCAPES: &str = "\\bcdDfnpPrsStvwWxu0123456789]^/.$*+?[{}|()Bk";
+/// Always meaningful in a class with the `v` flag
+const REGEX_CLASS_SET_SYNTAX_CHARACTERS: &str = "()[]{}/-\\|";
+/// Meaningful in a class with the `v` flag when next to the same character, e.g. `[a&\&b]`
+const REGEX_CLASS_SET_DOUBLE_PUNCTUATORS: &str = "!#$%&*+,.:;<=>?@^`~";
 
-fn check_regexp(regex: &str) -> Vec<usize> {
-    let mut offsets = vec![];
-    let mut in_escape = false;
-    let mut in_character_class = false;
-    let mut start_char_class = false;
-    let mut offset = 1;
+/// Collects the offsets of the uselessly escaped characters in a pattern
+struct UselessEscapeFinder<'a> {
+    pattern: &'a str,
+    unicode_sets_mode: bool,
+    offsets: Vec<usize>,
+}
 
-    // Skip the leading and trailing `/`
-    let mut chars = regex[1..regex.len() - 1].chars().peekable();
-    while let Some(c) = chars.next() {
-        if in_escape {
-            in_escape = false;
-            match c {
-                '-' if in_character_class
-                    && !start_char_class
-                    && !chars.peek().is_some_and(|c| *c == ']') =>
-                { /* noop */ }
-                '^' if start_char_class => { /* noop */ }
-                _ => {
-                    let escapes = if in_character_class {
-                        REGEX_GENERAL_ESCAPES
-                    } else {
-                        REGEX_NON_CHARCLASS_ESCAPES
-                    };
-                    if !escapes.contains(c) {
-                        offsets.push(offset);
+/// Where a character is in a class, `[\^a]` and `[a\-b]` are not useless
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ClassPosition {
+    First,
+    Middle,
+    Last,
+}
+
+impl<'a> UselessEscapeFinder<'a> {
+    fn check_class_character(
+        &mut self,
+        character: &Character,
+        negate: bool,
+        position: ClassPosition,
+    ) {
+        if character.kind != CharacterKind::Identity {
+            return;
+        }
+        let Some(c) = char::from_u32(character.value) else { return };
+        let useful = match c {
+            '^' => position == ClassPosition::First && !negate,
+            '-' if !self.unicode_sets_mode => position == ClassPosition::Middle,
+            _ if self.unicode_sets_mode => {
+                REGEX_GENERAL_ESCAPES.contains(c)
+                    || REGEX_CLASS_SET_SYNTAX_CHARACTERS.contains(c)
+                    || (REGEX_CLASS_SET_DOUBLE_PUNCTUATORS.contains(c) && {
+                        let start = character.span.start as usize;
+                        let end = character.span.end as usize;
+                        self.pattern[..start].ends_with(c) || self.pattern[end..].starts_with(c)
+                    })
+            }
+            _ => REGEX_GENERAL_ESCAPES.contains(c),
+        };
+        if !useful {
+            self.offsets.push(character.span.start as usize + 1);
+        }
+    }
+}
+
+impl<'a> Visit for UselessEscapeFinder<'a> {
+    fn visit_character(&mut self, character: &Character) {
+        if character.kind == CharacterKind::Identity
+            && char::from_u32(character.value)
+                .is_some_and(|c| !REGEX_NON_CHARCLASS_ESCAPES.contains(c))
+        {
+            self.offsets.push(character.span.start as usize + 1);
+        }
+    }
+
+    fn visit_character_class(&mut self, class: &CharacterClass) {
+        let last = class.elements.len().saturating_sub(1);
+        for (index, element) in class.elements.iter().enumerate() {
+            let position = if index == 0 {
+                ClassPosition::First
+            } else if index == last {
+                ClassPosition::Last
+            } else {
+                ClassPosition::Middle
+            };
+            match element {
+                ClassElement::Character(character) => {
+                    self.check_class_character(character, class.negate, position);
+                }
+                ClassElement::Range(range) => {
+                    self.check_class_character(&range.min, class.negate, ClassPosition::Middle);
+                    self.check_class_character(&range.max, class.negate, ClassPosition::Middle);
+                }
+                ClassElement::ClassStrings(strings) => {
+                    for character in strings.strings.iter().flatten() {
+                        self.check_class_character(character, class.negate, ClassPosition::Middle);
                     }
                 }
+                ClassElement::CharacterSet(_) | ClassElement::CharacterClass(_) => {
+                    self.visit_class_element(element);
+                }
             }
-        } else if c == '/' && !in_character_class {
-            break;
-        } else if c == '[' {
-            in_character_class = true;
-            start_char_class = true;
-        } else if c == '\\' {
-            in_escape = true;
-        } else if c == ']' {
-            in_character_class = false;
-        } else {
-            start_char_class = false;
         }
-        offset += c.len_utf8();
     }
-
-    offsets
 }
 
 const VALID_STRING_ESCAPES: &str = "\\nrvtbfux\n\r\u{2028}\u{2029}";
@@ -187,9 +264,9 @@ fn check_template(string: &str) -> Vec<usize> {
             }
         } else if c == '\\' {
             in_escape = true;
-        } else {
-            prev_char = c;
+            continue;
         }
+        prev_char = c;
     }
 
     offsets
@@ -303,6 +380,10 @@ fn test() {
         "var foo = /[\\p{ASCII}]/u",
         "var foo = /[\\P{ASCII}]/u",
         "`${/\\s+/g}`",
+        // ES2024
+        "var foo = /[\\(\\-\\)\\/]/v",
+        "var foo = /[a&\\&b]/v",
+        "var foo = /[\\q{\\|}]/v",
     ];
 
     let fail = vec![
@@ -356,7 +437,19 @@ fn test() {
         "`template literal with mixed linebreaks in line continuations \\\n\\\r\\\r\n\\and useless escape`",
         "`\\a```",
         r"var foo = /\（([^\）\（]+)\）$|\(([^\)\)]+)\)$/;",
+        "var foo = /[\\@]/v",
+        "var foo = /[^\\^]/",
+    ];
+
+    let fix = vec![
+        ("var foo = '\\#';", "var foo = '#';", None),
+        ("var foo = '\\p\\a\\@';", "var foo = 'pa@';", None),
+        ("var foo = `\\$\\{{${foo}`;", "var foo = `$\\{{${foo}`;", None),
+        ("var foo = /[ab\\-]/", "var foo = /[ab-]/", None),
+        ("var foo = /\\#\\./", "var foo = /#\\./", None),
     ];
 
-    Tester::new_without_config(NoUselessEscape::NAME, pass, fail).test_and_snapshot();
+    Tester::new_without_config(NoUselessEscape::NAME, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
@@ -0,0 +1,419 @@
+use oxc_ast::{
+    ast::{
+        AssignmentExpression, AssignmentTarget, AssignmentTargetMaybeDefault,
+        AssignmentTargetPattern, AssignmentTargetProperty, IdentifierReference, ModifierKind,
+        SimpleAssignmentTarget, VariableDeclarationKind,
+    },
+    syntax_directed_operations::BoundNames,
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{AstNodeId, SymbolId};
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::operator::AssignmentOperator;
+use rustc_hash::FxHashSet;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(prefer-const): '{0}' is never reassigned.")]
+#[diagnostic(help("Use `const` instead."))]
+struct PreferConstDiagnostic(Atom, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferConst {
+    /// Only report the variables of a destructuring declaration when all of them can be `const`
+    destructuring_all: bool,
+    /// Ignore variables which are read before their only assignment
+    ignore_read_before_assign: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require `const` declarations for variables that are never reassigned after declared
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If a variable is never reassigned, declaring it with `const` tells the reader that it
+    /// stays the same, and prevents reassigning it by mistake. Declarations are fixed when all
+    /// of their variables are initialized and never reassigned.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// let a = 3;
+    /// console.log(a);
+    ///
+    /// let b;
+    /// b = 0;
+    /// console.log(b);
+    ///
+    /// let c, d;
+    /// ({ c, d } = obj);
+    ///
+    /// for (let i in [1, 2, 3]) {
+    ///     console.log(i);
+    /// }
+    /// ```
+    PreferConst,
+    style,
+    fix,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": {
+                "destructuring": { "enum": ["any", "all"] },
+                "ignoreReadBeforeAssign": { "type": "boolean" }
+            },
+            "additionalProperties": false
+        }
+    ]"#
+);
+
+impl Rule for PreferConst {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        Self {
+            destructuring_all: config
+                .and_then(|v| v.get("destructuring"))
+                .and_then(serde_json::Value::as_str)
+                .is_some_and(|destructuring| destructuring == "all"),
+            ignore_read_before_assign: config
+                .and_then(|v| v.get("ignoreReadBeforeAssign"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::VariableDeclaration(decl) = node.kind() else { return };
+        if decl.kind != VariableDeclarationKind::Let
+            || decl.modifiers.contains(ModifierKind::Declare)
+        {
+            return;
+        }
+        let parent = ctx.nodes().parent_node(node.id());
+        // `for (let x of xs)` assigns `x` on each iteration, like an initializer
+        let is_for_in_of_left = parent.is_some_and(|parent| {
+            matches!(parent.kind(), AstKind::ForInStatement(_) | AstKind::ForOfStatement(_))
+        });
+        let is_for_init =
+            parent.is_some_and(|parent| matches!(parent.kind(), AstKind::ForStatementInit(_)));
+
+        let mut declarators = vec![];
+        for declarator in &decl.declarations {
+            let initialized = declarator.init.is_some() || is_for_in_of_left;
+            let mut names = vec![];
+            declarator.id.bound_names(&mut |ident| {
+                let symbol_id = ident.symbol_id.get();
+                let is_const = symbol_id.is_some_and(|symbol_id| {
+                    self.is_never_reassigned(symbol_id, initialized, node, ctx)
+                });
+                names.push((ident.name.clone(), ident.span, symbol_id, is_const));
+            });
+            declarators.push((initialized, names));
+        }
+        if self.destructuring_all {
+            Self::check_destructuring_assignments(&mut declarators, node, ctx);
+        }
+
+        let mut reports = vec![];
+        let mut fixable = true;
+        for (initialized, names) in declarators {
+            fixable &= initialized;
+            let all_const = names.iter().all(|(_, _, _, is_const)| *is_const);
+            fixable &= all_const;
+            if self.destructuring_all && !all_const {
+                continue;
+            }
+            reports.extend(
+                names
+                    .into_iter()
+                    .filter(|(_, _, _, is_const)| *is_const)
+                    .map(|(name, span, _, _)| (name, span)),
+            );
+        }
+        // The variables of `for (let i = 0, end = 10; i < end; ++i)` cannot be separated
+        if is_for_init && !fixable {
+            return;
+        }
+
+        let let_span = Span::new(decl.span.start, decl.span.start + 3);
+        for (name, span) in reports {
+            if fixable {
+                ctx.diagnostic_with_fix(PreferConstDiagnostic(name, span), || {
+                    Fix::new("const", let_span)
+                });
+            } else {
+                ctx.diagnostic(PreferConstDiagnostic(name, span));
+            }
+        }
+    }
+}
+
+/// The name, span and symbol of a declared variable, and whether it can be `const`
+type BoundName = (Atom, Span, Option<SymbolId>, bool);
+
+impl PreferConst {
+    /// With `destructuring: all`, a variable assigned by a destructuring assignment such as
+    /// `[a, b] = arr;` can only be `const` when all the assigned variables are declared by the
+    /// declaration and can be `const`.
+    fn check_destructuring_assignments(
+        declarators: &mut [(bool, Vec<BoundName>)],
+        declaration: &AstNode,
+        ctx: &LintContext,
+    ) {
+        let const_symbols = declarators
+            .iter()
+            .flat_map(|(_, names)| names)
+            .filter(|(_, _, _, is_const)| *is_const)
+            .filter_map(|(_, _, symbol_id, _)| *symbol_id)
+            .collect::<FxHashSet<_>>();
+        let parent_id = ctx.nodes().parent_id(declaration.id());
+        for (_, _, symbol_id, is_const) in declarators.iter_mut().flat_map(|(_, names)| names) {
+            let Some(symbol_id) = symbol_id.filter(|_| *is_const) else { continue };
+            let Some(write) =
+                ctx.symbols().get_resolved_references(symbol_id).find(|r| r.is_write())
+            else {
+                continue;
+            };
+            let Some(assignment) = assignment_statement_in(write.node_id(), parent_id, ctx) else {
+                continue;
+            };
+            let mut identifiers = vec![];
+            assigned_identifiers(&assignment.left, &mut identifiers);
+            *is_const = identifiers.iter().all(|ident| {
+                ident
+                    .reference_id
+                    .get()
+                    .and_then(|reference_id| ctx.symbols().get_reference(reference_id).symbol_id())
+                    .is_some_and(|symbol_id| const_symbols.contains(&symbol_id))
+            });
+        }
+    }
+
+    /// Whether the variable is only written by its initializer, or, when it is not initialized,
+    /// by a single `x = value;` or `[x] = value;` statement next to the declaration
+    fn is_never_reassigned(
+        &self,
+        symbol_id: SymbolId,
+        initialized: bool,
+        declaration: &AstNode,
+        ctx: &LintContext,
+    ) -> bool {
+        let mut writes = ctx
+            .symbols()
+            .get_resolved_references(symbol_id)
+            .filter(|reference| reference.is_write());
+        if initialized {
+            return writes.next().is_none();
+        }
+        let (Some(write), None) = (writes.next(), writes.next()) else { return false };
+        if self.ignore_read_before_assign
+            && ctx
+                .symbols()
+                .get_resolved_references(symbol_id)
+                .any(|reference| reference.is_read() && reference.span().start < write.span().start)
+        {
+            return false;
+        }
+        write.span().start > declaration.kind().span().end
+            && assignment_statement_in(
+                write.node_id(),
+                ctx.nodes().parent_id(declaration.id()),
+                ctx,
+            )
+            .is_some()
+    }
+}
+
+/// The `x = value;` or `[x, y] = value;` statement directly in `parent_id` assigning the
+/// identifier, e.g. not in a loop or a nested block. `None` when a member expression is also
+/// assigned, e.g. `[x, obj.y] = value;`, as the assignment cannot become a declaration.
+fn assignment_statement_in<'a>(
+    ident_id: AstNodeId,
+    parent_id: Option<AstNodeId>,
+    ctx: &LintContext<'a>,
+) -> Option<&'a AssignmentExpression<'a>> {
+    let nodes = ctx.nodes();
+    let mut parents = nodes.iter_parents(ident_id).skip(1).skip_while(|parent| {
+        matches!(
+            parent.kind(),
+            AstKind::SimpleAssignmentTarget(_)
+                | AstKind::AssignmentTarget(_)
+                | AstKind::AssignmentTargetWithDefault(_)
+        )
+    });
+    let Some(AstKind::AssignmentExpression(expr)) = parents.next().map(AstNode::kind) else {
+        return None;
+    };
+    // `({ x } = value);`
+    let statement =
+        parents.find(|parent| !matches!(parent.kind(), AstKind::ParenthesizedExpression(_)))?;
+    (expr.operator == AssignmentOperator::Assign
+        && matches!(statement.kind(), AstKind::ExpressionStatement(_))
+        && nodes.parent_id(statement.id()) == parent_id
+        && assigned_identifiers(&expr.left, &mut vec![]))
+    .then_some(expr)
+}
+
+/// Collect the identifiers assigned by `target`, `false` when it also assigns anything else,
+/// e.g. a member expression
+fn assigned_identifiers<'a, 'b>(
+    target: &'b AssignmentTarget<'a>,
+    identifiers: &mut Vec<&'b IdentifierReference>,
+) -> bool {
+    let maybe_default =
+        |target: &'b AssignmentTargetMaybeDefault<'a>,
+         identifiers: &mut Vec<&'b IdentifierReference>| match target {
+            AssignmentTargetMaybeDefault::AssignmentTarget(target) => {
+                assigned_identifiers(target, identifiers)
+            }
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                assigned_identifiers(&target.binding, identifiers)
+            }
+        };
+    match target {
+        AssignmentTarget::SimpleAssignmentTarget(
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident),
+        ) => {
+            identifiers.push(ident);
+            true
+        }
+        AssignmentTarget::SimpleAssignmentTarget(_) => false,
+        AssignmentTarget::AssignmentTargetPattern(
+            AssignmentTargetPattern::ArrayAssignmentTarget(array),
+        ) => {
+            array.elements.iter().flatten().all(|element| maybe_default(element, identifiers))
+                && array.rest.iter().all(|rest| assigned_identifiers(rest, identifiers))
+        }
+        AssignmentTarget::AssignmentTargetPattern(
+            AssignmentTargetPattern::ObjectAssignmentTarget(object),
+        ) => {
+            object.properties.iter().all(|property| match property {
+                AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                    identifiers.push(&property.binding);
+                    true
+                }
+                AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                    maybe_default(&property.binding, identifiers)
+                }
+            }) && object.rest.iter().all(|rest| assigned_identifiers(rest, identifiers))
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::too_many_lines)]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var x = 0;", None),
+        ("let x;", None),
+        ("let x; { x = 0; } foo(x);", None),
+        ("let x = 0; x = 1;", None),
+        ("const x = 0;", None),
+        ("for (let i = 0, end = 10; i < end; ++i) {}", None),
+        ("for (let i in [1,2,3]) { i = 0; }", None),
+        ("for (let x of [1,2,3]) { x = 0; }", None),
+        ("(function() { var x = 0; })();", None),
+        ("(function() { let x; })();", None),
+        ("(function() { let x; { x = 0; } foo(x); })();", None),
+        ("(function() { let x = 0; x = 1; })();", None),
+        ("(function() { const x = 0; })();", None),
+        ("(function() { for (let i = 0, end = 10; i < end; ++i) {} })();", None),
+        ("(function() { for (let i in [1,2,3]) { i = 0; } })();", None),
+        ("(function() { for (let x of [1,2,3]) { x = 0; } })();", None),
+        ("(function(x = 0) { })();", None),
+        ("let a; while (a = foo());", None),
+        ("let a; do {} while (a = foo());", None),
+        ("let a; for (;a = foo(););", None),
+        ("let a; for (;;++a);", None),
+        ("let a; for (const {b = ++a} in foo());", None),
+        ("let a; for (const {b = ++a} of foo());", None),
+        ("let a; for (const x of [1,2,3]) { if (a) {} a = foo(); }", None),
+        ("let a; for (const x of [1,2,3]) { a = a || foo(); bar(a); }", None),
+        ("let a; for (const x of [1,2,3]) { foo(++a); }", None),
+        ("let a; function foo() { if (a) {} a = bar(); }", None),
+        ("let a; function foo() { a = a || bar(); baz(a); }", None),
+        ("let a; function foo() { bar(++a); }", None),
+        ("let x; x = 0; x = 1;", None),
+        ("let x; x += 1;", None),
+        ("let x; if (a) x = 0;", None),
+        ("let x; while (a) x = 0;", None),
+        ("let x = 0; x++;", None),
+        ("let x = 0; ({x} = obj);", None),
+        ("let predicate; [typeNode.returnType, predicate] = foo();", None),
+        ("let {a, b} = obj; b = 0;", Some(json!([{ "destructuring": "all" }]))),
+        ("let {a: {b, c}} = {a: {b: 1, c: 2}}; b = 3;", Some(json!([{ "destructuring": "all" }]))),
+        ("let x; console.log(x); x = 0;", Some(json!([{ "ignoreReadBeforeAssign": true }]))),
+        ("let r; [r] = arr; r = 0;", None),
+        ("let r; if (a) { [r] = arr; }", None),
+        ("let r; [r] += arr;", None),
+        ("let r; [r, obj.s] = arr;", None),
+        ("let r; ({ a: { b: r } } = obj); foo(() => { r = 0; });", None),
+        ("let s; let t; ({ s, t } = obj); t = 0;", Some(json!([{ "destructuring": "all" }]))),
+        ("let s, t; ({ s, t } = obj); t = 0;", Some(json!([{ "destructuring": "all" }]))),
+        ("let s; ({ s, t } = obj);", Some(json!([{ "destructuring": "all" }]))),
+        ("declare let x: number;", None),
+    ];
+
+    let fail = vec![
+        ("let x = 1; foo(x);", None),
+        ("for (let i in [1,2,3]) { foo(i); }", None),
+        ("for (let x of [1,2,3]) { foo(x); }", None),
+        ("let [x = -1, y] = [1,2]; y = 0;", None),
+        ("let {a: x = -1, b: y} = {a:1,b:2}; y = 0;", None),
+        ("(function() { let x = 1; foo(x); })();", None),
+        ("(function() { for (let i in [1,2,3]) { foo(i); } })();", None),
+        ("(function() { for (let x of [1,2,3]) { foo(x); } })();", None),
+        ("(function() { let [x = -1, y] = [1,2]; y = 0; })();", None),
+        ("let f = (function() { let g = x; })(); f = 1;", None),
+        ("(function() { let {a: x = -1, b: y} = {a:1,b:2}; y = 0; })();", None),
+        ("let x = 0; { let x = 1; foo(x); } x = 0;", None),
+        ("for (let i = 0; i < 10; ++i) { let x = 1; foo(x); }", None),
+        ("for (let i in [1,2,3]) { let x = 1; foo(x); }", None),
+        ("let x; x = 0;", None),
+        ("switch (a) { case 0: let x; x = 0; }", None),
+        ("(function() { let x; x = 1; })();", None),
+        ("let {a = 0, b} = obj; foo(a, b);", None),
+        ("let {a = 0, b} = obj; foo(a, b);", Some(json!([{ "destructuring": "all" }]))),
+        ("let {a: {b, c}} = {a: {b: 1, c: 2}}; b = 3;", None),
+        ("let x = 0, y = 1; y = 2;", None),
+        ("let a = 1, b = 2; foo(a, b);", None),
+        ("let x; console.log(x); x = 0;", None),
+        ("for (let i = 0; i < 10;) {}", None),
+        ("export let x = 1;", None),
+        ("let r; [r] = arr;", None),
+        ("let s, t; ({ s, t } = obj);", None),
+        ("let s, t; ({ s, t } = obj);", Some(json!([{ "destructuring": "all" }]))),
+        ("let r; [r = 0, ...rest] = arr;", None),
+        ("let r; ({ a: [r] } = obj);", None),
+        ("let s, t; ({ s, t } = obj); t = 0;", None),
+        ("let s; ({ s, t } = obj);", None),
+    ];
+
+    let fix = vec![
+        ("let x = 1; foo(x);", "const x = 1; foo(x);", None),
+        ("for (let i in [1,2,3]) { foo(i); }", "for (const i in [1,2,3]) { foo(i); }", None),
+        ("for (let x of [1,2,3]) { foo(x); }", "for (const x of [1,2,3]) { foo(x); }", None),
+        ("let {a = 0, b} = obj; foo(a, b);", "const {a = 0, b} = obj; foo(a, b);", None),
+        ("let a = 1, b = 2; foo(a, b);", "const a = 1, b = 2; foo(a, b);", None),
+        ("for (let i = 0; i < 10;) {}", "for (const i = 0; i < 10;) {}", None),
+        ("export let x = 1;", "export const x = 1;", None),
+        // Not fixed when some of the variables are reassigned, or not initialized
+        ("let x = 0, y = 1; y = 2;", "let x = 0, y = 1; y = 2;", None),
+        ("let [x = -1, y] = [1,2]; y = 0;", "let [x = -1, y] = [1,2]; y = 0;", None),
+        ("let x; x = 0;", "let x; x = 0;", None),
+        ("let r; [r] = arr;", "let r; [r] = arr;", None),
+    ];
+
+    Tester::new(PreferConst::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,291 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_regex_parser::{parse_flags, Parser, ParserOptions};
+use oxc_span::{Atom, Span};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum PreferRegexLiteralsDiagnostic {
+    #[error(
+        "eslint(prefer-regex-literals): Use a regular expression literal instead of the `RegExp` constructor"
+    )]
+    Constructor(#[label] Span),
+
+    #[error(
+        "eslint(prefer-regex-literals): Regular expression literal is unnecessarily wrapped within a `RegExp` constructor"
+    )]
+    RedundantWrapping(#[label] Span),
+
+    #[error(
+        "eslint(prefer-regex-literals): Use a regular expression literal with flags instead of the `RegExp` constructor"
+    )]
+    RedundantWrappingWithFlags(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferRegexLiterals {
+    disallow_redundant_wrapping: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow use of the `RegExp` constructor in favor of regular expression literals
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A pattern in a string has to be escaped twice, e.g. `new RegExp("\\.")`, and cannot be
+    /// checked when the code is parsed. A regular expression literal is easier to read and
+    /// invalid patterns are reported early.
+    ///
+    /// With `disallowRedundantWrapping`, a literal wrapped in a constructor, such as
+    /// `new RegExp(/abc/, "u")`, is reported as well.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// new RegExp("abc");
+    /// RegExp("abc", "u");
+    /// new RegExp(String.raw`^\d\.$`);
+    /// ```
+    PreferRegexLiterals,
+    style,
+    fix,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": {
+                "disallowRedundantWrapping": { "type": "boolean" }
+            },
+            "additionalProperties": false
+        }
+    ]"#
+);
+
+impl Rule for PreferRegexLiterals {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            disallow_redundant_wrapping: value
+                .get(0)
+                .and_then(|v| v.get("disallowRedundantWrapping"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (callee, arguments, span) = match node.kind() {
+            AstKind::NewExpression(expr) => (&expr.callee, &expr.arguments, expr.span),
+            AstKind::CallExpression(expr) if !expr.optional => {
+                (&expr.callee, &expr.arguments, expr.span)
+            }
+            _ => return,
+        };
+        let Expression::Identifier(ident) = callee.get_inner_expression() else {
+            return;
+        };
+        if ident.name != "RegExp" || !ctx.semantic().is_reference_to_global_variable(ident) {
+            return;
+        }
+        let Some(first) = arguments.first() else {
+            return;
+        };
+        if arguments.len() > 2 {
+            return;
+        }
+        let flags = match arguments.get(1) {
+            Some(argument) => match static_string(argument) {
+                Some(flags) => Some(flags),
+                None => return,
+            },
+            None => None,
+        };
+
+        let (diagnostic, replacement) = match first {
+            Argument::Expression(Expression::RegExpLiteral(literal)) => {
+                if !self.disallow_redundant_wrapping {
+                    return;
+                }
+                flags.map_or_else(
+                    || {
+                        (
+                            PreferRegexLiteralsDiagnostic::RedundantWrapping(span),
+                            Some(literal.span.source_text(ctx.source_text()).to_string()),
+                        )
+                    },
+                    // The flags replace the flags of the literal
+                    |flags| {
+                        (
+                            PreferRegexLiteralsDiagnostic::RedundantWrappingWithFlags(span),
+                            regex_literal(literal.regex.pattern.as_str(), flags),
+                        )
+                    },
+                )
+            }
+            argument => {
+                let Some(pattern) = static_string(argument) else {
+                    return;
+                };
+                (
+                    PreferRegexLiteralsDiagnostic::Constructor(span),
+                    escape_pattern(pattern)
+                        .and_then(|source| regex_literal(&source, flags.unwrap_or_default())),
+                )
+            }
+        };
+
+        match replacement.filter(|_| can_replace_with_literal(span, ctx)) {
+            Some(replacement) => {
+                ctx.diagnostic_with_fix(diagnostic, || Fix::new(replacement, span));
+            }
+            None => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+/// The value of a string literal, a template literal without substitutions, or a `String.raw`
+/// tagged template without substitutions
+fn static_string<'a>(argument: &'a Argument<'a>) -> Option<&'a str> {
+    let Argument::Expression(expr) = argument else {
+        return None;
+    };
+    match expr.get_inner_expression() {
+        Expression::StringLiteral(literal) => Some(literal.value.as_str()),
+        Expression::TemplateLiteral(literal) if literal.is_no_substitution_template() => {
+            literal.quasi().map(Atom::as_str)
+        }
+        Expression::TaggedTemplateExpression(expr)
+            if expr.tag.is_specific_member_access("String", "raw")
+                && expr.quasi.is_no_substitution_template() =>
+        {
+            expr.quasi.quasis.first().map(|quasi| quasi.value.raw.as_str())
+        }
+        _ => None,
+    }
+}
+
+/// The source of a pattern in a regular expression literal, with `/` and line terminators
+/// escaped. `None` when a line terminator follows a `\`, which cannot be written in a literal.
+fn escape_pattern(pattern: &str) -> Option<String> {
+    if pattern.is_empty() {
+        return Some("(?:)".into());
+    }
+    let mut source = String::with_capacity(pattern.len());
+    let mut escaped = false;
+    for c in pattern.chars() {
+        match c {
+            '\n' | '\r' | '\u{2028}' | '\u{2029}' if escaped => return None,
+            '\n' => source.push_str("\\n"),
+            '\r' => source.push_str("\\r"),
+            '\u{2028}' => source.push_str("\\u2028"),
+            '\u{2029}' => source.push_str("\\u2029"),
+            '/' if !escaped => source.push_str("\\/"),
+            c => source.push(c),
+        }
+        escaped = !escaped && c == '\\';
+    }
+    Some(source)
+}
+
+/// `/source/flags` when it is a valid regular expression
+fn regex_literal(source: &str, flags: &str) -> Option<String> {
+    let options = ParserOptions::from_flags(parse_flags(flags).ok()?);
+    Parser::new(source, options).parse().ok()?;
+    Some(format!("/{source}/{flags}"))
+}
+
+/// A `/` after a line break may continue the previous line as a division, as in
+/// `a\nRegExp("b")` becoming `a\n/b/`
+fn can_replace_with_literal(span: Span, ctx: &LintContext) -> bool {
+    let before = &ctx.source_text()[..span.start as usize];
+    let trimmed = before.trim_end();
+    let has_line_break = before[trimmed.len()..].contains(['\n', '\r', '\u{2028}', '\u{2029}']);
+    // `a/RegExp("b")` would become the comment `a//b/`
+    !trimmed.ends_with('/')
+        && (!has_line_break || trimmed.is_empty() || trimmed.ends_with([';', '{', '(', ',', '=']))
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("/abc/", None),
+        ("/abc/g", None),
+        ("new RegExp(pattern)", None),
+        ("RegExp(pattern, 'g')", None),
+        ("new RegExp(f('a'))", None),
+        ("RegExp(prefix + 'a')", None),
+        ("new RegExp('a', flags)", None),
+        ("RegExp(`${prefix}abc`)", None),
+        ("new RegExp(String.raw`^\\d\\. ${suffix}`)", None),
+        ("new RegExp(String['raw'] + 'a')", None),
+        ("new RegExp('a', 'g', 'u')", None),
+        ("new RegExp", None),
+        ("RegExp()", None),
+        ("new RegExp(tag`a`)", None),
+        ("RegExp?.('a')", None),
+        ("new foo.RegExp('a')", None),
+        ("let RegExp; new RegExp('a');", None),
+        ("function f(RegExp) { return RegExp('a'); }", None),
+        ("new RegExp(/a/)", None),
+        ("new RegExp(/a/, 'u')", None),
+        ("new RegExp(/a/, flags)", Some(json!([{ "disallowRedundantWrapping": true }]))),
+        ("new RegExp(/a/.source)", Some(json!([{ "disallowRedundantWrapping": true }]))),
+    ];
+
+    let fail = vec![
+        ("new RegExp('abc');", None),
+        ("RegExp('abc');", None),
+        ("new RegExp('abc', 'g');", None),
+        ("RegExp('abc', 'g');", None),
+        ("new RegExp(`abc`);", None),
+        ("RegExp(`abc`, `g`);", None),
+        ("new RegExp(String.raw`abc`);", None),
+        ("new RegExp(String.raw`\\d`, 'g');", None),
+        ("new RegExp(('abc'));", None),
+        ("new RegExp('');", None),
+        ("new RegExp('[', 'g');", None),
+        ("new RegExp('a', 'z');", None),
+        ("globalThis.x = RegExp('a');", None),
+        ("new RegExp(/a/);", Some(json!([{ "disallowRedundantWrapping": true }]))),
+        ("new RegExp(/a/g, 'u');", Some(json!([{ "disallowRedundantWrapping": true }]))),
+        ("RegExp(/a/, `g`);", Some(json!([{ "disallowRedundantWrapping": true }]))),
+    ];
+
+    let fix = vec![
+        ("new RegExp('abc')", "/abc/", None),
+        ("RegExp('abc', 'gu')", "/abc/gu", None),
+        ("new RegExp(`a/b`)", "/a\\/b/", None),
+        ("new RegExp('a\\\\/b')", "/a\\/b/", None),
+        ("new RegExp('a\\nb')", "/a\\nb/", None),
+        ("new RegExp(String.raw`^\\d\\.$`)", "/^\\d\\.$/", None),
+        ("new RegExp('')", "/(?:)/", None),
+        ("x = RegExp('a')", "x = /a/", None),
+        ("x = a/RegExp('b')", "x = a/RegExp('b')", None),
+        ("a\nRegExp('b').test(c)", "a\nRegExp('b').test(c)", None),
+        ("a;\nRegExp('b').test(c)", "a;\n/b/.test(c)", None),
+        ("new RegExp('[')", "new RegExp('[')", None),
+        ("new RegExp('a', 'gg')", "new RegExp('a', 'gg')", None),
+        ("new RegExp('\\\\u{1F600}', 'u')", "/\\u{1F600}/u", None),
+        ("new RegExp(/a/)", "/a/", Some(json!([{ "disallowRedundantWrapping": true }]))),
+        ("new RegExp(/a/, 'g')", "/a/g", Some(json!([{ "disallowRedundantWrapping": true }]))),
+        ("new RegExp(/a/i, 'g')", "/a/g", Some(json!([{ "disallowRedundantWrapping": true }]))),
+        (
+            "new RegExp(/\\a/, 'u')",
+            "new RegExp(/\\a/, 'u')",
+            Some(json!([{ "disallowRedundantWrapping": true }])),
+        ),
+    ];
+
+    Tester::new(PreferRegexLiterals::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,113 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(require-await): Async function has no 'await' expression.")]
+#[diagnostic(help("Consider removing the 'async' keyword."))]
+struct RequireAwaitDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct RequireAwait;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow async functions which have no `await` expression
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An async function which does not use `await` might not need to be async, and may be the
+    /// unintentional result of a refactoring. Empty functions and async generators are ignored.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// async function foo() {
+    ///     doSomething();
+    /// }
+    /// ```
+    RequireAwait,
+    pedantic
+);
+
+impl Rule for RequireAwait {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if node.flags().has_await() {
+            return;
+        }
+        let span = match node.kind() {
+            AstKind::Function(func) if func.r#async && !func.generator => {
+                let Some(body) = &func.body else { return };
+                if body.is_empty() {
+                    return;
+                }
+                Span::new(func.span.start, func.params.span.start)
+            }
+            AstKind::ArrowExpression(arrow) if arrow.r#async && !arrow.body.is_empty() => {
+                Span::new(arrow.span.start, arrow.params.span.end)
+            }
+            _ => return,
+        };
+        // Point at the method name for `async foo() {}`
+        let span = match ctx.nodes().parent_kind(node.id()) {
+            Some(AstKind::MethodDefinition(method)) => {
+                Span::new(method.span.start, method.key.span().end)
+            }
+            Some(AstKind::ObjectProperty(prop)) if prop.method => {
+                Span::new(prop.span.start, prop.key.span().end)
+            }
+            _ => span,
+        };
+        ctx.diagnostic(RequireAwaitDiagnostic(span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("async function foo() { await doSomething() }", None),
+        ("async function f() { await using x = g(); return x; }", None),
+        ("(async function() { await doSomething() })", None),
+        ("async () => { await doSomething() }", None),
+        ("async () => await doSomething()", None),
+        ("({ async foo() { await doSomething() } })", None),
+        ("class A { async foo() { await doSomething() } }", None),
+        ("(class { async foo() { await doSomething() } })", None),
+        ("async function foo() { await (async () => { await doSomething() }) }", None),
+        ("async function foo() {}", None),
+        ("async () => {}", None),
+        ("function foo() { doSomething() }", None),
+        ("async function foo() { for await (x of xs); }", None),
+        ("async function* run() { yield * anotherAsyncGenerator() }", None),
+        (
+            "async function* run() { await new Promise(resolve => setTimeout(resolve, 100)); yield 'Hello'; }",
+            None,
+        ),
+        ("async function* run() { yield 'Hello'; }", None),
+        ("async function foo() { if (a) { await doSomething() } }", None),
+    ];
+
+    let fail = vec![
+        ("async function foo() { doSomething() }", None),
+        ("(async function() { doSomething() })", None),
+        ("async () => { doSomething() }", None),
+        ("async () => doSomething()", None),
+        ("({ async foo() { doSomething() } })", None),
+        ("class A { async foo() { doSomething() } }", None),
+        ("(class { async foo() { doSomething() } })", None),
+        ("(class { async ''() { doSomething() } })", None),
+        ("async function foo() { async () => { await doSomething() } }", None),
+        ("async function foo() { await (async () => { doSomething() }) }", None),
+        ("async function foo() { async function bar() { for await (x of xs); } }", None),
+    ];
+
+    Tester::new(RequireAwait::NAME, pass, fail).test_and_snapshot();
+}
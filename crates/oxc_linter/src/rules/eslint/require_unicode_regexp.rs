@@ -0,0 +1,187 @@
+use oxc_ast::{
+    ast::{Argument, Expression, RegExpFlags},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_regex_parser::{parse_flags, Parser, ParserOptions};
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(require-unicode-regexp): Use the 'u' flag")]
+#[diagnostic(help("Add the `u` or the `v` flag to the regular expression"))]
+struct RequireUnicodeRegexpDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct RequireUnicodeRegexp;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce the use of the `u` or the `v` flag on regular expressions
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Without the `u` or `v` flag, a pattern works on UTF-16 code units instead of code points,
+    /// so `/^.$/.test("😀")` is `false`, and `\p{...}` escapes are not available. The flags also
+    /// make invalid escapes such as `/\a/` syntax errors instead of silently matching `a`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// const a = /aaa/;
+    /// const b = new RegExp("aaa", "g");
+    /// ```
+    RequireUnicodeRegexp,
+    pedantic,
+    suggestion
+);
+
+impl Rule for RequireUnicodeRegexp {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::RegExpLiteral(literal) => {
+                if literal.regex.flags.intersects(RegExpFlags::U | RegExpFlags::V) {
+                    return;
+                }
+                let diagnostic = RequireUnicodeRegexpDiagnostic(literal.span);
+                if is_valid_with_unicode_flag(&literal.regex.pattern, literal.regex.flags) {
+                    let end = literal.span.end;
+                    ctx.diagnostic_with_fix(diagnostic, || {
+                        Fix::new("u", Span::new(end, end)).with_kind(FixKind::Suggestion)
+                    });
+                } else {
+                    ctx.diagnostic(diagnostic);
+                }
+            }
+            AstKind::NewExpression(expr) => {
+                check_constructor(&expr.callee, &expr.arguments, expr.span, ctx);
+            }
+            AstKind::CallExpression(expr) if !expr.optional => {
+                check_constructor(&expr.callee, &expr.arguments, expr.span, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_constructor<'a>(
+    callee: &Expression<'a>,
+    arguments: &oxc_allocator::Vec<'a, Argument<'a>>,
+    span: Span,
+    ctx: &LintContext<'a>,
+) {
+    let Expression::Identifier(ident) = callee.get_inner_expression() else {
+        return;
+    };
+    if ident.name != "RegExp" || !ctx.semantic().is_reference_to_global_variable(ident) {
+        return;
+    }
+    let Some(Argument::Expression(pattern)) = arguments.first() else {
+        return;
+    };
+    let pattern_literal = match pattern.get_inner_expression() {
+        Expression::StringLiteral(literal) => Some(literal),
+        _ => None,
+    };
+
+    let (flags, insert_at, insertion) = match arguments.get(1) {
+        None => {
+            // Reuse the quote of the pattern
+            let quote = pattern_literal
+                .and_then(|literal| ctx.source_text()[literal.span.start as usize..].chars().next())
+                .unwrap_or('"');
+            (RegExpFlags::empty(), pattern.span().end, format!(", {quote}u{quote}"))
+        }
+        Some(Argument::Expression(Expression::StringLiteral(literal))) => {
+            // Unknown flags are reported by `no-invalid-regexp`
+            let Ok(flags) = parse_flags(&literal.value) else {
+                return;
+            };
+            (flags, literal.span.end - 1, "u".to_string())
+        }
+        // Flags which are not known statically
+        Some(_) => return,
+    };
+    if flags.intersects(RegExpFlags::U | RegExpFlags::V) {
+        return;
+    }
+
+    let diagnostic = RequireUnicodeRegexpDiagnostic(span);
+    match pattern_literal {
+        Some(literal) if is_valid_with_unicode_flag(&literal.value, flags) => {
+            ctx.diagnostic_with_fix(diagnostic, || {
+                Fix::new(insertion, Span::new(insert_at, insert_at)).with_kind(FixKind::Suggestion)
+            });
+        }
+        _ => ctx.diagnostic(diagnostic),
+    }
+}
+
+/// Adding the `u` flag can make a valid pattern such as `/\a/` invalid
+fn is_valid_with_unicode_flag(pattern: &str, flags: RegExpFlags) -> bool {
+    let options = ParserOptions::from_flags(flags | RegExpFlags::U);
+    Parser::new(pattern, options).parse().is_ok()
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("/foo/u", None),
+        ("/foo/gimuy", None),
+        ("/foo/v", None),
+        ("RegExp('', 'u')", None),
+        ("new RegExp('', 'u')", None),
+        ("RegExp('', 'gimuy')", None),
+        ("new RegExp('', 'gimuy')", None),
+        ("new RegExp('', 'v')", None),
+        ("const flags = 'u'; new RegExp('', flags)", None),
+        ("const flags = 'g'; new RegExp('', flags + 'u')", None),
+        ("new RegExp('', flags)", None),
+        ("new RegExp('', 'gg')", None),
+        ("RegExp?.('a')", None),
+        ("new foo.RegExp('')", None),
+        ("function f(RegExp) { return new RegExp('foo') }", None),
+        ("class RegExp {}; new RegExp('foo')", None),
+    ];
+
+    let fail = vec![
+        ("/foo/", None),
+        ("/foo/gimy", None),
+        ("/\\a/", None),
+        ("RegExp('foo')", None),
+        ("RegExp('foo', '')", None),
+        ("RegExp('foo', 'gimy')", None),
+        ("new RegExp('foo')", None),
+        ("new RegExp('foo', 'gimy')", None),
+        ("new RegExp(pattern)", None),
+        ("new RegExp(pattern, 'g')", None),
+        ("new RegExp(('foo'))", None),
+        ("globalThis.x = RegExp('foo')", None),
+    ];
+
+    let fix = vec![
+        ("/foo/", "/foo/u", None),
+        ("/foo/gimy", "/foo/gimyu", None),
+        ("/\\a/", "/\\a/", None),
+        ("RegExp('foo')", "RegExp('foo', 'u')", None),
+        ("RegExp(\"foo\")", "RegExp(\"foo\", \"u\")", None),
+        ("new RegExp('foo', '')", "new RegExp('foo', 'u')", None),
+        ("new RegExp('foo', 'gimy')", "new RegExp('foo', 'gimyu')", None),
+        ("new RegExp('\\\\a')", "new RegExp('\\\\a')", None),
+        ("new RegExp(pattern)", "new RegExp(pattern)", None),
+    ];
+
+    Tester::new(RequireUnicodeRegexp::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
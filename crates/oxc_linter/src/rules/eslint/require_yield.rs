@@ -10,7 +10,6 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(require-yield): This generator function does not have 'yield'")]
-#[diagnostic(severity(warning))]
 struct RequireYieldDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -14,24 +14,18 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 #[derive(Debug, Error, Diagnostic)]
 enum UseIsnanDiagnostic {
     #[error("eslint(use-isnan): Requires calls to isNaN() when checking for NaN")]
-    #[diagnostic(severity(warning), help("Use the isNaN function to compare with NaN."))]
+    #[diagnostic(help("Use the isNaN function to compare with NaN."))]
     ComparisonWithNaN(#[label] Span),
     #[error("eslint(use-isnan): Requires calls to isNaN() when checking for NaN")]
-    #[diagnostic(
-        severity(warning),
-        help(
-            "'switch(NaN)' can never match a case clause. Use Number.isNaN instead of the switch."
-        )
-    )]
+    #[diagnostic(help(
+        "'switch(NaN)' can never match a case clause. Use Number.isNaN instead of the switch."
+    ))]
     SwitchNaN(#[label] Span),
     #[error("eslint(use-isnan): Requires calls to isNaN() when checking for NaN")]
-    #[diagnostic(
-        severity(warning),
-        help("'case NaN' can never match. Use Number.isNaN before the switch.")
-    )]
+    #[diagnostic(help("'case NaN' can never match. Use Number.isNaN before the switch."))]
     CaseNaN(#[label] Span),
     #[error("eslint(use-isnan): Requires calls to isNaN() when checking for NaN")]
-    #[diagnostic(severity(warning), help("Array prototype method '{0}' cannot find NaN."))]
+    #[diagnostic(help("Array prototype method '{0}' cannot find NaN."))]
     IndexOfNaN(&'static str, #[label] Span),
 }
 
@@ -75,6 +69,16 @@ declare_oxc_lint!(
     /// ```
     UseIsnan,
     correctness,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": {
+                "enforceForSwitchCase": { "type": "boolean" },
+                "enforceForIndexOf": { "type": "boolean" }
+            },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for UseIsnan {
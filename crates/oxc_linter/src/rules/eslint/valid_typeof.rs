@@ -8,15 +8,19 @@ use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::UnaryOperator;
 use phf::{phf_set, Set};
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+use crate::{
+    ast_util::outermost_paren,
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 enum ValidTypeofDiagnostic {
     #[error("eslint(valid-typeof): Typeof comparisons should be to string literals.")]
-    #[diagnostic(severity(warning))]
     NotString(#[help] Option<&'static str>, #[label] Span),
     #[error("eslint(valid-typeof): Invalid typeof comparison value.")]
-    #[diagnostic(severity(warning))]
     InvalidValue(#[help] Option<&'static str>, #[label] Span),
 }
 
@@ -46,20 +50,27 @@ declare_oxc_lint!(
     /// ```
     ValidTypeof,
     correctness,
+    suggestion,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": { "requireStringLiterals": { "type": "boolean" } },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for ValidTypeof {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         // match on `typeof` unary expression for better performance
-        let _unary_expr = match node.kind() {
+        match node.kind() {
             AstKind::UnaryExpression(unary_expr)
-                if unary_expr.operator == UnaryOperator::Typeof =>
-            {
-                unary_expr
-            }
+                if unary_expr.operator == UnaryOperator::Typeof => {}
             _ => return,
         };
 
+        // `(typeof foo) === "string"`
+        let node = outermost_paren(node, ctx);
         let binary_expr = match ctx.nodes().parent_kind(node.id()) {
             Some(AstKind::BinaryExpression(binary_expr)) if binary_expr.operator.is_equality() => {
                 binary_expr
@@ -67,11 +78,12 @@ impl Rule for ValidTypeof {
             _ => return,
         };
 
-        let ((Expression::UnaryExpression(_), sibling) | (sibling, Expression::UnaryExpression(_))) =
-            (&binary_expr.left, &binary_expr.right)
-        else {
-            return;
+        let sibling = if binary_expr.left.span() == node.kind().span() {
+            &binary_expr.right
+        } else {
+            &binary_expr.left
         };
+        let sibling = sibling.without_parenthesized();
 
         if let Expression::StringLiteral(lit) = sibling {
             if !VALID_TYPES.contains(lit.value.as_str()) {
@@ -103,7 +115,7 @@ impl Rule for ValidTypeof {
                             sibling.span(),
                         )
                     },
-                    || Fix::new("\"undefined\"", sibling.span()),
+                    || Fix::new("\"undefined\"", sibling.span()).with_kind(FixKind::Suggestion),
                 );
                 return;
             }
@@ -172,6 +184,8 @@ fn test() {
         ("var oddUse = typeof foo + 'thing'", None),
         ("function f(undefined) { typeof x === undefined }", None),
         ("typeof foo === `str${somethingElse}`", None),
+        ("(typeof foo) === 'string'", None),
+        ("typeof foo === ('string')", None),
         ("typeof foo === 'number'", Some(serde_json::json!([{ "requireStringLiterals": true }]))),
         ("typeof foo === \"number\"", Some(serde_json::json!([{ "requireStringLiterals": true }]))),
         (
@@ -197,6 +211,8 @@ fn test() {
         ("'strnig' == typeof foo", None),
         ("if (typeof bar == 'umdefined') {}", None),
         ("if (typeof bar === `umdefined`) {}", None),
+        ("(typeof foo) === 'strnig'", None),
+        ("((typeof foo)) !== ('strnig')", None),
         (
             "typeof foo == 'invalid string'",
             Some(serde_json::json!([{ "requireStringLiterals": true }])),
@@ -0,0 +1,135 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+use oxc_syntax::module_record::{ExportExportName, ImportImportName, ModuleRecord};
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-import(default): No default export found in imported module {0:?}")]
+#[diagnostic(help("does {0:?} have the default export?"))]
+struct DefaultDiagnostic(Atom, #[label] pub Span);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/default.md>
+#[derive(Debug, Default, Clone)]
+pub struct Default;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// If a default import is requested, this rule will report if there is no default export in the imported module.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The default import is `undefined` at runtime.
+    /// Modules without any import or export statements, such as CommonJS modules, are ignored.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // ./bar.js
+    /// export function bar() { return null }
+    ///
+    /// // ./foo.js
+    /// import bar from './bar' // no default export found in ./bar
+    /// ```
+    Default,
+    correctness,
+    needs = dependency_exports
+);
+
+impl Rule for Default {
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let semantic = ctx.semantic();
+
+        // This rule is disabled in the typescript config.
+        if semantic.source_type().is_typescript() {
+            return;
+        }
+
+        let module_record = semantic.module_record();
+
+        for import_entry in &module_record.import_entries {
+            let ImportImportName::Default(default_span) = import_entry.import_name else {
+                continue;
+            };
+            let specifier = import_entry.module_request.name();
+            // Get remote module record
+            let Some(remote_module_record_ref) = module_record.loaded_modules.get(specifier) else {
+                continue;
+            };
+            let remote_module_record = remote_module_record_ref.value();
+            if !is_es_module(remote_module_record) || has_default_export(remote_module_record) {
+                continue;
+            }
+            ctx.diagnostic(DefaultDiagnostic(specifier.clone(), default_span));
+        }
+    }
+}
+
+/// Modules without import or export statements are not checked, they may be CommonJS.
+fn is_es_module(module_record: &ModuleRecord) -> bool {
+    module_record.has_module_syntax
+}
+
+fn has_default_export(module_record: &ModuleRecord) -> bool {
+    module_record.export_default.is_some()
+        || module_record.exported_bindings.contains_key("default")
+        || module_record
+            .local_export_entries
+            .iter()
+            .chain(&module_record.indirect_export_entries)
+            .any(|export_entry| match &export_entry.export_name {
+                ExportExportName::Default(_) => true,
+                ExportExportName::Name(name) => name.name().as_str() == "default",
+                ExportExportName::Null => false,
+            })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "import './malformed.js'",
+        "import foo from './empty-folder'",
+        "import { foo } from './default-export'",
+        "import foo from './default-export'",
+        "import foo from './mixed-exports'",
+        "import bar from './default-export'",
+        "import CoolClass from './default-class'",
+        "import bar, { baz } from './default-export'",
+        // core modules always have a default
+        "import crypto from 'crypto'",
+        "import common from './common'",
+        "import nested from './nested-common'",
+        "export { default as bar } from './bar'",
+        "export { default as bar, foo } from './bar'",
+        // sanity check
+        "export {a} from './named-exports'",
+        "import twofer from './trampoline'",
+        // jsx
+        "import MyCoolComponent from './jsx/MyCoolComponent.jsx'",
+        // #54: import of named export default
+        "import foo from './named-default-export'",
+        "import foo from './default-export-string'",
+        // #94: redux export of execution result
+        "import connectedApp from './redux'",
+        // #328: *only* default
+        "import foo from './default-export-from'",
+        "import foo from './default-export-from-named'",
+    ];
+
+    let fail = vec![
+        "import baz from './named-exports'",
+        "import barDefault from './re-export'",
+        "import baz, { bar } from './named-exports'",
+    ];
+
+    Tester::new_without_config(Default::NAME, pass, fail)
+        .change_rule_path("index.js")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
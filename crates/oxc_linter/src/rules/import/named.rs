@@ -10,7 +10,7 @@ use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-import(named): named import {0:?} not found")]
-#[diagnostic(severity(warning), help("does {1:?} have the export {0:?}?"))]
+#[diagnostic(help("does {1:?} have the export {0:?}?"))]
 struct NamedDiagnostic(Atom, Atom, #[label] pub Span);
 
 /// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/named.md>
@@ -26,7 +26,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     Named,
-    correctness
+    correctness,
+    needs = dependency_exports
 );
 
 impl Rule for Named {
@@ -51,6 +52,10 @@ impl Rule for Named {
                 continue;
             };
             let remote_module_record = remote_module_record_ref.value();
+            // The named exports of CommonJS modules depend on the interop of the bundler
+            if remote_module_record.is_commonjs() {
+                continue;
+            }
             // Check remote bindings
             if remote_module_record.exported_bindings.contains_key(import_name.name()) {
                 continue;
@@ -75,6 +80,10 @@ impl Rule for Named {
                 continue;
             };
             let remote_module_record = remote_module_record_ref.value();
+            // The named exports of CommonJS modules depend on the interop of the bundler
+            if remote_module_record.is_commonjs() {
+                continue;
+            }
             // Check remote bindings
             if remote_module_record.exported_bindings.contains_key(import_name.name()) {
                 continue;
@@ -110,8 +119,7 @@ fn test() {
         // "import { jsxFoo } from './jsx/AnotherComponent'",
         "import {a, b, d} from './common'; // eslint-disable-line named",
         "import { foo, bar } from './re-export-names'",
-        // TODO: module.exports
-        // "import { foo, bar } from './common'",
+        "import { foo, bar } from './common'",
         // ignore core modules by default
         "import { foo } from 'crypto'",
         // "import { zoob } from 'a'",
@@ -144,7 +152,7 @@ fn test() {
         "import { me, soGreat } from './narcissist'",
         "import { foo, bar, baz } from './re-export-default'",
         "import { common } from './re-export-default'",
-        // "import {a, b, d} from './common'",
+        "import {a, b, d} from './common'",
         // settings: { 'import/ignore': ['bar'] },
         // "import { baz } from './bar'",
         // "import { common } from './re-export-default'",
@@ -156,8 +164,8 @@ fn test() {
         // "const { baz } = require('./bar')",
         // "const { default: defExport } = require('./bar')",
         // "import { ExtfieldModel, Extfield2Model } from './models';",       filename: testFilePath('./export-star/downstream.js'),
-        // "const { something } = require('./dynamic-import-in-commonjs')",
-        // "import { something } from './dynamic-import-in-commonjs'",
+        "const { something } = require('./dynamic-import-in-commonjs')",
+        "import { something } from './dynamic-import-in-commonjs'",
         "import { 'foo' as foo } from './bar'",
         "import { 'foo' as foo } from './empty-module'",
         // export all
@@ -0,0 +1,262 @@
+use std::path::PathBuf;
+
+use oxc_ast::{
+    ast::{ImportDeclaration, ImportDeclarationSpecifier, ModuleDeclaration},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-import(no-duplicates): {0:?} imported multiple times")]
+#[diagnostic(help("Merge these imports into a single import statement"))]
+struct NoDuplicatesDiagnostic(Atom, #[label] pub Span);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-duplicates.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoDuplicates;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Reports if a resolved path is imported more than once in the same file.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Several import statements for the same module are harder to read than a single one.
+    /// Type-only imports are checked separately from value imports,
+    /// so `import type { A } from './a'` next to `import { b } from './a'` is allowed.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import SomeDefaultClass from './mod'
+    /// import { something } from './mod.js' // both resolve to ./mod.js
+    /// ```
+    NoDuplicates,
+    style,
+    fix,
+    needs = module_graph
+);
+
+/// Imports of the same module: by resolved path when the import plugin resolved it,
+/// otherwise by the specifier as written.
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum ModuleKey<'a> {
+    Resolved(PathBuf),
+    Specifier(&'a str),
+}
+
+impl Rule for NoDuplicates {
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.semantic().module_record();
+
+        let mut group_index: FxHashMap<(ModuleKey, bool), usize> = FxHashMap::default();
+        let mut groups: Vec<Vec<&ImportDeclaration>> = vec![];
+        for node in ctx.nodes().iter() {
+            let AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(decl)) =
+                node.kind()
+            else {
+                continue;
+            };
+            let specifier = decl.source.value.as_str();
+            let key = module_record.loaded_modules.get(specifier).map_or(
+                ModuleKey::Specifier(specifier),
+                |remote_module_record_ref| {
+                    ModuleKey::Resolved(remote_module_record_ref.resolved_absolute_path.clone())
+                },
+            );
+            let index =
+                *group_index.entry((key, decl.import_kind.is_type())).or_insert_with(|| {
+                    groups.push(vec![]);
+                    groups.len() - 1
+                });
+            groups[index].push(decl);
+        }
+
+        for group in groups.iter().filter(|group| group.len() > 1) {
+            let Some(merged) = merge_imports(group, ctx.source_text()) else {
+                for decl in group {
+                    ctx.diagnostic(NoDuplicatesDiagnostic(
+                        decl.source.value.clone(),
+                        decl.source.span,
+                    ));
+                }
+                continue;
+            };
+            let (first, rest) = group.split_first().unwrap();
+            ctx.diagnostic_with_fix(
+                NoDuplicatesDiagnostic(first.source.value.clone(), first.source.span),
+                || merge_fix(merged, first, rest, ctx.source_text()),
+            );
+            for decl in rest {
+                ctx.diagnostic(NoDuplicatesDiagnostic(decl.source.value.clone(), decl.source.span));
+            }
+        }
+    }
+}
+
+/// Replace `first` with `merged` and remove the statements in `rest`,
+/// keeping the code between them. A single fix so that the statements are merged all at once.
+fn merge_fix<'a>(
+    merged: String,
+    first: &ImportDeclaration,
+    rest: &[&ImportDeclaration],
+    source_text: &str,
+) -> Fix<'a> {
+    let mut content = merged;
+    let mut end = first.span.end;
+    for decl in rest {
+        content.push_str(&source_text[end as usize..decl.span.start as usize]);
+        end = span_with_line_break(decl.span, source_text).end;
+    }
+    Fix::new(content, Span::new(first.span.start, end))
+}
+
+/// Build a single import statement importing every specifier of `decls`,
+/// `None` when they cannot be expressed as one statement.
+fn merge_imports(decls: &[&ImportDeclaration], source_text: &str) -> Option<String> {
+    let mut default = None;
+    let mut named = vec![];
+    for decl in decls {
+        // Namespace imports cannot be combined with named imports,
+        // and import attributes may differ between the statements.
+        if decl.assertions.is_some() {
+            return None;
+        }
+        for specifier in &decl.specifiers {
+            match specifier {
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => return None,
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    if default.is_some_and(|name: &Atom| name != &specifier.local.name) {
+                        return None;
+                    }
+                    default = Some(&specifier.local.name);
+                }
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    let text = specifier.span.source_text(source_text);
+                    if !named.contains(&text) {
+                        named.push(text);
+                    }
+                }
+            }
+        }
+    }
+
+    let first = decls[0];
+    // A type-only import can specify a default import or named bindings, but not both.
+    if first.import_kind.is_type() && default.is_some() && !named.is_empty() {
+        return None;
+    }
+
+    let mut merged = String::from("import ");
+    if first.import_kind.is_type() {
+        merged.push_str("type ");
+    }
+    if let Some(default) = default {
+        merged.push_str(default);
+        if !named.is_empty() {
+            merged.push_str(", ");
+        }
+    }
+    if !named.is_empty() {
+        merged.push_str("{ ");
+        merged.push_str(&named.join(", "));
+        merged.push_str(" }");
+    }
+    if default.is_some() || !named.is_empty() {
+        merged.push_str(" from ");
+    }
+    merged.push_str(first.source.span.source_text(source_text));
+    if first.span.source_text(source_text).ends_with(';') {
+        merged.push(';');
+    }
+    Some(merged)
+}
+
+/// Extend `span` over the line break that follows it, so removing the statement does not leave an empty line.
+fn span_with_line_break(span: Span, source_text: &str) -> Span {
+    let len = match source_text[span.end as usize..].as_bytes() {
+        [b'\r', b'\n', ..] => 2,
+        [b'\n', ..] => 1,
+        _ => 0,
+    };
+    Span::new(span.start, span.end + len)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "import './malformed.js'",
+        "import { x } from './foo'; import { y } from './bar'",
+        // type-only imports are checked separately from value imports
+        "import type { x } from './foo'; import { y } from './foo'",
+        "import { x } from './foo'; import type { y } from './foo'",
+        "import type x from './foo'; import type { y } from './bar'",
+        // different modules
+        "import x from './named-exports'; import y from './default-export'",
+    ];
+
+    let fail = vec![
+        "import { x } from './foo'; import { y } from './foo'",
+        "import {x} from './foo'; import {y} from './foo'; import { z } from './foo'",
+        // resolved to the same module
+        "import { x } from './bar'; import { y } from './bar.js'",
+        "import x from './bar'; import * as y from './bar'",
+        "import type { x } from './foo'; import type { y } from './foo'",
+        "import x from './foo'; import y from './foo'",
+    ];
+
+    let fix = vec![
+        (
+            "import { x } from './foo'\nimport { y } from './foo'\n",
+            "import { x, y } from './foo'\n",
+            None,
+        ),
+        (
+            "import { x } from './foo';\nimport { y as z } from './foo';\nfoo();\n",
+            "import { x, y as z } from './foo';\nfoo();\n",
+            None,
+        ),
+        (
+            "import x from './bar';\nimport { y } from './bar.js';\n",
+            "import x, { y } from './bar';\n",
+            None,
+        ),
+        (
+            "import './foo'\nimport { x } from './foo'\nimport './foo'\n",
+            "import { x } from './foo'\n",
+            None,
+        ),
+        (
+            "import type { x } from './foo';\nimport type { y } from './foo';\nimport { z } from './foo';\n",
+            "import type { x, y } from './foo';\nimport { z } from './foo';\n",
+            None,
+        ),
+        // cannot be merged into a single statement
+        (
+            "import x from './foo'\nimport * as y from './foo'\n",
+            "import x from './foo'\nimport * as y from './foo'\n",
+            None,
+        ),
+        (
+            "import x from './foo'\nimport y from './foo'\n",
+            "import x from './foo'\nimport y from './foo'\n",
+            None,
+        ),
+    ];
+
+    Tester::new_without_config(NoDuplicates::NAME, pass, fail)
+        .change_rule_path("index.ts")
+        .with_import_plugin(true)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
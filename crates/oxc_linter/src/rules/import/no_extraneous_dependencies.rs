@@ -0,0 +1,265 @@
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_resolver::{PackageJson, BUILTINS};
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoExtraneousDependenciesDiagnostic {
+    #[error("eslint-plugin-import(no-extraneous-dependencies): '{0}' should be listed in the project's dependencies")]
+    #[diagnostic(help("Run `npm i -S {0}` to add it"))]
+    Missing(String, #[label] Span),
+
+    #[error("eslint-plugin-import(no-extraneous-dependencies): '{0}' should be listed in the project's dependencies, not devDependencies")]
+    DevDependency(String, #[label] Span),
+
+    #[error("eslint-plugin-import(no-extraneous-dependencies): '{0}' should be listed in the project's dependencies, not optionalDependencies")]
+    OptionalDependency(String, #[label] Span),
+
+    #[error("eslint-plugin-import(no-extraneous-dependencies): '{0}' should be listed in the project's dependencies, not peerDependencies")]
+    PeerDependency(String, #[label] Span),
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-extraneous-dependencies.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoExtraneousDependencies {
+    dev_dependencies: Allow,
+    optional_dependencies: Allow,
+    peer_dependencies: Allow,
+    bundled_dependencies: Allow,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbid the import of external modules that are not declared in the closest `package.json`'s
+    /// `dependencies`, `devDependencies`, `optionalDependencies`, `peerDependencies` or `bundledDependencies`.
+    ///
+    /// Relative imports, Node.js builtin modules and bare specifiers resolved outside of `node_modules`
+    /// are ignored, as are type-only imports.
+    ///
+    /// ### Options
+    ///
+    /// `devDependencies`, `optionalDependencies`, `peerDependencies` and `bundledDependencies`
+    /// are allowed by default. Set an option to `false` to report imports of these packages,
+    /// or to an array of globs to only allow them in matching files, e.g.
+    /// `{ "devDependencies": ["**/*.test.js", "**/*.spec.js"] }`.
+    /// Globs are matched against the path relative to the `package.json` and the absolute path.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // package.json: { "dependencies": { "react": "^18.0.0" } }
+    /// import _ from 'lodash'; // 'lodash' should be listed in the project's dependencies
+    /// ```
+    NoExtraneousDependencies,
+    suspicious,
+    needs = module_graph,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": {
+                "devDependencies": {
+                    "oneOf": [{ "type": "boolean" }, { "type": "array", "items": { "type": "string" } }]
+                },
+                "optionalDependencies": {
+                    "oneOf": [{ "type": "boolean" }, { "type": "array", "items": { "type": "string" } }]
+                },
+                "peerDependencies": {
+                    "oneOf": [{ "type": "boolean" }, { "type": "array", "items": { "type": "string" } }]
+                },
+                "bundledDependencies": {
+                    "oneOf": [{ "type": "boolean" }, { "type": "array", "items": { "type": "string" } }]
+                }
+            },
+            "additionalProperties": false
+        }
+    ]"#
+);
+
+/// Whether a type of dependency may be imported.
+#[derive(Debug, Clone)]
+enum Allow {
+    Bool(bool),
+    /// Only in files matching the globs
+    Globs(GlobSet),
+}
+
+impl Default for Allow {
+    fn default() -> Self {
+        Self::Bool(true)
+    }
+}
+
+impl Allow {
+    fn from(value: Option<&serde_json::Value>) -> Self {
+        match value {
+            Some(serde_json::Value::Bool(allow)) => Self::Bool(*allow),
+            Some(serde_json::Value::Array(globs)) => {
+                let mut builder = GlobSetBuilder::new();
+                for glob in globs.iter().filter_map(serde_json::Value::as_str) {
+                    if let Ok(glob) = Glob::new(glob) {
+                        builder.add(glob);
+                    }
+                }
+                builder.build().map_or_else(|_| Self::default(), Self::Globs)
+            }
+            _ => Self::default(),
+        }
+    }
+
+    fn allows(&self, ctx: &LintContext, package_json: &PackageJson) -> bool {
+        match self {
+            Self::Bool(allow) => *allow,
+            Self::Globs(globs) => {
+                let path = ctx.file_path();
+                globs.is_match(path)
+                    || path.strip_prefix(package_json.directory()).is_ok_and(|p| globs.is_match(p))
+            }
+        }
+    }
+}
+
+impl Rule for NoExtraneousDependencies {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let allow = |key| Allow::from(config.and_then(|config| config.get(key)));
+        Self {
+            dev_dependencies: allow("devDependencies"),
+            optional_dependencies: allow("optionalDependencies"),
+            peer_dependencies: allow("peerDependencies"),
+            bundled_dependencies: allow("bundledDependencies"),
+        }
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let Some(package_json) = ctx.package_json() else {
+            return;
+        };
+        let module_record = ctx.semantic().module_record();
+
+        for (request, spans) in &module_record.requested_modules {
+            let Some(name) = package_name(request.as_str()) else {
+                continue;
+            };
+            // Bare specifiers resolved outside of `node_modules` are aliases to local files.
+            if module_record.loaded_modules.get(request).is_some_and(|remote_module_record_ref| {
+                !remote_module_record_ref
+                    .resolved_absolute_path
+                    .components()
+                    .any(|c| c.as_os_str() == "node_modules")
+            }) {
+                continue;
+            }
+
+            let declared = |field: &str| declares(package_json, field, name);
+            if declared("dependencies")
+                || (declared("devDependencies") && self.dev_dependencies.allows(ctx, package_json))
+                || (declared("optionalDependencies")
+                    && self.optional_dependencies.allows(ctx, package_json))
+                || (declared("peerDependencies")
+                    && self.peer_dependencies.allows(ctx, package_json))
+                || ((declared("bundledDependencies") || declared("bundleDependencies"))
+                    && self.bundled_dependencies.allows(ctx, package_json))
+            {
+                continue;
+            }
+
+            for span in spans {
+                let name = name.to_string();
+                ctx.diagnostic(if declared("devDependencies") {
+                    NoExtraneousDependenciesDiagnostic::DevDependency(name, *span)
+                } else if declared("optionalDependencies") {
+                    NoExtraneousDependenciesDiagnostic::OptionalDependency(name, *span)
+                } else if declared("peerDependencies") {
+                    NoExtraneousDependenciesDiagnostic::PeerDependency(name, *span)
+                } else {
+                    NoExtraneousDependenciesDiagnostic::Missing(name, *span)
+                });
+            }
+        }
+    }
+}
+
+/// The package imported by `specifier`, e.g. `@scope/name` for `@scope/name/sub/path`.
+/// `None` for relative and absolute paths, Node.js builtin modules and URL-like specifiers.
+fn package_name(specifier: &str) -> Option<&str> {
+    if specifier.is_empty()
+        || specifier.starts_with(['.', '/'])
+        || specifier.contains(':')
+        || BUILTINS.contains(&specifier)
+    {
+        return None;
+    }
+    let end = if specifier.starts_with('@') {
+        specifier.match_indices('/').nth(1).map(|(i, _)| i)
+    } else {
+        specifier.find('/')
+    };
+    let name = end.map_or(specifier, |end| &specifier[..end]);
+    (!BUILTINS.contains(&name)).then_some(name)
+}
+
+/// Whether `field` of the package.json declares `name`, either as an object key or an array item.
+fn declares(package_json: &PackageJson, field: &str, name: &str) -> bool {
+    match package_json.raw_json().get(field) {
+        Some(serde_json::Value::Object(dependencies)) => dependencies.contains_key(name),
+        Some(serde_json::Value::Array(dependencies)) => {
+            dependencies.iter().any(|dependency| dependency.as_str() == Some(name))
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import 'lodash.cond'", None),
+        ("import jquery from 'jquery'", None),
+        ("import '@org/package/sub/path'", None),
+        ("import 'esm-package/esm-module'", None),
+        ("const rxjs = require('rxjs')", None),
+        ("import 'fs'", None),
+        ("import 'fs/promises'", None),
+        ("import 'node:fs'", None),
+        ("import './foo'", None),
+        ("import '../foo'", None),
+        ("import '/foo'", None),
+        // devDependencies, optionalDependencies, peerDependencies and bundledDependencies are allowed by default
+        ("import 'glob'", None),
+        ("import 'eslint'", None),
+        ("import 'lodash.isarray'", None),
+        ("import '@generated/foo'", None),
+        // allowed in matching files
+        ("import 'glob'", Some(json!([{ "devDependencies": ["index.js"] }]))),
+        ("import 'glob'", Some(json!([{ "devDependencies": ["**/*.js"] }]))),
+        // declared as both a devDependency and a peerDependency
+        ("import 'eslint'", Some(json!([{ "devDependencies": false }]))),
+    ];
+
+    let fail = vec![
+        ("import 'not-a-dependency'", None),
+        ("import '@org/not-a-dependency'", None),
+        ("import notADependency from 'not-a-dependency/sub/path'", None),
+        ("var foo = require('not-a-dependency')", None),
+        ("export { foo } from 'not-a-dependency'", None),
+        ("import 'glob'", Some(json!([{ "devDependencies": false }]))),
+        ("import 'glob'", Some(json!([{ "devDependencies": ["**/*.test.js"] }]))),
+        ("import 'lodash.isarray'", Some(json!([{ "optionalDependencies": false }]))),
+        ("import 'eslint'", Some(json!([{ "devDependencies": false, "peerDependencies": false }]))),
+        ("import '@generated/foo'", Some(json!([{ "bundledDependencies": false }]))),
+    ];
+
+    Tester::new(NoExtraneousDependencies::NAME, pass, fail)
+        .change_rule_path("index.js")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
@@ -9,7 +9,6 @@ use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-import(no-self-import): module importing itself is not allowed")]
-#[diagnostic(severity(warning))]
 struct NoSelfImportDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -28,7 +27,8 @@ declare_oxc_lint!(
     /// const foo = require('./foo')
     /// ```
     NoSelfImport,
-    correctness
+    correctness,
+    needs = module_graph
 );
 
 impl Rule for NoSelfImport {
@@ -0,0 +1,536 @@
+use std::cmp::Ordering;
+
+use oxc_ast::{
+    ast::{ImportDeclaration, ModuleDeclaration},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_resolver::BUILTINS;
+use oxc_span::{Atom, Span};
+use oxc_syntax::module_record::ModuleRecord;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+enum OrderDiagnostic {
+    #[error("eslint-plugin-import(order): `{0}` import should occur before import of `{1}`")]
+    OutOfOrder(Atom, Atom, #[label] Span),
+
+    #[error("eslint-plugin-import(order): There should be at least one empty line between import groups")]
+    MissingEmptyLine(#[label] Span),
+
+    #[error("eslint-plugin-import(order): There should be no empty line within import group")]
+    EmptyLineWithinGroup(#[label] Span),
+
+    #[error("eslint-plugin-import(order): There should be no empty line between import groups")]
+    EmptyLineBetweenGroups(#[label] Span),
+}
+
+impl OrderDiagnostic {
+    fn span(&self) -> Span {
+        match self {
+            Self::OutOfOrder(_, _, span)
+            | Self::MissingEmptyLine(span)
+            | Self::EmptyLineWithinGroup(span)
+            | Self::EmptyLineBetweenGroups(span) => *span,
+        }
+    }
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/order.md>
+#[derive(Debug, Clone)]
+pub struct Order {
+    /// Rank of each [ImportGroup], groups sharing a rank may be mixed.
+    ranks: [usize; ImportGroup::ALL.len()],
+    newlines_between: NewlinesBetween,
+    alphabetize: Alphabetize,
+    case_insensitive: bool,
+}
+
+impl Default for Order {
+    fn default() -> Self {
+        let groups = ["builtin", "external", "parent", "sibling", "index"]
+            .into_iter()
+            .map(|group| vec![group])
+            .collect::<Vec<_>>();
+        Self {
+            ranks: ImportGroup::ranks(&groups),
+            newlines_between: NewlinesBetween::default(),
+            alphabetize: Alphabetize::default(),
+            case_insensitive: false,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a convention in the order of import statements.
+    /// Imports are ordered by group, the default order is:
+    ///
+    /// 1. `builtin`: Node.js builtin modules such as `fs` and `node:path`
+    /// 2. `external`: packages resolved into `node_modules`, or bare specifiers that are not resolved
+    /// 3. `parent`: `../foo`
+    /// 4. `sibling`: `./foo`
+    /// 5. `index`: `.` and `./index`
+    ///
+    /// `internal` (bare specifiers resolved outside of `node_modules`) and `unknown` imports
+    /// go after these unless they are listed in `groups`.
+    ///
+    /// Only `import` declarations are checked, `require` calls are not. Unassigned imports such
+    /// as `import './polyfill'` are ignored, their side effects may depend on their position.
+    ///
+    /// ### Options
+    ///
+    /// * `groups`: the order of the groups, a nested array puts several groups at the same rank,
+    ///   e.g. `["builtin", "external", ["parent", "sibling", "index"]]`
+    /// * `newlines-between`: `"ignore"` (default), `"always"` for exactly one empty line between groups
+    ///   and none within a group, or `"never"` for no empty lines between imports
+    /// * `alphabetize`: `{ "order": "ignore" | "asc" | "desc", "caseInsensitive": boolean }`
+    ///   sorts the imports of a group by their specifier
+    ///
+    /// The fix rewrites the block of imports in order, it is only available when the imports
+    /// are separated by nothing but whitespace, so unassigned imports are never moved.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import _ from 'lodash';
+    /// import path from 'path'; // `path` import should occur before import of `lodash`
+    /// ```
+    Order,
+    style,
+    fix,
+    needs = module_graph,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": {
+                "groups": {
+                    "type": "array",
+                    "items": {
+                        "oneOf": [
+                            {
+                                "enum": [
+                                    "builtin", "external", "internal", "parent", "sibling",
+                                    "index", "unknown"
+                                ]
+                            },
+                            {
+                                "type": "array",
+                                "items": {
+                                    "enum": [
+                                        "builtin", "external", "internal", "parent", "sibling",
+                                        "index", "unknown"
+                                    ]
+                                },
+                                "uniqueItems": true
+                            }
+                        ]
+                    }
+                },
+                "newlines-between": { "enum": ["ignore", "always", "never"] },
+                "alphabetize": {
+                    "type": "object",
+                    "properties": {
+                        "order": { "enum": ["ignore", "asc", "desc"] },
+                        "caseInsensitive": { "type": "boolean" }
+                    },
+                    "additionalProperties": false
+                }
+            },
+            "additionalProperties": false
+        }
+    ]"#
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportGroup {
+    Builtin,
+    External,
+    Internal,
+    Parent,
+    Sibling,
+    Index,
+    Unknown,
+}
+
+impl ImportGroup {
+    const ALL: [Self; 7] = [
+        Self::Builtin,
+        Self::External,
+        Self::Internal,
+        Self::Parent,
+        Self::Sibling,
+        Self::Index,
+        Self::Unknown,
+    ];
+
+    fn from(input: &str) -> Option<Self> {
+        match input {
+            "builtin" => Some(Self::Builtin),
+            "external" => Some(Self::External),
+            "internal" => Some(Self::Internal),
+            "parent" => Some(Self::Parent),
+            "sibling" => Some(Self::Sibling),
+            "index" => Some(Self::Index),
+            "unknown" => Some(Self::Unknown),
+            _ => None,
+        }
+    }
+
+    /// Groups missing from `groups` share the rank after the last listed group.
+    fn ranks(groups: &[Vec<&str>]) -> [usize; Self::ALL.len()] {
+        let mut ranks = [groups.len(); Self::ALL.len()];
+        for (rank, names) in groups.iter().enumerate() {
+            for group in names.iter().filter_map(|name| Self::from(name)) {
+                ranks[group as usize] = rank;
+            }
+        }
+        ranks
+    }
+
+    /// Classify `specifier`, using the module it was resolved to by the import plugin when available.
+    fn classify(specifier: &str, module_record: &ModuleRecord) -> Self {
+        if specifier.starts_with("node:") || BUILTINS.contains(&specifier) {
+            return Self::Builtin;
+        }
+        if matches!(specifier, "." | "./" | "./index" | "./index.js") {
+            return Self::Index;
+        }
+        if specifier == ".." || specifier.starts_with("../") {
+            return Self::Parent;
+        }
+        if specifier.starts_with("./") {
+            return Self::Sibling;
+        }
+        if specifier.starts_with('/') || specifier.is_empty() {
+            return Self::Unknown;
+        }
+        match module_record.loaded_modules.get(specifier) {
+            Some(remote_module_record_ref)
+                if !remote_module_record_ref
+                    .resolved_absolute_path
+                    .components()
+                    .any(|c| c.as_os_str() == "node_modules") =>
+            {
+                Self::Internal
+            }
+            _ => Self::External,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum NewlinesBetween {
+    #[default]
+    Ignore,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Alphabetize {
+    #[default]
+    Ignore,
+    Asc,
+    Desc,
+}
+
+struct ImportItem<'a, 'b> {
+    decl: &'b ImportDeclaration<'a>,
+    rank: usize,
+}
+
+impl Rule for Order {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mut order = Self::default();
+        let Some(config) = value.get(0) else {
+            return order;
+        };
+        if let Some(groups) = config.get("groups").and_then(serde_json::Value::as_array) {
+            let groups = groups
+                .iter()
+                .map(|group| match group {
+                    serde_json::Value::Array(names) => {
+                        names.iter().filter_map(serde_json::Value::as_str).collect()
+                    }
+                    _ => group.as_str().into_iter().collect(),
+                })
+                .collect::<Vec<_>>();
+            order.ranks = ImportGroup::ranks(&groups);
+        }
+        order.newlines_between =
+            match config.get("newlines-between").and_then(serde_json::Value::as_str) {
+                Some("always") => NewlinesBetween::Always,
+                Some("never") => NewlinesBetween::Never,
+                _ => NewlinesBetween::Ignore,
+            };
+        if let Some(alphabetize) = config.get("alphabetize") {
+            order.alphabetize = match alphabetize.get("order").and_then(serde_json::Value::as_str) {
+                Some("asc") => Alphabetize::Asc,
+                Some("desc") => Alphabetize::Desc,
+                _ => Alphabetize::Ignore,
+            };
+            order.case_insensitive = alphabetize
+                .get("caseInsensitive")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default();
+        }
+        order
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.semantic().module_record();
+        let source_text = ctx.source_text();
+
+        let imports = ctx
+            .nodes()
+            .iter()
+            .filter(|node| matches!(ctx.nodes().parent_kind(node.id()), Some(AstKind::Program(_))))
+            .filter_map(|node| match node.kind() {
+                AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(decl))
+                    if !decl.specifiers.is_empty() =>
+                {
+                    let group = ImportGroup::classify(decl.source.value.as_str(), module_record);
+                    Some(ImportItem { decl, rank: self.ranks[group as usize] })
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if imports.len() < 2 {
+            return;
+        }
+
+        let mut diagnostics = vec![];
+        for (i, import) in imports.iter().enumerate() {
+            if let Some(previous) =
+                imports[..i].iter().find(|previous| self.compare(previous, import).is_gt())
+            {
+                diagnostics.push(OrderDiagnostic::OutOfOrder(
+                    import.decl.source.value.clone(),
+                    previous.decl.source.value.clone(),
+                    import.decl.span,
+                ));
+            }
+        }
+        if self.newlines_between != NewlinesBetween::Ignore {
+            for pair in imports.windows(2) {
+                let (previous, import) = (&pair[0], &pair[1]);
+                let between =
+                    &source_text[previous.decl.span.end as usize..import.decl.span.start as usize];
+                let has_empty_line = between.matches('\n').count() > 1;
+                let span = previous.decl.span;
+                match self.newlines_between {
+                    NewlinesBetween::Always if previous.rank != import.rank && !has_empty_line => {
+                        diagnostics.push(OrderDiagnostic::MissingEmptyLine(span));
+                    }
+                    NewlinesBetween::Always if previous.rank == import.rank && has_empty_line => {
+                        diagnostics.push(OrderDiagnostic::EmptyLineWithinGroup(span));
+                    }
+                    NewlinesBetween::Never if has_empty_line => {
+                        diagnostics.push(OrderDiagnostic::EmptyLineBetweenGroups(span));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        if diagnostics.is_empty() {
+            return;
+        }
+        diagnostics.sort_by_key(OrderDiagnostic::span);
+
+        // Attach the fix for the whole block of imports to the first diagnostic.
+        let mut diagnostics = diagnostics.into_iter();
+        let first = diagnostics.next().unwrap();
+        match self.fix(&imports, source_text) {
+            Some(fix) => ctx.diagnostic_with_fix(first, || fix),
+            None => ctx.diagnostic(first),
+        }
+        for diagnostic in diagnostics {
+            ctx.diagnostic(diagnostic);
+        }
+    }
+}
+
+impl Order {
+    fn compare(&self, a: &ImportItem, b: &ImportItem) -> Ordering {
+        a.rank.cmp(&b.rank).then_with(|| {
+            let (a, b) = (a.decl.source.value.as_str(), b.decl.source.value.as_str());
+            let ordering = if self.case_insensitive {
+                a.to_lowercase().cmp(&b.to_lowercase())
+            } else {
+                a.cmp(b)
+            };
+            match self.alphabetize {
+                Alphabetize::Ignore => Ordering::Equal,
+                Alphabetize::Asc => ordering,
+                Alphabetize::Desc => ordering.reverse(),
+            }
+        })
+    }
+
+    /// Rewrite the imports in order with the expected empty lines,
+    /// `None` when anything other than whitespace is between them.
+    fn fix<'a>(&self, imports: &[ImportItem], source_text: &'a str) -> Option<Fix<'a>> {
+        let between = |previous: &ImportItem, import: &ImportItem| {
+            &source_text[previous.decl.span.end as usize..import.decl.span.start as usize]
+        };
+        if imports.windows(2).any(|pair| !between(&pair[0], &pair[1]).trim().is_empty()) {
+            return None;
+        }
+
+        let line_break = if source_text.contains("\r\n") { "\r\n" } else { "\n" };
+        let mut sorted = imports.iter().collect::<Vec<_>>();
+        sorted.sort_by(|a, b| self.compare(a, b));
+        let mut content = String::new();
+        for (i, import) in sorted.iter().enumerate() {
+            if i > 0 {
+                content.push_str(line_break);
+                if self.newlines_between == NewlinesBetween::Always
+                    && sorted[i - 1].rank != import.rank
+                {
+                    content.push_str(line_break);
+                }
+            }
+            content.push_str(import.decl.span.source_text(source_text));
+        }
+
+        let span = Span::new(imports[0].decl.span.start, imports[imports.len() - 1].decl.span.end);
+        Some(Fix::new(content, span))
+    }
+}
+
+#[test]
+#[allow(clippy::too_many_lines)]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            r#"
+                import fs from 'fs';
+                import async, {foo1} from 'async';
+                import relParent1 from '../foo';
+                import relParent2, {foo2} from '../foo/bar';
+                import relParent3 from '../';
+                import sibling, {foo3} from './foo';
+                import index from './';
+            "#,
+            None,
+        ),
+        ("import path from 'node:path'; import _ from 'lodash';", None),
+        // resolved into node_modules
+        ("import fs from 'fs'; import jquery from 'jquery'; import bar from './bar';", None),
+        // groups at the same rank may be mixed
+        (
+            "import sibling from './foo'; import fs from 'fs'; import index from './';",
+            Some(json!([{ "groups": [["builtin", "sibling", "index"], "external"] }])),
+        ),
+        // omitted groups go last
+        ("import bar from './bar'; import fs from 'fs';", Some(json!([{ "groups": ["sibling"] }]))),
+        (
+            "import fs from 'fs';\n\nimport _ from 'lodash';\nimport a from 'a';\n\nimport bar from './bar';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\nimport _ from 'lodash';\nimport bar from './bar';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            "import a from 'a'; import B from 'B'; import c from 'c';",
+            Some(json!([{ "alphabetize": { "order": "asc", "caseInsensitive": true } }])),
+        ),
+        (
+            "import B from 'B'; import a from 'a'; import c from 'c';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            "import c from 'c'; import b from 'b'; import fs from 'fs';",
+            Some(json!([{ "alphabetize": { "order": "desc" }, "groups": ["external", "builtin"] }])),
+        ),
+        // unassigned imports are ignored
+        ("import './polyfill'; import fs from 'fs';", None),
+        ("import fs from 'fs'; import _ from 'lodash'; import 'fs';", None),
+        // only top-level imports are checked
+        ("import bar from './bar'; const fs = require('fs');", None),
+    ];
+
+    let fail = vec![
+        ("import _ from 'lodash'; import fs from 'fs';", None),
+        ("import _ from 'lodash'; import './polyfill'; import fs from 'fs';", None),
+        ("import bar from './bar'; import foo from '../foo';", None),
+        ("import index from './'; import bar from './bar'; import fs from 'node:fs';", None),
+        (
+            "import fs from 'fs';\nimport _ from 'lodash';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport path from 'path';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport _ from 'lodash';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            "import c from 'c'; import B from 'B';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            "import a from 'a'; import B from 'B'; import c from 'c';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "import _ from 'lodash';\nimport bar from './bar';\nimport fs from 'fs';\nfoo();",
+            "import fs from 'fs';\nimport _ from 'lodash';\nimport bar from './bar';\nfoo();",
+            None,
+        ),
+        (
+            "import bar from './bar';\nimport _ from 'lodash';\nimport fs from 'fs';",
+            "import fs from 'fs';\n\nimport _ from 'lodash';\n\nimport bar from './bar';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport _ from 'lodash';",
+            "import fs from 'fs';\nimport _ from 'lodash';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            "import c from 'c';\nimport a from 'a';\nimport b from 'b';",
+            "import a from 'a';\nimport b from 'b';\nimport c from 'c';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        // unassigned imports are not moved
+        (
+            "import './polyfill';\nimport _ from 'lodash';\nimport fs from 'fs';",
+            "import './polyfill';\nimport fs from 'fs';\nimport _ from 'lodash';",
+            None,
+        ),
+        (
+            "import _ from 'lodash';\nimport './polyfill';\nimport fs from 'fs';",
+            "import _ from 'lodash';\nimport './polyfill';\nimport fs from 'fs';",
+            None,
+        ),
+        // comments between the imports would be lost
+        (
+            "import _ from 'lodash';\n// fs\nimport fs from 'fs';",
+            "import _ from 'lodash';\n// fs\nimport fs from 'fs';",
+            None,
+        ),
+    ];
+
+    Tester::new(Order::NAME, pass, fail)
+        .change_rule_path("index.js")
+        .with_import_plugin(true)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
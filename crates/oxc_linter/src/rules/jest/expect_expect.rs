@@ -20,7 +20,7 @@ use crate::{
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(jest/expect-expect): Test has no assertions")]
-#[diagnostic(severity(warning), help("Add assertion(s) in this Test"))]
+#[diagnostic(help("Add assertion(s) in this Test"))]
 struct ExpectExpectDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Clone)]
@@ -56,7 +56,17 @@ declare_oxc_lint!(
     /// test('should assert something', () => {});
     /// ```
     ExpectExpect,
-    restriction
+    restriction,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": {
+                "assertFunctionNames": { "type": "array", "items": { "type": "string" }, "uniqueItems": true },
+                "additionalTestBlockFunctions": { "type": "array", "items": { "type": "string" }, "uniqueItems": true }
+            },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for ExpectExpect {
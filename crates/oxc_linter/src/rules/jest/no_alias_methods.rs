@@ -12,7 +12,7 @@ use crate::{
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(jest/no-alias-methods): Unexpected alias {0:?}")]
-#[diagnostic(severity(warning), help("Replace {0:?} with its canonical name of {1:?}"))]
+#[diagnostic(help("Replace {0:?} with its canonical name of {1:?}"))]
 struct NoAliasMethodsDiagnostic(pub &'static str, pub &'static str, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -43,7 +43,8 @@ declare_oxc_lint!(
     /// expect(a).toThrowError();
     /// ```
     NoAliasMethods,
-    restriction
+    restriction,
+    fix
 );
 
 impl Rule for NoAliasMethods {
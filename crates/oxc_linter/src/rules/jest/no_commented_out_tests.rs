@@ -10,7 +10,7 @@ use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(jest/no-commented-out-tests): Some tests seem to be commented")]
-#[diagnostic(severity(warning), help("Remove or uncomment this comment"))]
+#[diagnostic(help("Remove or uncomment this comment"))]
 struct NoCommentedOutTestsDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
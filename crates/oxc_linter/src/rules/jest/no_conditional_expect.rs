@@ -15,7 +15,7 @@ use crate::{
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(jest/no-conditional-expect): Unexpected conditional expect")]
-#[diagnostic(severity(warning), help("Avoid calling `expect` conditionally`"))]
+#[diagnostic(help("Avoid calling `expect` conditionally`"))]
 struct NoConditionalExpectDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -21,7 +21,7 @@ use crate::{
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(jest/no-done-callback): {0:?}")]
-#[diagnostic(severity(warning), help("{1:?}"))]
+#[diagnostic(help("{1:?}"))]
 struct NoDoneCallbackDiagnostic(&'static str, &'static str, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
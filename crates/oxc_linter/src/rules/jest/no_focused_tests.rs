@@ -8,7 +8,7 @@ use oxc_span::Span;
 
 use crate::{
     context::LintContext,
-    fixer::Fix,
+    fixer::{Fix, FixKind},
     jest_ast_util::{
         parse_general_jest_fn_call, JestFnKind, JestGeneralFnKind, MemberExpressionElement,
         ParsedGeneralJestFnCall,
@@ -19,7 +19,7 @@ use crate::{
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(jest/no-focused-tests): Unexpected focused test.")]
-#[diagnostic(severity(warning), help("Remove focus from test."))]
+#[diagnostic(help("Remove focus from test."))]
 struct NoFocusedTestsDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -54,7 +54,8 @@ declare_oxc_lint!(
     /// `();
     /// ```
     NoFocusedTests,
-    restriction
+    restriction,
+    suggestion
 );
 
 impl Rule for NoFocusedTests {
@@ -72,7 +73,7 @@ impl Rule for NoFocusedTests {
         if name.starts_with('f') {
             ctx.diagnostic_with_fix(NoFocusedTestsDiagnostic(call_expr.span), || {
                 let start = call_expr.span.start;
-                Fix::delete(Span { start, end: start + 1 })
+                Fix::delete(Span { start, end: start + 1 }).with_kind(FixKind::Suggestion)
             });
 
             return;
@@ -88,7 +89,7 @@ impl Rule for NoFocusedTests {
                 } else {
                     span.end + 1
                 };
-                Fix::delete(Span { start, end })
+                Fix::delete(Span { start, end }).with_kind(FixKind::Suggestion)
             });
         }
     }
@@ -13,7 +13,7 @@ use crate::{context::LintContext, jest_ast_util::parse_expect_jest_fn_call, rule
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(jest/no-interpolation-in-snapshots): Do not use string interpolation inside of snapshots")]
-#[diagnostic(severity(warning), help("Remove string interpolation from snapshots"))]
+#[diagnostic(help("Remove string interpolation from snapshots"))]
 struct NoInterpolationInSnapshotsDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
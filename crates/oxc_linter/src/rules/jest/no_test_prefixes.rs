@@ -19,7 +19,6 @@ use crate::{
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(jest/no-test-prefixes): Use {0:?} instead.")]
-#[diagnostic(severity(warning))]
 struct NoTestPrefixesDiagnostic(Atom, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -48,7 +47,8 @@ declare_oxc_lint!(
     /// xdescribe('foo'); // invalid
     /// ```
     NoTestPrefixes,
-    restriction
+    restriction,
+    fix
 );
 
 fn get_preferred_node_names(jest_fn_call: &ParsedGeneralJestFnCall) -> Atom {
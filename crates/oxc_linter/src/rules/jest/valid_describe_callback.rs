@@ -18,7 +18,7 @@ use crate::{
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(jest/valid-describe-callback): {0:?}")]
-#[diagnostic(severity(warning), help("{1:?}"))]
+#[diagnostic(help("{1:?}"))]
 struct ValidDescribeCallbackDiagnostic(&'static str, &'static str, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -0,0 +1,283 @@
+use oxc_ast::{
+    ast::{JSXAttribute, JSXAttributeValue, JSXChild, JSXElement, JSXExpression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    ast_util::{
+        get_element_type, get_string_prop_value, has_jsx_prop_lowercase, is_jsx_prop_value_nullish,
+    },
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum AltTextDiagnostic {
+    #[error("eslint-plugin-jsx-a11y(alt-text): Missing `alt` attribute.")]
+    #[diagnostic(
+        help("img elements must have an alt prop, either with meaningful text, or an empty string for decorative images.")
+    )]
+    MissingImgAlt(#[label] Span),
+
+    #[error("eslint-plugin-jsx-a11y(alt-text): Invalid `alt` value.")]
+    #[diagnostic(help("Use alt=\"\" for presentational images, or provide meaningful text."))]
+    InvalidImgAlt(#[label] Span),
+
+    #[error("eslint-plugin-jsx-a11y(alt-text): Missing value for `aria-label` attribute.")]
+    #[diagnostic(
+        help("The aria-label attribute must have a value. The alt attribute is preferred over aria-label for images.")
+    )]
+    MissingAriaLabelValue(#[label] Span),
+
+    #[error("eslint-plugin-jsx-a11y(alt-text): Missing value for `aria-labelledby` attribute.")]
+    #[diagnostic(
+        help("The aria-labelledby attribute must have a value. The alt attribute is preferred over aria-labelledby for images.")
+    )]
+    MissingAriaLabelledbyValue(#[label] Span),
+
+    #[error("eslint-plugin-jsx-a11y(alt-text): Prefer alt=\"\" over presentational role.")]
+    #[diagnostic(
+        help("The first rule of ARIA is to not use ARIA if the same result can be achieved via native HTML.")
+    )]
+    PreferAlt(#[label] Span),
+
+    #[error(
+        "eslint-plugin-jsx-a11y(alt-text): Missing alternative text for the `object` element."
+    )]
+    #[diagnostic(
+        help("Embedded <object> elements must have alternative text by providing inner text, aria-label or aria-labelledby props.")
+    )]
+    Object(#[label] Span),
+
+    #[error("eslint-plugin-jsx-a11y(alt-text): Missing alternative text for the `area` element.")]
+    #[diagnostic(
+        help("Each area of an image map must have a text alternative through the `alt`, `aria-label`, or `aria-labelledby` prop.")
+    )]
+    Area(#[label] Span),
+
+    #[error("eslint-plugin-jsx-a11y(alt-text): Missing alternative text for the image button.")]
+    #[diagnostic(
+        help("<input> elements with type=\"image\" must have a text alternative through the `alt`, `aria-label`, or `aria-labelledby` prop.")
+    )]
+    InputTypeImage(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AltText;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforces that all elements that require alternative text have meaningful information
+    /// to relay back to the end user: `img`, `area`, `input[type="image"]` and `object`.
+    ///
+    /// ### Why is this bad?
+    /// Alternative text is a primary way for screen reader users to understand the content
+    /// of images and other non-text elements. Without it, these elements are either skipped
+    /// or announced by their file name.
+    ///
+    /// ### Example
+    /// ```jsx
+    /// // Bad
+    /// <img src="foo" />
+    /// <img src="foo" role="presentation" />
+    ///
+    /// // Good
+    /// <img src="foo" alt="Foo eating a sandwich." />
+    /// <img src="foo" alt="" />
+    /// ```
+    AltText,
+    correctness
+);
+
+impl Rule for AltText {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXElement(element) = node.kind() else { return };
+        let Some(element_type) = get_element_type(&element.opening_element) else { return };
+        match element_type.as_str() {
+            "img" => img_rule(element, ctx),
+            "object" => object_rule(element, ctx),
+            "area" => area_rule(element, ctx),
+            "input" => input_type_image_rule(element, ctx),
+            _ => {}
+        }
+    }
+}
+
+fn img_rule<'a>(element: &JSXElement<'a>, ctx: &LintContext<'a>) {
+    let opening = &element.opening_element;
+    let span = opening.name.span();
+
+    if let Some(alt) = has_jsx_prop_lowercase(opening, "alt") {
+        // `<img alt />` and `<img alt={undefined} />`
+        if alt.value.is_none() || is_jsx_prop_value_nullish(alt) {
+            ctx.diagnostic(AltTextDiagnostic::InvalidImgAlt(alt.span));
+        }
+        return;
+    }
+
+    if has_jsx_prop_lowercase(opening, "role")
+        .and_then(get_string_prop_value)
+        .is_some_and(|role| matches!(role.to_lowercase().as_str(), "presentation" | "none"))
+    {
+        ctx.diagnostic(AltTextDiagnostic::PreferAlt(span));
+        return;
+    }
+
+    if let Some(aria_label) = has_jsx_prop_lowercase(opening, "aria-label") {
+        if !has_valid_value(aria_label) {
+            ctx.diagnostic(AltTextDiagnostic::MissingAriaLabelValue(aria_label.span));
+        }
+        return;
+    }
+
+    if let Some(aria_labelledby) = has_jsx_prop_lowercase(opening, "aria-labelledby") {
+        if !has_valid_value(aria_labelledby) {
+            ctx.diagnostic(AltTextDiagnostic::MissingAriaLabelledbyValue(aria_labelledby.span));
+        }
+        return;
+    }
+
+    ctx.diagnostic(AltTextDiagnostic::MissingImgAlt(span));
+}
+
+fn object_rule<'a>(element: &JSXElement<'a>, ctx: &LintContext<'a>) {
+    let opening = &element.opening_element;
+    let has_label = ["aria-label", "aria-labelledby", "title"]
+        .iter()
+        .any(|prop| has_jsx_prop_lowercase(opening, prop).is_some_and(has_valid_value));
+    if has_label || has_accessible_child(element) {
+        return;
+    }
+    ctx.diagnostic(AltTextDiagnostic::Object(opening.name.span()));
+}
+
+fn area_rule<'a>(element: &JSXElement<'a>, ctx: &LintContext<'a>) {
+    let opening = &element.opening_element;
+    if !has_alternative_text(element) {
+        ctx.diagnostic(AltTextDiagnostic::Area(opening.name.span()));
+    }
+}
+
+fn input_type_image_rule<'a>(element: &JSXElement<'a>, ctx: &LintContext<'a>) {
+    let opening = &element.opening_element;
+    let is_image = has_jsx_prop_lowercase(opening, "type")
+        .and_then(get_string_prop_value)
+        .is_some_and(|value| value == "image");
+    if is_image && !has_alternative_text(element) {
+        ctx.diagnostic(AltTextDiagnostic::InputTypeImage(opening.name.span()));
+    }
+}
+
+/// `alt`, `aria-label` or `aria-labelledby` with a non-empty value
+fn has_alternative_text(element: &JSXElement) -> bool {
+    ["alt", "aria-label", "aria-labelledby"].iter().any(|prop| {
+        has_jsx_prop_lowercase(&element.opening_element, prop).is_some_and(has_valid_value)
+    })
+}
+
+/// Whether the attribute has a value that is neither empty nor statically nullish
+fn has_valid_value(attr: &JSXAttribute) -> bool {
+    match &attr.value {
+        None => false,
+        Some(JSXAttributeValue::StringLiteral(lit)) => !lit.value.is_empty(),
+        Some(JSXAttributeValue::ExpressionContainer(_)) => {
+            !is_jsx_prop_value_nullish(attr) && get_string_prop_value(attr) != Some("")
+        }
+        Some(_) => true,
+    }
+}
+
+fn has_accessible_child(element: &JSXElement) -> bool {
+    element.children.iter().any(|child| match child {
+        JSXChild::Text(text) => !text.value.trim().is_empty(),
+        JSXChild::ExpressionContainer(container) => {
+            !matches!(container.expression, JSXExpression::EmptyExpression(_))
+        }
+        _ => true,
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        // img
+        ("<img alt=\"foo\" />;", None),
+        ("<img alt={\"foo\"} />;", None),
+        ("<img alt={alt} />;", None),
+        ("<img ALT=\"foo\" />;", None),
+        ("<img ALT={`This is the ${alt} text`} />;", None),
+        ("<img alt=\"\" />;", None),
+        ("<img alt={\"\"} />;", None),
+        ("<img alt=\"foo\" role=\"presentation\" />;", None),
+        ("<img aria-label=\"foo\" />;", None),
+        ("<img aria-labelledby=\"id1\" />;", None),
+        ("<img {...this.props} alt=\"foo\" />", None),
+        ("<a />", None),
+        ("<div />", None),
+        ("<Img />", None),
+        ("<UX.Image />", None),
+        // object
+        ("<object aria-label=\"foo\" />", None),
+        ("<object aria-labelledby=\"id1\" />", None),
+        ("<object>Foo</object>", None),
+        ("<object><p>This is descriptive!</p></object>", None),
+        ("<object title=\"An object\" />", None),
+        ("<object>{children}</object>", None),
+        // area
+        ("<area aria-label=\"foo\" />", None),
+        ("<area aria-labelledby=\"id1\" />", None),
+        ("<area alt=\"This is descriptive!\" />", None),
+        ("<area alt={altText} />", None),
+        // input type="image"
+        ("<input />", None),
+        ("<input type=\"foo\" />", None),
+        ("<input type=\"image\" aria-label=\"foo\" />", None),
+        ("<input type=\"image\" aria-labelledby=\"id1\" />", None),
+        ("<input type=\"image\" alt=\"This is descriptive!\" />", None),
+        ("<input type=\"image\" alt={altText} />", None),
+    ];
+
+    let fail = vec![
+        // img
+        ("<img />;", None),
+        ("<img alt />;", None),
+        ("<img alt={undefined} />;", None),
+        ("<img src=\"xyz\" />", None),
+        ("<img role />", None),
+        ("<img {...this.props} />", None),
+        ("<img role=\"presentation\" />;", None),
+        ("<img role=\"none\" />;", None),
+        ("<img aria-label=\"\" />;", None),
+        ("<img aria-label={undefined} />;", None),
+        ("<img aria-labelledby=\"\" />;", None),
+        ("<img aria-labelledby={undefined} />;", None),
+        // object
+        ("<object />", None),
+        ("<object></object>", None),
+        ("<object title={undefined} />", None),
+        ("<object aria-label=\"\" />", None),
+        // area
+        ("<area />", None),
+        ("<area alt />", None),
+        ("<area alt={undefined} />", None),
+        ("<area src=\"xyz\" />", None),
+        ("<area aria-label=\"\" />", None),
+        // input type="image"
+        ("<input type=\"image\" />", None),
+        ("<input type=\"image\" alt />", None),
+        ("<input type=\"image\" alt={undefined} />", None),
+        ("<input type=\"image\">Foo</input>", None),
+        ("<input type=\"image\" aria-labelledby=\"\" />", None),
+    ];
+
+    Tester::new(AltText::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,169 @@
+use oxc_ast::{
+    ast::{JSXAttribute, JSXAttributeValue, JSXOpeningElement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    ast_util::{
+        get_element_type, get_string_prop_value, has_jsx_prop, has_jsx_spread_attribute,
+        is_jsx_prop_value_nullish,
+    },
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum AnchorIsValidDiagnostic {
+    #[error(
+        "eslint-plugin-jsx-a11y(anchor-is-valid): Missing `href` attribute for the `a` element."
+    )]
+    #[diagnostic(
+        help("The href attribute is required for an anchor to be keyboard accessible. Provide a valid, navigable address as the href value, or use a button styled as a link.")
+    )]
+    MissingHref(#[label] Span),
+
+    #[error("eslint-plugin-jsx-a11y(anchor-is-valid): Use of an invalid `href` value for the `a` element.")]
+    #[diagnostic(
+        help("The href attribute requires a valid, navigable address to be accessible. Values such as `#` or `javascript:void(0)` are not navigable.")
+    )]
+    InvalidHref(#[label] Span),
+
+    #[error("eslint-plugin-jsx-a11y(anchor-is-valid): Anchor used as a button.")]
+    #[diagnostic(help(
+        "Anchors are primarily expected to navigate. Use the `button` element instead."
+    ))]
+    PreferButton(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AnchorIsValid;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforces that all anchors are valid, navigable elements.
+    ///
+    /// ### Why is this bad?
+    /// Anchors without a valid `href` are not keyboard accessible, cannot be opened in a new tab
+    /// and are announced incorrectly by screen readers. Anchors with click handlers but no
+    /// navigation target should be buttons instead.
+    ///
+    /// ### Example
+    /// ```jsx
+    /// // Bad
+    /// <a>Perform action</a>
+    /// <a href="#">Perform action</a>
+    /// <a href="javascript:void(0)">Perform action</a>
+    /// <a onClick={foo}>Perform action</a>
+    ///
+    /// // Good
+    /// <a href="https://github.com">Navigate</a>
+    /// <button onClick={foo}>Perform action</button>
+    /// ```
+    AnchorIsValid,
+    correctness
+);
+
+impl Rule for AnchorIsValid {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(element) = node.kind() else { return };
+        if get_element_type(element).as_deref() != Some("a") {
+            return;
+        }
+        // The spread may provide a valid `href`, bail out to avoid false positives
+        if has_jsx_spread_attribute(element) {
+            return;
+        }
+
+        let span = element.name.span();
+        let has_on_click = has_jsx_prop(element, "onClick").is_some();
+        match get_href(element) {
+            Some(href) if is_invalid_href(href) => {
+                if has_on_click {
+                    ctx.diagnostic(AnchorIsValidDiagnostic::PreferButton(span));
+                } else {
+                    ctx.diagnostic(AnchorIsValidDiagnostic::InvalidHref(href.span));
+                }
+            }
+            Some(_) => {}
+            None if has_on_click => ctx.diagnostic(AnchorIsValidDiagnostic::PreferButton(span)),
+            None => ctx.diagnostic(AnchorIsValidDiagnostic::MissingHref(span)),
+        }
+    }
+}
+
+/// `href={undefined}` and `href={null}` are treated as if `href` was not provided at all
+fn get_href<'a, 'b>(element: &'b JSXOpeningElement<'a>) -> Option<&'b JSXAttribute<'a>> {
+    has_jsx_prop(element, "href").filter(|href| !is_jsx_prop_value_nullish(href))
+}
+
+fn is_invalid_href(href: &JSXAttribute) -> bool {
+    match &href.value {
+        // `<a href />`
+        None => true,
+        Some(JSXAttributeValue::StringLiteral(_) | JSXAttributeValue::ExpressionContainer(_)) => {
+            get_string_prop_value(href).is_some_and(|value| {
+                value.is_empty()
+                    || value == "#"
+                    || value
+                        .trim_start_matches(|c: char| !c.is_alphanumeric())
+                        .to_lowercase()
+                        .starts_with("javascript:")
+            })
+        }
+        Some(_) => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("<Anchor />", None),
+        ("<a {...props} />", None),
+        ("<a href=\"foo\" />", None),
+        ("<a href={foo} />", None),
+        ("<a href=\"/foo\" />", None),
+        ("<a href=\"https://foo.bar.com\" />", None),
+        ("<div href=\"foo\" />", None),
+        ("<a href=\"javascript\" />", None),
+        ("<a href=\"javascriptFoo\" />", None),
+        ("<a href={`#foo`}/>", None),
+        ("<a href={\"foo\"}/>", None),
+        ("<a href=\"#foo\" />", None),
+        ("<a href={`${foo}`}/>", None),
+        ("<a href={\"javascript\"}/>", None),
+        ("<a href={`#javascript`}/>", None),
+        ("<a href=\"#javascript\" />", None),
+        ("<a href={this} />", None),
+        ("<a href=\"foo\" onClick={foo} />", None),
+        ("<a href={foo} onClick={foo} />", None),
+        ("<UX.Layout>test</UX.Layout>", None),
+    ];
+
+    let fail = vec![
+        ("<a />", None),
+        ("<a href={undefined} />", None),
+        ("<a href={null} />", None),
+        ("<a href />", None),
+        ("<a href=\"\" />", None),
+        ("<a href=\"#\" />", None),
+        ("<a href={\"#\"} />", None),
+        ("<a href=\"javascript:void(0)\" />", None),
+        ("<a href={\"javascript:void(0)\"} />", None),
+        ("<a href={`javascript:void(0)`} />", None),
+        ("<a onClick={foo} />", None),
+        ("<a href=\"#\" onClick={foo} />", None),
+        ("<a href=\"javascript:void(0)\" onClick={foo} />", None),
+        ("<a href={undefined} onClick={foo} />", None),
+    ];
+
+    Tester::new(AnchorIsValid::NAME, pass, fail).test_and_snapshot();
+}
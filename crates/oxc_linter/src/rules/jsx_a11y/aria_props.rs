@@ -0,0 +1,118 @@
+use oxc_ast::{ast::JSXAttributeItem, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{aria::ARIA_PROPERTIES, context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum AriaPropsDiagnostic {
+    #[error("eslint-plugin-jsx-a11y(aria-props): `{0}` is not a valid ARIA attribute.")]
+    #[diagnostic(help("Did you mean `{1}`?"))]
+    WithSuggestion(String, &'static str, #[label] Span),
+
+    #[error("eslint-plugin-jsx-a11y(aria-props): `{0}` is not a valid ARIA attribute.")]
+    #[diagnostic(
+        help("You can find a list of valid ARIA attributes at https://www.w3.org/TR/wai-aria-1.2/#state_prop_def")
+    )]
+    WithoutSuggestion(String, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AriaProps;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforces that elements do not use invalid ARIA attributes.
+    ///
+    /// ### Why is this bad?
+    /// Using invalid ARIA attributes can mislead screen readers and other assistive technologies.
+    /// It may cause the accessibility features of the website to fail, making it difficult
+    /// for users with disabilities to use the site effectively.
+    ///
+    /// ### Example
+    /// ```jsx
+    /// // Bad
+    /// <input aria-labeledby="address_label" />
+    ///
+    /// // Good
+    /// <input aria-labelledby="address_label" />
+    /// ```
+    AriaProps,
+    correctness
+);
+
+impl Rule for AriaProps {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(attr)) = node.kind() else {
+            return;
+        };
+        let name = attr.name.get_name();
+        let normalized_name = name.to_lowercase();
+        if !normalized_name.starts_with("aria-") || ARIA_PROPERTIES.contains_key(&normalized_name) {
+            return;
+        }
+        let span = attr.name.span();
+        match get_suggestion(&normalized_name) {
+            Some(suggestion) => {
+                ctx.diagnostic(AriaPropsDiagnostic::WithSuggestion(name, suggestion, span));
+            }
+            None => ctx.diagnostic(AriaPropsDiagnostic::WithoutSuggestion(name, span)),
+        }
+    }
+}
+
+/// Find the closest valid ARIA property within an edit distance of 2
+fn get_suggestion(name: &str) -> Option<&'static str> {
+    ARIA_PROPERTIES
+        .keys()
+        .map(|prop| (*prop, edit_distance(name, prop)))
+        .filter(|(_, distance)| *distance <= 2)
+        .min_by_key(|(prop, distance)| (*distance, *prop))
+        .map(|(prop, _)| prop)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("<div />", None),
+        ("<div></div>", None),
+        ("<div aria=\"wee\"></div>", None),
+        ("<div abcARIAdef=\"true\"></div>", None),
+        ("<div fooaria-foobar=\"true\"></div>", None),
+        ("<div fooaria-hidden=\"true\"></div>", None),
+        ("<Bar baz />", None),
+        ("<input type=\"text\" aria-errormessage=\"foobar\" />", None),
+        ("<div aria-label=\"foo\" aria-labelledby=\"bar\" aria-hidden />", None),
+        ("<div aria-Label=\"foo\" />", None),
+        ("<div {...props} aria-describedby=\"baz\" />", None),
+    ];
+
+    let fail = vec![
+        ("<div aria-=\"foobar\" />", None),
+        ("<div aria-labeledby=\"foobar\" />", None),
+        ("<div aria-skldjfaria-klajsd=\"foobar\" />", None),
+        ("<input aria-invalidd />", None),
+    ];
+
+    Tester::new(AriaProps::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,226 @@
+use oxc_ast::{
+    ast::{Expression, JSXAttributeItem, JSXElement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{ast_util::outermost_paren, context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum JsxKeyDiagnostic {
+    #[error(r#"eslint-plugin-react(jsx-key): Missing "key" prop for element in array."#)]
+    ElementInArray(#[label] Span),
+
+    #[error(r#"eslint-plugin-react(jsx-key): Missing "key" prop for element in iterator."#)]
+    ElementInIterator(#[label] Span),
+
+    #[error(r#"eslint-plugin-react(jsx-key): Missing "key" prop for element in array."#)]
+    #[diagnostic(help(
+        "Shorthand fragment syntax does not support providing keys. Use `React.Fragment` instead."
+    ))]
+    FragmentInArray(#[label] Span),
+
+    #[error(r#"eslint-plugin-react(jsx-key): Missing "key" prop for element in iterator."#)]
+    #[diagnostic(help(
+        "Shorthand fragment syntax does not support providing keys. Use `React.Fragment` instead."
+    ))]
+    FragmentInIterator(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct JsxKey;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce `key` prop for elements in array and iterator callbacks.
+    ///
+    /// ### Why is this bad?
+    /// React uses keys to identify which items in a list have changed, been added, or been
+    /// removed. Without a stable key, React falls back to the element index which leads to
+    /// subtle bugs and unnecessary re-renders.
+    ///
+    /// ### Example
+    /// ```jsx
+    /// // Bad
+    /// [<Hello />, <Hello />, <Hello />];
+    /// data.map(x => <Hello>{x}</Hello>);
+    ///
+    /// // Good
+    /// [<Hello key="first" />, <Hello key="second" />, <Hello key="third" />];
+    /// data.map(x => <Hello key={x.id}>{x}</Hello>);
+    /// ```
+    JsxKey,
+    correctness
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InsideArrayOrIterator {
+    Array,
+    Iterator,
+}
+
+impl Rule for JsxKey {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::JSXElement(element) => {
+                if has_key_prop(element) {
+                    return;
+                }
+                let span = element.opening_element.name.span();
+                match is_in_array_or_iterator(node, ctx) {
+                    Some(InsideArrayOrIterator::Array) => {
+                        ctx.diagnostic(JsxKeyDiagnostic::ElementInArray(span));
+                    }
+                    Some(InsideArrayOrIterator::Iterator) => {
+                        ctx.diagnostic(JsxKeyDiagnostic::ElementInIterator(span));
+                    }
+                    None => {}
+                }
+            }
+            AstKind::JSXFragment(fragment) => {
+                let span = fragment.opening_fragment.span;
+                match is_in_array_or_iterator(node, ctx) {
+                    Some(InsideArrayOrIterator::Array) => {
+                        ctx.diagnostic(JsxKeyDiagnostic::FragmentInArray(span));
+                    }
+                    Some(InsideArrayOrIterator::Iterator) => {
+                        ctx.diagnostic(JsxKeyDiagnostic::FragmentInIterator(span));
+                    }
+                    None => {}
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn has_key_prop(element: &JSXElement) -> bool {
+    element.opening_element.attributes.iter().any(|attr| match attr {
+        JSXAttributeItem::Attribute(attr) => attr.is_identifier("key"),
+        JSXAttributeItem::SpreadAttribute(_) => false,
+    })
+}
+
+/// Skip over wrappers which still hand the JSX value through to the parent,
+/// e.g. `(<div />)`, `cond ? <div /> : null` and `cond && <div />`.
+fn outermost_jsx_value<'a, 'b>(node: &'b AstNode<'a>, ctx: &'b LintContext<'a>) -> &'b AstNode<'a> {
+    let mut node = node;
+    while let Some(parent) = ctx.nodes().parent_node(node.id()) {
+        match parent.kind() {
+            AstKind::ParenthesizedExpression(_) => {}
+            AstKind::ConditionalExpression(expr) if expr.test.span() != node.kind().span() => {}
+            AstKind::LogicalExpression(expr) if expr.right.span() == node.kind().span() => {}
+            _ => break,
+        }
+        node = parent;
+    }
+    node
+}
+
+fn is_in_array_or_iterator<'a>(
+    node: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<InsideArrayOrIterator> {
+    let node = outermost_jsx_value(node, ctx);
+    let parent = ctx.nodes().parent_node(node.id())?;
+
+    match parent.kind() {
+        AstKind::ExpressionArrayElement(_) => Some(InsideArrayOrIterator::Array),
+        // `x => <div />`
+        AstKind::ExpressionStatement(_) => {
+            let body = ctx.nodes().parent_node(parent.id())?;
+            let arrow = ctx.nodes().parent_node(body.id())?;
+            match arrow.kind() {
+                AstKind::ArrowExpression(expr) if expr.expression => {
+                    is_iterator_callback(arrow, ctx).then_some(InsideArrayOrIterator::Iterator)
+                }
+                _ => None,
+            }
+        }
+        // `function (x) { return <div /> }`
+        AstKind::ReturnStatement(_) => {
+            let function = ctx.nodes().iter_parents(parent.id()).find(|node| {
+                matches!(node.kind(), AstKind::Function(_) | AstKind::ArrowExpression(_))
+            })?;
+            is_iterator_callback(function, ctx).then_some(InsideArrayOrIterator::Iterator)
+        }
+        _ => None,
+    }
+}
+
+/// Whether the function node is the callback of `arr.map(fn)` or `Array.from(iterable, fn)`
+fn is_iterator_callback<'a>(function: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let function = outermost_paren(function, ctx);
+    let Some(argument) = ctx.nodes().parent_node(function.id()) else { return false };
+    if !matches!(argument.kind(), AstKind::Argument(_)) {
+        return false;
+    }
+    let Some(call) = ctx.nodes().parent_node(argument.id()) else { return false };
+    let AstKind::CallExpression(call) = call.kind() else { return false };
+    let Expression::MemberExpression(callee) = call.callee.without_parenthesized() else {
+        return false;
+    };
+    let function_span = function.kind().span();
+    if callee.is_specific_member_access("Array", "from") {
+        return call.arguments.get(1).is_some_and(|arg| arg.span() == function_span);
+    }
+    callee.static_property_name() == Some("map")
+        && call.arguments.first().is_some_and(|arg| arg.span() == function_span)
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("fn()", None),
+        ("[1, 2, 3].map(function () {})", None),
+        ("<App />;", None),
+        ("[<App key={0} />, <App key={1} />];", None),
+        ("[1, 2, 3].map(function(x) { return <App key={x} /> });", None),
+        ("[1, 2, 3].map(x => <App key={x} />);", None),
+        ("[1, 2 ,3].map(x => x && <App x={x} key={x} />);", None),
+        ("[1, 2 ,3].map(x => x ? <App x={x} key=\"1\" /> : <OtherApp x={x} key=\"2\" />);", None),
+        ("[1, 2, 3].map(x => { return <App key={x} /> });", None),
+        ("Array.from([1, 2, 3], function(x) { return <App key={x} /> });", None),
+        ("Array.from([1, 2, 3], (x => <App key={x} />));", None),
+        ("Array.from([1, 2, 3], (x => {return <App key={x} />}));", None),
+        ("Array.from([1, 2, 3], someFn);", None),
+        ("Array.from([1, 2, 3]);", None),
+        ("[1, 2, 3].foo(x => <App />);", None),
+        ("var App = () => <div />;", None),
+        ("[1, 2, 3].map(function(x) { return; });", None),
+        ("foo(() => <div />);", None),
+        ("foo(() => <></>);", None),
+        ("<></>;", None),
+        ("<App {...{}} />;", None),
+        ("const spans = [<span key=\"notunique\"/>, <span key=\"notunique\"/>];", None),
+        ("[1, 2, 3].map(x => { const y = <div />; return <App key={x}>{y}</App> });", None),
+    ];
+
+    let fail = vec![
+        ("[<App />];", None),
+        ("[<App {...key} />];", None),
+        ("[<App key={0}/>, <App />];", None),
+        ("[1, 2 ,3].map(function(x) { return <App /> });", None),
+        ("[1, 2 ,3].map(x => <App />);", None),
+        ("[1, 2 ,3].map(x => x && <App x={x} />);", None),
+        ("[1, 2 ,3].map(x => x ? <App x={x} key=\"2\" /> : <OtherApp x={x} />);", None),
+        ("[1, 2 ,3].map(x => x ? <App x={x} /> : <OtherApp x={x} key=\"2\" />);", None),
+        ("[1, 2 ,3].map(x => { return <App /> });", None),
+        ("Array.from([1, 2 ,3], function(x) { return <App /> });", None),
+        ("Array.from([1, 2 ,3], (x => { return <App /> }));", None),
+        ("Array.from([1, 2 ,3], (x => <App />));", None),
+        ("[1, 2, 3]?.map(x => <BabelEslintApp />)", None),
+        ("[1, 2, 3]?.map(x => <TypescriptEslintApp />)", None),
+        ("[1, 2, 3].map(x => <>{x}</>);", None),
+        ("[<></>];", None),
+    ];
+
+    Tester::new(JsxKey::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,117 @@
+use oxc_ast::{ast::JSXAttributeItem, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-react(jsx-no-duplicate-props): No duplicate props allowed. The prop \"{0}\" is duplicated.")]
+#[diagnostic(help("Remove one of the props, or rename them so each prop is distinct."))]
+struct JsxNoDuplicatePropsDiagnostic(String, #[label] pub Span, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct JsxNoDuplicateProps {
+    /// Compare prop names case-insensitively, e.g. `<div onClick onclick />` is reported.
+    ///
+    /// `false` by default.
+    ignore_case: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow duplicate properties in JSX elements.
+    ///
+    /// ### Why is this bad?
+    /// Creating JSX elements with duplicate props can cause unexpected behavior in your
+    /// application, since only the last one wins.
+    ///
+    /// ### Example
+    /// ```jsx
+    /// // Bad
+    /// <App a a />;
+    /// <App foo={2} bar baz foo={3} />;
+    ///
+    /// // Good
+    /// <App a />;
+    /// <App bar baz foo={3} />;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `ignoreCase`
+    /// Ignore case when comparing prop names. `false` by default.
+    JsxNoDuplicateProps,
+    correctness,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": { "ignoreCase": { "type": "boolean" } },
+            "additionalProperties": false
+        }
+    ]"#
+);
+
+impl Rule for JsxNoDuplicateProps {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let ignore_case = value
+            .get(0)
+            .and_then(|config| config.get("ignoreCase"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        Self { ignore_case }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(element) = node.kind() else { return };
+
+        let mut props: FxHashMap<String, Span> = FxHashMap::default();
+        for attr in &element.attributes {
+            let JSXAttributeItem::Attribute(attr) = attr else { continue };
+            let name = attr.name.get_name();
+            let key = if self.ignore_case { name.to_lowercase() } else { name.clone() };
+            let span = attr.name.span();
+            if let Some(old_span) = props.insert(key, span) {
+                ctx.diagnostic(JsxNoDuplicatePropsDiagnostic(name, old_span, span));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("<App />;", None),
+        ("<App {...this.props} />;", None),
+        ("<App a b c />;", None),
+        ("<App a b c A />;", None),
+        ("<App {...this.props} a b c />;", None),
+        ("<App c {...this.props} a b />;", None),
+        ("<App a=\"c\" b=\"b\" c=\"a\" />;", None),
+        ("<App {...this.props} a=\"c\" b=\"b\" c=\"a\" />;", None),
+        ("<App c=\"a\" {...this.props} a=\"c\" b=\"b\" />;", None),
+        ("<App A a />;", None),
+        ("<App A b a />;", None),
+        ("<App A=\"a\" b=\"b\" B=\"B\" />;", None),
+        ("<App a:b=\"c\" />;", None),
+        ("<App a:b=\"c\" a:c=\"d\" />;", None),
+    ];
+
+    let fail = vec![
+        ("<App a a />;", None),
+        ("<App A b c A />;", None),
+        ("<App a=\"a\" b=\"b\" a=\"a\" />;", None),
+        ("<App A a />;", Some(serde_json::json!([{ "ignoreCase": true }]))),
+        ("<App a b c A />;", Some(serde_json::json!([{ "ignoreCase": true }]))),
+        ("<App A=\"a\" b=\"b\" B=\"B\" />;", Some(serde_json::json!([{ "ignoreCase": true }]))),
+        ("<App a:b=\"c\" a:b=\"d\" />;", None),
+    ];
+
+    Tester::new(JsxNoDuplicateProps::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,135 @@
+use oxc_ast::{
+    ast::{Argument, Expression, JSXAttributeItem, ObjectPropertyKind},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoChildrenPropDiagnostic {
+    #[error("eslint-plugin-react(no-children-prop): Avoid passing children using a prop.")]
+    #[diagnostic(help("Nest children between the opening and closing tags instead."))]
+    Jsx(#[label] Span),
+
+    #[error("eslint-plugin-react(no-children-prop): Avoid passing children using a prop.")]
+    #[diagnostic(help("Pass children as additional arguments to `React.createElement` instead."))]
+    CreateElement(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoChildrenProp;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow passing of children as props.
+    ///
+    /// ### Why is this bad?
+    /// Children should always be actual children, not passed in as a prop.
+    /// When using JSX, the children should be nested between the opening and closing tags.
+    /// When not using JSX, the children should be passed as additional arguments to `React.createElement`.
+    ///
+    /// ### Example
+    /// ```jsx
+    /// // Bad
+    /// <div children='Children' />
+    /// <MyComponent children={<AnotherComponent />} />
+    /// React.createElement("div", { children: 'Children' })
+    ///
+    /// // Good
+    /// <div>Children</div>
+    /// <MyComponent><AnotherComponent /></MyComponent>
+    /// React.createElement("div", {}, 'Children')
+    /// ```
+    NoChildrenProp,
+    correctness
+);
+
+impl Rule for NoChildrenProp {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(attr)) => {
+                if attr.is_identifier("children") {
+                    ctx.diagnostic(NoChildrenPropDiagnostic::Jsx(attr.span));
+                }
+            }
+            AstKind::CallExpression(call) => {
+                if !is_create_element_call(&call.callee) {
+                    return;
+                }
+                let Some(Argument::Expression(Expression::ObjectExpression(props))) =
+                    call.arguments.get(1)
+                else {
+                    return;
+                };
+                for prop in &props.properties {
+                    let ObjectPropertyKind::ObjectProperty(prop) = prop else { continue };
+                    if !prop.computed && prop.key.is_specific_static_name("children") {
+                        ctx.diagnostic(NoChildrenPropDiagnostic::CreateElement(prop.span));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// `React.createElement(...)` or a bare `createElement(...)`
+fn is_create_element_call<'a>(callee: &'a Expression<'a>) -> bool {
+    match callee.without_parenthesized() {
+        Expression::Identifier(ident) => ident.name == "createElement",
+        Expression::MemberExpression(member) => {
+            member.is_specific_member_access("React", "createElement")
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("<div />;", None),
+        ("<div></div>;", None),
+        ("React.createElement(\"div\", {});", None),
+        ("React.createElement(\"div\", undefined);", None),
+        ("<div className=\"class-name\"></div>;", None),
+        ("React.createElement(\"div\", {className: \"class-name\"});", None),
+        ("<div>Children</div>;", None),
+        ("React.createElement(\"div\", \"Children\");", None),
+        ("React.createElement(\"div\", {}, \"Children\");", None),
+        ("React.createElement(\"div\", undefined, \"Children\");", None),
+        ("<div className=\"class-name\">Children</div>;", None),
+        ("React.createElement(\"div\", {className: \"class-name\"}, \"Children\");", None),
+        ("<div><div /></div>;", None),
+        ("React.createElement(\"div\", React.createElement(\"div\"));", None),
+        ("<MyComponent {...props} />", None),
+        ("React.createElement(MyComponent, {...props});", None),
+        ("React.createElement(MyComponent, {[\"children\"]: foo});", None),
+        ("foo.createElement(\"div\", {children: \"Children\"});", None),
+    ];
+
+    let fail = vec![
+        ("<div children />;", None),
+        ("<div children=\"Children\" />;", None),
+        ("<div children={<div />} />;", None),
+        ("<div children={[<div />, <div />]} />;", None),
+        ("<div children=\"Children\">Children</div>;", None),
+        ("React.createElement(\"div\", {children: \"Children\"});", None),
+        ("React.createElement(\"div\", {children: \"Children\"}, \"Children\");", None),
+        ("React.createElement(\"div\", {children: React.createElement(\"div\")});", None),
+        ("<MyComponent children=\"Children\" />", None),
+        ("React.createElement(MyComponent, {children: \"Children\"});", None),
+        ("<MyComponent className=\"class-name\" children=\"Children\" />;", None),
+        ("createElement(MyComponent, {'children': \"Children\"});", None),
+        ("<MyComponent {...props} children=\"Children\" />;", None),
+    ];
+
+    Tester::new(NoChildrenProp::NAME, pass, fail).test_and_snapshot();
+}
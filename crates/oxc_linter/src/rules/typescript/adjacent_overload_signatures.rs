@@ -18,7 +18,6 @@ use crate::{ast_util::get_name_from_property_key, context::LintContext, rule::Ru
 #[error(
     "typescript-eslint(adjacent-overload-signatures): All {0:?} signatures should be adjacent."
 )]
-#[diagnostic(severity(warning))]
 struct AdjacentOverloadSignaturesDiagnostic(Atom, #[label] pub Option<Span>, #[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
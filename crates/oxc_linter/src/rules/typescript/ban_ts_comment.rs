@@ -11,13 +11,10 @@ use crate::{context::LintContext, rule::Rule};
 #[derive(Debug, Error, Diagnostic)]
 pub enum BanTsCommentDiagnostic {
     #[error("Do not use @ts-{0} because it alters compilation errors.")]
-    #[diagnostic(severity(warning))]
     Comment(String, #[label] Span),
     #[error("Include a description after the @ts-{0} directive to explain why the @ts-{0} is necessary. The description must be {1} characters or longer.")]
-    #[diagnostic(severity(warning))]
     CommentRequiresDescription(String, u64, #[label] Span),
     #[error("The description for the @ts-{0} directive must match the {1} format.")]
-    #[diagnostic(severity(warning))]
     CommentDescriptionNotMatchPattern(String, String, #[label] Span),
 }
 
@@ -88,7 +85,60 @@ declare_oxc_lint!(
     /// }
     /// ```
     BanTsComment,
-    nursery // since rust regex may not compatible with ECMAScript regex
+    nursery, // since rust regex may not compatible with ECMAScript regex
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": {
+                "ts-expect-error": {
+                    "oneOf": [
+                        { "type": "boolean" },
+                        { "enum": ["allow-with-description"] },
+                        {
+                            "type": "object",
+                            "properties": { "descriptionFormat": { "type": "string" } },
+                            "additionalProperties": false
+                        }
+                    ]
+                },
+                "ts-ignore": {
+                    "oneOf": [
+                        { "type": "boolean" },
+                        { "enum": ["allow-with-description"] },
+                        {
+                            "type": "object",
+                            "properties": { "descriptionFormat": { "type": "string" } },
+                            "additionalProperties": false
+                        }
+                    ]
+                },
+                "ts-nocheck": {
+                    "oneOf": [
+                        { "type": "boolean" },
+                        { "enum": ["allow-with-description"] },
+                        {
+                            "type": "object",
+                            "properties": { "descriptionFormat": { "type": "string" } },
+                            "additionalProperties": false
+                        }
+                    ]
+                },
+                "ts-check": {
+                    "oneOf": [
+                        { "type": "boolean" },
+                        { "enum": ["allow-with-description"] },
+                        {
+                            "type": "object",
+                            "properties": { "descriptionFormat": { "type": "string" } },
+                            "additionalProperties": false
+                        }
+                    ]
+                },
+                "minimumDescriptionLength": { "type": "integer", "minimum": 0 }
+            },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for BanTsComment {
@@ -14,7 +14,7 @@ use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("typescript-eslint(consistent-type-export): Consistent type exports")]
-#[diagnostic(severity(error), help("Consistent type export"))]
+#[diagnostic(help("Consistent type export"))]
 struct ConsistentTypeExportDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -51,6 +51,7 @@ declare_oxc_lint!(
     /// ```
     ConsistentTypeExports,
     nursery,
+    severity = error
 );
 
 impl Rule for ConsistentTypeExports {
@@ -166,5 +167,5 @@ fn test() {
         ),
     ];
 
-    Tester::new(ConsistentTypeExports::NAME, pass, fail).test();
+    Tester::new(ConsistentTypeExports::NAME, pass, fail).test_and_snapshot();
 }
@@ -12,10 +12,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("typescript-eslint(no-duplicate-enum-values): Disallow duplicate enum member values")]
-#[diagnostic(
-    severity(warning),
-    help("Duplicate values can lead to bugs that are hard to track down")
-)]
+#[diagnostic(help("Duplicate values can lead to bugs that are hard to track down"))]
 struct NoDuplicateEnumValuesDiagnostic(#[label] Span, #[label] Span);
 
 #[derive(Debug, Default, Clone)]
@@ -10,14 +10,12 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("typescript-eslint(no-empty-interface): an empty interface is equivalent to `{{}}`")]
-#[diagnostic(severity(warning))]
 struct NoEmptyInterfaceDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Error, Diagnostic)]
 #[error(
     "typescript-eslint(no-empty-interface): an interface declaring no members is equivalent to its supertype"
 )]
-#[diagnostic(severity(warning))]
 struct NoEmptyInterfaceExtendDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
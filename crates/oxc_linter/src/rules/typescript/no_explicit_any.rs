@@ -1,4 +1,4 @@
-use crate::Fix;
+use crate::{Fix, FixKind};
 use oxc_ast::AstKind;
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
@@ -12,7 +12,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("typescript-eslint(no-explicit-any): Unexpected any. Specify a different type.")]
-#[diagnostic(severity(warning), help("Use `unknown` instead, this will force you to explicitly, and safely, assert the type is correct."))]
+#[diagnostic(help("Use `unknown` instead, this will force you to explicitly, and safely, assert the type is correct."))]
 struct NoExplicitAnyDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -85,7 +85,15 @@ declare_oxc_lint!(
     /// Whether to enable auto-fixing in which the `any` type is converted to the `unknown` type.
     /// `false` by default.
     NoExplicitAny,
-    restriction
+    restriction,
+    suggestion,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": { "fixToUnknown": { "type": "boolean" }, "ignoreRestArgs": { "type": "boolean" } },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for NoExplicitAny {
@@ -97,7 +105,7 @@ impl Rule for NoExplicitAny {
 
         if self.fix_to_unknown {
             ctx.diagnostic_with_fix(NoExplicitAnyDiagnostic(any.span), || {
-                Fix::new("unknown", any.span)
+                Fix::new("unknown", any.span).with_kind(FixKind::Suggestion)
             });
         } else {
             ctx.diagnostic(NoExplicitAnyDiagnostic(any.span));
@@ -132,7 +140,9 @@ mod tests {
     fn test_simple() {
         let pass = vec!["let x: number = 1"];
         let fail = vec!["let x: any = 1"];
-        Tester::new_without_config(NoExplicitAny::NAME, pass, fail).test();
+        Tester::new_without_config(NoExplicitAny::NAME, pass, fail)
+            .with_snapshot_suffix("simple")
+            .test_and_snapshot();
     }
 
     #[allow(clippy::too_many_lines)]
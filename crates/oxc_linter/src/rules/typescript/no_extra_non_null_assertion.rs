@@ -10,7 +10,6 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("typescript-eslint(no-extra-non-null-assertion): extra non-null assertion")]
-#[diagnostic(severity(warning))]
 struct NoExtraNonNullAssertionDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
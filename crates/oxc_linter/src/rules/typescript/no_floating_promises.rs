@@ -0,0 +1,185 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoFloatingPromisesDiagnostic {
+    #[error("typescript-eslint(no-floating-promises): Promises must be awaited, end with a call to .catch, end with a call to .then with a rejection handler or be explicitly marked as ignored with the `void` operator.")]
+    Floating(#[label] Span),
+
+    #[error("typescript-eslint(no-floating-promises): Promises must be awaited, end with a call to .catch, or end with a call to .then with a rejection handler.")]
+    FloatingNoVoid(#[label] Span),
+}
+
+#[derive(Debug, Clone)]
+pub struct NoFloatingPromises {
+    /// Whether to ignore `void` expressions.
+    ///
+    /// `true` by default.
+    ignore_void: bool,
+}
+
+impl Default for NoFloatingPromises {
+    fn default() -> Self {
+        Self { ignore_void: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require Promise-like statements to be handled appropriately.
+    ///
+    /// This rule is type-aware and does nothing unless type information is available,
+    /// see the `--type-aware` option.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A "floating" Promise is one that is created without any code set up to handle any errors
+    /// it might throw. Floating Promises can cause improperly sequenced operations, ignored
+    /// Promise rejections, and more.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// const promise = new Promise((resolve, reject) => resolve('value'));
+    /// promise;
+    ///
+    /// async function returnsPromise() {
+    ///   return 'value';
+    /// }
+    /// returnsPromise().then(() => {});
+    ///
+    /// Promise.reject('value').catch();
+    ///
+    /// Promise.reject('value').finally();
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `ignoreVoid`
+    /// Whether to allow marking a Promise as intentionally not awaited with the `void` operator.
+    /// `true` by default.
+    NoFloatingPromises,
+    correctness,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": { "ignoreVoid": { "type": "boolean" } },
+            "additionalProperties": false
+        }
+    ]"#
+);
+
+impl Rule for NoFloatingPromises {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(stmt) = node.kind() else { return };
+        if !ctx.has_type_info() {
+            return;
+        }
+
+        if self.is_unhandled(&stmt.expression, ctx) {
+            let span = stmt.expression.span();
+            if self.ignore_void {
+                ctx.diagnostic(NoFloatingPromisesDiagnostic::Floating(span));
+            } else {
+                ctx.diagnostic(NoFloatingPromisesDiagnostic::FloatingNoVoid(span));
+            }
+        }
+    }
+
+    fn from_configuration(value: Value) -> Self {
+        let Some(cfg) = value.get(0) else { return Self::default() };
+        let ignore_void = cfg.get("ignoreVoid").and_then(Value::as_bool).unwrap_or(true);
+
+        Self { ignore_void }
+    }
+}
+
+impl NoFloatingPromises {
+    fn is_unhandled<'a>(&self, expr: &Expression<'a>, ctx: &LintContext<'a>) -> bool {
+        match expr {
+            Expression::ParenthesizedExpression(expr) => self.is_unhandled(&expr.expression, ctx),
+            Expression::SequenceExpression(expr) => {
+                expr.expressions.iter().any(|expr| self.is_unhandled(expr, ctx))
+            }
+            Expression::UnaryExpression(expr) if expr.operator == UnaryOperator::Void => {
+                !self.ignore_void && self.is_unhandled(&expr.argument, ctx)
+            }
+            Expression::ConditionalExpression(expr) => {
+                self.is_unhandled(&expr.consequent, ctx) || self.is_unhandled(&expr.alternate, ctx)
+            }
+            Expression::LogicalExpression(expr) => {
+                self.is_unhandled(&expr.left, ctx) || self.is_unhandled(&expr.right, ctx)
+            }
+            Expression::CallExpression(call) => {
+                if ctx.is_promise_like(call.span) != Some(true) {
+                    return false;
+                }
+                let Some(member) = call.callee.get_member_expr() else { return true };
+                match member.static_property_name() {
+                    // `promise.catch(handler)`
+                    Some("catch") => call.arguments.is_empty(),
+                    // `promise.then(onFulfilled, onRejected)`
+                    Some("then") => call.arguments.len() < 2,
+                    // `promise.finally(handler)` is as handled as the promise it is called on
+                    Some("finally") => self.is_unhandled(member.object(), ctx),
+                    _ => true,
+                }
+            }
+            // The promise is stored and may be handled later
+            Expression::AwaitExpression(_) | Expression::AssignmentExpression(_) => false,
+            _ => ctx.is_promise_like(expr.span()) == Some(true),
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("async function f() { await returnsPromise(); }", None),
+        ("returnsPromise().then(() => {}, () => {});", None),
+        ("returnsPromise().catch(() => {});", None),
+        ("returnsPromise().then(() => {}).catch(() => {});", None),
+        ("returnsPromise().catch(() => {}).finally(() => {});", None),
+        ("returnsPromise().then(() => {}, () => {}).finally(() => {});", None),
+        ("void returnsPromise();", None),
+        ("void returnsPromise().then(() => {});", None),
+        ("const x = returnsPromise();", None),
+        ("returnsNumber();", None),
+        ("foo.bar();", None),
+        ("let x; x = returnsPromise();", None),
+        ("condition ? returnsPromise().catch(() => {}) : null;", None),
+        ("condition && returnsPromise().catch(() => {});", None),
+        // Type checking is disabled
+        ("// @ts-nocheck\nreturnsPromise();", None),
+    ];
+
+    let fail = vec![
+        ("returnsPromise();", None),
+        ("returnsPromise().then(() => {});", None),
+        ("returnsPromise().catch();", None),
+        ("returnsPromise().finally(() => {});", None),
+        ("returnsPromise().then(() => {}).finally(() => {});", None),
+        ("(returnsPromise());", None),
+        ("condition ? returnsPromise() : null;", None),
+        ("condition && returnsPromise();", None),
+        ("returnsNumber(), returnsPromise();", None),
+        ("async function f() { returnsPromise(); }", None),
+        ("Promise.resolve(1);", None),
+        ("void returnsPromise();", Some(serde_json::json!([{ "ignoreVoid": false }]))),
+    ];
+
+    Tester::new(NoFloatingPromises::NAME, pass, fail)
+        .with_promise_like(|text| text.contains("returnsPromise()") || text.starts_with("Promise."))
+        .test_and_snapshot();
+}
@@ -13,10 +13,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("typescript-eslint(no-namespace): ES2015 module syntax is preferred over namespaces.")]
-#[diagnostic(
-    severity(warning),
-    help("Replace the namespace with an ES2015 module or use `declare module`")
-)]
+#[diagnostic(help("Replace the namespace with an ES2015 module or use `declare module`"))]
 struct NoNamespaceDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -42,7 +39,17 @@ declare_oxc_lint!(
     /// declare namespace foo {}
     /// ```
     NoNamespace,
-    correctness
+    correctness,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": {
+                "allowDeclarations": { "type": "boolean" },
+                "allowDefinitionFiles": { "type": "boolean" }
+            },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for NoNamespace {
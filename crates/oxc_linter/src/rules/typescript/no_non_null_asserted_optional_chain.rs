@@ -16,7 +16,6 @@ use crate::{context::LintContext, rule::Rule, AstNode};
     "typescript-eslint(no-non-null-asserted-optional-chain): non-null assertions after an optional chain expression"
 )]
 #[diagnostic(
-    severity(warning),
     help(
         "Optional chain expressions can return undefined by design - using a non-null assertion is unsafe and wrong. You should remove the non-null assertion."
     )
@@ -0,0 +1,181 @@
+use oxc_ast::{
+    ast::{Expression, MemberExpression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{
+    context::LintContext,
+    fixer::{Fix, FixKind},
+    rule::Rule,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("typescript-eslint(no-non-null-assertion): Forbidden non-null assertion.")]
+#[diagnostic(
+    help("Consider using the optional chain operator `?.` instead. This operator includes runtime checks, so it is safer than the compile-only non-null assertion operator.")
+)]
+struct NoNonNullAssertionDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNonNullAssertion;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow non-null assertions using the `!` postfix operator.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// TypeScript's `!` non-null assertion operator asserts to the type system that an expression
+    /// is non-nullable, as in not `null` or `undefined`. Using assertions to tell the type system
+    /// new information is often a sign that code is not fully type-safe. It's generally better to
+    /// structure program logic so that TypeScript understands when values may be nullable.
+    ///
+    /// ### Example
+    /// ```typescript
+    /// x!;
+    /// x!.y;
+    /// x.y!;
+    /// ```
+    ///
+    /// The suggestion replaces `x!.y` with `x?.y`, except when the expression is assigned,
+    /// updated or deleted, e.g. `x!.y = 1`, where an optional chain is invalid or behaves
+    /// differently.
+    NoNonNullAssertion,
+    restriction,
+    suggestion
+);
+
+impl Rule for NoNonNullAssertion {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::TSNonNullExpression(expr) = node.kind() else { return };
+        // The span of the `!`
+        let bang = Span::new(expr.span.end - 1, expr.span.end);
+        let is_expr = |callee: &Expression| callee.span() == expr.span;
+        if Self::is_target(node, ctx) {
+            return ctx.diagnostic(NoNonNullAssertionDiagnostic(expr.span));
+        }
+        // Suggest `x?.y` for `x!.y`, `x?.[y]` for `x![y]` and `x?.()` for `x!()`, or only
+        // remove the `!` when it is already followed by `?.`
+        let content = match ctx.nodes().parent_kind(node.id()) {
+            Some(AstKind::MemberExpression(member)) if is_expr(member.object()) => match member {
+                _ if member.optional() => "",
+                MemberExpression::ComputedMemberExpression(_) => "?.",
+                _ => "?",
+            },
+            Some(AstKind::CallExpression(call)) if is_expr(&call.callee) => {
+                if call.optional {
+                    ""
+                } else {
+                    "?."
+                }
+            }
+            _ => return ctx.diagnostic(NoNonNullAssertionDiagnostic(expr.span)),
+        };
+        ctx.diagnostic_with_fix(NoNonNullAssertionDiagnostic(expr.span), || {
+            Fix::new(content, bang).with_kind(FixKind::Suggestion)
+        });
+    }
+}
+
+impl NoNonNullAssertion {
+    /// Whether the member or call chain starting at `node` is the target of an assignment,
+    /// an update or `delete`.
+    fn is_target(node: &AstNode, ctx: &LintContext) -> bool {
+        let mut span = node.kind().span();
+        for parent in ctx.nodes().iter_parents(node.id()).skip(1) {
+            span = match parent.kind() {
+                AstKind::MemberExpression(member) if member.object().span() == span => {
+                    member.span()
+                }
+                AstKind::CallExpression(call) if call.callee.span() == span => call.span,
+                AstKind::ChainExpression(chain) => chain.span,
+                AstKind::ParenthesizedExpression(paren) => paren.span,
+                AstKind::SimpleAssignmentTarget(_) => return true,
+                AstKind::UnaryExpression(unary) => return unary.operator == UnaryOperator::Delete,
+                _ => return false,
+            };
+        }
+        false
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "x;",
+        "x.y;",
+        "x.y.z;",
+        "x?.y.z;",
+        "x?.y?.z;",
+        "!x;",
+        "x != null;",
+        "const x: number | null = 1; x !== null && x.toFixed();",
+    ];
+
+    let fail = vec![
+        "x!;",
+        "x!.y;",
+        "x.y!;",
+        "!x!.y;",
+        "x!.y?.z;",
+        "x![y];",
+        "x?.y!.z;",
+        "x.y.z!();",
+        "x.y?.z!();",
+        "x!!!;",
+        "x!?.[y].z;",
+        "(x!)!.y;",
+        "x!.y = 1;",
+        "x!.y.z = 1;",
+        "x![y] += 1;",
+        "x!.y++;",
+        "--x!.y;",
+        "[x!.y] = [1];",
+        "({ a: x!.y } = {});",
+        "for (x!.y of z);",
+        "delete x!.y;",
+        "delete (x!.y);",
+    ];
+
+    let fix = vec![
+        ("x!.y;", "x?.y;", None),
+        ("x![y];", "x?.[y];", None),
+        ("x.y.z!();", "x.y.z?.();", None),
+        ("x!?.y;", "x?.y;", None),
+        ("x!?.[y].z;", "x?.[y].z;", None),
+        ("x.y.z!?.();", "x.y.z?.();", None),
+        ("(x!)!.y;", "(x!)?.y;", None),
+        ("x!;", "x!;", None),
+        ("x.y!;", "x.y!;", None),
+        ("foo(x!);", "foo(x!);", None),
+        ("x!.y = 1;", "x!.y = 1;", None),
+        ("x!.y.z = 1;", "x!.y.z = 1;", None),
+        ("x![y] += 1;", "x![y] += 1;", None),
+        ("x!.y++;", "x!.y++;", None),
+        ("[x!.y] = [1];", "[x!.y] = [1];", None),
+        ("for (x!.y of z);", "for (x!.y of z);", None),
+        ("delete x!.y;", "delete x!.y;", None),
+        // only the chain of the `!` is a target
+        ("a[x!.y] = 1;", "a[x?.y] = 1;", None),
+        ("x!.y.z;", "x?.y.z;", None),
+    ];
+
+    // Suggestions are not applied with `--fix` only
+    let safe_fix = vec![("x!.y;", "x!.y;", None), ("x.y.z!();", "x.y.z!();", None)];
+
+    Tester::new_without_config(NoNonNullAssertion::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_fix_of_kind(FixKind::Safe, safe_fix)
+        .test_and_snapshot();
+}
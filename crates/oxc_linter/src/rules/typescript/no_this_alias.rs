@@ -14,7 +14,6 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 #[derive(Debug, Error, Diagnostic)]
 #[error("typescript-eslint(no-this-alias): Unexpected aliasing of 'this' to local variable.")]
 #[diagnostic(
-    severity(warning),
     help(
         "Assigning a variable to this instead of properly using arrow lambdas may be a symptom of pre-ES6 practices or not managing scope well."
     )
@@ -25,10 +24,9 @@ struct NoThisAliasDiagnostic(#[label] pub Span);
 #[error(
     "typescript-eslint(no-this-alias): Unexpected aliasing of members of 'this' to local variables."
 )]
-#[diagnostic(
-    severity(warning),
-    help("Disabling destructuring of this is not a default, consider allowing destructuring")
-)]
+#[diagnostic(help(
+    "Disabling destructuring of this is not a default, consider allowing destructuring"
+))]
 struct NoThisDestructureDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Clone)]
@@ -58,7 +56,17 @@ declare_oxc_lint!(
     /// sampe with obj.<allowedName> = this
     /// ```
     NoThisAlias,
-    correctness
+    correctness,
+    schema = r#"[
+        {
+            "type": "object",
+            "properties": {
+                "allow_destructuring": { "type": "boolean" },
+                "allow_names": { "type": "array", "items": { "type": "string" }, "uniqueItems": true }
+            },
+            "additionalProperties": false
+        }
+    ]"#
 );
 
 impl Rule for NoThisAlias {
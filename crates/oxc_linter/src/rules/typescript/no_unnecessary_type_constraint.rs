@@ -12,7 +12,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 #[error(
     "typescript-eslint(no-unnecessary-type-constraint): constraining the generic type {0:?} to {1:?} does nothing and is unnecessary"
 )]
-#[diagnostic(severity(warning), help("Remove the unnecessary {1:?} constraint"))]
+#[diagnostic(help("Remove the unnecessary {1:?} constraint"))]
 struct NoUnnecessaryTypeConstraintDiagnostic(
     Atom,
     &'static str,
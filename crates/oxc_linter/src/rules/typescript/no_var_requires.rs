@@ -10,7 +10,7 @@ use crate::{ast_util::get_declaration_of_variable, context::LintContext, rule::R
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("typescript-eslint(no-var-requires): Require statement not part of import statement.")]
-#[diagnostic(severity(warning), help("Use ES6 style imports or import instead."))]
+#[diagnostic(help("Use ES6 style imports or import instead."))]
 struct NoVarRequiresDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -12,7 +12,7 @@ use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("Expected a `const` assertion instead of a literal type annotation.")]
-#[diagnostic(severity(warning), help("You should use `as const` instead of type annotation."))]
+#[diagnostic(help("You should use `as const` instead of type annotation."))]
 struct PreferAsConstDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -36,7 +36,8 @@ declare_oxc_lint!(
     /// let foo = { bar: 'baz' as 'baz' };
     /// ```
     PreferAsConst,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for PreferAsConst {
@@ -13,7 +13,7 @@ use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(no-instanceof-array): Use `Array.isArray()` instead of `instanceof Array`.")]
-#[diagnostic(severity(warning), help("The instanceof Array check doesn't work across realms/contexts, for example, frames/windows in browsers or the vm module in Node.js."))]
+#[diagnostic(help("The instanceof Array check doesn't work across realms/contexts, for example, frames/windows in browsers or the vm module in Node.js."))]
 struct NoInstanceofArrayDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -32,7 +32,8 @@ declare_oxc_lint!(
     /// [1,2,3] instanceof Array;
     /// ```
     NoInstanceofArray,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoInstanceofArray {
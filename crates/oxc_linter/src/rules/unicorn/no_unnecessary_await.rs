@@ -11,7 +11,7 @@ use crate::{context::LintContext, rule::Rule, AstNode, Fix};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(no-unnecessary-await): Disallow awaiting non-promise values")]
-#[diagnostic(severity(warning), help("consider to remove the `await`"))]
+#[diagnostic(help("consider to remove the `await`"))]
 struct NoUnnecessaryAwaitDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Default, Clone)]
@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// await await promise;
     /// ```
     NoUnnecessaryAwait,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoUnnecessaryAwait {
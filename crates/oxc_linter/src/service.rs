@@ -1,22 +1,39 @@
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use std::{
     collections::HashMap,
     fs,
-    path::Path,
+    io::{self, Write},
+    ops::Deref,
+    path::{Path, PathBuf},
     rc::Rc,
     sync::{Arc, Condvar, Mutex},
 };
 
 use oxc_allocator::Allocator;
-use oxc_diagnostics::{DiagnosticSender, DiagnosticService};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+    DiagnosticSender, DiagnosticService, Error,
+};
 use oxc_parser::Parser;
-use oxc_resolver::{ResolveOptions, Resolver};
-use oxc_semantic::{ModuleRecord, SemanticBuilder};
-use oxc_span::{SourceType, VALID_EXTENSIONS};
-use rustc_hash::FxHashSet;
-
-use crate::{Fixer, LintContext, LintOptions, Linter, Message};
-use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
+use oxc_resolver::{CompilerOptions, ResolveOptions, Resolver};
+use oxc_semantic::{JsxOptions, JsxRuntime, ModuleRecord, SemanticBuilder};
+use oxc_span::{Atom, SourceType, VALID_EXTENSIONS};
+
+use crate::{
+    cancellation::CancellationToken,
+    file_directives::FileDirectives,
+    fixer::unified_diff,
+    module_cache::ModuleCache,
+    partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
+    source_file::SourceFile,
+    Fixer, LintContext, LintOptions, Linter, Message, RuleCapabilities, TscTypeInfoProvider,
+    TypeInfoProvider,
+};
+use rayon::{
+    iter::{IntoParallelIterator, ParallelBridge},
+    prelude::ParallelIterator,
+};
 
 #[derive(Clone)]
 pub struct LintService {
@@ -24,36 +41,152 @@ pub struct LintService {
 }
 
 impl LintService {
-    pub fn new(cwd: Box<Path>, paths: &[Box<Path>], options: LintOptions) -> Self {
+    /// # Errors
+    ///
+    /// * The TypeScript compiler cannot be started with `type_aware`
+    pub fn new(cwd: Box<Path>, options: LintOptions) -> io::Result<Self> {
         let linter = Linter::from_options(options);
-        let runtime = Arc::new(Runtime::new(cwd, paths, linter));
-        Self { runtime }
+        let runtime = Arc::new(Runtime::new(cwd, linter)?);
+        Ok(Self { runtime })
     }
 
     #[cfg(test)]
-    pub(crate) fn from_linter(cwd: Box<Path>, paths: &[Box<Path>], linter: Linter) -> Self {
-        let runtime = Arc::new(Runtime::new(cwd, paths, linter));
-        Self { runtime }
+    pub(crate) fn from_linter(
+        cwd: Box<Path>,
+        paths: &[Box<Path>],
+        linter: Linter,
+        type_info: Option<Arc<dyn TypeInfoProvider>>,
+    ) -> Self {
+        let mut runtime = Runtime::new(cwd, linter).unwrap();
+        for path in paths {
+            runtime.paths.insert(Runtime::normalize_path(&runtime.cwd, path));
+        }
+        runtime.type_info = type_info;
+        Self { runtime: Arc::new(runtime) }
     }
 
     pub fn linter(&self) -> &Linter {
         &self.runtime.linter
     }
 
+    /// Cancel to stop linting, the diagnostics of the files linted so far are still sent.
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.runtime.cancellation
+    }
+
+    /// Number of files received by [LintService::run]
+    pub fn number_of_files(&self) -> usize {
+        self.runtime.paths.len()
+    }
+
     pub fn number_of_dependencies(&self) -> usize {
         self.runtime.module_map.len() - self.runtime.paths.len()
     }
 
+    /// Print the module graph built by the import plugin as JSON,
+    /// with the resolved path of each module request, or `null` when it is not resolved,
+    /// and whether it is a dynamic `import()`.
+    ///
+    /// # Errors
+    ///
+    /// * Writing to `writer` fails
+    pub fn print_module_graph_json<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let modules = self
+            .runtime
+            .module_graph()
+            .into_iter()
+            .map(|(path, requests)| {
+                let requests = requests
+                    .into_iter()
+                    .map(|edge| {
+                        serde_json::json!({
+                            "specifier": edge.specifier.as_str(),
+                            "resolved": edge.resolved.map(|path| path.to_string_lossy().to_string()),
+                            "dynamic": edge.is_dynamic,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                serde_json::json!({ "path": path.to_string_lossy(), "requests": requests })
+            })
+            .collect::<Vec<_>>();
+        serde_json::to_writer_pretty(&mut *writer, &modules)?;
+        writeln!(writer)
+    }
+
+    /// Print the module graph built by the import plugin in the Graphviz DOT language.
+    /// Edges are labeled with the module request, unresolved requests are dashed
+    /// and dynamic imports are dotted.
+    ///
+    /// # Errors
+    ///
+    /// * Writing to `writer` fails
+    pub fn print_module_graph_dot<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writeln!(writer, "digraph {{")?;
+        for (path, requests) in self.runtime.module_graph() {
+            let path = path.to_string_lossy();
+            writeln!(writer, "  {path:?};")?;
+            for edge in requests {
+                let specifier = edge.specifier.as_str();
+                let style = if edge.is_dynamic { ", style=dotted" } else { "" };
+                match edge.resolved {
+                    Some(resolved) => {
+                        let resolved = resolved.to_string_lossy();
+                        writeln!(writer, "  {path:?} -> {resolved:?} [label={specifier:?}{style}];")
+                    }
+                    None => writeln!(writer, "  {path:?} -> {specifier:?} [style=dashed];"),
+                }?;
+            }
+        }
+        writeln!(writer, "}}")
+    }
+
+    /// The unified diffs of the fixes computed with `--fix-dry-run`, sorted by path,
+    /// for the caller to print after [LintService::run].
+    ///
     /// # Panics
-    pub fn run(&self, tx_error: &DiagnosticSender) {
-        self.runtime
-            .paths
-            .iter()
-            .par_bridge()
-            .for_each_with(&self.runtime, |runtime, path| runtime.process_path(path, tx_error));
+    pub fn take_fix_diffs(&self) -> Vec<(PathBuf, String)> {
+        let mut fix_diffs = std::mem::take(&mut *self.runtime.fix_diffs.lock().unwrap());
+        fix_diffs.sort_by(|(a, _), (b, _)| a.cmp(b));
+        fix_diffs
+    }
+
+    /// Lint `paths` as they are received, e.g. while the walker threads are still running.
+    ///
+    /// With the import plugin all paths are received before linting starts, because a linted
+    /// file that is also imported by another linted file must be linted instead of only being
+    /// loaded as a dependency.
+    ///
+    /// # Panics
+    pub fn run<I>(&self, paths: I, tx_error: &DiagnosticSender)
+    where
+        I: Iterator<Item = Box<Path>> + Send,
+    {
+        let runtime = &self.runtime;
+        // Duplicates are linted once
+        let paths = paths
+            .map(|path| Runtime::normalize_path(&runtime.cwd, &path))
+            .filter(|path| runtime.paths.insert(path.clone()));
+        if runtime.linter.options().import_plugin {
+            paths
+                .collect::<Vec<_>>()
+                .into_par_iter()
+                .for_each(|path| runtime.process_path(&path, tx_error));
+        } else {
+            paths.par_bridge().for_each(|path| runtime.process_path(&path, tx_error));
+        }
         tx_error.send(None).unwrap();
     }
 
+    /// Write the module records of the dependencies loaded by [LintService::run] to the
+    /// `--module-cache`.
+    ///
+    /// # Errors
+    ///
+    /// * The module cache cannot be written
+    pub fn persist_module_cache(&self) -> io::Result<()> {
+        self.runtime.module_cache.as_ref().map_or(Ok(()), ModuleCache::persist)
+    }
+
     /// For tests
     #[cfg(test)]
     pub(crate) fn run_source<'a>(
@@ -67,6 +200,7 @@ impl LintService {
             .paths
             .iter()
             .flat_map(|path| {
+                let path = path.key();
                 let source_type = SourceType::from_path(path).unwrap();
                 self.runtime.init_cache_state(path);
                 self.runtime.process_source(
@@ -99,32 +233,172 @@ enum CacheStateEntry {
     PendingStore(usize),
 }
 
+#[derive(Debug, Error, Diagnostic)]
+enum ReadFileDiagnostic {
+    #[error("File is not valid UTF-8 or UTF-16 and was not linted")]
+    #[diagnostic(severity(warning), help("Save the file with UTF-8 encoding"))]
+    InvalidUtf8,
+
+    #[error("Failed to read file: {0}")]
+    #[diagnostic(severity(warning))]
+    Io(io::Error),
+}
+
 /// Keyed by canonicalized path
 type ModuleMap = DashMap<Box<Path>, Arc<ModuleRecord>>;
 
+/// Resolved paths keyed by the requesting module and the module request, for the module graph
+type ResolvedModules = DashMap<(Box<Path>, Atom), Box<Path>>;
+
+/// Shortest distance of the loaded dependencies from a linted file, which is at depth 0
+type ModuleDepths = DashMap<Box<Path>, usize>;
+
+/// Arenas recycled between files, so each rayon worker reuses the memory of the previous file
+/// instead of allocating and dropping an arena per file.
+///
+/// A worker may take several allocators at once because linting a file with the import plugin
+/// enabled processes its dependencies on the same thread.
+#[derive(Default)]
+struct AllocatorPool {
+    allocators: Mutex<Vec<Allocator>>,
+}
+
+impl AllocatorPool {
+    fn get(&self) -> PooledAllocator<'_> {
+        let allocator = self.allocators.lock().unwrap().pop().unwrap_or_default();
+        PooledAllocator { allocator: Some(allocator), pool: self }
+    }
+}
+
+/// An allocator borrowed from the pool, reset and returned to it when dropped.
+struct PooledAllocator<'a> {
+    allocator: Option<Allocator>,
+    pool: &'a AllocatorPool,
+}
+
+impl Deref for PooledAllocator<'_> {
+    type Target = Allocator;
+
+    fn deref(&self) -> &Self::Target {
+        self.allocator.as_ref().unwrap()
+    }
+}
+
+impl Drop for PooledAllocator<'_> {
+    fn drop(&mut self) {
+        if let Some(mut allocator) = self.allocator.take() {
+            allocator.reset();
+            self.pool.allocators.lock().unwrap().push(allocator);
+        }
+    }
+}
+
+/// Modules with their module requests and the resolved paths
+type ModuleGraph = Vec<(PathBuf, Vec<ModuleGraphEdge>)>;
+
+struct ModuleGraphEdge {
+    specifier: Atom,
+    /// `None` when the request is not resolved
+    resolved: Option<PathBuf>,
+    /// `import("mod")`, loaded asynchronously
+    is_dynamic: bool,
+}
+
 pub struct Runtime {
+    /// Canonicalized, paths are displayed relative to it
     cwd: Box<Path>,
-    /// All paths to lint
-    paths: FxHashSet<Box<Path>>,
+    /// All paths to lint received so far, absolute and canonicalized like the resolved paths
+    /// of dependencies
+    paths: DashSet<Box<Path>>,
     linter: Linter,
+    /// Linters of the workspace packages with a config file, the deepest directory first
+    workspace_linters: Vec<(Box<Path>, Linter)>,
+    /// What is loaded from the dependencies of the linted files, from the enabled rules
+    capabilities: RuleCapabilities,
     resolver: Resolver,
+    /// Resolvers of the workspace packages with a `tsconfig.json`, the deepest directory first
+    workspace_resolvers: Vec<(Box<Path>, Resolver)>,
+    /// Reads the `tsconfig.json` of the current working directory, for files outside of workspaces
+    tsconfig_resolver: Option<Resolver>,
     module_map: ModuleMap,
+    resolved_modules: ResolvedModules,
+    module_depths: ModuleDepths,
+    /// Module records of dependencies kept between runs, with `--module-cache`
+    module_cache: Option<ModuleCache>,
     cache_state: CacheState,
+    allocator_pool: AllocatorPool,
+    type_info: Option<Arc<dyn TypeInfoProvider>>,
+    cancellation: CancellationToken,
+    /// Unified diffs of the fixes with `--fix-dry-run`, keyed by the displayed path
+    fix_diffs: Mutex<Vec<(PathBuf, String)>>,
 }
 
 impl Runtime {
-    fn new(cwd: Box<Path>, paths: &[Box<Path>], linter: Linter) -> Self {
-        Self {
+    fn new(cwd: Box<Path>, linter: Linter) -> io::Result<Self> {
+        let cwd = cwd.canonicalize().map_or(cwd, PathBuf::into_boxed_path);
+        let type_info = if linter.options().type_aware {
+            Some(Arc::new(TscTypeInfoProvider::new(&cwd)?) as Arc<dyn TypeInfoProvider>)
+        } else {
+            None
+        };
+        let workspace_linters = Self::workspace_linters(linter.options());
+        let capabilities = if linter.options().module_graph {
+            RuleCapabilities::all()
+        } else {
+            workspace_linters.iter().fold(linter.capabilities(), |capabilities, (_, linter)| {
+                capabilities | linter.capabilities()
+            })
+        };
+        let module_cache = linter
+            .options()
+            .module_cache
+            .clone()
+            .filter(|_| linter.options().import_plugin)
+            .map(ModuleCache::new);
+        let resolver = Self::resolver(&linter.options().workspaces);
+        let workspace_resolvers = Self::workspace_resolvers(&resolver);
+        let tsconfig = cwd.join("tsconfig.json");
+        let tsconfig_resolver = tsconfig.is_file().then(|| {
+            resolver.clone_with_options(ResolveOptions {
+                tsconfig: Some(tsconfig),
+                ..resolver.options().clone()
+            })
+        });
+        Ok(Self {
             cwd,
-            paths: paths.iter().cloned().collect(),
+            paths: DashSet::default(),
             linter,
-            resolver: Self::resolver(),
+            workspace_linters,
+            capabilities,
+            resolver,
+            workspace_resolvers,
+            tsconfig_resolver,
             module_map: ModuleMap::default(),
+            resolved_modules: ResolvedModules::default(),
+            module_depths: ModuleDepths::default(),
+            module_cache,
             cache_state: CacheState::default(),
-        }
+            allocator_pool: AllocatorPool::default(),
+            type_info,
+            cancellation: CancellationToken::new(),
+            fix_diffs: Mutex::default(),
+        })
     }
 
-    fn resolver() -> Resolver {
+    /// `path` made absolute and canonicalized, so that a file is the same module whether it is
+    /// linted, imported or reached through a symlink. Kept as is when it does not exist.
+    fn normalize_path(cwd: &Path, path: &Path) -> Box<Path> {
+        let path = cwd.join(path);
+        path.canonicalize().unwrap_or(path).into_boxed_path()
+    }
+
+    /// `path` relative to the current working directory for reporting, or absolute when it is
+    /// outside of it, e.g. a dependency in a global store or a symlinked workspace.
+    fn display_path<'a>(&self, path: &'a Path) -> &'a Path {
+        path.strip_prefix(&self.cwd).unwrap_or(path)
+    }
+
+    fn resolver(workspaces: &[PathBuf]) -> Resolver {
         Resolver::new(ResolveOptions {
             condition_names: vec!["node".into(), "import".into()],
             extension_alias: vec![
@@ -132,42 +406,282 @@ impl Runtime {
                 (".mjs".into(), vec![".mjs".into(), ".mts".into()]),
             ],
             extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
+            workspaces: workspaces.to_vec(),
             ..ResolveOptions::default()
         })
     }
 
+    /// A resolver for each workspace package with a `tsconfig.json`, sharing the cache of `resolver`,
+    /// so that `paths` are resolved with the tsconfig of the package a file belongs to.
+    fn workspace_resolvers(resolver: &Resolver) -> Vec<(Box<Path>, Resolver)> {
+        let mut workspace_resolvers = resolver
+            .options()
+            .workspaces
+            .iter()
+            .filter_map(|workspace| {
+                let tsconfig = workspace.join("tsconfig.json");
+                tsconfig.is_file().then(|| {
+                    let options =
+                        ResolveOptions { tsconfig: Some(tsconfig), ..resolver.options().clone() };
+                    // Matched against canonicalized paths
+                    let workspace = workspace.canonicalize().unwrap_or_else(|_| workspace.clone());
+                    (workspace.into_boxed_path(), resolver.clone_with_options(options))
+                })
+            })
+            .collect::<Vec<_>>();
+        workspace_resolvers
+            .sort_by_key(|(workspace, _)| std::cmp::Reverse(workspace.components().count()));
+        workspace_resolvers
+    }
+
+    /// A linter for each workspace package with a config file, configured like `options` with the
+    /// config file of the package instead of `--config`.
+    fn workspace_linters(options: &LintOptions) -> Vec<(Box<Path>, Linter)> {
+        let mut workspace_linters = options
+            .workspace_configs
+            .iter()
+            .map(|(workspace, config)| {
+                let options = LintOptions {
+                    config: Some(config.clone()),
+                    workspace_configs: vec![],
+                    ..options.clone()
+                };
+                // Matched against canonicalized paths
+                let workspace = workspace.canonicalize().unwrap_or_else(|_| workspace.clone());
+                (workspace.into_boxed_path(), Linter::from_options(options))
+            })
+            .collect::<Vec<_>>();
+        workspace_linters
+            .sort_by_key(|(workspace, _)| std::cmp::Reverse(workspace.components().count()));
+        workspace_linters
+    }
+
+    /// The linter of the workspace package containing `path`.
+    fn linter_for(&self, path: &Path) -> &Linter {
+        self.workspace_linters
+            .iter()
+            .find(|(workspace, _)| path.starts_with(workspace))
+            .map_or(&self.linter, |(_, linter)| linter)
+    }
+
+    /// The resolver of the workspace package containing `path`.
+    fn resolver_for(&self, path: &Path) -> &Resolver {
+        self.workspace_resolvers
+            .iter()
+            .find(|(workspace, _)| path.starts_with(workspace))
+            .map_or(&self.resolver, |(_, resolver)| resolver)
+    }
+
+    /// The `jsx` options of the `tsconfig.json` of the workspace package containing `path`,
+    /// or of the current working directory
+    fn jsx_options(&self, path: &Path) -> JsxOptions {
+        let resolver = self
+            .workspace_resolvers
+            .iter()
+            .find(|(workspace, _)| path.starts_with(workspace))
+            .map(|(_, resolver)| resolver)
+            .or(self.tsconfig_resolver.as_ref());
+        resolver
+            .and_then(|resolver| resolver.tsconfig().ok().flatten())
+            .map(|tsconfig| Self::jsx_options_from_compiler_options(tsconfig.compiler_options()))
+            .unwrap_or_default()
+    }
+
+    fn jsx_options_from_compiler_options(compiler_options: &CompilerOptions) -> JsxOptions {
+        // `preserve` and `react-native` keep the JSX, but `tsc` still checks the factory is in scope
+        let runtime = match compiler_options.jsx() {
+            Some("react" | "preserve" | "react-native") => JsxRuntime::Classic,
+            _ => JsxRuntime::Automatic,
+        };
+        JsxOptions {
+            runtime,
+            factory: compiler_options.jsx_factory().map(Atom::from),
+            fragment_factory: compiler_options.jsx_fragment_factory().map(Atom::from),
+            import_source: compiler_options.jsx_import_source().map(Atom::from),
+        }
+    }
+
+    #[tracing::instrument(level = "debug", skip_all, fields(path = %path.display()))]
     fn process_path(&self, path: &Path, tx_error: &DiagnosticSender) {
-        let Ok(source_type) = SourceType::from_path(path) else { return };
+        if self.cancellation.is_cancelled() {
+            return;
+        }
+
+        let Some(ext) = path.extension().and_then(std::ffi::OsStr::to_str) else { return };
+        let lint_as = self.linter.options().resolve_extension(ext);
+        let source_type = if lint_as == ext {
+            SourceType::from_path(path)
+        } else {
+            SourceType::from_path(path.with_extension(lint_as))
+        };
+        let ext = lint_as;
+        if source_type.is_err() && !LINT_PARTIAL_LOADER_EXT.contains(&ext) {
+            return;
+        }
 
         if self.module_map.contains_key(path) {
             return;
         }
 
-        if self.init_cache_state(path) {
+        let options = self.linter.options();
+        let metadata = match fs::metadata(path) {
+            Ok(metadata) => metadata,
+            Err(err) => {
+                self.report_read_error(path, err, tx_error);
+                return;
+            }
+        };
+        if options.max_file_size.is_some_and(|max_file_size| metadata.len() > max_file_size) {
+            tracing::debug!(size = metadata.len(), "skipped, larger than --max-file-size");
             return;
         }
 
-        let allocator = Allocator::default();
-        let source_text =
-            fs::read_to_string(path).unwrap_or_else(|_| panic!("Failed to read {path:?}"));
+        if self.init_cache_state(path) {
+            tracing::trace!("skipped, processed by another thread");
+            return;
+        }
 
-        let mut messages =
-            self.process_source(path, &allocator, &source_text, source_type, true, tx_error);
+        if let Some(module_record) = self
+            .module_cache
+            .as_ref()
+            .filter(|_| !self.paths.contains(path))
+            .and_then(|module_cache| module_cache.get(path, &metadata))
+        {
+            tracing::trace!("module record read from the module cache");
+            let module_record = Arc::new(module_record);
+            self.module_map
+                .insert(path.to_path_buf().into_boxed_path(), Arc::clone(&module_record));
+            self.update_cache_state(path);
+            self.process_dependencies(path, &module_record, self.module_depth(path), tx_error);
+            return;
+        }
 
-        if self.linter.options().fix {
-            let fix_result = Fixer::new(&source_text, messages).fix();
-            fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
-            messages = fix_result.messages;
+        // Fixes are written to the file, which must not be mapped while the source text is in use.
+        let mmap = !options.fix || options.fix_dry_run;
+        let source_file = match SourceFile::read(path, metadata.len(), mmap) {
+            Ok(source_file) => source_file,
+            Err(err) => {
+                if options.import_plugin {
+                    // Unblock the threads waiting for the module record of this file.
+                    self.update_cache_state(path);
+                }
+                self.report_read_error(path, err, tx_error);
+                return;
+            }
+        };
+        let source_text = source_file.as_str();
+
+        let (errors, fixed_code) = source_type.map_or_else(
+            |_| {
+                let sources = PartialLoader::parse(ext, source_text).unwrap_or_default();
+                self.process_partial_sources(path, source_text, &sources, tx_error)
+            },
+            |source_type| self.process_source_with_fix(path, source_text, source_type, tx_error),
+        );
+
+        if let Some(fixed_code) = fixed_code {
+            if self.linter.options().fix_dry_run {
+                let path = self.display_path(path);
+                if let Some(diff) = unified_diff(source_text, &fixed_code, path) {
+                    self.fix_diffs.lock().unwrap().push((path.to_path_buf(), diff));
+                }
+            } else {
+                tracing::debug!("writing fixes");
+                fs::write(path, source_file.encoding().encode(&fixed_code)).unwrap();
+            }
         }
 
-        if !messages.is_empty() {
-            let errors = messages.into_iter().map(|m| m.error).collect();
-            let path = path.strip_prefix(&self.cwd).unwrap();
-            let diagnostics = DiagnosticService::wrap_diagnostics(path, &source_text, errors);
+        if !errors.is_empty() {
+            let path = self.display_path(path);
+            let diagnostics = DiagnosticService::wrap_diagnostics(path, source_text, errors);
             tx_error.send(Some(diagnostics)).unwrap();
         }
     }
 
+    /// Report a file that cannot be read as a warning instead of aborting the whole run.
+    /// Dependencies found by the import plugin are not reported, only the files to lint.
+    fn report_read_error(&self, path: &Path, err: io::Error, tx_error: &DiagnosticSender) {
+        tracing::debug!(error = %err, "failed to read");
+        if !self.paths.contains(path) {
+            return;
+        }
+        let error = if err.kind() == io::ErrorKind::InvalidData {
+            ReadFileDiagnostic::InvalidUtf8.into()
+        } else {
+            ReadFileDiagnostic::Io(err).into()
+        };
+        let path = self.display_path(path);
+        let diagnostics = DiagnosticService::wrap_diagnostics(path, "", vec![error]);
+        tx_error.send(Some(diagnostics)).unwrap();
+    }
+
+    /// Lint the JavaScript sources embedded in a file, e.g. `<script>` blocks in `.vue` files.
+    ///
+    /// Each source is linted against the original file with everything else blanked out,
+    /// so diagnostics and fixes point into the original file.
+    fn process_partial_sources(
+        &self,
+        path: &Path,
+        source_text: &str,
+        sources: &[JavaScriptSource],
+        tx_error: &DiagnosticSender,
+    ) -> (Vec<Error>, Option<String>) {
+        let mut errors = vec![];
+        let mut fixes = vec![];
+        for source in sources {
+            let padded_source_text = source.padded_source_text(source_text);
+            let (source_errors, fixed_code) = self.process_source_with_fix(
+                path,
+                &padded_source_text,
+                source.source_type,
+                tx_error,
+            );
+            errors.extend(source_errors);
+            if let Some(fixed_code) = fixed_code {
+                // Text around the source is unchanged by fixes
+                let start = source.start as usize;
+                let end = start + source.source_text.len();
+                let fixed_end = fixed_code.len() - (source_text.len() - end);
+                fixes.push((start..end, fixed_code[start..fixed_end].to_string()));
+            }
+        }
+        if fixes.is_empty() {
+            return (errors, None);
+        }
+        let mut fixed_code = source_text.to_string();
+        // Apply from the end so that the ranges of the preceding sources stay valid
+        for (range, fixed_source_text) in fixes.into_iter().rev() {
+            fixed_code.replace_range(range, &fixed_source_text);
+        }
+        (errors, Some(fixed_code))
+    }
+
+    /// Lint a single source and apply fixes when enabled.
+    /// Returns the remaining errors and the fixed code if anything was fixed.
+    fn process_source_with_fix(
+        &self,
+        path: &Path,
+        source_text: &str,
+        source_type: SourceType,
+        tx_error: &DiagnosticSender,
+    ) -> (Vec<Error>, Option<String>) {
+        let allocator = self.allocator_pool.get();
+        let mut messages =
+            self.process_source(path, &allocator, source_text, source_type, true, tx_error);
+
+        let mut fixed_code = None;
+        let options = self.linter.options();
+        if options.fix || options.fix_dry_run {
+            let fix_result = Fixer::new(source_text, messages).fix();
+            if fix_result.fixed {
+                fixed_code = Some(fix_result.fixed_code.into_owned());
+            }
+            messages = fix_result.messages;
+        }
+
+        (messages.into_iter().map(Message::into_error).collect(), fixed_code)
+    }
+
     fn process_source<'a>(
         &self,
         path: &Path,
@@ -177,9 +691,14 @@ impl Runtime {
         check_syntax_errors: bool,
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
-        let ret = Parser::new(allocator, source_text, source_type)
-            .allow_return_outside_function(true)
-            .parse();
+        let file_directives = FileDirectives::parse(source_text);
+        let source_type = file_directives.source_type(source_type);
+        let ret = tracing::debug_span!("parse").in_scope(|| {
+            Parser::new(allocator, source_text, source_type)
+                .allow_return_outside_function(true)
+                .allow_legacy_decorators(true)
+                .parse()
+        });
 
         if !ret.errors.is_empty() {
             return ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
@@ -192,6 +711,7 @@ impl Runtime {
         let semantic_builder = SemanticBuilder::new(source_text, source_type)
             .with_trivias(ret.trivias)
             .with_check_syntax_error(check_syntax_errors)
+            .with_jsx_options(self.jsx_options(path))
             .build_module_record(path.to_path_buf(), program);
         let module_record = semantic_builder.module_record();
 
@@ -200,42 +720,152 @@ impl Runtime {
                 .insert(path.to_path_buf().into_boxed_path(), Arc::clone(&module_record));
             self.update_cache_state(path);
 
+            self.process_dependencies(path, &module_record, self.module_depth(path), tx_error);
+
             // Stop if the current module is not marked for lint.
             if !self.paths.contains(path) {
+                if let Some(module_cache) = &self.module_cache {
+                    if let Ok(metadata) = fs::metadata(path) {
+                        module_cache.insert(path, &metadata, &module_record);
+                    }
+                }
                 return vec![];
             }
-
-            let dir = path.parent().unwrap();
-
-            // Retrieve all dependency modules from this module.
-            module_record
-                .requested_modules
-                .keys()
-                .cloned()
-                .par_bridge()
-                .map_with(&self.resolver, |resolver, specifier| {
-                    resolver.resolve(dir, &specifier).ok().map(|r| (specifier, r))
-                })
-                .flatten()
-                .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
-                    let path = resolution.path();
-                    self.process_path(path, tx_error);
-                    if let Some(target_module_record) = self.module_map.get(path) {
-                        module_record
-                            .loaded_modules
-                            .insert(specifier, Arc::clone(&target_module_record));
-                    }
-                });
         }
 
-        let semantic_ret = semantic_builder.build(program);
+        let semantic_ret =
+            tracing::debug_span!("semantic").in_scope(|| semantic_builder.build(program));
 
         if !semantic_ret.errors.is_empty() {
             return semantic_ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
         };
 
-        let lint_ctx = LintContext::new(&Rc::new(semantic_ret.semantic));
-        self.linter.run(lint_ctx)
+        let package_json = if self.linter.options().import_plugin {
+            self.resolver_for(path).find_package_json(path).ok().flatten()
+        } else {
+            None
+        };
+        let lint_ctx = LintContext::new(&Rc::new(semantic_ret.semantic))
+            .with_file_path(path)
+            .with_type_info(self.type_info.clone())
+            .with_file_directives(file_directives)
+            .with_package_json(package_json);
+        // Checked again after parsing and resolving, which take most of the time of a file.
+        if self.cancellation.is_cancelled() {
+            return vec![];
+        }
+        tracing::debug_span!("lint").in_scope(|| self.linter_for(path).run(lint_ctx))
+    }
+
+    /// How many imports away from a linted file the module at `path` is
+    fn module_depth(&self, path: &Path) -> usize {
+        if self.paths.contains(path) {
+            0
+        } else {
+            self.module_depths.get(path).map_or(usize::MAX, |depth| *depth)
+        }
+    }
+
+    /// Resolve and load the dependencies of the module at `path`, which is `depth` imports away
+    /// from a linted file, within the configured module depth.
+    ///
+    /// Dependencies are only resolved when an enabled rule needs the module graph,
+    /// and only parsed when a rule needs their exports.
+    fn process_dependencies(
+        &self,
+        path: &Path,
+        module_record: &ModuleRecord,
+        depth: usize,
+        tx_error: &DiagnosticSender,
+    ) {
+        let options = self.linter.options();
+        if self.capabilities.is_empty() || !options.loads_dependencies_at(depth) {
+            return;
+        }
+        let Some(dir) = path.parent() else { return };
+
+        // Retrieve all dependency modules from this module, including dynamic imports.
+        let dynamic_imports = module_record
+            .dynamic_imports
+            .keys()
+            .filter(|specifier| !module_record.requested_modules.contains_key(*specifier));
+        module_record
+            .requested_modules
+            .keys()
+            .chain(dynamic_imports)
+            .cloned()
+            .par_bridge()
+            .map_with(self.resolver_for(path), |resolver, specifier| {
+                resolver
+                    .resolve(dir, &specifier)
+                    .map_err(|error| tracing::debug!(%specifier, %error, "unresolved"))
+                    .ok()
+                    .map(|r| (specifier, r))
+            })
+            .flatten()
+            .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
+                let resolved_path = resolution.path();
+                self.resolved_modules.insert(
+                    (path.to_path_buf().into_boxed_path(), specifier.clone()),
+                    resolved_path.to_path_buf().into_boxed_path(),
+                );
+                if options.workspace_modules_only && !self.is_workspace_module(resolved_path) {
+                    return;
+                }
+                if !self.capabilities.contains(RuleCapabilities::DEPENDENCY_EXPORTS) {
+                    // Only the resolved path is used, the dependency is not parsed.
+                    let target_module_record = ModuleRecord::new(resolved_path.to_path_buf());
+                    module_record.loaded_modules.insert(specifier, Arc::new(target_module_record));
+                    return;
+                }
+                self.module_depths
+                    .entry(resolved_path.to_path_buf().into_boxed_path())
+                    .and_modify(|dependency_depth| {
+                        *dependency_depth = (*dependency_depth).min(depth + 1);
+                    })
+                    .or_insert(depth + 1);
+                self.process_path(resolved_path, tx_error);
+                if let Some(target_module_record) = self.module_map.get(resolved_path) {
+                    module_record
+                        .loaded_modules
+                        .insert(specifier, Arc::clone(&target_module_record));
+                }
+            });
+    }
+
+    /// Whether `path` is inside the current working directory and outside of `node_modules`
+    fn is_workspace_module(&self, path: &Path) -> bool {
+        path.starts_with(&self.cwd)
+            && !path.components().any(|component| component.as_os_str() == "node_modules")
+    }
+
+    /// Modules sorted by path with their module requests in source order followed by their
+    /// dynamic imports, paths are relative to the current working directory when possible.
+    fn module_graph(&self) -> ModuleGraph {
+        let relative = |path: &Path| self.display_path(path).to_path_buf();
+        let mut modules = self
+            .module_map
+            .iter()
+            .map(|entry| {
+                let module_record = entry.value();
+                let requests = module_record
+                    .requested_modules
+                    .keys()
+                    .map(|specifier| (specifier, false))
+                    .chain(module_record.dynamic_imports.keys().map(|specifier| (specifier, true)))
+                    .map(|(specifier, is_dynamic)| {
+                        let resolved = self
+                            .resolved_modules
+                            .get(&(entry.key().clone(), specifier.clone()))
+                            .map(|resolved| relative(&resolved));
+                        ModuleGraphEdge { specifier: specifier.clone(), resolved, is_dynamic }
+                    })
+                    .collect();
+                (relative(entry.key()), requests)
+            })
+            .collect::<Vec<_>>();
+        modules.sort_by(|a, b| a.0.cmp(&b.0));
+        modules
     }
 
     fn init_cache_state(&self, path: &Path) -> bool {
@@ -292,3 +922,70 @@ impl Runtime {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_diagnostics::DiagnosticService;
+
+    use super::LintService;
+    use crate::{LintConfig, LintOptions};
+
+    #[test]
+    #[cfg(unix)]
+    fn normalize_paths() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path().join("root");
+        std::fs::create_dir_all(dir.join("sub")).unwrap();
+        std::fs::write(dir.join("a.js"), "").unwrap();
+        let link = temp_dir.path().join("link");
+        std::os::unix::fs::symlink(&dir, &link).unwrap();
+        let dir = dir.canonicalize().unwrap();
+
+        // The current working directory and the linted paths are canonicalized
+        let lint_service =
+            LintService::new(link.into_boxed_path(), LintOptions::default()).unwrap();
+        let paths = [
+            Path::new("sub/../a.js").into(),
+            dir.join("missing.js").into_boxed_path(),
+            dir.join("a.js").into_boxed_path(),
+        ];
+        let diagnostic_service = DiagnosticService::default();
+        lint_service.run(paths.into_iter(), diagnostic_service.sender());
+        let runtime = &lint_service.runtime;
+        assert!(runtime.paths.contains(dir.join("a.js").as_path()));
+        assert!(runtime.paths.contains(dir.join("missing.js").as_path()));
+        // The same file is linted once
+        assert_eq!(lint_service.number_of_files(), 2);
+
+        // Paths are displayed relative to the current working directory when inside of it
+        assert_eq!(runtime.display_path(&dir.join("a.js")), Path::new("a.js"));
+        assert_eq!(runtime.display_path(Path::new("/outside/b.js")), Path::new("/outside/b.js"));
+    }
+
+    #[test]
+    fn workspace_configs() {
+        let dir = Path::new("fixtures/workspace_configs");
+        let workspace_configs = ["a", "b"]
+            .into_iter()
+            .map(|name| {
+                let workspace = dir.join("packages").join(name);
+                let config = LintConfig::from_file(&workspace.join(".oxlintrc.json")).unwrap();
+                (workspace, config)
+            })
+            .collect();
+        let options = LintOptions::default().with_workspace_configs(workspace_configs);
+        let lint_service = LintService::new(dir.into(), options).unwrap();
+
+        // The same source, `no-debugger` is off in `a` and `no-empty` is enabled in `b`
+        for (path, warnings) in
+            [("packages/a/index.js", 0), ("packages/b/index.js", 2), ("index.js", 1)]
+        {
+            let mut diagnostic_service = DiagnosticService::default().with_quiet(true);
+            lint_service.run(std::iter::once(Path::new(path).into()), diagnostic_service.sender());
+            diagnostic_service.run();
+            assert_eq!(diagnostic_service.warnings_count(), warnings, "{path}");
+        }
+    }
+}
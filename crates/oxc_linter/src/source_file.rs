@@ -0,0 +1,207 @@
+use std::{fs, fs::File, io, path::Path};
+
+use memmap2::Mmap;
+
+/// Files at least this large are memory-mapped instead of copied into a `String`,
+/// e.g. bundles and generated files of several megabytes.
+const MMAP_THRESHOLD: u64 = 1024 * 1024;
+
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+const UTF16_LE_BOM: &[u8] = &[0xFF, 0xFE];
+const UTF16_BE_BOM: &[u8] = &[0xFE, 0xFF];
+
+/// Source text of a file, read into memory or memory-mapped.
+///
+/// The byte order mark is not part of the source text and UTF-16 files are transcoded to UTF-8,
+/// so spans are offsets into [SourceFile::as_str]. Use [Encoding::encode] to write fixes back.
+pub struct SourceFile {
+    source: Source,
+    encoding: Encoding,
+}
+
+enum Source {
+    Owned(String),
+    /// Validated as UTF-8 after the byte order mark when mapped
+    Mapped(Mmap),
+}
+
+/// Encoding of a file, detected from its byte order mark.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Utf8,
+    Utf8Bom,
+    Utf16Le,
+    Utf16Be,
+}
+
+impl Encoding {
+    fn detect(bytes: &[u8]) -> Self {
+        if bytes.starts_with(UTF8_BOM) {
+            Self::Utf8Bom
+        } else if bytes.starts_with(UTF16_LE_BOM) {
+            Self::Utf16Le
+        } else if bytes.starts_with(UTF16_BE_BOM) {
+            Self::Utf16Be
+        } else {
+            Self::Utf8
+        }
+    }
+
+    fn bom(self) -> &'static [u8] {
+        match self {
+            Self::Utf8 => &[],
+            Self::Utf8Bom => UTF8_BOM,
+            Self::Utf16Le => UTF16_LE_BOM,
+            Self::Utf16Be => UTF16_BE_BOM,
+        }
+    }
+
+    /// Encode `source_text` in this encoding with the byte order mark, e.g. to write fixed code.
+    pub fn encode(self, source_text: &str) -> Vec<u8> {
+        let mut bytes = self.bom().to_vec();
+        match self {
+            Self::Utf8 | Self::Utf8Bom => bytes.extend_from_slice(source_text.as_bytes()),
+            Self::Utf16Le => bytes.extend(source_text.encode_utf16().flat_map(u16::to_le_bytes)),
+            Self::Utf16Be => bytes.extend(source_text.encode_utf16().flat_map(u16::to_be_bytes)),
+        }
+        bytes
+    }
+
+    fn decode(self, mut bytes: Vec<u8>) -> io::Result<String> {
+        bytes.drain(..self.bom().len());
+        let to_u16: fn([u8; 2]) -> u16 = match self {
+            Self::Utf8 | Self::Utf8Bom => {
+                return String::from_utf8(bytes).map_err(|err| invalid_data(err.utf8_error()));
+            }
+            Self::Utf16Le => u16::from_le_bytes,
+            Self::Utf16Be => u16::from_be_bytes,
+        };
+        let chunks = bytes.chunks_exact(2);
+        if !chunks.remainder().is_empty() {
+            return Err(invalid_data("odd number of bytes in UTF-16 file"));
+        }
+        char::decode_utf16(chunks.map(|chunk| to_u16([chunk[0], chunk[1]])))
+            .collect::<Result<String, _>>()
+            .map_err(invalid_data)
+    }
+}
+
+impl SourceFile {
+    /// Read the file at `path` of `len` bytes, memory-mapped when it is large and `mmap` is allowed.
+    /// Falls back to reading into a `String` when the file cannot be mapped.
+    ///
+    /// Mapping must not be allowed when the file is written while the source text is in use,
+    /// e.g. when fixes are applied.
+    ///
+    /// # Errors
+    ///
+    /// * When the file cannot be read or is not valid UTF-8 or UTF-16
+    pub fn read(path: &Path, len: u64, mmap: bool) -> io::Result<Self> {
+        if mmap && len >= MMAP_THRESHOLD {
+            if let Ok(source_file) = Self::map(path) {
+                return Ok(source_file);
+            }
+        }
+        let bytes = fs::read(path)?;
+        let encoding = Encoding::detect(&bytes);
+        let source_text = encoding.decode(bytes)?;
+        Ok(Self { source: Source::Owned(source_text), encoding })
+    }
+
+    /// Only UTF-8 files are mapped, UTF-16 files are transcoded by `read`.
+    fn map(path: &Path) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: the file is not written by the linter while it is mapped, see `read`.
+        // Changes by other processes while linting are not supported.
+        let mmap = unsafe { Mmap::map(&file)? };
+        let encoding = Encoding::detect(&mmap);
+        if !matches!(encoding, Encoding::Utf8 | Encoding::Utf8Bom) {
+            return Err(invalid_data("not UTF-8"));
+        }
+        std::str::from_utf8(&mmap[encoding.bom().len()..]).map_err(invalid_data)?;
+        Ok(Self { source: Source::Mapped(mmap), encoding })
+    }
+
+    pub fn as_str(&self) -> &str {
+        match &self.source {
+            Source::Owned(source_text) => source_text,
+            // SAFETY: validated as UTF-8 in `map`
+            Source::Mapped(mmap) => unsafe {
+                std::str::from_utf8_unchecked(&mmap[self.encoding.bom().len()..])
+            },
+        }
+    }
+
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+}
+
+fn invalid_data<E>(err: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path};
+
+    use super::{Encoding, Source, SourceFile, MMAP_THRESHOLD};
+
+    #[test]
+    fn read() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        let threshold = usize::try_from(MMAP_THRESHOLD).unwrap();
+
+        let small = dir.join("small.js");
+        fs::write(&small, "debugger;").unwrap();
+        let source_file = SourceFile::read(&small, 9, true).unwrap();
+        assert!(matches!(source_file.source, Source::Owned(_)));
+        assert_eq!(source_file.as_str(), "debugger;");
+
+        let large = dir.join("large.js");
+        let source_text = "debugger;\n".repeat(threshold / 10 + 1);
+        fs::write(&large, &source_text).unwrap();
+        let len = source_text.len() as u64;
+        let source_file = SourceFile::read(&large, len, true).unwrap();
+        assert!(matches!(source_file.source, Source::Mapped(_)));
+        assert_eq!(source_file.as_str(), source_text);
+        let source_file = SourceFile::read(&large, len, false).unwrap();
+        assert!(matches!(source_file.source, Source::Owned(_)));
+
+        fs::write(&large, Encoding::Utf8Bom.encode(&source_text)).unwrap();
+        let source_file = SourceFile::read(&large, len + 3, true).unwrap();
+        assert!(matches!(source_file.source, Source::Mapped(_)));
+        assert_eq!(source_file.encoding(), Encoding::Utf8Bom);
+        assert_eq!(source_file.as_str(), source_text);
+
+        let invalid = dir.join("invalid.js");
+        fs::write(&invalid, [0xff].repeat(threshold)).unwrap();
+        assert!(SourceFile::read(&invalid, MMAP_THRESHOLD, true).is_err());
+    }
+
+    #[test]
+    fn encodings() {
+        let dir = Path::new("fixtures/encodings");
+        let source_text = "let café = '😀';\n";
+        for (file, encoding) in [
+            ("utf8.js", Encoding::Utf8),
+            ("utf8_bom.js", Encoding::Utf8Bom),
+            ("utf16le.js", Encoding::Utf16Le),
+            ("utf16be.js", Encoding::Utf16Be),
+        ] {
+            let path = dir.join(file);
+            let bytes = encoding.encode(source_text);
+            assert_eq!(fs::read(&path).unwrap(), bytes);
+            let source_file = SourceFile::read(&path, bytes.len() as u64, true).unwrap();
+            assert_eq!(source_file.encoding(), encoding);
+            assert_eq!(source_file.as_str(), source_text);
+        }
+
+        // UTF-16 with an odd number of bytes
+        assert!(SourceFile::read(&dir.join("odd.js"), 3, true).is_err());
+    }
+}
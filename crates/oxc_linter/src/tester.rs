@@ -1,14 +1,19 @@
 use std::{
     env,
     path::{Path, PathBuf},
+    sync::Arc,
 };
 
 use oxc_allocator::Allocator;
 use oxc_diagnostics::miette::{GraphicalReportHandler, GraphicalTheme, NamedSource};
-use oxc_diagnostics::DiagnosticService;
+use oxc_diagnostics::{DiagnosticService, Error};
+use oxc_span::Span;
 use serde_json::Value;
 
-use crate::{rules::RULES, Fixer, LintOptions, LintService, Linter, RuleEnum};
+use crate::{
+    fixer::FixKind, rules::RULES, Fixer, LintOptions, LintService, Linter, Message, RuleEnum,
+    TypeInfoProvider,
+};
 
 #[derive(Eq, PartialEq)]
 enum TestResult {
@@ -17,15 +22,129 @@ enum TestResult {
     Fixed(String),
 }
 
+/// Type information derived from the source text of the queried expression,
+/// for testing type-aware rules without the TypeScript compiler.
+#[derive(Debug)]
+struct SourceTypeInfo {
+    source_text: String,
+    is_promise_like: fn(&str) -> bool,
+}
+
+impl TypeInfoProvider for SourceTypeInfo {
+    fn is_promise_like(&self, _path: &Path, span: Span) -> Option<bool> {
+        Some((self.is_promise_like)(span.source_text(&self.source_text)))
+    }
+}
+
+/// The expected `errors` of an invalid test case of the ESLint rule tester,
+/// either the number of errors or the errors sorted by location.
+#[derive(Debug, Clone)]
+enum ExpectedErrors {
+    Count(usize),
+    Errors(Vec<ExpectedError>),
+}
+
+/// `{ "message": "...", "line": 1, "column": 1, "endLine": 1, "endColumn": 2 }`,
+/// lines and columns start at 1 and columns count UTF-16 code units, like in ESLint.
+#[derive(Debug, Clone, Default)]
+struct ExpectedError {
+    message: Option<String>,
+    line: Option<usize>,
+    column: Option<usize>,
+    end_line: Option<usize>,
+    end_column: Option<usize>,
+}
+
+impl ExpectedErrors {
+    fn from_json(value: &Value) -> Self {
+        match value {
+            Value::Number(count) => Self::Count(usize::try_from(count.as_u64().unwrap()).unwrap()),
+            Value::Array(errors) => {
+                Self::Errors(errors.iter().map(ExpectedError::from_json).collect())
+            }
+            _ => panic!("`errors` must be a number or an array: {value}"),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::Count(count) => *count,
+            Self::Errors(errors) => errors.len(),
+        }
+    }
+}
+
+impl ExpectedError {
+    fn from_json(value: &Value) -> Self {
+        let number =
+            |key: &str| value.get(key).and_then(Value::as_u64).map(|n| usize::try_from(n).unwrap());
+        match value {
+            Value::String(message) => Self { message: Some(message.clone()), ..Self::default() },
+            Value::Object(_) => Self {
+                message: value.get("message").and_then(Value::as_str).map(String::from),
+                line: number("line"),
+                column: number("column"),
+                end_line: number("endLine"),
+                end_column: number("endColumn"),
+            },
+            _ => panic!("an error must be a message or an object: {value}"),
+        }
+    }
+
+    /// Compare with the message, or the help when the rule puts the ESLint message there,
+    /// without the `plugin(rule): ` prefix, and the location of the first label.
+    fn assert_eq(&self, error: &Error, source_text: &str, test: &str) {
+        if let Some(expected) = &self.message {
+            let message = error.to_string();
+            let message =
+                message.split_once("): ").map_or(message.as_str(), |(_, message)| message);
+            let help = error.help().map(|help| help.to_string());
+            assert!(
+                message == expected || help.as_deref() == Some(expected),
+                "expect message `{expected}`, found `{message}`: {test}"
+            );
+        }
+        let span = error.labels().and_then(|mut labels| labels.next()).map(|label| {
+            (
+                location(source_text, label.offset()),
+                location(source_text, label.offset() + label.len()),
+            )
+        });
+        let ((line, column), (end_line, end_column)) =
+            span.unwrap_or_else(|| panic!("expect a label: {test}"));
+        for (name, expected, found) in [
+            ("line", self.line, line),
+            ("column", self.column, column),
+            ("endLine", self.end_line, end_line),
+            ("endColumn", self.end_column, end_column),
+        ] {
+            if let Some(expected) = expected {
+                assert_eq!(expected, found, "expect {name} of `{error}`: {test}");
+            }
+        }
+    }
+}
+
+/// The 1-based line and UTF-16 column of `offset`
+fn location(source_text: &str, offset: usize) -> (usize, usize) {
+    let before = &source_text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    let line = before.matches('\n').count() + 1;
+    (line, before[line_start..].encode_utf16().count() + 1)
+}
+
 pub struct Tester {
     rule_name: &'static str,
     rule_path: PathBuf,
     expect_pass: Vec<(String, Option<Value>)>,
     expect_fail: Vec<(String, Option<Value>)>,
-    expect_fix: Vec<(String, String, Option<Value>)>,
+    expect_errors: Vec<(String, Option<Value>, ExpectedErrors)>,
+    expect_fix: Vec<(String, String, Option<Value>, FixKind)>,
     snapshot: String,
+    snapshot_suffix: Option<&'static str>,
     current_working_directory: Box<Path>,
     import_plugin: bool,
+    is_promise_like: Option<fn(&str) -> bool>,
 }
 
 impl Tester {
@@ -44,10 +163,13 @@ impl Tester {
             rule_path,
             expect_pass,
             expect_fail,
+            expect_errors: vec![],
             expect_fix: vec![],
             snapshot: String::new(),
+            snapshot_suffix: None,
             current_working_directory,
             import_plugin: false,
+            is_promise_like: None,
         }
     }
 
@@ -61,6 +183,42 @@ impl Tester {
         Self::new(rule_name, expect_pass, expect_fail)
     }
 
+    /// Test with the `valid` and `invalid` test cases of the ESLint or typescript-eslint rule tester,
+    /// converted to JSON, for porting the upstream test suite of a rule as is:
+    ///
+    /// * valid: `"code"` or `{ "code": "code", "options": [...] }`
+    /// * invalid: `{ "code": "code", "options": [...], "errors": 1 }`,
+    ///   or with `"errors": [{ "message": "...", "line": 1, "column": 1 }]`
+    ///
+    /// The number of diagnostics must match, and the messages and locations which are given.
+    /// Other properties, e.g. `output` and `parserOptions`, are ignored.
+    pub fn from_eslint(rule_name: &'static str, valid: &Value, invalid: &Value) -> Self {
+        let case = |value: &Value| match value {
+            Value::String(code) => (code.clone(), None),
+            Value::Object(case) => {
+                let code = case.get("code").and_then(Value::as_str);
+                let code = code.unwrap_or_else(|| panic!("a test case requires `code`: {value}"));
+                (code.to_string(), case.get("options").cloned())
+            }
+            _ => panic!("a test case must be code or an object: {value}"),
+        };
+        let expect_pass = valid.as_array().expect("`valid` must be an array").iter().map(case);
+        let mut tester = Self::new::<String>(rule_name, expect_pass.collect(), vec![]);
+        tester.expect_errors = invalid
+            .as_array()
+            .expect("`invalid` must be an array")
+            .iter()
+            .map(|value| {
+                let (code, options) = case(value);
+                let errors = value.get("errors").map(ExpectedErrors::from_json);
+                let errors =
+                    errors.unwrap_or_else(|| panic!("an invalid case requires `errors`: {value}"));
+                (code, options, errors)
+            })
+            .collect();
+        tester
+    }
+
     pub fn update_expect_pass_fail<S: Into<String>>(
         mut self,
         expect_pass: Vec<S>,
@@ -77,20 +235,46 @@ impl Tester {
         self
     }
 
+    /// Snapshot to `{rule_name}@{suffix}.snap` instead of `{rule_name}.snap`,
+    /// for rules which are tested by multiple testers
+    pub fn with_snapshot_suffix(mut self, suffix: &'static str) -> Self {
+        self.snapshot_suffix = Some(suffix);
+        self
+    }
+
     pub fn with_import_plugin(mut self, yes: bool) -> Self {
         self.import_plugin = yes;
         self
     }
 
-    pub fn expect_fix<S: Into<String>>(mut self, expect_fix: Vec<(S, S, Option<Value>)>) -> Self {
-        self.expect_fix =
-            expect_fix.into_iter().map(|(s1, s2, r)| (s1.into(), s2.into(), r)).collect::<Vec<_>>();
+    /// Enable type-aware linting, where an expression is promise-like if `is_promise_like`
+    /// returns true for its source text
+    pub fn with_promise_like(mut self, is_promise_like: fn(&str) -> bool) -> Self {
+        self.is_promise_like = Some(is_promise_like);
+        self
+    }
+
+    /// Expect the fixed code with all kinds of fixes enabled
+    pub fn expect_fix<S: Into<String>>(self, expect_fix: Vec<(S, S, Option<Value>)>) -> Self {
+        self.expect_fix_of_kind(FixKind::Unsafe, expect_fix)
+    }
+
+    /// Expect the fixed code with fixes up to `kind` enabled, e.g. only safe fixes for
+    /// `FixKind::Safe` like `--fix`, or also suggestions for `FixKind::Suggestion`
+    pub fn expect_fix_of_kind<S: Into<String>>(
+        mut self,
+        kind: FixKind,
+        expect_fix: Vec<(S, S, Option<Value>)>,
+    ) -> Self {
+        self.expect_fix
+            .extend(expect_fix.into_iter().map(|(s1, s2, r)| (s1.into(), s2.into(), r, kind)));
         self
     }
 
     pub fn test(&mut self) {
         self.test_pass();
         self.test_fail();
+        self.test_errors();
         self.test_fix();
     }
 
@@ -99,8 +283,13 @@ impl Tester {
         self.snapshot();
     }
 
+    /// Assert the rendered diagnostics of all fail cases, in `src/snapshots`
     pub fn snapshot(&self) {
         let name = self.rule_name.replace('-', "_");
+        let name = match self.snapshot_suffix {
+            Some(suffix) => format!("{name}@{suffix}"),
+            None => name,
+        };
         insta::with_settings!({ prepend_module_to_snapshot => false, }, {
             insta::assert_snapshot!(name.clone(), self.snapshot, &name);
         });
@@ -108,7 +297,7 @@ impl Tester {
 
     fn test_pass(&mut self) {
         for (test, config) in self.expect_pass.clone() {
-            let result = self.run(&test, config, false);
+            let result = self.run(&test, config, None);
             let passed = result == TestResult::Passed;
             assert!(passed, "expect test to pass: {test} {}", self.snapshot);
         }
@@ -116,28 +305,74 @@ impl Tester {
 
     fn test_fail(&mut self) {
         for (test, config) in self.expect_fail.clone() {
-            let result = self.run(&test, config, false);
+            let result = self.run(&test, config, None);
             let failed = result == TestResult::Failed;
             assert!(failed, "expect test to fail: {test}");
         }
     }
 
+    fn test_errors(&mut self) {
+        for (test, config, expected) in self.expect_errors.clone() {
+            let allocator = Allocator::default();
+            let mut result = self.lint(&allocator, &test, config, None);
+            assert_eq!(expected.len(), result.len(), "expect number of errors: {test}");
+            result.sort_by_key(Message::start);
+            if let ExpectedErrors::Errors(errors) = expected {
+                for (expected, message) in errors.iter().zip(&result) {
+                    expected.assert_eq(&message.error, &test, &test);
+                }
+            }
+            self.render(&test, result);
+        }
+    }
+
     fn test_fix(&mut self) {
-        for (test, expected, config) in self.expect_fix.clone() {
-            let result = self.run(&test, config, true);
+        for (test, expected, config, kind) in self.expect_fix.clone() {
+            let result = self.run(&test, config, Some(kind));
             if let TestResult::Fixed(fixed_str) = result {
-                assert_eq!(expected, fixed_str);
+                assert_eq!(expected, fixed_str, "expect {} fixes: {test}", kind.as_str());
             } else {
                 unreachable!()
             }
         }
     }
 
-    fn run(&mut self, source_text: &str, config: Option<Value>, is_fix: bool) -> TestResult {
+    /// Lint `source_text`, and fix it with fixes up to `fix_kind` enabled
+    fn run(
+        &mut self,
+        source_text: &str,
+        config: Option<Value>,
+        fix_kind: Option<FixKind>,
+    ) -> TestResult {
         let allocator = Allocator::default();
+        let result = self.lint(&allocator, source_text, config, fix_kind);
+
+        if result.is_empty() {
+            return TestResult::Passed;
+        }
+
+        if fix_kind.is_some() {
+            let fix_result = Fixer::new(source_text, result).fix();
+            return TestResult::Fixed(fix_result.fixed_code.to_string());
+        }
+
+        self.render(source_text, result);
+        TestResult::Failed
+    }
+
+    fn lint<'a>(
+        &self,
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        config: Option<Value>,
+        fix_kind: Option<FixKind>,
+    ) -> Vec<Message<'a>> {
         let rule = self.find_rule().read_json(config);
-        let options =
-            LintOptions::default().with_fix(is_fix).with_import_plugin(self.import_plugin);
+        let options = LintOptions::default()
+            .with_fix(fix_kind.is_some())
+            .with_fix_suggestions(matches!(fix_kind, Some(FixKind::Suggestion | FixKind::Unsafe)))
+            .with_fix_dangerously(fix_kind == Some(FixKind::Unsafe))
+            .with_import_plugin(self.import_plugin);
         let linter = Linter::from_options(options).with_rules(vec![rule]);
         let path_to_lint = if self.import_plugin {
             self.current_working_directory.join(&self.rule_path)
@@ -148,20 +383,18 @@ impl Tester {
             self.current_working_directory.clone(),
             &[path_to_lint.into_boxed_path()],
             linter,
+            self.is_promise_like.map(|is_promise_like| {
+                Arc::new(SourceTypeInfo { source_text: source_text.to_string(), is_promise_like })
+                    as Arc<dyn TypeInfoProvider>
+            }),
         );
         let diagnostic_service = DiagnosticService::default();
         let tx_error = diagnostic_service.sender();
-        let result = lint_service.run_source(&allocator, source_text, false, tx_error);
-
-        if result.is_empty() {
-            return TestResult::Passed;
-        }
-
-        if is_fix {
-            let fix_result = Fixer::new(source_text, result).fix();
-            return TestResult::Fixed(fix_result.fixed_code.to_string());
-        }
+        lint_service.run_source(allocator, source_text, false, tx_error)
+    }
 
+    /// Render the diagnostics into the snapshot
+    fn render(&mut self, source_text: &str, result: Vec<Message>) {
         let diagnostic_path = if self.import_plugin {
             self.rule_path.strip_prefix(&self.current_working_directory).unwrap()
         } else {
@@ -169,8 +402,10 @@ impl Tester {
         }
         .to_string_lossy();
         let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor());
-        for diagnostic in result {
-            let diagnostic = diagnostic.error.with_source_code(source_text.to_string());
+        for mut diagnostic in result {
+            // With the severity of the rule, without the rule as the code
+            diagnostic.rule = None;
+            let diagnostic = diagnostic.into_error().with_source_code(source_text.to_string());
             let diagnostic = diagnostic.with_source_code(NamedSource::new(
                 diagnostic_path.clone(),
                 source_text.to_string(),
@@ -178,7 +413,6 @@ impl Tester {
             handler.render_report(&mut self.snapshot, diagnostic.as_ref()).unwrap();
             self.snapshot.push('\n');
         }
-        TestResult::Failed
     }
 
     fn find_rule(&self) -> &RuleEnum {
@@ -0,0 +1,25 @@
+//! Type information for type-aware lint rules.
+//!
+//! Rules such as `no-floating-promises` need to know the type of an expression, which cannot be
+//! derived from the AST alone. A [`TypeInfoProvider`] answers these queries for the linter;
+//! rules access it through [`LintContext::is_promise_like`](crate::LintContext::is_promise_like)
+//! and should skip their checks when no type information is available.
+
+mod tsc;
+
+use std::{fmt::Debug, path::Path};
+
+use oxc_span::Span;
+
+pub use self::tsc::TscTypeInfoProvider;
+
+/// Answers type queries about expressions in a source file.
+///
+/// Expressions are identified by their file path and span, spans being UTF-8 byte offsets into the
+/// source text as produced by the parser.
+/// All methods return `None` when the type is unknown, e.g. when the file is not part of the project.
+pub trait TypeInfoProvider: Debug + Send + Sync {
+    /// Whether the type of the expression is a `Promise` or another thenable,
+    /// including unions containing a thenable member.
+    fn is_promise_like(&self, path: &Path, span: Span) -> Option<bool>;
+}
@@ -0,0 +1,109 @@
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+    sync::Mutex,
+};
+
+use oxc_span::Span;
+use serde_json::{json, Value};
+
+use super::TypeInfoProvider;
+
+/// The script run by the `node` sidecar, see the header comment in the file for the protocol.
+const TSC_SERVER: &str = include_str!("tsc_server.js");
+
+/// A [`TypeInfoProvider`] backed by the TypeScript compiler.
+///
+/// A `node` process is spawned once and kept alive for the lifetime of the provider. It loads the
+/// `typescript` package installed in the project, builds a program from the nearest `tsconfig.json`
+/// and answers queries sent over stdin / stdout as JSON lines.
+/// Queries from different threads are serialized.
+#[derive(Debug)]
+pub struct TscTypeInfoProvider {
+    server: Mutex<TscServer>,
+}
+
+#[derive(Debug)]
+struct TscServer {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl TscTypeInfoProvider {
+    /// Spawn the sidecar for the project in `cwd`.
+    ///
+    /// # Errors
+    ///
+    /// * `node` is not installed, or `typescript` cannot be resolved from `cwd`
+    ///
+    /// # Panics
+    ///
+    /// * never, stdin and stdout are always piped
+    pub fn new(cwd: &Path) -> io::Result<Self> {
+        let mut child = Command::new("node")
+            .arg("-e")
+            .arg(TSC_SERVER)
+            .current_dir(cwd)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+        let mut server = TscServer { child, stdin, stdout };
+
+        // The server sends a single line once the program is built, or exits on failure.
+        let ready = server.read_response()?;
+        if ready.get("ready").and_then(Value::as_bool) != Some(true) {
+            let message = ready.get("error").and_then(Value::as_str).unwrap_or("unknown error");
+            return Err(io::Error::new(io::ErrorKind::Other, message.to_string()));
+        }
+
+        Ok(Self { server: Mutex::new(server) })
+    }
+
+    fn query(&self, query: &str, path: &Path, span: Span) -> Option<Value> {
+        let request = json!({
+            "query": query,
+            "file": path.to_string_lossy(),
+            "start": span.start,
+            "end": span.end,
+        });
+        let response = {
+            let mut server = self.server.lock().ok()?;
+            server.write_request(&request).ok()?;
+            server.read_response().ok()?
+        };
+        response.get("result").filter(|result| !result.is_null()).cloned()
+    }
+}
+
+impl TypeInfoProvider for TscTypeInfoProvider {
+    fn is_promise_like(&self, path: &Path, span: Span) -> Option<bool> {
+        self.query("isPromiseLike", path, span)?.as_bool()
+    }
+}
+
+impl TscServer {
+    fn write_request(&mut self, request: &Value) -> io::Result<()> {
+        writeln!(self.stdin, "{request}")?;
+        self.stdin.flush()
+    }
+
+    fn read_response(&mut self) -> io::Result<Value> {
+        let mut line = String::new();
+        if self.stdout.read_line(&mut line)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "tsc server exited"));
+        }
+        serde_json::from_str(&line).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+impl Drop for TscServer {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
@@ -0,0 +1,95 @@
+// Type information sidecar for oxc_linter, run with `node -e` in the project directory.
+//
+// Protocol, one JSON object per line:
+//   startup:  <- {"ready": true} or {"ready": false, "error": "..."}
+//   request:  -> {"query": "isPromiseLike", "file": "/abs/path.ts", "start": 0, "end": 10}
+//   response: <- {"result": true | false | null}
+//
+// `start` and `end` are UTF-8 byte offsets, `null` means the type is unknown.
+
+const readline = require("readline");
+
+function send(value) {
+  process.stdout.write(JSON.stringify(value) + "\n");
+}
+
+let ts;
+try {
+  ts = require(require.resolve("typescript", { paths: [process.cwd()] }));
+} catch {
+  send({ ready: false, error: "Cannot find module 'typescript' from " + process.cwd() });
+  process.exit(1);
+}
+
+const configPath = ts.findConfigFile(process.cwd(), ts.sys.fileExists, "tsconfig.json");
+const parsed = configPath
+  ? ts.getParsedCommandLineOfConfigFile(configPath, {}, {
+    ...ts.sys,
+    onUnRecoverableConfigFileDiagnostic() {},
+  })
+  : undefined;
+const program = ts.createProgram(
+  parsed ? parsed.fileNames : [],
+  parsed ? parsed.options : { allowJs: true },
+);
+const checker = program.getTypeChecker();
+
+/** Convert a UTF-8 byte offset into a UTF-16 offset used by TypeScript. */
+function toUtf16Offset(text, byteOffset) {
+  return Buffer.from(text, "utf8").subarray(0, byteOffset).toString("utf8").length;
+}
+
+/** Find the innermost node spanning exactly `[start, end)`. */
+function findNode(sourceFile, start, end) {
+  let found;
+  function visit(node) {
+    const nodeStart = node.getStart(sourceFile);
+    const nodeEnd = node.getEnd();
+    if (nodeStart > start || nodeEnd < end) {
+      return;
+    }
+    if (nodeStart === start && nodeEnd === end) {
+      found = node;
+    }
+    ts.forEachChild(node, visit);
+  }
+  ts.forEachChild(sourceFile, visit);
+  return found;
+}
+
+function isPromiseLike(node) {
+  const type = checker.getTypeAtLocation(node);
+  const types = type.isUnion() ? type.types : [type];
+  return types.some((type) => {
+    const then = checker.getPropertyOfType(type, "then");
+    if (!then) {
+      return false;
+    }
+    return checker.getTypeOfSymbolAtLocation(then, node).getCallSignatures().length > 0;
+  });
+}
+
+const queries = { isPromiseLike };
+
+readline.createInterface({ input: process.stdin }).on("line", (line) => {
+  let result = null;
+  try {
+    const { query, file, start, end } = JSON.parse(line);
+    const sourceFile = program.getSourceFile(file);
+    if (sourceFile && queries[query]) {
+      const node = findNode(
+        sourceFile,
+        toUtf16Offset(sourceFile.text, start),
+        toUtf16Offset(sourceFile.text, end),
+      );
+      if (node) {
+        result = queries[query](node);
+      }
+    }
+  } catch {
+    result = null;
+  }
+  send({ result });
+});
+
+send({ ready: true });
@@ -1,4 +1,8 @@
-use oxc_linter::rule::{RuleCategory, RuleMeta};
+use oxc_diagnostics::Severity;
+use oxc_linter::{
+    rule::{RuleCapabilities, RuleCategory, RuleMeta},
+    FixKind,
+};
 use oxc_macros::declare_oxc_lint_test;
 
 struct TestRule;
@@ -21,6 +25,27 @@ declare_oxc_lint_test!(
     correctness
 );
 
+struct TestRule3;
+
+declare_oxc_lint_test!(
+    /// Dummy description3
+    TestRule3,
+    style,
+    suggestion
+);
+
+struct TestRule4;
+
+declare_oxc_lint_test!(
+    /// Dummy description4
+    TestRule4,
+    correctness,
+    fix,
+    severity = error,
+    needs = module_graph,
+    schema = r#"[{ "type": "boolean" }]"#,
+);
+
 #[test]
 fn test_declare_oxc_lint() {
     // Simple, multiline documentation
@@ -31,4 +56,19 @@ fn test_declare_oxc_lint() {
 
     // Auto-generated kebab-case name
     assert_eq!(TestRule::NAME, "test-rule");
+
+    // Fix kind defaults to none
+    assert_eq!(TestRule::FIX, None);
+    assert_eq!(TestRule3::FIX, Some(FixKind::Suggestion));
+
+    // Severity defaults to warning, schema defaults to none
+    assert_eq!(TestRule::SEVERITY, Severity::Warning);
+    assert_eq!(TestRule::schema(), None);
+    assert_eq!(TestRule4::FIX, Some(FixKind::Safe));
+    assert_eq!(TestRule4::SEVERITY, Severity::Error);
+    assert_eq!(TestRule4::schema(), Some(r#"[{ "type": "boolean" }]"#));
+
+    // Rules need nothing from the dependencies by default
+    assert_eq!(TestRule::CAPABILITIES, RuleCapabilities::empty());
+    assert_eq!(TestRule4::CAPABILITIES, RuleCapabilities::MODULE_GRAPH);
 }
@@ -7,7 +7,7 @@ use crate::{
 use ignore::Walk;
 use miette::{NamedSource, SourceSpan};
 use oxc_diagnostics::miette::{self};
-use oxc_linter::LintContext;
+use oxc_linter::{LintContext, RuleId};
 use oxc_query::{schema, Adapter};
 use serde::Deserialize;
 use trustfall::{execute_query, FieldValue, TransparentValue};
@@ -156,7 +156,7 @@ impl LinterPlugin {
                 }
             };
 
-            ctx.with_rule_name(""); // leave this empty as it's a static string so we can't make it at runtime, and it's not userfacing
+            ctx.with_rule(RuleId::new("", ""), None); // leave this empty as it's a static string so we can't make it at runtime, and it's not userfacing
 
             for plugin_diagnostic in transformed_data_to_span {
                 let error = ErrorFromLinterPlugin::PluginGenerated(
@@ -67,7 +67,7 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
         #(#use_stmts)*
 
         use std::time::{Instant, Duration};
-        use crate::{context::LintContext, rule::{Rule, RuleCategory, RuleMeta}, rule_timer:: RuleTimer, AstNode};
+        use crate::{context::LintContext, fixer::FixKind, rule::{Rule, RuleCapabilities, RuleCategory, RuleMeta}, rule_timer:: RuleTimer, AstNode};
         use oxc_semantic::SymbolId;
 
         #[derive(Debug, Clone)]
@@ -89,13 +89,37 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub fn fix(&self) -> Option<FixKind> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::FIX),*
+                }
+            }
+
+            pub fn severity(&self) -> oxc_diagnostics::Severity {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::SEVERITY),*
+                }
+            }
+
+            pub fn capabilities(&self) -> RuleCapabilities {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::CAPABILITIES),*
+                }
+            }
+
+            pub fn schema(&self) -> Option<&'static str> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::schema()),*
+                }
+            }
+
             pub fn documentation(&self) -> Option<&'static str> {
                 match self {
                     #(Self::#struct_names(_) => #struct_names::documentation()),*
                 }
             }
 
-            pub fn plugin_name(&self) -> &str {
+            pub fn plugin_name(&self) -> &'static str {
                 match self {
                     #(Self::#struct_names(_) => #mod_names),*
                 }
@@ -9,6 +9,10 @@ use syn::{
 pub struct LintRuleMeta {
     name: Ident,
     category: Ident,
+    fix: Option<Ident>,
+    severity: Option<Ident>,
+    needs: Option<Ident>,
+    schema: Option<LitStr>,
     documentation: String,
     pub used_in_test: bool,
 }
@@ -34,25 +38,101 @@ impl Parse for LintRuleMeta {
         input.parse::<Token!(,)>()?;
         let category = input.parse()?;
 
-        // Ignore the rest
-        input.parse::<TokenStream>()?;
+        // Optional fix kind and `key = value` metadata in any order,
+        // e.g. `NoDebugger, correctness, fix, severity = error, needs = module_graph, schema = "[]"`
+        let mut fix = None;
+        let mut severity = None;
+        let mut needs = None;
+        let mut schema = None;
+        while input.peek(Token!(,)) {
+            input.parse::<Token!(,)>()?;
+            if input.is_empty() {
+                break;
+            }
+            let ident: Ident = input.parse()?;
+            if !input.peek(Token!(=)) {
+                fix = Some(ident);
+                continue;
+            }
+            input.parse::<Token!(=)>()?;
+            match ident.to_string().as_str() {
+                "severity" => severity = Some(input.parse()?),
+                "needs" => needs = Some(input.parse()?),
+                "schema" => schema = Some(input.parse()?),
+                _ => return Err(Error::new_spanned(ident, "unexpected metadata")),
+            }
+        }
 
-        Ok(Self { name: struct_name, category, documentation, used_in_test: false })
+        Ok(Self {
+            name: struct_name,
+            category,
+            fix,
+            severity,
+            needs,
+            schema,
+            documentation,
+            used_in_test: false,
+        })
     }
 }
 
 pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
-    let LintRuleMeta { name, category, documentation, used_in_test } = metadata;
+    let LintRuleMeta { name, category, fix, severity, needs, schema, documentation, used_in_test } =
+        metadata;
     let canonical_name = name.to_string().to_case(Case::Kebab);
     let category = match category.to_string().as_str() {
         "correctness" => quote! { RuleCategory::Correctness },
         "suspicious" => quote! { RuleCategory::Suspicious },
         "pedantic" => quote! { RuleCategory::Pedantic },
+        "perf" => quote! { RuleCategory::Perf },
         "style" => quote! { RuleCategory::Style },
         "restriction" => quote! { RuleCategory::Restriction },
         "nursery" => quote! { RuleCategory::Nursery },
         _ => panic!("invalid rule category"),
     };
+    let fix_kind = if used_in_test {
+        quote! { FixKind }
+    } else {
+        quote! { crate::fixer::FixKind }
+    };
+    let fix = fix.map(|fix| {
+        let fix = match fix.to_string().as_str() {
+            "fix" => quote! { #fix_kind::Safe },
+            "suggestion" => quote! { #fix_kind::Suggestion },
+            "dangerous_fix" => quote! { #fix_kind::Unsafe },
+            _ => panic!("invalid fix kind"),
+        };
+        quote! { const FIX: Option<#fix_kind> = Some(#fix); }
+    });
+    let severity = severity.map(|severity| {
+        let severity = match severity.to_string().as_str() {
+            "advice" => quote! { Advice },
+            "warning" => quote! { Warning },
+            "error" => quote! { Error },
+            _ => panic!("invalid severity"),
+        };
+        quote! { const SEVERITY: oxc_diagnostics::Severity = oxc_diagnostics::Severity::#severity; }
+    });
+    let capabilities = if used_in_test {
+        quote! { RuleCapabilities }
+    } else {
+        quote! { crate::rule::RuleCapabilities }
+    };
+    let needs = needs.map(|needs| {
+        let needs = match needs.to_string().as_str() {
+            "module_graph" => quote! { MODULE_GRAPH },
+            "dependency_exports" => quote! { DEPENDENCY_EXPORTS },
+            _ => panic!("invalid capability"),
+        };
+        quote! { const CAPABILITIES: #capabilities = #capabilities::#needs; }
+    });
+    let schema = schema.map(|schema| {
+        quote! {
+            fn schema() -> Option<&'static str> {
+                Some(#schema)
+            }
+        }
+    });
 
     let import_statement = if used_in_test {
         None
@@ -68,9 +148,17 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
 
             const CATEGORY: RuleCategory = #category;
 
+            #fix
+
+            #severity
+
+            #needs
+
             fn documentation() -> Option<&'static str> {
                 Some(#documentation)
             }
+
+            #schema
         }
     };
 
@@ -42,6 +42,9 @@ impl<'a> Gen for Program<'a> {
     fn gen(&self, p: &mut Printer, ctx: Context) {
         if let Some(hashbang) = &self.hashbang {
             hashbang.gen(p, ctx);
+            if !self.directives.is_empty() || !self.body.is_empty() {
+                p.print(b'\n');
+            }
         }
         for directive in &self.directives {
             directive.gen(p, ctx);
@@ -457,6 +460,8 @@ impl<'a> Gen for VariableDeclaration<'a> {
             VariableDeclarationKind::Const => b"const",
             VariableDeclarationKind::Let => b"let",
             VariableDeclarationKind::Var => b"var",
+            VariableDeclarationKind::Using => b"using",
+            VariableDeclarationKind::AwaitUsing => b"await using",
         });
         p.print(b' ');
         p.print_list(&self.declarations, ctx);
@@ -1840,6 +1845,7 @@ impl<'a> Gen for PropertyDefinition<'a> {
 
 impl<'a> Gen for AccessorProperty<'a> {
     fn gen(&self, p: &mut Printer, ctx: Context) {
+        self.decorators.gen(p, ctx);
         if self.r#static {
             p.print_str(b"static ");
         }
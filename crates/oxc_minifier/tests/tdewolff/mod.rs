@@ -5,6 +5,7 @@ use crate::test;
 #[test]
 fn tdewolff() {
     test("#!shebang", "#!shebang");
+    test("#!shebang\nfoo()", "#!shebang\nfoo()");
     test("/*comment*/a", "a");
     // expect("/*!comment*/a", "/*!comment*/a");
     // expect("//!comment1\n\n//!comment2\na", "//!comment1\n//!comment2\na");
@@ -38,3 +38,47 @@ export function parseSync(sourceText: string, options?: ParserOptions | undefine
  * * Tokio crashes
  */
 export function parseAsync(sourceText: string, options?: ParserOptions | undefined | null): Promise<ParseResult>
+/** Linter Options */
+export interface LintOptions {
+  /** The file name decides the source type, e.g. `.tsx`, defaults to `index.js` */
+  sourceFilename?: string
+  /**
+   * The levels of categories and rules, in the format of the `--config` file of the CLI.
+   * The `correctness` category is enabled by default.
+   */
+  config?: { categories?: Record<string, 'allow' | 'warn' | 'deny'>, rules?: Record<string, unknown> }
+  /** Compute fixes, including suggestions and dangerous fixes */
+  fix?: boolean
+}
+export interface LintDiagnostic {
+  /** `plugin/rule`, e.g. `eslint/no-debugger`, undefined for syntax errors */
+  rule?: string
+  severity: 'advice' | 'warning' | 'error'
+  message: string
+  help?: string
+  /** UTF-8 byte offsets */
+  start: number
+  end: number
+  fix?: LintFix
+}
+export interface LintFix {
+  kind: 'safe' | 'suggestion' | 'unsafe'
+  start: number
+  end: number
+  content: string
+}
+/**
+ * # Errors
+ *
+ * * The config is invalid
+ */
+export function lint(sourceText: string, options?: LintOptions | undefined | null): Array<LintDiagnostic>
+/**
+ * Resolve `request` from `directory` like Node.js `require.resolve`,
+ * with the `node` and `import` conditions and the extensions of JavaScript and TypeScript.
+ *
+ * # Errors
+ *
+ * * The request cannot be resolved
+ */
+export function resolveSync(directory: string, request: string): string
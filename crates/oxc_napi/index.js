@@ -252,8 +252,10 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { parseWithoutReturn, parseSync, parseAsync } = nativeBinding
+const { parseWithoutReturn, parseSync, parseAsync, lint, resolveSync } = nativeBinding
 
 module.exports.parseWithoutReturn = parseWithoutReturn
 module.exports.parseSync = parseSync
 module.exports.parseAsync = parseAsync
+module.exports.lint = lint
+module.exports.resolveSync = resolveSync
@@ -1,13 +1,16 @@
 #![allow(clippy::trailing_empty_array)]
 
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 use miette::NamedSource;
+use napi::{Error, Result};
 use napi_derive::napi;
 use oxc_allocator::Allocator;
 pub use oxc_ast::ast::Program;
+use oxc_linter::{LintConfig, Linter};
 use oxc_parser::{Parser, ParserReturn};
-use oxc_span::SourceType;
+use oxc_resolver::Resolver;
+use oxc_span::{SourceType, VALID_EXTENSIONS};
 
 /// Babel Parser Options
 ///
@@ -95,3 +98,103 @@ pub fn parse_sync(source_text: String, options: Option<ParserOptions>) -> ParseR
 pub async fn parse_async(source_text: String, options: Option<ParserOptions>) -> ParseResult {
     tokio::spawn(async move { parse_sync(source_text, options) }).await.unwrap()
 }
+
+/// Linter Options
+#[napi(object)]
+#[derive(Default)]
+pub struct LintOptions {
+    /// The file name decides the source type, e.g. `.tsx`, defaults to `index.js`
+    pub source_filename: Option<String>,
+    /// The levels of categories and rules, in the format of the `--config` file of the CLI.
+    /// The `correctness` category is enabled by default.
+    #[napi(
+        ts_type = "{ categories?: Record<string, 'allow' | 'warn' | 'deny'>, rules?: Record<string, unknown> }"
+    )]
+    pub config: Option<serde_json::Value>,
+    /// Compute fixes, including suggestions and dangerous fixes
+    pub fix: Option<bool>,
+}
+
+#[napi(object)]
+pub struct LintDiagnostic {
+    /// `plugin/rule`, e.g. `eslint/no-debugger`, undefined for syntax errors
+    pub rule: Option<String>,
+    #[napi(ts_type = "'advice' | 'warning' | 'error'")]
+    pub severity: String,
+    pub message: String,
+    pub help: Option<String>,
+    /// UTF-8 byte offsets
+    pub start: u32,
+    pub end: u32,
+    pub fix: Option<LintFix>,
+}
+
+#[napi(object)]
+pub struct LintFix {
+    #[napi(ts_type = "'safe' | 'suggestion' | 'unsafe'")]
+    pub kind: String,
+    pub start: u32,
+    pub end: u32,
+    pub content: String,
+}
+
+/// # Errors
+///
+/// * The config is invalid
+#[allow(clippy::needless_pass_by_value)]
+#[napi]
+pub fn lint(source_text: String, options: Option<LintOptions>) -> Result<Vec<LintDiagnostic>> {
+    let options = options.unwrap_or_default();
+    let config = options
+        .config
+        .as_ref()
+        .map(LintConfig::from_value)
+        .transpose()
+        .map_err(Error::from_reason)?;
+    let fix = options.fix.unwrap_or_default();
+    let lint_options = oxc_linter::LintOptions::default()
+        .with_config(config)
+        .with_fix(fix)
+        .with_fix_suggestions(fix)
+        .with_fix_dangerously(fix);
+    let path = options.source_filename.unwrap_or_else(|| "index.js".into());
+
+    let diagnostics = Linter::lint_source(Path::new(&path), &source_text, lint_options);
+    Ok(diagnostics
+        .into_iter()
+        .map(|diagnostic| LintDiagnostic {
+            rule: diagnostic.rule.map(|rule| rule.to_string()),
+            severity: format!("{:?}", diagnostic.severity).to_lowercase(),
+            message: diagnostic.message,
+            help: diagnostic.help,
+            start: diagnostic.span.start,
+            end: diagnostic.span.end,
+            fix: diagnostic.fix.map(|fix| LintFix {
+                kind: fix.kind.as_str().into(),
+                start: fix.span.start,
+                end: fix.span.end,
+                content: fix.content,
+            }),
+        })
+        .collect())
+}
+
+/// Resolve `request` from `directory` like Node.js `require.resolve`,
+/// with the `node` and `import` conditions and the extensions of JavaScript and TypeScript.
+///
+/// # Errors
+///
+/// * The request cannot be resolved
+#[allow(clippy::needless_pass_by_value)]
+#[napi]
+pub fn resolve_sync(directory: String, request: String) -> Result<String> {
+    let resolver = Resolver::new(oxc_resolver::ResolveOptions {
+        condition_names: vec!["node".into(), "import".into()],
+        extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
+        ..oxc_resolver::ResolveOptions::default()
+    });
+    resolver
+        .resolve(directory, &request)
+        .map(|resolution| resolution.full_path().to_string_lossy().into_owned())
+        .map_err(|error| Error::from_reason(error.to_string()))
+}
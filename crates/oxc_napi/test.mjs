@@ -12,6 +12,14 @@ function test(ret) {
 
 test(oxc.parseSync("foo"));
 
+const diagnostics = oxc.lint("debugger;", { fix: true });
+console.log(diagnostics);
+assert(diagnostics.length == 1);
+assert(diagnostics[0].rule == "eslint/no-debugger");
+assert(diagnostics[0].fix.content == "");
+
+assert(oxc.resolveSync(process.cwd(), "./index.js").endsWith("index.js"));
+
 async function main() {
   test(await oxc.parseAsync("foo"));
 }
@@ -88,6 +88,16 @@ pub struct InvalidDestrucuringDeclaration(#[label] pub Span);
 #[diagnostic()]
 pub struct MissinginitializerInConst(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Missing initializer in using declaration")]
+#[diagnostic()]
+pub struct MissingInitializerInUsing(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Using declarations may not have binding patterns")]
+#[diagnostic()]
+pub struct UsingDeclarationPattern(#[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Lexical declaration cannot appear in a single-statement context")]
 #[diagnostic(help("Wrap this declaration in a block statement"))]
@@ -281,3 +291,8 @@ pub struct ReturnStatementOnlyInFunctionBody(#[label] pub Span);
 #[error("TS18007: JSX expressions may not use the comma operator.")]
 #[diagnostic(help("Did you mean to write an array?"))]
 pub struct JSXExpressionsMayNotUseTheCommaOperator(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Decorators are not valid here")]
+#[diagnostic(help("Parameter decorators are only supported by legacy decorators"))]
+pub struct ParameterDecorators(#[label] pub Span);
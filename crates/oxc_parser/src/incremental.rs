@@ -0,0 +1,182 @@
+//! Incremental reparsing for editors
+
+use oxc_allocator::Vec;
+use oxc_ast::ast::{Declaration, Directive, Hashbang, Program, Statement};
+use oxc_diagnostics::Result;
+use oxc_span::{GetSpan, Span};
+
+use crate::{Parser, ParserReturn};
+
+/// A replacement of `span` in the previous source text with `text`
+#[derive(Debug, Clone, Copy)]
+pub struct TextEdit<'e> {
+    pub span: Span,
+    pub text: &'e str,
+}
+
+impl<'a> Parser<'a> {
+    /// Parse the source text resulting from applying `edit` to the source text of `old`,
+    /// reusing the top-level statements of `old` before the edit.
+    ///
+    /// Parsing resumes at the first statement that may be affected by the edit,
+    /// so an edit near the end of a large file only parses the end of the file again.
+    ///
+    /// Falls back to [Parser::parse] when nothing can be reused, e.g. when `old` has errors,
+    /// the edit is inside the directive prologue, or the source text does not match the edit,
+    /// and when tokens are collected with [Parser::with_tokens].
+    pub fn reparse(mut self, old: ParserReturn<'a>, edit: &TextEdit) -> ParserReturn<'a> {
+        let Some(reused) = self.reusable_statements(&old, edit) else {
+            return self.parse();
+        };
+
+        let ParserReturn { program, mut trivias, .. } = old;
+        let Program { directives, hashbang, mut body, .. } = program;
+        body.truncate(reused);
+        let resume = body[reused - 1].span().end;
+        trivias.truncate(resume);
+        self.lexer.seek(resume, trivias);
+
+        let program = self.reparse_program(directives, hashbang, body);
+        self.finish(program)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn reparse_program(
+        &mut self,
+        directives: Vec<'a, Directive>,
+        hashbang: Option<Hashbang>,
+        statements: Vec<'a, Statement<'a>>,
+    ) -> Result<Program<'a>> {
+        self.bump_any();
+        let (_, statements) = self.parse_directives_and_statements_with(
+            /* is_top_level */ true, statements, /* expecting_diretives */ false,
+        )?;
+
+        let span = Span::new(0, self.source_text.len() as u32);
+        Ok(self.ast.program(span, self.source_type, directives, hashbang, statements))
+    }
+
+    /// Number of top-level statements of `old` that are not affected by `edit`
+    fn reusable_statements(&self, old: &ParserReturn<'a>, edit: &TextEdit) -> Option<usize> {
+        let program = &old.program;
+        let old_len = program.span.end as usize;
+        if !old.errors.is_empty()
+            || old.panicked
+            || self.tokens.is_some()
+            || edit.span.start > edit.span.end
+            || edit.span.end as usize > old_len
+            || old_len - edit.span.size() as usize + edit.text.len() != self.source_text.len()
+            || program.directives.last().is_some_and(|d| d.span.end >= edit.span.start)
+        {
+            return None;
+        }
+
+        // The source text before the edit is unchanged.
+        let mut reused =
+            program.body.iter().take_while(|stmt| stmt.span().end < edit.span.start).count();
+        while reused > 0 && !is_closed(&program.body[reused - 1], self.source_text) {
+            reused -= 1;
+        }
+        (reused > 0).then_some(reused)
+    }
+}
+
+/// Whether the statement cannot be continued by the source text that follows it,
+/// e.g. `a` is continued by `(b)` as a call and `if (a) b;` is continued by `else c`.
+fn is_closed(stmt: &Statement, source_text: &str) -> bool {
+    let ends_with_semicolon = source_text[..stmt.span().end as usize].ends_with(';');
+    match stmt {
+        Statement::ExpressionStatement(_)
+        | Statement::EmptyStatement(_)
+        | Statement::DebuggerStatement(_)
+        | Statement::BreakStatement(_)
+        | Statement::ContinueStatement(_)
+        | Statement::ReturnStatement(_)
+        | Statement::ThrowStatement(_)
+        | Statement::ModuleDeclaration(_) => ends_with_semicolon,
+        Statement::Declaration(decl) => match decl {
+            Declaration::VariableDeclaration(_) | Declaration::TSTypeAliasDeclaration(_) => {
+                ends_with_semicolon
+            }
+            Declaration::FunctionDeclaration(_)
+            | Declaration::ClassDeclaration(_)
+            | Declaration::TSInterfaceDeclaration(_)
+            | Declaration::TSEnumDeclaration(_) => true,
+            Declaration::TSModuleDeclaration(_) | Declaration::TSImportEqualsDeclaration(_) => {
+                false
+            }
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_span::{SourceType, Span};
+
+    use super::TextEdit;
+    use crate::Parser;
+
+    /// Apply `edit` to `source_text` and check that reparsing gives the same result as parsing.
+    fn check(source_text: &str, start: u32, end: u32, text: &str, source_type: SourceType) {
+        let allocator = Allocator::default();
+        let edit = TextEdit { span: Span::new(start, end), text };
+        let mut new_source_text = source_text.to_string();
+        new_source_text.replace_range(start as usize..end as usize, text);
+
+        let old = Parser::new(&allocator, source_text, source_type).parse();
+        let reparsed = Parser::new(&allocator, &new_source_text, source_type).reparse(old, &edit);
+        let parsed = Parser::new(&allocator, &new_source_text, source_type).parse();
+
+        assert_eq!(
+            serde_json::to_string(&reparsed.program).unwrap(),
+            serde_json::to_string(&parsed.program).unwrap(),
+            "{new_source_text:?}"
+        );
+        assert_eq!(reparsed.errors.len(), parsed.errors.len(), "{new_source_text:?}");
+        assert_eq!(
+            reparsed.trivias.comments().keys().collect::<Vec<_>>(),
+            parsed.trivias.comments().keys().collect::<Vec<_>>(),
+            "{new_source_text:?}"
+        );
+    }
+
+    #[test]
+    fn reparse() {
+        let source_type = SourceType::default();
+        let source_text = "'use strict';\n// a\nlet a = 1;\nfunction f() {}\n/* b */ b();\nc\n";
+        let len = u32::try_from(source_text.len()).unwrap();
+        // insert, replace and delete after the reused statements
+        check(source_text, 60, 60, "d", source_type);
+        check(source_text, 59, 60, "e + f", source_type);
+        check(source_text, 46, 58, "", source_type);
+        check(source_text, len, len, "g;", source_type);
+        // errors after the edit
+        check(source_text, 60, 60, "(", source_type);
+        // nothing to reuse
+        check(source_text, 0, 0, "'use client';\n", source_type);
+        check(source_text, 20, 21, "x", source_type);
+    }
+
+    #[test]
+    fn continued_statements() {
+        let source_type = SourceType::default();
+        // the previous statement is continued by the edit
+        check("a\n(b)", 2, 3, "[", source_type);
+        check("a\nb", 2, 3, "(b)", source_type);
+        check("if (a) b;\nc", 10, 11, "else c", source_type);
+        check("export { a }\nb", 13, 14, "from 'b'", source_type.with_module(true));
+    }
+
+    #[test]
+    fn mismatched_edit() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let old = Parser::new(&allocator, "a;\nb;", source_type).parse();
+        let edit = TextEdit { span: Span::new(3, 4), text: "cc" };
+        let ret = Parser::new(&allocator, "a;\nc;", source_type).reparse(old, &edit);
+        assert_eq!(ret.program.body.len(), 2);
+        assert!(ret.errors.is_empty());
+    }
+}
@@ -466,6 +466,14 @@ impl<'a> Parser<'a> {
     ) -> Result<ClassElement<'a>> {
         let value =
             self.eat(Kind::Eq).then(|| self.parse_assignment_expression_base()).transpose()?;
-        Ok(self.ast.accessor_property(self.end_span(span), key, value, computed, r#static))
+        let decorators = self.state.consume_decorators();
+        Ok(self.ast.accessor_property(
+            self.end_span(span),
+            key,
+            value,
+            computed,
+            r#static,
+            decorators,
+        ))
     }
 }
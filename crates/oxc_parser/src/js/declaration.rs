@@ -42,6 +42,29 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// `using` [no `LineTerminator` here] `BindingList`
+    /// <https://github.com/tc39/proposal-explicit-resource-management>
+    pub(crate) fn is_using_declaration(&mut self) -> bool {
+        if !self.at(Kind::Using) || self.cur_token().escaped {
+            return false;
+        }
+        let token = self.peek_token();
+        token.kind.is_binding_identifier() && !token.is_on_new_line
+    }
+
+    /// `await` [no `LineTerminator` here] `using` [no `LineTerminator` here] `BindingList`
+    pub(crate) fn is_await_using_declaration(&mut self) -> bool {
+        if !self.ctx.has_await() || !self.at(Kind::Await) {
+            return false;
+        }
+        let token = self.peek_token();
+        if token.kind != Kind::Using || token.escaped || token.is_on_new_line {
+            return false;
+        }
+        let token = self.nth(2);
+        token.kind.is_binding_identifier() && !token.is_on_new_line
+    }
+
     pub(crate) fn parse_variable_declaration(
         &mut self,
         start_span: Span,
@@ -52,6 +75,11 @@ impl<'a> Parser<'a> {
             Kind::Var => VariableDeclarationKind::Var,
             Kind::Const => VariableDeclarationKind::Const,
             Kind::Let => VariableDeclarationKind::Let,
+            Kind::Using => VariableDeclarationKind::Using,
+            Kind::Await => {
+                self.bump_any(); // bump `await`
+                VariableDeclarationKind::AwaitUsing
+            }
             _ => return Err(self.unexpected()),
         };
         self.bump_any();
@@ -87,7 +115,9 @@ impl<'a> Parser<'a> {
         let init =
             self.eat(Kind::Eq).then(|| self.parse_assignment_expression_base()).transpose()?;
 
-        if init.is_none() && decl_ctx.parent == VariableDeclarationParent::Statement {
+        if kind.is_using() && !matches!(id.kind, BindingPatternKind::BindingIdentifier(_)) {
+            self.error(diagnostics::UsingDeclarationPattern(id.span()));
+        } else if init.is_none() && decl_ctx.parent == VariableDeclarationParent::Statement {
             // LexicalBinding[In, Yield, Await] :
             //   BindingIdentifier[?Yield, ?Await] Initializer[?In, ?Yield, ?Await] opt
             //   BindingPattern[?Yield, ?Await] Initializer[?In, ?Yield, ?Await]
@@ -97,6 +127,8 @@ impl<'a> Parser<'a> {
             } else if kind == VariableDeclarationKind::Const && !self.ctx.has_ambient() {
                 // It is a Syntax Error if Initializer is not present and IsConstantDeclaration of the LexicalDeclaration containing this LexicalBinding is true.
                 self.error(diagnostics::MissinginitializerInConst(id.span()));
+            } else if kind.is_using() {
+                self.error(diagnostics::MissingInitializerInUsing(id.span()));
             }
         }
 
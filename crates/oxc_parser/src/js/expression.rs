@@ -907,6 +907,7 @@ impl<'a> Parser<'a> {
                 continue;
             }
 
+            let operator_span = self.cur_token().span();
             self.bump_any(); // bump operator
             let rhs = self.parse_binary_or_logical_expression_base(left_precedence)?;
 
@@ -915,6 +916,7 @@ impl<'a> Parser<'a> {
                     self.end_span(lhs_span),
                     lhs,
                     map_logical_operator(kind),
+                    operator_span,
                     rhs,
                 )
             } else if kind.is_binary_operator() {
@@ -922,6 +924,7 @@ impl<'a> Parser<'a> {
                     self.end_span(lhs_span),
                     lhs,
                     map_binary_operator(kind),
+                    operator_span,
                     rhs,
                 )
             } else {
@@ -1029,10 +1032,17 @@ impl<'a> Parser<'a> {
         //    ArrayAssignmentPattern
         let left = AssignmentTarget::cover(lhs, self)?;
 
+        let operator_span = self.cur_token().span();
         self.bump_any();
 
         let right = self.parse_assignment_expression_base()?;
-        Ok(self.ast.assignment_expression(self.end_span(span), operator, left, right))
+        Ok(self.ast.assignment_expression(
+            self.end_span(span),
+            operator,
+            operator_span,
+            left,
+            right,
+        ))
     }
 
     /// Section 13.16 Sequence Expression
@@ -1075,7 +1085,16 @@ impl<'a> Parser<'a> {
             self.parse_paren_expression()?
         } else {
             let lhs = self.parse_decorator_member_expression()?;
-            self.parse_decorator_call_expression(span, lhs)?
+            let mut expr = self.parse_decorator_call_expression(span, lhs)?;
+            // Legacy decorators continue with member accesses and calls, e.g. `@a().b()`
+            while self.legacy_decorators && matches!(self.cur_kind(), Kind::Dot | Kind::LParen) {
+                expr = if self.at(Kind::Dot) {
+                    self.parse_static_member_expression(span, expr, false)?
+                } else {
+                    self.parse_call_arguments(span, expr, false, None)?
+                };
+            }
+            expr
         };
         Ok(self.ast.decorator(self.end_span(span), expr))
     }
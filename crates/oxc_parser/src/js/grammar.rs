@@ -33,7 +33,6 @@ impl<'a> CoverGrammar<'a, Expression<'a>> for AssignmentTarget<'a> {
 }
 
 impl<'a> CoverGrammar<'a, Expression<'a>> for SimpleAssignmentTarget<'a> {
-    #[allow(clippy::only_used_in_recursion)]
     fn cover(expr: Expression<'a>, p: &mut Parser<'a>) -> Result<Self> {
         match expr {
             Expression::Identifier(ident) => {
@@ -44,6 +43,7 @@ impl<'a> CoverGrammar<'a, Expression<'a>> for SimpleAssignmentTarget<'a> {
             }
             Expression::ParenthesizedExpression(expr) => {
                 let span = expr.span;
+                p.lexer.trivia_builder.add_parenthesized_target(span);
                 match expr.unbox().expression {
                     Expression::ObjectExpression(_) | Expression::ArrayExpression(_) => {
                         Err(diagnostics::InvalidAssignment(span).into())
@@ -253,6 +253,11 @@ impl<'a> SeparatedList<'a> for FormalParameterList<'a> {
     fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
         let span = p.start_span();
         p.eat_decorators()?;
+        if !p.legacy_decorators {
+            if let Some(decorator) = p.state.decorators.first() {
+                p.error(diagnostics::ParameterDecorators(decorator.span));
+            }
+        }
 
         let modifiers = p.parse_class_element_modifiers(true);
         let accessibility = modifiers.accessibility();
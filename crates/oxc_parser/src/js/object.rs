@@ -105,6 +105,7 @@ impl<'a> Parser<'a> {
         // IdentifierReference ({ foo })
         let value = Expression::Identifier(self.ast.alloc(identifier.clone()));
         // CoverInitializedName ({ foo = bar })
+        let operator_span = self.cur_token().span();
         let init = if self.eat(Kind::Eq) {
             let right = self.parse_assignment_expression_base()?;
             let left = AssignmentTarget::SimpleAssignmentTarget(
@@ -113,6 +114,7 @@ impl<'a> Parser<'a> {
             Some(self.ast.assignment_expression(
                 self.end_span(span),
                 AssignmentOperator::Assign,
+                operator_span,
                 left,
                 right,
             ))
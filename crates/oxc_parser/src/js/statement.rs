@@ -33,11 +33,20 @@ impl<'a> Parser<'a> {
     pub(crate) fn parse_directives_and_statements(
         &mut self,
         is_top_level: bool,
+    ) -> Result<(Vec<'a, Directive>, Vec<'a, Statement<'a>>)> {
+        self.parse_directives_and_statements_with(is_top_level, self.ast.new_vec(), true)
+    }
+
+    /// Parse the statement list after `statements`,
+    /// a directive prologue is only parsed when `expecting_diretives` is true.
+    pub(crate) fn parse_directives_and_statements_with(
+        &mut self,
+        is_top_level: bool,
+        mut statements: Vec<'a, Statement<'a>>,
+        mut expecting_diretives: bool,
     ) -> Result<(Vec<'a, Directive>, Vec<'a, Statement<'a>>)> {
         let mut directives = self.ast.new_vec();
-        let mut statements = self.ast.new_vec();
 
-        let mut expecting_diretives = true;
         while !self.at(Kind::Eof) {
             match self.cur_kind() {
                 Kind::RCurly if !is_top_level => break,
@@ -122,6 +131,11 @@ impl<'a> Parser<'a> {
                 self.parse_variable_statement(stmt_ctx)
             }
             Kind::Let if !self.cur_token().escaped => self.parse_let(stmt_ctx),
+            Kind::Using | Kind::Await
+                if self.is_using_declaration() || self.is_await_using_declaration() =>
+            {
+                self.parse_variable_statement(stmt_ctx)
+            }
             _ if self.at_function_with_async() => self.parse_function_declaration(stmt_ctx),
             _ if self.ts_enabled() && self.at_start_of_ts_declaration() => {
                 self.parse_ts_declaration_statement(start_span)
@@ -247,11 +261,13 @@ impl<'a> Parser<'a> {
             return self.parse_for_loop(span, None, r#await);
         }
 
-        // for (let | for (const | for (var
-        // disallow for (let in ..)
+        // for (let | for (const | for (var | for (using | for (await using
+        // disallow for (let in ..) and for (using of ..)
         if self.at(Kind::Const)
             || self.at(Kind::Var)
             || (self.at(Kind::Let) && self.peek_kind().is_after_let())
+            || (self.is_using_declaration() && !self.peek_at(Kind::Of))
+            || self.is_await_using_declaration()
         {
             let start_span = self.start_span();
             let init_declaration = self.without_context(Context::In, |p| {
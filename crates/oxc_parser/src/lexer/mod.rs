@@ -14,7 +14,7 @@ mod trivia_builder;
 use std::{collections::VecDeque, str::Chars};
 
 use oxc_allocator::{Allocator, String};
-use oxc_ast::ast::RegExpFlags;
+use oxc_ast::{ast::RegExpFlags, Trivias};
 use oxc_diagnostics::Error;
 use oxc_span::{SourceType, Span};
 use oxc_syntax::{
@@ -112,6 +112,14 @@ impl<'a> Lexer<'a> {
         self.lookahead.clear();
     }
 
+    /// Continue lexing from `offset` with the comments before it already collected in `trivias`,
+    /// for reparsing the source text after the reused part of a program.
+    pub fn seek(&mut self, offset: u32, trivias: Trivias) {
+        self.current.chars = self.source[offset as usize..].chars();
+        self.lookahead.clear();
+        self.trivia_builder = TriviaBuilder::new(trivias);
+    }
+
     /// Find the nth lookahead token lazily
     pub fn lookahead(&mut self, n: u8) -> &Token<'a> {
         let n = n as usize;
@@ -406,16 +414,20 @@ impl<'a> Lexer<'a> {
     }
 
     /// Section 12.5 Hashbang Comments
+    /// The line terminator is not part of the comment.
     fn read_hashbang_comment(&mut self) -> Kind {
-        while let Some(c) = self.current.chars.next().as_ref() {
-            if is_line_terminator(*c) {
-                break;
-            }
+        while self.peek().is_some_and(|c| !is_line_terminator(c)) {
+            self.current.chars.next();
         }
-        self.current.token.is_on_new_line = true;
         Kind::HashbangComment
     }
 
+    /// A hashbang comment may only appear at the very start of the source, after the BOM
+    fn is_at_hashbang_position(&self) -> bool {
+        let start = if self.source.starts_with('\u{feff}') { '\u{feff}'.len_utf8() } else { 0 };
+        self.current.token.start as usize == start
+    }
+
     /// Section 12.6.1 Identifier Names
     fn identifier_tail(&mut self, mut builder: AutoCow<'a>) -> (bool, &'a str) {
         // ident tail
@@ -1355,7 +1367,7 @@ const HAS: ByteHandler = |lexer| {
     builder.push_matching(c);
     // HashbangComment ::
     //     `#!` SingleLineCommentChars?
-    if lexer.current.token.start == 0 && lexer.next_eq('!') {
+    if lexer.is_at_hashbang_position() && lexer.next_eq('!') {
         lexer.read_hashbang_comment()
     } else {
         builder.get_mut_string_without_current_ascii_char(lexer);
@@ -1791,6 +1803,7 @@ const L_U: ByteHandler = |lexer| match &lexer.identifier_name_handler()[1..] {
     "ndefined" => Kind::Undefined,
     "nique" => Kind::Unique,
     "nknown" => Kind::Unknown,
+    "sing" => Kind::Using,
     _ => Kind::Ident,
 };
 
@@ -7,6 +7,10 @@ pub struct TriviaBuilder {
 }
 
 impl TriviaBuilder {
+    pub fn new(trivias: Trivias) -> Self {
+        Self { trivias }
+    }
+
     pub fn build(self) -> Trivias {
         self.trivias
     }
@@ -16,6 +20,10 @@ impl TriviaBuilder {
         self.trivias.add_single_line_comment(Span::new(start + 2, end));
     }
 
+    pub fn add_parenthesized_target(&mut self, span: Span) {
+        self.trivias.add_parenthesized_target(span);
+    }
+
     pub fn add_multi_line_comment(&mut self, start: u32, end: u32) {
         // skip leading `/*` and trailing `*/`
         self.trivias.add_multi_line_comment(Span::new(start + 2, end - 2));
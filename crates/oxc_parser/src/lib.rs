@@ -71,7 +71,9 @@ mod jsx;
 mod ts;
 
 mod diagnostics;
+mod incremental;
 mod lexer;
+mod tokens;
 
 use context::{Context, StatementContext};
 use oxc_allocator::Allocator;
@@ -79,8 +81,13 @@ use oxc_ast::{ast::Program, AstBuilder, Trivias};
 use oxc_diagnostics::{Error, Result};
 use oxc_span::{ModuleKind, SourceType, Span};
 
+pub use crate::{
+    incremental::TextEdit,
+    lexer::Kind,
+    tokens::{lex, Token},
+};
 use crate::{
-    lexer::{Kind, Lexer, Token},
+    lexer::{Lexer, Token as LexerToken},
     state::ParserState,
 };
 
@@ -93,6 +100,8 @@ pub struct ParserReturn<'a> {
     pub program: Program<'a>,
     pub errors: Vec<Error>,
     pub trivias: Trivias,
+    /// Consumed tokens when [Parser::with_tokens] is enabled, empty otherwise
+    pub tokens: Vec<Token>,
     pub panicked: bool,
 }
 
@@ -113,7 +122,7 @@ pub struct Parser<'a> {
     errors: Vec<Error>,
 
     /// The current parsing token
-    token: Token<'a>,
+    token: LexerToken<'a>,
 
     /// The end range of the previous token
     prev_token_end: u32,
@@ -126,6 +135,12 @@ pub struct Parser<'a> {
 
     /// Ast builder for creating AST spans
     ast: AstBuilder<'a>,
+
+    /// Consumed tokens, only collected when requested
+    tokens: Option<Vec<Token>>,
+
+    /// Accept legacy (experimental) decorators, see [Parser::allow_legacy_decorators]
+    legacy_decorators: bool,
 }
 
 impl<'a> Parser<'a> {
@@ -136,11 +151,13 @@ impl<'a> Parser<'a> {
             source_type,
             source_text,
             errors: vec![],
-            token: Token::default(),
+            token: LexerToken::default(),
             prev_token_end: 0,
             state: ParserState::new(allocator),
             ctx: Self::default_context(source_type),
             ast: AstBuilder::new(allocator),
+            tokens: None,
+            legacy_decorators: source_type.is_typescript(),
         }
     }
 
@@ -154,12 +171,36 @@ impl<'a> Parser<'a> {
         self
     }
 
+    /// Allow legacy decorators
+    ///
+    /// Legacy (experimental) decorators, as in TypeScript's `experimentalDecorators` and
+    /// Babel's `decorators-legacy`, accept parameter decorators and decorator expressions
+    /// such as `@a().b`, which are errors with TC39 stage 3 decorators.
+    /// Enabled by default for TypeScript.
+    #[must_use]
+    pub fn allow_legacy_decorators(mut self, allow: bool) -> Self {
+        self.legacy_decorators = allow;
+        self
+    }
+
+    /// Collect the tokens consumed by the parser into [ParserReturn::tokens]
+    #[must_use]
+    pub fn with_tokens(mut self, yes: bool) -> Self {
+        self.tokens = yes.then(Vec::new);
+        self
+    }
+
     /// Main entry point
     ///
     /// Returns an empty `Program` on unrecoverable error,
     /// Recoverable errors are stored inside `errors`.
     pub fn parse(mut self) -> ParserReturn<'a> {
-        let (program, panicked) = match self.parse_program() {
+        let program = self.parse_program();
+        self.finish(program)
+    }
+
+    fn finish(mut self, program: Result<Program<'a>>) -> ParserReturn<'a> {
+        let (program, panicked) = match program {
             Ok(program) => (program, false),
             Err(error) => {
                 self.error(self.flow_error().unwrap_or(error));
@@ -175,7 +216,8 @@ impl<'a> Parser<'a> {
         };
         let errors = self.lexer.errors.into_iter().chain(self.errors).collect();
         let trivias = self.lexer.trivia_builder.build();
-        ParserReturn { program, errors, trivias, panicked }
+        let tokens = self.tokens.unwrap_or_default();
+        ParserReturn { program, errors, trivias, tokens, panicked }
     }
 
     #[allow(clippy::cast_possible_truncation)]
@@ -239,6 +281,11 @@ impl<'a> Parser<'a> {
 #[cfg(test)]
 mod test {
 
+    use oxc_ast::ast::{
+        ClassElement, Declaration, Expression, ForStatementInit, ForStatementLeft, Statement,
+        VariableDeclarationKind,
+    };
+
     use super::*;
 
     #[test]
@@ -265,4 +312,149 @@ mod test {
         assert!(ret.program.is_empty());
         assert_eq!(ret.errors.first().unwrap().to_string(), "Flow is not supported");
     }
+
+    #[test]
+    fn hashbang() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        for source in [
+            "#!/usr/bin/env node\nfoo",
+            "#!/usr/bin/env node\r\nfoo",
+            "\u{feff}#!/usr/bin/env node\nfoo",
+        ] {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source:?}");
+            let hashbang = ret.program.hashbang.unwrap();
+            assert_eq!(hashbang.value, "/usr/bin/env node");
+            assert_eq!(
+                &source[hashbang.span.start as usize..hashbang.span.end as usize],
+                "#!/usr/bin/env node"
+            );
+            assert_eq!(ret.program.body.len(), 1);
+        }
+    }
+
+    #[test]
+    fn operator_span() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "a = b /* + */ + c || d; ({ e = f } = g)";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let operators = ret
+            .program
+            .body
+            .iter()
+            .flat_map(|stmt| {
+                let Statement::ExpressionStatement(stmt) = stmt else { unreachable!() };
+                let mut expr = &stmt.expression;
+                let mut spans = vec![];
+                loop {
+                    expr = match expr {
+                        Expression::AssignmentExpression(e) => {
+                            spans.push(e.operator_span);
+                            &e.right
+                        }
+                        Expression::LogicalExpression(e) => {
+                            spans.push(e.operator_span);
+                            &e.left
+                        }
+                        Expression::BinaryExpression(e) => {
+                            spans.push(e.operator_span);
+                            &e.left
+                        }
+                        Expression::ParenthesizedExpression(e) => &e.expression,
+                        _ => break,
+                    };
+                }
+                spans
+            })
+            .map(|span| &source[span.start as usize..span.end as usize])
+            .collect::<std::vec::Vec<_>>();
+        assert_eq!(operators, vec!["=", "||", "+", "="]);
+    }
+
+    #[test]
+    fn parenthesized_targets() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "(a) = 1; [(b), c] = d; ((e.f)) += 2; (g = 3);";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+        let parens = ret
+            .trivias
+            .parenthesized_targets()
+            .map(|span| &source[span.start as usize..span.end as usize])
+            .collect::<std::vec::Vec<_>>();
+        assert_eq!(parens, vec!["(a)", "(b)", "((e.f))", "(e.f)"]);
+        assert!(ret.trivias.is_parenthesized_target(Span::new(1, 2)));
+        assert!(!ret.trivias.is_parenthesized_target(Span::new(15, 16)));
+    }
+    #[test]
+    fn legacy_decorators() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let sources = ["class A { m(@dec b) {} }", "@a().b class A {}", "@a()() class A {}"];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(!ret.errors.is_empty(), "{source:?}");
+            let ret =
+                Parser::new(&allocator, source, source_type).allow_legacy_decorators(true).parse();
+            assert!(ret.errors.is_empty(), "{source:?}");
+            let ret = Parser::new(&allocator, source, source_type.with_typescript(true)).parse();
+            assert!(ret.errors.is_empty(), "{source:?}");
+        }
+
+        let source = "class A { @dec accessor b = 1 }";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+        let Statement::Declaration(Declaration::ClassDeclaration(class)) = &ret.program.body[0]
+        else {
+            unreachable!()
+        };
+        let ClassElement::AccessorProperty(property) = &class.body.body[0] else { unreachable!() };
+        assert_eq!(property.decorators.len(), 1);
+    }
+    #[test]
+    fn using_declarations() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let pass = [
+            ("{ using a = b, c = d; }", Some(VariableDeclarationKind::Using)),
+            ("await using a = b;", Some(VariableDeclarationKind::AwaitUsing)),
+            ("for (using a of b);", Some(VariableDeclarationKind::Using)),
+            ("for (await using a of b);", Some(VariableDeclarationKind::AwaitUsing)),
+            ("for (using a = b; ;);", Some(VariableDeclarationKind::Using)),
+            ("using\na = b", None),
+            ("using[a] = b", None),
+            ("using;", None),
+            ("for (using of b);", None),
+            ("await using;", None),
+        ];
+        for (source, kind) in pass {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source:?}");
+            let decl = match &ret.program.body[0] {
+                Statement::Declaration(Declaration::VariableDeclaration(decl)) => Some(decl),
+                Statement::BlockStatement(block) => match &block.body[0] {
+                    Statement::Declaration(Declaration::VariableDeclaration(decl)) => Some(decl),
+                    _ => None,
+                },
+                Statement::ForOfStatement(stmt) => match &stmt.left {
+                    ForStatementLeft::VariableDeclaration(decl) => Some(decl),
+                    ForStatementLeft::AssignmentTarget(_) => None,
+                },
+                Statement::ForStatement(stmt) => match &stmt.init {
+                    Some(ForStatementInit::VariableDeclaration(decl)) => Some(decl),
+                    _ => None,
+                },
+                _ => None,
+            };
+            assert_eq!(decl.map(|decl| decl.kind), kind, "{source:?}");
+        }
+
+        for source in ["{ using a; }", "{ using a = b, [c] = d; }", "{ using a = b, {c} = d; }"] {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert_eq!(ret.errors.len(), 1, "{source:?}");
+        }
+    }
 }
@@ -0,0 +1,98 @@
+//! Token stream of a source text
+
+use oxc_allocator::Allocator;
+use oxc_span::{SourceType, Span};
+
+use crate::{lexer::Kind, Parser};
+
+/// A token of the source text with its kind and position
+///
+/// Whitespace and comments are not tokens, comments are collected in [oxc_ast::Trivias].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub kind: Kind,
+    pub span: Span,
+}
+
+/// Tokenize `source_text`
+///
+/// The source text is parsed to tell tokens apart that depend on the syntactic context,
+/// e.g. `/` starting a regular expression or dividing, `>>` closing type arguments,
+/// and template literal parts. Tokens after an unrecoverable syntax error are not returned.
+pub fn lex(source_text: &str, source_type: SourceType) -> Vec<Token> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).with_tokens(true).parse();
+    ret.tokens
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+
+    use super::lex;
+    use crate::Kind;
+
+    fn kinds(source_text: &str, source_type: SourceType) -> Vec<Kind> {
+        lex(source_text, source_type).into_iter().map(|token| token.kind).collect()
+    }
+
+    #[test]
+    fn tokens() {
+        let source_text = "let a = b / 2; // c\n/d/g.test(`e${f}g`)";
+        let tokens = lex(source_text, SourceType::default());
+        let texts = tokens
+            .iter()
+            .map(|token| &source_text[token.span.start as usize..token.span.end as usize])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            texts,
+            vec![
+                "let", "a", "=", "b", "/", "2", ";", "/d/g", ".", "test", "(", "`e${", "f", "}g`",
+                ")"
+            ]
+        );
+        assert_eq!(tokens[4].kind, Kind::Slash);
+        assert_eq!(tokens[7].kind, Kind::RegExp);
+        assert_eq!(tokens[11].kind, Kind::TemplateHead);
+        assert_eq!(tokens[13].kind, Kind::TemplateTail);
+    }
+
+    #[test]
+    fn context_sensitive() {
+        let source_type = SourceType::default().with_typescript(true);
+        assert_eq!(
+            kinds("let a: A<B<C>> = b >> c", source_type),
+            vec![
+                Kind::Let,
+                Kind::Ident,
+                Kind::Colon,
+                Kind::Ident,
+                Kind::LAngle,
+                Kind::Ident,
+                Kind::LAngle,
+                Kind::Ident,
+                Kind::RAngle,
+                Kind::RAngle,
+                Kind::Eq,
+                Kind::Ident,
+                Kind::ShiftRight,
+                Kind::Ident,
+            ]
+        );
+        // tokens are rewound with the parser when it backtracks
+        assert_eq!(
+            kinds("(a) => a; (a)", SourceType::default()),
+            vec![
+                Kind::LParen,
+                Kind::Ident,
+                Kind::RParen,
+                Kind::Arrow,
+                Kind::Ident,
+                Kind::Semicolon,
+                Kind::LParen,
+                Kind::Ident,
+                Kind::RParen,
+            ]
+        );
+    }
+}
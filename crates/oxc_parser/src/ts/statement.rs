@@ -253,7 +253,9 @@ impl<'a> Parser<'a> {
     ) -> Result<Box<'a, TSModuleDeclaration<'a>>> {
         let id = match self.cur_kind() {
             Kind::Str => self.parse_literal_string().map(TSModuleDeclarationName::StringLiteral),
-            _ => self.parse_identifier_name().map(TSModuleDeclarationName::Identifier),
+            _ => self.parse_identifier_name().map(|ident| {
+                TSModuleDeclarationName::Identifier(BindingIdentifier::new(ident.name, ident.span))
+            }),
         }?;
 
         let body = if self.eat(Kind::Dot) {
@@ -0,0 +1,28 @@
+from typing import Any, List, Optional
+
+class ParseResult:
+    program: str
+    errors: List[str]
+
+class LintFix:
+    kind: str
+    start: int
+    end: int
+    content: str
+
+class LintDiagnostic:
+    rule: Optional[str]
+    severity: str
+    message: str
+    help: Optional[str]
+    start: int
+    end: int
+    fix: Optional[LintFix]
+
+def parse(source_text: str, source_filename: str = "index.js") -> ParseResult: ...
+def lint(
+    source_text: str,
+    source_filename: str = "index.js",
+    config: Optional[Any] = None,
+    fix: bool = False,
+) -> List[LintDiagnostic]: ...
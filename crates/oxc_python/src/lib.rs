@@ -0,0 +1,160 @@
+// Generated by `#[pymodule]`
+#![allow(clippy::redundant_pub_crate)]
+
+use std::{path::Path, sync::Arc};
+
+use miette::NamedSource;
+use oxc_allocator::Allocator;
+use oxc_linter::{LintConfig, Linter};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+#[pyclass(get_all)]
+pub struct ParseResult {
+    /// The ESTree compatible AST as JSON
+    pub program: String,
+    pub errors: Vec<String>,
+}
+
+#[pyclass(get_all)]
+pub struct LintDiagnostic {
+    /// `plugin/rule`, e.g. `eslint/no-debugger`, `None` for syntax errors
+    pub rule: Option<String>,
+    /// `advice`, `warning` or `error`
+    pub severity: String,
+    pub message: String,
+    pub help: Option<String>,
+    /// UTF-8 byte offsets
+    pub start: u32,
+    pub end: u32,
+    pub fix: Option<LintFix>,
+}
+
+#[pyclass(get_all)]
+#[derive(Clone)]
+pub struct LintFix {
+    /// `safe`, `suggestion` or `unsafe`
+    pub kind: String,
+    pub start: u32,
+    pub end: u32,
+    pub content: String,
+}
+
+#[pymethods]
+impl LintDiagnostic {
+    fn __repr__(&self) -> String {
+        let rule = self.rule.as_ref().map_or_else(|| "None".into(), |rule| format!("{rule:?}"));
+        format!(
+            "LintDiagnostic(rule={rule}, severity={:?}, message={:?}, start={}, end={})",
+            self.severity, self.message, self.start, self.end
+        )
+    }
+}
+
+#[pymethods]
+impl LintFix {
+    fn __repr__(&self) -> String {
+        format!(
+            "LintFix(kind={:?}, start={}, end={}, content={:?})",
+            self.kind, self.start, self.end, self.content
+        )
+    }
+}
+
+fn source_type(source_filename: &str) -> PyResult<SourceType> {
+    SourceType::from_path(source_filename)
+        .map_err(|_| PyValueError::new_err(format!("Invalid file extension: {source_filename}")))
+}
+
+/// Parse `source_text`, the file name decides the source type, e.g. `.tsx`.
+///
+/// # Errors
+///
+/// * The file extension is invalid
+///
+/// # Panics
+///
+/// * Serde JSON serialization
+#[pyfunction]
+#[pyo3(signature = (source_text, source_filename = "index.js"))]
+pub fn parse(source_text: &str, source_filename: &str) -> PyResult<ParseResult> {
+    let source_type = source_type(source_filename)?;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let program = serde_json::to_string(&ret.program).unwrap();
+
+    let errors = if ret.errors.is_empty() {
+        vec![]
+    } else {
+        let source = Arc::new(NamedSource::new(source_filename, source_text.to_string()));
+        ret.errors
+            .into_iter()
+            .map(|diagnostic| diagnostic.with_source_code(Arc::clone(&source)))
+            .map(|error| format!("{error:?}"))
+            .collect()
+    };
+
+    Ok(ParseResult { program, errors })
+}
+
+/// Lint `source_text` with the rules of the `correctness` category,
+/// or with `config`, a dict in the format of the `--config` file of the CLI.
+/// `fix` computes fixes, including suggestions and dangerous fixes.
+///
+/// # Errors
+///
+/// * The file extension or the config is invalid
+#[pyfunction]
+#[pyo3(signature = (source_text, source_filename = "index.js", config = None, fix = false))]
+pub fn lint(
+    py: Python<'_>,
+    source_text: &str,
+    source_filename: &str,
+    config: Option<&PyAny>,
+    fix: bool,
+) -> PyResult<Vec<LintDiagnostic>> {
+    source_type(source_filename)?;
+    let config = config
+        .map(|config| -> PyResult<_> {
+            let json: String = py.import("json")?.call_method1("dumps", (config,))?.extract()?;
+            let value = serde_json::from_str(&json)
+                .map_err(|error| PyValueError::new_err(error.to_string()))?;
+            LintConfig::from_value(&value).map_err(PyValueError::new_err)
+        })
+        .transpose()?;
+    let options = oxc_linter::LintOptions::default()
+        .with_config(config)
+        .with_fix(fix)
+        .with_fix_suggestions(fix)
+        .with_fix_dangerously(fix);
+
+    let diagnostics = Linter::lint_source(Path::new(source_filename), source_text, options);
+    Ok(diagnostics
+        .into_iter()
+        .map(|diagnostic| LintDiagnostic {
+            rule: diagnostic.rule.map(|rule| rule.to_string()),
+            severity: format!("{:?}", diagnostic.severity).to_lowercase(),
+            message: diagnostic.message,
+            help: diagnostic.help,
+            start: diagnostic.span.start,
+            end: diagnostic.span.end,
+            fix: diagnostic.fix.map(|fix| LintFix {
+                kind: fix.kind.as_str().into(),
+                start: fix.span.start,
+                end: fix.span.end,
+                content: fix.content,
+            }),
+        })
+        .collect())
+}
+
+#[pymodule]
+fn oxc_python(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_function(wrap_pyfunction!(lint, m)?)?;
+    m.add_class::<ParseResult>()?;
+    m.add_class::<LintDiagnostic>()?;
+    m.add_class::<LintFix>()?;
+    Ok(())
+}
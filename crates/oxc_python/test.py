@@ -0,0 +1,27 @@
+import json
+
+import oxc_python as oxc
+
+ret = oxc.parse("foo")
+print(ret.program)
+print(ret.errors)
+assert len(json.loads(ret.program)["body"]) == 1
+assert len(ret.errors) == 0
+
+ret = oxc.parse("let", "index.ts")
+assert len(ret.errors) == 1
+
+diagnostics = oxc.lint("debugger;", fix=True)
+print(diagnostics)
+assert len(diagnostics) == 1
+assert diagnostics[0].rule == "eslint/no-debugger"
+assert diagnostics[0].fix.content == ""
+
+diagnostics = oxc.lint("debugger;", config={"rules": {"no-debugger": "off"}})
+assert len(diagnostics) == 0
+
+try:
+    oxc.lint("debugger;", config={"rules": {"foo": "off"}})
+    assert False
+except ValueError as error:
+    assert str(error) == "unknown rule `foo`"
@@ -903,6 +903,8 @@ pub(super) fn resolve_variable_declaration_property<'a, 'b: 'a>(
                 oxc_ast::ast::VariableDeclarationKind::Var => "var".into(),
                 oxc_ast::ast::VariableDeclarationKind::Const => "const".into(),
                 oxc_ast::ast::VariableDeclarationKind::Let => "let".into(),
+                oxc_ast::ast::VariableDeclarationKind::Using => "using".into(),
+                oxc_ast::ast::VariableDeclarationKind::AwaitUsing => "await using".into(),
             }
         }),
         _ => {
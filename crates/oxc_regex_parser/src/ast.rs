@@ -0,0 +1,245 @@
+//! AST for regular expression patterns
+//!
+//! Spans are byte offsets into the pattern, not into the source file.
+//!
+//! See [Patterns](https://tc39.es/ecma262/#sec-patterns)
+
+use oxc_span::{Atom, Span};
+
+/// `/a|b/` is a pattern with two alternatives
+#[derive(Debug)]
+pub struct Pattern {
+    pub span: Span,
+    pub alternatives: Vec<Alternative>,
+}
+
+#[derive(Debug)]
+pub struct Alternative {
+    pub span: Span,
+    pub terms: Vec<Term>,
+}
+
+#[derive(Debug)]
+pub enum Term {
+    Assertion(Assertion),
+    LookAround(Box<LookAround>),
+    Quantifier(Box<Quantifier>),
+    Group(Box<Group>),
+    CharacterClass(Box<CharacterClass>),
+    CharacterSet(CharacterSet),
+    Character(Character),
+    Backreference(Backreference),
+}
+
+/// `^`, `$`, `\b` or `\B`
+#[derive(Debug)]
+pub struct Assertion {
+    pub span: Span,
+    pub kind: AssertionKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssertionKind {
+    Start,
+    End,
+    WordBoundary,
+    NotWordBoundary,
+}
+
+/// `(?=a)`, `(?!a)`, `(?<=a)` or `(?<!a)`
+#[derive(Debug)]
+pub struct LookAround {
+    pub span: Span,
+    pub kind: LookAroundKind,
+    pub alternatives: Vec<Alternative>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LookAroundKind {
+    Lookahead,
+    NegativeLookahead,
+    Lookbehind,
+    NegativeLookbehind,
+}
+
+/// `a*`, `a+?`, `a{1,2}`
+#[derive(Debug)]
+pub struct Quantifier {
+    pub span: Span,
+    pub min: u32,
+    /// `None` for an unbounded quantifier
+    pub max: Option<u32>,
+    pub greedy: bool,
+    pub term: Term,
+}
+
+/// `(a)`, `(?<name>a)` or `(?:a)`
+#[derive(Debug)]
+pub struct Group {
+    pub span: Span,
+    pub kind: GroupKind,
+    pub alternatives: Vec<Alternative>,
+}
+
+#[derive(Debug)]
+pub enum GroupKind {
+    Capturing { name: Option<Atom> },
+    NonCapturing,
+}
+
+/// `[a-z]`, `[^a]`, and the `v` flag's `[\w&&\d]`, `[\w--\d]`
+#[derive(Debug)]
+pub struct CharacterClass {
+    pub span: Span,
+    pub negate: bool,
+    pub kind: CharacterClassKind,
+    pub elements: Vec<ClassElement>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacterClassKind {
+    Union,
+    /// `[a&&b]`, only with the `v` flag
+    Intersection,
+    /// `[a--b]`, only with the `v` flag
+    Subtraction,
+}
+
+#[derive(Debug)]
+pub enum ClassElement {
+    Character(Character),
+    CharacterSet(CharacterSet),
+    Range(Box<ClassRange>),
+    /// A nested class, only with the `v` flag
+    CharacterClass(Box<CharacterClass>),
+    /// `\q{abc|def}`, only with the `v` flag
+    ClassStrings(ClassStrings),
+}
+
+/// `a-z`
+#[derive(Debug)]
+pub struct ClassRange {
+    pub span: Span,
+    pub min: Character,
+    pub max: Character,
+}
+
+/// `\q{abc|def}`
+#[derive(Debug)]
+pub struct ClassStrings {
+    pub span: Span,
+    pub strings: Vec<Vec<Character>>,
+}
+
+/// `.`, `\d`, `\w`, `\s`, `\p{...}` and their negations
+#[derive(Debug)]
+pub struct CharacterSet {
+    pub span: Span,
+    pub kind: CharacterSetKind,
+    pub negate: bool,
+}
+
+#[derive(Debug)]
+pub enum CharacterSetKind {
+    Any,
+    Digit,
+    Word,
+    Space,
+    /// `\p{Letter}`, `\p{Script=Greek}`
+    Property {
+        name: Atom,
+        value: Option<Atom>,
+        /// A property of strings such as `\p{RGI_Emoji}`, only with the `v` flag
+        strings: bool,
+    },
+}
+
+/// A single character, whether it is written literally or escaped
+#[derive(Debug, Clone, Copy)]
+pub struct Character {
+    pub span: Span,
+    /// The code point, or a lone surrogate
+    pub value: u32,
+    pub kind: CharacterKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacterKind {
+    /// `a`
+    Symbol,
+    /// `\cJ`
+    ControlLetter,
+    /// `\x0a`
+    HexEscape,
+    /// `\u000a`, or a surrogate pair such as `😀` with the `u` flag
+    UnicodeEscape,
+    /// `\u{a}`, only with the `u` or `v` flag
+    CodePointEscape,
+    /// `\12`, only without the `u` or `v` flag
+    Octal,
+    /// `\0`
+    Null,
+    /// `\n`, `\t`, `\b` in a class, ...
+    SingleEscape,
+    /// `\.`, or any escaped character without the `u` or `v` flag
+    Identity,
+}
+
+/// `\1` or `\k<name>`
+#[derive(Debug)]
+pub struct Backreference {
+    pub span: Span,
+    pub kind: BackreferenceKind,
+}
+
+#[derive(Debug)]
+pub enum BackreferenceKind {
+    Index(u32),
+    Name(Atom),
+}
+
+impl Term {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Assertion(assertion) => assertion.span,
+            Self::LookAround(look_around) => look_around.span,
+            Self::Quantifier(quantifier) => quantifier.span,
+            Self::Group(group) => group.span,
+            Self::CharacterClass(class) => class.span,
+            Self::CharacterSet(set) => set.span,
+            Self::Character(character) => character.span,
+            Self::Backreference(backreference) => backreference.span,
+        }
+    }
+}
+
+impl CharacterClass {
+    /// Whether the class can match a string of more than one character,
+    /// which is only possible with the `v` flag
+    pub fn may_contain_strings(&self) -> bool {
+        match self.kind {
+            CharacterClassKind::Union => {
+                self.elements.iter().any(ClassElement::may_contain_strings)
+            }
+            CharacterClassKind::Intersection => {
+                self.elements.iter().all(ClassElement::may_contain_strings)
+            }
+            CharacterClassKind::Subtraction => {
+                self.elements.first().is_some_and(ClassElement::may_contain_strings)
+            }
+        }
+    }
+}
+
+impl ClassElement {
+    pub fn may_contain_strings(&self) -> bool {
+        match self {
+            Self::Character(_) | Self::Range(_) => false,
+            Self::CharacterSet(set) => {
+                matches!(set.kind, CharacterSetKind::Property { strings: true, .. })
+            }
+            Self::CharacterClass(class) => class.may_contain_strings(),
+            Self::ClassStrings(strings) => strings.strings.iter().any(|string| string.len() != 1),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! # Oxc Regex Parser
+//!
+//! Parser for ECMAScript regular expression patterns and flags, shared by the rules which
+//! inspect regular expressions.
+//!
+//! Without the `u` or `v` flag, patterns are parsed with the
+//! [Annex B](https://tc39.es/ecma262/#sec-regular-expressions-patterns) extensions that browsers
+//! implement, e.g. `/]/` and `/\a/` are valid.
+//!
+//! ```ignore
+//! let options = ParserOptions::from_flags(parse_flags("gu")?);
+//! let pattern = Parser::new(r"\p{Letter}+", options).parse()?;
+//! ```
+
+pub mod ast;
+mod parser;
+mod unicode_property;
+mod visit;
+
+use oxc_ast::ast::RegExpFlags;
+use oxc_span::Span;
+
+pub use crate::{
+    parser::{Parser, ParserOptions},
+    visit::Visit,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegexError {
+    pub message: &'static str,
+    /// Byte offsets into the pattern or the flags
+    pub span: Span,
+}
+
+impl RegexError {
+    pub(crate) const fn new(message: &'static str, span: Span) -> Self {
+        Self { message, span }
+    }
+}
+
+impl std::fmt::Display for RegexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.message.fmt(f)
+    }
+}
+
+impl std::error::Error for RegexError {}
+
+/// Parse the flags of a `RegExp` constructor call, such as the `"gu"` in `new RegExp("a", "gu")`
+///
+/// # Errors
+///
+/// * an unknown flag
+/// * a repeated flag
+/// * both the `u` and `v` flags
+pub fn parse_flags(source: &str) -> Result<RegExpFlags, RegexError> {
+    let mut flags = RegExpFlags::empty();
+    for (offset, ch) in source.char_indices() {
+        #[allow(clippy::cast_possible_truncation)]
+        let span = Span::new(offset as u32, (offset + ch.len_utf8()) as u32);
+        let flag = RegExpFlags::try_from(ch)
+            .map_err(|_| RegexError::new("Invalid regular expression flags", span))?;
+        if flags.contains(flag) {
+            return Err(RegexError::new("Duplicate regular expression flag", span));
+        }
+        flags |= flag;
+    }
+    if flags.contains(RegExpFlags::U | RegExpFlags::V) {
+        #[allow(clippy::cast_possible_truncation)]
+        let span = Span::new(0, source.len() as u32);
+        return Err(RegexError::new("Invalid regular expression flags", span));
+    }
+    Ok(flags)
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_ast::ast::RegExpFlags;
+
+    use super::parse_flags;
+
+    #[test]
+    fn flags() {
+        assert_eq!(parse_flags(""), Ok(RegExpFlags::empty()));
+        assert_eq!(parse_flags("dgimsuy"), Ok(RegExpFlags::all() - RegExpFlags::V));
+        assert_eq!(parse_flags("v"), Ok(RegExpFlags::V));
+        assert_eq!(parse_flags("a").unwrap_err().message, "Invalid regular expression flags");
+        assert_eq!(parse_flags("gg").unwrap_err().message, "Duplicate regular expression flag");
+        assert_eq!(parse_flags("uv").unwrap_err().message, "Invalid regular expression flags");
+    }
+}
@@ -0,0 +1,965 @@
+//! Recursive descent parser for regular expression patterns
+//!
+//! See [Patterns](https://tc39.es/ecma262/#sec-patterns)
+//! and [Regular Expressions Patterns](https://tc39.es/ecma262/#sec-regular-expressions-patterns)
+//! for the Annex B extensions.
+
+use oxc_ast::ast::RegExpFlags;
+use oxc_span::{Atom, Span};
+use oxc_syntax::identifier::{is_identifier_part, is_identifier_start_all};
+
+#[allow(clippy::wildcard_imports)]
+use crate::{ast::*, unicode_property, RegexError};
+
+type Result<T> = std::result::Result<T, RegexError>;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ParserOptions {
+    /// The `u` or the `v` flag
+    pub unicode_mode: bool,
+    /// The `v` flag
+    pub unicode_sets_mode: bool,
+}
+
+impl ParserOptions {
+    pub fn from_flags(flags: RegExpFlags) -> Self {
+        Self {
+            unicode_mode: flags.intersects(RegExpFlags::U | RegExpFlags::V),
+            unicode_sets_mode: flags.contains(RegExpFlags::V),
+        }
+    }
+}
+
+pub struct Parser<'a> {
+    source: &'a str,
+    options: ParserOptions,
+    pos: usize,
+    /// `\N` is a backreference only up to the number of capturing groups
+    group_count: u32,
+    /// A named group makes `\k` a named backreference even without the `u` flag
+    has_named_groups: bool,
+    group_names: Vec<Atom>,
+    named_references: Vec<(Atom, Span)>,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(source: &'a str, options: ParserOptions) -> Self {
+        let (group_count, has_named_groups) = count_groups(source, options.unicode_sets_mode);
+        Self {
+            source,
+            options,
+            pos: 0,
+            group_count,
+            has_named_groups,
+            group_names: vec![],
+            named_references: vec![],
+        }
+    }
+
+    /// # Errors
+    ///
+    /// The first syntax error in the pattern
+    pub fn parse(mut self) -> Result<Pattern> {
+        let alternatives = self.parse_disjunction()?;
+        if self.peek() == Some(')') {
+            return Err(self.error_at("Unmatched ')'", self.pos));
+        }
+        if let Some((_, span)) =
+            self.named_references.iter().find(|(name, _)| !self.group_names.contains(name))
+        {
+            return Err(RegexError::new("Invalid named capture referenced", *span));
+        }
+        Ok(Pattern { span: self.span(0), alternatives })
+    }
+
+    fn parse_disjunction(&mut self) -> Result<Vec<Alternative>> {
+        let mut alternatives = vec![self.parse_alternative()?];
+        while self.eat('|') {
+            alternatives.push(self.parse_alternative()?);
+        }
+        Ok(alternatives)
+    }
+
+    fn parse_alternative(&mut self) -> Result<Alternative> {
+        let start = self.pos;
+        let mut terms = vec![];
+        while !matches!(self.peek(), None | Some('|' | ')')) {
+            terms.push(self.parse_term()?);
+        }
+        Ok(Alternative { span: self.span(start), terms })
+    }
+
+    fn parse_term(&mut self) -> Result<Term> {
+        let start = self.pos;
+        let assertion = if self.eat('^') {
+            Some(AssertionKind::Start)
+        } else if self.eat('$') {
+            Some(AssertionKind::End)
+        } else if self.eat_str("\\b") {
+            Some(AssertionKind::WordBoundary)
+        } else if self.eat_str("\\B") {
+            Some(AssertionKind::NotWordBoundary)
+        } else {
+            None
+        };
+        if let Some(kind) = assertion {
+            return self
+                .non_quantifiable(Term::Assertion(Assertion { span: self.span(start), kind }));
+        }
+
+        if let Some(kind) = self.eat_look_around_open() {
+            let alternatives = self.parse_disjunction()?;
+            if !self.eat(')') {
+                return Err(self.error("Unterminated group", start));
+            }
+            let term = Term::LookAround(Box::new(LookAround {
+                span: self.span(start),
+                kind,
+                alternatives,
+            }));
+            // Annex B: lookaheads are quantifiable without the `u` flag
+            if !self.options.unicode_mode
+                && matches!(kind, LookAroundKind::Lookahead | LookAroundKind::NegativeLookahead)
+            {
+                return self.parse_quantifier(start, term);
+            }
+            return self.non_quantifiable(term);
+        }
+
+        let atom = self.parse_atom()?;
+        self.parse_quantifier(start, atom)
+    }
+
+    fn eat_look_around_open(&mut self) -> Option<LookAroundKind> {
+        [
+            ("(?=", LookAroundKind::Lookahead),
+            ("(?!", LookAroundKind::NegativeLookahead),
+            ("(?<=", LookAroundKind::Lookbehind),
+            ("(?<!", LookAroundKind::NegativeLookbehind),
+        ]
+        .into_iter()
+        .find_map(|(open, kind)| self.eat_str(open).then_some(kind))
+    }
+
+    fn non_quantifiable(&mut self, term: Term) -> Result<Term> {
+        let start = self.pos;
+        if self.eat_quantifier()?.is_some() {
+            return Err(self.error("Nothing to repeat", start));
+        }
+        Ok(term)
+    }
+
+    fn parse_quantifier(&mut self, start: usize, term: Term) -> Result<Term> {
+        let Some((min, max)) = self.eat_quantifier()? else {
+            return Ok(term);
+        };
+        let greedy = !self.eat('?');
+        Ok(Term::Quantifier(Box::new(Quantifier {
+            span: self.span(start),
+            min,
+            max,
+            greedy,
+            term,
+        })))
+    }
+
+    /// `*`, `+`, `?` or `{n}`, `{n,}`, `{n,m}`, without the trailing `?` of a lazy quantifier
+    fn eat_quantifier(&mut self) -> Result<Option<(u32, Option<u32>)>> {
+        let start = self.pos;
+        let (min, max) = match self.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => {
+                let Some(range) = self.eat_braced_quantifier() else {
+                    if self.options.unicode_mode {
+                        return Err(self.error_at("Incomplete quantifier", start));
+                    }
+                    return Ok(None);
+                };
+                if range.1.is_some_and(|max| max < range.0) {
+                    return Err(self.error("numbers out of order in {} quantifier", start));
+                }
+                return Ok(Some(range));
+            }
+            _ => return Ok(None),
+        };
+        self.bump();
+        Ok(Some((min, max)))
+    }
+
+    fn eat_braced_quantifier(&mut self) -> Option<(u32, Option<u32>)> {
+        let start = self.pos;
+        let range = self.try_braced_quantifier();
+        if range.is_none() {
+            self.pos = start;
+        }
+        range
+    }
+
+    fn try_braced_quantifier(&mut self) -> Option<(u32, Option<u32>)> {
+        if !self.eat('{') {
+            return None;
+        }
+        let min = self.eat_decimal()?;
+        let max = if self.eat(',') {
+            if self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                Some(self.eat_decimal()?)
+            } else {
+                None
+            }
+        } else {
+            Some(min)
+        };
+        self.eat('}').then_some((min, max))
+    }
+
+    fn parse_atom(&mut self) -> Result<Term> {
+        let start = self.pos;
+        match self.peek() {
+            Some('.') => {
+                self.bump();
+                let kind = CharacterSetKind::Any;
+                Ok(Term::CharacterSet(CharacterSet { span: self.span(start), kind, negate: false }))
+            }
+            Some('(') => self.parse_group(),
+            Some('[') => Ok(Term::CharacterClass(Box::new(self.parse_class()?))),
+            Some('\\') => self.parse_atom_escape(),
+            Some('*' | '+' | '?') => Err(self.error_at("Nothing to repeat", start)),
+            Some('{') if self.eat_braced_quantifier().is_some() => {
+                Err(self.error("Nothing to repeat", start))
+            }
+            Some('{' | '}' | ']') if self.options.unicode_mode => {
+                Err(self.error_at("Lone quantifier brackets", start))
+            }
+            _ => Ok(Term::Character(self.parse_symbol()?)),
+        }
+    }
+
+    fn parse_symbol(&mut self) -> Result<Character> {
+        let start = self.pos;
+        let Some(c) = self.bump() else {
+            return Err(self.error("Unexpected end of pattern", start));
+        };
+        Ok(Character { span: self.span(start), value: c as u32, kind: CharacterKind::Symbol })
+    }
+
+    fn parse_group(&mut self) -> Result<Term> {
+        let start = self.pos;
+        self.bump();
+        let kind = if self.eat_str("?:") {
+            GroupKind::NonCapturing
+        } else if self.eat_str("?<") {
+            let name = self.parse_group_name("Invalid capture group name")?;
+            if self.group_names.contains(&name) {
+                return Err(self.error("Duplicate capture group name", start));
+            }
+            self.group_names.push(name.clone());
+            GroupKind::Capturing { name: Some(name) }
+        } else if self.peek() == Some('?') {
+            return Err(self.error_at("Invalid group", start));
+        } else {
+            GroupKind::Capturing { name: None }
+        };
+        let alternatives = self.parse_disjunction()?;
+        if !self.eat(')') {
+            return Err(self.error("Unterminated group", start));
+        }
+        Ok(Term::Group(Box::new(Group { span: self.span(start), kind, alternatives })))
+    }
+
+    /// The name of `(?<name>)` or `\k<name>`, after the `<`
+    fn parse_group_name(&mut self, message: &'static str) -> Result<Atom> {
+        let start = self.pos;
+        let mut name = String::new();
+        while !self.eat('>') {
+            let c = if self.eat_str("\\u") {
+                self.parse_unicode_escape_value(true).and_then(char::from_u32)
+            } else {
+                self.bump()
+            };
+            let valid = c.is_some_and(|c| {
+                if name.is_empty() {
+                    is_identifier_start_all(c)
+                } else {
+                    is_identifier_part(c)
+                }
+            });
+            match c {
+                Some(c) if valid => name.push(c),
+                _ => return Err(self.error(message, start)),
+            }
+        }
+        if name.is_empty() {
+            return Err(self.error(message, start));
+        }
+        Ok(Atom::from(name))
+    }
+
+    fn parse_atom_escape(&mut self) -> Result<Term> {
+        let start = self.pos;
+        self.bump();
+        match self.peek() {
+            None => Err(self.error("\\ at end of pattern", start)),
+            Some('1'..='9') => {
+                let digits_start = self.pos;
+                let index = self.eat_decimal().unwrap_or(u32::MAX);
+                if index <= self.group_count {
+                    let kind = BackreferenceKind::Index(index);
+                    return Ok(Term::Backreference(Backreference { span: self.span(start), kind }));
+                }
+                if self.options.unicode_mode {
+                    return Err(self.error("Invalid escape", start));
+                }
+                self.pos = digits_start;
+                Ok(Term::Character(self.parse_legacy_octal(start)))
+            }
+            Some('k') if self.options.unicode_mode || self.has_named_groups => {
+                self.bump();
+                if !self.eat('<') {
+                    return Err(self.error("Invalid named reference", start));
+                }
+                let name = self.parse_group_name("Invalid named reference")?;
+                let span = self.span(start);
+                self.named_references.push((name.clone(), span));
+                Ok(Term::Backreference(Backreference { span, kind: BackreferenceKind::Name(name) }))
+            }
+            _ => {
+                if let Some(set) = self.eat_character_class_escape(start)? {
+                    return Ok(Term::CharacterSet(set));
+                }
+                Ok(Term::Character(self.parse_character_escape(start, false)?))
+            }
+        }
+    }
+
+    /// `\d`, `\D`, `\w`, `\W`, `\s`, `\S`, and `\p{...}`, `\P{...}` with the `u` or `v` flag,
+    /// after the `\`
+    fn eat_character_class_escape(&mut self, start: usize) -> Result<Option<CharacterSet>> {
+        let (kind, negate) = match self.peek() {
+            Some('d') => (CharacterSetKind::Digit, false),
+            Some('D') => (CharacterSetKind::Digit, true),
+            Some('w') => (CharacterSetKind::Word, false),
+            Some('W') => (CharacterSetKind::Word, true),
+            Some('s') => (CharacterSetKind::Space, false),
+            Some('S') => (CharacterSetKind::Space, true),
+            Some(c @ ('p' | 'P')) if self.options.unicode_mode => {
+                self.bump();
+                return self.parse_property(start, c == 'P').map(Some);
+            }
+            _ => return Ok(None),
+        };
+        self.bump();
+        Ok(Some(CharacterSet { span: self.span(start), kind, negate }))
+    }
+
+    /// `{name}` or `{name=value}` after `\p` or `\P`
+    fn parse_property(&mut self, start: usize, negate: bool) -> Result<CharacterSet> {
+        let source = self.source;
+        let eat_word = |parser: &mut Self| {
+            let word_start = parser.pos;
+            while parser.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == '_') {
+                parser.bump();
+            }
+            &source[word_start..parser.pos]
+        };
+        if !self.eat('{') {
+            return Err(self.error("Invalid property name", start));
+        }
+        let name = eat_word(self);
+        let value = if self.eat('=') { Some(eat_word(self)) } else { None };
+        if !self.eat('}') {
+            return Err(self.error("Invalid property name", start));
+        }
+        let valid = match value {
+            Some(value) => unicode_property::is_valid_name_and_value(name, value),
+            None => unicode_property::is_valid_lone_name(name, self.options.unicode_sets_mode),
+        };
+        let strings = value.is_none() && unicode_property::is_property_of_strings(name);
+        if !valid || (strings && negate) {
+            return Err(self.error("Invalid property name", start));
+        }
+        let kind =
+            CharacterSetKind::Property { name: name.into(), value: value.map(Atom::from), strings };
+        Ok(CharacterSet { span: self.span(start), kind, negate })
+    }
+
+    /// A character escape after the `\`
+    fn parse_character_escape(&mut self, start: usize, in_class: bool) -> Result<Character> {
+        let unicode_mode = self.options.unicode_mode;
+        let Some(c) = self.bump() else {
+            return Err(self.error("\\ at end of pattern", start));
+        };
+        let (value, kind) = match c {
+            'f' => (0x0C, CharacterKind::SingleEscape),
+            'n' => (0x0A, CharacterKind::SingleEscape),
+            'r' => (0x0D, CharacterKind::SingleEscape),
+            't' => (0x09, CharacterKind::SingleEscape),
+            'v' => (0x0B, CharacterKind::SingleEscape),
+            'c' => {
+                // Annex B: `\c_` and `\c0` in a class
+                let is_control = |c: char| {
+                    c.is_ascii_alphabetic()
+                        || (in_class && !unicode_mode && (c.is_ascii_digit() || c == '_'))
+                };
+                match self.peek() {
+                    Some(letter) if is_control(letter) => {
+                        self.bump();
+                        (letter as u32 % 32, CharacterKind::ControlLetter)
+                    }
+                    _ if unicode_mode => return Err(self.error("Invalid unicode escape", start)),
+                    _ => {
+                        // Annex B: `\c` is a `\` followed by a `c`
+                        self.pos = start + 1;
+                        ('\\' as u32, CharacterKind::Symbol)
+                    }
+                }
+            }
+            '0' if !self.peek().is_some_and(|c| c.is_ascii_digit()) => (0, CharacterKind::Null),
+            '0'..='9' if unicode_mode => {
+                let message =
+                    if in_class { "Invalid class escape" } else { "Invalid decimal escape" };
+                return Err(self.error(message, start));
+            }
+            '0'..='7' => {
+                self.pos = start + 1;
+                return Ok(self.parse_legacy_octal(start));
+            }
+            'x' => match self.eat_hex_exact(2) {
+                Some(value) => (value, CharacterKind::HexEscape),
+                None if unicode_mode => return Err(self.error("Invalid escape", start)),
+                None => ('x' as u32, CharacterKind::Identity),
+            },
+            'u' => {
+                let kind = if unicode_mode && self.peek() == Some('{') {
+                    CharacterKind::CodePointEscape
+                } else {
+                    CharacterKind::UnicodeEscape
+                };
+                let escape_start = self.pos;
+                match self.parse_unicode_escape_value(unicode_mode) {
+                    Some(value) => (value, kind),
+                    None if unicode_mode => {
+                        return Err(self.error("Invalid unicode escape", start));
+                    }
+                    None => {
+                        self.pos = escape_start;
+                        ('u' as u32, CharacterKind::Identity)
+                    }
+                }
+            }
+            'k' if in_class && (unicode_mode || self.has_named_groups) => {
+                return Err(self.error("Invalid escape", start));
+            }
+            '-' if in_class && unicode_mode => ('-' as u32, CharacterKind::Identity),
+            c if is_syntax_character(c) || c == '/' || !unicode_mode => {
+                (c as u32, CharacterKind::Identity)
+            }
+            _ => return Err(self.error("Invalid escape", start)),
+        };
+        Ok(Character { span: self.span(start), value, kind })
+    }
+
+    /// Annex B: `\1` to `\377` when they are not backreferences, and `\8`, `\9`
+    fn parse_legacy_octal(&mut self, start: usize) -> Character {
+        let first = self.bump().and_then(|c| c.to_digit(10)).unwrap_or_default();
+        if first > 7 {
+            return Character {
+                span: self.span(start),
+                value: char::from_digit(first, 10).map_or(0, |c| c as u32),
+                kind: CharacterKind::Identity,
+            };
+        }
+        let mut value = first;
+        let max_digits = if first <= 3 { 2 } else { 1 };
+        for _ in 0..max_digits {
+            match self.peek().and_then(|c| c.to_digit(8)) {
+                Some(digit) => {
+                    self.bump();
+                    value = value * 8 + digit;
+                }
+                None => break,
+            }
+        }
+        Character { span: self.span(start), value, kind: CharacterKind::Octal }
+    }
+
+    /// `XXXX` after `\u`, a surrogate pair `XXXX\uXXXX` and `{X...}` in unicode mode
+    fn parse_unicode_escape_value(&mut self, unicode_mode: bool) -> Option<u32> {
+        if unicode_mode && self.eat('{') {
+            let digits_start = self.pos;
+            let mut value = 0u32;
+            while let Some(digit) = self.peek().and_then(|c| c.to_digit(16)) {
+                self.bump();
+                value = value.saturating_mul(16).saturating_add(digit);
+            }
+            let valid = self.pos > digits_start && value <= 0x0010_FFFF;
+            return (valid && self.eat('}')).then_some(value);
+        }
+        let lead = self.eat_hex_exact(4)?;
+        if unicode_mode && (0xD800..=0xDBFF).contains(&lead) {
+            let trail_start = self.pos;
+            if self.eat_str("\\u") {
+                if let Some(trail @ 0xDC00..=0xDFFF) = self.eat_hex_exact(4) {
+                    return Some(0x10000 + ((lead - 0xD800) << 10) + (trail - 0xDC00));
+                }
+            }
+            self.pos = trail_start;
+        }
+        Some(lead)
+    }
+
+    fn parse_class(&mut self) -> Result<CharacterClass> {
+        let start = self.pos;
+        self.bump();
+        let negate = self.eat('^');
+        if self.options.unicode_sets_mode {
+            return self.parse_class_set(start, negate);
+        }
+        let mut elements = vec![];
+        loop {
+            match self.peek() {
+                None => return Err(self.error("Unterminated character class", start)),
+                Some(']') => {
+                    self.bump();
+                    break;
+                }
+                _ => {}
+            }
+            let atom_start = self.pos;
+            let min = self.parse_class_atom()?;
+            if self.peek() != Some('-') || matches!(self.peek_nth(1), None | Some(']')) {
+                elements.push(min);
+                continue;
+            }
+            let dash = self.parse_symbol()?;
+            match (min, self.parse_class_atom()?) {
+                (ClassElement::Character(min), ClassElement::Character(max)) => {
+                    if min.value > max.value {
+                        return Err(self.error("Range out of order in character class", atom_start));
+                    }
+                    let span = self.span(atom_start);
+                    elements.push(ClassElement::Range(Box::new(ClassRange { span, min, max })));
+                }
+                _ if self.options.unicode_mode => {
+                    return Err(self.error("Invalid character class", atom_start));
+                }
+                // Annex B: `[\d-z]` is `\d`, `-` and `z`
+                (min, max) => {
+                    elements.extend([min, ClassElement::Character(dash), max]);
+                }
+            }
+        }
+        let kind = CharacterClassKind::Union;
+        Ok(CharacterClass { span: self.span(start), negate, kind, elements })
+    }
+
+    fn parse_class_atom(&mut self) -> Result<ClassElement> {
+        let start = self.pos;
+        if !self.eat('\\') {
+            return Ok(ClassElement::Character(self.parse_symbol()?));
+        }
+        if self.eat('b') {
+            let kind = CharacterKind::SingleEscape;
+            return Ok(ClassElement::Character(Character {
+                span: self.span(start),
+                value: 8,
+                kind,
+            }));
+        }
+        if let Some(set) = self.eat_character_class_escape(start)? {
+            return Ok(ClassElement::CharacterSet(set));
+        }
+        Ok(ClassElement::Character(self.parse_character_escape(start, true)?))
+    }
+
+    /// A class with the `v` flag, after the `[` and the optional `^`
+    fn parse_class_set(&mut self, start: usize, negate: bool) -> Result<CharacterClass> {
+        let mut elements = vec![];
+        let mut kind = CharacterClassKind::Union;
+        if !self.eat(']') {
+            elements.push(self.parse_class_set_operand(true)?);
+            let operator = if self.starts_with("&&") {
+                Some((CharacterClassKind::Intersection, "&&"))
+            } else if self.starts_with("--") {
+                Some((CharacterClassKind::Subtraction, "--"))
+            } else {
+                None
+            };
+            if let Some((operator_kind, operator)) = operator {
+                if matches!(elements[0], ClassElement::Range(_)) {
+                    return Err(self.error_at("Invalid set operation in character class", self.pos));
+                }
+                kind = operator_kind;
+                while !self.eat(']') {
+                    let operator_start = self.pos;
+                    if !self.eat_str(operator) {
+                        let message = "Invalid set operation in character class";
+                        return Err(self.error_at(message, operator_start));
+                    }
+                    if operator == "&&" && self.peek() == Some('&') {
+                        return Err(self.error_at("Invalid character in character class", self.pos));
+                    }
+                    elements.push(self.parse_class_set_operand(false)?);
+                }
+            } else {
+                while !self.eat(']') {
+                    if self.starts_with("&&") || self.starts_with("--") {
+                        let message = "Invalid set operation in character class";
+                        return Err(self.error_at(message, self.pos));
+                    }
+                    elements.push(self.parse_class_set_operand(true)?);
+                }
+            }
+        }
+        let class = CharacterClass { span: self.span(start), negate, kind, elements };
+        if negate && class.may_contain_strings() {
+            return Err(self.error("Negated character class may contain strings", start));
+        }
+        Ok(class)
+    }
+
+    fn parse_class_set_operand(&mut self, allow_range: bool) -> Result<ClassElement> {
+        let start = self.pos;
+        match self.peek() {
+            None => return Err(self.error("Unterminated character class", start)),
+            Some('[') => {
+                self.bump();
+                let negate = self.eat('^');
+                let class = self.parse_class_set(start, negate)?;
+                return Ok(ClassElement::CharacterClass(Box::new(class)));
+            }
+            Some('\\') => {
+                if self.eat_str("\\q{") {
+                    return self.parse_class_strings(start).map(ClassElement::ClassStrings);
+                }
+                self.bump();
+                if let Some(set) = self.eat_character_class_escape(start)? {
+                    return Ok(ClassElement::CharacterSet(set));
+                }
+                self.pos = start;
+            }
+            _ => {}
+        }
+        let min = self.parse_class_set_character()?;
+        if !allow_range || self.peek() != Some('-') || self.peek_nth(1) == Some('-') {
+            return Ok(ClassElement::Character(min));
+        }
+        self.bump();
+        let max = self.parse_class_set_character()?;
+        if min.value > max.value {
+            return Err(self.error("Range out of order in character class", start));
+        }
+        Ok(ClassElement::Range(Box::new(ClassRange { span: self.span(start), min, max })))
+    }
+
+    fn parse_class_set_character(&mut self) -> Result<Character> {
+        let start = self.pos;
+        let Some(c) = self.peek() else {
+            return Err(self.error("Unterminated character class", start));
+        };
+        if self.eat('\\') {
+            return match self.peek() {
+                Some('b') => {
+                    self.bump();
+                    let kind = CharacterKind::SingleEscape;
+                    Ok(Character { span: self.span(start), value: 8, kind })
+                }
+                Some(c) if is_class_set_reserved_punctuator(c) => {
+                    self.bump();
+                    let kind = CharacterKind::Identity;
+                    Ok(Character { span: self.span(start), value: c as u32, kind })
+                }
+                _ => self.parse_character_escape(start, true),
+            };
+        }
+        if is_class_set_syntax_character(c) {
+            return Err(self.error_at("Invalid character in character class", start));
+        }
+        if is_class_set_reserved_double_punctuator(c) && self.peek_nth(1) == Some(c) {
+            self.pos += 2;
+            return Err(self.error("Invalid set operation in character class", start));
+        }
+        self.parse_symbol()
+    }
+
+    /// `abc|def}` after `\q{`
+    fn parse_class_strings(&mut self, start: usize) -> Result<ClassStrings> {
+        let mut strings = vec![vec![]];
+        loop {
+            match self.peek() {
+                None => return Err(self.error("Unterminated character class", start)),
+                Some('}') => {
+                    self.bump();
+                    break;
+                }
+                Some('|') => {
+                    self.bump();
+                    strings.push(vec![]);
+                }
+                _ => {
+                    let character = self.parse_class_set_character()?;
+                    if let Some(string) = strings.last_mut() {
+                        string.push(character);
+                    }
+                }
+            }
+        }
+        Ok(ClassStrings { span: self.span(start), strings })
+    }
+
+    fn eat_decimal(&mut self) -> Option<u32> {
+        let start = self.pos;
+        let mut value = 0u32;
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(10)) {
+            self.bump();
+            value = value.saturating_mul(10).saturating_add(digit);
+        }
+        (self.pos > start).then_some(value)
+    }
+
+    fn eat_hex_exact(&mut self, len: usize) -> Option<u32> {
+        let digits = self.source.get(self.pos..self.pos + len)?;
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        self.pos += len;
+        u32::from_str_radix(digits, 16).ok()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.source[self.pos..].chars().nth(n)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let matched = self.peek() == Some(c);
+        if matched {
+            self.pos += c.len_utf8();
+        }
+        matched
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        self.source[self.pos..].starts_with(s)
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        let matched = self.starts_with(s);
+        if matched {
+            self.pos += s.len();
+        }
+        matched
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn span(&self, start: usize) -> Span {
+        Span::new(start as u32, self.pos as u32)
+    }
+
+    /// An error from `start` to the current position
+    fn error(&self, message: &'static str, start: usize) -> RegexError {
+        RegexError::new(message, self.span(start.min(self.pos)))
+    }
+
+    /// An error on the character at `start`
+    #[allow(clippy::cast_possible_truncation)]
+    fn error_at(&self, message: &'static str, start: usize) -> RegexError {
+        let len = self.source[start..].chars().next().map_or(0, char::len_utf8);
+        RegexError::new(message, Span::new(start as u32, (start + len) as u32))
+    }
+}
+
+/// Counts the capturing groups of a pattern before parsing it, since `\N` and `\k` depend on
+/// groups which may come after them
+fn count_groups(source: &str, unicode_sets_mode: bool) -> (u32, bool) {
+    let bytes = source.as_bytes();
+    let mut count = 0;
+    let mut has_named_groups = false;
+    let mut class_depth = 0u32;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'[' if unicode_sets_mode || class_depth == 0 => class_depth += 1,
+            b']' => class_depth = class_depth.saturating_sub(1),
+            b'(' if class_depth == 0 => {
+                if bytes.get(i + 1) != Some(&b'?') {
+                    count += 1;
+                } else if bytes.get(i + 2) == Some(&b'<')
+                    && !matches!(bytes.get(i + 3), Some(b'=' | b'!'))
+                {
+                    count += 1;
+                    has_named_groups = true;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    (count, has_named_groups)
+}
+
+const fn is_syntax_character(c: char) -> bool {
+    matches!(c, '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|')
+}
+
+const fn is_class_set_syntax_character(c: char) -> bool {
+    matches!(c, '(' | ')' | '[' | ']' | '{' | '}' | '/' | '-' | '\\' | '|')
+}
+
+const fn is_class_set_reserved_punctuator(c: char) -> bool {
+    matches!(c, '&' | '-' | '!' | '#' | '%' | ',' | ':' | ';' | '<' | '=' | '>' | '@' | '`' | '~')
+}
+
+const fn is_class_set_reserved_double_punctuator(c: char) -> bool {
+    matches!(
+        c,
+        '&' | '!'
+            | '#'
+            | '$'
+            | '%'
+            | '*'
+            | '+'
+            | ','
+            | '.'
+            | ':'
+            | ';'
+            | '<'
+            | '='
+            | '>'
+            | '?'
+            | '@'
+            | '^'
+            | '`'
+            | '~'
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_ast::ast::RegExpFlags;
+
+    use super::{Parser, ParserOptions};
+    use crate::{ast::*, parse_flags};
+
+    fn parse(pattern: &str, flags: &str) -> Result<Pattern, &'static str> {
+        let options = ParserOptions::from_flags(parse_flags(flags).unwrap());
+        Parser::new(pattern, options).parse().map_err(|error| error.message)
+    }
+
+    #[test]
+    fn valid() {
+        let pass = [
+            ("", ""),
+            ("a|b|", ""),
+            ("^abc$", "m"),
+            (r"\bfoo\B", ""),
+            ("a*b+?c??d{1}e{1,}f{1,2}?", ""),
+            ("(a)(?:b)(?<name>c)(?=d)(?!e)(?<=f)(?<!g)", ""),
+            (r"(a)\1(?<b>b)\k<b>", "u"),
+            (r"[a-z\d\-]", "u"),
+            (r"\p{Letter}\P{Script=Greek}\p{gc=Lu}\p{Script_Extensions=Latn}", "u"),
+            (r"\u{1F600}\u{0}😀", "u"),
+            (r"(?<\u{1d49c}>.)", ""),
+            (r"(?<$ab_c>.)", "u"),
+            (r"[\w&&\d]", "v"),
+            (r"[\w--[a-z]]", "v"),
+            (r"[[a-z]--\q{abc|d}]", "v"),
+            (r"[^\q{a|b}]", "v"),
+            (r"\p{RGI_Emoji}", "v"),
+            (r"[\&\-\!]", "v"),
+            // Annex B
+            ("]{}", ""),
+            (r"\a\c\8\1", ""),
+            (r"[\d-z]", ""),
+            (r"a{", ""),
+            ("(?=a)*", ""),
+            (r"\k", ""),
+            (r"\u{1F600}", ""),
+            (r"[\c_]", ""),
+        ];
+        for (pattern, flags) in pass {
+            assert!(parse(pattern, flags).is_ok(), "/{pattern}/{flags} should be valid");
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let fail = [
+            ("(", "", "Unterminated group"),
+            (")", "", "Unmatched ')'"),
+            ("*", "", "Nothing to repeat"),
+            ("a**", "", "Nothing to repeat"),
+            ("^*", "", "Nothing to repeat"),
+            ("{1}", "", "Nothing to repeat"),
+            ("(?<=a)*", "", "Nothing to repeat"),
+            ("a{2,1}", "", "numbers out of order in {} quantifier"),
+            ("[", "", "Unterminated character class"),
+            ("[z-a]", "", "Range out of order in character class"),
+            (r"\", "", "\\ at end of pattern"),
+            ("(?a)", "", "Invalid group"),
+            ("(?<1>a)", "", "Invalid capture group name"),
+            ("(?<a>.)(?<a>.)", "", "Duplicate capture group name"),
+            (r"(?<a>.)\k<b>", "", "Invalid named capture referenced"),
+            (r"(?<a>.)\k", "", "Invalid named reference"),
+            ("]", "u", "Lone quantifier brackets"),
+            ("{", "u", "Lone quantifier brackets"),
+            ("a{", "u", "Incomplete quantifier"),
+            (r"\a", "u", "Invalid escape"),
+            (r"\1", "u", "Invalid escape"),
+            (r"\00", "u", "Invalid decimal escape"),
+            (r"\c", "u", "Invalid unicode escape"),
+            (r"\u{110000}", "u", "Invalid unicode escape"),
+            (r"[\d-z]", "u", "Invalid character class"),
+            (r"\p{Foo}", "u", "Invalid property name"),
+            (r"\p{Script=foo}", "u", "Invalid property name"),
+            (r"\p{RGI_Emoji}", "u", "Invalid property name"),
+            (r"\P{RGI_Emoji}", "v", "Invalid property name"),
+            ("[(]", "v", "Invalid character in character class"),
+            ("[a&&&b]", "v", "Invalid character in character class"),
+            ("[a&&b--c]", "v", "Invalid set operation in character class"),
+            ("[a-z&&b]", "v", "Invalid set operation in character class"),
+            ("[a!!b]", "v", "Invalid set operation in character class"),
+            (r"[^\q{abc}]", "v", "Negated character class may contain strings"),
+        ];
+        for (pattern, flags, message) in fail {
+            assert_eq!(parse(pattern, flags).err(), Some(message), "/{pattern}/{flags}");
+        }
+    }
+
+    #[test]
+    fn characters() {
+        let pattern = parse(r"a\x1f\u{16}\cJ\012[\b]", "").unwrap();
+        let terms = &pattern.alternatives[0].terms;
+        let values = terms
+            .iter()
+            .filter_map(|term| match term {
+                Term::Character(character) => Some((character.value, character.kind)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(values[0], ('a' as u32, CharacterKind::Symbol));
+        assert_eq!(values[1], (0x1F, CharacterKind::HexEscape));
+        // `\u{16}` without the `u` flag is `u` repeated 16 times
+        assert!(matches!(&terms[2], Term::Quantifier(quantifier) if quantifier.min == 16));
+        assert_eq!(values[2], (10, CharacterKind::ControlLetter));
+        assert_eq!(values[3], (0o12, CharacterKind::Octal));
+
+        let pattern = parse(r"\u{1F600}", "u").unwrap();
+        let Term::Character(character) = &pattern.alternatives[0].terms[0] else { unreachable!() };
+        assert_eq!((character.value, character.kind), (0x1F600, CharacterKind::CodePointEscape));
+        assert_eq!(character.span.end, 9);
+
+        assert_eq!(ParserOptions::from_flags(RegExpFlags::G), ParserOptions::default());
+    }
+}
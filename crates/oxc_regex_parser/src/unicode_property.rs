@@ -0,0 +1,229 @@
+//! Names accepted by `\p{...}`
+//!
+//! See [Table 66: Non-binary Unicode property aliases](https://tc39.es/ecma262/#table-nonbinary-unicode-properties)
+//! and [Table 67: Binary Unicode property aliases](https://tc39.es/ecma262/#table-binary-unicode-properties)
+
+/// Whether `\p{name=value}` is valid
+pub fn is_valid_name_and_value(name: &str, value: &str) -> bool {
+    match name {
+        "General_Category" | "gc" => GENERAL_CATEGORY_VALUES.contains(&value),
+        // Script values are only checked for their shape, e.g. `Latn` or `Old_Italic`,
+        // the list of scripts grows with every Unicode version.
+        "Script" | "sc" | "Script_Extensions" | "scx" => is_script_value(value),
+        _ => false,
+    }
+}
+
+/// Whether `\p{name}` is valid, a property of strings such as `RGI_Emoji` is only valid with the
+/// `v` flag
+pub fn is_valid_lone_name(name: &str, unicode_sets_mode: bool) -> bool {
+    GENERAL_CATEGORY_VALUES.contains(&name)
+        || BINARY_PROPERTIES.contains(&name)
+        || (unicode_sets_mode && is_property_of_strings(name))
+}
+
+pub fn is_property_of_strings(name: &str) -> bool {
+    PROPERTIES_OF_STRINGS.contains(&name)
+}
+
+fn is_script_value(value: &str) -> bool {
+    value.split('_').all(|word| {
+        let mut chars = word.chars();
+        chars.next().is_some_and(|c| c.is_ascii_uppercase())
+            && chars.all(|c| c.is_ascii_alphanumeric())
+    })
+}
+
+const GENERAL_CATEGORY_VALUES: &[&str] = &[
+    "C",
+    "Cased_Letter",
+    "Cc",
+    "Cf",
+    "Close_Punctuation",
+    "Cn",
+    "Co",
+    "Combining_Mark",
+    "Connector_Punctuation",
+    "Control",
+    "Cs",
+    "Currency_Symbol",
+    "Dash_Punctuation",
+    "Decimal_Number",
+    "Enclosing_Mark",
+    "Final_Punctuation",
+    "Format",
+    "Initial_Punctuation",
+    "L",
+    "LC",
+    "Letter",
+    "Letter_Number",
+    "Line_Separator",
+    "Ll",
+    "Lm",
+    "Lo",
+    "Lowercase_Letter",
+    "Lt",
+    "Lu",
+    "M",
+    "Mark",
+    "Math_Symbol",
+    "Mc",
+    "Me",
+    "Mn",
+    "Modifier_Letter",
+    "Modifier_Symbol",
+    "N",
+    "Nd",
+    "Nl",
+    "No",
+    "Nonspacing_Mark",
+    "Number",
+    "Open_Punctuation",
+    "Other",
+    "Other_Letter",
+    "Other_Number",
+    "Other_Punctuation",
+    "Other_Symbol",
+    "P",
+    "Paragraph_Separator",
+    "Pc",
+    "Pd",
+    "Pe",
+    "Pf",
+    "Pi",
+    "Po",
+    "Private_Use",
+    "Ps",
+    "Punctuation",
+    "S",
+    "Sc",
+    "Separator",
+    "Sk",
+    "Sm",
+    "So",
+    "Space_Separator",
+    "Spacing_Mark",
+    "Surrogate",
+    "Symbol",
+    "Titlecase_Letter",
+    "Unassigned",
+    "Uppercase_Letter",
+    "Z",
+    "Zl",
+    "Zp",
+    "Zs",
+    "cntrl",
+    "digit",
+    "punct",
+];
+
+const BINARY_PROPERTIES: &[&str] = &[
+    "AHex",
+    "ASCII",
+    "ASCII_Hex_Digit",
+    "Alpha",
+    "Alphabetic",
+    "Any",
+    "Assigned",
+    "Bidi_C",
+    "Bidi_Control",
+    "Bidi_M",
+    "Bidi_Mirrored",
+    "CI",
+    "CWCF",
+    "CWCM",
+    "CWKCF",
+    "CWL",
+    "CWT",
+    "CWU",
+    "Case_Ignorable",
+    "Cased",
+    "Changes_When_Casefolded",
+    "Changes_When_Casemapped",
+    "Changes_When_Lowercased",
+    "Changes_When_NFKC_Casefolded",
+    "Changes_When_Titlecased",
+    "Changes_When_Uppercased",
+    "DI",
+    "Dash",
+    "Default_Ignorable_Code_Point",
+    "Dep",
+    "Deprecated",
+    "Dia",
+    "Diacritic",
+    "EBase",
+    "EComp",
+    "EMod",
+    "EPres",
+    "Emoji",
+    "Emoji_Component",
+    "Emoji_Modifier",
+    "Emoji_Modifier_Base",
+    "Emoji_Presentation",
+    "Ext",
+    "ExtPict",
+    "Extended_Pictographic",
+    "Extender",
+    "Gr_Base",
+    "Gr_Ext",
+    "Grapheme_Base",
+    "Grapheme_Extend",
+    "Hex",
+    "Hex_Digit",
+    "IDC",
+    "IDS",
+    "IDSB",
+    "IDST",
+    "IDS_Binary_Operator",
+    "IDS_Trinary_Operator",
+    "ID_Continue",
+    "ID_Start",
+    "Ideo",
+    "Ideographic",
+    "Join_C",
+    "Join_Control",
+    "LOE",
+    "Logical_Order_Exception",
+    "Lower",
+    "Lowercase",
+    "Math",
+    "NChar",
+    "Noncharacter_Code_Point",
+    "Pat_Syn",
+    "Pat_WS",
+    "Pattern_Syntax",
+    "Pattern_White_Space",
+    "QMark",
+    "Quotation_Mark",
+    "RI",
+    "Radical",
+    "Regional_Indicator",
+    "SD",
+    "STerm",
+    "Sentence_Terminal",
+    "Soft_Dotted",
+    "Term",
+    "Terminal_Punctuation",
+    "UIdeo",
+    "Unified_Ideograph",
+    "Upper",
+    "Uppercase",
+    "VS",
+    "Variation_Selector",
+    "White_Space",
+    "XIDC",
+    "XIDS",
+    "XID_Continue",
+    "XID_Start",
+    "space",
+];
+
+const PROPERTIES_OF_STRINGS: &[&str] = &[
+    "Basic_Emoji",
+    "Emoji_Keycap_Sequence",
+    "RGI_Emoji",
+    "RGI_Emoji_Flag_Sequence",
+    "RGI_Emoji_Modifier_Sequence",
+    "RGI_Emoji_Tag_Sequence",
+    "RGI_Emoji_ZWJ_Sequence",
+];
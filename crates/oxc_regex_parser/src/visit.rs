@@ -0,0 +1,87 @@
+//! Visitor Pattern
+//!
+//! See [`oxc_ast::Visit`]
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::*;
+
+/// Regular expression pattern traversal
+pub trait Visit: Sized {
+    fn visit_pattern(&mut self, pattern: &Pattern) {
+        self.visit_alternatives(&pattern.alternatives);
+    }
+
+    fn visit_alternatives(&mut self, alternatives: &[Alternative]) {
+        for alternative in alternatives {
+            self.visit_alternative(alternative);
+        }
+    }
+
+    fn visit_alternative(&mut self, alternative: &Alternative) {
+        for term in &alternative.terms {
+            self.visit_term(term);
+        }
+    }
+
+    fn visit_term(&mut self, term: &Term) {
+        match term {
+            Term::Assertion(assertion) => self.visit_assertion(assertion),
+            Term::LookAround(look_around) => self.visit_look_around(look_around),
+            Term::Quantifier(quantifier) => self.visit_quantifier(quantifier),
+            Term::Group(group) => self.visit_group(group),
+            Term::CharacterClass(class) => self.visit_character_class(class),
+            Term::CharacterSet(set) => self.visit_character_set(set),
+            Term::Character(character) => self.visit_character(character),
+            Term::Backreference(backreference) => self.visit_backreference(backreference),
+        }
+    }
+
+    fn visit_assertion(&mut self, _assertion: &Assertion) {}
+
+    fn visit_look_around(&mut self, look_around: &LookAround) {
+        self.visit_alternatives(&look_around.alternatives);
+    }
+
+    fn visit_quantifier(&mut self, quantifier: &Quantifier) {
+        self.visit_term(&quantifier.term);
+    }
+
+    fn visit_group(&mut self, group: &Group) {
+        self.visit_alternatives(&group.alternatives);
+    }
+
+    fn visit_character_class(&mut self, class: &CharacterClass) {
+        for element in &class.elements {
+            self.visit_class_element(element);
+        }
+    }
+
+    fn visit_class_element(&mut self, element: &ClassElement) {
+        match element {
+            ClassElement::Character(character) => self.visit_character(character),
+            ClassElement::CharacterSet(set) => self.visit_character_set(set),
+            ClassElement::Range(range) => self.visit_class_range(range),
+            ClassElement::CharacterClass(class) => self.visit_character_class(class),
+            ClassElement::ClassStrings(strings) => self.visit_class_strings(strings),
+        }
+    }
+
+    fn visit_class_range(&mut self, range: &ClassRange) {
+        self.visit_character(&range.min);
+        self.visit_character(&range.max);
+    }
+
+    fn visit_class_strings(&mut self, strings: &ClassStrings) {
+        for string in &strings.strings {
+            for character in string {
+                self.visit_character(character);
+            }
+        }
+    }
+
+    fn visit_character_set(&mut self, _set: &CharacterSet) {}
+
+    fn visit_character(&mut self, _character: &Character) {}
+
+    fn visit_backreference(&mut self, _backreference: &Backreference) {}
+}
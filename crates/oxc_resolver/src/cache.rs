@@ -14,10 +14,10 @@ use dashmap::{DashMap, DashSet};
 use rustc_hash::FxHasher;
 
 use crate::{
-    package_json::PackageJson, FileMetadata, FileSystem, ResolveError, ResolveOptions, TsConfig,
+    package_json::PackageJson, pnp::PnpManifest, FileMetadata, FileSystem, ResolveError,
+    ResolveOptions, TsConfig,
 };
 
-#[derive(Default)]
 pub struct Cache<Fs> {
     pub(crate) fs: Fs,
     cache: DashSet<CachedPath, BuildHasherDefault<IdentityHasher>>,
@@ -26,7 +26,7 @@ pub struct Cache<Fs> {
 
 impl<Fs: FileSystem> Cache<Fs> {
     pub fn new(fs: Fs) -> Self {
-        Self { fs, ..Self::default() }
+        Self { fs, cache: DashSet::default(), tsconfigs: DashMap::default() }
     }
 
     pub fn clear(&self) {
@@ -34,6 +34,15 @@ impl<Fs: FileSystem> Cache<Fs> {
         self.tsconfigs.clear();
     }
 
+    /// Remove the parent directory of `path` and everything below it.
+    ///
+    /// Entries are only linked to their parents, so entries outside of the directory stay valid.
+    pub fn invalidate(&self, path: &Path) {
+        let directory = path.parent().unwrap_or(path);
+        self.cache.retain(|cached_path| !cached_path.path().starts_with(directory));
+        self.tsconfigs.retain(|tsconfig_path, _| !tsconfig_path.starts_with(directory));
+    }
+
     pub fn value(&self, path: &Path) -> CachedPath {
         let hash = {
             let mut hasher = FxHasher::default();
@@ -58,26 +67,26 @@ impl<Fs: FileSystem> Cache<Fs> {
         tsconfig_path: &CachedPath,
         callback: impl FnOnce(&mut TsConfig) -> Result<(), ResolveError>, // callback for modifying tsconfig with `extends`
     ) -> Result<Arc<TsConfig>, ResolveError> {
-        self.tsconfigs
-            .entry(tsconfig_path.path().to_path_buf())
-            .or_try_insert_with(|| {
-                let tsconfig_path = if tsconfig_path.is_dir(&self.fs) {
-                    Cow::Owned(tsconfig_path.path().join("tsconfig.json"))
-                } else {
-                    Cow::Borrowed(tsconfig_path.path())
-                };
-                let mut tsconfig_string = self
-                    .fs
-                    .read_to_string(&tsconfig_path)
-                    .map_err(|_| ResolveError::NotFound(tsconfig_path.to_path_buf()))?;
-                let mut tsconfig =
-                    TsConfig::parse(&tsconfig_path, &mut tsconfig_string).map_err(|error| {
-                        ResolveError::from_serde_json_error(tsconfig_path.to_path_buf(), &error)
-                    })?;
-                callback(&mut tsconfig)?;
-                Ok(Arc::new(tsconfig))
-            })
-            .map(|r| Arc::clone(r.value()))
+        // Do not hold the map entry while running `callback`, which loads extended tsconfigs
+        // from the same map and would deadlock on the entry's shard.
+        if let Some(tsconfig) = self.tsconfigs.get(tsconfig_path.path()) {
+            return Ok(Arc::clone(tsconfig.value()));
+        }
+        let path = if tsconfig_path.is_dir(&self.fs) {
+            Cow::Owned(tsconfig_path.path().join("tsconfig.json"))
+        } else {
+            Cow::Borrowed(tsconfig_path.path())
+        };
+        let mut tsconfig_string = self
+            .fs
+            .read_to_string(&path)
+            .map_err(|_| ResolveError::NotFound(path.to_path_buf()))?;
+        let mut tsconfig = TsConfig::parse(&path, &mut tsconfig_string)
+            .map_err(|error| ResolveError::from_serde_json_error(path.to_path_buf(), &error))?;
+        callback(&mut tsconfig)?;
+        let tsconfig = Arc::new(tsconfig);
+        self.tsconfigs.insert(tsconfig_path.to_path_buf(), Arc::clone(&tsconfig));
+        Ok(tsconfig)
     }
 
     // Code copied from parcel
@@ -182,6 +191,7 @@ pub struct CachedPathImpl {
     canonicalized: OnceLock<PathBuf>,
     node_modules: OnceLock<Option<CachedPath>>,
     package_json: OnceLock<Option<Arc<PackageJson>>>,
+    pnp_manifest: OnceLock<Option<Arc<PnpManifest>>>,
 }
 
 impl CachedPathImpl {
@@ -195,6 +205,7 @@ impl CachedPathImpl {
             canonicalized: OnceLock::new(),
             node_modules: OnceLock::new(),
             package_json: OnceLock::new(),
+            pnp_manifest: OnceLock::new(),
         }
     }
 
@@ -303,6 +314,53 @@ impl CachedPathImpl {
             })
             .cloned()
     }
+
+    /// Find the Yarn Plug'n'Play manifest of a path by traversing parent directories.
+    ///
+    /// # Errors
+    ///
+    /// * [ResolveError::JSON]
+    pub fn find_pnp_manifest<Fs: FileSystem>(
+        &self,
+        fs: &Fs,
+    ) -> Result<Option<Arc<PnpManifest>>, ResolveError> {
+        let mut cache_value = Some(self);
+        while let Some(cv) = cache_value {
+            if let Some(pnp_manifest) = cv.pnp_manifest(fs)? {
+                return Ok(Some(pnp_manifest));
+            }
+            cache_value = cv.parent.as_deref();
+        }
+        Ok(None)
+    }
+
+    /// Get `.pnp.data.json` or `.pnp.cjs` of the given directory.
+    ///
+    /// # Errors
+    ///
+    /// * [ResolveError::JSON]
+    fn pnp_manifest<Fs: FileSystem>(
+        &self,
+        fs: &Fs,
+    ) -> Result<Option<Arc<PnpManifest>>, ResolveError> {
+        self.pnp_manifest
+            .get_or_try_init(|| {
+                for file_name in PnpManifest::FILE_NAMES {
+                    let manifest_path = self.path.join(file_name);
+                    let Ok(manifest_string) = fs.read_to_string(&manifest_path) else {
+                        continue;
+                    };
+                    return PnpManifest::parse(manifest_path.clone(), &manifest_string)
+                        .map(Arc::new)
+                        .map(Some)
+                        .map_err(|error| {
+                            ResolveError::from_serde_json_error(manifest_path, &error)
+                        });
+                }
+                Ok(None)
+            })
+            .cloned()
+    }
 }
 
 /// Memoized cache key, code adapted from <https://stackoverflow.com/a/50478038>.
@@ -83,6 +83,10 @@ pub enum ResolveError {
     /// Occurs when alias paths reference each other.
     #[error("Recursion in resolving")]
     Recursion,
+
+    /// Occurs when tsconfig files extend each other.
+    #[error("Tsconfig extends itself {0}")]
+    TsconfigCircularExtend(PathBuf),
 }
 
 impl ResolveError {
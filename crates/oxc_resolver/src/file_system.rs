@@ -4,7 +4,11 @@ use std::{
 };
 
 /// File System abstraction used for `ResolverGeneric`.
-pub trait FileSystem: Default + Send + Sync {
+///
+/// Implement this trait to resolve against a virtual or in-memory file system,
+/// and pass it to [crate::ResolverGeneric::new_with_file_system].
+/// Results are cached by the resolver, call [crate::ResolverGeneric::clear_cache] after the file system changes.
+pub trait FileSystem: Send + Sync {
     /// See [std::fs::read_to_string]
     ///
     /// # Errors
@@ -54,10 +58,14 @@ impl From<fs::Metadata> for FileMetadata {
     }
 }
 
-/// Operating System
-#[derive(Default)]
+/// Operating System, the file system used by [crate::Resolver]
+///
+/// There is no operating system file system on `wasm32`, where all operations fail with
+/// [io::ErrorKind::Unsupported]. Provide a virtual file system to [crate::ResolverGeneric::new_with_file_system] instead.
+#[derive(Debug, Default, Clone, Copy)]
 pub struct FileSystemOs;
 
+#[cfg(not(target_arch = "wasm32"))]
 impl FileSystem for FileSystemOs {
     fn read_to_string<P: AsRef<Path>>(&self, path: P) -> io::Result<String> {
         fs::read_to_string(path)
@@ -75,3 +83,22 @@ impl FileSystem for FileSystemOs {
         fs::read_link(path).map(|p| dunce::simplified(&p).to_path_buf())
     }
 }
+
+#[cfg(target_arch = "wasm32")]
+impl FileSystem for FileSystemOs {
+    fn read_to_string<P: AsRef<Path>>(&self, _path: P) -> io::Result<String> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    fn metadata<P: AsRef<Path>>(&self, _path: P) -> io::Result<FileMetadata> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    fn symlink_metadata<P: AsRef<Path>>(&self, _path: P) -> io::Result<FileMetadata> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    fn read_link<P: AsRef<Path>>(&self, _path: P) -> io::Result<PathBuf> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
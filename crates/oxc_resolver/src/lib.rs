@@ -14,6 +14,12 @@
 //! [CommonJS Module Resolution Algorithm]: https://nodejs.org/api/modules.html#all-together
 //! [ECMAScript Module Resolution Algorithm]: https://nodejs.org/api/esm.html#resolution-algorithm-specification
 //! [parcel-resolver]: https://github.com/parcel-bundler/parcel/blob/v2/packages/utils/node-resolver-rs
+//!
+//! ## WebAssembly
+//!
+//! All file system access goes through the [FileSystem] trait,
+//! so the resolver runs on `wasm32-unknown-unknown` with a virtual file system passed to [ResolverGeneric::new_with_file_system].
+//! [FileSystemOs], the file system of [Resolver], fails all operations on `wasm32`.
 
 mod builtins;
 mod cache;
@@ -23,8 +29,10 @@ mod json_comments;
 mod options;
 mod package_json;
 mod path;
+mod pnp;
 mod resolution;
 mod specifier;
+mod trace;
 mod tsconfig;
 
 #[cfg(test)]
@@ -35,26 +43,31 @@ use std::{
     cmp::Ordering,
     ffi::OsStr,
     fmt,
+    hash::BuildHasherDefault,
     ops::{Deref, DerefMut},
     path::{Path, PathBuf},
     sync::Arc,
 };
 
-use crate::{
+#[cfg(not(target_arch = "wasm32"))]
+use rayon::prelude::*;
+use rustc_hash::FxHasher;
+
+pub use crate::{
     builtins::BUILTINS,
+    error::{JSONError, ResolveError},
+    file_system::{FileMetadata, FileSystem, FileSystemOs},
+    options::{Alias, AliasValue, EnforceExtension, ResolveOptions, Restriction},
+    package_json::{PackageJson, SideEffects},
+    resolution::{AliasFieldRemap, Resolution},
+    trace::ResolveTrace,
+    tsconfig::{CompilerOptions, TsConfig},
+};
+use crate::{
     cache::{Cache, CachedPath},
-    file_system::FileSystemOs,
     package_json::{ExportsField, ExportsKey, MatchObject},
     path::PathUtil,
     specifier::Specifier,
-    tsconfig::TsConfig,
-};
-pub use crate::{
-    error::{JSONError, ResolveError},
-    file_system::{FileMetadata, FileSystem},
-    options::{Alias, AliasValue, EnforceExtension, ResolveOptions, Restriction},
-    package_json::PackageJson,
-    resolution::Resolution,
 };
 
 /// Resolver with the current operating system as the file system
@@ -74,6 +87,8 @@ impl<Fs> fmt::Debug for ResolverGeneric<Fs> {
 
 type ResolveState = Result<Option<CachedPath>, ResolveError>;
 
+type FxIndexSet<T> = indexmap::IndexSet<T, BuildHasherDefault<FxHasher>>;
+
 #[derive(Debug, Default, Clone)]
 struct ResolveContext(ResolveContextImpl);
 
@@ -108,6 +123,13 @@ impl ResolveContext {
         self.resolving_alias = Some(alias);
     }
 
+    /// Record a step for [ResolverGeneric::resolve_traced], `trace` is only called when tracing.
+    fn trace<F: FnOnce() -> ResolveTrace>(&mut self, trace: F) {
+        if let Some(traces) = &mut self.trace {
+            traces.push(trace());
+        }
+    }
+
     fn test_for_infinite_recursion(&mut self) -> Result<(), ResolveError> {
         self.depth += 1;
         // 64 should be more than enough for detecting infinite recursion.
@@ -127,21 +149,30 @@ struct ResolveContextImpl {
     resolving_alias: Option<String>,
     /// For avoiding infinite recursion, which will cause stack overflow.
     depth: u8,
+    /// The first path violating [ResolveOptions::restrictions], reported when no alternative is found.
+    restricted_path: Option<PathBuf>,
+    /// The first alias field remapping, for [Resolution::alias_field_remap].
+    alias_field_remap: Option<AliasFieldRemap>,
+    /// Steps taken so far, `None` when not tracing.
+    trace: Option<Vec<ResolveTrace>>,
 }
 
-impl<Fs: FileSystem> Default for ResolverGeneric<Fs> {
+impl<Fs: FileSystem + Default> Default for ResolverGeneric<Fs> {
     fn default() -> Self {
         Self::new(ResolveOptions::default())
     }
 }
 
-impl<Fs: FileSystem> ResolverGeneric<Fs> {
+impl<Fs: FileSystem + Default> ResolverGeneric<Fs> {
     pub fn new(options: ResolveOptions) -> Self {
-        Self { options: options.sanitize(), cache: Arc::new(Cache::default()) }
+        Self::new_with_file_system(Fs::default(), options)
     }
+}
 
+impl<Fs: FileSystem> ResolverGeneric<Fs> {
+    /// Create a resolver which reads from `file_system`, e.g. a virtual or in-memory file system.
     pub fn new_with_file_system(file_system: Fs, options: ResolveOptions) -> Self {
-        Self { cache: Arc::new(Cache::new(file_system)), ..Self::new(options) }
+        Self { options: options.sanitize(), cache: Arc::new(Cache::new(file_system)) }
     }
 
     #[must_use]
@@ -153,10 +184,45 @@ impl<Fs: FileSystem> ResolverGeneric<Fs> {
         &self.options
     }
 
+    /// Drop all cached file system information, package.json and tsconfig files.
     pub fn clear_cache(&self) {
         self.cache.clear();
     }
 
+    /// Drop the cached information of a changed file or directory, for watch mode and editors.
+    ///
+    /// The cache of the parent directory is dropped as well,
+    /// because it memoizes lookups such as `package.json` and `node_modules`.
+    pub fn invalidate<P: AsRef<Path>>(&self, path: P) {
+        self.cache.invalidate(path.as_ref());
+    }
+
+    /// Find the closest package.json of `path` by traversing parent directories,
+    /// e.g. to read the dependencies declared by the package a file belongs to.
+    ///
+    /// The package.json is cached together with the ones read during resolution.
+    ///
+    /// # Errors
+    ///
+    /// * [ResolveError::JSON]
+    pub fn find_package_json<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> Result<Option<Arc<PackageJson>>, ResolveError> {
+        self.cache.value(path.as_ref()).find_package_json(&self.cache.fs, &self.options)
+    }
+
+    /// Load the tsconfig of [ResolveOptions::tsconfig], with the options inherited from `extends`
+    ///
+    /// # Errors
+    ///
+    /// * See [ResolveError]
+    pub fn tsconfig(&self) -> Result<Option<Arc<TsConfig>>, ResolveError> {
+        let Some(tsconfig_path) = &self.options.tsconfig else { return Ok(None) };
+        let tsconfig_path = self.cache.value(tsconfig_path);
+        self.load_tsconfig(&tsconfig_path).map(Some)
+    }
+
     /// Resolve `specifier` at `path`
     ///
     /// # Errors
@@ -167,27 +233,124 @@ impl<Fs: FileSystem> ResolverGeneric<Fs> {
         path: P,
         specifier: &str,
     ) -> Result<Resolution, ResolveError> {
-        self.resolve_impl(path.as_ref(), specifier)
+        self.resolve_impl(path.as_ref(), specifier, &mut self.new_context())
     }
 
-    #[tracing::instrument(name = "resolve", level = "DEBUG", ret, skip(self), fields(options = %self.options))]
-    fn resolve_impl(&self, path: &Path, specifier: &str) -> Result<Resolution, ResolveError> {
-        let mut ctx = ResolveContext(ResolveContextImpl {
+    /// Resolve a batch of `(path, specifier)` requests in parallel on the rayon thread pool,
+    /// for bundlers resolving many specifiers at once. Resolves sequentially on `wasm32`.
+    ///
+    /// Identical requests are resolved once. Before resolving, the directories of the requests
+    /// and their parents are read in parallel, i.e. their metadata, `node_modules` and
+    /// `package.json`, so that the resolutions mostly hit the cache shared between the requests
+    /// instead of blocking on the file system one after another.
+    ///
+    /// The results are in the same order as `requests`.
+    pub fn resolve_all<P: AsRef<Path> + Sync>(
+        &self,
+        requests: &[(P, &str)],
+    ) -> Vec<Result<Resolution, ResolveError>> {
+        let mut unique_requests = FxIndexSet::default();
+        let mut indices = Vec::with_capacity(requests.len());
+        for (path, specifier) in requests {
+            indices.push(unique_requests.insert_full((path.as_ref(), *specifier)).0);
+        }
+        let directories = unique_requests
+            .iter()
+            .map(|(path, _)| *path)
+            .collect::<FxIndexSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let unique_requests = unique_requests.into_iter().collect::<Vec<_>>();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let (directories, unique_requests) = (directories.par_iter(), unique_requests.par_iter());
+        #[cfg(target_arch = "wasm32")]
+        let (directories, unique_requests) = (directories.iter(), unique_requests.iter());
+        directories.for_each(|directory| self.warm_cache(directory));
+        let resolutions = unique_requests
+            .map(|(path, specifier)| self.resolve(path, specifier))
+            .collect::<Vec<_>>();
+        indices.into_iter().map(|index| resolutions[index].clone()).collect()
+    }
+
+    /// Read the file system information looked up by every resolution in `directory`
+    fn warm_cache(&self, directory: &Path) {
+        let cached_path = self.cache.value(directory);
+        // Errors are reported by the resolutions
+        let _ = cached_path.find_package_json(&self.cache.fs, &self.options);
+        let mut cached_path = Some(&cached_path);
+        while let Some(cv) = cached_path {
+            if cv.is_dir(&self.cache.fs) {
+                cv.cached_node_modules(&self.cache);
+            }
+            cached_path = cv.parent();
+        }
+    }
+
+    /// Resolve `specifier` at `path` and return the steps taken along with the result,
+    /// i.e. the attempted files, alias expansions and matched "exports" branches,
+    /// for explaining why a specifier cannot be resolved.
+    pub fn resolve_traced<P: AsRef<Path>>(
+        &self,
+        path: P,
+        specifier: &str,
+    ) -> (Result<Resolution, ResolveError>, Vec<ResolveTrace>) {
+        let mut ctx = self.new_context();
+        ctx.trace = Some(vec![]);
+        let result = self.resolve_impl(path.as_ref(), specifier, &mut ctx);
+        (result, ctx.trace.take().unwrap_or_default())
+    }
+
+    /// Resolve `specifier` at `path` with `conditions` in place of [ResolveOptions::condition_names],
+    /// e.g. to resolve the same specifier for both the `browser` and the `node` condition.
+    ///
+    /// The cache is shared with this resolver.
+    ///
+    /// # Errors
+    ///
+    /// * See [ResolveError]
+    pub fn resolve_with_conditions<P: AsRef<Path>>(
+        &self,
+        path: P,
+        specifier: &str,
+        conditions: &[&str],
+    ) -> Result<Resolution, ResolveError> {
+        let resolver = self.clone_with_options(ResolveOptions {
+            condition_names: conditions.iter().map(ToString::to_string).collect(),
+            ..self.options.clone()
+        });
+        resolver.resolve_impl(path.as_ref(), specifier, &mut resolver.new_context())
+    }
+
+    fn new_context(&self) -> ResolveContext {
+        ResolveContext(ResolveContextImpl {
             fully_specified: self.options.fully_specified,
             ..ResolveContextImpl::default()
-        });
+        })
+    }
+
+    #[tracing::instrument(name = "resolve", level = "DEBUG", ret, skip(self, ctx), fields(options = %self.options))]
+    fn resolve_impl(
+        &self,
+        path: &Path,
+        specifier: &str,
+        ctx: &mut ResolveContext,
+    ) -> Result<Resolution, ResolveError> {
         let specifier = Specifier::parse(specifier).map_err(ResolveError::Specifier)?;
         ctx.with_query_fragment(specifier.query, specifier.fragment);
         let cached_path = self.cache.value(path);
-        let cached_path =
-            self.require(&cached_path, specifier.path(), &mut ctx).or_else(|err| {
-                if err.is_ignore() {
-                    return Err(err);
-                }
-                // enhanced-resolve: try fallback
-                self.load_alias(&cached_path, specifier.path(), &self.options.fallback, &mut ctx)
-                    .and_then(|value| value.ok_or(err))
-            })?;
+        let cached_path = self.require(&cached_path, specifier.path(), ctx).or_else(|err| {
+            if err.is_ignore() {
+                return Err(err);
+            }
+            // enhanced-resolve: try fallback
+            self.load_alias(&cached_path, specifier.path(), &self.options.fallback, ctx)
+                .and_then(|value| value.ok_or(err))
+        });
+        let cached_path = cached_path.map_err(|err| match (err, ctx.restricted_path.take()) {
+            (ResolveError::NotFound(_), Some(path)) => ResolveError::Restriction(path),
+            (err, _) => err,
+        })?;
         let path = self.load_realpath(&cached_path)?;
         // enhanced-resolve: restrictions
         self.check_restrictions(&path)?;
@@ -195,6 +358,7 @@ impl<Fs: FileSystem> ResolverGeneric<Fs> {
             path,
             query: ctx.query.take(),
             fragment: ctx.fragment.take(),
+            alias_field_remap: ctx.alias_field_remap.take(),
             package_json: cached_path.find_package_json(&self.cache.fs, &self.options)?,
         })
     }
@@ -377,10 +541,22 @@ impl<Fs: FileSystem> ResolverGeneric<Fs> {
         if let Some(path) = self.load_package_self(cached_path, specifier, ctx)? {
             return Ok(path);
         }
+        // Yarn Plug'n'Play
+        if self.options.enable_pnp {
+            if let Some(path) = self.load_pnp(cached_path, specifier, ctx)? {
+                return Ok(path);
+            }
+        }
         // 6. LOAD_NODE_MODULES(X, dirname(Y))
         if let Some(path) = self.load_node_modules(cached_path, specifier, ctx)? {
             return Ok(path);
         }
+        // Workspace packages declared with the `workspace:` protocol
+        if !self.options.workspaces.is_empty() {
+            if let Some(path) = self.load_workspace(cached_path, specifier, ctx)? {
+                return Ok(path);
+            }
+        }
         // 7. THROW "not found"
         Err(ResolveError::NotFound(cached_path.to_path_buf()))
     }
@@ -519,14 +695,9 @@ impl<Fs: FileSystem> ResolverGeneric<Fs> {
 
     fn check_restrictions(&self, path: &Path) -> Result<(), ResolveError> {
         // https://github.com/webpack/enhanced-resolve/blob/a998c7d218b7a9ec2461fc4fddd1ad5dd7687485/lib/RestrictionsPlugin.js#L19-L24
+        // `Path::starts_with` compares whole components, so `/a/bc` is not inside `/a/b`.
         fn is_inside(path: &Path, parent: &Path) -> bool {
-            if !path.starts_with(parent) {
-                return false;
-            }
-            if path.as_os_str().len() == parent.as_os_str().len() {
-                return true;
-            }
-            path.strip_prefix(parent).is_ok_and(|p| p == Path::new("./"))
+            path.starts_with(parent)
         }
         for restriction in &self.options.restrictions {
             match restriction {
@@ -535,8 +706,10 @@ impl<Fs: FileSystem> ResolverGeneric<Fs> {
                         return Err(ResolveError::Restriction(path.to_path_buf()));
                     }
                 }
-                Restriction::RegExp(_) => {
-                    return Err(ResolveError::Unimplemented("Restriction with regex"))
+                Restriction::RegExp(regex) => {
+                    if !regex.is_match(&path.to_string_lossy()) {
+                        return Err(ResolveError::Restriction(path.to_path_buf()));
+                    }
                 }
             }
         }
@@ -574,6 +747,12 @@ impl<Fs: FileSystem> ResolverGeneric<Fs> {
             if let Some(path) = self.load_browser_field(path, None, &package_json, ctx)? {
                 return Ok(Some(path));
             }
+            if !package_json.browser_fields.is_empty() {
+                ctx.trace(|| ResolveTrace::AliasFieldMiss {
+                    package_json: package_json.path.clone(),
+                    request: path.to_string_lossy().to_string(),
+                });
+            }
         }
         // enhanced-resolve: try file as alias
         let alias_specifier = cached_path.path().to_string_lossy();
@@ -582,7 +761,16 @@ impl<Fs: FileSystem> ResolverGeneric<Fs> {
         {
             return Ok(Some(path));
         }
-        if cached_path.is_file(&self.cache.fs) {
+        let exists = cached_path.is_file(&self.cache.fs);
+        ctx.trace(|| ResolveTrace::File { path: cached_path.to_path_buf(), exists });
+        if exists {
+            // enhanced-resolve: restrictions, try the other candidates when the file is restricted
+            if let Err(ResolveError::Restriction(path)) =
+                self.check_restrictions(cached_path.path())
+            {
+                ctx.restricted_path.get_or_insert(path);
+                return Ok(None);
+            }
             return Ok(Some(cached_path.clone()));
         }
         Ok(None)
@@ -647,6 +835,68 @@ impl<Fs: FileSystem> ResolverGeneric<Fs> {
         Ok(None)
     }
 
+    /// Resolve the package directory from the Yarn Plug'n'Play manifest,
+    /// then load the package the same way as from a `node_modules` directory.
+    fn load_pnp(
+        &self,
+        cached_path: &CachedPath,
+        specifier: &str,
+        ctx: &mut ResolveContext,
+    ) -> ResolveState {
+        let Some(pnp_manifest) = cached_path.find_pnp_manifest(&self.cache.fs)? else {
+            return Ok(None);
+        };
+        let (package_name, subpath) = Self::parse_package_specifier(specifier);
+        let Some(package_path) = pnp_manifest.resolve_package(package_name, cached_path.path())
+        else {
+            return Ok(None);
+        };
+        let cached_path = self.cache.value(package_path);
+        if let Some(path) = self.load_package_exports(subpath, &cached_path, ctx)? {
+            return Ok(Some(path));
+        }
+        let package_file = package_path.normalize_with(subpath.trim_start_matches('/'));
+        let cached_path = self.cache.value(&package_file);
+        self.load_as_file_or_directory(&cached_path, specifier, ctx)
+    }
+
+    /// Resolve the package directory from [ResolveOptions::workspaces] when the closest package.json
+    /// declares the package with the `workspace:` protocol,
+    /// then load the package the same way as from a `node_modules` directory.
+    fn load_workspace(
+        &self,
+        cached_path: &CachedPath,
+        specifier: &str,
+        ctx: &mut ResolveContext,
+    ) -> ResolveState {
+        let (package_name, subpath) = Self::parse_package_specifier(specifier);
+        let Some(package_json) = cached_path.find_package_json(&self.cache.fs, &self.options)?
+        else {
+            return Ok(None);
+        };
+        if !package_json.is_workspace_dependency(package_name) {
+            return Ok(None);
+        }
+        for workspace in &self.options.workspaces {
+            let cached_path = self.cache.value(workspace);
+            let Some(workspace_package_json) =
+                cached_path.package_json(&self.cache.fs, &self.options)?
+            else {
+                continue;
+            };
+            if workspace_package_json.name.as_deref() != Some(package_name) {
+                continue;
+            }
+            if let Some(path) = self.load_package_exports(subpath, &cached_path, ctx)? {
+                return Ok(Some(path));
+            }
+            let package_file = workspace.normalize_with(subpath.trim_start_matches('/'));
+            let cached_path = self.cache.value(&package_file);
+            return self.load_as_file_or_directory(&cached_path, specifier, ctx);
+        }
+        Ok(None)
+    }
+
     fn load_package_exports(
         &self,
         subpath: &str,
@@ -762,12 +1012,20 @@ impl<Fs: FileSystem> ResolverGeneric<Fs> {
         package_json: &PackageJson,
         ctx: &mut ResolveContext,
     ) -> ResolveState {
+        let request = specifier;
         let Some(specifier) = package_json.resolve_browser_field(path, specifier)? else {
             return Ok(None);
         };
         if ctx.resolving_alias.as_ref().is_some_and(|s| s == specifier) {
             return Ok(None);
         }
+        let remap = AliasFieldRemap {
+            package_json: package_json.path.clone(),
+            request: request.map_or_else(|| path.to_string_lossy().to_string(), str::to_string),
+            target: specifier.to_string(),
+        };
+        ctx.trace(|| ResolveTrace::AliasField(remap.clone()));
+        ctx.alias_field_remap.get_or_insert(remap);
         let specifier = Specifier::parse(specifier).map_err(ResolveError::Specifier)?;
         ctx.with_query_fragment(specifier.query, specifier.fragment);
         ctx.with_resolving_alias(specifier.path().to_string());
@@ -847,6 +1105,10 @@ impl<Fs: FileSystem> ResolverGeneric<Fs> {
             && !request.strip_prefix(alias_value).is_some_and(|prefix| prefix.starts_with('/'))
         {
             let new_specifier = format!("{alias_value}{}", &request[alias_key.len()..]);
+            ctx.trace(|| ResolveTrace::Alias {
+                request: request.to_string(),
+                new_request: new_specifier.clone(),
+            });
             ctx.with_fully_specified(false);
             return match self.require(cached_path, &new_specifier, ctx) {
                 Err(ResolveError::NotFound(_)) => Ok(None),
@@ -907,26 +1169,34 @@ impl<Fs: FileSystem> ResolverGeneric<Fs> {
     }
 
     fn load_tsconfig(&self, cached_path: &CachedPath) -> Result<Arc<TsConfig>, ResolveError> {
-        self.cache.tsconfig(cached_path, |tsconfig| {
+        self.load_tsconfig_impl(cached_path, &mut vec![])
+    }
+
+    /// `extended_from` is the chain of tsconfig files being loaded, for detecting circular `extends`.
+    fn load_tsconfig_impl(
+        &self,
+        cached_path: &CachedPath,
+        extended_from: &mut Vec<PathBuf>,
+    ) -> Result<Arc<TsConfig>, ResolveError> {
+        if extended_from.iter().any(|path| path == cached_path.path()) {
+            return Err(ResolveError::TsconfigCircularExtend(cached_path.to_path_buf()));
+        }
+        extended_from.push(cached_path.to_path_buf());
+        let tsconfig = self.cache.tsconfig(cached_path, |tsconfig| {
             // Extend tsconfig
             if !tsconfig.extends().is_empty() {
-                let resolver = self.clone_with_options(ResolveOptions {
-                    extensions: vec![".json".into()],
-                    main_files: vec!["tsconfig".into()],
-                    ..ResolveOptions::default()
-                });
+                let directory = self.cache.value(tsconfig.directory());
                 let mut extended_tsconfigs = vec![];
                 for tsconfig_extend_specifier in tsconfig.extends() {
-                    let extended_tsconfig_path = resolver.require(
-                        cached_path.parent().unwrap(),
-                        tsconfig_extend_specifier,
-                        &mut ResolveContext::default(),
-                    )?;
-                    let extended_tsconfig = self.load_tsconfig(&extended_tsconfig_path)?;
+                    let extended_tsconfig_path =
+                        self.resolve_tsconfig_extends(&directory, tsconfig_extend_specifier)?;
+                    let extended_tsconfig =
+                        self.load_tsconfig_impl(&extended_tsconfig_path, extended_from)?;
                     extended_tsconfigs.push(extended_tsconfig);
                 }
-                for extended_tsconfig in extended_tsconfigs {
-                    tsconfig.extend_tsconfig(&extended_tsconfig);
+                // Later entries of an `extends` array override earlier ones.
+                for extended_tsconfig in extended_tsconfigs.iter().rev() {
+                    tsconfig.extend_tsconfig(extended_tsconfig);
                 }
             }
             // Load project references
@@ -938,7 +1208,56 @@ impl<Fs: FileSystem> ResolverGeneric<Fs> {
                 reference.tsconfig.replace(tsconfig);
             }
             Ok(())
-        })
+        });
+        extended_from.pop();
+        tsconfig
+    }
+
+    /// Resolve the `extends` field of a tsconfig in `directory`,
+    /// which is a path or a package with a "tsconfig" field or a `tsconfig.json` file.
+    fn resolve_tsconfig_extends(
+        &self,
+        directory: &CachedPath,
+        specifier: &str,
+    ) -> Result<CachedPath, ResolveError> {
+        let resolver = self.clone_with_options(ResolveOptions {
+            extensions: vec![".json".into()],
+            main_files: vec!["tsconfig".into()],
+            ..ResolveOptions::default()
+        });
+        if !specifier.starts_with(['.', '/']) {
+            if let Some(path) = resolver.load_tsconfig_field(directory, specifier)? {
+                return Ok(path);
+            }
+        }
+        resolver.require(directory, specifier, &mut ResolveContext::default())
+    }
+
+    /// The "tsconfig" field in package.json of `extends: "package"`
+    fn load_tsconfig_field(
+        &self,
+        directory: &CachedPath,
+        package_name: &str,
+    ) -> Result<Option<CachedPath>, ResolveError> {
+        for cached_path in std::iter::successors(Some(directory), |p| p.parent()) {
+            let Some(node_modules) = cached_path.cached_node_modules(&self.cache) else {
+                continue;
+            };
+            let package_path = self.cache.value(&node_modules.path().join(package_name));
+            let Some(package_json) = package_path.package_json(&self.cache.fs, &self.options)?
+            else {
+                continue;
+            };
+            let Some(tsconfig_field) =
+                package_json.raw_json().get("tsconfig").and_then(serde_json::Value::as_str)
+            else {
+                return Ok(None);
+            };
+            let tsconfig_path =
+                self.cache.value(&package_path.path().normalize_with(tsconfig_field));
+            return Ok(tsconfig_path.is_file(&self.cache.fs).then_some(tsconfig_path));
+        }
+        Ok(None)
     }
 
     /// PACKAGE_RESOLVE(packageSpecifier, parentURL)
@@ -1233,7 +1552,7 @@ impl<Fs: FileSystem> ResolverGeneric<Fs> {
     }
 
     /// PACKAGE_TARGET_RESOLVE(packageURL, target, patternMatch, isImports, conditions)
-    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
     fn package_target_resolve(
         &self,
         package_url: &Path,
@@ -1302,6 +1621,11 @@ impl<Fs: FileSystem> ResolverGeneric<Fs> {
                     return Err(ResolveError::InvalidPackageTarget(target.to_string()));
                 }
                 let resolved_target = package_url.join(target.as_ref()).normalize();
+                ctx.trace(|| ResolveTrace::ExportsTarget {
+                    package_json: package_url.join("package.json"),
+                    key: target_key.to_string(),
+                    target: target.to_string(),
+                });
                 // 6. If patternMatch split on "/" or "\" contains any "", ".", "..", or "node_modules" segments, case insensitive and including percent encoded variants, throw an Invalid Module Specifier error.
                 // 7. Return the URL resolution of resolvedTarget with every instance of "*" replaced with patternMatch.
                 let value = self.cache.value(&resolved_target);
@@ -1326,6 +1650,12 @@ impl<Fs: FileSystem> ResolverGeneric<Fs> {
                     if is_default
                         || matches!(key, ExportsKey::CustomCondition(condition) if conditions.contains(condition))
                     {
+                        if let ExportsKey::CustomCondition(condition) = key {
+                            ctx.trace(|| ResolveTrace::ExportsCondition {
+                                package_json: package_url.join("package.json"),
+                                condition: condition.clone(),
+                            });
+                        }
                         // 1. Let targetValue be the value of the p property in target.
                         // 2. Let resolved be the result of PACKAGE_TARGET_RESOLVE( packageURL, targetValue, patternMatch, isImports, conditions).
                         let resolved = self.package_target_resolve(
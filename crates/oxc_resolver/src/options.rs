@@ -1,5 +1,7 @@
 use std::{fmt, path::PathBuf};
 
+use regex::Regex;
+
 /// Module Resolution Options
 ///
 /// Options are directly ported from [enhanced-resolve](https://github.com/webpack/enhanced-resolve#resolver-options).
@@ -108,6 +110,9 @@ pub struct ResolveOptions {
 
     /// A list of resolve restrictions to restrict the paths that a request can be resolved on.
     ///
+    /// A resolved path must satisfy all of the restrictions, other candidates such as other main fields
+    /// or extensions are tried when it does not. [ResolveError::Restriction] is returned when none is found.
+    ///
     /// Default `[]`
     pub restrictions: Vec<Restriction>,
 
@@ -121,6 +126,9 @@ pub struct ResolveOptions {
     /// When enabled, symlinked resources are resolved to their real path, not their symlinked location.
     /// Note that this may cause module resolution to fail when using tools that symlink packages (like npm link).
     ///
+    /// Disable to preserve the symlinked path, e.g. for pnpm where the identity of a package depends on
+    /// the `node_modules` it is linked into. This is the equivalent of Node.js's `--preserve-symlinks`.
+    ///
     /// Default `true`
     pub symlinks: bool,
 
@@ -129,6 +137,23 @@ pub struct ResolveOptions {
     ///
     /// Default `false`
     pub builtin_modules: bool,
+
+    /// Resolve bare specifiers with the [Yarn Plug'n'Play](https://yarnpkg.com/features/pnp) manifest
+    /// (`.pnp.data.json` or `.pnp.cjs`) found in a parent directory, before looking into `node_modules`.
+    ///
+    /// Packages stored in zip archives can only be resolved by a [crate::FileSystem] that reads zip archives.
+    ///
+    /// Default `false`
+    pub enable_pnp: bool,
+
+    /// Directories of the packages in a monorepo workspace.
+    ///
+    /// A bare specifier that is not found in `node_modules` resolves to the workspace package of the same name
+    /// when the closest package.json declares it with the `workspace:` protocol, e.g. `"a": "workspace:*"`,
+    /// so the packages of a pnpm, yarn or npm workspace can be resolved before they are linked into `node_modules`.
+    ///
+    /// Default `[]`
+    pub workspaces: Vec<PathBuf>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -167,8 +192,10 @@ pub enum AliasValue {
 
 #[derive(Debug, Clone)]
 pub enum Restriction {
+    /// The resolved path must be inside this directory.
     Path(PathBuf),
-    RegExp(String),
+    /// The resolved path must match this regular expression, e.g. `\.(sass|scss|css)$`.
+    RegExp(Regex),
 }
 
 impl Default for ResolveOptions {
@@ -195,6 +222,8 @@ impl Default for ResolveOptions {
             roots: vec![],
             symlinks: true,
             builtin_modules: false,
+            enable_pnp: false,
+            workspaces: vec![],
         }
     }
 }
@@ -271,12 +300,18 @@ impl fmt::Display for ResolveOptions {
         if !self.roots.is_empty() {
             write!(f, "roots:{:?},", self.roots)?;
         }
-        if self.symlinks {
+        if !self.symlinks {
             write!(f, "symlinks:{:?},", self.symlinks)?;
         }
         if self.builtin_modules {
             write!(f, "builtin_modules:{:?},", self.builtin_modules)?;
         }
+        if self.enable_pnp {
+            write!(f, "enable_pnp:{:?},", self.enable_pnp)?;
+        }
+        if !self.workspaces.is_empty() {
+            write!(f, "workspaces:{:?},", self.workspaces)?;
+        }
         Ok(())
     }
 }
@@ -318,11 +353,14 @@ mod test {
             prefer_absolute: true,
             restrictions: vec![Restriction::Path(PathBuf::from("restrictions"))],
             roots: vec![PathBuf::from("roots")],
+            symlinks: false,
             builtin_modules: true,
+            enable_pnp: true,
+            workspaces: vec![PathBuf::from("packages/a")],
             ..ResolveOptions::default()
         };
 
-        let expected = r#"tsconfig:"tsconfig.json",alias:[("a", [Ignore])],alias_fields:[["browser"]],condition_names:["require"],enforce_extension:Enabled,exports_fields:[["exports"]],extension_alias:[(".js", [".ts"])],extensions:[".js", ".json", ".node"],fallback:[("fallback", [Ignore])],fully_specified:true,main_fields:["main"],main_files:["index"],modules:["node_modules"],resolve_to_context:true,prefer_relative:true,prefer_absolute:true,restrictions:[Path("restrictions")],roots:["roots"],symlinks:true,builtin_modules:true,"#;
+        let expected = r#"tsconfig:"tsconfig.json",alias:[("a", [Ignore])],alias_fields:[["browser"]],condition_names:["require"],enforce_extension:Enabled,exports_fields:[["exports"]],extension_alias:[(".js", [".ts"])],extensions:[".js", ".json", ".node"],fallback:[("fallback", [Ignore])],fully_specified:true,main_fields:["main"],main_files:["index"],modules:["node_modules"],resolve_to_context:true,prefer_relative:true,prefer_absolute:true,restrictions:[Path("restrictions")],roots:["roots"],symlinks:false,builtin_modules:true,enable_pnp:true,workspaces:["packages/a"],"#;
         assert_eq!(format!("{options}"), expected);
     }
 }
@@ -31,6 +31,18 @@ pub struct PackageJson {
     /// <https://nodejs.org/api/packages.html#name>
     pub name: Option<String>,
 
+    /// The "version" field of the package.
+    ///
+    /// <https://docs.npmjs.com/cli/configuring-npm/package-json#version>
+    pub version: Option<String>,
+
+    /// The "sideEffects" field tells bundlers which modules of the package are free of side effects.
+    /// Invalid values are ignored.
+    ///
+    /// <https://webpack.js.org/guides/tree-shaking/#mark-the-file-as-side-effect-free>
+    #[serde(skip)]
+    pub side_effects: Option<SideEffects>,
+
     /// The "main" field defines the entry point of a package when imported by name via a node_modules lookup. Its value is a path.
     /// When a package has an "exports" field, this will take precedence over the "main" field when importing the package by name.
     ///
@@ -105,6 +117,16 @@ impl<'de> Deserialize<'de> for ExportsKey {
     }
 }
 
+/// Value of the "sideEffects" field in package.json.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum SideEffects {
+    /// `true` or `false` for all modules of the package.
+    Bool(bool),
+    /// Glob patterns of the modules with side effects.
+    Array(Vec<String>),
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 pub enum BrowserField {
@@ -137,6 +159,9 @@ impl PackageJson {
                     package_json.main_fields.push(value.clone());
                 }
             }
+            package_json.side_effects = package_json_value
+                .get("sideEffects")
+                .and_then(|value| SideEffects::deserialize(value).ok());
         }
 
         // Dynamically create `browser_fields`.
@@ -198,6 +223,15 @@ impl PackageJson {
         self.raw_json.as_ref()
     }
 
+    /// Whether `package_name` is declared as a dependency with the `workspace:` protocol,
+    /// e.g. `"dependencies": { "a": "workspace:*" }`.
+    pub fn is_workspace_dependency(&self, package_name: &str) -> bool {
+        ["dependencies", "devDependencies", "peerDependencies", "optionalDependencies"]
+            .into_iter()
+            .filter_map(|field| self.raw_json.get(field)?.get(package_name)?.as_str())
+            .any(|version| version.starts_with("workspace:"))
+    }
+
     /// Directory to `package.json`
     ///
     /// # Panics
@@ -0,0 +1,205 @@
+//! Yarn Plug'n'Play manifest
+//!
+//! Yarn Berry installs packages without a `node_modules` directory and writes the dependency tree
+//! to `.pnp.data.json`, or inlines it into `.pnp.cjs` as the `RAW_RUNTIME_STATE` string.
+//!
+//! References:
+//! * [Plug'n'Play specification](https://yarnpkg.com/advanced/pnp-spec)
+//! * [pnp-rs](https://github.com/yarnpkg/pnp-rs)
+use std::{
+    hash::BuildHasherDefault,
+    path::{Component, Path, PathBuf},
+};
+
+use rustc_hash::{FxHashMap, FxHasher};
+use serde::Deserialize;
+
+use crate::path::PathUtil;
+
+type FxIndexMap<K, V> = indexmap::IndexMap<K, V, BuildHasherDefault<FxHasher>>;
+
+/// A dependency reference, either `"npm:1.0.0"` or an alias `["name", "npm:1.0.0"]`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum PackageDependency {
+    Reference(String),
+    Alias(String, String),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PackageInformation {
+    package_location: PathBuf,
+    #[serde(default)]
+    package_dependencies: Vec<(String, Option<PackageDependency>)>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawManifest {
+    #[serde(default)]
+    enable_top_level_fallback: bool,
+    #[serde(default)]
+    fallback_pool: Vec<(String, Option<PackageDependency>)>,
+    package_registry_data: Vec<(Option<String>, PackageReferences)>,
+}
+
+/// `[reference, information]` pairs of a package name
+type PackageReferences = Vec<(Option<String>, PackageInformation)>;
+
+#[derive(Debug)]
+struct Package {
+    /// Absolute path to the package directory, with virtual paths resolved.
+    location: PathBuf,
+    dependencies: FxHashMap<String, Option<PackageDependency>>,
+}
+
+/// Parsed `.pnp.data.json` or `.pnp.cjs`.
+#[derive(Debug)]
+pub struct PnpManifest {
+    /// Path to the manifest file
+    pub path: PathBuf,
+    /// Packages keyed by `(name, reference)`, the top-level workspace is `(None, None)`.
+    packages: FxIndexMap<(Option<String>, Option<String>), Package>,
+    enable_top_level_fallback: bool,
+    fallback_pool: FxHashMap<String, Option<PackageDependency>>,
+}
+
+impl PnpManifest {
+    /// Manifest files in the order they are looked up.
+    pub const FILE_NAMES: [&'static str; 2] = [".pnp.data.json", ".pnp.cjs"];
+
+    /// Parse a manifest, `.pnp.cjs` files are searched for the inlined `RAW_RUNTIME_STATE`.
+    ///
+    /// # Errors
+    ///
+    /// * When the manifest is not valid JSON
+    pub fn parse(path: PathBuf, content: &str) -> Result<Self, serde_json::Error> {
+        let raw_manifest: RawManifest = if path.extension().is_some_and(|ext| ext == "cjs") {
+            serde_json::from_str(&Self::extract_runtime_state(content).unwrap_or_default())?
+        } else {
+            serde_json::from_str(content)?
+        };
+
+        let root = path.parent().unwrap_or_else(|| Path::new("/"));
+        let mut packages = FxIndexMap::default();
+        for (name, references) in raw_manifest.package_registry_data {
+            for (reference, info) in references {
+                let location = Self::devirtualize(&root.normalize_with(&info.package_location));
+                let dependencies = info.package_dependencies.into_iter().collect();
+                packages.insert((name.clone(), reference), Package { location, dependencies });
+            }
+        }
+        // Longest locations first so the first match is the closest package.
+        packages
+            .sort_by(|_, a, _, b| b.location.as_os_str().len().cmp(&a.location.as_os_str().len()));
+
+        Ok(Self {
+            path,
+            packages,
+            enable_top_level_fallback: raw_manifest.enable_top_level_fallback,
+            fallback_pool: raw_manifest.fallback_pool.into_iter().collect(),
+        })
+    }
+
+    /// Extract the JSON from `const RAW_RUNTIME_STATE = '...'` in `.pnp.cjs`.
+    fn extract_runtime_state(content: &str) -> Option<String> {
+        let start = content.find("RAW_RUNTIME_STATE")?;
+        let start = start + content[start..].find('\'')? + 1;
+        let mut json = String::new();
+        let mut escaped = false;
+        for c in content[start..].chars() {
+            match c {
+                '\'' if !escaped => return Some(json),
+                '\\' if !escaped => escaped = true,
+                _ => {
+                    escaped = false;
+                    json.push(c);
+                }
+            }
+        }
+        None
+    }
+
+    /// Resolve `.yarn/__virtual__/<hash>/<depth>/<path>` to the real location,
+    /// which is `<path>` relative to `depth` directories above `.yarn`.
+    fn devirtualize(path: &Path) -> PathBuf {
+        let mut components = path.components();
+        let mut base = PathBuf::new();
+        while let Some(component) = components.next() {
+            if component == Component::Normal("__virtual__".as_ref()) {
+                let _hash = components.next();
+                let depth = components
+                    .next()
+                    .and_then(|c| c.as_os_str().to_str())
+                    .and_then(|depth| depth.parse::<usize>().ok());
+                let Some(depth) = depth else { break };
+                for _ in 0..depth {
+                    base.pop();
+                }
+                base.push(components.as_path());
+                return base;
+            }
+            base.push(component);
+        }
+        path.to_path_buf()
+    }
+
+    /// Resolve a bare `package_name` imported from `issuer` to the directory of the package.
+    ///
+    /// Returns `None` when the issuer is not part of the dependency tree,
+    /// or when the package is not a dependency of the issuer.
+    pub fn resolve_package(&self, package_name: &str, issuer: &Path) -> Option<&Path> {
+        let issuer =
+            self.packages.values().find(|package| issuer.starts_with(&package.location))?;
+        let dependency = issuer.dependencies.get(package_name).or_else(|| {
+            if !self.enable_top_level_fallback {
+                return None;
+            }
+            self.packages
+                .get(&(None, None))
+                .and_then(|top_level| top_level.dependencies.get(package_name))
+                .or_else(|| self.fallback_pool.get(package_name))
+        })?;
+        // `null` for missing peer dependencies
+        let (name, reference) = match dependency.as_ref()? {
+            PackageDependency::Reference(reference) => (package_name, reference),
+            PackageDependency::Alias(name, reference) => (name.as_str(), reference),
+        };
+        self.packages
+            .get(&(Some(name.to_string()), Some(reference.clone())))
+            .map(|package| package.location.as_path())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use super::PnpManifest;
+
+    #[test]
+    fn devirtualize() {
+        let cases = [
+            (
+                "/p/.yarn/__virtual__/a-virtual-1/0/cache/a.zip/node_modules/a",
+                "/p/.yarn/cache/a.zip/node_modules/a",
+            ),
+            ("/p/.yarn/__virtual__/a-virtual-1/2/x/a", "/x/a"),
+            ("/p/.yarn/unplugged/a/node_modules/a", "/p/.yarn/unplugged/a/node_modules/a"),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(PnpManifest::devirtualize(Path::new(path)), PathBuf::from(expected));
+        }
+    }
+
+    #[test]
+    fn extract_runtime_state() {
+        let content = "const RAW_RUNTIME_STATE =\n'{\\\n  \"a\": \"\\'b\\'\"\\\n}';\n";
+        assert_eq!(
+            PnpManifest::extract_runtime_state(content).as_deref(),
+            Some("{\n  \"a\": \"'b'\"\n}")
+        );
+        assert_eq!(PnpManifest::extract_runtime_state("module.exports = {}"), None);
+    }
+}
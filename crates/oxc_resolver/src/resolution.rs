@@ -17,6 +17,23 @@ pub struct Resolution {
     pub(crate) fragment: Option<String>,
 
     pub(crate) package_json: Option<Arc<PackageJson>>,
+
+    pub(crate) alias_field_remap: Option<AliasFieldRemap>,
+}
+
+/// A remapping of an alias field such as `browser` in package.json.
+///
+/// See [crate::ResolveOptions::alias_fields]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AliasFieldRemap {
+    /// Path to the `package.json` containing the alias field.
+    pub package_json: PathBuf,
+
+    /// The remapped request, a module specifier or an absolute path for relative keys.
+    pub request: String,
+
+    /// The value of the remapping.
+    pub target: String,
 }
 
 impl fmt::Debug for Resolution {
@@ -26,6 +43,7 @@ impl fmt::Debug for Resolution {
             .field("query", &self.query)
             .field("fragment", &self.fragment)
             .field("package_json", &self.package_json.as_ref().map(|p| &p.path))
+            .field("alias_field_remap", &self.alias_field_remap)
             .finish()
     }
 }
@@ -58,10 +76,16 @@ impl Resolution {
         self.fragment.as_deref()
     }
 
+    /// Returns the closest package.json of the resolved path, i.e. the description file of the owning package.
     pub fn package_json(&self) -> Option<&Arc<PackageJson>> {
         self.package_json.as_ref()
     }
 
+    /// Returns the first alias field remapping applied while resolving, e.g. from the `browser` field.
+    pub fn alias_field_remap(&self) -> Option<&AliasFieldRemap> {
+        self.alias_field_remap.as_ref()
+    }
+
     /// Returns the full path with query and fragment
     pub fn full_path(&self) -> PathBuf {
         let mut path = self.path.clone().into_os_string();
@@ -82,10 +106,12 @@ fn test() {
         query: Some("?query".to_string()),
         fragment: Some("#fragment".to_string()),
         package_json: None,
+        alias_field_remap: None,
     };
     assert_eq!(resolution.path(), Path::new("foo"));
     assert_eq!(resolution.query(), Some("?query"));
     assert_eq!(resolution.fragment(), Some("#fragment"));
+    assert_eq!(resolution.alias_field_remap(), None);
     assert_eq!(resolution.full_path(), PathBuf::from("foo?query#fragment"));
     assert_eq!(resolution.into_path_buf(), PathBuf::from("foo"));
 }
@@ -1,6 +1,6 @@
 //! <https://github.com/webpack/enhanced-resolve/blob/main/test/browserField.test.js>
 
-use crate::{AliasValue, ResolveError, ResolveOptions, Resolver};
+use crate::{AliasFieldRemap, AliasValue, ResolveError, ResolveOptions, Resolver};
 
 #[test]
 fn ignore() {
@@ -106,3 +106,83 @@ fn crypto_js() {
     let resolved_path = resolver.resolve(f.join("crypto-js"), "crypto").map(|r| r.full_path());
     assert_eq!(resolved_path, Err(ResolveError::Ignored(f.join("crypto-js"))));
 }
+
+#[test]
+#[cfg(not(target_os = "windows"))] // MemoryFS's path separator is always `/` so the test will not pass in windows.
+fn nested_remap() {
+    use super::memory_fs::MemoryFS;
+    use crate::ResolverGeneric;
+    use std::path::PathBuf;
+
+    let file_system = MemoryFS::new(&[
+        (
+            "/pkg/package.json",
+            r#"{ "browser": {
+                "./lib/a/b.js": "./lib/shim/b.js",
+                "./lib/c.js": false,
+                "./lib/d": "os",
+                "os": "os-browserify",
+                "os-browserify/sub": "./lib/shim/sub.js",
+                "fs": false
+            } }"#,
+        ),
+        ("/pkg/lib/a/b.js", ""),
+        ("/pkg/lib/c.js", ""),
+        ("/pkg/lib/d.js", ""),
+        ("/pkg/lib/x/index.js", ""),
+        ("/pkg/lib/shim/b.js", ""),
+        ("/pkg/lib/shim/sub.js", ""),
+        ("/pkg/node_modules/os-browserify/index.js", ""),
+    ]);
+    let resolver = ResolverGeneric::<MemoryFS>::new_with_file_system(
+        file_system,
+        ResolveOptions { alias_fields: vec![vec!["browser".into()]], ..ResolveOptions::default() },
+    );
+
+    #[rustfmt::skip]
+    let pass = [
+        ("relative key from a nested directory", "/pkg/lib/x", "../a/b", "/pkg/lib/shim/b.js"),
+        ("relative key without extension", "/pkg", "./lib/a/b.js", "/pkg/lib/shim/b.js"),
+        ("relative key to module", "/pkg", "./lib/d", "/pkg/node_modules/os-browserify/index.js"),
+        ("module to module", "/pkg/lib/x", "os", "/pkg/node_modules/os-browserify/index.js"),
+        ("module subpath to file", "/pkg/lib", "os-browserify/sub", "/pkg/lib/shim/sub.js"),
+    ];
+
+    for (comment, path, request, expected) in pass {
+        let resolved_path = resolver.resolve(path, request).map(|r| r.full_path());
+        assert_eq!(resolved_path, Ok(PathBuf::from(expected)), "{comment} {path:?} {request}");
+    }
+
+    #[rustfmt::skip]
+    let remaps = [
+        ("/pkg/lib/x", "../a/b", Some(("/pkg/lib/a/b.js", "./lib/shim/b.js"))),
+        ("/pkg", "./lib/d", Some(("/pkg/lib/d", "os"))),
+        ("/pkg/lib/x", "os", Some(("os", "os-browserify"))),
+        ("/pkg/lib/x", "./index", None),
+    ];
+
+    for (path, request, expected) in remaps {
+        let resolution = resolver.resolve(path, request).unwrap();
+        let expected = expected.map(|(request, target)| AliasFieldRemap {
+            package_json: PathBuf::from("/pkg/package.json"),
+            request: request.into(),
+            target: target.into(),
+        });
+        assert_eq!(resolution.alias_field_remap(), expected.as_ref(), "{path:?} {request}");
+    }
+
+    #[rustfmt::skip]
+    let ignored = [
+        ("relative key to false", "/pkg/lib/x", "../c", "/pkg/lib/c.js"),
+        ("module to false", "/pkg/lib/x", "fs", "/pkg/lib/x"),
+    ];
+
+    for (comment, path, request, expected) in ignored {
+        let resolution = resolver.resolve(path, request);
+        assert_eq!(
+            resolution,
+            Err(ResolveError::Ignored(PathBuf::from(expected))),
+            "{comment} {path:?} {request}"
+        );
+    }
+}
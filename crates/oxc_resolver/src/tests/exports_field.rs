@@ -76,6 +76,34 @@ fn test() {
     }
 }
 
+#[test]
+fn resolve_with_conditions() {
+    let f = super::fixture().join("exports-field");
+
+    let resolver = Resolver::new(ResolveOptions {
+        extensions: vec![".js".into()],
+        condition_names: vec!["webpack".into()],
+        ..ResolveOptions::default()
+    });
+
+    #[rustfmt::skip]
+    let pass = [
+        ("condition of the resolver", vec![], f.join("node_modules/exports-field/lib/lib2/main.js")),
+        ("overridden condition", vec!["node"], f.join("node_modules/exports-field/lib/main.js")),
+        ("first matching condition in exports field order", vec!["node", "webpack"], f.join("node_modules/exports-field/lib/lib2/main.js")),
+    ];
+
+    for (comment, conditions, expected) in pass {
+        let resolution = if conditions.is_empty() {
+            resolver.resolve(&f, "exports-field/dist/main.js")
+        } else {
+            resolver.resolve_with_conditions(&f, "exports-field/dist/main.js", &conditions)
+        };
+        assert_eq!(resolution.map(|r| r.full_path()), Ok(expected), "{comment}");
+    }
+    assert_eq!(resolver.options().condition_names, vec!["webpack".to_string()]);
+}
+
 // resolve using exports field, not a browser field #1
 #[test]
 fn exports_not_browser_field1() {
@@ -0,0 +1,50 @@
+//! Tests for custom file systems
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use super::memory_fs::MemoryFS;
+use crate::{FileMetadata, FileSystem, ResolveOptions, ResolverGeneric};
+
+/// A file system which does not implement `Default`
+struct WrappedFS {
+    fs: MemoryFS,
+}
+
+impl FileSystem for WrappedFS {
+    fn read_to_string<P: AsRef<Path>>(&self, path: P) -> io::Result<String> {
+        self.fs.read_to_string(path)
+    }
+
+    fn metadata<P: AsRef<Path>>(&self, path: P) -> io::Result<FileMetadata> {
+        self.fs.metadata(path)
+    }
+
+    fn symlink_metadata<P: AsRef<Path>>(&self, path: P) -> io::Result<FileMetadata> {
+        self.fs.symlink_metadata(path)
+    }
+
+    fn read_link<P: AsRef<Path>>(&self, path: P) -> io::Result<PathBuf> {
+        self.fs.read_link(path)
+    }
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))] // MemoryFS's path separator is always `/` so the test will not pass in windows.
+fn custom_file_system() {
+    let file_system = WrappedFS {
+        fs: MemoryFS::new(&[
+            ("/project/index.js", ""),
+            ("/project/node_modules/a/package.json", r#"{ "main": "main.js" }"#),
+            ("/project/node_modules/a/main.js", ""),
+        ]),
+    };
+    let resolver = ResolverGeneric::new_with_file_system(file_system, ResolveOptions::default());
+
+    let resolved_path = resolver.resolve("/project", "a").map(|r| r.full_path());
+    assert_eq!(resolved_path, Ok(PathBuf::from("/project/node_modules/a/main.js")));
+    let resolved_path = resolver.resolve("/project", "./index").map(|r| r.full_path());
+    assert_eq!(resolved_path, Ok(PathBuf::from("/project/index.js")));
+}
@@ -0,0 +1,40 @@
+//! Tests for cache invalidation
+
+use std::{fs, path::PathBuf};
+
+use crate::Resolver;
+
+#[test]
+fn invalidate() {
+    let dir = tempfile::tempdir().unwrap();
+    let f = dir.path();
+    let package = f.join("node_modules/package");
+    fs::create_dir_all(&package).unwrap();
+    fs::write(package.join("package.json"), r#"{ "main": "a.js" }"#).unwrap();
+    fs::write(package.join("a.js"), "").unwrap();
+    fs::write(package.join("b.js"), "").unwrap();
+
+    let resolver = Resolver::default();
+    let resolve = || resolver.resolve(f, "package").map(|r| r.full_path());
+    let canonicalize = |path: PathBuf| path.canonicalize().unwrap();
+
+    assert_eq!(resolve(), Ok(canonicalize(package.join("a.js"))));
+
+    // package.json is cached
+    fs::write(package.join("package.json"), r#"{ "main": "b.js" }"#).unwrap();
+    assert_eq!(resolve(), Ok(canonicalize(package.join("a.js"))));
+
+    resolver.invalidate(package.join("package.json"));
+    assert_eq!(resolve(), Ok(canonicalize(package.join("b.js"))));
+
+    // Removed files are not found after invalidation
+    fs::remove_file(package.join("b.js")).unwrap();
+    fs::write(package.join("package.json"), "{}").unwrap();
+    resolver.invalidate(package.join("b.js"));
+    assert!(resolve().is_err());
+
+    // The whole cache
+    fs::write(package.join("index.js"), "").unwrap();
+    resolver.clear_cache();
+    assert_eq!(resolve(), Ok(canonicalize(package.join("index.js"))));
+}
@@ -5,19 +5,25 @@ mod exports_field;
 mod extension_alias;
 mod extensions;
 mod fallback;
+mod file_system;
 mod full_specified;
 mod imports_field;
 mod incorrect_description_file;
+mod invalidate;
 mod main_field;
 mod memory_fs;
+mod package_json;
+mod pnp;
 mod resolve;
 mod restrictions;
 mod roots;
 mod scoped_packages;
 mod simple;
 mod symlink;
+mod trace;
 mod tsconfig_paths;
 mod tsconfig_project_references;
+mod workspaces;
 
 use crate::Resolver;
 use std::{env, path::PathBuf, sync::Arc, thread};
@@ -0,0 +1,73 @@
+//! Tests for the package.json metadata of `Resolution`
+
+use std::path::PathBuf;
+
+use super::memory_fs::MemoryFS;
+use crate::{ResolveOptions, ResolverGeneric, SideEffects};
+
+#[test]
+#[cfg(not(target_os = "windows"))] // MemoryFS's path separator is always `/` so the test will not pass in windows.
+fn metadata() {
+    let file_system = MemoryFS::new(&[
+        (
+            "/node_modules/a/package.json",
+            r#"{ "name": "a", "version": "1.0.0", "sideEffects": false }"#,
+        ),
+        ("/node_modules/a/index.js", ""),
+        ("/node_modules/a/lib/b.js", ""),
+        ("/node_modules/b/package.json", r#"{ "name": "b", "sideEffects": ["*.css"] }"#),
+        ("/node_modules/b/index.js", ""),
+        ("/node_modules/c/package.json", r#"{ "name": "c", "sideEffects": "invalid" }"#),
+        ("/node_modules/c/index.js", ""),
+    ]);
+    let resolver =
+        ResolverGeneric::<MemoryFS>::new_with_file_system(file_system, ResolveOptions::default());
+
+    #[rustfmt::skip]
+    let data = [
+        ("a", "/node_modules/a/package.json", Some("1.0.0"), Some(SideEffects::Bool(false))),
+        ("a/lib/b", "/node_modules/a/package.json", Some("1.0.0"), Some(SideEffects::Bool(false))),
+        ("b", "/node_modules/b/package.json", None, Some(SideEffects::Array(vec!["*.css".into()]))),
+        ("c", "/node_modules/c/package.json", None, None),
+    ];
+
+    for (request, path, version, side_effects) in data {
+        let resolution = resolver.resolve("/", request).unwrap();
+        let package_json = resolution.package_json().unwrap();
+        assert_eq!(package_json.path, PathBuf::from(path), "{request}");
+        assert_eq!(package_json.name.as_deref(), Some(&request[..1]), "{request}");
+        assert_eq!(package_json.version.as_deref(), version, "{request}");
+        assert_eq!(package_json.side_effects, side_effects, "{request}");
+    }
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))] // MemoryFS's path separator is always `/` so the test will not pass in windows.
+fn find_package_json() {
+    let file_system = MemoryFS::new(&[
+        ("/package.json", r#"{ "name": "root" }"#),
+        ("/src/index.js", ""),
+        ("/packages/a/package.json", r#"{ "name": "a" }"#),
+        ("/packages/a/lib/index.js", ""),
+    ]);
+    let resolver =
+        ResolverGeneric::<MemoryFS>::new_with_file_system(file_system, ResolveOptions::default());
+
+    let data = [
+        ("/src/index.js", "root"),
+        ("/src", "root"),
+        ("/packages/a/lib/index.js", "a"),
+        ("/packages/a", "a"),
+    ];
+
+    for (path, name) in data {
+        let package_json = resolver.find_package_json(path).unwrap().unwrap();
+        assert_eq!(package_json.name.as_deref(), Some(name), "{path}");
+    }
+
+    let resolver = ResolverGeneric::<MemoryFS>::new_with_file_system(
+        MemoryFS::new(&[("/src/index.js", "")]),
+        ResolveOptions::default(),
+    );
+    assert!(resolver.find_package_json("/src/index.js").unwrap().is_none());
+}
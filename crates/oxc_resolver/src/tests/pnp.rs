@@ -0,0 +1,87 @@
+//! Tests for Yarn Plug'n'Play
+
+use std::{env, path::PathBuf};
+
+use super::memory_fs::MemoryFS;
+use crate::{ResolveError, ResolveOptions, Resolver, ResolverGeneric};
+
+#[test]
+fn pnp() {
+    let f = env::current_dir().unwrap().join("tests/pnp");
+    let lodash = f.join(".yarn/unplugged/lodash-npm-4.17.21/node_modules/lodash");
+    let scope_pkg = f.join(".yarn/unplugged/@scope-pkg-npm-1.0.0/node_modules/@scope/pkg");
+
+    let resolver = Resolver::new(ResolveOptions { enable_pnp: true, ..ResolveOptions::default() });
+
+    #[rustfmt::skip]
+    let pass = [
+        ("main field", f.clone(), "lodash", lodash.join("lodash.js")),
+        ("subpath", f.clone(), "lodash/fp", lodash.join("fp.js")),
+        ("aliased dependency", f.clone(), "aliased", lodash.join("lodash.js")),
+        ("virtual package with exports field", f.clone(), "@scope/pkg", scope_pkg.join("main.js")),
+        ("exports field subpath", f.clone(), "@scope/pkg/sub", scope_pkg.join("sub.js")),
+        ("dependency of a dependency", scope_pkg.clone(), "lodash", lodash.join("lodash.js")),
+        ("relative path", f.clone(), "./index.js", f.join("index.js")),
+    ];
+
+    for (comment, path, request, expected) in pass {
+        let resolved_path = resolver.resolve(&path, request).map(|r| r.full_path());
+        assert_eq!(resolved_path, Ok(expected), "{comment} {path:?} {request}");
+    }
+
+    #[rustfmt::skip]
+    let fail = [
+        ("missing peer dependency", f.clone(), "peer"),
+        ("undeclared dependency", f.clone(), "@scope/undeclared"),
+        ("transitive dependency is not accessible", lodash, "@scope/pkg"),
+    ];
+
+    for (comment, path, request) in fail {
+        let resolution = resolver.resolve(&path, request);
+        assert!(
+            matches!(resolution, Err(ResolveError::NotFound(_))),
+            "{comment} {path:?} {request} {resolution:?}"
+        );
+    }
+
+    let resolver = Resolver::default();
+    assert!(resolver.resolve(&f, "lodash").is_err(), "disabled by default");
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))] // MemoryFS's path separator is always `/` so the test will not pass in windows.
+fn pnp_data_json() {
+    let file_system = MemoryFS::new(&[
+        (
+            "/app/.pnp.data.json",
+            r#"{
+                "enableTopLevelFallback": true,
+                "fallbackPool": [["fallback", "npm:1.0.0"]],
+                "packageRegistryData": [
+                    [null, [[null, { "packageLocation": "./", "packageDependencies": [["a", "npm:1.0.0"]] }]]],
+                    ["a", [["npm:1.0.0", { "packageLocation": "./.yarn/unplugged/a/node_modules/a/", "packageDependencies": [] }]]],
+                    ["fallback", [["npm:1.0.0", { "packageLocation": "./.yarn/unplugged/fallback/node_modules/fallback/", "packageDependencies": [] }]]]
+                ]
+            }"#,
+        ),
+        ("/app/.yarn/unplugged/a/node_modules/a/index.js", ""),
+        ("/app/.yarn/unplugged/fallback/node_modules/fallback/index.js", ""),
+    ]);
+
+    let resolver = ResolverGeneric::<MemoryFS>::new_with_file_system(
+        file_system,
+        ResolveOptions { enable_pnp: true, ..ResolveOptions::default() },
+    );
+
+    #[rustfmt::skip]
+    let pass = [
+        ("dependency", "/app", "a", "/app/.yarn/unplugged/a/node_modules/a/index.js"),
+        ("top-level fallback", "/app/.yarn/unplugged/a/node_modules/a", "a", "/app/.yarn/unplugged/a/node_modules/a/index.js"),
+        ("fallback pool", "/app/.yarn/unplugged/a/node_modules/a", "fallback", "/app/.yarn/unplugged/fallback/node_modules/fallback/index.js"),
+    ];
+
+    for (comment, path, request, expected) in pass {
+        let resolved_path = resolver.resolve(path, request).map(|r| r.full_path());
+        assert_eq!(resolved_path, Ok(PathBuf::from(expected)), "{comment} {path:?} {request}");
+    }
+}
@@ -102,3 +102,25 @@ fn resolve_to_context() {
         assert_eq!(resolved_path, Ok(expected), "{comment} {path:?} {request}");
     }
 }
+
+#[test]
+fn resolve_all() {
+    let f = super::fixture();
+    let resolver = Resolver::default();
+
+    let requests = [
+        (f.clone(), "./main1"),
+        (f.clone(), "m1/a"),
+        (f.join("node_modules/complexm"), "m2/b.js"),
+        (f.clone(), "./missing"),
+        // Identical requests are resolved once
+        (f.clone(), "m1/a"),
+        (f.join("node_modules/complexm"), "m2/b.js"),
+    ];
+    let resolutions = resolver.resolve_all(&requests);
+    assert_eq!(resolutions.len(), requests.len());
+    for ((path, request), resolution) in requests.iter().zip(resolutions) {
+        assert_eq!(resolution, resolver.resolve(path, request), "{path:?} {request}");
+    }
+    assert!(resolver.resolve(&f, "./missing").is_err());
+}
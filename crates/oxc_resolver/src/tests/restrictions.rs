@@ -1,12 +1,63 @@
 //! <https://github.com/webpack/enhanced-resolve/blob/main/test/restrictions.test.js>
 
+use regex::Regex;
+
 use crate::{ResolveError, ResolveOptions, Resolver, Restriction};
 
-// TODO: regex
-// * should respect RegExp restriction
-// * should try to find alternative #1
-// * should try to find alternative #2
-// * should try to find alternative #3
+fn css_restriction() -> Restriction {
+    Restriction::RegExp(Regex::new(r"\.(sass|scss|css)$").unwrap())
+}
+
+// should respect RegExp restriction
+#[test]
+fn restriction_regex() {
+    let fixture = super::fixture();
+    let f = fixture.join("restrictions");
+
+    let resolver = Resolver::new(ResolveOptions {
+        extensions: vec![".js".into()],
+        restrictions: vec![css_restriction()],
+        ..ResolveOptions::default()
+    });
+
+    let resolution = resolver.resolve(&f, "pck1");
+    assert_eq!(resolution, Err(ResolveError::Restriction(f.join("node_modules/pck1/index.js"))));
+}
+
+// should try to find alternative #1
+#[test]
+fn alternative1() {
+    let f = super::fixture().join("restrictions");
+
+    let resolver = Resolver::new(ResolveOptions {
+        extensions: vec![".js".into(), ".css".into()],
+        main_files: vec!["index".into()],
+        restrictions: vec![css_restriction()],
+        ..ResolveOptions::default()
+    });
+
+    let resolved_path = resolver.resolve(&f, "pck1").map(|r| r.full_path());
+    assert_eq!(resolved_path, Ok(f.join("node_modules/pck1/index.css")));
+}
+
+// should try to find alternative #2
+// should try to find alternative #3
+#[test]
+fn alternative2() {
+    let f = super::fixture().join("restrictions");
+
+    for main_fields in [vec!["main", "style"], vec!["main", "module", "style"]] {
+        let resolver = Resolver::new(ResolveOptions {
+            extensions: vec![".js".into()],
+            main_fields: main_fields.iter().map(ToString::to_string).collect(),
+            restrictions: vec![Restriction::Path(f.clone()), css_restriction()],
+            ..ResolveOptions::default()
+        });
+
+        let resolved_path = resolver.resolve(&f, "pck2").map(|r| r.full_path());
+        assert_eq!(resolved_path, Ok(f.join("node_modules/pck2/index.css")), "{main_fields:?}");
+    }
+}
 
 // should respect string restriction
 #[test]
@@ -124,3 +124,27 @@ fn test() -> io::Result<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(target_family = "unix")]
+fn preserve_symlinks_in_node_modules() {
+    // pnpm layout: node_modules/foo -> node_modules/.pnpm/foo@1.0.0/node_modules/foo
+    let dir = tempfile::tempdir().unwrap();
+    let f = dir.path();
+    let real_package = f.join("node_modules/.pnpm/foo@1.0.0/node_modules/foo");
+    fs::create_dir_all(&real_package).unwrap();
+    fs::write(real_package.join("package.json"), r#"{ "main": "index.js" }"#).unwrap();
+    fs::write(real_package.join("index.js"), "").unwrap();
+    symlink(&real_package, f.join("node_modules/foo"), FileType::Dir).unwrap();
+
+    let resolver = Resolver::new(ResolveOptions { symlinks: false, ..ResolveOptions::default() });
+    let resolution = resolver.resolve(f, "foo").unwrap();
+    assert_eq!(resolution.full_path(), f.join("node_modules/foo/index.js"));
+    assert_eq!(
+        resolution.package_json().map(|p| p.path.clone()),
+        Some(f.join("node_modules/foo/package.json"))
+    );
+
+    let resolution = Resolver::default().resolve(f, "foo").unwrap();
+    assert_eq!(resolution.full_path(), real_package.canonicalize().unwrap().join("index.js"));
+}
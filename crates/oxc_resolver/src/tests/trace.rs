@@ -0,0 +1,97 @@
+//! Tests for `Resolver::resolve_traced`
+
+use std::path::PathBuf;
+
+use super::memory_fs::MemoryFS;
+use crate::{
+    AliasFieldRemap, AliasValue, ResolveError, ResolveOptions, ResolveTrace, ResolverGeneric,
+};
+
+#[test]
+#[cfg(not(target_os = "windows"))] // MemoryFS's path separator is always `/` so the test will not pass in windows.
+fn trace() {
+    let file_system = MemoryFS::new(&[
+        ("/project/a.js", ""),
+        (
+            "/project/node_modules/pkg/package.json",
+            r#"{ "exports": { ".": { "import": "./a.mjs", "require": "./a.cjs" } } }"#,
+        ),
+        ("/project/node_modules/pkg/a.cjs", ""),
+        ("/project/node_modules/browser/package.json", r#"{ "browser": { "./a.js": "./b.js" } }"#),
+        ("/project/node_modules/browser/c.js", ""),
+    ]);
+    let resolver = ResolverGeneric::<MemoryFS>::new_with_file_system(
+        file_system,
+        ResolveOptions {
+            alias: vec![("@".into(), vec![AliasValue::Path("/project".into())])],
+            alias_fields: vec![vec!["browser".into()]],
+            condition_names: vec!["require".into()],
+            extensions: vec![".js".into(), ".ts".into()],
+            ..ResolveOptions::default()
+        },
+    );
+
+    let (resolution, trace) = resolver.resolve_traced("/project", "@/a");
+    assert_eq!(resolution.map(|r| r.full_path()), Ok(PathBuf::from("/project/a.js")));
+    assert_eq!(
+        trace,
+        vec![
+            ResolveTrace::Alias { request: "@/a".into(), new_request: "/project/a".into() },
+            ResolveTrace::File { path: "/project/a".into(), exists: false },
+            ResolveTrace::File { path: "/project/a.js".into(), exists: true },
+        ]
+    );
+
+    let (resolution, trace) = resolver.resolve_traced("/project", "pkg");
+    assert_eq!(
+        resolution.map(|r| r.full_path()),
+        Ok(PathBuf::from("/project/node_modules/pkg/a.cjs"))
+    );
+    let package_json = PathBuf::from("/project/node_modules/pkg/package.json");
+    assert_eq!(
+        trace,
+        vec![
+            ResolveTrace::ExportsCondition {
+                package_json: package_json.clone(),
+                condition: "require".into()
+            },
+            ResolveTrace::ExportsTarget { package_json, key: ".".into(), target: "./a.cjs".into() },
+            ResolveTrace::File { path: "/project/node_modules/pkg/a.cjs".into(), exists: true },
+        ]
+    );
+
+    let (resolution, trace) = resolver.resolve_traced("/project/node_modules/browser", "./c");
+    assert!(resolution.is_ok());
+    let package_json = PathBuf::from("/project/node_modules/browser/package.json");
+    assert_eq!(
+        trace[0],
+        ResolveTrace::AliasFieldMiss {
+            package_json: package_json.clone(),
+            request: "/project/node_modules/browser/c".into()
+        }
+    );
+    assert_eq!(
+        trace[0].to_string(),
+        "/project/node_modules/browser/c: alias fields of description file /project/node_modules/browser/package.json don't contain a valid alias configuration"
+    );
+
+    let (resolution, trace) = resolver.resolve_traced("/project/node_modules/browser", "./a");
+    assert_eq!(
+        resolution,
+        Err(ResolveError::NotFound(PathBuf::from("/project/node_modules/browser/b.js")))
+    );
+    assert!(trace.contains(&ResolveTrace::AliasField(AliasFieldRemap {
+        package_json,
+        request: "/project/node_modules/browser/a.js".into(),
+        target: "./b.js".into()
+    })));
+    assert!(trace.contains(&ResolveTrace::File {
+        path: "/project/node_modules/browser/b.js".into(),
+        exists: false
+    }));
+
+    // A found file ends the trace
+    let (_, trace) = resolver.resolve_traced("/project", "./a.js");
+    assert_eq!(trace.len(), 1);
+    assert_eq!(trace[0].to_string(), "existing file: /project/a.js");
+}
@@ -19,8 +19,7 @@ fn tsconfig() {
         (f.clone(), "ts-path", f.join("foo.js")),
         (f.join("nested"), "ts-path", f.join("nested/test.js")),
         (f.join("tsconfig/index"), "foo", f.join("node_modules/tsconfig-index/foo.js")),
-        // This requires reading package.json.tsconfig field
-        // (f.join("tsconfig/field"), "foo", f.join("node_modules/tsconfig-field/foo.js"))
+        (f.join("tsconfig/field"), "foo", f.join("node_modules/tsconfig-field/foo.js")),
         (f.join("tsconfig/exports"), "foo", f.join("node_modules/tsconfig-exports/foo.js")),
         (f.join("tsconfig/extends-extension"), "foo", f.join("tsconfig/extends-extension/foo.js"))
     ];
@@ -47,6 +46,93 @@ fn tsconfig() {
         let resolution = resolver.resolve(&path, request).map(|f| f.full_path());
         assert_eq!(resolution, expected, "{path:?} {request}");
     }
+
+    let path = f.join("tsconfig/extends-not-found");
+    let resolver = Resolver::new(ResolveOptions {
+        tsconfig: Some(path.join("tsconfig.json")),
+        ..ResolveOptions::default()
+    });
+    let resolution = resolver.resolve(&path, "foo");
+    assert!(matches!(resolution, Err(ResolveError::NotFound(_))), "{resolution:?}");
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))] // MemoryFS's path separator is always `/` so the test will not pass in windows.
+fn extends() {
+    let file_system = MemoryFS::new(&[
+        (
+            "/root/tsconfig.json",
+            r#"{ "extends": ["./configs/a.json", "./configs/b.json"], "compilerOptions": { "paths": { "own/*": ["own/*"] } } }"#,
+        ),
+        ("/root/configs/a.json", r#"{ "compilerOptions": { "baseUrl": "../a" } }"#),
+        ("/root/configs/b.json", r#"{ "extends": "./base.json" }"#),
+        ("/root/configs/base.json", r#"{ "compilerOptions": { "baseUrl": "../src" } }"#),
+        ("/root/src/own/foo.js", ""),
+        ("/root/src/bar.js", ""),
+        ("/root/test/index.js", ""),
+        ("/circular/tsconfig.json", r#"{ "extends": "./other.json" }"#),
+        ("/circular/other.json", r#"{ "extends": "./tsconfig.json" }"#),
+    ]);
+    let resolver = ResolverGeneric::<MemoryFS>::new_with_file_system(
+        file_system,
+        ResolveOptions {
+            tsconfig: Some("/root/tsconfig.json".into()),
+            ..ResolveOptions::default()
+        },
+    );
+
+    #[rustfmt::skip]
+    let pass = [
+        ("later extends override earlier ones and paths use the inherited baseUrl", "/root", "own/foo", "/root/src/own/foo.js"),
+        ("inherited baseUrl", "/root", "bar", "/root/src/bar.js"),
+        ("files outside of baseUrl", "/root/test", "bar", "/root/src/bar.js"),
+    ];
+
+    for (comment, path, request, expected) in pass {
+        let resolved_path = resolver.resolve(path, request).map(|f| f.full_path());
+        assert_eq!(resolved_path, Ok(PathBuf::from(expected)), "{comment} {path} {request}");
+    }
+
+    let resolver = resolver.clone_with_options(ResolveOptions {
+        tsconfig: Some("/circular/tsconfig.json".into()),
+        ..ResolveOptions::default()
+    });
+    let resolution = resolver.resolve("/circular", "foo");
+    assert_eq!(
+        resolution,
+        Err(ResolveError::TsconfigCircularExtend("/circular/tsconfig.json".into()))
+    );
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))] // MemoryFS's path separator is always `/` so the test will not pass in windows.
+fn jsx_options() {
+    let file_system = MemoryFS::new(&[
+        (
+            "/root/tsconfig.json",
+            r#"{ "extends": "./base.json", "compilerOptions": { "jsxFactory": "h" } }"#,
+        ),
+        (
+            "/root/base.json",
+            r#"{ "compilerOptions": { "jsx": "react", "jsxFactory": "React.createElement", "jsxFragmentFactory": "Fragment" } }"#,
+        ),
+    ]);
+    let resolver = ResolverGeneric::<MemoryFS>::new_with_file_system(
+        file_system,
+        ResolveOptions {
+            tsconfig: Some("/root/tsconfig.json".into()),
+            ..ResolveOptions::default()
+        },
+    );
+    let tsconfig = resolver.tsconfig().unwrap().unwrap();
+    let compiler_options = tsconfig.compiler_options();
+    assert_eq!(compiler_options.jsx(), Some("react"));
+    assert_eq!(compiler_options.jsx_factory(), Some("h"));
+    assert_eq!(compiler_options.jsx_fragment_factory(), Some("Fragment"));
+    assert_eq!(compiler_options.jsx_import_source(), None);
+
+    let resolver = resolver.clone_with_options(ResolveOptions::default());
+    assert!(resolver.tsconfig().unwrap().is_none());
 }
 
 #[test]
@@ -0,0 +1,87 @@
+//! Tests for monorepo workspace packages declared with the `workspace:` protocol
+
+use std::path::PathBuf;
+
+use super::memory_fs::MemoryFS;
+use crate::{ResolveOptions, ResolverGeneric};
+
+#[test]
+#[cfg(not(target_os = "windows"))] // MemoryFS's path separator is always `/` so the test will not pass in windows.
+fn workspaces() {
+    let file_system = MemoryFS::new(&[
+        (
+            "/packages/app/package.json",
+            r#"{ "name": "app", "dependencies": { "a": "workspace:*", "@scope/b": "workspace:^", "c": "^1.0.0" } }"#,
+        ),
+        ("/packages/app/index.js", ""),
+        ("/packages/a/package.json", r#"{ "name": "a", "main": "lib/main.js" }"#),
+        ("/packages/a/lib/main.js", ""),
+        ("/packages/a/lib/util.js", ""),
+        (
+            "/packages/b/package.json",
+            r#"{ "name": "@scope/b", "exports": { ".": "./index.js", "./sub": "./sub.js" } }"#,
+        ),
+        ("/packages/b/index.js", ""),
+        ("/packages/b/sub.js", ""),
+        ("/packages/c/package.json", r#"{ "name": "c" }"#),
+        ("/packages/c/index.js", ""),
+    ]);
+    let resolver = ResolverGeneric::<MemoryFS>::new_with_file_system(
+        file_system,
+        ResolveOptions {
+            workspaces: vec![
+                PathBuf::from("/packages/app"),
+                PathBuf::from("/packages/a"),
+                PathBuf::from("/packages/b"),
+                PathBuf::from("/packages/c"),
+            ],
+            ..ResolveOptions::default()
+        },
+    );
+
+    #[rustfmt::skip]
+    let pass = [
+        ("main field", "a", "/packages/a/lib/main.js"),
+        ("subpath", "a/lib/util", "/packages/a/lib/util.js"),
+        ("exports field", "@scope/b", "/packages/b/index.js"),
+        ("exports field subpath", "@scope/b/sub", "/packages/b/sub.js"),
+    ];
+
+    for (comment, request, expected) in pass {
+        let resolved_path = resolver.resolve("/packages/app", request).map(|r| r.full_path());
+        assert_eq!(resolved_path, Ok(PathBuf::from(expected)), "{comment} {request}");
+    }
+
+    #[rustfmt::skip]
+    let fail = [
+        ("not declared with the workspace protocol", "/packages/app", "c"),
+        ("not declared", "/packages/a", "@scope/b"),
+        ("exports field subpath not exported", "/packages/app", "@scope/b/index.js"),
+    ];
+
+    for (comment, path, request) in fail {
+        let resolution = resolver.resolve(path, request);
+        assert!(resolution.is_err(), "{comment} {path} {request} {resolution:?}");
+    }
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))] // MemoryFS's path separator is always `/` so the test will not pass in windows.
+fn node_modules_first() {
+    let file_system = MemoryFS::new(&[
+        ("/packages/app/package.json", r#"{ "dependencies": { "a": "workspace:*" } }"#),
+        ("/packages/app/node_modules/a/package.json", r#"{ "name": "a" }"#),
+        ("/packages/app/node_modules/a/index.js", ""),
+        ("/packages/a/package.json", r#"{ "name": "a" }"#),
+        ("/packages/a/index.js", ""),
+    ]);
+    let resolver = ResolverGeneric::<MemoryFS>::new_with_file_system(
+        file_system,
+        ResolveOptions {
+            workspaces: vec![PathBuf::from("/packages/a")],
+            ..ResolveOptions::default()
+        },
+    );
+    let resolved_path = resolver.resolve("/packages/app", "a").map(|r| r.full_path());
+    assert_eq!(resolved_path, Ok(PathBuf::from("/packages/app/node_modules/a/index.js")));
+}
@@ -0,0 +1,59 @@
+use std::{fmt, path::PathBuf};
+
+use crate::AliasFieldRemap;
+
+/// A step taken while resolving a specifier, collected by [crate::ResolverGeneric::resolve_traced].
+///
+/// The [fmt::Display] implementation explains the step in the style of enhanced-resolve's error messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolveTrace {
+    /// A file that was checked for existence.
+    File { path: PathBuf, exists: bool },
+
+    /// `request` was rewritten to `new_request` by [crate::ResolveOptions::alias] or [crate::ResolveOptions::fallback].
+    Alias { request: String, new_request: String },
+
+    /// A request was rewritten by an alias field such as `browser`.
+    AliasField(AliasFieldRemap),
+
+    /// The alias fields in `package_json` do not contain `request`.
+    AliasFieldMiss { package_json: PathBuf, request: String },
+
+    /// The condition `condition` of the "exports" or "imports" field in `package_json` was taken.
+    ExportsCondition { package_json: PathBuf, condition: String },
+
+    /// The key `key` of the "exports" or "imports" field in `package_json` was mapped to `target`.
+    ExportsTarget { package_json: PathBuf, key: String, target: String },
+}
+
+impl fmt::Display for ResolveTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File { path, exists: true } => write!(f, "existing file: {}", path.display()),
+            Self::File { path, exists: false } => write!(f, "{} doesn't exist", path.display()),
+            Self::Alias { request, new_request } => {
+                write!(f, "aliased '{request}' to '{new_request}'")
+            }
+            Self::AliasField(AliasFieldRemap { package_json, request, target }) => write!(
+                f,
+                "aliased from description file {} with mapping '{request}' to '{target}'",
+                package_json.display()
+            ),
+            Self::AliasFieldMiss { package_json, request } => write!(
+                f,
+                "{request}: alias fields of description file {} don't contain a valid alias configuration",
+                package_json.display()
+            ),
+            Self::ExportsCondition { package_json, condition } => write!(
+                f,
+                "using condition '{condition}' of description file {}",
+                package_json.display()
+            ),
+            Self::ExportsTarget { package_json, key, target } => write!(
+                f,
+                "using exports field '{key}': '{target}' of description file {}",
+                package_json.display()
+            ),
+        }
+    }
+}
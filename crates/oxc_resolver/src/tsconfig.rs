@@ -48,6 +48,29 @@ pub struct CompilerOptions {
     paths: Option<FxIndexMap<String, Vec<String>>>,
     #[serde(skip)]
     paths_base: PathBuf,
+    jsx: Option<String>,
+    jsx_factory: Option<String>,
+    jsx_fragment_factory: Option<String>,
+    jsx_import_source: Option<String>,
+}
+
+impl CompilerOptions {
+    /// `jsx`, e.g. `react` or `react-jsx`
+    pub fn jsx(&self) -> Option<&str> {
+        self.jsx.as_deref()
+    }
+
+    pub fn jsx_factory(&self) -> Option<&str> {
+        self.jsx_factory.as_deref()
+    }
+
+    pub fn jsx_fragment_factory(&self) -> Option<&str> {
+        self.jsx_fragment_factory.as_deref()
+    }
+
+    pub fn jsx_import_source(&self) -> Option<&str> {
+        self.jsx_import_source.as_deref()
+    }
 }
 
 fn deserialize_extends<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
@@ -67,6 +90,11 @@ where
 }
 
 impl TsConfig {
+    /// Parse the tsconfig at `path` from `json`, which may contain comments and trailing commas
+    ///
+    /// # Errors
+    ///
+    /// * When `json` is not a valid tsconfig
     pub fn parse(path: &Path, json: &mut str) -> Result<Self, serde_json::Error> {
         _ = strip_comments_in_place(json);
         let mut tsconfig: Self = serde_json::from_str(json)?;
@@ -92,6 +120,10 @@ impl TsConfig {
         self.path.parent().unwrap()
     }
 
+    pub fn compiler_options(&self) -> &CompilerOptions {
+        &self.compiler_options
+    }
+
     pub fn extends(&self) -> &Vec<String> {
         &self.extends
     }
@@ -107,21 +139,36 @@ impl TsConfig {
             .map_or_else(|| self.directory(), |path| path.as_ref())
     }
 
+    /// Inherit `baseUrl`, `paths` and the `jsx` options from an extended tsconfig, as `tsc` does,
+    /// `paths` are replaced as a whole instead of being merged.
     pub fn extend_tsconfig(&mut self, tsconfig: &Self) {
         let compiler_options = &mut self.compiler_options;
-        if compiler_options.base_url.is_none() {
-            compiler_options.base_url = tsconfig.compiler_options.base_url.clone();
-        }
         if compiler_options.paths.is_none() {
             compiler_options.paths_base = tsconfig.compiler_options.paths_base.clone();
             compiler_options.paths = tsconfig.compiler_options.paths.clone();
+        } else if compiler_options.base_url.is_none() {
+            // Own `paths` are relative to the inherited `baseUrl`
+            if let Some(base_url) = &tsconfig.compiler_options.base_url {
+                compiler_options.paths_base = base_url.clone();
+            }
+        }
+        if compiler_options.base_url.is_none() {
+            compiler_options.base_url = tsconfig.compiler_options.base_url.clone();
+        }
+        let inherited = &tsconfig.compiler_options;
+        for (option, inherited) in [
+            (&mut compiler_options.jsx, &inherited.jsx),
+            (&mut compiler_options.jsx_factory, &inherited.jsx_factory),
+            (&mut compiler_options.jsx_fragment_factory, &inherited.jsx_fragment_factory),
+            (&mut compiler_options.jsx_import_source, &inherited.jsx_import_source),
+        ] {
+            if option.is_none() {
+                option.clone_from(inherited);
+            }
         }
     }
 
     pub fn resolve(&self, path: &Path, specifier: &str) -> Vec<PathBuf> {
-        if path.starts_with(self.base_path()) {
-            return self.resolve_path_alias(specifier);
-        }
         for reference in &self.references {
             if let Some(tsconfig) = &reference.tsconfig {
                 if path.starts_with(tsconfig.base_path()) {
@@ -129,6 +176,9 @@ impl TsConfig {
                 }
             }
         }
+        if path.starts_with(self.directory()) || path.starts_with(self.base_path()) {
+            return self.resolve_path_alias(specifier);
+        }
         vec![]
     }
 
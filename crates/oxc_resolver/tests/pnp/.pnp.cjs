@@ -0,0 +1,71 @@
+#!/usr/bin/env node
+/* eslint-disable */
+"use strict";
+
+const RAW_RUNTIME_STATE =
+'{\
+  "__info": [\
+    "This file is automatically generated. Do not touch it, or risk",\
+    "your modifications being lost."\
+  ],\
+  "dependencyTreeRoots": [\
+    {\
+      "name": "pnp",\
+      "reference": "workspace:."\
+    }\
+  ],\
+  "enableTopLevelFallback": false,\
+  "ignorePatternData": null,\
+  "fallbackExclusionList": [],\
+  "fallbackPool": [],\
+  "packageRegistryData": [\
+    [null, [\
+      [null, {\
+        "packageLocation": "./",\
+        "packageDependencies": [\
+          ["@scope/pkg", "virtual:abc#npm:1.0.0"],\
+          ["aliased", ["lodash", "npm:4.17.21"]],\
+          ["lodash", "npm:4.17.21"],\
+          ["peer", null]\
+        ],\
+        "linkType": "SOFT"\
+      }]\
+    ]],\
+    ["@scope/pkg", [\
+      ["virtual:abc#npm:1.0.0", {\
+        "packageLocation": "./.yarn/__virtual__/@scope-pkg-virtual-abc/0/unplugged/@scope-pkg-npm-1.0.0/node_modules/@scope/pkg/",\
+        "packageDependencies": [\
+          ["@scope/pkg", "virtual:abc#npm:1.0.0"],\
+          ["lodash", "npm:4.17.21"]\
+        ],\
+        "packagePeers": [],\
+        "linkType": "HARD"\
+      }]\
+    ]],\
+    ["lodash", [\
+      ["npm:4.17.21", {\
+        "packageLocation": "./.yarn/unplugged/lodash-npm-4.17.21/node_modules/lodash/",\
+        "packageDependencies": [\
+          ["lodash", "npm:4.17.21"]\
+        ],\
+        "linkType": "HARD"\
+      }]\
+    ]],\
+    ["pnp", [\
+      ["workspace:.", {\
+        "packageLocation": "./",\
+        "packageDependencies": [\
+          ["@scope/pkg", "virtual:abc#npm:1.0.0"],\
+          ["aliased", ["lodash", "npm:4.17.21"]],\
+          ["lodash", "npm:4.17.21"],\
+          ["peer", null]\
+        ],\
+        "linkType": "SOFT"\
+      }]\
+    ]]\
+  ]\
+}';
+
+function $$SETUP_STATE(hydrateRuntimeState, basePath) {
+  return hydrateRuntimeState(JSON.parse(RAW_RUNTIME_STATE), {basePath: basePath || __dirname});
+}
@@ -3,7 +3,7 @@
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::ast::*;
 use oxc_ast::{syntax_directed_operations::BoundNames, AstKind};
-use oxc_span::{Atom, SourceType};
+use oxc_span::{GetSpan, SourceType};
 
 use crate::{scope::ScopeFlags, symbol::SymbolFlags, SemanticBuilder};
 
@@ -22,6 +22,12 @@ impl<'a> Binder for VariableDeclarator<'a> {
             VariableDeclarationKind::Let => {
                 (SymbolFlags::BlockScopedVariable, SymbolFlags::BlockScopedVariableExcludes)
             }
+            VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing => (
+                SymbolFlags::BlockScopedVariable
+                    | SymbolFlags::ConstVariable
+                    | SymbolFlags::UsingVariable,
+                SymbolFlags::BlockScopedVariableExcludes,
+            ),
             VariableDeclarationKind::Var => {
                 (SymbolFlags::FunctionScopedVariable, SymbolFlags::FunctionScopedVariableExcludes)
             }
@@ -114,7 +120,7 @@ impl<'a> Binder for Function<'a> {
                     ident.span,
                     &ident.name,
                     parent_scope_id,
-                    includes,
+                    includes | SymbolFlags::Function,
                     excludes,
                 );
                 ident.symbol_id.set(Some(symbol_id));
@@ -242,21 +248,14 @@ impl<'a> Binder for TSEnumDeclaration<'a> {
         };
         let symbol_id = builder.declare_symbol(self.id.span, &self.id.name, includes, excludes);
         self.id.symbol_id.set(Some(symbol_id));
+        builder.enums.add_declaration(symbol_id, self);
     }
 }
 
 impl<'a> Binder for TSEnumMember<'a> {
     fn bind(&self, builder: &mut SemanticBuilder) {
-        // TODO: Perf
-        if matches!(&self.id, TSEnumMemberName::ComputedPropertyName(_)) {
-            return;
-        }
-        let name = match &self.id {
-            TSEnumMemberName::Identifier(id) => id.name.clone(),
-            TSEnumMemberName::StringLiteral(s) => s.value.clone(),
-            TSEnumMemberName::NumberLiteral(n) => Atom::new_inline(&n.value.to_string()),
-            TSEnumMemberName::ComputedPropertyName(_) => panic!("TODO: implement"),
-        };
+        // Dynamic computed names such as `[foo]` cannot be referred to by name
+        let Some(name) = self.id.static_name() else { return };
         builder.declare_symbol(
             self.span,
             &name,
@@ -275,12 +274,32 @@ impl<'a> Binder for TSModuleDeclaration<'a> {
         } else {
             SymbolFlags::None
         };
-        builder.declare_symbol(
-            self.span,
-            self.id.name(),
-            SymbolFlags::NameSpaceModule | ambient,
-            SymbolFlags::None,
-        );
+        let includes = SymbolFlags::NameSpaceModule | ambient;
+
+        // `B` and `C` in `namespace A.B.C {}` are exported members of `A` and `A.B`,
+        // they are not visible in the scope containing `A`.
+        let symbol_id = if matches!(
+            builder.nodes.parent_kind(builder.current_node_id),
+            Some(AstKind::TSModuleDeclaration(_))
+        ) {
+            let symbol_id = builder.symbols.create_symbol(
+                self.id.span(),
+                self.id.name().clone(),
+                includes | SymbolFlags::Export,
+                builder.current_scope_id,
+            );
+            builder.symbols.add_declaration(builder.current_node_id);
+            symbol_id
+        } else {
+            builder.declare_symbol(self.id.span(), self.id.name(), includes, SymbolFlags::None)
+        };
+
+        if let TSModuleDeclarationName::Identifier(ident) = &self.id {
+            ident.symbol_id.set(Some(symbol_id));
+        }
+        // Popped when leaving the declaration, value declarations in the body
+        // turn the namespaces on the stack into value modules
+        builder.namespace_stack.push(symbol_id);
     }
 }
 
@@ -14,7 +14,10 @@ use crate::{
     binder::Binder,
     checker::{EarlyErrorJavaScript, EarlyErrorTypeScript},
     diagnostics::Redeclaration,
+    enum_table::EnumTable,
+    export_table::ExportTable,
     jsdoc::JSDocBuilder,
+    jsx::JsxOptions,
     module_record::ModuleRecordBuilder,
     node::{AstNode, AstNodeId, AstNodes, NodeFlags},
     reference::{Reference, ReferenceFlag, ReferenceId},
@@ -62,6 +65,7 @@ pub struct SemanticBuilder<'a> {
     pub nodes: AstNodes<'a>,
     pub scope: ScopeTree,
     pub symbols: SymbolTable,
+    pub enums: EnumTable,
 
     pub(crate) module_record: Arc<ModuleRecord>,
 
@@ -70,6 +74,8 @@ pub struct SemanticBuilder<'a> {
     jsdoc: JSDocBuilder<'a>,
 
     check_syntax_error: bool,
+
+    jsx: JsxOptions,
 }
 
 pub struct SemanticBuilderReturn<'a> {
@@ -97,10 +103,12 @@ impl<'a> SemanticBuilder<'a> {
             nodes: AstNodes::default(),
             scope,
             symbols: SymbolTable::default(),
+            enums: EnumTable::default(),
             module_record: Arc::new(ModuleRecord::default()),
             unused_labels: UnusedLabels { scopes: vec![], curr_scope: 0, labels: vec![] },
             jsdoc: JSDocBuilder::new(source_text, &trivias),
             check_syntax_error: false,
+            jsx: JsxOptions::default(),
         }
     }
 
@@ -118,6 +126,13 @@ impl<'a> SemanticBuilder<'a> {
         self
     }
 
+    /// The JSX options of the project, overridden by the pragma comments of the file
+    #[must_use]
+    pub fn with_jsx_options(mut self, options: JsxOptions) -> Self {
+        self.jsx = options;
+        self
+    }
+
     /// Get the built module record from `build_module_record`
     pub fn module_record(&self) -> Arc<ModuleRecord> {
         Arc::clone(&self.module_record)
@@ -137,6 +152,7 @@ impl<'a> SemanticBuilder<'a> {
     }
 
     pub fn build(mut self, program: &'a Program<'a>) -> SemanticBuilderReturn<'a> {
+        self.jsx = std::mem::take(&mut self.jsx).with_pragmas(self.source_text, &self.trivias);
         if !self.source_type.is_typescript_definition() {
             self.visit_program(program);
 
@@ -146,6 +162,7 @@ impl<'a> SemanticBuilder<'a> {
             }
         }
 
+        let exports = ExportTable::build(&self.nodes, &self.scope);
         let semantic = Semantic {
             source_text: self.source_text,
             source_type: self.source_type,
@@ -153,9 +170,12 @@ impl<'a> SemanticBuilder<'a> {
             nodes: self.nodes,
             scopes: self.scope,
             symbols: self.symbols,
+            enums: self.enums,
+            exports,
             module_record: Arc::clone(&self.module_record),
             jsdoc: self.jsdoc.build(),
             unused_labels: self.unused_labels.labels,
+            jsx: self.jsx,
         };
         SemanticBuilderReturn { semantic, errors: self.errors.into_inner() }
     }
@@ -168,9 +188,12 @@ impl<'a> SemanticBuilder<'a> {
             nodes: self.nodes,
             scopes: self.scope,
             symbols: self.symbols,
+            enums: self.enums,
+            exports: ExportTable::default(),
             module_record: Arc::new(ModuleRecord::default()),
             jsdoc: self.jsdoc.build(),
             unused_labels: self.unused_labels.labels,
+            jsx: self.jsx,
         }
     }
 
@@ -252,6 +275,7 @@ impl<'a> SemanticBuilder<'a> {
     ) -> SymbolId {
         if let Some(symbol_id) = self.check_redeclaration(scope_id, span, name, excludes, true) {
             self.symbols.union_flag(symbol_id, includes);
+            self.symbols.add_redeclaration(symbol_id, self.current_node_id);
             return symbol_id;
         }
 
@@ -475,11 +499,6 @@ impl<'a> SemanticBuilder<'a> {
             }
             AstKind::TSModuleDeclaration(module_declaration) => {
                 module_declaration.bind(self);
-                let symbol_id = self
-                    .scope
-                    .get_bindings(self.current_scope_id)
-                    .get(module_declaration.id.name());
-                self.namespace_stack.push(*symbol_id.unwrap());
             }
             AstKind::TSTypeAliasDeclaration(type_alias_declaration) => {
                 type_alias_declaration.bind(self);
@@ -504,6 +523,12 @@ impl<'a> SemanticBuilder<'a> {
             AstKind::JSXElementName(elem) => {
                 self.reference_jsx_element_name(elem);
             }
+            AstKind::JSXOpeningElement(elem) => {
+                self.reference_jsx_factory(elem.span, false);
+            }
+            AstKind::JSXFragment(fragment) => {
+                self.reference_jsx_factory(fragment.opening_fragment.span, true);
+            }
             AstKind::LabeledStatement(stmt) => {
                 self.unused_labels.scopes.push(LabeledScope {
                     name: stmt.label.name.as_str(),
@@ -533,6 +558,15 @@ impl<'a> SemanticBuilder<'a> {
             AstKind::YieldExpression(_) => {
                 self.set_function_node_flag(NodeFlags::HasYield);
             }
+            // `await using` is disposed with an implicit `await` at the end of the block
+            AstKind::AwaitExpression(_)
+            | AstKind::ForOfStatement(ForOfStatement { r#await: true, .. })
+            | AstKind::VariableDeclaration(VariableDeclaration {
+                kind: VariableDeclarationKind::AwaitUsing,
+                ..
+            }) => {
+                self.set_function_node_flag(NodeFlags::HasAwait);
+            }
             _ => {}
         }
     }
@@ -556,7 +590,7 @@ impl<'a> SemanticBuilder<'a> {
             AstKind::Function(_) | AstKind::ArrowExpression(_) => {
                 self.function_stack.pop();
             }
-            AstKind::TSModuleBlock(_) => {
+            AstKind::TSModuleDeclaration(_) => {
                 self.namespace_stack.pop();
             }
             _ => {}
@@ -671,6 +705,15 @@ impl<'a> SemanticBuilder<'a> {
         }
     }
 
+    /// Reference the factories of the classic runtime, e.g. `h` with `/** @jsx h */`
+    fn reference_jsx_factory(&mut self, span: Span, is_fragment: bool) {
+        let names = self.jsx.referenced_names(is_fragment).map(Atom::from).collect::<Vec<_>>();
+        for name in names {
+            let reference = Reference::new(span, name, self.current_node_id, ReferenceFlag::read());
+            self.declare_reference(reference);
+        }
+    }
+
     fn symbol_flag_from_module_declaration(module: &ModuleDeclaration) -> SymbolFlags {
         if matches!(module, ModuleDeclaration::ImportDeclaration(_)) {
             SymbolFlags::Import
@@ -58,6 +58,7 @@ impl EarlyErrorJavaScript {
             AstKind::LabeledStatement(stmt) => check_labeled_statement(stmt, node, ctx),
             AstKind::ForInStatement(stmt) => check_for_statement_left(&stmt.left, true, node, ctx),
             AstKind::ForOfStatement(stmt) => check_for_statement_left(&stmt.left, false, node, ctx),
+            AstKind::VariableDeclaration(decl) => check_variable_declaration(decl, node, ctx),
 
             AstKind::Class(class) => check_class(class, ctx),
             AstKind::Super(sup) => check_super(sup, node, ctx),
@@ -764,6 +765,39 @@ fn check_labeled_statement<'a>(
     }
 }
 
+fn check_variable_declaration<'a>(
+    decl: &VariableDeclaration,
+    node: &AstNode<'a>,
+    ctx: &SemanticBuilder<'a>,
+) {
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("Using declarations are not allowed at the top level of a script")]
+    #[diagnostic()]
+    struct UsingDeclarationInScript(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("Using declarations are not allowed in `case` or `default` clauses")]
+    #[diagnostic(help("Wrap the clause in a block"))]
+    struct UsingDeclarationInSwitchCase(#[label] Span);
+
+    #[derive(Debug, Error, Diagnostic)]
+    #[error("The left-hand side of a `for...in` statement cannot be a using declaration")]
+    #[diagnostic()]
+    struct UsingDeclarationInForIn(#[label] Span);
+
+    if !decl.kind.is_using() {
+        return;
+    }
+    match ctx.nodes.parent_kind(node.id()) {
+        Some(AstKind::Program(_)) if ctx.source_type.is_script() => {
+            ctx.error(UsingDeclarationInScript(decl.span));
+        }
+        Some(AstKind::SwitchCase(_)) => ctx.error(UsingDeclarationInSwitchCase(decl.span)),
+        Some(AstKind::ForInStatement(_)) => ctx.error(UsingDeclarationInForIn(decl.span)),
+        _ => {}
+    }
+}
+
 fn check_for_statement_left<'a>(
     left: &ForStatementLeft,
     is_for_in: bool,
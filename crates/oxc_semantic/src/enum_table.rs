@@ -0,0 +1,176 @@
+//! Constant values of TypeScript enum members.
+//!
+//! Initializers are evaluated as TypeScript does for
+//! [constant enum expressions](https://www.typescriptlang.org/docs/handbook/enums.html#computed-and-constant-members):
+//! literals, references to previous members of the same enum, and unary, binary and template
+//! expressions of those. Any other initializer makes the member computed.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_span::{Atom, Span};
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use rustc_hash::FxHashMap;
+
+use crate::SymbolId;
+
+/// The value of a constant enum member
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnumValue {
+    Number(f64),
+    String(Atom),
+}
+
+impl EnumValue {
+    /// <https://tc39.es/ecma262/#sec-numeric-types-number-tostring>, for integers and
+    /// the special values, other numbers use Rust's formatting
+    fn to_js_string(&self) -> String {
+        match self {
+            Self::String(value) => value.to_string(),
+            Self::Number(value) if *value == 0.0 => "0".into(),
+            Self::Number(value) if value.is_infinite() => {
+                if value.is_sign_positive() { "Infinity" } else { "-Infinity" }.into()
+            }
+            Self::Number(value) => value.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EnumMemberValue {
+    pub name: Atom,
+    pub span: Span,
+    /// `None` for computed members, e.g. `A = foo()`
+    pub value: Option<EnumValue>,
+}
+
+/// Member values of the enums of a file, by the symbol of the enum.
+///
+/// The members of merged declarations, `enum E { A } enum E { B = 1 }`, are in declaration order.
+#[derive(Debug, Default)]
+pub struct EnumTable {
+    enums: FxHashMap<SymbolId, Vec<EnumMemberValue>>,
+}
+
+impl EnumTable {
+    pub fn get_members(&self, symbol_id: SymbolId) -> Option<&[EnumMemberValue]> {
+        self.enums.get(&symbol_id).map(Vec::as_slice)
+    }
+
+    /// The value of the member `name`, `None` if there is no such member or it is computed
+    pub fn get_value(&self, symbol_id: SymbolId, name: &str) -> Option<&EnumValue> {
+        self.get_members(symbol_id)?.iter().find(|member| member.name == name)?.value.as_ref()
+    }
+
+    pub(crate) fn add_declaration(&mut self, symbol_id: SymbolId, decl: &TSEnumDeclaration) {
+        let members = self.enums.entry(symbol_id).or_default();
+        // Members without initializers are the previous number plus one, starting at 0
+        let mut next = Some(0.0);
+        for member in &decl.body.members {
+            // Dynamic computed names such as `[foo]` cannot be referred to by name
+            let Some(name) = member.id.static_name() else {
+                next = None;
+                continue;
+            };
+            let value = member.initializer.as_ref().map_or_else(
+                || next.map(EnumValue::Number),
+                |initializer| evaluate(initializer, &decl.id.name, members),
+            );
+            next = match value {
+                Some(EnumValue::Number(value)) => Some(value + 1.0),
+                _ => None,
+            };
+            members.push(EnumMemberValue { name, span: member.span, value });
+        }
+    }
+}
+
+fn evaluate(expr: &Expression, enum_name: &Atom, members: &[EnumMemberValue]) -> Option<EnumValue> {
+    let member = |name: &str| {
+        members
+            .iter()
+            .rev()
+            .find(|member| member.name == name)
+            .and_then(|member| member.value.clone())
+    };
+    match expr {
+        Expression::NumberLiteral(lit) => Some(EnumValue::Number(lit.value)),
+        Expression::StringLiteral(lit) => Some(EnumValue::String(lit.value.clone())),
+        Expression::TemplateLiteral(lit) => {
+            let mut value = String::new();
+            for (i, quasi) in lit.quasis.iter().enumerate() {
+                value.push_str(quasi.value.cooked.as_ref()?);
+                if let Some(expr) = lit.expressions.get(i) {
+                    value.push_str(&evaluate(expr, enum_name, members)?.to_js_string());
+                }
+            }
+            Some(EnumValue::String(value.into()))
+        }
+        Expression::ParenthesizedExpression(expr) => evaluate(&expr.expression, enum_name, members),
+        // Members shadow the globals in initializers
+        Expression::Identifier(ident) => {
+            member(&ident.name).or_else(|| match ident.name.as_str() {
+                "Infinity" => Some(EnumValue::Number(f64::INFINITY)),
+                "NaN" => Some(EnumValue::Number(f64::NAN)),
+                _ => None,
+            })
+        }
+        // `E.A` and `E["A"]`
+        Expression::MemberExpression(expr) => match expr.object() {
+            Expression::Identifier(object) if object.name == enum_name => {
+                member(expr.static_property_name()?)
+            }
+            _ => None,
+        },
+        Expression::UnaryExpression(expr) => {
+            let EnumValue::Number(value) = evaluate(&expr.argument, enum_name, members)? else {
+                return None;
+            };
+            let value = match expr.operator {
+                UnaryOperator::UnaryPlus => value,
+                UnaryOperator::UnaryNegation => -value,
+                UnaryOperator::BitwiseNot => f64::from(!NumberLiteral::ecmascript_to_int32(value)),
+                _ => return None,
+            };
+            Some(EnumValue::Number(value))
+        }
+        Expression::BinaryExpression(expr) => {
+            let left = evaluate(&expr.left, enum_name, members)?;
+            let right = evaluate(&expr.right, enum_name, members)?;
+            match (expr.operator, left, right) {
+                (BinaryOperator::Addition, left @ EnumValue::String(_), right)
+                | (BinaryOperator::Addition, left, right @ EnumValue::String(_)) => {
+                    let value = left.to_js_string() + &right.to_js_string();
+                    Some(EnumValue::String(value.into()))
+                }
+                (operator, EnumValue::Number(left), EnumValue::Number(right)) => {
+                    evaluate_binary(operator, left, right).map(EnumValue::Number)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[allow(clippy::cast_sign_loss)]
+fn evaluate_binary(operator: BinaryOperator, left: f64, right: f64) -> Option<f64> {
+    let to_int32 = NumberLiteral::ecmascript_to_int32;
+    // Only the lower 5 bits of the shift count are used
+    let shift = || to_int32(right) as u32 & 31;
+    let value = match operator {
+        BinaryOperator::Addition => left + right,
+        BinaryOperator::Subtraction => left - right,
+        BinaryOperator::Multiplication => left * right,
+        BinaryOperator::Division => left / right,
+        BinaryOperator::Remainder => left % right,
+        BinaryOperator::Exponential => left.powf(right),
+        BinaryOperator::BitwiseOR => f64::from(to_int32(left) | to_int32(right)),
+        BinaryOperator::BitwiseAnd => f64::from(to_int32(left) & to_int32(right)),
+        BinaryOperator::BitwiseXOR => f64::from(to_int32(left) ^ to_int32(right)),
+        BinaryOperator::ShiftLeft => f64::from(to_int32(left) << shift()),
+        BinaryOperator::ShiftRight => f64::from(to_int32(left) >> shift()),
+        BinaryOperator::ShiftRightZeroFill => f64::from(to_int32(left) as u32 >> shift()),
+        _ => return None,
+    };
+    Some(value)
+}
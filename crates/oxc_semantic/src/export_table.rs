@@ -0,0 +1,166 @@
+//! Exported names of a file and the local symbols they refer to.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, AstKind, AstType};
+use oxc_span::{Atom, GetSpan, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{node::AstNodes, scope::ScopeTree, symbol::SymbolId};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportedBinding {
+    /// `export const a`, `export { a as b }`, `export default a` and `export default function a() {}`
+    Local(SymbolId),
+    /// `export default 1 + 1` and `export default function () {}`, by the span of the exported
+    /// expression or declaration
+    Anonymous(Span),
+    /// `export { a as b } from "mod"`, `imported_name` is `None` for `export * as b from "mod"`
+    ReExport { module_request: Atom, imported_name: Option<Atom> },
+}
+
+/// Exported names of a module, built after the symbols of the file are declared.
+///
+/// Exports of names which are not declared in the file, e.g. `export { undeclared }`, are not
+/// included. `export` declarations inside TypeScript namespaces are not exports of the file,
+/// their symbols are included in [`ExportTable::is_exported`].
+#[derive(Debug, Default)]
+pub struct ExportTable {
+    exports: FxHashMap<Atom, ExportedBinding>,
+    /// Module requests of `export * from "mod"`
+    star_exports: Vec<Atom>,
+    /// Local symbols exported from the file or from a namespace
+    exported_symbols: FxHashSet<SymbolId>,
+}
+
+impl ExportTable {
+    pub fn get(&self, exported_name: &str) -> Option<&ExportedBinding> {
+        self.exports.get(exported_name)
+    }
+
+    /// The local symbol exported as `exported_name`
+    pub fn get_symbol_id(&self, exported_name: &str) -> Option<SymbolId> {
+        match self.exports.get(exported_name)? {
+            ExportedBinding::Local(symbol_id) => Some(*symbol_id),
+            _ => None,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Atom, &ExportedBinding)> + '_ {
+        self.exports.iter()
+    }
+
+    pub fn star_exports(&self) -> &[Atom] {
+        &self.star_exports
+    }
+
+    /// Whether the symbol is exported from the file or from the namespace it is declared in
+    pub fn is_exported(&self, symbol_id: SymbolId) -> bool {
+        self.exported_symbols.contains(&symbol_id)
+    }
+
+    pub(crate) fn build(nodes: &AstNodes, scopes: &ScopeTree) -> Self {
+        let mut table = Self::default();
+        for node in nodes.iter_kind(AstType::ModuleDeclaration) {
+            let AstKind::ModuleDeclaration(decl) = node.kind() else { continue };
+            let is_namespace_member = match nodes.parent_kind(node.id()) {
+                Some(AstKind::Program(_)) => false,
+                Some(AstKind::TSModuleBlock(_)) => true,
+                _ => continue,
+            };
+            let resolve = |local_name: &Atom| {
+                scopes.get_binding(node.scope_id(), local_name).map(ExportedBinding::Local)
+            };
+            let mut exports = vec![];
+            match decl {
+                ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                    if let Some(declaration) = &decl.declaration {
+                        for name in declaration_names(declaration) {
+                            exports.extend(resolve(&name).map(|binding| (name, binding)));
+                        }
+                    }
+                    for specifier in &decl.specifiers {
+                        let exported_name = specifier.exported.name();
+                        match &decl.source {
+                            Some(source) => exports.push((
+                                exported_name.clone(),
+                                ExportedBinding::ReExport {
+                                    module_request: source.value.clone(),
+                                    imported_name: Some(specifier.local.name().clone()),
+                                },
+                            )),
+                            None => exports.extend(
+                                resolve(specifier.local.name())
+                                    .map(|binding| (exported_name.clone(), binding)),
+                            ),
+                        }
+                    }
+                }
+                ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                    let default = Atom::from("default");
+                    let local_name = match &decl.declaration {
+                        ExportDefaultDeclarationKind::Expression(Expression::Identifier(ident)) => {
+                            Some(&ident.name)
+                        }
+                        ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                            func.id.as_ref().map(|id| &id.name)
+                        }
+                        ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                            class.id.as_ref().map(|id| &id.name)
+                        }
+                        ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl) => {
+                            Some(&decl.id.name)
+                        }
+                        ExportDefaultDeclarationKind::TSEnumDeclaration(decl) => {
+                            Some(&decl.id.name)
+                        }
+                        ExportDefaultDeclarationKind::Expression(_) => None,
+                    };
+                    match local_name {
+                        Some(local_name) => {
+                            exports.extend(resolve(local_name).map(|binding| (default, binding)));
+                        }
+                        None => exports
+                            .push((default, ExportedBinding::Anonymous(decl.declaration.span()))),
+                    }
+                }
+                ModuleDeclaration::ExportAllDeclaration(decl) => match &decl.exported {
+                    Some(exported) => exports.push((
+                        exported.name().clone(),
+                        ExportedBinding::ReExport {
+                            module_request: decl.source.value.clone(),
+                            imported_name: None,
+                        },
+                    )),
+                    None => table.star_exports.push(decl.source.value.clone()),
+                },
+                _ => {}
+            }
+            for (exported_name, binding) in exports {
+                if let ExportedBinding::Local(symbol_id) = binding {
+                    table.exported_symbols.insert(symbol_id);
+                }
+                if !is_namespace_member {
+                    table.exports.insert(exported_name, binding);
+                }
+            }
+        }
+        table
+    }
+}
+
+fn declaration_names(declaration: &Declaration) -> Vec<Atom> {
+    let mut names = vec![];
+    match declaration {
+        Declaration::VariableDeclaration(_)
+        | Declaration::FunctionDeclaration(_)
+        | Declaration::ClassDeclaration(_) => {
+            declaration.bound_names(&mut |ident| names.push(ident.name.clone()));
+        }
+        Declaration::TSTypeAliasDeclaration(decl) => names.push(decl.id.name.clone()),
+        Declaration::TSInterfaceDeclaration(decl) => names.push(decl.id.name.clone()),
+        Declaration::TSEnumDeclaration(decl) => names.push(decl.id.name.clone()),
+        Declaration::TSModuleDeclaration(decl) => names.push(decl.id.name().clone()),
+        Declaration::TSImportEqualsDeclaration(decl) => names.push(decl.id.name.clone()),
+    }
+    names
+}
@@ -0,0 +1,109 @@
+//! JSX runtime and factories of a file, from the configuration and the pragma comments.
+//!
+//! The classic runtime compiles `<div />` to `React.createElement("div")`, so the root of the
+//! factory, `React`, must be in scope and is referenced by every JSX element. The automatic
+//! runtime imports its functions from `jsxImportSource` and references nothing in scope.
+
+use oxc_ast::Trivias;
+use oxc_span::{Atom, Span};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum JsxRuntime {
+    /// `React.createElement(...)`, tsconfig `"jsx": "react"`
+    Classic,
+    /// Calls imported from `react/jsx-runtime`, tsconfig `"jsx": "react-jsx"`
+    #[default]
+    Automatic,
+}
+
+/// Options of tsconfig `jsx`, `jsxFactory`, `jsxFragmentFactory` and `jsxImportSource`,
+/// overridden in a file by the `@jsxRuntime`, `@jsx`, `@jsxFrag` and `@jsxImportSource` pragmas
+#[derive(Debug, Default, Clone)]
+pub struct JsxOptions {
+    pub runtime: JsxRuntime,
+    pub factory: Option<Atom>,
+    pub fragment_factory: Option<Atom>,
+    pub import_source: Option<Atom>,
+}
+
+impl JsxOptions {
+    /// The function creating elements with the classic runtime, `React.createElement` by default
+    pub fn factory(&self) -> &str {
+        self.factory.as_ref().map_or("React.createElement", Atom::as_str)
+    }
+
+    /// The component of `<></>` with the classic runtime, `React.Fragment` by default
+    pub fn fragment_factory(&self) -> &str {
+        self.fragment_factory.as_ref().map_or("React.Fragment", Atom::as_str)
+    }
+
+    /// The module providing the automatic runtime, `react` by default
+    pub fn import_source(&self) -> &str {
+        self.import_source.as_ref().map_or("react", Atom::as_str)
+    }
+
+    /// The identifiers in scope referenced by an element, and a fragment with `is_fragment`,
+    /// e.g. `React` of `React.createElement`
+    pub fn referenced_names(&self, is_fragment: bool) -> impl Iterator<Item = &str> + '_ {
+        let factories = match self.runtime {
+            JsxRuntime::Classic if is_fragment => vec![self.factory(), self.fragment_factory()],
+            JsxRuntime::Classic => vec![self.factory()],
+            JsxRuntime::Automatic => vec![],
+        };
+        factories.into_iter().filter_map(|factory| {
+            factory.split('.').next().filter(|name| !name.is_empty() && *name != "this")
+        })
+    }
+
+    /// Apply the pragmas of the comments in `source_text`, the first of each kind wins.
+    ///
+    /// `@jsx` and `@jsxFrag` switch to the classic runtime and `@jsxImportSource` to the
+    /// automatic runtime, unless `@jsxRuntime` is given.
+    #[must_use]
+    pub fn with_pragmas(mut self, source_text: &str, trivias: &Trivias) -> Self {
+        let mut runtime = None;
+        let mut factory = None;
+        let mut fragment_factory = None;
+        let mut import_source = None;
+        for (start, comment) in trivias.comments() {
+            let text = Span::new(*start, comment.end()).source_text(source_text);
+            for (pragma, value) in pragmas(text) {
+                let slot = match pragma {
+                    "jsx" => &mut factory,
+                    "jsxFrag" => &mut fragment_factory,
+                    "jsxImportSource" => &mut import_source,
+                    "jsxRuntime" => {
+                        runtime = runtime.or(match value {
+                            "classic" => Some(JsxRuntime::Classic),
+                            "automatic" => Some(JsxRuntime::Automatic),
+                            _ => None,
+                        });
+                        continue;
+                    }
+                    _ => continue,
+                };
+                slot.get_or_insert_with(|| Atom::from(value));
+            }
+        }
+        if factory.is_some() || fragment_factory.is_some() {
+            self.runtime = JsxRuntime::Classic;
+        } else if import_source.is_some() {
+            self.runtime = JsxRuntime::Automatic;
+        }
+        self.runtime = runtime.unwrap_or(self.runtime);
+        self.factory = factory.or(self.factory);
+        self.fragment_factory = fragment_factory.or(self.fragment_factory);
+        self.import_source = import_source.or(self.import_source);
+        self
+    }
+}
+
+/// `@name value` pairs of a comment
+fn pragmas(comment: &str) -> impl Iterator<Item = (&str, &str)> {
+    comment.split('@').skip(1).filter_map(|pragma| {
+        let mut words = pragma.split(|c: char| c.is_whitespace() || c == '*');
+        let name = words.next()?;
+        let value = words.find(|word| !word.is_empty())?;
+        Some((name, value))
+    })
+}
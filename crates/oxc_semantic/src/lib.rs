@@ -2,17 +2,22 @@ mod binder;
 mod builder;
 mod checker;
 mod diagnostics;
+mod enum_table;
+mod export_table;
 mod jsdoc;
+mod jsx;
 mod module_record;
 mod node;
 mod reference;
 mod scope;
+mod side_effects;
 mod symbol;
 
 use std::{rc::Rc, sync::Arc};
 
 pub use builder::{SemanticBuilder, SemanticBuilderReturn};
 pub use jsdoc::{JSDoc, JSDocComment, JSDocTag};
+pub use jsx::{JsxOptions, JsxRuntime};
 use oxc_ast::{ast::IdentifierReference, AstKind, Trivias};
 use oxc_span::SourceType;
 pub use oxc_syntax::{
@@ -22,6 +27,8 @@ pub use oxc_syntax::{
 };
 
 pub use crate::{
+    enum_table::{EnumMemberValue, EnumTable, EnumValue},
+    export_table::{ExportTable, ExportedBinding},
     node::{AstNode, AstNodeId, AstNodes, NodeFlags},
     reference::{Reference, ReferenceFlag, ReferenceId},
     scope::ScopeTree,
@@ -39,6 +46,10 @@ pub struct Semantic<'a> {
 
     symbols: SymbolTable,
 
+    enums: EnumTable,
+
+    exports: ExportTable,
+
     trivias: Rc<Trivias>,
 
     module_record: Arc<ModuleRecord>,
@@ -46,6 +57,8 @@ pub struct Semantic<'a> {
     jsdoc: JSDoc<'a>,
 
     unused_labels: Vec<AstNodeId>,
+
+    jsx: JsxOptions,
 }
 
 impl<'a> Semantic<'a> {
@@ -85,10 +98,25 @@ impl<'a> Semantic<'a> {
         &self.symbols
     }
 
+    /// Values of the members of TypeScript enums
+    pub fn enums(&self) -> &EnumTable {
+        &self.enums
+    }
+
+    /// Exported names of the file and the local symbols they refer to
+    pub fn exports(&self) -> &ExportTable {
+        &self.exports
+    }
+
     pub fn unused_labels(&self) -> &Vec<AstNodeId> {
         &self.unused_labels
     }
 
+    /// The JSX runtime and factories of the file, after applying its pragma comments
+    pub fn jsx_options(&self) -> &JsxOptions {
+        &self.jsx
+    }
+
     pub fn is_unresolved_reference(&self, node_id: AstNodeId) -> bool {
         let reference_node = self.nodes.get_node(node_id);
         let AstKind::IdentifierReference(id) = reference_node.kind() else {
@@ -110,6 +138,18 @@ impl<'a> Semantic<'a> {
         self.symbols.get_resolved_references(symbol_id)
     }
 
+    /// Get all resolved references for a symbol, together with the node of each
+    /// reference and the scope the reference is in
+    pub fn symbol_references_with_nodes(
+        &self,
+        symbol_id: SymbolId,
+    ) -> impl Iterator<Item = (&Reference, &AstNode<'a>, ScopeId)> + '_ {
+        self.symbols.get_resolved_references(symbol_id).map(|reference| {
+            let node = self.nodes.get_node(reference.node_id());
+            (reference, node, node.scope_id())
+        })
+    }
+
     pub fn symbol_declaration(&self, symbol_id: SymbolId) -> &AstNode<'a> {
         self.nodes.get_node(self.symbols.get_declaration(symbol_id))
     }
@@ -122,8 +162,11 @@ impl<'a> Semantic<'a> {
 #[cfg(test)]
 mod tests {
     use oxc_allocator::Allocator;
-    use oxc_ast::{ast::VariableDeclarationKind, AstKind};
-    use oxc_span::{Atom, SourceType};
+    use oxc_ast::{
+        ast::{TSModuleDeclarationName, VariableDeclarationKind},
+        AstKind, AstType,
+    };
+    use oxc_span::{Atom, SourceType, Span};
 
     use super::*;
 
@@ -169,6 +212,92 @@ mod tests {
         assert_eq!(references.count(), 1);
     }
 
+    #[test]
+    fn test_symbol_references_with_nodes() {
+        let source = "
+            let a;
+            function foo() {
+                a = 1;
+            }";
+        let allocator = Allocator::default();
+        let semantic = get_semantic(&allocator, source, SourceType::default());
+
+        let a =
+            semantic.scopes().get_binding(semantic.scopes().root_scope_id(), &"a".into()).unwrap();
+        let references = semantic.symbol_references_with_nodes(a).collect::<Vec<_>>();
+        assert_eq!(references.len(), 1);
+        let (reference, node, scope_id) = references[0];
+        assert!(reference.is_write());
+        assert_eq!(node.id(), reference.node_id());
+        assert!(matches!(node.kind(), AstKind::IdentifierReference(ident) if ident.name == "a"));
+        assert_ne!(scope_id, semantic.scopes().root_scope_id());
+        assert!(semantic.scopes().get_flags(scope_id).contains(ScopeFlags::Function));
+    }
+
+    #[test]
+    fn test_nested_namespace_symbols() {
+        let source = "namespace A.B { const x = 1; }";
+        let allocator = Allocator::default();
+        let semantic =
+            get_semantic(&allocator, source, SourceType::default().with_typescript(true));
+
+        let root_scope_id = semantic.scopes().root_scope_id();
+        assert!(semantic.scopes().get_binding(root_scope_id, &"B".into()).is_none());
+        let names = semantic
+            .nodes()
+            .iter()
+            .filter_map(|node| match node.kind() {
+                AstKind::TSModuleDeclaration(decl) => match &decl.id {
+                    TSModuleDeclarationName::Identifier(ident) => ident.symbol_id.get(),
+                    TSModuleDeclarationName::StringLiteral(_) => None,
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names.len(), 2);
+        assert_eq!(semantic.scopes().get_binding(root_scope_id, &"A".into()), Some(names[0]));
+        for &symbol_id in &names {
+            assert!(semantic.symbols().get_flag(symbol_id).contains(SymbolFlags::ValueModule));
+        }
+        assert!(semantic.symbols().get_flag(names[1]).contains(SymbolFlags::Export));
+    }
+
+    #[test]
+    fn test_node_queries() {
+        let source = "let a = 1; function foo() { return a + bar; }";
+        let allocator = Allocator::default();
+        let semantic = get_semantic(&allocator, source, SourceType::default());
+        let nodes = semantic.nodes();
+
+        let names = nodes
+            .iter_kind(AstType::IdentifierReference)
+            .filter_map(|node| match node.kind() {
+                AstKind::IdentifierReference(ident) => Some(ident.name.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["a", "bar"]);
+        assert_eq!(nodes.iter_kind(AstType::ClassHeritage).count(), 0);
+
+        // `bar` is both an `Expression` and an `IdentifierReference`
+        let bar = Span::new(39, 42);
+        let kinds = nodes.iter_span(bar).map(|node| node.kind().ty()).collect::<Vec<_>>();
+        assert_eq!(kinds.last(), Some(&AstType::IdentifierReference));
+        assert_eq!(nodes.iter_span(Span::new(39, 41)).count(), 0);
+
+        let node = nodes.node_at_offset(40).unwrap();
+        assert!(matches!(node.kind(), AstKind::IdentifierReference(ident) if ident.name == "bar"));
+        let node = nodes.node_at_offset(37).unwrap();
+        assert!(matches!(node.kind(), AstKind::BinaryExpression(_)));
+        let node = nodes.node_at_offset(2).unwrap();
+        assert!(matches!(node.kind(), AstKind::VariableDeclaration(_)));
+        let node = nodes.node_at_offset(10).unwrap();
+        assert!(matches!(node.kind(), AstKind::VariableDeclaration(_)));
+        let node = nodes.node_at_offset(11).unwrap();
+        assert!(matches!(node.kind(), AstKind::Function(_)));
+        assert!(nodes.node_at_offset(100).is_none());
+    }
+
     #[test]
     fn test_top_level_symbols() {
         let source = "function Fn() {}";
@@ -329,4 +458,28 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_using_declaration_errors() {
+        let allocator = Allocator::default();
+        let module = SourceType::default().with_module(true);
+        let cases = [
+            ("using a = b;", SourceType::default(), 1),
+            ("using a = b;", module, 0),
+            ("{ using a = b; }", SourceType::default(), 0),
+            ("switch (a) { case 1: using b = c; }", module, 1),
+            ("switch (a) { case 1: { using b = c; } }", module, 0),
+            ("for (using a in b);", module, 1),
+            ("for (using a of b);", module, 0),
+        ];
+        for (source, source_type, errors) in cases {
+            let parse = oxc_parser::Parser::new(&allocator, source, source_type).parse();
+            assert!(parse.errors.is_empty(), "{source:?}");
+            let program = allocator.alloc(parse.program);
+            let ret = SemanticBuilder::new(source, source_type)
+                .with_check_syntax_error(true)
+                .build(program);
+            assert_eq!(ret.errors.len(), errors, "{source:?}");
+        }
+    }
 }
@@ -1,11 +1,13 @@
 use std::path::PathBuf;
 
 #[allow(clippy::wildcard_imports)]
-use oxc_ast::{ast::*, syntax_directed_operations::BoundNames};
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, Visit};
 use oxc_span::{Atom, GetSpan, Span};
 #[allow(clippy::wildcard_imports)]
 use oxc_syntax::module_record::*;
 
+use super::expressions::ExpressionVisitor;
+
 #[derive(Default)]
 pub struct ModuleRecordBuilder {
     pub module_record: ModuleRecord,
@@ -17,7 +19,7 @@ impl ModuleRecordBuilder {
         Self { module_record: ModuleRecord::new(resolved_absolute_path), ..Self::default() }
     }
 
-    pub fn visit(&mut self, program: &Program) {
+    pub fn visit<'a>(&mut self, program: &'a Program<'a>) {
         // This avoids additional checks on TypeScript `TsModuleBlock` which
         // also has `ModuleDeclaration`s.
         for stmt in &program.body {
@@ -62,12 +64,26 @@ impl ModuleRecordBuilder {
                             local_name: identifier,
                         });
                     });
-
-                    self.add_module_request(&module_request);
                 }
             }
         }
 
+        // `require` and `import()` calls and CommonJS exports may be nested in functions and blocks
+        let mut expressions = ExpressionVisitor::default();
+        expressions.visit_program(program);
+        for request in &expressions.requests {
+            self.add_module_request(request);
+        }
+        for request in expressions.dynamic_imports {
+            self.module_record
+                .dynamic_imports
+                .entry(request.name().clone())
+                .or_default()
+                .push(request.span());
+        }
+        self.module_record.commonjs_exports = expressions.exports;
+        self.module_record.commonjs_export_assignment = expressions.export_assignment;
+
         // The `ParseModule` algorithm requires `importedBoundNames` (import entries) to be
         // resolved before resolving export entries.
         self.resolve_export_entries();
@@ -191,6 +207,7 @@ impl ModuleRecordBuilder {
     }
 
     pub fn visit_module_declaration(&mut self, module_decl: &ModuleDeclaration) {
+        self.module_record.has_module_syntax = true;
         match module_decl {
             ModuleDeclaration::ImportDeclaration(import_decl) => {
                 self.visit_import_declaration(import_decl);
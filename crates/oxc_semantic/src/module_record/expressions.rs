@@ -0,0 +1,111 @@
+//! Module requests and CommonJS exports in expressions anywhere in a program:
+//! `require("mod")`, `import("mod")`, `exports.a = ...` and `module.exports = ...`.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstKind, Visit};
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::{module_record::NameSpan, operator::AssignmentOperator};
+use rustc_hash::FxHashMap;
+
+#[derive(Default)]
+pub struct ExpressionVisitor {
+    /// `require("mod")` in source order
+    pub requests: Vec<NameSpan>,
+    /// `import("mod")` in source order
+    pub dynamic_imports: Vec<NameSpan>,
+    pub exports: FxHashMap<Atom, Span>,
+    pub export_assignment: Option<Span>,
+}
+
+impl<'a> Visit<'a> for ExpressionVisitor {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        match kind {
+            AstKind::CallExpression(call) => self.visit_call(call),
+            AstKind::AssignmentExpression(expr) if expr.operator == AssignmentOperator::Assign => {
+                let AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::MemberAssignmentTarget(member),
+                ) = &expr.left
+                else {
+                    return;
+                };
+                self.visit_export_assignment(member, &expr.right);
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_import_expression(&mut self, expr: &'a ImportExpression<'a>) {
+        if let Some(request) = static_request(&expr.source) {
+            self.dynamic_imports.push(request);
+        }
+        self.visit_expression(&expr.source);
+        for arg in &expr.arguments {
+            self.visit_expression(arg);
+        }
+    }
+}
+
+impl ExpressionVisitor {
+    fn visit_call<'a>(&mut self, call: &'a CallExpression<'a>) {
+        if call.is_require_call() {
+            if let Some(Argument::Expression(expr)) = call.arguments.first() {
+                self.requests.extend(static_request(expr));
+            }
+            return;
+        }
+        // `Object.defineProperty(exports, "a", { ... })`, as compiled by TypeScript and Babel
+        if call.callee.is_specific_member_access("Object", "defineProperty") {
+            let (Some(Argument::Expression(target)), Some(Argument::Expression(name))) =
+                (call.arguments.first(), call.arguments.get(1))
+            else {
+                return;
+            };
+            if is_exports_object(target) {
+                if let Expression::StringLiteral(name) = name {
+                    self.exports.insert(name.value.clone(), name.span);
+                }
+            }
+        }
+    }
+
+    fn visit_export_assignment<'a>(
+        &mut self,
+        member: &'a MemberExpression<'a>,
+        value: &'a Expression<'a>,
+    ) {
+        // `module.exports = { a, b: c }`
+        if member.is_specific_member_access("module", "exports") {
+            self.export_assignment = Some(member.span());
+            if let Expression::ObjectExpression(object) = value.get_inner_expression() {
+                for property in &object.properties {
+                    let ObjectPropertyKind::ObjectProperty(property) = property else { continue };
+                    if let Some(name) = property.key.static_name() {
+                        self.exports.insert(name, property.key.span());
+                    }
+                }
+            }
+            return;
+        }
+        // `exports.a = ...` and `module.exports.a = ...`
+        if is_exports_object(member.object()) {
+            if let Some((span, name)) = member.static_property_info() {
+                self.exports.insert(Atom::from(name), span);
+            }
+        }
+    }
+}
+
+/// `"mod"` and `` `mod` ``, other specifiers are only known at runtime
+fn static_request(expr: &Expression) -> Option<NameSpan> {
+    let request = match expr {
+        Expression::StringLiteral(lit) => lit.value.clone(),
+        Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => lit.quasi()?.clone(),
+        _ => return None,
+    };
+    Some(NameSpan::new(request, expr.span()))
+}
+
+/// `exports` or `module.exports`
+fn is_exports_object<'a>(expr: &'a Expression<'a>) -> bool {
+    expr.is_specific_id("exports") || expr.is_specific_member_access("module", "exports")
+}
@@ -1,4 +1,5 @@
 mod builder;
+mod expressions;
 
 pub use builder::ModuleRecordBuilder;
 
@@ -235,4 +236,45 @@ mod module_record_tests {
         assert_eq!(module_record.local_export_entries.len(), 1);
         assert_eq!(module_record.local_export_entries[0], export_entry);
     }
+
+    #[test]
+    fn require_requests() {
+        let module_record =
+            build("const a = require('a'); function f() { return require(`b`) } require(c)");
+        let requests = module_record.requested_modules.keys().map(oxc_span::Atom::as_str);
+        assert_eq!(requests.collect::<Vec<_>>(), vec!["a", "b"]);
+        assert_eq!(module_record.requested_modules["b"], vec![Span::new(54, 57)]);
+        assert!(!module_record.has_module_syntax);
+        assert!(!module_record.is_commonjs());
+    }
+
+    #[test]
+    fn commonjs_exports() {
+        let module_record = build(
+            "module.exports = { a: 1, 'b': 2, ...c }; exports.d = 1; module.exports['e'] = 1;
+            (function () { Object.defineProperty(exports, 'f', { value: 1 }) })(); exports[g] = 1;",
+        );
+        let mut names =
+            module_record.commonjs_exports.keys().map(oxc_span::Atom::as_str).collect::<Vec<_>>();
+        names.sort_unstable();
+        assert_eq!(names, vec!["a", "b", "d", "e", "f"]);
+        assert_eq!(module_record.commonjs_exports["a"], Span::new(19, 20));
+        assert_eq!(module_record.commonjs_export_assignment, Some(Span::new(0, 14)));
+        assert!(module_record.is_commonjs());
+
+        let module_record = build("exports.a = 1; export const b = 1;");
+        assert!(module_record.has_module_syntax);
+        assert!(!module_record.is_commonjs());
+    }
+
+    #[test]
+    fn dynamic_imports() {
+        let module_record =
+            build("import a from 'a'; async function f() { await import('b'); import(c) }");
+        let requests = module_record.requested_modules.keys().map(oxc_span::Atom::as_str);
+        assert_eq!(requests.collect::<Vec<_>>(), vec!["a"]);
+        let dynamic_imports = module_record.dynamic_imports.keys().map(oxc_span::Atom::as_str);
+        assert_eq!(dynamic_imports.collect::<Vec<_>>(), vec!["b"]);
+        assert_eq!(module_record.dynamic_imports["b"], vec![Span::new(53, 56)]);
+    }
 }
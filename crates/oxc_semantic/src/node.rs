@@ -1,8 +1,12 @@
 #![allow(non_upper_case_globals)] // for bitflags
 
+use std::cell::OnceCell;
+
 use bitflags::bitflags;
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_index::{define_index_type, IndexVec};
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
 
 use crate::scope::{ScopeFlags, ScopeId};
 
@@ -16,7 +20,7 @@ bitflags! {
         const JSDoc    = 1 << 0; // If the Node has a JSDoc comment attached
         const Class    = 1 << 1; // If Node is inside a class
         const HasYield = 1 << 2; // If function has yield statement
-
+        const HasAwait = 1 << 3; // If function has await expression, `for await` or `await using`
     }
 }
 
@@ -32,6 +36,10 @@ impl NodeFlags {
     pub fn has_yield(&self) -> bool {
         self.contains(Self::HasYield)
     }
+
+    pub fn has_await(&self) -> bool {
+        self.contains(Self::HasAwait)
+    }
 }
 
 /// Semantic node contains all the semantic information about an ast node.
@@ -87,6 +95,11 @@ impl<'a> AstNode<'a> {
 pub struct AstNodes<'a> {
     nodes: IndexVec<AstNodeId, AstNode<'a>>,
     parent_ids: IndexVec<AstNodeId, Option<AstNodeId>>,
+    /// Node ids of each [`AstType`], in the order they were added
+    kind_index: FxHashMap<AstType, Vec<AstNodeId>>,
+    /// Node ids sorted by span start, then by span end descending so outer nodes come before
+    /// the nodes they contain, built on the first span query
+    span_index: OnceCell<Vec<AstNodeId>>,
 }
 
 impl<'a> AstNodes<'a> {
@@ -136,11 +149,61 @@ impl<'a> AstNodes<'a> {
         std::iter::successors(Some(ast_node_id), |node_id| parent_ids[*node_id])
     }
 
+    /// All nodes of the type `ty`, in traversal order
+    pub fn iter_kind(&self, ty: AstType) -> impl Iterator<Item = &AstNode<'a>> + '_ {
+        self.kind_index.get(&ty).into_iter().flatten().map(|node_id| self.get_node(*node_id))
+    }
+
+    /// All nodes whose span is exactly `span`, outermost first, e.g. the `Expression` and the
+    /// `IdentifierReference` of the same identifier
+    pub fn iter_span(&self, span: Span) -> impl Iterator<Item = &AstNode<'a>> + '_ {
+        let span_index = self.span_index();
+        let start = span_index.partition_point(|node_id| {
+            let node_span = self.kind(*node_id).span();
+            (node_span.start, std::cmp::Reverse(node_span.end))
+                < (span.start, std::cmp::Reverse(span.end))
+        });
+        span_index[start..]
+            .iter()
+            .map(|node_id| self.get_node(*node_id))
+            .take_while(move |node| node.kind().span() == span)
+    }
+
+    /// The innermost node whose span contains `offset`, e.g. the node at the cursor.
+    ///
+    /// A span contains the offset of its end, so the node ending right before the cursor is found
+    /// when the cursor is at the end of an identifier.
+    pub fn node_at_offset(&self, offset: u32) -> Option<&AstNode<'a>> {
+        let span_index = self.span_index();
+        // The last node starting at or before `offset` is the innermost node containing it,
+        // or the node containing it is one of its ancestors
+        let end = span_index.partition_point(|node_id| self.kind(*node_id).span().start <= offset);
+        let node_id = *span_index[..end].last()?;
+        self.ancestors(node_id).map(|node_id| self.get_node(node_id)).find(|node| {
+            let span = node.kind().span();
+            span.start <= offset && offset <= span.end
+        })
+    }
+
+    fn span_index(&self) -> &[AstNodeId] {
+        self.span_index.get_or_init(|| {
+            let mut span_index = self.nodes.iter().map(AstNode::id).collect::<Vec<_>>();
+            // Stable sort keeps parents before children with the same span
+            span_index.sort_by_key(|node_id| {
+                let span = self.kind(*node_id).span();
+                (span.start, std::cmp::Reverse(span.end))
+            });
+            span_index
+        })
+    }
+
     pub fn add_node(&mut self, node: AstNode<'a>, parent_id: Option<AstNodeId>) -> AstNodeId {
         let mut node = node;
         let ast_node_id = self.parent_ids.push(parent_id);
         node.id = ast_node_id;
+        self.kind_index.entry(node.kind.ty()).or_default().push(ast_node_id);
         self.nodes.push(node);
+        self.span_index.take();
         ast_node_id
     }
 }
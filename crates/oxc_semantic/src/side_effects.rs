@@ -0,0 +1,142 @@
+//! Conservative side effect analysis.
+//!
+//! Ported from [closure-compiler](https://github.com/google/closure-compiler/blob/f3ce5ed8b630428e311fe9aa2e20d36560d975e2/src/com/google/javascript/jscomp/AstAnalyzer.java#L241),
+//! see `oxc_hir::hir_util::MayHaveSideEffects` for the HIR version used by the minifier.
+//!
+//! Getters, `valueOf` and `toString` are assumed to be pure, as in closure-compiler.
+//! Everything else that may run user code (calls, property access, iteration) is considered
+//! to have side effects.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+
+use crate::Semantic;
+
+impl<'a> Semantic<'a> {
+    /// Whether evaluating the expression may change program state or throw.
+    ///
+    /// When `false`, the expression can be safely removed, e.g. the initializer of an unused
+    /// variable. Reading an undeclared variable throws a `ReferenceError`, so references to
+    /// unresolved globals other than `undefined`, `NaN` and `Infinity` are treated as side effects.
+    pub fn may_have_side_effects(&self, expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumberLiteral(_)
+            | Expression::BigintLiteral(_)
+            | Expression::RegExpLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::MetaProperty(_)
+            | Expression::ThisExpression(_)
+            | Expression::FunctionExpression(_)
+            | Expression::ArrowExpression(_) => false,
+            Expression::Identifier(ident) => self.may_throw_reference_error(ident),
+            Expression::TemplateLiteral(template) => {
+                template.expressions.iter().any(|expr| self.may_have_side_effects(expr))
+            }
+            Expression::ParenthesizedExpression(expr) => {
+                self.may_have_side_effects(&expr.expression)
+            }
+            Expression::SequenceExpression(expr) => {
+                expr.expressions.iter().any(|expr| self.may_have_side_effects(expr))
+            }
+            Expression::ConditionalExpression(expr) => {
+                self.may_have_side_effects(&expr.test)
+                    || self.may_have_side_effects(&expr.consequent)
+                    || self.may_have_side_effects(&expr.alternate)
+            }
+            Expression::LogicalExpression(expr) => {
+                self.may_have_side_effects(&expr.left) || self.may_have_side_effects(&expr.right)
+            }
+            Expression::BinaryExpression(expr) => {
+                // `in` and `instanceof` throw a `TypeError` when the right side is not an object
+                matches!(expr.operator, BinaryOperator::In | BinaryOperator::Instanceof)
+                    || self.may_have_side_effects(&expr.left)
+                    || self.may_have_side_effects(&expr.right)
+            }
+            Expression::UnaryExpression(expr) => match expr.operator {
+                UnaryOperator::Delete => true,
+                // `typeof` does not throw for undeclared variables
+                UnaryOperator::Typeof if matches!(expr.argument, Expression::Identifier(_)) => {
+                    false
+                }
+                _ => self.may_have_side_effects(&expr.argument),
+            },
+            Expression::ArrayExpression(expr) => {
+                expr.elements.iter().any(|element| match element {
+                    ArrayExpressionElement::Expression(expr) => self.may_have_side_effects(expr),
+                    // Spreading runs the iterator protocol
+                    ArrayExpressionElement::SpreadElement(_) => true,
+                    ArrayExpressionElement::Elision(_) => false,
+                })
+            }
+            Expression::ObjectExpression(expr) => {
+                expr.properties.iter().any(|property| match property {
+                    ObjectPropertyKind::ObjectProperty(property) => {
+                        self.property_key_may_have_side_effects(&property.key)
+                            || self.may_have_side_effects(&property.value)
+                    }
+                    // Spreading may invoke getters
+                    ObjectPropertyKind::SpreadProperty(_) => true,
+                })
+            }
+            Expression::ClassExpression(class) => self.class_may_have_side_effects(class),
+            Expression::TSAsExpression(expr) => self.may_have_side_effects(&expr.expression),
+            Expression::TSSatisfiesExpression(expr) => self.may_have_side_effects(&expr.expression),
+            Expression::TSTypeAssertion(expr) => self.may_have_side_effects(&expr.expression),
+            Expression::TSNonNullExpression(expr) => self.may_have_side_effects(&expr.expression),
+            Expression::TSInstantiationExpression(expr) => {
+                self.may_have_side_effects(&expr.expression)
+            }
+            _ => true,
+        }
+    }
+
+    fn may_throw_reference_error(&self, ident: &IdentifierReference) -> bool {
+        self.is_reference_to_global_variable(ident)
+            && !matches!(ident.name.as_str(), "undefined" | "NaN" | "Infinity")
+    }
+
+    fn property_key_may_have_side_effects(&self, key: &PropertyKey<'a>) -> bool {
+        match key {
+            PropertyKey::Identifier(_) | PropertyKey::PrivateIdentifier(_) => false,
+            PropertyKey::Expression(expr) => self.may_have_side_effects(expr),
+        }
+    }
+
+    /// Class definitions evaluate the super class, decorators, computed keys,
+    /// static initializers and static blocks.
+    fn class_may_have_side_effects(&self, class: &Class<'a>) -> bool {
+        if class.super_class.is_some() || !class.decorators.is_empty() {
+            return true;
+        }
+        class.body.body.iter().any(|element| match element {
+            ClassElement::StaticBlock(block) => !block.body.is_empty(),
+            ClassElement::MethodDefinition(method) => {
+                !method.decorators.is_empty()
+                    || self.property_key_may_have_side_effects(&method.key)
+            }
+            ClassElement::PropertyDefinition(property) => {
+                !property.decorators.is_empty()
+                    || self.property_key_may_have_side_effects(&property.key)
+                    || (property.r#static
+                        && property
+                            .value
+                            .as_ref()
+                            .is_some_and(|value| self.may_have_side_effects(value)))
+            }
+            ClassElement::AccessorProperty(property) => {
+                self.property_key_may_have_side_effects(&property.key)
+                    || (property.r#static
+                        && property
+                            .value
+                            .as_ref()
+                            .is_some_and(|value| self.may_have_side_effects(value)))
+            }
+            ClassElement::TSAbstractMethodDefinition(_)
+            | ClassElement::TSAbstractPropertyDefinition(_)
+            | ClassElement::TSIndexSignature(_) => false,
+        })
+    }
+}
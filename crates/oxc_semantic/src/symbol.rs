@@ -21,6 +21,9 @@ pub struct SymbolTable {
     pub scope_ids: IndexVec<SymbolId, ScopeId>,
     /// Pointer to the AST Node where this symbol is declared
     pub declarations: IndexVec<SymbolId, AstNodeId>,
+    /// Pointers to the AST Nodes of the other declarations of this symbol,
+    /// e.g. declaration merging in TypeScript, function overloads and redeclared `var`s
+    pub redeclarations: IndexVec<SymbolId, Vec<AstNodeId>>,
     pub resolved_references: IndexVec<SymbolId, Vec<ReferenceId>>,
     pub references: IndexVec<ReferenceId, Reference>,
 }
@@ -82,6 +85,26 @@ impl SymbolTable {
 
     pub fn add_declaration(&mut self, node_id: AstNodeId) {
         self.declarations.push(node_id);
+        self.redeclarations.push(vec![]);
+    }
+
+    pub fn get_redeclarations(&self, symbol_id: SymbolId) -> &[AstNodeId] {
+        &self.redeclarations[symbol_id]
+    }
+
+    pub fn add_redeclaration(&mut self, symbol_id: SymbolId, node_id: AstNodeId) {
+        self.redeclarations[symbol_id].push(node_id);
+    }
+
+    /// Whether the symbol is declared by a `class`, `enum`, `function` or `namespace`
+    /// which is merged with an `interface`, `namespace` or another `enum` of the same name.
+    ///
+    /// See <https://www.typescriptlang.org/docs/handbook/declaration-merging.html>
+    pub fn is_merged_declaration(&self, symbol_id: SymbolId) -> bool {
+        !self.redeclarations[symbol_id].is_empty()
+            && self.flags[symbol_id].intersects(
+                SymbolFlags::Interface | SymbolFlags::NameSpaceModule | SymbolFlags::Enum,
+            )
     }
 
     pub fn create_reference(&mut self, reference: Reference) -> ReferenceId {
@@ -0,0 +1,94 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::{EnumValue, SemanticBuilder};
+use oxc_span::{Atom, SourceType};
+
+/// The values of the members of the root enum `E` in `source`
+fn enum_values(source: &str) -> Vec<(String, Option<EnumValue>)> {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true).with_typescript(true);
+    let ret = Parser::new(&allocator, source, source_type).parse();
+    assert!(ret.errors.is_empty(), "failed to parse: {source}");
+    let program = allocator.alloc(ret.program);
+    let semantic = SemanticBuilder::new(source, source_type).build(program).semantic;
+    let symbol_id = semantic.scopes().get_root_binding(&Atom::from("E")).unwrap();
+    semantic
+        .enums()
+        .get_members(symbol_id)
+        .unwrap()
+        .iter()
+        .map(|member| (member.name.to_string(), member.value.clone()))
+        .collect()
+}
+
+fn number(value: f64) -> EnumValue {
+    EnumValue::Number(value)
+}
+
+fn string(value: &str) -> EnumValue {
+    EnumValue::String(value.into())
+}
+
+#[test]
+fn test_auto_increment() {
+    assert_eq!(
+        enum_values("enum E { A, B, C = 10, D }"),
+        vec![
+            ("A".into(), Some(number(0.0))),
+            ("B".into(), Some(number(1.0))),
+            ("C".into(), Some(number(10.0))),
+            ("D".into(), Some(number(11.0))),
+        ]
+    );
+    // No value follows a string or computed member
+    assert_eq!(
+        enum_values("enum E { A = 'a', B, C = foo(), D }"),
+        vec![
+            ("A".into(), Some(string("a"))),
+            ("B".into(), None),
+            ("C".into(), None),
+            ("D".into(), None)
+        ]
+    );
+}
+
+#[test]
+fn test_constant_expressions() {
+    assert_eq!(
+        enum_values(
+            "enum E { A = 1 << 2, B = A | 1, C = ~E.A, D = -(1 + 2) * 2, S = 'a' + 1, T = `${E['A']}b` }"
+        ),
+        vec![
+            ("A".into(), Some(number(4.0))),
+            ("B".into(), Some(number(5.0))),
+            ("C".into(), Some(number(-5.0))),
+            ("D".into(), Some(number(-6.0))),
+            ("S".into(), Some(string("a1"))),
+            ("T".into(), Some(string("4b"))),
+        ]
+    );
+    assert_eq!(
+        enum_values("enum E { A = 1 / 0, B = -1 >>> 28, C = 'x' + A }"),
+        vec![
+            ("A".into(), Some(number(f64::INFINITY))),
+            ("B".into(), Some(number(15.0))),
+            ("C".into(), Some(string("xInfinity"))),
+        ]
+    );
+}
+
+#[test]
+fn test_computed_members() {
+    assert_eq!(
+        enum_values("const a = 1; enum E { A = a, B = 'b'.length, C = [1][0] }"),
+        vec![("A".into(), None), ("B".into(), None), ("C".into(), None)]
+    );
+}
+
+#[test]
+fn test_merged_declarations() {
+    assert_eq!(
+        enum_values("enum E { A = 1 } enum E { B = A + 1 }"),
+        vec![("A".into(), Some(number(1.0))), ("B".into(), Some(number(2.0)))]
+    );
+}
@@ -0,0 +1,69 @@
+mod util;
+use oxc_semantic::{JsxOptions, JsxRuntime};
+use util::SemanticTester;
+
+fn jsx(source_text: &'static str) -> SemanticTester {
+    SemanticTester::js(source_text).with_jsx(true)
+}
+
+fn classic() -> JsxOptions {
+    JsxOptions { runtime: JsxRuntime::Classic, ..JsxOptions::default() }
+}
+
+#[test]
+fn test_automatic_runtime() {
+    let tester = jsx("import React from 'react'; <div />");
+    tester.has_some_symbol("React").has_number_of_references(0).test();
+    let semantic = tester.build();
+    assert_eq!(semantic.jsx_options().runtime, JsxRuntime::Automatic);
+}
+
+#[test]
+fn test_classic_runtime() {
+    jsx("import React from 'react'; <div><span /></div>")
+        .with_jsx_options(classic())
+        .has_root_symbol("React")
+        .has_number_of_reads(2)
+        .test();
+    jsx("import { h, Frag } from 'preact'; <><div /></>")
+        .with_jsx_options(JsxOptions {
+            factory: Some("h".into()),
+            fragment_factory: Some("Frag".into()),
+            ..classic()
+        })
+        .has_root_symbol("h")
+        .has_number_of_reads(2)
+        .test();
+}
+
+#[test]
+fn test_pragmas() {
+    let tester = jsx("/** @jsx h */\n/* @jsxFrag Fragment */\nimport { h, Fragment } from 'preact'; <><div /></>");
+    tester.has_root_symbol("h").has_number_of_reads(2).test();
+    tester.has_root_symbol("Fragment").has_number_of_reads(1).test();
+    let semantic = tester.build();
+    let options = semantic.jsx_options();
+    assert_eq!(options.runtime, JsxRuntime::Classic);
+    assert_eq!(options.factory(), "h");
+    assert_eq!(options.fragment_factory(), "Fragment");
+
+    // Pragmas override the configured options
+    jsx("// @jsxRuntime automatic\nimport React from 'react'; <div />")
+        .with_jsx_options(classic())
+        .has_root_symbol("React")
+        .has_number_of_references(0)
+        .test();
+    let tester = jsx("/** @jsxImportSource preact */ <div />").with_jsx_options(classic());
+    let semantic = tester.build();
+    assert_eq!(semantic.jsx_options().runtime, JsxRuntime::Automatic);
+    assert_eq!(semantic.jsx_options().import_source(), "preact");
+}
+
+#[test]
+fn test_scoped_factory() {
+    // The factory resolves to the nearest binding, the parameter `h` inside of `f`
+    jsx("/** @jsx h */\nimport { h } from 'preact'; function f(h) { return <div /> } <span />")
+        .has_root_symbol("h")
+        .has_number_of_reads(1)
+        .test();
+}
@@ -1,5 +1,7 @@
 mod util;
 
+use oxc_semantic::ExportedBinding;
+use oxc_span::{Atom, Span};
 #[allow(clippy::wildcard_imports)]
 use util::*;
 
@@ -27,6 +29,74 @@ fn test_exports() {
 
     test.has_some_symbol("foo").is_exported().test();
 
-    // FIXME: failing
-    // test.has_some_symbol("defaultExport").is_exported().test();
+    test.has_some_symbol("defaultExport").is_exported().test();
+}
+
+#[test]
+fn test_export_table() {
+    let test = SemanticTester::ts(
+        "
+        const a = 1, b = 2;
+        export const { c } = { c: 3 };
+        export { a, b as renamed };
+        export { d as e } from 'mod';
+        export * as ns from 'mod';
+        export * from 'other';
+        export default function f() {}
+        export enum E { A }
+        namespace N { export const g = 1; const h = 2; }
+        ",
+    );
+    let semantic = test.build();
+    let exports = semantic.exports();
+    let symbol = |name: &str| semantic.scopes().get_root_binding(&name.into()).unwrap();
+
+    for (exported_name, local_name) in
+        [("a", "a"), ("renamed", "b"), ("c", "c"), ("default", "f"), ("E", "E")]
+    {
+        assert_eq!(exports.get_symbol_id(exported_name), Some(symbol(local_name)));
+        assert!(exports.is_exported(symbol(local_name)));
+    }
+    assert_eq!(exports.get_symbol_id("b"), None);
+    assert_eq!(
+        exports.get("e"),
+        Some(&ExportedBinding::ReExport {
+            module_request: "mod".into(),
+            imported_name: Some("d".into())
+        })
+    );
+    assert_eq!(
+        exports.get("ns"),
+        Some(&ExportedBinding::ReExport { module_request: "mod".into(), imported_name: None })
+    );
+    assert_eq!(exports.star_exports(), &[Atom::from("other")]);
+
+    // Namespace members are exported from the namespace, not the file
+    assert!(exports.get("g").is_none());
+    let namespace_member = |name: &str| {
+        semantic
+            .scopes()
+            .iter_bindings()
+            .find(|(_, _, n)| n.as_str() == name)
+            .map(|(_, id, _)| id)
+            .unwrap()
+    };
+    assert!(exports.is_exported(namespace_member("g")));
+    assert!(!exports.is_exported(namespace_member("h")));
+    assert!(!exports.is_exported(symbol("N")));
+}
+
+#[test]
+fn test_export_default_expression() {
+    let test = SemanticTester::js("const a = 1; export default a;");
+    let semantic = test.build();
+    let a = semantic.scopes().get_root_binding(&"a".into()).unwrap();
+    assert_eq!(semantic.exports().get_symbol_id("default"), Some(a));
+
+    let test = SemanticTester::js("export default 1 + 1;");
+    let semantic = test.build();
+    assert_eq!(
+        semantic.exports().get("default"),
+        Some(&ExportedBinding::Anonymous(Span::new(15, 20)))
+    );
 }
@@ -0,0 +1,91 @@
+use oxc_allocator::Allocator;
+use oxc_ast::AstKind;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+/// Check the expression of the last top level expression statement in `source`
+fn may_have_side_effects(source: &str) -> bool {
+    let allocator = Allocator::default();
+    let source_type = SourceType::default().with_module(true).with_typescript(true);
+    let ret = Parser::new(&allocator, source, source_type).parse();
+    assert!(ret.errors.is_empty(), "failed to parse: {source}");
+    let program = allocator.alloc(ret.program);
+    let semantic = SemanticBuilder::new(source, source_type).build(program).semantic;
+    let nodes = semantic.nodes();
+    let expr = nodes
+        .iter()
+        .filter(|node| matches!(nodes.parent_kind(node.id()), Some(AstKind::Program(_))))
+        .filter_map(|node| match node.kind() {
+            AstKind::ExpressionStatement(stmt) => Some(&stmt.expression),
+            _ => None,
+        })
+        .last()
+        .unwrap_or_else(|| panic!("expected an expression statement: {source}"));
+    semantic.may_have_side_effects(expr)
+}
+
+#[test]
+fn test_pure_expressions() {
+    let pure = [
+        "1",
+        "('str')",
+        "null",
+        "undefined",
+        "NaN",
+        "/re/g",
+        "`a${1}b`",
+        "let x; x",
+        "let x; (x, 1)",
+        "let x; x ? 1 : 2",
+        "let x; x && x || 1",
+        "let x; x + 1 === 2",
+        "!0",
+        "void 0",
+        "typeof undeclared",
+        "[1, , 'a']",
+        "({ a: 1, [`b`]: 2, c() {}, get d() { return 1 } })",
+        "(function () { sideEffect() })",
+        "() => sideEffect()",
+        "(class { a = sideEffect(); method() {} static b = 1 })",
+        "this",
+        "let x; x as any",
+        "let x; x!",
+    ];
+    for source in pure {
+        assert!(!may_have_side_effects(source), "expected no side effects: {source}");
+    }
+}
+
+#[test]
+fn test_impure_expressions() {
+    let impure = [
+        "undeclared",
+        "let x; x = 1",
+        "let x; x++",
+        "let x; x += 1",
+        "foo()",
+        "new Foo()",
+        "let x; x.y",
+        "let x; x?.y",
+        "let x; delete x.y",
+        "let x; 'a' in x",
+        "let x; x instanceof Object",
+        "let x; [...x]",
+        "let x; ({ ...x })",
+        "({ [foo()]: 1 })",
+        "`${foo()}`",
+        "let x; (x, foo())",
+        "let x; x ? foo() : 1",
+        "(class extends Object {})",
+        "(class { static a = foo() })",
+        "(class { static { foo() } })",
+        "(class { [foo()]() {} })",
+        "await 1",
+        "import('foo')",
+        "let x; x`tag`",
+    ];
+    for source in impure {
+        assert!(may_have_side_effects(source), "expected side effects: {source}");
+    }
+}
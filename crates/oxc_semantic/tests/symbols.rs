@@ -17,7 +17,6 @@ fn test_class_simple() {
         .test();
 }
 
-#[ignore = "function symbols currently lack SymbolFlags::Function"]
 #[test]
 fn test_function_simple() {
     SemanticTester::js("function foo() { return }")
@@ -87,3 +86,132 @@ fn test_types_simple() {
         .has_number_of_references(1)
         .test();
 }
+
+#[test]
+fn test_decorator_references() {
+    for source in [
+        "let dec; @dec class Foo {}",
+        "let dec; class Foo { @dec method() {} }",
+        "let dec; class Foo { @dec prop = 1 }",
+        "let dec; class Foo { @dec.a() accessor prop = 1 }",
+    ] {
+        SemanticTester::js(source).has_root_symbol("dec").has_number_of_reads(1).test();
+    }
+
+    SemanticTester::ts("let dec; class Foo { constructor(@dec() foo) {} }")
+        .has_root_symbol("dec")
+        .has_number_of_reads(1)
+        .test();
+}
+
+#[test]
+fn test_using_declarations() {
+    SemanticTester::js("{ using x = foo(); x.bar(); }")
+        .has_some_symbol("x")
+        .contains_flags(
+            SymbolFlags::BlockScopedVariable
+                | SymbolFlags::ConstVariable
+                | SymbolFlags::UsingVariable,
+        )
+        .has_number_of_reads(1)
+        .test();
+
+    SemanticTester::js("async function f() { await using x = foo(); }")
+        .has_some_symbol("x")
+        .contains_flags(SymbolFlags::UsingVariable)
+        .test();
+}
+
+#[test]
+fn test_declaration_merging() {
+    SemanticTester::ts("interface Foo {} class Foo {}")
+        .has_root_symbol("Foo")
+        .contains_flags(SymbolFlags::Class | SymbolFlags::Interface)
+        .is_merged_declaration(true)
+        .test();
+
+    SemanticTester::ts("function foo() {} namespace foo { export const bar = 1; }")
+        .has_root_symbol("foo")
+        .contains_flags(SymbolFlags::NameSpaceModule)
+        .is_merged_declaration(true)
+        .test();
+
+    SemanticTester::ts("enum Foo { A } enum Foo { B = 1 }")
+        .has_root_symbol("Foo")
+        .is_merged_declaration(true)
+        .test();
+
+    SemanticTester::ts("function foo(a: string): void; function foo(a) {}")
+        .has_root_symbol("foo")
+        .is_merged_declaration(false)
+        .test();
+
+    SemanticTester::js("var foo; var foo;")
+        .has_root_symbol("foo")
+        .is_merged_declaration(false)
+        .test();
+
+    SemanticTester::ts("interface Foo {}")
+        .has_root_symbol("Foo")
+        .is_merged_declaration(false)
+        .test();
+}
+
+#[test]
+fn test_type_query_and_qualified_name_references() {
+    SemanticTester::ts("const a = 1; type B = typeof a;")
+        .has_root_symbol("a")
+        .has_number_of_reads(1)
+        .test();
+
+    SemanticTester::ts("const a = { b: 1 }; type C = typeof a.b;")
+        .has_root_symbol("a")
+        .has_number_of_reads(1)
+        .test();
+
+    SemanticTester::ts("namespace N { export type T = number; }\nlet a: N.T;")
+        .has_root_symbol("N")
+        .has_number_of_reads(1)
+        .test();
+}
+
+#[test]
+fn test_namespaces() {
+    SemanticTester::ts("namespace N { export const a = 1; const b = 2; }")
+        .has_root_symbol("N")
+        .contains_flags(SymbolFlags::NameSpaceModule | SymbolFlags::ValueModule)
+        .test();
+
+    SemanticTester::ts("namespace N { export const a = 1; const b = 2; }")
+        .has_some_symbol("a")
+        .contains_flags(SymbolFlags::Export)
+        .test();
+
+    SemanticTester::ts("namespace N { export type T = number; }")
+        .has_root_symbol("N")
+        .contains_flags(SymbolFlags::NameSpaceModule)
+        .test();
+
+    SemanticTester::ts("namespace N { export const a = 1; }\nN.a;")
+        .has_root_symbol("N")
+        .has_number_of_reads(1)
+        .test();
+}
+
+#[test]
+fn test_computed_enum_members() {
+    SemanticTester::ts("enum E { ['a'] = 1 }")
+        .has_some_symbol("a")
+        .contains_flags(SymbolFlags::EnumMember)
+        .test();
+
+    SemanticTester::ts("enum E { [`b`] = 1 }")
+        .has_some_symbol("b")
+        .contains_flags(SymbolFlags::EnumMember)
+        .test();
+
+    SemanticTester::ts("const foo = 'c'; enum E { [foo] = 1 }")
+        .has_root_symbol("foo")
+        .has_number_of_reads(1)
+        .test();
+}
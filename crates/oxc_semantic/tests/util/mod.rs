@@ -7,13 +7,14 @@ use oxc_diagnostics::{
     Error,
 };
 extern crate miette;
-use oxc_semantic::{Reference, Semantic, SemanticBuilder, SymbolFlags, SymbolId};
+use oxc_semantic::{JsxOptions, Reference, Semantic, SemanticBuilder, SymbolFlags, SymbolId};
 use oxc_span::{Atom, SourceType};
 
 pub struct SemanticTester {
     allocator: Allocator,
     source_type: SourceType,
     source_text: &'static str,
+    jsx_options: JsxOptions,
 }
 
 impl SemanticTester {
@@ -33,7 +34,12 @@ impl SemanticTester {
     }
 
     pub fn new(source_text: &'static str, source_type: SourceType) -> Self {
-        Self { allocator: Allocator::default(), source_type, source_text }
+        Self {
+            allocator: Allocator::default(),
+            source_type,
+            source_text,
+            jsx_options: JsxOptions::default(),
+        }
     }
 
     /// Set the [`SourceType`] to TypeScript (or JavaScript, using `false`)
@@ -50,6 +56,13 @@ impl SemanticTester {
         self
     }
 
+    /// Set the JSX options of the project, which the pragmas of the source text override
+    #[allow(dead_code)]
+    pub fn with_jsx_options(mut self, options: JsxOptions) -> Self {
+        self.jsx_options = options;
+        self
+    }
+
     /// Parse the source text and produce a new [`Semantic`]
     #[allow(unstable_name_collisions)]
     pub fn build(&self) -> Semantic<'_> {
@@ -72,6 +85,7 @@ impl SemanticTester {
         let semantic_ret = SemanticBuilder::new(self.source_text, self.source_type)
             .with_check_syntax_error(true)
             .with_trivias(parse.trivias)
+            .with_jsx_options(self.jsx_options.clone())
             .build_module_record(PathBuf::new(), program)
             .build(program);
 
@@ -251,13 +265,33 @@ impl<'a> SymbolTester<'a> {
     pub fn is_exported(mut self) -> Self {
         self.test_result = match self.test_result {
             Ok(symbol_id) => {
-                let binding = Atom::from(self.target_symbol_name.clone());
-                if self.semantic.module_record().exported_bindings.contains_key(&binding)
-                    && self.semantic.scopes().get_root_binding(&binding) == Some(symbol_id)
-                {
+                if self.semantic.exports().is_exported(symbol_id) {
                     Ok(symbol_id)
                 } else {
-                    Err(miette!("Expected {binding} to be exported."))
+                    Err(miette!("Expected {} to be exported.", self.target_symbol_name))
+                }
+            }
+            e => e,
+        };
+        self
+    }
+
+    /// Checks if the resolved symbol merges multiple declarations, using [`SymbolTable::is_merged_declaration()`]
+    ///
+    /// [`SymbolTable::is_merged_declaration()`]: oxc_semantic::SymbolTable::is_merged_declaration
+    #[allow(clippy::wrong_self_convention)]
+    pub fn is_merged_declaration(mut self, yes: bool) -> Self {
+        self.test_result = match self.test_result {
+            Ok(symbol_id) => {
+                if self.semantic.symbols().is_merged_declaration(symbol_id) == yes {
+                    Ok(symbol_id)
+                } else if yes {
+                    Err(miette!("Expected {} to be a merged declaration.", self.target_symbol_name))
+                } else {
+                    Err(miette!(
+                        "Expected {} not to be a merged declaration.",
+                        self.target_symbol_name
+                    ))
                 }
             }
             e => e,
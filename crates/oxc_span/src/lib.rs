@@ -1,9 +1,11 @@
 mod atom;
+mod line_index;
 mod source_type;
 mod span;
 
 pub use crate::{
     atom::Atom,
+    line_index::{LineCol, LineIndex},
     source_type::{Language, LanguageVariant, ModuleKind, SourceType, VALID_EXTENSIONS},
     span::{GetSpan, Span},
 };
@@ -0,0 +1,148 @@
+//! Conversion between offsets and line / column positions
+
+/// Zero-based line and column of a position in the source text
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LineCol {
+    pub line: u32,
+    pub col: u32,
+}
+
+impl LineCol {
+    pub const fn new(line: u32, col: u32) -> Self {
+        Self { line, col }
+    }
+}
+
+/// Start offsets of the lines of a source text, built once per file.
+///
+/// Converting an offset to a line and column is a binary search over the line starts.
+/// Lines are terminated by `\n`, `\r\n` or `\r`, as in editors and source maps.
+///
+/// Columns are UTF-8 byte offsets from the start of the line. The `_utf16` methods count
+/// UTF-16 code units instead, as used by the Language Server Protocol and source maps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineIndex {
+    /// Offset of the first character of each line
+    line_starts: Vec<u32>,
+    /// Length of the source text
+    len: u32,
+}
+
+impl LineIndex {
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn new(source_text: &str) -> Self {
+        let bytes = source_text.as_bytes();
+        let mut line_starts = vec![0];
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\n' => line_starts.push(i as u32 + 1),
+                b'\r' => {
+                    if bytes.get(i + 1) == Some(&b'\n') {
+                        i += 1;
+                    }
+                    line_starts.push(i as u32 + 1);
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        Self { line_starts, len: bytes.len() as u32 }
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Line and byte column of `offset`, offsets past the end are clamped to the end
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn line_col(&self, offset: u32) -> LineCol {
+        let offset = offset.min(self.len);
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        LineCol::new(line as u32, offset - self.line_starts[line])
+    }
+
+    /// Offset of `line_col`, or `None` when it is past the end of its line
+    pub fn offset(&self, line_col: LineCol) -> Option<u32> {
+        let (start, end) = self.line_bounds(line_col.line)?;
+        let offset = start.checked_add(line_col.col)?;
+        (offset <= end).then_some(offset)
+    }
+
+    /// Line and UTF-16 column of `offset` in `source_text`, which this index was built from
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn line_col_utf16(&self, source_text: &str, offset: u32) -> LineCol {
+        let LineCol { line, col } = self.line_col(offset);
+        let start = self.line_starts[line as usize] as usize;
+        let col = source_text[start..]
+            .char_indices()
+            .take_while(|(i, _)| *i < col as usize)
+            .map(|(_, c)| c.len_utf16())
+            .sum::<usize>();
+        LineCol::new(line, col as u32)
+    }
+
+    /// Offset of the UTF-16 position `line_col` in `source_text`, which this index was built from,
+    /// or `None` when it is past the end of its line or inside a surrogate pair
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn offset_utf16(&self, source_text: &str, line_col: LineCol) -> Option<u32> {
+        let (start, end) = self.line_bounds(line_col.line)?;
+        let mut col = 0;
+        for (i, c) in source_text[start as usize..end as usize].char_indices() {
+            if col >= line_col.col {
+                return (col == line_col.col).then_some(start + i as u32);
+            }
+            col += c.len_utf16() as u32;
+        }
+        (col == line_col.col).then_some(end)
+    }
+
+    /// Start and end offsets of `line`, the end includes the line terminator
+    fn line_bounds(&self, line: u32) -> Option<(u32, u32)> {
+        let line = line as usize;
+        let start = *self.line_starts.get(line)?;
+        let end = self.line_starts.get(line + 1).copied().unwrap_or(self.len);
+        Some((start, end))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LineCol, LineIndex};
+
+    #[test]
+    fn line_col() {
+        let source_text = "a\nbc\r\nd\re\n";
+        let index = LineIndex::new(source_text);
+        assert_eq!(index.line_count(), 5);
+        let positions =
+            [(0, 0, 0), (1, 0, 1), (2, 1, 0), (4, 1, 2), (6, 2, 0), (8, 3, 0), (10, 4, 0)];
+        for (offset, line, col) in positions {
+            let line_col = LineCol::new(line, col);
+            assert_eq!(index.line_col(offset), line_col, "{offset}");
+            assert_eq!(index.offset(line_col), Some(offset), "{offset}");
+        }
+        assert_eq!(index.line_col(100), LineCol::new(4, 0));
+        assert_eq!(index.offset(LineCol::new(0, 3)), None);
+        assert_eq!(index.offset(LineCol::new(5, 0)), None);
+
+        let index = LineIndex::new("");
+        assert_eq!(index.line_count(), 1);
+        assert_eq!(index.line_col(0), LineCol::new(0, 0));
+    }
+
+    #[test]
+    fn utf16() {
+        let source_text = "let a = 'é😀';\nb";
+        let index = LineIndex::new(source_text);
+        let emoji = u32::try_from(source_text.find('😀').unwrap()).unwrap();
+        assert_eq!(index.line_col(emoji + 4), LineCol::new(0, 15));
+        assert_eq!(index.line_col_utf16(source_text, emoji), LineCol::new(0, 10));
+        assert_eq!(index.line_col_utf16(source_text, emoji + 4), LineCol::new(0, 12));
+        assert_eq!(index.offset_utf16(source_text, LineCol::new(0, 12)), Some(emoji + 4));
+        assert_eq!(index.offset_utf16(source_text, LineCol::new(0, 11)), None);
+        assert_eq!(index.offset_utf16(source_text, LineCol::new(0, 15)), Some(emoji + 7));
+        assert_eq!(index.offset_utf16(source_text, LineCol::new(1, 1)), Some(emoji + 8));
+        assert_eq!(index.offset_utf16(source_text, LineCol::new(1, 2)), None);
+    }
+}
@@ -25,9 +25,16 @@ pub struct ModuleRecord {
     ///   import ImportClause FromClause
     ///   import ModuleSpecifier
     ///   export ExportFromClause FromClause
+    ///   require(ModuleSpecifier)
     /// Keyed by ModuleSpecifier, valued by all node occurrences
     pub requested_modules: IndexMap<Atom, Vec<Span>, BuildHasherDefault<FxHasher>>,
 
+    /// `import("mod")` with a literal specifier, keyed by specifier in source order.
+    ///
+    /// Dynamic imports are loaded asynchronously, they are in `loaded_modules` but not in
+    /// `requested_modules`.
+    pub dynamic_imports: IndexMap<Atom, Vec<Span>, BuildHasherDefault<FxHasher>>,
+
     /// `[[LoadedModules]]`
     ///
     /// A map from the specifier strings used by the module represented by this record to request the importation of a module to the resolved Module Record.
@@ -64,12 +71,30 @@ pub struct ModuleRecord {
 
     pub export_default: Option<Span>,
     pub export_default_duplicated: Vec<Span>,
+
+    /// Whether the module has `import` or `export` declarations
+    pub has_module_syntax: bool,
+
+    /// CommonJS exports by name, from `exports.a = ...`, `module.exports.a = ...`,
+    /// `Object.defineProperty(exports, "a", ...)` and the properties of `module.exports = { a }`
+    pub commonjs_exports: FxHashMap<Atom, Span>,
+
+    /// `module.exports = ...`, the exported value is not known when it is not an object literal
+    pub commonjs_export_assignment: Option<Span>,
 }
 
 impl ModuleRecord {
     pub fn new(resolved_absolute_path: PathBuf) -> Self {
         Self { resolved_absolute_path, ..Self::default() }
     }
+
+    /// A module with CommonJS exports and without `import` or `export` declarations.
+    ///
+    /// `require("mod")` requests are in [`ModuleRecord::requested_modules`] for both kinds.
+    pub fn is_commonjs(&self) -> bool {
+        !self.has_module_syntax
+            && (!self.commonjs_exports.is_empty() || self.commonjs_export_assignment.is_some())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
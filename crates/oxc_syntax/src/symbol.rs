@@ -37,6 +37,8 @@ bitflags! {
         const ValueModule             = 1 << 17;
         // In a dts file or there is a declare flag
         const Ambient                 = 1 << 18;
+        /// A `using` or `await using` variable, disposed at the end of its block
+        const UsingVariable           = 1 << 19;
 
         const Enum = Self::ConstEnum.bits() | Self::RegularEnum.bits();
 
@@ -79,6 +81,10 @@ impl SymbolFlags {
         self.contains(Self::ConstVariable)
     }
 
+    pub fn is_using_variable(&self) -> bool {
+        self.contains(Self::UsingVariable)
+    }
+
     pub fn is_function(&self) -> bool {
         self.contains(Self::Function)
     }
@@ -6,7 +6,7 @@ use oxc_allocator::Allocator;
 use oxc_ast_lower::AstLower;
 use oxc_diagnostics::Error;
 use oxc_formatter::{Formatter, FormatterOptions};
-use oxc_linter::{LintContext, Linter};
+use oxc_linter::{LintConfig, LintContext, LintOptions, Linter, Message};
 use oxc_minifier::{CompressOptions, Compressor, ManglerBuilder, Printer, PrinterOptions};
 use oxc_parser::{Parser, ParserReturn};
 use oxc_query::{schema, Adapter, SCHEMA_TEXT};
@@ -33,6 +33,17 @@ pub fn graphql_schema_text() -> String {
     SCHEMA_TEXT.to_string()
 }
 
+/// Metadata and documentation of all lint rules as a JSON array, for the config editor
+///
+/// # Panics
+/// When writing to the buffer fails
+#[wasm_bindgen(js_name = lintRules)]
+pub fn lint_rules() -> String {
+    let mut buffer = vec![];
+    Linter::print_rules_json(&mut buffer);
+    String::from_utf8(buffer).unwrap()
+}
+
 #[wasm_bindgen]
 #[derive(Default)]
 pub struct Oxc {
@@ -58,6 +69,9 @@ pub struct OxcDiagnostic {
     pub end: usize,
     pub severity: String,
     pub message: String,
+    /// The lint rule, e.g. `eslint/no-debugger`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
 }
 
 #[wasm_bindgen]
@@ -121,6 +135,7 @@ impl Oxc {
                             end: label.offset() + label.len(),
                             severity: format!("{:?}", error.severity().unwrap_or_default()),
                             message: format!("{error}"),
+                            rule: error.code().map(|code| code.to_string()),
                         }
                         .serialize(&self.serializer)
                         .unwrap()
@@ -142,6 +157,7 @@ impl Oxc {
                             end: position.end as usize,
                             severity: format!("{kind:?}"),
                             message: reason.to_string(),
+                            rule: None,
                         }
                         .serialize(&self.serializer)
                         .unwrap(),
@@ -152,13 +168,13 @@ impl Oxc {
     }
 
     /// # Errors
-    /// Serde serialization error
+    /// Serde serialization error, or an invalid linter config
     #[wasm_bindgen]
     pub fn run(
         &mut self,
         run_options: &OxcRunOptions,
         parser_options: &OxcParserOptions,
-        _linter_options: &OxcLinterOptions,
+        linter_options: &OxcLinterOptions,
         formatter_options: &OxcFormatterOptions,
         minifier_options: &OxcMinifierOptions,
         _type_checking_options: &OxcTypeCheckingOptions,
@@ -191,10 +207,22 @@ impl Oxc {
                 .build(program);
             self.save_diagnostics(semantic_ret.errors);
 
+            let config = linter_options
+                .config()
+                .map(|config| {
+                    let value = serde_json::from_str(&config).map_err(|e| e.to_string())?;
+                    LintConfig::from_value(&value)
+                })
+                .transpose()
+                .map_err(|error| {
+                    serde_wasm_bindgen::Error::new(format!("Invalid config: {error}"))
+                })?;
+            let linter = Linter::from_options(LintOptions::default().with_config(config));
+
             let semantic = Rc::new(semantic_ret.semantic);
             let lint_ctx = LintContext::new(&semantic);
-            let linter_ret = Linter::new().run(lint_ctx);
-            let diagnostics = linter_ret.into_iter().map(|e| e.error).collect();
+            let linter_ret = linter.run(lint_ctx);
+            let diagnostics = linter_ret.into_iter().map(Message::into_error).collect();
             self.save_diagnostics(diagnostics);
         }
 
@@ -256,10 +284,11 @@ impl Oxc {
             return "'test.tsx' source type invalid, this should never happen.\nPlease open an issue at https://github.com/web-infra-dev/oxc".to_string().serialize(&self.serializer);
         };
 
-        let ParserReturn { errors: parse_errors, panicked, program: returned_program, trivias } =
-            Parser::new(&allocator, source_text, source_type)
-                .allow_return_outside_function(parser_options.allow_return_outside_function)
-                .parse();
+        let ParserReturn {
+            errors: parse_errors, panicked, program: returned_program, trivias, ..
+        } = Parser::new(&allocator, source_text, source_type)
+            .allow_return_outside_function(parser_options.allow_return_outside_function)
+            .parse();
 
         let allocated_program = allocator.alloc(returned_program);
 
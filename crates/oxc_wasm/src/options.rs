@@ -21,7 +21,7 @@ impl OxcRunOptions {
 
     #[wasm_bindgen(getter)]
     pub fn syntax(self) -> bool {
-        self.format
+        self.syntax
     }
 
     #[wasm_bindgen(setter)]
@@ -96,14 +96,28 @@ impl OxcParserOptions {
 }
 
 #[wasm_bindgen]
-#[derive(Default, Clone, Copy)]
-pub struct OxcLinterOptions;
+#[derive(Default, Clone)]
+pub struct OxcLinterOptions {
+    config: Option<String>,
+}
 
 #[wasm_bindgen]
 impl OxcLinterOptions {
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// The config file of the CLI as a JSON string,
+    /// e.g. `{ "categories": { "pedantic": "warn" }, "rules": { "no-debugger": "off" } }`
+    #[wasm_bindgen(getter)]
+    pub fn config(&self) -> Option<String> {
+        self.config.clone()
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_config(&mut self, config: Option<String>) {
+        self.config = config;
     }
 }
 
@@ -13,13 +13,12 @@ use crate::walk::Walk;
 use miette::NamedSource;
 use oxc_allocator::Allocator;
 use oxc_diagnostics::{miette, Error, Severity};
-use oxc_linter::{LintContext, Linter};
+use oxc_linter::{CancellationToken, LintContext, Linter};
 use oxc_linter_plugin::{make_relative_path_parts, LinterPlugin};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
-use oxc_span::{SourceType, VALID_EXTENSIONS};
-use ropey::Rope;
-use tower_lsp::lsp_types::{self, Position, Range, Url};
+use oxc_span::{LineCol, LineIndex, SourceType, VALID_EXTENSIONS};
+use tower_lsp::lsp_types::{self, CodeDescription, NumberOrString, Position, Range, Url};
 
 #[derive(Debug)]
 struct ErrorWithPosition {
@@ -38,15 +37,23 @@ struct LabeledSpanWithPosition {
 }
 
 impl ErrorWithPosition {
-    pub fn new(error: Error, text: &str, fixed_content: Option<FixedContent>) -> Self {
+    pub fn new(
+        error: Error,
+        text: &str,
+        line_index: &LineIndex,
+        fixed_content: Option<FixedContent>,
+    ) -> Self {
         let labels = error.labels().map_or(vec![], Iterator::collect);
 
         let labels_with_pos: Vec<LabeledSpanWithPosition> = labels
             .iter()
             .map(|labeled_span| LabeledSpanWithPosition {
-                start_pos: offset_to_position(labeled_span.offset(), text).unwrap_or_default(),
-                end_pos: offset_to_position(labeled_span.offset() + labeled_span.len(), text)
-                    .unwrap_or_default(),
+                start_pos: offset_to_position(labeled_span.offset(), text, line_index),
+                end_pos: offset_to_position(
+                    labeled_span.offset() + labeled_span.len(),
+                    text,
+                    line_index,
+                ),
                 message: labeled_span.label().map(ToString::to_string),
             })
             .collect();
@@ -94,10 +101,12 @@ impl ErrorWithPosition {
         lsp_types::Diagnostic {
             range: Range { start: self.start_pos, end: self.end_pos },
             severity,
-            code: None,
+            code: self.miette_err.code().map(|code| NumberOrString::String(code.to_string())),
             message,
             source: Some("oxc".into()),
-            code_description: None,
+            code_description: self.miette_err.url().and_then(|url| {
+                Url::parse(&url.to_string()).ok().map(|href| CodeDescription { href })
+            }),
             related_information,
             tags: None,
             data: None,
@@ -137,11 +146,17 @@ pub struct IsolatedLintHandler {
     options: Arc<LintOptions>,
     linter: Arc<Linter>,
     plugin: Plugin,
+    cancellation: CancellationToken,
 }
 
 impl IsolatedLintHandler {
-    pub fn new(options: Arc<LintOptions>, linter: Arc<Linter>, plugin: Plugin) -> Self {
-        Self { options, linter, plugin }
+    pub fn new(
+        options: Arc<LintOptions>,
+        linter: Arc<Linter>,
+        plugin: Plugin,
+        cancellation: CancellationToken,
+    ) -> Self {
+        Self { options, linter, plugin, cancellation }
     }
 
     /// # Panics
@@ -195,12 +210,17 @@ impl IsolatedLintHandler {
 
         let linter = Arc::clone(&self.linter);
         let plugin = Arc::clone(&self.plugin);
+        let cancellation = self.cancellation.clone();
         rayon::spawn(move || {
             while let Ok(path) = rx_path.recv() {
                 let tx_error = tx_error.clone();
                 let linter = Arc::clone(&linter);
                 let plugin = Arc::clone(&plugin);
+                let cancellation = cancellation.clone();
                 rayon::spawn(move || {
+                    if cancellation.is_cancelled() {
+                        return;
+                    }
                     if let Some(diagnostics) = Self::lint_path(&linter, &path, plugin) {
                         tx_error.send(diagnostics).unwrap();
                     }
@@ -231,6 +251,7 @@ impl IsolatedLintHandler {
     ) -> Option<(PathBuf, Vec<ErrorWithPosition>)> {
         let source_text =
             fs::read_to_string(path).unwrap_or_else(|_| panic!("Failed to read {path:?}"));
+        let line_index = LineIndex::new(&source_text);
         let allocator = Allocator::default();
         let source_type =
             SourceType::from_path(path).unwrap_or_else(|_| panic!("Incorrect {path:?}"));
@@ -245,7 +266,7 @@ impl IsolatedLintHandler {
                 .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
                 .collect();
 
-            return Some(Self::wrap_diagnostics(path, &source_text, reports));
+            return Some(Self::wrap_diagnostics(path, &source_text, &line_index, reports));
         };
 
         let program = allocator.alloc(ret.program);
@@ -260,7 +281,7 @@ impl IsolatedLintHandler {
                 .into_iter()
                 .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
                 .collect();
-            return Some(Self::wrap_diagnostics(path, &source_text, reports));
+            return Some(Self::wrap_diagnostics(path, &source_text, &line_index, reports));
         };
 
         let mut lint_ctx = LintContext::new(&Rc::new(semantic_ret.semantic));
@@ -285,34 +306,37 @@ impl IsolatedLintHandler {
         if linter.options().fix {
             let reports = result
                 .into_iter()
-                .map(|msg| {
-                    let fixed_content = msg.fix.map(|f| FixedContent {
+                .map(|mut msg| {
+                    let fixed_content = msg.fix.take().map(|f| FixedContent {
                         code: f.content.to_string(),
                         range: Range {
-                            start: offset_to_position(f.span.start as usize, &source_text)
-                                .unwrap_or_default(),
-                            end: offset_to_position(f.span.end as usize, &source_text)
-                                .unwrap_or_default(),
+                            start: offset_to_position(
+                                f.span.start as usize,
+                                &source_text,
+                                &line_index,
+                            ),
+                            end: offset_to_position(f.span.end as usize, &source_text, &line_index),
                         },
                     });
 
-                    ErrorReport { error: msg.error, fixed_content }
+                    ErrorReport { error: msg.into_error(), fixed_content }
                 })
                 .collect::<Vec<ErrorReport>>();
 
-            return Some(Self::wrap_diagnostics(path, &source_text, reports));
+            return Some(Self::wrap_diagnostics(path, &source_text, &line_index, reports));
         }
 
         let errors = result
             .into_iter()
-            .map(|diagnostic| ErrorReport { error: diagnostic.error, fixed_content: None })
+            .map(|diagnostic| ErrorReport { error: diagnostic.into_error(), fixed_content: None })
             .collect();
-        Some(Self::wrap_diagnostics(path, &source_text, errors))
+        Some(Self::wrap_diagnostics(path, &source_text, &line_index, errors))
     }
 
     fn wrap_diagnostics(
         path: &Path,
         source_text: &str,
+        line_index: &LineIndex,
         reports: Vec<ErrorReport>,
     ) -> (PathBuf, Vec<ErrorWithPosition>) {
         let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text.to_owned()));
@@ -322,6 +346,7 @@ impl IsolatedLintHandler {
                 ErrorWithPosition::new(
                     report.error.with_source_code(Arc::clone(&source)),
                     source_text,
+                    line_index,
                     report.fixed_content,
                 )
             })
@@ -330,25 +355,34 @@ impl IsolatedLintHandler {
     }
 }
 
+/// Positions in the Language Server Protocol count UTF-16 code units by default
 #[allow(clippy::cast_possible_truncation)]
-fn offset_to_position(offset: usize, source_text: &str) -> Option<Position> {
-    let rope = Rope::from_str(source_text);
-    let line = rope.try_char_to_line(offset).ok()?;
-    let first_char_of_line = rope.try_line_to_char(line).ok()?;
-    let column = offset - first_char_of_line;
-    Some(Position::new(line as u32, column as u32))
+fn offset_to_position(offset: usize, source_text: &str, line_index: &LineIndex) -> Position {
+    let LineCol { line, col } = line_index.line_col_utf16(source_text, offset as u32);
+    Position::new(line, col)
 }
 
 #[derive(Debug)]
 pub struct ServerLinter {
     linter: Arc<Linter>,
     plugin: Plugin,
+    /// Cancelled when the server shuts down
+    cancellation: CancellationToken,
 }
 
 impl ServerLinter {
     pub fn new() -> Self {
         let linter = Linter::new().with_fix(true);
-        Self { linter: Arc::new(linter), plugin: Arc::new(RwLock::new(None)) }
+        Self {
+            linter: Arc::new(linter),
+            plugin: Arc::new(RwLock::new(None)),
+            cancellation: CancellationToken::new(),
+        }
+    }
+
+    /// Stop linting, files that are being linted are finished.
+    pub fn cancel(&self) {
+        self.cancellation.cancel();
     }
 
     pub fn make_plugin(&self, root_uri: &Url) {
@@ -374,6 +408,7 @@ impl ServerLinter {
             Arc::new(options),
             Arc::clone(&self.linter),
             Arc::clone(&self.plugin),
+            self.cancellation.clone(),
         )
         .run_full()
     }
@@ -391,6 +426,7 @@ impl ServerLinter {
             Arc::new(options),
             Arc::clone(&self.linter),
             Arc::clone(&self.plugin),
+            self.cancellation.clone(),
         )
         .run_single(&uri.to_file_path().unwrap())
     }
@@ -72,6 +72,7 @@ impl LanguageServer for Backend {
     }
 
     async fn shutdown(&self) -> Result<()> {
+        self.server_linter.cancel();
         Ok(())
     }
 
@@ -163,6 +163,15 @@ impl BabelCase {
         })
     }
 
+    fn is_decorators_legacy(&self) -> bool {
+        self.options.plugins.iter().any(|v| {
+            let string_value = v.as_str().is_some_and(|v| v == "decorators-legacy");
+            let array_value =
+                v.get(0).and_then(Value::as_str).is_some_and(|s| s == "decorators-legacy");
+            string_value || array_value
+        })
+    }
+
     fn is_typescript_definition(&self) -> bool {
         self.options.plugins.iter().filter_map(Value::as_array).any(|p| {
             let typescript = p.get(0).and_then(Value::as_str).is_some_and(|s| s == "typescript");
@@ -214,6 +223,10 @@ impl Case for BabelCase {
         self.options.allow_return_outside_function
     }
 
+    fn allow_legacy_decorators(&self, source_type: SourceType) -> bool {
+        source_type.is_typescript() || self.is_decorators_legacy()
+    }
+
     fn test_result(&self) -> &TestResult {
         &self.result
     }
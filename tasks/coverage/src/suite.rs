@@ -256,6 +256,9 @@ pub trait Case: Sized + Sync + Send + UnwindSafe {
     fn allow_return_outside_function(&self) -> bool {
         false
     }
+    fn allow_legacy_decorators(&self, source_type: SourceType) -> bool {
+        source_type.is_typescript()
+    }
     fn test_result(&self) -> &TestResult;
 
     fn should_fail(&self) -> bool {
@@ -292,6 +295,7 @@ pub trait Case: Sized + Sync + Send + UnwindSafe {
         let source_text = self.code();
         let parser_ret = Parser::new(&allocator, source_text, source_type)
             .allow_return_outside_function(self.allow_return_outside_function())
+            .allow_legacy_decorators(self.allow_legacy_decorators(source_type))
             .parse();
 
         // Make sure serialization doesn't crash for ast and hir, also for code coverage.